@@ -49,6 +49,11 @@ case ${TARGET} in
     riscv64*)
         export TARGET_CC="riscv64-linux-gnu-gcc"
         ;;
+    # The table tests grow the indirect function table, which `wasm-ld` only
+    # allows when asked to.
+    wasm32*)
+        export RUSTFLAGS="${RUSTFLAGS} -Clink-arg=--growable-table"
+        ;;
 esac
 
 echo "RUSTFLAGS=${RUSTFLAGS}"
@@ -96,7 +101,35 @@ INTRINSIC_TEST="--manifest-path=crates/intrinsic-test/Cargo.toml"
 
 cargo_test "${CORE_ARCH} --release"
 
+# There's no `std` for nvptx, and so no test harness to run the
+# `#[assert_instr]` tests with. Instead build their shims into a PTX module,
+# against a build of `stdarch_test` which only has the macros, and check that
+# module on the host.
+if [ "${TARGET}" = "nvptx64-nvidia-cuda" ] && [ "${STDARCH_DISABLE_ASSERT_INSTR}" = "" ]; then
+    TARGET_DIR="${CARGO_TARGET_DIR:-target}"
+    cargo build --release --manifest-path=crates/stdarch-test/Cargo.toml
+    cargo build --release --lib --manifest-path=crates/stdarch-test/Cargo.toml --target="${TARGET}"
+    # `sm_80` is the oldest architecture with all the instructions tested. With
+    # a single codegen unit all the shims end up in a single PTX module.
+    cargo rustc ${CORE_ARCH} --lib --release --target="${TARGET}" -- \
+        --cfg test \
+        --extern stdarch_test="${TARGET_DIR}/${TARGET}/release/libstdarch_test.rlib" \
+        -L dependency="${TARGET_DIR}/${TARGET}/release/deps" \
+        -L dependency="${TARGET_DIR}/release/deps" \
+        -C codegen-units=1 \
+        -C target-cpu=sm_80 \
+        --emit=asm="${TARGET_DIR}/core_arch.ptx"
+    "${TARGET_DIR}/release/assert-instr-ptx" "${TARGET_DIR}/core_arch.ptx"
+fi
+
 if [ "$NOSTD" != "1" ]; then
+    # Record the raw values that features are detected from on the host, so
+    # that the tests check that the features detected from them round trip.
+    if [ "$NORUN" != "1" ]; then
+        STD_DETECT_DUMP="${CARGO_TARGET_DIR:-$(pwd)/target}/std_detect_dump.json"
+        cargo run ${STD_DETECT} --target="${TARGET}" --bin std_detect_dump > "${STD_DETECT_DUMP}"
+        export STD_DETECT_DUMP
+    fi
     cargo_test "${STD_DETECT}"
     cargo_test "${STD_DETECT} --release"
 
@@ -135,6 +168,31 @@ case ${TARGET} in
         export RUSTFLAGS="${OLD_RUSTFLAGS} -C target-feature=+vsx"
         cargo_test "--release"
         ;;
+    # objdump only decodes the cache-block and wait-on-reservation-set
+    # instructions if the extensions are recorded in the ELF attributes, so
+    # their `assert_instr` tests only run with them enabled.
+    riscv64*)
+        export RUSTFLAGS="${RUSTFLAGS} -C target-feature=+zawrs,+zicbom,+zicboz,+zicbop"
+        cargo_test "${CORE_ARCH} --release"
+        ;;
+    loongarch64*)
+        export RUSTFLAGS="${RUSTFLAGS} -C target-feature=+lasx"
+        cargo_test "--release"
+        ;;
+    # `memory.init` and `data.drop` only validate in modules with a data count
+    # section, which `wasm-ld` only emits when linking with a shared memory.
+    # That needs everything, including `std`, to be built with atomics.
+    wasm32*)
+        OLD_RUSTFLAGS="${RUSTFLAGS}"
+        export RUSTFLAGS="${OLD_RUSTFLAGS} -C target-feature=+atomics,+bulk-memory"
+        cargo_test "${CORE_ARCH} --release -Z build-std=std,panic_abort"
+
+        # The half-precision intrinsics are only built with the `fp16` target
+        # feature. wasmtime doesn't implement the half-precision proposal,
+        # so only check that they and their tests build.
+        export RUSTFLAGS="${OLD_RUSTFLAGS} -C target-feature=+fp16"
+        cargo build ${CORE_ARCH} --release --tests --target="${TARGET}"
+        ;;
     *)
         ;;
 
@@ -51,10 +51,13 @@ pub fn assert_instr(
         return (quote! { #item }).into();
     }
 
+    // The expected instruction can also be a PTX special register, such as
+    // `%laneid`, whose `%` isn't valid in an identifier.
     let instr_str = instr
         .replace('.', "_")
         .replace('/', "_")
         .replace(':', "_")
+        .replace('%', "")
         .replace(char::is_whitespace, "");
     let assert_name = syn::Ident::new(&format!("assert_{name}_{instr_str}"), name.span());
     // These name has to be unique enough for us to find it in the disassembly later on:
@@ -167,9 +170,33 @@ pub fn assert_instr(
         }
     };
 
+    // There's no `std`, and so no test harness, for nvptx. Instead the shim
+    // is emitted as an item of its own, along with the expected instruction,
+    // and the resulting PTX is checked on the host by `stdarch-test`. The
+    // dedup guard isn't available there as it lives in `stdarch-test` itself.
+    let expected_name = syn::Ident::new(&format!("{shim_name}_expected"), name.span());
+    let expected = syn::LitByteStr::new(instr.as_bytes(), name.span());
+    let expected_len = instr.len();
+    let ptx_shim = quote! {
+        #[cfg(target_arch = "nvptx64")]
+        const _: () = {
+            #attrs
+            #[no_mangle]
+            #[inline(never)]
+            pub unsafe extern "C" fn #shim_name(#(#inputs),*) #ret {
+                #name::<#(#const_vals),*>(#(#input_vals),*)
+            }
+
+            #[no_mangle]
+            #[allow(non_upper_case_globals)]
+            pub static #expected_name: [u8; #expected_len] = *#expected;
+        };
+    };
+
     let tokens: TokenStream = quote! {
         #[test]
         #[allow(non_snake_case)]
+        #[cfg(not(target_arch = "nvptx64"))]
         fn #assert_name() {
             #to_test
 
@@ -177,6 +204,8 @@ pub fn assert_instr(
                                    stringify!(#shim_name),
                                    #instr);
         }
+
+        #ptx_shim
     };
 
     let tokens: TokenStream = quote! {
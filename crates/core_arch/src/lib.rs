@@ -25,16 +25,20 @@
     avx512_target_feature,
     mips_target_feature,
     powerpc_target_feature,
+    s390x_target_feature,
+    loongarch_target_feature,
     wasm_target_feature,
     abi_unadjusted,
     rtm_target_feature,
     allow_internal_unstable,
     decl_macro,
     asm_const,
+    asm_experimental_arch,
     target_feature_11,
     inline_const,
     generic_arg_infer
 )]
+#![cfg_attr(all(target_family = "wasm", target_feature = "fp16"), feature(f16))]
 #![cfg_attr(test, feature(test, abi_vectorcall))]
 #![deny(clippy::missing_inline_in_public_items)]
 #![allow(
@@ -58,10 +62,12 @@
     test(attr(allow(dead_code, deprecated, unused_variables, unused_mut)))
 )]
 
-#[cfg(test)]
+// nvptx has no `std`: its `#[assert_instr]` shims are built without a test
+// harness and checked on the host, see `stdarch_test::ptx`.
+#[cfg(all(test, not(target_arch = "nvptx64")))]
 #[macro_use]
 extern crate std;
-#[cfg(test)]
+#[cfg(all(test, not(target_arch = "nvptx64")))]
 #[macro_use]
 extern crate std_detect;
 #[path = "mod.rs"]
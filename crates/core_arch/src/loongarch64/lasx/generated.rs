@@ -0,0 +1,7766 @@
+// This code is automatically generated. DO NOT MODIFY.
+//
+// Instead, modify `crates/stdarch-gen-loongarch/lasx.spec` and run the following command to re-generate this file:
+//
+// ```
+// OUT_DIR=`pwd`/crates/core_arch cargo run -p stdarch-gen-loongarch -- crates/stdarch-gen-loongarch/lasx.spec
+// ```
+
+use super::types::*;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "unadjusted" {
+    #[link_name = "llvm.loongarch.lasx.xvsll.b"]
+    fn __lasx_xvsll_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsll.h"]
+    fn __lasx_xvsll_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsll.w"]
+    fn __lasx_xvsll_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsll.d"]
+    fn __lasx_xvsll_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvslli.b"]
+    fn __lasx_xvslli_b(a: v32i8, b: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvslli.h"]
+    fn __lasx_xvslli_h(a: v16i16, b: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvslli.w"]
+    fn __lasx_xvslli_w(a: v8i32, b: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvslli.d"]
+    fn __lasx_xvslli_d(a: v4i64, b: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsra.b"]
+    fn __lasx_xvsra_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsra.h"]
+    fn __lasx_xvsra_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsra.w"]
+    fn __lasx_xvsra_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsra.d"]
+    fn __lasx_xvsra_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsrai.b"]
+    fn __lasx_xvsrai_b(a: v32i8, b: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsrai.h"]
+    fn __lasx_xvsrai_h(a: v16i16, b: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsrai.w"]
+    fn __lasx_xvsrai_w(a: v8i32, b: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsrai.d"]
+    fn __lasx_xvsrai_d(a: v4i64, b: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsrar.b"]
+    fn __lasx_xvsrar_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsrar.h"]
+    fn __lasx_xvsrar_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsrar.w"]
+    fn __lasx_xvsrar_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsrar.d"]
+    fn __lasx_xvsrar_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsrari.b"]
+    fn __lasx_xvsrari_b(a: v32i8, b: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsrari.h"]
+    fn __lasx_xvsrari_h(a: v16i16, b: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsrari.w"]
+    fn __lasx_xvsrari_w(a: v8i32, b: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsrari.d"]
+    fn __lasx_xvsrari_d(a: v4i64, b: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsrl.b"]
+    fn __lasx_xvsrl_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsrl.h"]
+    fn __lasx_xvsrl_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsrl.w"]
+    fn __lasx_xvsrl_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsrl.d"]
+    fn __lasx_xvsrl_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsrli.b"]
+    fn __lasx_xvsrli_b(a: v32i8, b: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsrli.h"]
+    fn __lasx_xvsrli_h(a: v16i16, b: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsrli.w"]
+    fn __lasx_xvsrli_w(a: v8i32, b: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsrli.d"]
+    fn __lasx_xvsrli_d(a: v4i64, b: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsrlr.b"]
+    fn __lasx_xvsrlr_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsrlr.h"]
+    fn __lasx_xvsrlr_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsrlr.w"]
+    fn __lasx_xvsrlr_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsrlr.d"]
+    fn __lasx_xvsrlr_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsrlri.b"]
+    fn __lasx_xvsrlri_b(a: v32i8, b: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsrlri.h"]
+    fn __lasx_xvsrlri_h(a: v16i16, b: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsrlri.w"]
+    fn __lasx_xvsrlri_w(a: v8i32, b: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsrlri.d"]
+    fn __lasx_xvsrlri_d(a: v4i64, b: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvbitclr.b"]
+    fn __lasx_xvbitclr_b(a: v32u8, b: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvbitclr.h"]
+    fn __lasx_xvbitclr_h(a: v16u16, b: v16u16) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvbitclr.w"]
+    fn __lasx_xvbitclr_w(a: v8u32, b: v8u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvbitclr.d"]
+    fn __lasx_xvbitclr_d(a: v4u64, b: v4u64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvbitclri.b"]
+    fn __lasx_xvbitclri_b(a: v32u8, b: u32) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvbitclri.h"]
+    fn __lasx_xvbitclri_h(a: v16u16, b: u32) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvbitclri.w"]
+    fn __lasx_xvbitclri_w(a: v8u32, b: u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvbitclri.d"]
+    fn __lasx_xvbitclri_d(a: v4u64, b: u32) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvbitset.b"]
+    fn __lasx_xvbitset_b(a: v32u8, b: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvbitset.h"]
+    fn __lasx_xvbitset_h(a: v16u16, b: v16u16) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvbitset.w"]
+    fn __lasx_xvbitset_w(a: v8u32, b: v8u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvbitset.d"]
+    fn __lasx_xvbitset_d(a: v4u64, b: v4u64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvbitseti.b"]
+    fn __lasx_xvbitseti_b(a: v32u8, b: u32) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvbitseti.h"]
+    fn __lasx_xvbitseti_h(a: v16u16, b: u32) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvbitseti.w"]
+    fn __lasx_xvbitseti_w(a: v8u32, b: u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvbitseti.d"]
+    fn __lasx_xvbitseti_d(a: v4u64, b: u32) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvbitrev.b"]
+    fn __lasx_xvbitrev_b(a: v32u8, b: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvbitrev.h"]
+    fn __lasx_xvbitrev_h(a: v16u16, b: v16u16) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvbitrev.w"]
+    fn __lasx_xvbitrev_w(a: v8u32, b: v8u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvbitrev.d"]
+    fn __lasx_xvbitrev_d(a: v4u64, b: v4u64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvbitrevi.b"]
+    fn __lasx_xvbitrevi_b(a: v32u8, b: u32) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvbitrevi.h"]
+    fn __lasx_xvbitrevi_h(a: v16u16, b: u32) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvbitrevi.w"]
+    fn __lasx_xvbitrevi_w(a: v8u32, b: u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvbitrevi.d"]
+    fn __lasx_xvbitrevi_d(a: v4u64, b: u32) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvadd.b"]
+    fn __lasx_xvadd_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvadd.h"]
+    fn __lasx_xvadd_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvadd.w"]
+    fn __lasx_xvadd_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvadd.d"]
+    fn __lasx_xvadd_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvaddi.bu"]
+    fn __lasx_xvaddi_bu(a: v32i8, b: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvaddi.hu"]
+    fn __lasx_xvaddi_hu(a: v16i16, b: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvaddi.wu"]
+    fn __lasx_xvaddi_wu(a: v8i32, b: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvaddi.du"]
+    fn __lasx_xvaddi_du(a: v4i64, b: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsub.b"]
+    fn __lasx_xvsub_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsub.h"]
+    fn __lasx_xvsub_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsub.w"]
+    fn __lasx_xvsub_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsub.d"]
+    fn __lasx_xvsub_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsubi.bu"]
+    fn __lasx_xvsubi_bu(a: v32i8, b: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsubi.hu"]
+    fn __lasx_xvsubi_hu(a: v16i16, b: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsubi.wu"]
+    fn __lasx_xvsubi_wu(a: v8i32, b: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsubi.du"]
+    fn __lasx_xvsubi_du(a: v4i64, b: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmax.b"]
+    fn __lasx_xvmax_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvmax.h"]
+    fn __lasx_xvmax_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvmax.w"]
+    fn __lasx_xvmax_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmax.d"]
+    fn __lasx_xvmax_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmaxi.b"]
+    fn __lasx_xvmaxi_b(a: v32i8, b: i32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvmaxi.h"]
+    fn __lasx_xvmaxi_h(a: v16i16, b: i32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvmaxi.w"]
+    fn __lasx_xvmaxi_w(a: v8i32, b: i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmaxi.d"]
+    fn __lasx_xvmaxi_d(a: v4i64, b: i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmax.bu"]
+    fn __lasx_xvmax_bu(a: v32u8, b: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvmax.hu"]
+    fn __lasx_xvmax_hu(a: v16u16, b: v16u16) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvmax.wu"]
+    fn __lasx_xvmax_wu(a: v8u32, b: v8u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvmax.du"]
+    fn __lasx_xvmax_du(a: v4u64, b: v4u64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvmaxi.bu"]
+    fn __lasx_xvmaxi_bu(a: v32u8, b: u32) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvmaxi.hu"]
+    fn __lasx_xvmaxi_hu(a: v16u16, b: u32) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvmaxi.wu"]
+    fn __lasx_xvmaxi_wu(a: v8u32, b: u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvmaxi.du"]
+    fn __lasx_xvmaxi_du(a: v4u64, b: u32) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvmin.b"]
+    fn __lasx_xvmin_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvmin.h"]
+    fn __lasx_xvmin_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvmin.w"]
+    fn __lasx_xvmin_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmin.d"]
+    fn __lasx_xvmin_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmini.b"]
+    fn __lasx_xvmini_b(a: v32i8, b: i32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvmini.h"]
+    fn __lasx_xvmini_h(a: v16i16, b: i32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvmini.w"]
+    fn __lasx_xvmini_w(a: v8i32, b: i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmini.d"]
+    fn __lasx_xvmini_d(a: v4i64, b: i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmin.bu"]
+    fn __lasx_xvmin_bu(a: v32u8, b: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvmin.hu"]
+    fn __lasx_xvmin_hu(a: v16u16, b: v16u16) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvmin.wu"]
+    fn __lasx_xvmin_wu(a: v8u32, b: v8u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvmin.du"]
+    fn __lasx_xvmin_du(a: v4u64, b: v4u64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvmini.bu"]
+    fn __lasx_xvmini_bu(a: v32u8, b: u32) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvmini.hu"]
+    fn __lasx_xvmini_hu(a: v16u16, b: u32) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvmini.wu"]
+    fn __lasx_xvmini_wu(a: v8u32, b: u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvmini.du"]
+    fn __lasx_xvmini_du(a: v4u64, b: u32) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvseq.b"]
+    fn __lasx_xvseq_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvseq.h"]
+    fn __lasx_xvseq_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvseq.w"]
+    fn __lasx_xvseq_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvseq.d"]
+    fn __lasx_xvseq_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvseqi.b"]
+    fn __lasx_xvseqi_b(a: v32i8, b: i32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvseqi.h"]
+    fn __lasx_xvseqi_h(a: v16i16, b: i32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvseqi.w"]
+    fn __lasx_xvseqi_w(a: v8i32, b: i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvseqi.d"]
+    fn __lasx_xvseqi_d(a: v4i64, b: i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvslt.b"]
+    fn __lasx_xvslt_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvslt.h"]
+    fn __lasx_xvslt_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvslt.w"]
+    fn __lasx_xvslt_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvslt.d"]
+    fn __lasx_xvslt_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvslti.b"]
+    fn __lasx_xvslti_b(a: v32i8, b: i32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvslti.h"]
+    fn __lasx_xvslti_h(a: v16i16, b: i32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvslti.w"]
+    fn __lasx_xvslti_w(a: v8i32, b: i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvslti.d"]
+    fn __lasx_xvslti_d(a: v4i64, b: i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvslt.bu"]
+    fn __lasx_xvslt_bu(a: v32u8, b: v32u8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvslt.hu"]
+    fn __lasx_xvslt_hu(a: v16u16, b: v16u16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvslt.wu"]
+    fn __lasx_xvslt_wu(a: v8u32, b: v8u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvslt.du"]
+    fn __lasx_xvslt_du(a: v4u64, b: v4u64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvslti.bu"]
+    fn __lasx_xvslti_bu(a: v32u8, b: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvslti.hu"]
+    fn __lasx_xvslti_hu(a: v16u16, b: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvslti.wu"]
+    fn __lasx_xvslti_wu(a: v8u32, b: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvslti.du"]
+    fn __lasx_xvslti_du(a: v4u64, b: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsle.b"]
+    fn __lasx_xvsle_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsle.h"]
+    fn __lasx_xvsle_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsle.w"]
+    fn __lasx_xvsle_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsle.d"]
+    fn __lasx_xvsle_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvslei.b"]
+    fn __lasx_xvslei_b(a: v32i8, b: i32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvslei.h"]
+    fn __lasx_xvslei_h(a: v16i16, b: i32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvslei.w"]
+    fn __lasx_xvslei_w(a: v8i32, b: i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvslei.d"]
+    fn __lasx_xvslei_d(a: v4i64, b: i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsle.bu"]
+    fn __lasx_xvsle_bu(a: v32u8, b: v32u8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsle.hu"]
+    fn __lasx_xvsle_hu(a: v16u16, b: v16u16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsle.wu"]
+    fn __lasx_xvsle_wu(a: v8u32, b: v8u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsle.du"]
+    fn __lasx_xvsle_du(a: v4u64, b: v4u64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvslei.bu"]
+    fn __lasx_xvslei_bu(a: v32u8, b: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvslei.hu"]
+    fn __lasx_xvslei_hu(a: v16u16, b: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvslei.wu"]
+    fn __lasx_xvslei_wu(a: v8u32, b: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvslei.du"]
+    fn __lasx_xvslei_du(a: v4u64, b: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsat.b"]
+    fn __lasx_xvsat_b(a: v32i8, b: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsat.h"]
+    fn __lasx_xvsat_h(a: v16i16, b: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsat.w"]
+    fn __lasx_xvsat_w(a: v8i32, b: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsat.d"]
+    fn __lasx_xvsat_d(a: v4i64, b: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsat.bu"]
+    fn __lasx_xvsat_bu(a: v32u8, b: u32) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvsat.hu"]
+    fn __lasx_xvsat_hu(a: v16u16, b: u32) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvsat.wu"]
+    fn __lasx_xvsat_wu(a: v8u32, b: u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvsat.du"]
+    fn __lasx_xvsat_du(a: v4u64, b: u32) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvadda.b"]
+    fn __lasx_xvadda_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvadda.h"]
+    fn __lasx_xvadda_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvadda.w"]
+    fn __lasx_xvadda_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvadda.d"]
+    fn __lasx_xvadda_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsadd.b"]
+    fn __lasx_xvsadd_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsadd.h"]
+    fn __lasx_xvsadd_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsadd.w"]
+    fn __lasx_xvsadd_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsadd.d"]
+    fn __lasx_xvsadd_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsadd.bu"]
+    fn __lasx_xvsadd_bu(a: v32u8, b: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvsadd.hu"]
+    fn __lasx_xvsadd_hu(a: v16u16, b: v16u16) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvsadd.wu"]
+    fn __lasx_xvsadd_wu(a: v8u32, b: v8u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvsadd.du"]
+    fn __lasx_xvsadd_du(a: v4u64, b: v4u64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvavg.b"]
+    fn __lasx_xvavg_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvavg.h"]
+    fn __lasx_xvavg_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvavg.w"]
+    fn __lasx_xvavg_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvavg.d"]
+    fn __lasx_xvavg_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvavg.bu"]
+    fn __lasx_xvavg_bu(a: v32u8, b: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvavg.hu"]
+    fn __lasx_xvavg_hu(a: v16u16, b: v16u16) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvavg.wu"]
+    fn __lasx_xvavg_wu(a: v8u32, b: v8u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvavg.du"]
+    fn __lasx_xvavg_du(a: v4u64, b: v4u64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvavgr.b"]
+    fn __lasx_xvavgr_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvavgr.h"]
+    fn __lasx_xvavgr_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvavgr.w"]
+    fn __lasx_xvavgr_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvavgr.d"]
+    fn __lasx_xvavgr_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvavgr.bu"]
+    fn __lasx_xvavgr_bu(a: v32u8, b: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvavgr.hu"]
+    fn __lasx_xvavgr_hu(a: v16u16, b: v16u16) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvavgr.wu"]
+    fn __lasx_xvavgr_wu(a: v8u32, b: v8u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvavgr.du"]
+    fn __lasx_xvavgr_du(a: v4u64, b: v4u64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvssub.b"]
+    fn __lasx_xvssub_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvssub.h"]
+    fn __lasx_xvssub_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvssub.w"]
+    fn __lasx_xvssub_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvssub.d"]
+    fn __lasx_xvssub_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvssub.bu"]
+    fn __lasx_xvssub_bu(a: v32u8, b: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvssub.hu"]
+    fn __lasx_xvssub_hu(a: v16u16, b: v16u16) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvssub.wu"]
+    fn __lasx_xvssub_wu(a: v8u32, b: v8u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvssub.du"]
+    fn __lasx_xvssub_du(a: v4u64, b: v4u64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvabsd.b"]
+    fn __lasx_xvabsd_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvabsd.h"]
+    fn __lasx_xvabsd_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvabsd.w"]
+    fn __lasx_xvabsd_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvabsd.d"]
+    fn __lasx_xvabsd_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvabsd.bu"]
+    fn __lasx_xvabsd_bu(a: v32u8, b: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvabsd.hu"]
+    fn __lasx_xvabsd_hu(a: v16u16, b: v16u16) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvabsd.wu"]
+    fn __lasx_xvabsd_wu(a: v8u32, b: v8u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvabsd.du"]
+    fn __lasx_xvabsd_du(a: v4u64, b: v4u64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvmul.b"]
+    fn __lasx_xvmul_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvmul.h"]
+    fn __lasx_xvmul_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvmul.w"]
+    fn __lasx_xvmul_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmul.d"]
+    fn __lasx_xvmul_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmadd.b"]
+    fn __lasx_xvmadd_b(a: v32i8, b: v32i8, c: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvmadd.h"]
+    fn __lasx_xvmadd_h(a: v16i16, b: v16i16, c: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvmadd.w"]
+    fn __lasx_xvmadd_w(a: v8i32, b: v8i32, c: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmadd.d"]
+    fn __lasx_xvmadd_d(a: v4i64, b: v4i64, c: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmsub.b"]
+    fn __lasx_xvmsub_b(a: v32i8, b: v32i8, c: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvmsub.h"]
+    fn __lasx_xvmsub_h(a: v16i16, b: v16i16, c: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvmsub.w"]
+    fn __lasx_xvmsub_w(a: v8i32, b: v8i32, c: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmsub.d"]
+    fn __lasx_xvmsub_d(a: v4i64, b: v4i64, c: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvdiv.b"]
+    fn __lasx_xvdiv_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvdiv.h"]
+    fn __lasx_xvdiv_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvdiv.w"]
+    fn __lasx_xvdiv_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvdiv.d"]
+    fn __lasx_xvdiv_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvdiv.bu"]
+    fn __lasx_xvdiv_bu(a: v32u8, b: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvdiv.hu"]
+    fn __lasx_xvdiv_hu(a: v16u16, b: v16u16) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvdiv.wu"]
+    fn __lasx_xvdiv_wu(a: v8u32, b: v8u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvdiv.du"]
+    fn __lasx_xvdiv_du(a: v4u64, b: v4u64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvhaddw.h.b"]
+    fn __lasx_xvhaddw_h_b(a: v32i8, b: v32i8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvhaddw.w.h"]
+    fn __lasx_xvhaddw_w_h(a: v16i16, b: v16i16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvhaddw.d.w"]
+    fn __lasx_xvhaddw_d_w(a: v8i32, b: v8i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvhaddw.hu.bu"]
+    fn __lasx_xvhaddw_hu_bu(a: v32u8, b: v32u8) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvhaddw.wu.hu"]
+    fn __lasx_xvhaddw_wu_hu(a: v16u16, b: v16u16) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvhaddw.du.wu"]
+    fn __lasx_xvhaddw_du_wu(a: v8u32, b: v8u32) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvhsubw.h.b"]
+    fn __lasx_xvhsubw_h_b(a: v32i8, b: v32i8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvhsubw.w.h"]
+    fn __lasx_xvhsubw_w_h(a: v16i16, b: v16i16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvhsubw.d.w"]
+    fn __lasx_xvhsubw_d_w(a: v8i32, b: v8i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvhsubw.hu.bu"]
+    fn __lasx_xvhsubw_hu_bu(a: v32u8, b: v32u8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvhsubw.wu.hu"]
+    fn __lasx_xvhsubw_wu_hu(a: v16u16, b: v16u16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvhsubw.du.wu"]
+    fn __lasx_xvhsubw_du_wu(a: v8u32, b: v8u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmod.b"]
+    fn __lasx_xvmod_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvmod.h"]
+    fn __lasx_xvmod_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvmod.w"]
+    fn __lasx_xvmod_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmod.d"]
+    fn __lasx_xvmod_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmod.bu"]
+    fn __lasx_xvmod_bu(a: v32u8, b: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvmod.hu"]
+    fn __lasx_xvmod_hu(a: v16u16, b: v16u16) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvmod.wu"]
+    fn __lasx_xvmod_wu(a: v8u32, b: v8u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvmod.du"]
+    fn __lasx_xvmod_du(a: v4u64, b: v4u64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvrepl128vei.b"]
+    fn __lasx_xvrepl128vei_b(a: v32i8, b: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvrepl128vei.h"]
+    fn __lasx_xvrepl128vei_h(a: v16i16, b: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvrepl128vei.w"]
+    fn __lasx_xvrepl128vei_w(a: v8i32, b: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvrepl128vei.d"]
+    fn __lasx_xvrepl128vei_d(a: v4i64, b: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvpickev.b"]
+    fn __lasx_xvpickev_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvpickev.h"]
+    fn __lasx_xvpickev_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvpickev.w"]
+    fn __lasx_xvpickev_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvpickev.d"]
+    fn __lasx_xvpickev_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvpickod.b"]
+    fn __lasx_xvpickod_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvpickod.h"]
+    fn __lasx_xvpickod_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvpickod.w"]
+    fn __lasx_xvpickod_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvpickod.d"]
+    fn __lasx_xvpickod_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvilvh.b"]
+    fn __lasx_xvilvh_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvilvh.h"]
+    fn __lasx_xvilvh_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvilvh.w"]
+    fn __lasx_xvilvh_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvilvh.d"]
+    fn __lasx_xvilvh_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvilvl.b"]
+    fn __lasx_xvilvl_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvilvl.h"]
+    fn __lasx_xvilvl_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvilvl.w"]
+    fn __lasx_xvilvl_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvilvl.d"]
+    fn __lasx_xvilvl_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvpackev.b"]
+    fn __lasx_xvpackev_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvpackev.h"]
+    fn __lasx_xvpackev_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvpackev.w"]
+    fn __lasx_xvpackev_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvpackev.d"]
+    fn __lasx_xvpackev_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvpackod.b"]
+    fn __lasx_xvpackod_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvpackod.h"]
+    fn __lasx_xvpackod_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvpackod.w"]
+    fn __lasx_xvpackod_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvpackod.d"]
+    fn __lasx_xvpackod_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvshuf.b"]
+    fn __lasx_xvshuf_b(a: v32i8, b: v32i8, c: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvshuf.h"]
+    fn __lasx_xvshuf_h(a: v16i16, b: v16i16, c: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvshuf.w"]
+    fn __lasx_xvshuf_w(a: v8i32, b: v8i32, c: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvshuf.d"]
+    fn __lasx_xvshuf_d(a: v4i64, b: v4i64, c: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvand.v"]
+    fn __lasx_xvand_v(a: v32u8, b: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvandi.b"]
+    fn __lasx_xvandi_b(a: v32u8, b: u32) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvor.v"]
+    fn __lasx_xvor_v(a: v32u8, b: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvori.b"]
+    fn __lasx_xvori_b(a: v32u8, b: u32) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvnor.v"]
+    fn __lasx_xvnor_v(a: v32u8, b: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvnori.b"]
+    fn __lasx_xvnori_b(a: v32u8, b: u32) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvxor.v"]
+    fn __lasx_xvxor_v(a: v32u8, b: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvxori.b"]
+    fn __lasx_xvxori_b(a: v32u8, b: u32) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvbitsel.v"]
+    fn __lasx_xvbitsel_v(a: v32u8, b: v32u8, c: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvbitseli.b"]
+    fn __lasx_xvbitseli_b(a: v32u8, b: v32u8, c: u32) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvshuf4i.b"]
+    fn __lasx_xvshuf4i_b(a: v32i8, b: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvshuf4i.h"]
+    fn __lasx_xvshuf4i_h(a: v16i16, b: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvshuf4i.w"]
+    fn __lasx_xvshuf4i_w(a: v8i32, b: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvreplgr2vr.b"]
+    fn __lasx_xvreplgr2vr_b(a: i32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvreplgr2vr.h"]
+    fn __lasx_xvreplgr2vr_h(a: i32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvreplgr2vr.w"]
+    fn __lasx_xvreplgr2vr_w(a: i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvreplgr2vr.d"]
+    fn __lasx_xvreplgr2vr_d(a: i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvpcnt.b"]
+    fn __lasx_xvpcnt_b(a: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvpcnt.h"]
+    fn __lasx_xvpcnt_h(a: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvpcnt.w"]
+    fn __lasx_xvpcnt_w(a: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvpcnt.d"]
+    fn __lasx_xvpcnt_d(a: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvclo.b"]
+    fn __lasx_xvclo_b(a: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvclo.h"]
+    fn __lasx_xvclo_h(a: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvclo.w"]
+    fn __lasx_xvclo_w(a: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvclo.d"]
+    fn __lasx_xvclo_d(a: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvclz.b"]
+    fn __lasx_xvclz_b(a: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvclz.h"]
+    fn __lasx_xvclz_h(a: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvclz.w"]
+    fn __lasx_xvclz_w(a: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvclz.d"]
+    fn __lasx_xvclz_d(a: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfadd.s"]
+    fn __lasx_xvfadd_s(a: v8f32, b: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfadd.d"]
+    fn __lasx_xvfadd_d(a: v4f64, b: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvfsub.s"]
+    fn __lasx_xvfsub_s(a: v8f32, b: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfsub.d"]
+    fn __lasx_xvfsub_d(a: v4f64, b: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvfmul.s"]
+    fn __lasx_xvfmul_s(a: v8f32, b: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfmul.d"]
+    fn __lasx_xvfmul_d(a: v4f64, b: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvfdiv.s"]
+    fn __lasx_xvfdiv_s(a: v8f32, b: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfdiv.d"]
+    fn __lasx_xvfdiv_d(a: v4f64, b: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvfcvt.h.s"]
+    fn __lasx_xvfcvt_h_s(a: v8f32, b: v8f32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvfcvt.s.d"]
+    fn __lasx_xvfcvt_s_d(a: v4f64, b: v4f64) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfmin.s"]
+    fn __lasx_xvfmin_s(a: v8f32, b: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfmin.d"]
+    fn __lasx_xvfmin_d(a: v4f64, b: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvfmina.s"]
+    fn __lasx_xvfmina_s(a: v8f32, b: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfmina.d"]
+    fn __lasx_xvfmina_d(a: v4f64, b: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvfmax.s"]
+    fn __lasx_xvfmax_s(a: v8f32, b: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfmax.d"]
+    fn __lasx_xvfmax_d(a: v4f64, b: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvfmaxa.s"]
+    fn __lasx_xvfmaxa_s(a: v8f32, b: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfmaxa.d"]
+    fn __lasx_xvfmaxa_d(a: v4f64, b: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvfclass.s"]
+    fn __lasx_xvfclass_s(a: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfclass.d"]
+    fn __lasx_xvfclass_d(a: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfsqrt.s"]
+    fn __lasx_xvfsqrt_s(a: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfsqrt.d"]
+    fn __lasx_xvfsqrt_d(a: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvfrecip.s"]
+    fn __lasx_xvfrecip_s(a: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfrecip.d"]
+    fn __lasx_xvfrecip_d(a: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvfrint.s"]
+    fn __lasx_xvfrint_s(a: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfrint.d"]
+    fn __lasx_xvfrint_d(a: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvfrsqrt.s"]
+    fn __lasx_xvfrsqrt_s(a: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfrsqrt.d"]
+    fn __lasx_xvfrsqrt_d(a: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvflogb.s"]
+    fn __lasx_xvflogb_s(a: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvflogb.d"]
+    fn __lasx_xvflogb_d(a: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvfcvth.s.h"]
+    fn __lasx_xvfcvth_s_h(a: v16i16) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfcvth.d.s"]
+    fn __lasx_xvfcvth_d_s(a: v8f32) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvfcvtl.s.h"]
+    fn __lasx_xvfcvtl_s_h(a: v16i16) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfcvtl.d.s"]
+    fn __lasx_xvfcvtl_d_s(a: v8f32) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvftint.w.s"]
+    fn __lasx_xvftint_w_s(a: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvftint.l.d"]
+    fn __lasx_xvftint_l_d(a: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvftint.wu.s"]
+    fn __lasx_xvftint_wu_s(a: v8f32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvftint.lu.d"]
+    fn __lasx_xvftint_lu_d(a: v4f64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvftintrz.w.s"]
+    fn __lasx_xvftintrz_w_s(a: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvftintrz.l.d"]
+    fn __lasx_xvftintrz_l_d(a: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvftintrz.wu.s"]
+    fn __lasx_xvftintrz_wu_s(a: v8f32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvftintrz.lu.d"]
+    fn __lasx_xvftintrz_lu_d(a: v4f64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvffint.s.w"]
+    fn __lasx_xvffint_s_w(a: v8i32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvffint.d.l"]
+    fn __lasx_xvffint_d_l(a: v4i64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvffint.s.wu"]
+    fn __lasx_xvffint_s_wu(a: v8u32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvffint.d.lu"]
+    fn __lasx_xvffint_d_lu(a: v4u64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvreplve.b"]
+    fn __lasx_xvreplve_b(a: v32i8, b: i32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvreplve.h"]
+    fn __lasx_xvreplve_h(a: v16i16, b: i32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvreplve.w"]
+    fn __lasx_xvreplve_w(a: v8i32, b: i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvreplve.d"]
+    fn __lasx_xvreplve_d(a: v4i64, b: i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvpermi.w"]
+    fn __lasx_xvpermi_w(a: v8i32, b: v8i32, c: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvandn.v"]
+    fn __lasx_xvandn_v(a: v32u8, b: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvneg.b"]
+    fn __lasx_xvneg_b(a: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvneg.h"]
+    fn __lasx_xvneg_h(a: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvneg.w"]
+    fn __lasx_xvneg_w(a: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvneg.d"]
+    fn __lasx_xvneg_d(a: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmuh.b"]
+    fn __lasx_xvmuh_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvmuh.h"]
+    fn __lasx_xvmuh_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvmuh.w"]
+    fn __lasx_xvmuh_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmuh.d"]
+    fn __lasx_xvmuh_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmuh.bu"]
+    fn __lasx_xvmuh_bu(a: v32u8, b: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvmuh.hu"]
+    fn __lasx_xvmuh_hu(a: v16u16, b: v16u16) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvmuh.wu"]
+    fn __lasx_xvmuh_wu(a: v8u32, b: v8u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvmuh.du"]
+    fn __lasx_xvmuh_du(a: v4u64, b: v4u64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvsllwil.h.b"]
+    fn __lasx_xvsllwil_h_b(a: v32i8, b: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsllwil.w.h"]
+    fn __lasx_xvsllwil_w_h(a: v16i16, b: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsllwil.d.w"]
+    fn __lasx_xvsllwil_d_w(a: v8i32, b: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsllwil.hu.bu"]
+    fn __lasx_xvsllwil_hu_bu(a: v32u8, b: u32) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvsllwil.wu.hu"]
+    fn __lasx_xvsllwil_wu_hu(a: v16u16, b: u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvsllwil.du.wu"]
+    fn __lasx_xvsllwil_du_wu(a: v8u32, b: u32) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvsran.b.h"]
+    fn __lasx_xvsran_b_h(a: v16i16, b: v16i16) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsran.h.w"]
+    fn __lasx_xvsran_h_w(a: v8i32, b: v8i32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsran.w.d"]
+    fn __lasx_xvsran_w_d(a: v4i64, b: v4i64) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvssran.b.h"]
+    fn __lasx_xvssran_b_h(a: v16i16, b: v16i16) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvssran.h.w"]
+    fn __lasx_xvssran_h_w(a: v8i32, b: v8i32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvssran.w.d"]
+    fn __lasx_xvssran_w_d(a: v4i64, b: v4i64) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvssran.bu.h"]
+    fn __lasx_xvssran_bu_h(a: v16u16, b: v16u16) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvssran.hu.w"]
+    fn __lasx_xvssran_hu_w(a: v8u32, b: v8u32) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvssran.wu.d"]
+    fn __lasx_xvssran_wu_d(a: v4u64, b: v4u64) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvsrarn.b.h"]
+    fn __lasx_xvsrarn_b_h(a: v16i16, b: v16i16) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsrarn.h.w"]
+    fn __lasx_xvsrarn_h_w(a: v8i32, b: v8i32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsrarn.w.d"]
+    fn __lasx_xvsrarn_w_d(a: v4i64, b: v4i64) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvssrarn.b.h"]
+    fn __lasx_xvssrarn_b_h(a: v16i16, b: v16i16) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvssrarn.h.w"]
+    fn __lasx_xvssrarn_h_w(a: v8i32, b: v8i32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvssrarn.w.d"]
+    fn __lasx_xvssrarn_w_d(a: v4i64, b: v4i64) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvssrarn.bu.h"]
+    fn __lasx_xvssrarn_bu_h(a: v16u16, b: v16u16) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvssrarn.hu.w"]
+    fn __lasx_xvssrarn_hu_w(a: v8u32, b: v8u32) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvssrarn.wu.d"]
+    fn __lasx_xvssrarn_wu_d(a: v4u64, b: v4u64) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvsrln.b.h"]
+    fn __lasx_xvsrln_b_h(a: v16i16, b: v16i16) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsrln.h.w"]
+    fn __lasx_xvsrln_h_w(a: v8i32, b: v8i32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsrln.w.d"]
+    fn __lasx_xvsrln_w_d(a: v4i64, b: v4i64) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvssrln.bu.h"]
+    fn __lasx_xvssrln_bu_h(a: v16u16, b: v16u16) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvssrln.hu.w"]
+    fn __lasx_xvssrln_hu_w(a: v8u32, b: v8u32) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvssrln.wu.d"]
+    fn __lasx_xvssrln_wu_d(a: v4u64, b: v4u64) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvsrlrn.b.h"]
+    fn __lasx_xvsrlrn_b_h(a: v16i16, b: v16i16) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsrlrn.h.w"]
+    fn __lasx_xvsrlrn_h_w(a: v8i32, b: v8i32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsrlrn.w.d"]
+    fn __lasx_xvsrlrn_w_d(a: v4i64, b: v4i64) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvssrlrn.bu.h"]
+    fn __lasx_xvssrlrn_bu_h(a: v16u16, b: v16u16) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvssrlrn.hu.w"]
+    fn __lasx_xvssrlrn_hu_w(a: v8u32, b: v8u32) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvssrlrn.wu.d"]
+    fn __lasx_xvssrlrn_wu_d(a: v4u64, b: v4u64) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvfrstpi.b"]
+    fn __lasx_xvfrstpi_b(a: v32i8, b: v32i8, c: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvfrstpi.h"]
+    fn __lasx_xvfrstpi_h(a: v16i16, b: v16i16, c: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvfrstp.b"]
+    fn __lasx_xvfrstp_b(a: v32i8, b: v32i8, c: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvfrstp.h"]
+    fn __lasx_xvfrstp_h(a: v16i16, b: v16i16, c: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvshuf4i.d"]
+    fn __lasx_xvshuf4i_d(a: v4i64, b: v4i64, c: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvbsrl.v"]
+    fn __lasx_xvbsrl_v(a: v32i8, b: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvbsll.v"]
+    fn __lasx_xvbsll_v(a: v32i8, b: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvextrins.b"]
+    fn __lasx_xvextrins_b(a: v32i8, b: v32i8, c: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvextrins.h"]
+    fn __lasx_xvextrins_h(a: v16i16, b: v16i16, c: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvextrins.w"]
+    fn __lasx_xvextrins_w(a: v8i32, b: v8i32, c: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvextrins.d"]
+    fn __lasx_xvextrins_d(a: v4i64, b: v4i64, c: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmskltz.b"]
+    fn __lasx_xvmskltz_b(a: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvmskltz.h"]
+    fn __lasx_xvmskltz_h(a: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvmskltz.w"]
+    fn __lasx_xvmskltz_w(a: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmskltz.d"]
+    fn __lasx_xvmskltz_d(a: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsigncov.b"]
+    fn __lasx_xvsigncov_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsigncov.h"]
+    fn __lasx_xvsigncov_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsigncov.w"]
+    fn __lasx_xvsigncov_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsigncov.d"]
+    fn __lasx_xvsigncov_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfmadd.s"]
+    fn __lasx_xvfmadd_s(a: v8f32, b: v8f32, c: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfmadd.d"]
+    fn __lasx_xvfmadd_d(a: v4f64, b: v4f64, c: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvfmsub.s"]
+    fn __lasx_xvfmsub_s(a: v8f32, b: v8f32, c: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfmsub.d"]
+    fn __lasx_xvfmsub_d(a: v4f64, b: v4f64, c: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvfnmadd.s"]
+    fn __lasx_xvfnmadd_s(a: v8f32, b: v8f32, c: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfnmadd.d"]
+    fn __lasx_xvfnmadd_d(a: v4f64, b: v4f64, c: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvfnmsub.s"]
+    fn __lasx_xvfnmsub_s(a: v8f32, b: v8f32, c: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfnmsub.d"]
+    fn __lasx_xvfnmsub_d(a: v4f64, b: v4f64, c: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvftintrne.w.s"]
+    fn __lasx_xvftintrne_w_s(a: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvftintrne.l.d"]
+    fn __lasx_xvftintrne_l_d(a: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvftintrp.w.s"]
+    fn __lasx_xvftintrp_w_s(a: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvftintrp.l.d"]
+    fn __lasx_xvftintrp_l_d(a: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvftintrm.w.s"]
+    fn __lasx_xvftintrm_w_s(a: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvftintrm.l.d"]
+    fn __lasx_xvftintrm_l_d(a: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvftint.w.d"]
+    fn __lasx_xvftint_w_d(a: v4f64, b: v4f64) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvffint.s.l"]
+    fn __lasx_xvffint_s_l(a: v4i64, b: v4i64) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvftintrz.w.d"]
+    fn __lasx_xvftintrz_w_d(a: v4f64, b: v4f64) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvftintrp.w.d"]
+    fn __lasx_xvftintrp_w_d(a: v4f64, b: v4f64) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvftintrm.w.d"]
+    fn __lasx_xvftintrm_w_d(a: v4f64, b: v4f64) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvftintrne.w.d"]
+    fn __lasx_xvftintrne_w_d(a: v4f64, b: v4f64) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvftinth.l.s"]
+    fn __lasx_xvftinth_l_s(a: v8f32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvftintl.l.s"]
+    fn __lasx_xvftintl_l_s(a: v8f32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvffinth.d.w"]
+    fn __lasx_xvffinth_d_w(a: v8i32) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvffintl.d.w"]
+    fn __lasx_xvffintl_d_w(a: v8i32) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvftintrzh.l.s"]
+    fn __lasx_xvftintrzh_l_s(a: v8f32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvftintrzl.l.s"]
+    fn __lasx_xvftintrzl_l_s(a: v8f32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvftintrph.l.s"]
+    fn __lasx_xvftintrph_l_s(a: v8f32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvftintrpl.l.s"]
+    fn __lasx_xvftintrpl_l_s(a: v8f32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvftintrmh.l.s"]
+    fn __lasx_xvftintrmh_l_s(a: v8f32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvftintrml.l.s"]
+    fn __lasx_xvftintrml_l_s(a: v8f32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvftintrneh.l.s"]
+    fn __lasx_xvftintrneh_l_s(a: v8f32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvftintrnel.l.s"]
+    fn __lasx_xvftintrnel_l_s(a: v8f32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfrintrne.s"]
+    fn __lasx_xvfrintrne_s(a: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfrintrne.d"]
+    fn __lasx_xvfrintrne_d(a: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvfrintrz.s"]
+    fn __lasx_xvfrintrz_s(a: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfrintrz.d"]
+    fn __lasx_xvfrintrz_d(a: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvfrintrp.s"]
+    fn __lasx_xvfrintrp_s(a: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfrintrp.d"]
+    fn __lasx_xvfrintrp_d(a: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvfrintrm.s"]
+    fn __lasx_xvfrintrm_s(a: v8f32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvfrintrm.d"]
+    fn __lasx_xvfrintrm_d(a: v4f64) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvld"]
+    fn __lasx_xvld(mem_addr: *const i8, a: i32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvst"]
+    fn __lasx_xvst(a: v32i8, mem_addr: *mut i8, b: i32);
+    #[link_name = "llvm.loongarch.lasx.xvstelm.b"]
+    fn __lasx_xvstelm_b(a: v32i8, mem_addr: *mut i8, b: i32, c: u32);
+    #[link_name = "llvm.loongarch.lasx.xvstelm.h"]
+    fn __lasx_xvstelm_h(a: v16i16, mem_addr: *mut i8, b: i32, c: u32);
+    #[link_name = "llvm.loongarch.lasx.xvstelm.w"]
+    fn __lasx_xvstelm_w(a: v8i32, mem_addr: *mut i8, b: i32, c: u32);
+    #[link_name = "llvm.loongarch.lasx.xvstelm.d"]
+    fn __lasx_xvstelm_d(a: v4i64, mem_addr: *mut i8, b: i32, c: u32);
+    #[link_name = "llvm.loongarch.lasx.xvinsve0.w"]
+    fn __lasx_xvinsve0_w(a: v8i32, b: v8i32, c: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvinsve0.d"]
+    fn __lasx_xvinsve0_d(a: v4i64, b: v4i64, c: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvpickve.w"]
+    fn __lasx_xvpickve_w(a: v8i32, b: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvpickve.d"]
+    fn __lasx_xvpickve_d(a: v4i64, b: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvssrlrn.b.h"]
+    fn __lasx_xvssrlrn_b_h(a: v16i16, b: v16i16) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvssrlrn.h.w"]
+    fn __lasx_xvssrlrn_h_w(a: v8i32, b: v8i32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvssrlrn.w.d"]
+    fn __lasx_xvssrlrn_w_d(a: v4i64, b: v4i64) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvssrln.b.h"]
+    fn __lasx_xvssrln_b_h(a: v16i16, b: v16i16) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvssrln.h.w"]
+    fn __lasx_xvssrln_h_w(a: v8i32, b: v8i32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvssrln.w.d"]
+    fn __lasx_xvssrln_w_d(a: v4i64, b: v4i64) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvorn.v"]
+    fn __lasx_xvorn_v(a: v32u8, b: v32u8) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvldi"]
+    fn __lasx_xvldi(a: i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvldx"]
+    fn __lasx_xvldx(mem_addr: *const i8, a: i64) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvstx"]
+    fn __lasx_xvstx(a: v32i8, mem_addr: *mut i8, b: i64);
+    #[link_name = "llvm.loongarch.lasx.xvextl.qu.du"]
+    fn __lasx_xvextl_qu_du(a: v4u64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvinsgr2vr.w"]
+    fn __lasx_xvinsgr2vr_w(a: v8i32, b: i32, c: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvinsgr2vr.d"]
+    fn __lasx_xvinsgr2vr_d(a: v4i64, b: i64, c: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvreplve0.b"]
+    fn __lasx_xvreplve0_b(a: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvreplve0.h"]
+    fn __lasx_xvreplve0_h(a: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvreplve0.w"]
+    fn __lasx_xvreplve0_w(a: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvreplve0.d"]
+    fn __lasx_xvreplve0_d(a: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvreplve0.q"]
+    fn __lasx_xvreplve0_q(a: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.vext2xv.h.b"]
+    fn __lasx_vext2xv_h_b(a: v32i8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.vext2xv.w.h"]
+    fn __lasx_vext2xv_w_h(a: v16i16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.vext2xv.d.w"]
+    fn __lasx_vext2xv_d_w(a: v8i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.vext2xv.w.b"]
+    fn __lasx_vext2xv_w_b(a: v32i8) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.vext2xv.d.h"]
+    fn __lasx_vext2xv_d_h(a: v16i16) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.vext2xv.d.b"]
+    fn __lasx_vext2xv_d_b(a: v32i8) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.vext2xv.hu.bu"]
+    fn __lasx_vext2xv_hu_bu(a: v32i8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.vext2xv.wu.hu"]
+    fn __lasx_vext2xv_wu_hu(a: v16i16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.vext2xv.du.wu"]
+    fn __lasx_vext2xv_du_wu(a: v8i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.vext2xv.wu.bu"]
+    fn __lasx_vext2xv_wu_bu(a: v32i8) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.vext2xv.du.hu"]
+    fn __lasx_vext2xv_du_hu(a: v16i16) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.vext2xv.du.bu"]
+    fn __lasx_vext2xv_du_bu(a: v32i8) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvpermi.q"]
+    fn __lasx_xvpermi_q(a: v32i8, b: v32i8, c: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvpermi.d"]
+    fn __lasx_xvpermi_d(a: v4i64, b: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvperm.w"]
+    fn __lasx_xvperm_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvldrepl.b"]
+    fn __lasx_xvldrepl_b(mem_addr: *const i8, a: i32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvldrepl.h"]
+    fn __lasx_xvldrepl_h(mem_addr: *const i8, a: i32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvldrepl.w"]
+    fn __lasx_xvldrepl_w(mem_addr: *const i8, a: i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvldrepl.d"]
+    fn __lasx_xvldrepl_d(mem_addr: *const i8, a: i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvpickve2gr.w"]
+    fn __lasx_xvpickve2gr_w(a: v8i32, b: u32) -> i32;
+    #[link_name = "llvm.loongarch.lasx.xvpickve2gr.wu"]
+    fn __lasx_xvpickve2gr_wu(a: v8i32, b: u32) -> u32;
+    #[link_name = "llvm.loongarch.lasx.xvpickve2gr.d"]
+    fn __lasx_xvpickve2gr_d(a: v4i64, b: u32) -> i64;
+    #[link_name = "llvm.loongarch.lasx.xvpickve2gr.du"]
+    fn __lasx_xvpickve2gr_du(a: v4i64, b: u32) -> u64;
+    #[link_name = "llvm.loongarch.lasx.xvaddwev.q.d"]
+    fn __lasx_xvaddwev_q_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvaddwev.d.w"]
+    fn __lasx_xvaddwev_d_w(a: v8i32, b: v8i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvaddwev.w.h"]
+    fn __lasx_xvaddwev_w_h(a: v16i16, b: v16i16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvaddwev.h.b"]
+    fn __lasx_xvaddwev_h_b(a: v32i8, b: v32i8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvaddwev.q.du"]
+    fn __lasx_xvaddwev_q_du(a: v4u64, b: v4u64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvaddwev.d.wu"]
+    fn __lasx_xvaddwev_d_wu(a: v8u32, b: v8u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvaddwev.w.hu"]
+    fn __lasx_xvaddwev_w_hu(a: v16u16, b: v16u16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvaddwev.h.bu"]
+    fn __lasx_xvaddwev_h_bu(a: v32u8, b: v32u8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsubwev.q.d"]
+    fn __lasx_xvsubwev_q_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsubwev.d.w"]
+    fn __lasx_xvsubwev_d_w(a: v8i32, b: v8i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsubwev.w.h"]
+    fn __lasx_xvsubwev_w_h(a: v16i16, b: v16i16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsubwev.h.b"]
+    fn __lasx_xvsubwev_h_b(a: v32i8, b: v32i8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsubwev.q.du"]
+    fn __lasx_xvsubwev_q_du(a: v4u64, b: v4u64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsubwev.d.wu"]
+    fn __lasx_xvsubwev_d_wu(a: v8u32, b: v8u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsubwev.w.hu"]
+    fn __lasx_xvsubwev_w_hu(a: v16u16, b: v16u16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsubwev.h.bu"]
+    fn __lasx_xvsubwev_h_bu(a: v32u8, b: v32u8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvmulwev.q.d"]
+    fn __lasx_xvmulwev_q_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmulwev.d.w"]
+    fn __lasx_xvmulwev_d_w(a: v8i32, b: v8i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmulwev.w.h"]
+    fn __lasx_xvmulwev_w_h(a: v16i16, b: v16i16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmulwev.h.b"]
+    fn __lasx_xvmulwev_h_b(a: v32i8, b: v32i8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvmulwev.q.du"]
+    fn __lasx_xvmulwev_q_du(a: v4u64, b: v4u64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmulwev.d.wu"]
+    fn __lasx_xvmulwev_d_wu(a: v8u32, b: v8u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmulwev.w.hu"]
+    fn __lasx_xvmulwev_w_hu(a: v16u16, b: v16u16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmulwev.h.bu"]
+    fn __lasx_xvmulwev_h_bu(a: v32u8, b: v32u8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvaddwod.q.d"]
+    fn __lasx_xvaddwod_q_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvaddwod.d.w"]
+    fn __lasx_xvaddwod_d_w(a: v8i32, b: v8i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvaddwod.w.h"]
+    fn __lasx_xvaddwod_w_h(a: v16i16, b: v16i16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvaddwod.h.b"]
+    fn __lasx_xvaddwod_h_b(a: v32i8, b: v32i8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvaddwod.q.du"]
+    fn __lasx_xvaddwod_q_du(a: v4u64, b: v4u64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvaddwod.d.wu"]
+    fn __lasx_xvaddwod_d_wu(a: v8u32, b: v8u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvaddwod.w.hu"]
+    fn __lasx_xvaddwod_w_hu(a: v16u16, b: v16u16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvaddwod.h.bu"]
+    fn __lasx_xvaddwod_h_bu(a: v32u8, b: v32u8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsubwod.q.d"]
+    fn __lasx_xvsubwod_q_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsubwod.d.w"]
+    fn __lasx_xvsubwod_d_w(a: v8i32, b: v8i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsubwod.w.h"]
+    fn __lasx_xvsubwod_w_h(a: v16i16, b: v16i16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsubwod.h.b"]
+    fn __lasx_xvsubwod_h_b(a: v32i8, b: v32i8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsubwod.q.du"]
+    fn __lasx_xvsubwod_q_du(a: v4u64, b: v4u64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsubwod.d.wu"]
+    fn __lasx_xvsubwod_d_wu(a: v8u32, b: v8u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsubwod.w.hu"]
+    fn __lasx_xvsubwod_w_hu(a: v16u16, b: v16u16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsubwod.h.bu"]
+    fn __lasx_xvsubwod_h_bu(a: v32u8, b: v32u8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvmulwod.q.d"]
+    fn __lasx_xvmulwod_q_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmulwod.d.w"]
+    fn __lasx_xvmulwod_d_w(a: v8i32, b: v8i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmulwod.w.h"]
+    fn __lasx_xvmulwod_w_h(a: v16i16, b: v16i16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmulwod.h.b"]
+    fn __lasx_xvmulwod_h_b(a: v32i8, b: v32i8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvmulwod.q.du"]
+    fn __lasx_xvmulwod_q_du(a: v4u64, b: v4u64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmulwod.d.wu"]
+    fn __lasx_xvmulwod_d_wu(a: v8u32, b: v8u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmulwod.w.hu"]
+    fn __lasx_xvmulwod_w_hu(a: v16u16, b: v16u16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmulwod.h.bu"]
+    fn __lasx_xvmulwod_h_bu(a: v32u8, b: v32u8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvaddwev.d.wu.w"]
+    fn __lasx_xvaddwev_d_wu_w(a: v8u32, b: v8i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvaddwev.w.hu.h"]
+    fn __lasx_xvaddwev_w_hu_h(a: v16u16, b: v16i16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvaddwev.h.bu.b"]
+    fn __lasx_xvaddwev_h_bu_b(a: v32u8, b: v32i8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvmulwev.d.wu.w"]
+    fn __lasx_xvmulwev_d_wu_w(a: v8u32, b: v8i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmulwev.w.hu.h"]
+    fn __lasx_xvmulwev_w_hu_h(a: v16u16, b: v16i16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmulwev.h.bu.b"]
+    fn __lasx_xvmulwev_h_bu_b(a: v32u8, b: v32i8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvaddwod.d.wu.w"]
+    fn __lasx_xvaddwod_d_wu_w(a: v8u32, b: v8i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvaddwod.w.hu.h"]
+    fn __lasx_xvaddwod_w_hu_h(a: v16u16, b: v16i16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvaddwod.h.bu.b"]
+    fn __lasx_xvaddwod_h_bu_b(a: v32u8, b: v32i8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvmulwod.d.wu.w"]
+    fn __lasx_xvmulwod_d_wu_w(a: v8u32, b: v8i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmulwod.w.hu.h"]
+    fn __lasx_xvmulwod_w_hu_h(a: v16u16, b: v16i16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmulwod.h.bu.b"]
+    fn __lasx_xvmulwod_h_bu_b(a: v32u8, b: v32i8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvhaddw.q.d"]
+    fn __lasx_xvhaddw_q_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvhaddw.qu.du"]
+    fn __lasx_xvhaddw_qu_du(a: v4u64, b: v4u64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvhsubw.q.d"]
+    fn __lasx_xvhsubw_q_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvhsubw.qu.du"]
+    fn __lasx_xvhsubw_qu_du(a: v4u64, b: v4u64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwev.q.d"]
+    fn __lasx_xvmaddwev_q_d(a: v4i64, b: v4i64, c: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwev.d.w"]
+    fn __lasx_xvmaddwev_d_w(a: v4i64, b: v8i32, c: v8i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwev.w.h"]
+    fn __lasx_xvmaddwev_w_h(a: v8i32, b: v16i16, c: v16i16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwev.h.b"]
+    fn __lasx_xvmaddwev_h_b(a: v16i16, b: v32i8, c: v32i8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwev.q.du"]
+    fn __lasx_xvmaddwev_q_du(a: v4u64, b: v4u64, c: v4u64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwev.d.wu"]
+    fn __lasx_xvmaddwev_d_wu(a: v4u64, b: v8u32, c: v8u32) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwev.w.hu"]
+    fn __lasx_xvmaddwev_w_hu(a: v8u32, b: v16u16, c: v16u16) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwev.h.bu"]
+    fn __lasx_xvmaddwev_h_bu(a: v16u16, b: v32u8, c: v32u8) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwod.q.d"]
+    fn __lasx_xvmaddwod_q_d(a: v4i64, b: v4i64, c: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwod.d.w"]
+    fn __lasx_xvmaddwod_d_w(a: v4i64, b: v8i32, c: v8i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwod.w.h"]
+    fn __lasx_xvmaddwod_w_h(a: v8i32, b: v16i16, c: v16i16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwod.h.b"]
+    fn __lasx_xvmaddwod_h_b(a: v16i16, b: v32i8, c: v32i8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwod.q.du"]
+    fn __lasx_xvmaddwod_q_du(a: v4u64, b: v4u64, c: v4u64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwod.d.wu"]
+    fn __lasx_xvmaddwod_d_wu(a: v4u64, b: v8u32, c: v8u32) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwod.w.hu"]
+    fn __lasx_xvmaddwod_w_hu(a: v8u32, b: v16u16, c: v16u16) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwod.h.bu"]
+    fn __lasx_xvmaddwod_h_bu(a: v16u16, b: v32u8, c: v32u8) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwev.q.du.d"]
+    fn __lasx_xvmaddwev_q_du_d(a: v4i64, b: v4u64, c: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwev.d.wu.w"]
+    fn __lasx_xvmaddwev_d_wu_w(a: v4i64, b: v8u32, c: v8i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwev.w.hu.h"]
+    fn __lasx_xvmaddwev_w_hu_h(a: v8i32, b: v16u16, c: v16i16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwev.h.bu.b"]
+    fn __lasx_xvmaddwev_h_bu_b(a: v16i16, b: v32u8, c: v32i8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwod.q.du.d"]
+    fn __lasx_xvmaddwod_q_du_d(a: v4i64, b: v4u64, c: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwod.d.wu.w"]
+    fn __lasx_xvmaddwod_d_wu_w(a: v4i64, b: v8u32, c: v8i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwod.w.hu.h"]
+    fn __lasx_xvmaddwod_w_hu_h(a: v8i32, b: v16u16, c: v16i16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvmaddwod.h.bu.b"]
+    fn __lasx_xvmaddwod_h_bu_b(a: v16i16, b: v32u8, c: v32i8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvrotr.b"]
+    fn __lasx_xvrotr_b(a: v32i8, b: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvrotr.h"]
+    fn __lasx_xvrotr_h(a: v16i16, b: v16i16) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvrotr.w"]
+    fn __lasx_xvrotr_w(a: v8i32, b: v8i32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvrotr.d"]
+    fn __lasx_xvrotr_d(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvadd.q"]
+    fn __lasx_xvadd_q(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsub.q"]
+    fn __lasx_xvsub_q(a: v4i64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvaddwev.q.du.d"]
+    fn __lasx_xvaddwev_q_du_d(a: v4u64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvaddwod.q.du.d"]
+    fn __lasx_xvaddwod_q_du_d(a: v4u64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmulwev.q.du.d"]
+    fn __lasx_xvmulwev_q_du_d(a: v4u64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmulwod.q.du.d"]
+    fn __lasx_xvmulwod_q_du_d(a: v4u64, b: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvmskgez.b"]
+    fn __lasx_xvmskgez_b(a: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvmsknz.b"]
+    fn __lasx_xvmsknz_b(a: v32i8) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvexth.h.b"]
+    fn __lasx_xvexth_h_b(a: v32i8) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvexth.w.h"]
+    fn __lasx_xvexth_w_h(a: v16i16) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvexth.d.w"]
+    fn __lasx_xvexth_d_w(a: v8i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvexth.q.d"]
+    fn __lasx_xvexth_q_d(a: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvexth.hu.bu"]
+    fn __lasx_xvexth_hu_bu(a: v32u8) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvexth.wu.hu"]
+    fn __lasx_xvexth_wu_hu(a: v16u16) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvexth.du.wu"]
+    fn __lasx_xvexth_du_wu(a: v8u32) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvexth.qu.du"]
+    fn __lasx_xvexth_qu_du(a: v4u64) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvrotri.b"]
+    fn __lasx_xvrotri_b(a: v32i8, b: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvrotri.h"]
+    fn __lasx_xvrotri_h(a: v16i16, b: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvrotri.w"]
+    fn __lasx_xvrotri_w(a: v8i32, b: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvrotri.d"]
+    fn __lasx_xvrotri_d(a: v4i64, b: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvextl.q.d"]
+    fn __lasx_xvextl_q_d(a: v4i64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsrlni.b.h"]
+    fn __lasx_xvsrlni_b_h(a: v32i8, b: v32i8, c: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsrlni.h.w"]
+    fn __lasx_xvsrlni_h_w(a: v16i16, b: v16i16, c: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsrlni.w.d"]
+    fn __lasx_xvsrlni_w_d(a: v8i32, b: v8i32, c: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsrlni.d.q"]
+    fn __lasx_xvsrlni_d_q(a: v4i64, b: v4i64, c: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsrlrni.b.h"]
+    fn __lasx_xvsrlrni_b_h(a: v32i8, b: v32i8, c: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsrlrni.h.w"]
+    fn __lasx_xvsrlrni_h_w(a: v16i16, b: v16i16, c: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsrlrni.w.d"]
+    fn __lasx_xvsrlrni_w_d(a: v8i32, b: v8i32, c: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsrlrni.d.q"]
+    fn __lasx_xvsrlrni_d_q(a: v4i64, b: v4i64, c: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvssrlni.b.h"]
+    fn __lasx_xvssrlni_b_h(a: v32i8, b: v32i8, c: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvssrlni.h.w"]
+    fn __lasx_xvssrlni_h_w(a: v16i16, b: v16i16, c: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvssrlni.w.d"]
+    fn __lasx_xvssrlni_w_d(a: v8i32, b: v8i32, c: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvssrlni.d.q"]
+    fn __lasx_xvssrlni_d_q(a: v4i64, b: v4i64, c: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvssrlni.bu.h"]
+    fn __lasx_xvssrlni_bu_h(a: v32u8, b: v32i8, c: u32) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvssrlni.hu.w"]
+    fn __lasx_xvssrlni_hu_w(a: v16u16, b: v16i16, c: u32) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvssrlni.wu.d"]
+    fn __lasx_xvssrlni_wu_d(a: v8u32, b: v8i32, c: u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvssrlni.du.q"]
+    fn __lasx_xvssrlni_du_q(a: v4u64, b: v4i64, c: u32) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvssrlrni.b.h"]
+    fn __lasx_xvssrlrni_b_h(a: v32i8, b: v32i8, c: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvssrlrni.h.w"]
+    fn __lasx_xvssrlrni_h_w(a: v16i16, b: v16i16, c: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvssrlrni.w.d"]
+    fn __lasx_xvssrlrni_w_d(a: v8i32, b: v8i32, c: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvssrlrni.d.q"]
+    fn __lasx_xvssrlrni_d_q(a: v4i64, b: v4i64, c: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvssrlrni.bu.h"]
+    fn __lasx_xvssrlrni_bu_h(a: v32u8, b: v32i8, c: u32) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvssrlrni.hu.w"]
+    fn __lasx_xvssrlrni_hu_w(a: v16u16, b: v16i16, c: u32) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvssrlrni.wu.d"]
+    fn __lasx_xvssrlrni_wu_d(a: v8u32, b: v8i32, c: u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvssrlrni.du.q"]
+    fn __lasx_xvssrlrni_du_q(a: v4u64, b: v4i64, c: u32) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvsrani.b.h"]
+    fn __lasx_xvsrani_b_h(a: v32i8, b: v32i8, c: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsrani.h.w"]
+    fn __lasx_xvsrani_h_w(a: v16i16, b: v16i16, c: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsrani.w.d"]
+    fn __lasx_xvsrani_w_d(a: v8i32, b: v8i32, c: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsrani.d.q"]
+    fn __lasx_xvsrani_d_q(a: v4i64, b: v4i64, c: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvsrarni.b.h"]
+    fn __lasx_xvsrarni_b_h(a: v32i8, b: v32i8, c: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvsrarni.h.w"]
+    fn __lasx_xvsrarni_h_w(a: v16i16, b: v16i16, c: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvsrarni.w.d"]
+    fn __lasx_xvsrarni_w_d(a: v8i32, b: v8i32, c: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvsrarni.d.q"]
+    fn __lasx_xvsrarni_d_q(a: v4i64, b: v4i64, c: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvssrani.b.h"]
+    fn __lasx_xvssrani_b_h(a: v32i8, b: v32i8, c: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvssrani.h.w"]
+    fn __lasx_xvssrani_h_w(a: v16i16, b: v16i16, c: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvssrani.w.d"]
+    fn __lasx_xvssrani_w_d(a: v8i32, b: v8i32, c: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvssrani.d.q"]
+    fn __lasx_xvssrani_d_q(a: v4i64, b: v4i64, c: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvssrani.bu.h"]
+    fn __lasx_xvssrani_bu_h(a: v32u8, b: v32i8, c: u32) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvssrani.hu.w"]
+    fn __lasx_xvssrani_hu_w(a: v16u16, b: v16i16, c: u32) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvssrani.wu.d"]
+    fn __lasx_xvssrani_wu_d(a: v8u32, b: v8i32, c: u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvssrani.du.q"]
+    fn __lasx_xvssrani_du_q(a: v4u64, b: v4i64, c: u32) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xvssrarni.b.h"]
+    fn __lasx_xvssrarni_b_h(a: v32i8, b: v32i8, c: u32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvssrarni.h.w"]
+    fn __lasx_xvssrarni_h_w(a: v16i16, b: v16i16, c: u32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvssrarni.w.d"]
+    fn __lasx_xvssrarni_w_d(a: v8i32, b: v8i32, c: u32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvssrarni.d.q"]
+    fn __lasx_xvssrarni_d_q(a: v4i64, b: v4i64, c: u32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvssrarni.bu.h"]
+    fn __lasx_xvssrarni_bu_h(a: v32u8, b: v32i8, c: u32) -> v32u8;
+    #[link_name = "llvm.loongarch.lasx.xvssrarni.hu.w"]
+    fn __lasx_xvssrarni_hu_w(a: v16u16, b: v16i16, c: u32) -> v16u16;
+    #[link_name = "llvm.loongarch.lasx.xvssrarni.wu.d"]
+    fn __lasx_xvssrarni_wu_d(a: v8u32, b: v8i32, c: u32) -> v8u32;
+    #[link_name = "llvm.loongarch.lasx.xvssrarni.du.q"]
+    fn __lasx_xvssrarni_du_q(a: v4u64, b: v4i64, c: u32) -> v4u64;
+    #[link_name = "llvm.loongarch.lasx.xbnz.b"]
+    fn __lasx_xbnz_b(a: v32u8) -> i32;
+    #[link_name = "llvm.loongarch.lasx.xbnz.d"]
+    fn __lasx_xbnz_d(a: v4u64) -> i32;
+    #[link_name = "llvm.loongarch.lasx.xbnz.h"]
+    fn __lasx_xbnz_h(a: v16u16) -> i32;
+    #[link_name = "llvm.loongarch.lasx.xbnz.v"]
+    fn __lasx_xbnz_v(a: v32u8) -> i32;
+    #[link_name = "llvm.loongarch.lasx.xbnz.w"]
+    fn __lasx_xbnz_w(a: v8u32) -> i32;
+    #[link_name = "llvm.loongarch.lasx.xbz.b"]
+    fn __lasx_xbz_b(a: v32u8) -> i32;
+    #[link_name = "llvm.loongarch.lasx.xbz.d"]
+    fn __lasx_xbz_d(a: v4u64) -> i32;
+    #[link_name = "llvm.loongarch.lasx.xbz.h"]
+    fn __lasx_xbz_h(a: v16u16) -> i32;
+    #[link_name = "llvm.loongarch.lasx.xbz.v"]
+    fn __lasx_xbz_v(a: v32u8) -> i32;
+    #[link_name = "llvm.loongarch.lasx.xbz.w"]
+    fn __lasx_xbz_w(a: v8u32) -> i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.caf.d"]
+    fn __lasx_xvfcmp_caf_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.caf.s"]
+    fn __lasx_xvfcmp_caf_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.ceq.d"]
+    fn __lasx_xvfcmp_ceq_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.ceq.s"]
+    fn __lasx_xvfcmp_ceq_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.cle.d"]
+    fn __lasx_xvfcmp_cle_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.cle.s"]
+    fn __lasx_xvfcmp_cle_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.clt.d"]
+    fn __lasx_xvfcmp_clt_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.clt.s"]
+    fn __lasx_xvfcmp_clt_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.cne.d"]
+    fn __lasx_xvfcmp_cne_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.cne.s"]
+    fn __lasx_xvfcmp_cne_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.cor.d"]
+    fn __lasx_xvfcmp_cor_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.cor.s"]
+    fn __lasx_xvfcmp_cor_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.cueq.d"]
+    fn __lasx_xvfcmp_cueq_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.cueq.s"]
+    fn __lasx_xvfcmp_cueq_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.cule.d"]
+    fn __lasx_xvfcmp_cule_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.cule.s"]
+    fn __lasx_xvfcmp_cule_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.cult.d"]
+    fn __lasx_xvfcmp_cult_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.cult.s"]
+    fn __lasx_xvfcmp_cult_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.cun.d"]
+    fn __lasx_xvfcmp_cun_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.cune.d"]
+    fn __lasx_xvfcmp_cune_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.cune.s"]
+    fn __lasx_xvfcmp_cune_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.cun.s"]
+    fn __lasx_xvfcmp_cun_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.saf.d"]
+    fn __lasx_xvfcmp_saf_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.saf.s"]
+    fn __lasx_xvfcmp_saf_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.seq.d"]
+    fn __lasx_xvfcmp_seq_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.seq.s"]
+    fn __lasx_xvfcmp_seq_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.sle.d"]
+    fn __lasx_xvfcmp_sle_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.sle.s"]
+    fn __lasx_xvfcmp_sle_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.slt.d"]
+    fn __lasx_xvfcmp_slt_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.slt.s"]
+    fn __lasx_xvfcmp_slt_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.sne.d"]
+    fn __lasx_xvfcmp_sne_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.sne.s"]
+    fn __lasx_xvfcmp_sne_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.sor.d"]
+    fn __lasx_xvfcmp_sor_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.sor.s"]
+    fn __lasx_xvfcmp_sor_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.sueq.d"]
+    fn __lasx_xvfcmp_sueq_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.sueq.s"]
+    fn __lasx_xvfcmp_sueq_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.sule.d"]
+    fn __lasx_xvfcmp_sule_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.sule.s"]
+    fn __lasx_xvfcmp_sule_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.sult.d"]
+    fn __lasx_xvfcmp_sult_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.sult.s"]
+    fn __lasx_xvfcmp_sult_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.sun.d"]
+    fn __lasx_xvfcmp_sun_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.sune.d"]
+    fn __lasx_xvfcmp_sune_d(a: v4f64, b: v4f64) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.sune.s"]
+    fn __lasx_xvfcmp_sune_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvfcmp.sun.s"]
+    fn __lasx_xvfcmp_sun_s(a: v8f32, b: v8f32) -> v8i32;
+    #[link_name = "llvm.loongarch.lasx.xvpickve.d.f"]
+    fn __lasx_xvpickve_d_f(a: v4f64, b: u32) -> v4f64;
+    #[link_name = "llvm.loongarch.lasx.xvpickve.w.f"]
+    fn __lasx_xvpickve_w_f(a: v8f32, b: u32) -> v8f32;
+    #[link_name = "llvm.loongarch.lasx.xvrepli.b"]
+    fn __lasx_xvrepli_b(a: i32) -> v32i8;
+    #[link_name = "llvm.loongarch.lasx.xvrepli.d"]
+    fn __lasx_xvrepli_d(a: i32) -> v4i64;
+    #[link_name = "llvm.loongarch.lasx.xvrepli.h"]
+    fn __lasx_xvrepli_h(a: i32) -> v16i16;
+    #[link_name = "llvm.loongarch.lasx.xvrepli.w"]
+    fn __lasx_xvrepli_w(a: i32) -> v8i32;
+}
+
+/// Generates the instruction `xvsll.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsll.b))]
+pub unsafe fn lasx_xvsll_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvsll_b(a, b)
+}
+
+/// Generates the instruction `xvsll.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsll.h))]
+pub unsafe fn lasx_xvsll_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvsll_h(a, b)
+}
+
+/// Generates the instruction `xvsll.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsll.w))]
+pub unsafe fn lasx_xvsll_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvsll_w(a, b)
+}
+
+/// Generates the instruction `xvsll.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsll.d))]
+pub unsafe fn lasx_xvsll_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvsll_d(a, b)
+}
+
+/// Generates the instruction `xvslli.b xd, xj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslli.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslli_b<const IMM3: u32>(a: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvslli_b(a, IMM3)
+}
+
+/// Generates the instruction `xvslli.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslli.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslli_h<const IMM4: u32>(a: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvslli_h(a, IMM4)
+}
+
+/// Generates the instruction `xvslli.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslli.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslli_w<const IMM5: u32>(a: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvslli_w(a, IMM5)
+}
+
+/// Generates the instruction `xvslli.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslli.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslli_d<const IMM6: u32>(a: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvslli_d(a, IMM6)
+}
+
+/// Generates the instruction `xvsra.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsra.b))]
+pub unsafe fn lasx_xvsra_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvsra_b(a, b)
+}
+
+/// Generates the instruction `xvsra.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsra.h))]
+pub unsafe fn lasx_xvsra_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvsra_h(a, b)
+}
+
+/// Generates the instruction `xvsra.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsra.w))]
+pub unsafe fn lasx_xvsra_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvsra_w(a, b)
+}
+
+/// Generates the instruction `xvsra.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsra.d))]
+pub unsafe fn lasx_xvsra_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvsra_d(a, b)
+}
+
+/// Generates the instruction `xvsrai.b xd, xj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrai.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsrai_b<const IMM3: u32>(a: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvsrai_b(a, IMM3)
+}
+
+/// Generates the instruction `xvsrai.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrai.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsrai_h<const IMM4: u32>(a: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvsrai_h(a, IMM4)
+}
+
+/// Generates the instruction `xvsrai.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrai.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsrai_w<const IMM5: u32>(a: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvsrai_w(a, IMM5)
+}
+
+/// Generates the instruction `xvsrai.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrai.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsrai_d<const IMM6: u32>(a: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvsrai_d(a, IMM6)
+}
+
+/// Generates the instruction `xvsrar.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrar.b))]
+pub unsafe fn lasx_xvsrar_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvsrar_b(a, b)
+}
+
+/// Generates the instruction `xvsrar.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrar.h))]
+pub unsafe fn lasx_xvsrar_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvsrar_h(a, b)
+}
+
+/// Generates the instruction `xvsrar.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrar.w))]
+pub unsafe fn lasx_xvsrar_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvsrar_w(a, b)
+}
+
+/// Generates the instruction `xvsrar.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrar.d))]
+pub unsafe fn lasx_xvsrar_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvsrar_d(a, b)
+}
+
+/// Generates the instruction `xvsrari.b xd, xj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrari.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsrari_b<const IMM3: u32>(a: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvsrari_b(a, IMM3)
+}
+
+/// Generates the instruction `xvsrari.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrari.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsrari_h<const IMM4: u32>(a: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvsrari_h(a, IMM4)
+}
+
+/// Generates the instruction `xvsrari.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrari.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsrari_w<const IMM5: u32>(a: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvsrari_w(a, IMM5)
+}
+
+/// Generates the instruction `xvsrari.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrari.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsrari_d<const IMM6: u32>(a: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvsrari_d(a, IMM6)
+}
+
+/// Generates the instruction `xvsrl.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrl.b))]
+pub unsafe fn lasx_xvsrl_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvsrl_b(a, b)
+}
+
+/// Generates the instruction `xvsrl.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrl.h))]
+pub unsafe fn lasx_xvsrl_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvsrl_h(a, b)
+}
+
+/// Generates the instruction `xvsrl.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrl.w))]
+pub unsafe fn lasx_xvsrl_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvsrl_w(a, b)
+}
+
+/// Generates the instruction `xvsrl.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrl.d))]
+pub unsafe fn lasx_xvsrl_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvsrl_d(a, b)
+}
+
+/// Generates the instruction `xvsrli.b xd, xj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrli.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsrli_b<const IMM3: u32>(a: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvsrli_b(a, IMM3)
+}
+
+/// Generates the instruction `xvsrli.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrli.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsrli_h<const IMM4: u32>(a: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvsrli_h(a, IMM4)
+}
+
+/// Generates the instruction `xvsrli.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrli.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsrli_w<const IMM5: u32>(a: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvsrli_w(a, IMM5)
+}
+
+/// Generates the instruction `xvsrli.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrli.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsrli_d<const IMM6: u32>(a: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvsrli_d(a, IMM6)
+}
+
+/// Generates the instruction `xvsrlr.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrlr.b))]
+pub unsafe fn lasx_xvsrlr_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvsrlr_b(a, b)
+}
+
+/// Generates the instruction `xvsrlr.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrlr.h))]
+pub unsafe fn lasx_xvsrlr_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvsrlr_h(a, b)
+}
+
+/// Generates the instruction `xvsrlr.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrlr.w))]
+pub unsafe fn lasx_xvsrlr_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvsrlr_w(a, b)
+}
+
+/// Generates the instruction `xvsrlr.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrlr.d))]
+pub unsafe fn lasx_xvsrlr_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvsrlr_d(a, b)
+}
+
+/// Generates the instruction `xvsrlri.b xd, xj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrlri.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsrlri_b<const IMM3: u32>(a: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvsrlri_b(a, IMM3)
+}
+
+/// Generates the instruction `xvsrlri.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrlri.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsrlri_h<const IMM4: u32>(a: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvsrlri_h(a, IMM4)
+}
+
+/// Generates the instruction `xvsrlri.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrlri.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsrlri_w<const IMM5: u32>(a: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvsrlri_w(a, IMM5)
+}
+
+/// Generates the instruction `xvsrlri.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrlri.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsrlri_d<const IMM6: u32>(a: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvsrlri_d(a, IMM6)
+}
+
+/// Generates the instruction `xvbitclr.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitclr.b))]
+pub unsafe fn lasx_xvbitclr_b(a: v32u8, b: v32u8) -> v32u8 {
+    __lasx_xvbitclr_b(a, b)
+}
+
+/// Generates the instruction `xvbitclr.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitclr.h))]
+pub unsafe fn lasx_xvbitclr_h(a: v16u16, b: v16u16) -> v16u16 {
+    __lasx_xvbitclr_h(a, b)
+}
+
+/// Generates the instruction `xvbitclr.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitclr.w))]
+pub unsafe fn lasx_xvbitclr_w(a: v8u32, b: v8u32) -> v8u32 {
+    __lasx_xvbitclr_w(a, b)
+}
+
+/// Generates the instruction `xvbitclr.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitclr.d))]
+pub unsafe fn lasx_xvbitclr_d(a: v4u64, b: v4u64) -> v4u64 {
+    __lasx_xvbitclr_d(a, b)
+}
+
+/// Generates the instruction `xvbitclri.b xd, xj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitclri.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvbitclri_b<const IMM3: u32>(a: v32u8) -> v32u8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvbitclri_b(a, IMM3)
+}
+
+/// Generates the instruction `xvbitclri.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitclri.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvbitclri_h<const IMM4: u32>(a: v16u16) -> v16u16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvbitclri_h(a, IMM4)
+}
+
+/// Generates the instruction `xvbitclri.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitclri.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvbitclri_w<const IMM5: u32>(a: v8u32) -> v8u32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvbitclri_w(a, IMM5)
+}
+
+/// Generates the instruction `xvbitclri.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitclri.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvbitclri_d<const IMM6: u32>(a: v4u64) -> v4u64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvbitclri_d(a, IMM6)
+}
+
+/// Generates the instruction `xvbitset.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitset.b))]
+pub unsafe fn lasx_xvbitset_b(a: v32u8, b: v32u8) -> v32u8 {
+    __lasx_xvbitset_b(a, b)
+}
+
+/// Generates the instruction `xvbitset.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitset.h))]
+pub unsafe fn lasx_xvbitset_h(a: v16u16, b: v16u16) -> v16u16 {
+    __lasx_xvbitset_h(a, b)
+}
+
+/// Generates the instruction `xvbitset.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitset.w))]
+pub unsafe fn lasx_xvbitset_w(a: v8u32, b: v8u32) -> v8u32 {
+    __lasx_xvbitset_w(a, b)
+}
+
+/// Generates the instruction `xvbitset.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitset.d))]
+pub unsafe fn lasx_xvbitset_d(a: v4u64, b: v4u64) -> v4u64 {
+    __lasx_xvbitset_d(a, b)
+}
+
+/// Generates the instruction `xvbitseti.b xd, xj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitseti.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvbitseti_b<const IMM3: u32>(a: v32u8) -> v32u8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvbitseti_b(a, IMM3)
+}
+
+/// Generates the instruction `xvbitseti.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitseti.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvbitseti_h<const IMM4: u32>(a: v16u16) -> v16u16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvbitseti_h(a, IMM4)
+}
+
+/// Generates the instruction `xvbitseti.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitseti.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvbitseti_w<const IMM5: u32>(a: v8u32) -> v8u32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvbitseti_w(a, IMM5)
+}
+
+/// Generates the instruction `xvbitseti.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitseti.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvbitseti_d<const IMM6: u32>(a: v4u64) -> v4u64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvbitseti_d(a, IMM6)
+}
+
+/// Generates the instruction `xvbitrev.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitrev.b))]
+pub unsafe fn lasx_xvbitrev_b(a: v32u8, b: v32u8) -> v32u8 {
+    __lasx_xvbitrev_b(a, b)
+}
+
+/// Generates the instruction `xvbitrev.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitrev.h))]
+pub unsafe fn lasx_xvbitrev_h(a: v16u16, b: v16u16) -> v16u16 {
+    __lasx_xvbitrev_h(a, b)
+}
+
+/// Generates the instruction `xvbitrev.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitrev.w))]
+pub unsafe fn lasx_xvbitrev_w(a: v8u32, b: v8u32) -> v8u32 {
+    __lasx_xvbitrev_w(a, b)
+}
+
+/// Generates the instruction `xvbitrev.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitrev.d))]
+pub unsafe fn lasx_xvbitrev_d(a: v4u64, b: v4u64) -> v4u64 {
+    __lasx_xvbitrev_d(a, b)
+}
+
+/// Generates the instruction `xvbitrevi.b xd, xj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitrevi.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvbitrevi_b<const IMM3: u32>(a: v32u8) -> v32u8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvbitrevi_b(a, IMM3)
+}
+
+/// Generates the instruction `xvbitrevi.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitrevi.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvbitrevi_h<const IMM4: u32>(a: v16u16) -> v16u16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvbitrevi_h(a, IMM4)
+}
+
+/// Generates the instruction `xvbitrevi.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitrevi.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvbitrevi_w<const IMM5: u32>(a: v8u32) -> v8u32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvbitrevi_w(a, IMM5)
+}
+
+/// Generates the instruction `xvbitrevi.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitrevi.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvbitrevi_d<const IMM6: u32>(a: v4u64) -> v4u64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvbitrevi_d(a, IMM6)
+}
+
+/// Generates the instruction `xvadd.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvadd.b))]
+pub unsafe fn lasx_xvadd_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvadd_b(a, b)
+}
+
+/// Generates the instruction `xvadd.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvadd.h))]
+pub unsafe fn lasx_xvadd_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvadd_h(a, b)
+}
+
+/// Generates the instruction `xvadd.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvadd.w))]
+pub unsafe fn lasx_xvadd_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvadd_w(a, b)
+}
+
+/// Generates the instruction `xvadd.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvadd.d))]
+pub unsafe fn lasx_xvadd_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvadd_d(a, b)
+}
+
+/// Generates the instruction `xvaddi.bu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddi.bu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvaddi_bu<const IMM5: u32>(a: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvaddi_bu(a, IMM5)
+}
+
+/// Generates the instruction `xvaddi.hu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddi.hu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvaddi_hu<const IMM5: u32>(a: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvaddi_hu(a, IMM5)
+}
+
+/// Generates the instruction `xvaddi.wu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddi.wu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvaddi_wu<const IMM5: u32>(a: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvaddi_wu(a, IMM5)
+}
+
+/// Generates the instruction `xvaddi.du xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddi.du, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvaddi_du<const IMM5: u32>(a: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvaddi_du(a, IMM5)
+}
+
+/// Generates the instruction `xvsub.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsub.b))]
+pub unsafe fn lasx_xvsub_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvsub_b(a, b)
+}
+
+/// Generates the instruction `xvsub.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsub.h))]
+pub unsafe fn lasx_xvsub_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvsub_h(a, b)
+}
+
+/// Generates the instruction `xvsub.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsub.w))]
+pub unsafe fn lasx_xvsub_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvsub_w(a, b)
+}
+
+/// Generates the instruction `xvsub.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsub.d))]
+pub unsafe fn lasx_xvsub_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvsub_d(a, b)
+}
+
+/// Generates the instruction `xvsubi.bu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubi.bu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsubi_bu<const IMM5: u32>(a: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvsubi_bu(a, IMM5)
+}
+
+/// Generates the instruction `xvsubi.hu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubi.hu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsubi_hu<const IMM5: u32>(a: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvsubi_hu(a, IMM5)
+}
+
+/// Generates the instruction `xvsubi.wu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubi.wu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsubi_wu<const IMM5: u32>(a: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvsubi_wu(a, IMM5)
+}
+
+/// Generates the instruction `xvsubi.du xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubi.du, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsubi_du<const IMM5: u32>(a: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvsubi_du(a, IMM5)
+}
+
+/// Generates the instruction `xvmax.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmax.b))]
+pub unsafe fn lasx_xvmax_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvmax_b(a, b)
+}
+
+/// Generates the instruction `xvmax.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmax.h))]
+pub unsafe fn lasx_xvmax_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvmax_h(a, b)
+}
+
+/// Generates the instruction `xvmax.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmax.w))]
+pub unsafe fn lasx_xvmax_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvmax_w(a, b)
+}
+
+/// Generates the instruction `xvmax.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmax.d))]
+pub unsafe fn lasx_xvmax_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvmax_d(a, b)
+}
+
+/// Generates the instruction `xvmaxi.b xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaxi.b, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvmaxi_b<const IMM_S5: i32>(a: v32i8) -> v32i8 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvmaxi_b(a, IMM_S5)
+}
+
+/// Generates the instruction `xvmaxi.h xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaxi.h, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvmaxi_h<const IMM_S5: i32>(a: v16i16) -> v16i16 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvmaxi_h(a, IMM_S5)
+}
+
+/// Generates the instruction `xvmaxi.w xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaxi.w, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvmaxi_w<const IMM_S5: i32>(a: v8i32) -> v8i32 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvmaxi_w(a, IMM_S5)
+}
+
+/// Generates the instruction `xvmaxi.d xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaxi.d, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvmaxi_d<const IMM_S5: i32>(a: v4i64) -> v4i64 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvmaxi_d(a, IMM_S5)
+}
+
+/// Generates the instruction `xvmax.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmax.bu))]
+pub unsafe fn lasx_xvmax_bu(a: v32u8, b: v32u8) -> v32u8 {
+    __lasx_xvmax_bu(a, b)
+}
+
+/// Generates the instruction `xvmax.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmax.hu))]
+pub unsafe fn lasx_xvmax_hu(a: v16u16, b: v16u16) -> v16u16 {
+    __lasx_xvmax_hu(a, b)
+}
+
+/// Generates the instruction `xvmax.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmax.wu))]
+pub unsafe fn lasx_xvmax_wu(a: v8u32, b: v8u32) -> v8u32 {
+    __lasx_xvmax_wu(a, b)
+}
+
+/// Generates the instruction `xvmax.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmax.du))]
+pub unsafe fn lasx_xvmax_du(a: v4u64, b: v4u64) -> v4u64 {
+    __lasx_xvmax_du(a, b)
+}
+
+/// Generates the instruction `xvmaxi.bu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaxi.bu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvmaxi_bu<const IMM5: u32>(a: v32u8) -> v32u8 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvmaxi_bu(a, IMM5)
+}
+
+/// Generates the instruction `xvmaxi.hu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaxi.hu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvmaxi_hu<const IMM5: u32>(a: v16u16) -> v16u16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvmaxi_hu(a, IMM5)
+}
+
+/// Generates the instruction `xvmaxi.wu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaxi.wu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvmaxi_wu<const IMM5: u32>(a: v8u32) -> v8u32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvmaxi_wu(a, IMM5)
+}
+
+/// Generates the instruction `xvmaxi.du xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaxi.du, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvmaxi_du<const IMM5: u32>(a: v4u64) -> v4u64 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvmaxi_du(a, IMM5)
+}
+
+/// Generates the instruction `xvmin.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmin.b))]
+pub unsafe fn lasx_xvmin_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvmin_b(a, b)
+}
+
+/// Generates the instruction `xvmin.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmin.h))]
+pub unsafe fn lasx_xvmin_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvmin_h(a, b)
+}
+
+/// Generates the instruction `xvmin.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmin.w))]
+pub unsafe fn lasx_xvmin_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvmin_w(a, b)
+}
+
+/// Generates the instruction `xvmin.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmin.d))]
+pub unsafe fn lasx_xvmin_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvmin_d(a, b)
+}
+
+/// Generates the instruction `xvmini.b xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmini.b, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvmini_b<const IMM_S5: i32>(a: v32i8) -> v32i8 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvmini_b(a, IMM_S5)
+}
+
+/// Generates the instruction `xvmini.h xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmini.h, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvmini_h<const IMM_S5: i32>(a: v16i16) -> v16i16 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvmini_h(a, IMM_S5)
+}
+
+/// Generates the instruction `xvmini.w xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmini.w, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvmini_w<const IMM_S5: i32>(a: v8i32) -> v8i32 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvmini_w(a, IMM_S5)
+}
+
+/// Generates the instruction `xvmini.d xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmini.d, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvmini_d<const IMM_S5: i32>(a: v4i64) -> v4i64 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvmini_d(a, IMM_S5)
+}
+
+/// Generates the instruction `xvmin.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmin.bu))]
+pub unsafe fn lasx_xvmin_bu(a: v32u8, b: v32u8) -> v32u8 {
+    __lasx_xvmin_bu(a, b)
+}
+
+/// Generates the instruction `xvmin.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmin.hu))]
+pub unsafe fn lasx_xvmin_hu(a: v16u16, b: v16u16) -> v16u16 {
+    __lasx_xvmin_hu(a, b)
+}
+
+/// Generates the instruction `xvmin.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmin.wu))]
+pub unsafe fn lasx_xvmin_wu(a: v8u32, b: v8u32) -> v8u32 {
+    __lasx_xvmin_wu(a, b)
+}
+
+/// Generates the instruction `xvmin.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmin.du))]
+pub unsafe fn lasx_xvmin_du(a: v4u64, b: v4u64) -> v4u64 {
+    __lasx_xvmin_du(a, b)
+}
+
+/// Generates the instruction `xvmini.bu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmini.bu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvmini_bu<const IMM5: u32>(a: v32u8) -> v32u8 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvmini_bu(a, IMM5)
+}
+
+/// Generates the instruction `xvmini.hu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmini.hu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvmini_hu<const IMM5: u32>(a: v16u16) -> v16u16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvmini_hu(a, IMM5)
+}
+
+/// Generates the instruction `xvmini.wu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmini.wu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvmini_wu<const IMM5: u32>(a: v8u32) -> v8u32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvmini_wu(a, IMM5)
+}
+
+/// Generates the instruction `xvmini.du xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmini.du, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvmini_du<const IMM5: u32>(a: v4u64) -> v4u64 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvmini_du(a, IMM5)
+}
+
+/// Generates the instruction `xvseq.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvseq.b))]
+pub unsafe fn lasx_xvseq_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvseq_b(a, b)
+}
+
+/// Generates the instruction `xvseq.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvseq.h))]
+pub unsafe fn lasx_xvseq_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvseq_h(a, b)
+}
+
+/// Generates the instruction `xvseq.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvseq.w))]
+pub unsafe fn lasx_xvseq_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvseq_w(a, b)
+}
+
+/// Generates the instruction `xvseq.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvseq.d))]
+pub unsafe fn lasx_xvseq_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvseq_d(a, b)
+}
+
+/// Generates the instruction `xvseqi.b xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvseqi.b, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvseqi_b<const IMM_S5: i32>(a: v32i8) -> v32i8 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvseqi_b(a, IMM_S5)
+}
+
+/// Generates the instruction `xvseqi.h xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvseqi.h, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvseqi_h<const IMM_S5: i32>(a: v16i16) -> v16i16 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvseqi_h(a, IMM_S5)
+}
+
+/// Generates the instruction `xvseqi.w xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvseqi.w, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvseqi_w<const IMM_S5: i32>(a: v8i32) -> v8i32 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvseqi_w(a, IMM_S5)
+}
+
+/// Generates the instruction `xvseqi.d xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvseqi.d, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvseqi_d<const IMM_S5: i32>(a: v4i64) -> v4i64 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvseqi_d(a, IMM_S5)
+}
+
+/// Generates the instruction `xvslt.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslt.b))]
+pub unsafe fn lasx_xvslt_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvslt_b(a, b)
+}
+
+/// Generates the instruction `xvslt.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslt.h))]
+pub unsafe fn lasx_xvslt_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvslt_h(a, b)
+}
+
+/// Generates the instruction `xvslt.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslt.w))]
+pub unsafe fn lasx_xvslt_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvslt_w(a, b)
+}
+
+/// Generates the instruction `xvslt.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslt.d))]
+pub unsafe fn lasx_xvslt_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvslt_d(a, b)
+}
+
+/// Generates the instruction `xvslti.b xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslti.b, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslti_b<const IMM_S5: i32>(a: v32i8) -> v32i8 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvslti_b(a, IMM_S5)
+}
+
+/// Generates the instruction `xvslti.h xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslti.h, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslti_h<const IMM_S5: i32>(a: v16i16) -> v16i16 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvslti_h(a, IMM_S5)
+}
+
+/// Generates the instruction `xvslti.w xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslti.w, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslti_w<const IMM_S5: i32>(a: v8i32) -> v8i32 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvslti_w(a, IMM_S5)
+}
+
+/// Generates the instruction `xvslti.d xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslti.d, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslti_d<const IMM_S5: i32>(a: v4i64) -> v4i64 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvslti_d(a, IMM_S5)
+}
+
+/// Generates the instruction `xvslt.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslt.bu))]
+pub unsafe fn lasx_xvslt_bu(a: v32u8, b: v32u8) -> v32i8 {
+    __lasx_xvslt_bu(a, b)
+}
+
+/// Generates the instruction `xvslt.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslt.hu))]
+pub unsafe fn lasx_xvslt_hu(a: v16u16, b: v16u16) -> v16i16 {
+    __lasx_xvslt_hu(a, b)
+}
+
+/// Generates the instruction `xvslt.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslt.wu))]
+pub unsafe fn lasx_xvslt_wu(a: v8u32, b: v8u32) -> v8i32 {
+    __lasx_xvslt_wu(a, b)
+}
+
+/// Generates the instruction `xvslt.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslt.du))]
+pub unsafe fn lasx_xvslt_du(a: v4u64, b: v4u64) -> v4i64 {
+    __lasx_xvslt_du(a, b)
+}
+
+/// Generates the instruction `xvslti.bu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslti.bu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslti_bu<const IMM5: u32>(a: v32u8) -> v32i8 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvslti_bu(a, IMM5)
+}
+
+/// Generates the instruction `xvslti.hu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslti.hu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslti_hu<const IMM5: u32>(a: v16u16) -> v16i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvslti_hu(a, IMM5)
+}
+
+/// Generates the instruction `xvslti.wu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslti.wu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslti_wu<const IMM5: u32>(a: v8u32) -> v8i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvslti_wu(a, IMM5)
+}
+
+/// Generates the instruction `xvslti.du xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslti.du, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslti_du<const IMM5: u32>(a: v4u64) -> v4i64 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvslti_du(a, IMM5)
+}
+
+/// Generates the instruction `xvsle.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsle.b))]
+pub unsafe fn lasx_xvsle_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvsle_b(a, b)
+}
+
+/// Generates the instruction `xvsle.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsle.h))]
+pub unsafe fn lasx_xvsle_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvsle_h(a, b)
+}
+
+/// Generates the instruction `xvsle.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsle.w))]
+pub unsafe fn lasx_xvsle_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvsle_w(a, b)
+}
+
+/// Generates the instruction `xvsle.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsle.d))]
+pub unsafe fn lasx_xvsle_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvsle_d(a, b)
+}
+
+/// Generates the instruction `xvslei.b xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslei.b, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslei_b<const IMM_S5: i32>(a: v32i8) -> v32i8 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvslei_b(a, IMM_S5)
+}
+
+/// Generates the instruction `xvslei.h xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslei.h, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslei_h<const IMM_S5: i32>(a: v16i16) -> v16i16 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvslei_h(a, IMM_S5)
+}
+
+/// Generates the instruction `xvslei.w xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslei.w, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslei_w<const IMM_S5: i32>(a: v8i32) -> v8i32 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvslei_w(a, IMM_S5)
+}
+
+/// Generates the instruction `xvslei.d xd, xj, si5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslei.d, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslei_d<const IMM_S5: i32>(a: v4i64) -> v4i64 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lasx_xvslei_d(a, IMM_S5)
+}
+
+/// Generates the instruction `xvsle.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsle.bu))]
+pub unsafe fn lasx_xvsle_bu(a: v32u8, b: v32u8) -> v32i8 {
+    __lasx_xvsle_bu(a, b)
+}
+
+/// Generates the instruction `xvsle.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsle.hu))]
+pub unsafe fn lasx_xvsle_hu(a: v16u16, b: v16u16) -> v16i16 {
+    __lasx_xvsle_hu(a, b)
+}
+
+/// Generates the instruction `xvsle.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsle.wu))]
+pub unsafe fn lasx_xvsle_wu(a: v8u32, b: v8u32) -> v8i32 {
+    __lasx_xvsle_wu(a, b)
+}
+
+/// Generates the instruction `xvsle.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsle.du))]
+pub unsafe fn lasx_xvsle_du(a: v4u64, b: v4u64) -> v4i64 {
+    __lasx_xvsle_du(a, b)
+}
+
+/// Generates the instruction `xvslei.bu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslei.bu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslei_bu<const IMM5: u32>(a: v32u8) -> v32i8 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvslei_bu(a, IMM5)
+}
+
+/// Generates the instruction `xvslei.hu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslei.hu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslei_hu<const IMM5: u32>(a: v16u16) -> v16i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvslei_hu(a, IMM5)
+}
+
+/// Generates the instruction `xvslei.wu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslei.wu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslei_wu<const IMM5: u32>(a: v8u32) -> v8i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvslei_wu(a, IMM5)
+}
+
+/// Generates the instruction `xvslei.du xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvslei.du, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvslei_du<const IMM5: u32>(a: v4u64) -> v4i64 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvslei_du(a, IMM5)
+}
+
+/// Generates the instruction `xvsat.b xd, xj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsat.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsat_b<const IMM3: u32>(a: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvsat_b(a, IMM3)
+}
+
+/// Generates the instruction `xvsat.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsat.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsat_h<const IMM4: u32>(a: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvsat_h(a, IMM4)
+}
+
+/// Generates the instruction `xvsat.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsat.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsat_w<const IMM5: u32>(a: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvsat_w(a, IMM5)
+}
+
+/// Generates the instruction `xvsat.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsat.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsat_d<const IMM6: u32>(a: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvsat_d(a, IMM6)
+}
+
+/// Generates the instruction `xvsat.bu xd, xj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsat.bu, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsat_bu<const IMM3: u32>(a: v32u8) -> v32u8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvsat_bu(a, IMM3)
+}
+
+/// Generates the instruction `xvsat.hu xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsat.hu, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsat_hu<const IMM4: u32>(a: v16u16) -> v16u16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvsat_hu(a, IMM4)
+}
+
+/// Generates the instruction `xvsat.wu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsat.wu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsat_wu<const IMM5: u32>(a: v8u32) -> v8u32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvsat_wu(a, IMM5)
+}
+
+/// Generates the instruction `xvsat.du xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsat.du, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsat_du<const IMM6: u32>(a: v4u64) -> v4u64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvsat_du(a, IMM6)
+}
+
+/// Generates the instruction `xvadda.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvadda.b))]
+pub unsafe fn lasx_xvadda_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvadda_b(a, b)
+}
+
+/// Generates the instruction `xvadda.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvadda.h))]
+pub unsafe fn lasx_xvadda_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvadda_h(a, b)
+}
+
+/// Generates the instruction `xvadda.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvadda.w))]
+pub unsafe fn lasx_xvadda_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvadda_w(a, b)
+}
+
+/// Generates the instruction `xvadda.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvadda.d))]
+pub unsafe fn lasx_xvadda_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvadda_d(a, b)
+}
+
+/// Generates the instruction `xvsadd.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsadd.b))]
+pub unsafe fn lasx_xvsadd_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvsadd_b(a, b)
+}
+
+/// Generates the instruction `xvsadd.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsadd.h))]
+pub unsafe fn lasx_xvsadd_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvsadd_h(a, b)
+}
+
+/// Generates the instruction `xvsadd.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsadd.w))]
+pub unsafe fn lasx_xvsadd_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvsadd_w(a, b)
+}
+
+/// Generates the instruction `xvsadd.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsadd.d))]
+pub unsafe fn lasx_xvsadd_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvsadd_d(a, b)
+}
+
+/// Generates the instruction `xvsadd.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsadd.bu))]
+pub unsafe fn lasx_xvsadd_bu(a: v32u8, b: v32u8) -> v32u8 {
+    __lasx_xvsadd_bu(a, b)
+}
+
+/// Generates the instruction `xvsadd.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsadd.hu))]
+pub unsafe fn lasx_xvsadd_hu(a: v16u16, b: v16u16) -> v16u16 {
+    __lasx_xvsadd_hu(a, b)
+}
+
+/// Generates the instruction `xvsadd.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsadd.wu))]
+pub unsafe fn lasx_xvsadd_wu(a: v8u32, b: v8u32) -> v8u32 {
+    __lasx_xvsadd_wu(a, b)
+}
+
+/// Generates the instruction `xvsadd.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsadd.du))]
+pub unsafe fn lasx_xvsadd_du(a: v4u64, b: v4u64) -> v4u64 {
+    __lasx_xvsadd_du(a, b)
+}
+
+/// Generates the instruction `xvavg.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvavg.b))]
+pub unsafe fn lasx_xvavg_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvavg_b(a, b)
+}
+
+/// Generates the instruction `xvavg.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvavg.h))]
+pub unsafe fn lasx_xvavg_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvavg_h(a, b)
+}
+
+/// Generates the instruction `xvavg.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvavg.w))]
+pub unsafe fn lasx_xvavg_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvavg_w(a, b)
+}
+
+/// Generates the instruction `xvavg.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvavg.d))]
+pub unsafe fn lasx_xvavg_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvavg_d(a, b)
+}
+
+/// Generates the instruction `xvavg.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvavg.bu))]
+pub unsafe fn lasx_xvavg_bu(a: v32u8, b: v32u8) -> v32u8 {
+    __lasx_xvavg_bu(a, b)
+}
+
+/// Generates the instruction `xvavg.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvavg.hu))]
+pub unsafe fn lasx_xvavg_hu(a: v16u16, b: v16u16) -> v16u16 {
+    __lasx_xvavg_hu(a, b)
+}
+
+/// Generates the instruction `xvavg.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvavg.wu))]
+pub unsafe fn lasx_xvavg_wu(a: v8u32, b: v8u32) -> v8u32 {
+    __lasx_xvavg_wu(a, b)
+}
+
+/// Generates the instruction `xvavg.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvavg.du))]
+pub unsafe fn lasx_xvavg_du(a: v4u64, b: v4u64) -> v4u64 {
+    __lasx_xvavg_du(a, b)
+}
+
+/// Generates the instruction `xvavgr.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvavgr.b))]
+pub unsafe fn lasx_xvavgr_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvavgr_b(a, b)
+}
+
+/// Generates the instruction `xvavgr.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvavgr.h))]
+pub unsafe fn lasx_xvavgr_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvavgr_h(a, b)
+}
+
+/// Generates the instruction `xvavgr.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvavgr.w))]
+pub unsafe fn lasx_xvavgr_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvavgr_w(a, b)
+}
+
+/// Generates the instruction `xvavgr.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvavgr.d))]
+pub unsafe fn lasx_xvavgr_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvavgr_d(a, b)
+}
+
+/// Generates the instruction `xvavgr.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvavgr.bu))]
+pub unsafe fn lasx_xvavgr_bu(a: v32u8, b: v32u8) -> v32u8 {
+    __lasx_xvavgr_bu(a, b)
+}
+
+/// Generates the instruction `xvavgr.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvavgr.hu))]
+pub unsafe fn lasx_xvavgr_hu(a: v16u16, b: v16u16) -> v16u16 {
+    __lasx_xvavgr_hu(a, b)
+}
+
+/// Generates the instruction `xvavgr.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvavgr.wu))]
+pub unsafe fn lasx_xvavgr_wu(a: v8u32, b: v8u32) -> v8u32 {
+    __lasx_xvavgr_wu(a, b)
+}
+
+/// Generates the instruction `xvavgr.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvavgr.du))]
+pub unsafe fn lasx_xvavgr_du(a: v4u64, b: v4u64) -> v4u64 {
+    __lasx_xvavgr_du(a, b)
+}
+
+/// Generates the instruction `xvssub.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssub.b))]
+pub unsafe fn lasx_xvssub_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvssub_b(a, b)
+}
+
+/// Generates the instruction `xvssub.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssub.h))]
+pub unsafe fn lasx_xvssub_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvssub_h(a, b)
+}
+
+/// Generates the instruction `xvssub.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssub.w))]
+pub unsafe fn lasx_xvssub_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvssub_w(a, b)
+}
+
+/// Generates the instruction `xvssub.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssub.d))]
+pub unsafe fn lasx_xvssub_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvssub_d(a, b)
+}
+
+/// Generates the instruction `xvssub.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssub.bu))]
+pub unsafe fn lasx_xvssub_bu(a: v32u8, b: v32u8) -> v32u8 {
+    __lasx_xvssub_bu(a, b)
+}
+
+/// Generates the instruction `xvssub.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssub.hu))]
+pub unsafe fn lasx_xvssub_hu(a: v16u16, b: v16u16) -> v16u16 {
+    __lasx_xvssub_hu(a, b)
+}
+
+/// Generates the instruction `xvssub.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssub.wu))]
+pub unsafe fn lasx_xvssub_wu(a: v8u32, b: v8u32) -> v8u32 {
+    __lasx_xvssub_wu(a, b)
+}
+
+/// Generates the instruction `xvssub.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssub.du))]
+pub unsafe fn lasx_xvssub_du(a: v4u64, b: v4u64) -> v4u64 {
+    __lasx_xvssub_du(a, b)
+}
+
+/// Generates the instruction `xvabsd.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvabsd.b))]
+pub unsafe fn lasx_xvabsd_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvabsd_b(a, b)
+}
+
+/// Generates the instruction `xvabsd.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvabsd.h))]
+pub unsafe fn lasx_xvabsd_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvabsd_h(a, b)
+}
+
+/// Generates the instruction `xvabsd.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvabsd.w))]
+pub unsafe fn lasx_xvabsd_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvabsd_w(a, b)
+}
+
+/// Generates the instruction `xvabsd.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvabsd.d))]
+pub unsafe fn lasx_xvabsd_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvabsd_d(a, b)
+}
+
+/// Generates the instruction `xvabsd.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvabsd.bu))]
+pub unsafe fn lasx_xvabsd_bu(a: v32u8, b: v32u8) -> v32u8 {
+    __lasx_xvabsd_bu(a, b)
+}
+
+/// Generates the instruction `xvabsd.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvabsd.hu))]
+pub unsafe fn lasx_xvabsd_hu(a: v16u16, b: v16u16) -> v16u16 {
+    __lasx_xvabsd_hu(a, b)
+}
+
+/// Generates the instruction `xvabsd.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvabsd.wu))]
+pub unsafe fn lasx_xvabsd_wu(a: v8u32, b: v8u32) -> v8u32 {
+    __lasx_xvabsd_wu(a, b)
+}
+
+/// Generates the instruction `xvabsd.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvabsd.du))]
+pub unsafe fn lasx_xvabsd_du(a: v4u64, b: v4u64) -> v4u64 {
+    __lasx_xvabsd_du(a, b)
+}
+
+/// Generates the instruction `xvmul.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmul.b))]
+pub unsafe fn lasx_xvmul_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvmul_b(a, b)
+}
+
+/// Generates the instruction `xvmul.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmul.h))]
+pub unsafe fn lasx_xvmul_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvmul_h(a, b)
+}
+
+/// Generates the instruction `xvmul.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmul.w))]
+pub unsafe fn lasx_xvmul_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvmul_w(a, b)
+}
+
+/// Generates the instruction `xvmul.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmul.d))]
+pub unsafe fn lasx_xvmul_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvmul_d(a, b)
+}
+
+/// Generates the instruction `xvmadd.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmadd.b))]
+pub unsafe fn lasx_xvmadd_b(a: v32i8, b: v32i8, c: v32i8) -> v32i8 {
+    __lasx_xvmadd_b(a, b, c)
+}
+
+/// Generates the instruction `xvmadd.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmadd.h))]
+pub unsafe fn lasx_xvmadd_h(a: v16i16, b: v16i16, c: v16i16) -> v16i16 {
+    __lasx_xvmadd_h(a, b, c)
+}
+
+/// Generates the instruction `xvmadd.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmadd.w))]
+pub unsafe fn lasx_xvmadd_w(a: v8i32, b: v8i32, c: v8i32) -> v8i32 {
+    __lasx_xvmadd_w(a, b, c)
+}
+
+/// Generates the instruction `xvmadd.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmadd.d))]
+pub unsafe fn lasx_xvmadd_d(a: v4i64, b: v4i64, c: v4i64) -> v4i64 {
+    __lasx_xvmadd_d(a, b, c)
+}
+
+/// Generates the instruction `xvmsub.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmsub.b))]
+pub unsafe fn lasx_xvmsub_b(a: v32i8, b: v32i8, c: v32i8) -> v32i8 {
+    __lasx_xvmsub_b(a, b, c)
+}
+
+/// Generates the instruction `xvmsub.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmsub.h))]
+pub unsafe fn lasx_xvmsub_h(a: v16i16, b: v16i16, c: v16i16) -> v16i16 {
+    __lasx_xvmsub_h(a, b, c)
+}
+
+/// Generates the instruction `xvmsub.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmsub.w))]
+pub unsafe fn lasx_xvmsub_w(a: v8i32, b: v8i32, c: v8i32) -> v8i32 {
+    __lasx_xvmsub_w(a, b, c)
+}
+
+/// Generates the instruction `xvmsub.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmsub.d))]
+pub unsafe fn lasx_xvmsub_d(a: v4i64, b: v4i64, c: v4i64) -> v4i64 {
+    __lasx_xvmsub_d(a, b, c)
+}
+
+/// Generates the instruction `xvdiv.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvdiv.b))]
+pub unsafe fn lasx_xvdiv_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvdiv_b(a, b)
+}
+
+/// Generates the instruction `xvdiv.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvdiv.h))]
+pub unsafe fn lasx_xvdiv_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvdiv_h(a, b)
+}
+
+/// Generates the instruction `xvdiv.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvdiv.w))]
+pub unsafe fn lasx_xvdiv_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvdiv_w(a, b)
+}
+
+/// Generates the instruction `xvdiv.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvdiv.d))]
+pub unsafe fn lasx_xvdiv_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvdiv_d(a, b)
+}
+
+/// Generates the instruction `xvdiv.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvdiv.bu))]
+pub unsafe fn lasx_xvdiv_bu(a: v32u8, b: v32u8) -> v32u8 {
+    __lasx_xvdiv_bu(a, b)
+}
+
+/// Generates the instruction `xvdiv.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvdiv.hu))]
+pub unsafe fn lasx_xvdiv_hu(a: v16u16, b: v16u16) -> v16u16 {
+    __lasx_xvdiv_hu(a, b)
+}
+
+/// Generates the instruction `xvdiv.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvdiv.wu))]
+pub unsafe fn lasx_xvdiv_wu(a: v8u32, b: v8u32) -> v8u32 {
+    __lasx_xvdiv_wu(a, b)
+}
+
+/// Generates the instruction `xvdiv.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvdiv.du))]
+pub unsafe fn lasx_xvdiv_du(a: v4u64, b: v4u64) -> v4u64 {
+    __lasx_xvdiv_du(a, b)
+}
+
+/// Generates the instruction `xvhaddw.h.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvhaddw.h.b))]
+pub unsafe fn lasx_xvhaddw_h_b(a: v32i8, b: v32i8) -> v16i16 {
+    __lasx_xvhaddw_h_b(a, b)
+}
+
+/// Generates the instruction `xvhaddw.w.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvhaddw.w.h))]
+pub unsafe fn lasx_xvhaddw_w_h(a: v16i16, b: v16i16) -> v8i32 {
+    __lasx_xvhaddw_w_h(a, b)
+}
+
+/// Generates the instruction `xvhaddw.d.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvhaddw.d.w))]
+pub unsafe fn lasx_xvhaddw_d_w(a: v8i32, b: v8i32) -> v4i64 {
+    __lasx_xvhaddw_d_w(a, b)
+}
+
+/// Generates the instruction `xvhaddw.hu.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvhaddw.hu.bu))]
+pub unsafe fn lasx_xvhaddw_hu_bu(a: v32u8, b: v32u8) -> v16u16 {
+    __lasx_xvhaddw_hu_bu(a, b)
+}
+
+/// Generates the instruction `xvhaddw.wu.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvhaddw.wu.hu))]
+pub unsafe fn lasx_xvhaddw_wu_hu(a: v16u16, b: v16u16) -> v8u32 {
+    __lasx_xvhaddw_wu_hu(a, b)
+}
+
+/// Generates the instruction `xvhaddw.du.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvhaddw.du.wu))]
+pub unsafe fn lasx_xvhaddw_du_wu(a: v8u32, b: v8u32) -> v4u64 {
+    __lasx_xvhaddw_du_wu(a, b)
+}
+
+/// Generates the instruction `xvhsubw.h.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvhsubw.h.b))]
+pub unsafe fn lasx_xvhsubw_h_b(a: v32i8, b: v32i8) -> v16i16 {
+    __lasx_xvhsubw_h_b(a, b)
+}
+
+/// Generates the instruction `xvhsubw.w.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvhsubw.w.h))]
+pub unsafe fn lasx_xvhsubw_w_h(a: v16i16, b: v16i16) -> v8i32 {
+    __lasx_xvhsubw_w_h(a, b)
+}
+
+/// Generates the instruction `xvhsubw.d.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvhsubw.d.w))]
+pub unsafe fn lasx_xvhsubw_d_w(a: v8i32, b: v8i32) -> v4i64 {
+    __lasx_xvhsubw_d_w(a, b)
+}
+
+/// Generates the instruction `xvhsubw.hu.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvhsubw.hu.bu))]
+pub unsafe fn lasx_xvhsubw_hu_bu(a: v32u8, b: v32u8) -> v16i16 {
+    __lasx_xvhsubw_hu_bu(a, b)
+}
+
+/// Generates the instruction `xvhsubw.wu.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvhsubw.wu.hu))]
+pub unsafe fn lasx_xvhsubw_wu_hu(a: v16u16, b: v16u16) -> v8i32 {
+    __lasx_xvhsubw_wu_hu(a, b)
+}
+
+/// Generates the instruction `xvhsubw.du.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvhsubw.du.wu))]
+pub unsafe fn lasx_xvhsubw_du_wu(a: v8u32, b: v8u32) -> v4i64 {
+    __lasx_xvhsubw_du_wu(a, b)
+}
+
+/// Generates the instruction `xvmod.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmod.b))]
+pub unsafe fn lasx_xvmod_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvmod_b(a, b)
+}
+
+/// Generates the instruction `xvmod.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmod.h))]
+pub unsafe fn lasx_xvmod_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvmod_h(a, b)
+}
+
+/// Generates the instruction `xvmod.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmod.w))]
+pub unsafe fn lasx_xvmod_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvmod_w(a, b)
+}
+
+/// Generates the instruction `xvmod.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmod.d))]
+pub unsafe fn lasx_xvmod_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvmod_d(a, b)
+}
+
+/// Generates the instruction `xvmod.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmod.bu))]
+pub unsafe fn lasx_xvmod_bu(a: v32u8, b: v32u8) -> v32u8 {
+    __lasx_xvmod_bu(a, b)
+}
+
+/// Generates the instruction `xvmod.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmod.hu))]
+pub unsafe fn lasx_xvmod_hu(a: v16u16, b: v16u16) -> v16u16 {
+    __lasx_xvmod_hu(a, b)
+}
+
+/// Generates the instruction `xvmod.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmod.wu))]
+pub unsafe fn lasx_xvmod_wu(a: v8u32, b: v8u32) -> v8u32 {
+    __lasx_xvmod_wu(a, b)
+}
+
+/// Generates the instruction `xvmod.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmod.du))]
+pub unsafe fn lasx_xvmod_du(a: v4u64, b: v4u64) -> v4u64 {
+    __lasx_xvmod_du(a, b)
+}
+
+/// Generates the instruction `xvrepl128vei.b xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvrepl128vei.b, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvrepl128vei_b<const IMM4: u32>(a: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvrepl128vei_b(a, IMM4)
+}
+
+/// Generates the instruction `xvrepl128vei.h xd, xj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvrepl128vei.h, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvrepl128vei_h<const IMM3: u32>(a: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvrepl128vei_h(a, IMM3)
+}
+
+/// Generates the instruction `xvrepl128vei.w xd, xj, ui2`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvrepl128vei.w, IMM2 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvrepl128vei_w<const IMM2: u32>(a: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM2, 2);
+    __lasx_xvrepl128vei_w(a, IMM2)
+}
+
+/// Generates the instruction `xvrepl128vei.d xd, xj, ui1`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvrepl128vei.d, IMM1 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvrepl128vei_d<const IMM1: u32>(a: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM1, 1);
+    __lasx_xvrepl128vei_d(a, IMM1)
+}
+
+/// Generates the instruction `xvpickev.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpickev.b))]
+pub unsafe fn lasx_xvpickev_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvpickev_b(a, b)
+}
+
+/// Generates the instruction `xvpickev.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpickev.h))]
+pub unsafe fn lasx_xvpickev_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvpickev_h(a, b)
+}
+
+/// Generates the instruction `xvpickev.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpickev.w))]
+pub unsafe fn lasx_xvpickev_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvpickev_w(a, b)
+}
+
+/// Generates the instruction `xvpickev.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpickev.d))]
+pub unsafe fn lasx_xvpickev_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvpickev_d(a, b)
+}
+
+/// Generates the instruction `xvpickod.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpickod.b))]
+pub unsafe fn lasx_xvpickod_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvpickod_b(a, b)
+}
+
+/// Generates the instruction `xvpickod.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpickod.h))]
+pub unsafe fn lasx_xvpickod_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvpickod_h(a, b)
+}
+
+/// Generates the instruction `xvpickod.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpickod.w))]
+pub unsafe fn lasx_xvpickod_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvpickod_w(a, b)
+}
+
+/// Generates the instruction `xvpickod.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpickod.d))]
+pub unsafe fn lasx_xvpickod_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvpickod_d(a, b)
+}
+
+/// Generates the instruction `xvilvh.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvilvh.b))]
+pub unsafe fn lasx_xvilvh_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvilvh_b(a, b)
+}
+
+/// Generates the instruction `xvilvh.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvilvh.h))]
+pub unsafe fn lasx_xvilvh_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvilvh_h(a, b)
+}
+
+/// Generates the instruction `xvilvh.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvilvh.w))]
+pub unsafe fn lasx_xvilvh_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvilvh_w(a, b)
+}
+
+/// Generates the instruction `xvilvh.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvilvh.d))]
+pub unsafe fn lasx_xvilvh_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvilvh_d(a, b)
+}
+
+/// Generates the instruction `xvilvl.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvilvl.b))]
+pub unsafe fn lasx_xvilvl_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvilvl_b(a, b)
+}
+
+/// Generates the instruction `xvilvl.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvilvl.h))]
+pub unsafe fn lasx_xvilvl_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvilvl_h(a, b)
+}
+
+/// Generates the instruction `xvilvl.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvilvl.w))]
+pub unsafe fn lasx_xvilvl_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvilvl_w(a, b)
+}
+
+/// Generates the instruction `xvilvl.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvilvl.d))]
+pub unsafe fn lasx_xvilvl_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvilvl_d(a, b)
+}
+
+/// Generates the instruction `xvpackev.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpackev.b))]
+pub unsafe fn lasx_xvpackev_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvpackev_b(a, b)
+}
+
+/// Generates the instruction `xvpackev.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpackev.h))]
+pub unsafe fn lasx_xvpackev_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvpackev_h(a, b)
+}
+
+/// Generates the instruction `xvpackev.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpackev.w))]
+pub unsafe fn lasx_xvpackev_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvpackev_w(a, b)
+}
+
+/// Generates the instruction `xvpackev.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpackev.d))]
+pub unsafe fn lasx_xvpackev_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvpackev_d(a, b)
+}
+
+/// Generates the instruction `xvpackod.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpackod.b))]
+pub unsafe fn lasx_xvpackod_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvpackod_b(a, b)
+}
+
+/// Generates the instruction `xvpackod.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpackod.h))]
+pub unsafe fn lasx_xvpackod_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvpackod_h(a, b)
+}
+
+/// Generates the instruction `xvpackod.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpackod.w))]
+pub unsafe fn lasx_xvpackod_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvpackod_w(a, b)
+}
+
+/// Generates the instruction `xvpackod.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpackod.d))]
+pub unsafe fn lasx_xvpackod_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvpackod_d(a, b)
+}
+
+/// Generates the instruction `xvshuf.b xd, xj, xk, xa`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvshuf.b))]
+pub unsafe fn lasx_xvshuf_b(a: v32i8, b: v32i8, c: v32i8) -> v32i8 {
+    __lasx_xvshuf_b(a, b, c)
+}
+
+/// Generates the instruction `xvshuf.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvshuf.h))]
+pub unsafe fn lasx_xvshuf_h(a: v16i16, b: v16i16, c: v16i16) -> v16i16 {
+    __lasx_xvshuf_h(a, b, c)
+}
+
+/// Generates the instruction `xvshuf.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvshuf.w))]
+pub unsafe fn lasx_xvshuf_w(a: v8i32, b: v8i32, c: v8i32) -> v8i32 {
+    __lasx_xvshuf_w(a, b, c)
+}
+
+/// Generates the instruction `xvshuf.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvshuf.d))]
+pub unsafe fn lasx_xvshuf_d(a: v4i64, b: v4i64, c: v4i64) -> v4i64 {
+    __lasx_xvshuf_d(a, b, c)
+}
+
+/// Generates the instruction `xvand.v xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvand.v))]
+pub unsafe fn lasx_xvand_v(a: v32u8, b: v32u8) -> v32u8 {
+    __lasx_xvand_v(a, b)
+}
+
+/// Generates the instruction `xvandi.b xd, xj, ui8`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvandi.b, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvandi_b<const IMM8: u32>(a: v32u8) -> v32u8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lasx_xvandi_b(a, IMM8)
+}
+
+/// Generates the instruction `xvor.v xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvor.v))]
+pub unsafe fn lasx_xvor_v(a: v32u8, b: v32u8) -> v32u8 {
+    __lasx_xvor_v(a, b)
+}
+
+/// Generates the instruction `xvori.b xd, xj, ui8`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvori.b, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvori_b<const IMM8: u32>(a: v32u8) -> v32u8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lasx_xvori_b(a, IMM8)
+}
+
+/// Generates the instruction `xvnor.v xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvnor.v))]
+pub unsafe fn lasx_xvnor_v(a: v32u8, b: v32u8) -> v32u8 {
+    __lasx_xvnor_v(a, b)
+}
+
+/// Generates the instruction `xvnori.b xd, xj, ui8`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvnori.b, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvnori_b<const IMM8: u32>(a: v32u8) -> v32u8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lasx_xvnori_b(a, IMM8)
+}
+
+/// Generates the instruction `xvxor.v xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvxor.v))]
+pub unsafe fn lasx_xvxor_v(a: v32u8, b: v32u8) -> v32u8 {
+    __lasx_xvxor_v(a, b)
+}
+
+/// Generates the instruction `xvxori.b xd, xj, ui8`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvxori.b, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvxori_b<const IMM8: u32>(a: v32u8) -> v32u8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lasx_xvxori_b(a, IMM8)
+}
+
+/// Generates the instruction `xvbitsel.v xd, xj, xk, xa`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitsel.v))]
+pub unsafe fn lasx_xvbitsel_v(a: v32u8, b: v32u8, c: v32u8) -> v32u8 {
+    __lasx_xvbitsel_v(a, b, c)
+}
+
+/// Generates the instruction `xvbitseli.b xd, xj, ui8`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbitseli.b, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvbitseli_b<const IMM8: u32>(a: v32u8, b: v32u8) -> v32u8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lasx_xvbitseli_b(a, b, IMM8)
+}
+
+/// Generates the instruction `xvshuf4i.b xd, xj, ui8`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvshuf4i.b, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvshuf4i_b<const IMM8: u32>(a: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lasx_xvshuf4i_b(a, IMM8)
+}
+
+/// Generates the instruction `xvshuf4i.h xd, xj, ui8`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvshuf4i.h, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvshuf4i_h<const IMM8: u32>(a: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lasx_xvshuf4i_h(a, IMM8)
+}
+
+/// Generates the instruction `xvshuf4i.w xd, xj, ui8`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvshuf4i.w, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvshuf4i_w<const IMM8: u32>(a: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lasx_xvshuf4i_w(a, IMM8)
+}
+
+/// Generates the instruction `xvreplgr2vr.b xd, rj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvreplgr2vr.b))]
+pub unsafe fn lasx_xvreplgr2vr_b(a: i32) -> v32i8 {
+    __lasx_xvreplgr2vr_b(a)
+}
+
+/// Generates the instruction `xvreplgr2vr.h xd, rj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvreplgr2vr.h))]
+pub unsafe fn lasx_xvreplgr2vr_h(a: i32) -> v16i16 {
+    __lasx_xvreplgr2vr_h(a)
+}
+
+/// Generates the instruction `xvreplgr2vr.w xd, rj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvreplgr2vr.w))]
+pub unsafe fn lasx_xvreplgr2vr_w(a: i32) -> v8i32 {
+    __lasx_xvreplgr2vr_w(a)
+}
+
+/// Generates the instruction `xvreplgr2vr.d xd, rj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvreplgr2vr.d))]
+pub unsafe fn lasx_xvreplgr2vr_d(a: i64) -> v4i64 {
+    __lasx_xvreplgr2vr_d(a)
+}
+
+/// Generates the instruction `xvpcnt.b xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpcnt.b))]
+pub unsafe fn lasx_xvpcnt_b(a: v32i8) -> v32i8 {
+    __lasx_xvpcnt_b(a)
+}
+
+/// Generates the instruction `xvpcnt.h xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpcnt.h))]
+pub unsafe fn lasx_xvpcnt_h(a: v16i16) -> v16i16 {
+    __lasx_xvpcnt_h(a)
+}
+
+/// Generates the instruction `xvpcnt.w xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpcnt.w))]
+pub unsafe fn lasx_xvpcnt_w(a: v8i32) -> v8i32 {
+    __lasx_xvpcnt_w(a)
+}
+
+/// Generates the instruction `xvpcnt.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpcnt.d))]
+pub unsafe fn lasx_xvpcnt_d(a: v4i64) -> v4i64 {
+    __lasx_xvpcnt_d(a)
+}
+
+/// Generates the instruction `xvclo.b xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvclo.b))]
+pub unsafe fn lasx_xvclo_b(a: v32i8) -> v32i8 {
+    __lasx_xvclo_b(a)
+}
+
+/// Generates the instruction `xvclo.h xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvclo.h))]
+pub unsafe fn lasx_xvclo_h(a: v16i16) -> v16i16 {
+    __lasx_xvclo_h(a)
+}
+
+/// Generates the instruction `xvclo.w xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvclo.w))]
+pub unsafe fn lasx_xvclo_w(a: v8i32) -> v8i32 {
+    __lasx_xvclo_w(a)
+}
+
+/// Generates the instruction `xvclo.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvclo.d))]
+pub unsafe fn lasx_xvclo_d(a: v4i64) -> v4i64 {
+    __lasx_xvclo_d(a)
+}
+
+/// Generates the instruction `xvclz.b xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvclz.b))]
+pub unsafe fn lasx_xvclz_b(a: v32i8) -> v32i8 {
+    __lasx_xvclz_b(a)
+}
+
+/// Generates the instruction `xvclz.h xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvclz.h))]
+pub unsafe fn lasx_xvclz_h(a: v16i16) -> v16i16 {
+    __lasx_xvclz_h(a)
+}
+
+/// Generates the instruction `xvclz.w xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvclz.w))]
+pub unsafe fn lasx_xvclz_w(a: v8i32) -> v8i32 {
+    __lasx_xvclz_w(a)
+}
+
+/// Generates the instruction `xvclz.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvclz.d))]
+pub unsafe fn lasx_xvclz_d(a: v4i64) -> v4i64 {
+    __lasx_xvclz_d(a)
+}
+
+/// Generates the instruction `xvfadd.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfadd.s))]
+pub unsafe fn lasx_xvfadd_s(a: v8f32, b: v8f32) -> v8f32 {
+    __lasx_xvfadd_s(a, b)
+}
+
+/// Generates the instruction `xvfadd.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfadd.d))]
+pub unsafe fn lasx_xvfadd_d(a: v4f64, b: v4f64) -> v4f64 {
+    __lasx_xvfadd_d(a, b)
+}
+
+/// Generates the instruction `xvfsub.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfsub.s))]
+pub unsafe fn lasx_xvfsub_s(a: v8f32, b: v8f32) -> v8f32 {
+    __lasx_xvfsub_s(a, b)
+}
+
+/// Generates the instruction `xvfsub.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfsub.d))]
+pub unsafe fn lasx_xvfsub_d(a: v4f64, b: v4f64) -> v4f64 {
+    __lasx_xvfsub_d(a, b)
+}
+
+/// Generates the instruction `xvfmul.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfmul.s))]
+pub unsafe fn lasx_xvfmul_s(a: v8f32, b: v8f32) -> v8f32 {
+    __lasx_xvfmul_s(a, b)
+}
+
+/// Generates the instruction `xvfmul.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfmul.d))]
+pub unsafe fn lasx_xvfmul_d(a: v4f64, b: v4f64) -> v4f64 {
+    __lasx_xvfmul_d(a, b)
+}
+
+/// Generates the instruction `xvfdiv.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfdiv.s))]
+pub unsafe fn lasx_xvfdiv_s(a: v8f32, b: v8f32) -> v8f32 {
+    __lasx_xvfdiv_s(a, b)
+}
+
+/// Generates the instruction `xvfdiv.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfdiv.d))]
+pub unsafe fn lasx_xvfdiv_d(a: v4f64, b: v4f64) -> v4f64 {
+    __lasx_xvfdiv_d(a, b)
+}
+
+/// Generates the instruction `xvfcvt.h.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcvt.h.s))]
+pub unsafe fn lasx_xvfcvt_h_s(a: v8f32, b: v8f32) -> v16i16 {
+    __lasx_xvfcvt_h_s(a, b)
+}
+
+/// Generates the instruction `xvfcvt.s.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcvt.s.d))]
+pub unsafe fn lasx_xvfcvt_s_d(a: v4f64, b: v4f64) -> v8f32 {
+    __lasx_xvfcvt_s_d(a, b)
+}
+
+/// Generates the instruction `xvfmin.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfmin.s))]
+pub unsafe fn lasx_xvfmin_s(a: v8f32, b: v8f32) -> v8f32 {
+    __lasx_xvfmin_s(a, b)
+}
+
+/// Generates the instruction `xvfmin.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfmin.d))]
+pub unsafe fn lasx_xvfmin_d(a: v4f64, b: v4f64) -> v4f64 {
+    __lasx_xvfmin_d(a, b)
+}
+
+/// Generates the instruction `xvfmina.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfmina.s))]
+pub unsafe fn lasx_xvfmina_s(a: v8f32, b: v8f32) -> v8f32 {
+    __lasx_xvfmina_s(a, b)
+}
+
+/// Generates the instruction `xvfmina.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfmina.d))]
+pub unsafe fn lasx_xvfmina_d(a: v4f64, b: v4f64) -> v4f64 {
+    __lasx_xvfmina_d(a, b)
+}
+
+/// Generates the instruction `xvfmax.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfmax.s))]
+pub unsafe fn lasx_xvfmax_s(a: v8f32, b: v8f32) -> v8f32 {
+    __lasx_xvfmax_s(a, b)
+}
+
+/// Generates the instruction `xvfmax.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfmax.d))]
+pub unsafe fn lasx_xvfmax_d(a: v4f64, b: v4f64) -> v4f64 {
+    __lasx_xvfmax_d(a, b)
+}
+
+/// Generates the instruction `xvfmaxa.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfmaxa.s))]
+pub unsafe fn lasx_xvfmaxa_s(a: v8f32, b: v8f32) -> v8f32 {
+    __lasx_xvfmaxa_s(a, b)
+}
+
+/// Generates the instruction `xvfmaxa.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfmaxa.d))]
+pub unsafe fn lasx_xvfmaxa_d(a: v4f64, b: v4f64) -> v4f64 {
+    __lasx_xvfmaxa_d(a, b)
+}
+
+/// Generates the instruction `xvfclass.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfclass.s))]
+pub unsafe fn lasx_xvfclass_s(a: v8f32) -> v8i32 {
+    __lasx_xvfclass_s(a)
+}
+
+/// Generates the instruction `xvfclass.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfclass.d))]
+pub unsafe fn lasx_xvfclass_d(a: v4f64) -> v4i64 {
+    __lasx_xvfclass_d(a)
+}
+
+/// Generates the instruction `xvfsqrt.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfsqrt.s))]
+pub unsafe fn lasx_xvfsqrt_s(a: v8f32) -> v8f32 {
+    __lasx_xvfsqrt_s(a)
+}
+
+/// Generates the instruction `xvfsqrt.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfsqrt.d))]
+pub unsafe fn lasx_xvfsqrt_d(a: v4f64) -> v4f64 {
+    __lasx_xvfsqrt_d(a)
+}
+
+/// Generates the instruction `xvfrecip.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfrecip.s))]
+pub unsafe fn lasx_xvfrecip_s(a: v8f32) -> v8f32 {
+    __lasx_xvfrecip_s(a)
+}
+
+/// Generates the instruction `xvfrecip.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfrecip.d))]
+pub unsafe fn lasx_xvfrecip_d(a: v4f64) -> v4f64 {
+    __lasx_xvfrecip_d(a)
+}
+
+/// Generates the instruction `xvfrint.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfrint.s))]
+pub unsafe fn lasx_xvfrint_s(a: v8f32) -> v8f32 {
+    __lasx_xvfrint_s(a)
+}
+
+/// Generates the instruction `xvfrint.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfrint.d))]
+pub unsafe fn lasx_xvfrint_d(a: v4f64) -> v4f64 {
+    __lasx_xvfrint_d(a)
+}
+
+/// Generates the instruction `xvfrsqrt.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfrsqrt.s))]
+pub unsafe fn lasx_xvfrsqrt_s(a: v8f32) -> v8f32 {
+    __lasx_xvfrsqrt_s(a)
+}
+
+/// Generates the instruction `xvfrsqrt.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfrsqrt.d))]
+pub unsafe fn lasx_xvfrsqrt_d(a: v4f64) -> v4f64 {
+    __lasx_xvfrsqrt_d(a)
+}
+
+/// Generates the instruction `xvflogb.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvflogb.s))]
+pub unsafe fn lasx_xvflogb_s(a: v8f32) -> v8f32 {
+    __lasx_xvflogb_s(a)
+}
+
+/// Generates the instruction `xvflogb.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvflogb.d))]
+pub unsafe fn lasx_xvflogb_d(a: v4f64) -> v4f64 {
+    __lasx_xvflogb_d(a)
+}
+
+/// Generates the instruction `xvfcvth.s.h xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcvth.s.h))]
+pub unsafe fn lasx_xvfcvth_s_h(a: v16i16) -> v8f32 {
+    __lasx_xvfcvth_s_h(a)
+}
+
+/// Generates the instruction `xvfcvth.d.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcvth.d.s))]
+pub unsafe fn lasx_xvfcvth_d_s(a: v8f32) -> v4f64 {
+    __lasx_xvfcvth_d_s(a)
+}
+
+/// Generates the instruction `xvfcvtl.s.h xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcvtl.s.h))]
+pub unsafe fn lasx_xvfcvtl_s_h(a: v16i16) -> v8f32 {
+    __lasx_xvfcvtl_s_h(a)
+}
+
+/// Generates the instruction `xvfcvtl.d.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcvtl.d.s))]
+pub unsafe fn lasx_xvfcvtl_d_s(a: v8f32) -> v4f64 {
+    __lasx_xvfcvtl_d_s(a)
+}
+
+/// Generates the instruction `xvftint.w.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftint.w.s))]
+pub unsafe fn lasx_xvftint_w_s(a: v8f32) -> v8i32 {
+    __lasx_xvftint_w_s(a)
+}
+
+/// Generates the instruction `xvftint.l.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftint.l.d))]
+pub unsafe fn lasx_xvftint_l_d(a: v4f64) -> v4i64 {
+    __lasx_xvftint_l_d(a)
+}
+
+/// Generates the instruction `xvftint.wu.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftint.wu.s))]
+pub unsafe fn lasx_xvftint_wu_s(a: v8f32) -> v8u32 {
+    __lasx_xvftint_wu_s(a)
+}
+
+/// Generates the instruction `xvftint.lu.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftint.lu.d))]
+pub unsafe fn lasx_xvftint_lu_d(a: v4f64) -> v4u64 {
+    __lasx_xvftint_lu_d(a)
+}
+
+/// Generates the instruction `xvftintrz.w.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrz.w.s))]
+pub unsafe fn lasx_xvftintrz_w_s(a: v8f32) -> v8i32 {
+    __lasx_xvftintrz_w_s(a)
+}
+
+/// Generates the instruction `xvftintrz.l.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrz.l.d))]
+pub unsafe fn lasx_xvftintrz_l_d(a: v4f64) -> v4i64 {
+    __lasx_xvftintrz_l_d(a)
+}
+
+/// Generates the instruction `xvftintrz.wu.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrz.wu.s))]
+pub unsafe fn lasx_xvftintrz_wu_s(a: v8f32) -> v8u32 {
+    __lasx_xvftintrz_wu_s(a)
+}
+
+/// Generates the instruction `xvftintrz.lu.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrz.lu.d))]
+pub unsafe fn lasx_xvftintrz_lu_d(a: v4f64) -> v4u64 {
+    __lasx_xvftintrz_lu_d(a)
+}
+
+/// Generates the instruction `xvffint.s.w xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvffint.s.w))]
+pub unsafe fn lasx_xvffint_s_w(a: v8i32) -> v8f32 {
+    __lasx_xvffint_s_w(a)
+}
+
+/// Generates the instruction `xvffint.d.l xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvffint.d.l))]
+pub unsafe fn lasx_xvffint_d_l(a: v4i64) -> v4f64 {
+    __lasx_xvffint_d_l(a)
+}
+
+/// Generates the instruction `xvffint.s.wu xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvffint.s.wu))]
+pub unsafe fn lasx_xvffint_s_wu(a: v8u32) -> v8f32 {
+    __lasx_xvffint_s_wu(a)
+}
+
+/// Generates the instruction `xvffint.d.lu xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvffint.d.lu))]
+pub unsafe fn lasx_xvffint_d_lu(a: v4u64) -> v4f64 {
+    __lasx_xvffint_d_lu(a)
+}
+
+/// Generates the instruction `xvreplve.b xd, xj, rk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvreplve.b))]
+pub unsafe fn lasx_xvreplve_b(a: v32i8, b: i32) -> v32i8 {
+    __lasx_xvreplve_b(a, b)
+}
+
+/// Generates the instruction `xvreplve.h xd, xj, rk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvreplve.h))]
+pub unsafe fn lasx_xvreplve_h(a: v16i16, b: i32) -> v16i16 {
+    __lasx_xvreplve_h(a, b)
+}
+
+/// Generates the instruction `xvreplve.w xd, xj, rk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvreplve.w))]
+pub unsafe fn lasx_xvreplve_w(a: v8i32, b: i32) -> v8i32 {
+    __lasx_xvreplve_w(a, b)
+}
+
+/// Generates the instruction `xvreplve.d xd, xj, rk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvreplve.d))]
+pub unsafe fn lasx_xvreplve_d(a: v4i64, b: i32) -> v4i64 {
+    __lasx_xvreplve_d(a, b)
+}
+
+/// Generates the instruction `xvpermi.w xd, xj, ui8`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpermi.w, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvpermi_w<const IMM8: u32>(a: v8i32, b: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lasx_xvpermi_w(a, b, IMM8)
+}
+
+/// Generates the instruction `xvandn.v xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvandn.v))]
+pub unsafe fn lasx_xvandn_v(a: v32u8, b: v32u8) -> v32u8 {
+    __lasx_xvandn_v(a, b)
+}
+
+/// Generates the instruction `xvneg.b xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvneg.b))]
+pub unsafe fn lasx_xvneg_b(a: v32i8) -> v32i8 {
+    __lasx_xvneg_b(a)
+}
+
+/// Generates the instruction `xvneg.h xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvneg.h))]
+pub unsafe fn lasx_xvneg_h(a: v16i16) -> v16i16 {
+    __lasx_xvneg_h(a)
+}
+
+/// Generates the instruction `xvneg.w xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvneg.w))]
+pub unsafe fn lasx_xvneg_w(a: v8i32) -> v8i32 {
+    __lasx_xvneg_w(a)
+}
+
+/// Generates the instruction `xvneg.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvneg.d))]
+pub unsafe fn lasx_xvneg_d(a: v4i64) -> v4i64 {
+    __lasx_xvneg_d(a)
+}
+
+/// Generates the instruction `xvmuh.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmuh.b))]
+pub unsafe fn lasx_xvmuh_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvmuh_b(a, b)
+}
+
+/// Generates the instruction `xvmuh.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmuh.h))]
+pub unsafe fn lasx_xvmuh_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvmuh_h(a, b)
+}
+
+/// Generates the instruction `xvmuh.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmuh.w))]
+pub unsafe fn lasx_xvmuh_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvmuh_w(a, b)
+}
+
+/// Generates the instruction `xvmuh.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmuh.d))]
+pub unsafe fn lasx_xvmuh_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvmuh_d(a, b)
+}
+
+/// Generates the instruction `xvmuh.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmuh.bu))]
+pub unsafe fn lasx_xvmuh_bu(a: v32u8, b: v32u8) -> v32u8 {
+    __lasx_xvmuh_bu(a, b)
+}
+
+/// Generates the instruction `xvmuh.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmuh.hu))]
+pub unsafe fn lasx_xvmuh_hu(a: v16u16, b: v16u16) -> v16u16 {
+    __lasx_xvmuh_hu(a, b)
+}
+
+/// Generates the instruction `xvmuh.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmuh.wu))]
+pub unsafe fn lasx_xvmuh_wu(a: v8u32, b: v8u32) -> v8u32 {
+    __lasx_xvmuh_wu(a, b)
+}
+
+/// Generates the instruction `xvmuh.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmuh.du))]
+pub unsafe fn lasx_xvmuh_du(a: v4u64, b: v4u64) -> v4u64 {
+    __lasx_xvmuh_du(a, b)
+}
+
+/// Generates the instruction `xvsllwil.h.b xd, xj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsllwil.h.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsllwil_h_b<const IMM3: u32>(a: v32i8) -> v16i16 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvsllwil_h_b(a, IMM3)
+}
+
+/// Generates the instruction `xvsllwil.w.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsllwil.w.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsllwil_w_h<const IMM4: u32>(a: v16i16) -> v8i32 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvsllwil_w_h(a, IMM4)
+}
+
+/// Generates the instruction `xvsllwil.d.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsllwil.d.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsllwil_d_w<const IMM5: u32>(a: v8i32) -> v4i64 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvsllwil_d_w(a, IMM5)
+}
+
+/// Generates the instruction `xvsllwil.hu.bu xd, xj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsllwil.hu.bu, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsllwil_hu_bu<const IMM3: u32>(a: v32u8) -> v16u16 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvsllwil_hu_bu(a, IMM3)
+}
+
+/// Generates the instruction `xvsllwil.wu.hu xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsllwil.wu.hu, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsllwil_wu_hu<const IMM4: u32>(a: v16u16) -> v8u32 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvsllwil_wu_hu(a, IMM4)
+}
+
+/// Generates the instruction `xvsllwil.du.wu xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsllwil.du.wu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvsllwil_du_wu<const IMM5: u32>(a: v8u32) -> v4u64 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvsllwil_du_wu(a, IMM5)
+}
+
+/// Generates the instruction `xvsran.b.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsran.b.h))]
+pub unsafe fn lasx_xvsran_b_h(a: v16i16, b: v16i16) -> v32i8 {
+    __lasx_xvsran_b_h(a, b)
+}
+
+/// Generates the instruction `xvsran.h.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsran.h.w))]
+pub unsafe fn lasx_xvsran_h_w(a: v8i32, b: v8i32) -> v16i16 {
+    __lasx_xvsran_h_w(a, b)
+}
+
+/// Generates the instruction `xvsran.w.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsran.w.d))]
+pub unsafe fn lasx_xvsran_w_d(a: v4i64, b: v4i64) -> v8i32 {
+    __lasx_xvsran_w_d(a, b)
+}
+
+/// Generates the instruction `xvssran.b.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssran.b.h))]
+pub unsafe fn lasx_xvssran_b_h(a: v16i16, b: v16i16) -> v32i8 {
+    __lasx_xvssran_b_h(a, b)
+}
+
+/// Generates the instruction `xvssran.h.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssran.h.w))]
+pub unsafe fn lasx_xvssran_h_w(a: v8i32, b: v8i32) -> v16i16 {
+    __lasx_xvssran_h_w(a, b)
+}
+
+/// Generates the instruction `xvssran.w.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssran.w.d))]
+pub unsafe fn lasx_xvssran_w_d(a: v4i64, b: v4i64) -> v8i32 {
+    __lasx_xvssran_w_d(a, b)
+}
+
+/// Generates the instruction `xvssran.bu.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssran.bu.h))]
+pub unsafe fn lasx_xvssran_bu_h(a: v16u16, b: v16u16) -> v32u8 {
+    __lasx_xvssran_bu_h(a, b)
+}
+
+/// Generates the instruction `xvssran.hu.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssran.hu.w))]
+pub unsafe fn lasx_xvssran_hu_w(a: v8u32, b: v8u32) -> v16u16 {
+    __lasx_xvssran_hu_w(a, b)
+}
+
+/// Generates the instruction `xvssran.wu.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssran.wu.d))]
+pub unsafe fn lasx_xvssran_wu_d(a: v4u64, b: v4u64) -> v8u32 {
+    __lasx_xvssran_wu_d(a, b)
+}
+
+/// Generates the instruction `xvsrarn.b.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrarn.b.h))]
+pub unsafe fn lasx_xvsrarn_b_h(a: v16i16, b: v16i16) -> v32i8 {
+    __lasx_xvsrarn_b_h(a, b)
+}
+
+/// Generates the instruction `xvsrarn.h.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrarn.h.w))]
+pub unsafe fn lasx_xvsrarn_h_w(a: v8i32, b: v8i32) -> v16i16 {
+    __lasx_xvsrarn_h_w(a, b)
+}
+
+/// Generates the instruction `xvsrarn.w.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrarn.w.d))]
+pub unsafe fn lasx_xvsrarn_w_d(a: v4i64, b: v4i64) -> v8i32 {
+    __lasx_xvsrarn_w_d(a, b)
+}
+
+/// Generates the instruction `xvssrarn.b.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrarn.b.h))]
+pub unsafe fn lasx_xvssrarn_b_h(a: v16i16, b: v16i16) -> v32i8 {
+    __lasx_xvssrarn_b_h(a, b)
+}
+
+/// Generates the instruction `xvssrarn.h.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrarn.h.w))]
+pub unsafe fn lasx_xvssrarn_h_w(a: v8i32, b: v8i32) -> v16i16 {
+    __lasx_xvssrarn_h_w(a, b)
+}
+
+/// Generates the instruction `xvssrarn.w.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrarn.w.d))]
+pub unsafe fn lasx_xvssrarn_w_d(a: v4i64, b: v4i64) -> v8i32 {
+    __lasx_xvssrarn_w_d(a, b)
+}
+
+/// Generates the instruction `xvssrarn.bu.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrarn.bu.h))]
+pub unsafe fn lasx_xvssrarn_bu_h(a: v16u16, b: v16u16) -> v32u8 {
+    __lasx_xvssrarn_bu_h(a, b)
+}
+
+/// Generates the instruction `xvssrarn.hu.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrarn.hu.w))]
+pub unsafe fn lasx_xvssrarn_hu_w(a: v8u32, b: v8u32) -> v16u16 {
+    __lasx_xvssrarn_hu_w(a, b)
+}
+
+/// Generates the instruction `xvssrarn.wu.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrarn.wu.d))]
+pub unsafe fn lasx_xvssrarn_wu_d(a: v4u64, b: v4u64) -> v8u32 {
+    __lasx_xvssrarn_wu_d(a, b)
+}
+
+/// Generates the instruction `xvsrln.b.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrln.b.h))]
+pub unsafe fn lasx_xvsrln_b_h(a: v16i16, b: v16i16) -> v32i8 {
+    __lasx_xvsrln_b_h(a, b)
+}
+
+/// Generates the instruction `xvsrln.h.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrln.h.w))]
+pub unsafe fn lasx_xvsrln_h_w(a: v8i32, b: v8i32) -> v16i16 {
+    __lasx_xvsrln_h_w(a, b)
+}
+
+/// Generates the instruction `xvsrln.w.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrln.w.d))]
+pub unsafe fn lasx_xvsrln_w_d(a: v4i64, b: v4i64) -> v8i32 {
+    __lasx_xvsrln_w_d(a, b)
+}
+
+/// Generates the instruction `xvssrln.bu.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrln.bu.h))]
+pub unsafe fn lasx_xvssrln_bu_h(a: v16u16, b: v16u16) -> v32u8 {
+    __lasx_xvssrln_bu_h(a, b)
+}
+
+/// Generates the instruction `xvssrln.hu.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrln.hu.w))]
+pub unsafe fn lasx_xvssrln_hu_w(a: v8u32, b: v8u32) -> v16u16 {
+    __lasx_xvssrln_hu_w(a, b)
+}
+
+/// Generates the instruction `xvssrln.wu.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrln.wu.d))]
+pub unsafe fn lasx_xvssrln_wu_d(a: v4u64, b: v4u64) -> v8u32 {
+    __lasx_xvssrln_wu_d(a, b)
+}
+
+/// Generates the instruction `xvsrlrn.b.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrlrn.b.h))]
+pub unsafe fn lasx_xvsrlrn_b_h(a: v16i16, b: v16i16) -> v32i8 {
+    __lasx_xvsrlrn_b_h(a, b)
+}
+
+/// Generates the instruction `xvsrlrn.h.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrlrn.h.w))]
+pub unsafe fn lasx_xvsrlrn_h_w(a: v8i32, b: v8i32) -> v16i16 {
+    __lasx_xvsrlrn_h_w(a, b)
+}
+
+/// Generates the instruction `xvsrlrn.w.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrlrn.w.d))]
+pub unsafe fn lasx_xvsrlrn_w_d(a: v4i64, b: v4i64) -> v8i32 {
+    __lasx_xvsrlrn_w_d(a, b)
+}
+
+/// Generates the instruction `xvssrlrn.bu.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlrn.bu.h))]
+pub unsafe fn lasx_xvssrlrn_bu_h(a: v16u16, b: v16u16) -> v32u8 {
+    __lasx_xvssrlrn_bu_h(a, b)
+}
+
+/// Generates the instruction `xvssrlrn.hu.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlrn.hu.w))]
+pub unsafe fn lasx_xvssrlrn_hu_w(a: v8u32, b: v8u32) -> v16u16 {
+    __lasx_xvssrlrn_hu_w(a, b)
+}
+
+/// Generates the instruction `xvssrlrn.wu.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlrn.wu.d))]
+pub unsafe fn lasx_xvssrlrn_wu_d(a: v4u64, b: v4u64) -> v8u32 {
+    __lasx_xvssrlrn_wu_d(a, b)
+}
+
+/// Generates the instruction `xvfrstpi.b xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfrstpi.b, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvfrstpi_b<const IMM5: u32>(a: v32i8, b: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvfrstpi_b(a, b, IMM5)
+}
+
+/// Generates the instruction `xvfrstpi.h xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfrstpi.h, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvfrstpi_h<const IMM5: u32>(a: v16i16, b: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvfrstpi_h(a, b, IMM5)
+}
+
+/// Generates the instruction `xvfrstp.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfrstp.b))]
+pub unsafe fn lasx_xvfrstp_b(a: v32i8, b: v32i8, c: v32i8) -> v32i8 {
+    __lasx_xvfrstp_b(a, b, c)
+}
+
+/// Generates the instruction `xvfrstp.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfrstp.h))]
+pub unsafe fn lasx_xvfrstp_h(a: v16i16, b: v16i16, c: v16i16) -> v16i16 {
+    __lasx_xvfrstp_h(a, b, c)
+}
+
+/// Generates the instruction `xvshuf4i.d xd, xj, ui8`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvshuf4i.d, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvshuf4i_d<const IMM8: u32>(a: v4i64, b: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lasx_xvshuf4i_d(a, b, IMM8)
+}
+
+/// Generates the instruction `xvbsrl.v xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbsrl.v, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvbsrl_v<const IMM5: u32>(a: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvbsrl_v(a, IMM5)
+}
+
+/// Generates the instruction `xvbsll.v xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvbsll.v, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvbsll_v<const IMM5: u32>(a: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvbsll_v(a, IMM5)
+}
+
+/// Generates the instruction `xvextrins.b xd, xj, ui8`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvextrins.b, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvextrins_b<const IMM8: u32>(a: v32i8, b: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lasx_xvextrins_b(a, b, IMM8)
+}
+
+/// Generates the instruction `xvextrins.h xd, xj, ui8`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvextrins.h, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvextrins_h<const IMM8: u32>(a: v16i16, b: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lasx_xvextrins_h(a, b, IMM8)
+}
+
+/// Generates the instruction `xvextrins.w xd, xj, ui8`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvextrins.w, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvextrins_w<const IMM8: u32>(a: v8i32, b: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lasx_xvextrins_w(a, b, IMM8)
+}
+
+/// Generates the instruction `xvextrins.d xd, xj, ui8`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvextrins.d, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvextrins_d<const IMM8: u32>(a: v4i64, b: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lasx_xvextrins_d(a, b, IMM8)
+}
+
+/// Generates the instruction `xvmskltz.b xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmskltz.b))]
+pub unsafe fn lasx_xvmskltz_b(a: v32i8) -> v32i8 {
+    __lasx_xvmskltz_b(a)
+}
+
+/// Generates the instruction `xvmskltz.h xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmskltz.h))]
+pub unsafe fn lasx_xvmskltz_h(a: v16i16) -> v16i16 {
+    __lasx_xvmskltz_h(a)
+}
+
+/// Generates the instruction `xvmskltz.w xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmskltz.w))]
+pub unsafe fn lasx_xvmskltz_w(a: v8i32) -> v8i32 {
+    __lasx_xvmskltz_w(a)
+}
+
+/// Generates the instruction `xvmskltz.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmskltz.d))]
+pub unsafe fn lasx_xvmskltz_d(a: v4i64) -> v4i64 {
+    __lasx_xvmskltz_d(a)
+}
+
+/// Generates the instruction `xvsigncov.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsigncov.b))]
+pub unsafe fn lasx_xvsigncov_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvsigncov_b(a, b)
+}
+
+/// Generates the instruction `xvsigncov.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsigncov.h))]
+pub unsafe fn lasx_xvsigncov_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvsigncov_h(a, b)
+}
+
+/// Generates the instruction `xvsigncov.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsigncov.w))]
+pub unsafe fn lasx_xvsigncov_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvsigncov_w(a, b)
+}
+
+/// Generates the instruction `xvsigncov.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsigncov.d))]
+pub unsafe fn lasx_xvsigncov_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvsigncov_d(a, b)
+}
+
+/// Generates the instruction `xvfmadd.s xd, xj, xk, xa`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfmadd.s))]
+pub unsafe fn lasx_xvfmadd_s(a: v8f32, b: v8f32, c: v8f32) -> v8f32 {
+    __lasx_xvfmadd_s(a, b, c)
+}
+
+/// Generates the instruction `xvfmadd.d xd, xj, xk, xa`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfmadd.d))]
+pub unsafe fn lasx_xvfmadd_d(a: v4f64, b: v4f64, c: v4f64) -> v4f64 {
+    __lasx_xvfmadd_d(a, b, c)
+}
+
+/// Generates the instruction `xvfmsub.s xd, xj, xk, xa`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfmsub.s))]
+pub unsafe fn lasx_xvfmsub_s(a: v8f32, b: v8f32, c: v8f32) -> v8f32 {
+    __lasx_xvfmsub_s(a, b, c)
+}
+
+/// Generates the instruction `xvfmsub.d xd, xj, xk, xa`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfmsub.d))]
+pub unsafe fn lasx_xvfmsub_d(a: v4f64, b: v4f64, c: v4f64) -> v4f64 {
+    __lasx_xvfmsub_d(a, b, c)
+}
+
+/// Generates the instruction `xvfnmadd.s xd, xj, xk, xa`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfnmadd.s))]
+pub unsafe fn lasx_xvfnmadd_s(a: v8f32, b: v8f32, c: v8f32) -> v8f32 {
+    __lasx_xvfnmadd_s(a, b, c)
+}
+
+/// Generates the instruction `xvfnmadd.d xd, xj, xk, xa`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfnmadd.d))]
+pub unsafe fn lasx_xvfnmadd_d(a: v4f64, b: v4f64, c: v4f64) -> v4f64 {
+    __lasx_xvfnmadd_d(a, b, c)
+}
+
+/// Generates the instruction `xvfnmsub.s xd, xj, xk, xa`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfnmsub.s))]
+pub unsafe fn lasx_xvfnmsub_s(a: v8f32, b: v8f32, c: v8f32) -> v8f32 {
+    __lasx_xvfnmsub_s(a, b, c)
+}
+
+/// Generates the instruction `xvfnmsub.d xd, xj, xk, xa`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfnmsub.d))]
+pub unsafe fn lasx_xvfnmsub_d(a: v4f64, b: v4f64, c: v4f64) -> v4f64 {
+    __lasx_xvfnmsub_d(a, b, c)
+}
+
+/// Generates the instruction `xvftintrne.w.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrne.w.s))]
+pub unsafe fn lasx_xvftintrne_w_s(a: v8f32) -> v8i32 {
+    __lasx_xvftintrne_w_s(a)
+}
+
+/// Generates the instruction `xvftintrne.l.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrne.l.d))]
+pub unsafe fn lasx_xvftintrne_l_d(a: v4f64) -> v4i64 {
+    __lasx_xvftintrne_l_d(a)
+}
+
+/// Generates the instruction `xvftintrp.w.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrp.w.s))]
+pub unsafe fn lasx_xvftintrp_w_s(a: v8f32) -> v8i32 {
+    __lasx_xvftintrp_w_s(a)
+}
+
+/// Generates the instruction `xvftintrp.l.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrp.l.d))]
+pub unsafe fn lasx_xvftintrp_l_d(a: v4f64) -> v4i64 {
+    __lasx_xvftintrp_l_d(a)
+}
+
+/// Generates the instruction `xvftintrm.w.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrm.w.s))]
+pub unsafe fn lasx_xvftintrm_w_s(a: v8f32) -> v8i32 {
+    __lasx_xvftintrm_w_s(a)
+}
+
+/// Generates the instruction `xvftintrm.l.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrm.l.d))]
+pub unsafe fn lasx_xvftintrm_l_d(a: v4f64) -> v4i64 {
+    __lasx_xvftintrm_l_d(a)
+}
+
+/// Generates the instruction `xvftint.w.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftint.w.d))]
+pub unsafe fn lasx_xvftint_w_d(a: v4f64, b: v4f64) -> v8i32 {
+    __lasx_xvftint_w_d(a, b)
+}
+
+/// Generates the instruction `xvffint.s.l xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvffint.s.l))]
+pub unsafe fn lasx_xvffint_s_l(a: v4i64, b: v4i64) -> v8f32 {
+    __lasx_xvffint_s_l(a, b)
+}
+
+/// Generates the instruction `xvftintrz.w.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrz.w.d))]
+pub unsafe fn lasx_xvftintrz_w_d(a: v4f64, b: v4f64) -> v8i32 {
+    __lasx_xvftintrz_w_d(a, b)
+}
+
+/// Generates the instruction `xvftintrp.w.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrp.w.d))]
+pub unsafe fn lasx_xvftintrp_w_d(a: v4f64, b: v4f64) -> v8i32 {
+    __lasx_xvftintrp_w_d(a, b)
+}
+
+/// Generates the instruction `xvftintrm.w.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrm.w.d))]
+pub unsafe fn lasx_xvftintrm_w_d(a: v4f64, b: v4f64) -> v8i32 {
+    __lasx_xvftintrm_w_d(a, b)
+}
+
+/// Generates the instruction `xvftintrne.w.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrne.w.d))]
+pub unsafe fn lasx_xvftintrne_w_d(a: v4f64, b: v4f64) -> v8i32 {
+    __lasx_xvftintrne_w_d(a, b)
+}
+
+/// Generates the instruction `xvftinth.l.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftinth.l.s))]
+pub unsafe fn lasx_xvftinth_l_s(a: v8f32) -> v4i64 {
+    __lasx_xvftinth_l_s(a)
+}
+
+/// Generates the instruction `xvftintl.l.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintl.l.s))]
+pub unsafe fn lasx_xvftintl_l_s(a: v8f32) -> v4i64 {
+    __lasx_xvftintl_l_s(a)
+}
+
+/// Generates the instruction `xvffinth.d.w xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvffinth.d.w))]
+pub unsafe fn lasx_xvffinth_d_w(a: v8i32) -> v4f64 {
+    __lasx_xvffinth_d_w(a)
+}
+
+/// Generates the instruction `xvffintl.d.w xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvffintl.d.w))]
+pub unsafe fn lasx_xvffintl_d_w(a: v8i32) -> v4f64 {
+    __lasx_xvffintl_d_w(a)
+}
+
+/// Generates the instruction `xvftintrzh.l.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrzh.l.s))]
+pub unsafe fn lasx_xvftintrzh_l_s(a: v8f32) -> v4i64 {
+    __lasx_xvftintrzh_l_s(a)
+}
+
+/// Generates the instruction `xvftintrzl.l.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrzl.l.s))]
+pub unsafe fn lasx_xvftintrzl_l_s(a: v8f32) -> v4i64 {
+    __lasx_xvftintrzl_l_s(a)
+}
+
+/// Generates the instruction `xvftintrph.l.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrph.l.s))]
+pub unsafe fn lasx_xvftintrph_l_s(a: v8f32) -> v4i64 {
+    __lasx_xvftintrph_l_s(a)
+}
+
+/// Generates the instruction `xvftintrpl.l.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrpl.l.s))]
+pub unsafe fn lasx_xvftintrpl_l_s(a: v8f32) -> v4i64 {
+    __lasx_xvftintrpl_l_s(a)
+}
+
+/// Generates the instruction `xvftintrmh.l.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrmh.l.s))]
+pub unsafe fn lasx_xvftintrmh_l_s(a: v8f32) -> v4i64 {
+    __lasx_xvftintrmh_l_s(a)
+}
+
+/// Generates the instruction `xvftintrml.l.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrml.l.s))]
+pub unsafe fn lasx_xvftintrml_l_s(a: v8f32) -> v4i64 {
+    __lasx_xvftintrml_l_s(a)
+}
+
+/// Generates the instruction `xvftintrneh.l.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrneh.l.s))]
+pub unsafe fn lasx_xvftintrneh_l_s(a: v8f32) -> v4i64 {
+    __lasx_xvftintrneh_l_s(a)
+}
+
+/// Generates the instruction `xvftintrnel.l.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvftintrnel.l.s))]
+pub unsafe fn lasx_xvftintrnel_l_s(a: v8f32) -> v4i64 {
+    __lasx_xvftintrnel_l_s(a)
+}
+
+/// Generates the instruction `xvfrintrne.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfrintrne.s))]
+pub unsafe fn lasx_xvfrintrne_s(a: v8f32) -> v8f32 {
+    __lasx_xvfrintrne_s(a)
+}
+
+/// Generates the instruction `xvfrintrne.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfrintrne.d))]
+pub unsafe fn lasx_xvfrintrne_d(a: v4f64) -> v4f64 {
+    __lasx_xvfrintrne_d(a)
+}
+
+/// Generates the instruction `xvfrintrz.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfrintrz.s))]
+pub unsafe fn lasx_xvfrintrz_s(a: v8f32) -> v8f32 {
+    __lasx_xvfrintrz_s(a)
+}
+
+/// Generates the instruction `xvfrintrz.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfrintrz.d))]
+pub unsafe fn lasx_xvfrintrz_d(a: v4f64) -> v4f64 {
+    __lasx_xvfrintrz_d(a)
+}
+
+/// Generates the instruction `xvfrintrp.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfrintrp.s))]
+pub unsafe fn lasx_xvfrintrp_s(a: v8f32) -> v8f32 {
+    __lasx_xvfrintrp_s(a)
+}
+
+/// Generates the instruction `xvfrintrp.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfrintrp.d))]
+pub unsafe fn lasx_xvfrintrp_d(a: v4f64) -> v4f64 {
+    __lasx_xvfrintrp_d(a)
+}
+
+/// Generates the instruction `xvfrintrm.s xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfrintrm.s))]
+pub unsafe fn lasx_xvfrintrm_s(a: v8f32) -> v8f32 {
+    __lasx_xvfrintrm_s(a)
+}
+
+/// Generates the instruction `xvfrintrm.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfrintrm.d))]
+pub unsafe fn lasx_xvfrintrm_d(a: v4f64) -> v4f64 {
+    __lasx_xvfrintrm_d(a)
+}
+
+/// Generates the instruction `xvld xd, rj, si12`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvld, IMM_S12 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvld<const IMM_S12: i32>(mem_addr: *const i8) -> v32i8 {
+    static_assert_simm_bits!(IMM_S12, 12);
+    __lasx_xvld(mem_addr, IMM_S12)
+}
+
+/// Generates the instruction `xvst xd, rj, si12`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvst, IMM_S12 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvst<const IMM_S12: i32>(a: v32i8, mem_addr: *mut i8) {
+    static_assert_simm_bits!(IMM_S12, 12);
+    __lasx_xvst(a, mem_addr, IMM_S12)
+}
+
+/// Generates the instruction `xvstelm.b xd, rj, si8, idx`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvstelm.b, IMM_S8 = 1, IMM5 = 1))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn lasx_xvstelm_b<const IMM_S8: i32, const IMM5: u32>(a: v32i8, mem_addr: *mut i8) {
+    static_assert_simm_bits!(IMM_S8, 8);
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvstelm_b(a, mem_addr, IMM_S8, IMM5)
+}
+
+/// Generates the instruction `xvstelm.h xd, rj, si8, idx`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvstelm.h, IMM_S8 = 1, IMM4 = 1))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn lasx_xvstelm_h<const IMM_S8: i32, const IMM4: u32>(a: v16i16, mem_addr: *mut i8) {
+    static_assert_simm_bits!(IMM_S8, 8);
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvstelm_h(a, mem_addr, IMM_S8, IMM4)
+}
+
+/// Generates the instruction `xvstelm.w xd, rj, si8, idx`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvstelm.w, IMM_S8 = 1, IMM3 = 1))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn lasx_xvstelm_w<const IMM_S8: i32, const IMM3: u32>(a: v8i32, mem_addr: *mut i8) {
+    static_assert_simm_bits!(IMM_S8, 8);
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvstelm_w(a, mem_addr, IMM_S8, IMM3)
+}
+
+/// Generates the instruction `xvstelm.d xd, rj, si8, idx`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvstelm.d, IMM_S8 = 1, IMM2 = 1))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn lasx_xvstelm_d<const IMM_S8: i32, const IMM2: u32>(a: v4i64, mem_addr: *mut i8) {
+    static_assert_simm_bits!(IMM_S8, 8);
+    static_assert_uimm_bits!(IMM2, 2);
+    __lasx_xvstelm_d(a, mem_addr, IMM_S8, IMM2)
+}
+
+/// Generates the instruction `xvinsve0.w xd, xj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvinsve0.w, IMM3 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvinsve0_w<const IMM3: u32>(a: v8i32, b: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvinsve0_w(a, b, IMM3)
+}
+
+/// Generates the instruction `xvinsve0.d xd, xj, ui2`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvinsve0.d, IMM2 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvinsve0_d<const IMM2: u32>(a: v4i64, b: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM2, 2);
+    __lasx_xvinsve0_d(a, b, IMM2)
+}
+
+/// Generates the instruction `xvpickve.w xd, xj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpickve.w, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvpickve_w<const IMM3: u32>(a: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvpickve_w(a, IMM3)
+}
+
+/// Generates the instruction `xvpickve.d xd, xj, ui2`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpickve.d, IMM2 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvpickve_d<const IMM2: u32>(a: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM2, 2);
+    __lasx_xvpickve_d(a, IMM2)
+}
+
+/// Generates the instruction `xvssrlrn.b.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlrn.b.h))]
+pub unsafe fn lasx_xvssrlrn_b_h(a: v16i16, b: v16i16) -> v32i8 {
+    __lasx_xvssrlrn_b_h(a, b)
+}
+
+/// Generates the instruction `xvssrlrn.h.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlrn.h.w))]
+pub unsafe fn lasx_xvssrlrn_h_w(a: v8i32, b: v8i32) -> v16i16 {
+    __lasx_xvssrlrn_h_w(a, b)
+}
+
+/// Generates the instruction `xvssrlrn.w.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlrn.w.d))]
+pub unsafe fn lasx_xvssrlrn_w_d(a: v4i64, b: v4i64) -> v8i32 {
+    __lasx_xvssrlrn_w_d(a, b)
+}
+
+/// Generates the instruction `xvssrln.b.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrln.b.h))]
+pub unsafe fn lasx_xvssrln_b_h(a: v16i16, b: v16i16) -> v32i8 {
+    __lasx_xvssrln_b_h(a, b)
+}
+
+/// Generates the instruction `xvssrln.h.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrln.h.w))]
+pub unsafe fn lasx_xvssrln_h_w(a: v8i32, b: v8i32) -> v16i16 {
+    __lasx_xvssrln_h_w(a, b)
+}
+
+/// Generates the instruction `xvssrln.w.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrln.w.d))]
+pub unsafe fn lasx_xvssrln_w_d(a: v4i64, b: v4i64) -> v8i32 {
+    __lasx_xvssrln_w_d(a, b)
+}
+
+/// Generates the instruction `xvorn.v xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvorn.v))]
+pub unsafe fn lasx_xvorn_v(a: v32u8, b: v32u8) -> v32u8 {
+    __lasx_xvorn_v(a, b)
+}
+
+/// Generates the instruction `xvldi xd, i13`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvldi, IMM_S13 = 1))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn lasx_xvldi<const IMM_S13: i32>() -> v4i64 {
+    static_assert_simm_bits!(IMM_S13, 13);
+    __lasx_xvldi(IMM_S13)
+}
+
+/// Generates the instruction `xvldx xd, rj, rk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvldx))]
+pub unsafe fn lasx_xvldx(mem_addr: *const i8, a: i64) -> v32i8 {
+    __lasx_xvldx(mem_addr, a)
+}
+
+/// Generates the instruction `xvstx xd, rj, rk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvstx))]
+pub unsafe fn lasx_xvstx(a: v32i8, mem_addr: *mut i8, b: i64) {
+    __lasx_xvstx(a, mem_addr, b)
+}
+
+/// Generates the instruction `xvextl.qu.du xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvextl.qu.du))]
+pub unsafe fn lasx_xvextl_qu_du(a: v4u64) -> v4u64 {
+    __lasx_xvextl_qu_du(a)
+}
+
+/// Generates the instruction `xvinsgr2vr.w xd, rj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvinsgr2vr.w, IMM3 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvinsgr2vr_w<const IMM3: u32>(a: v8i32, b: i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvinsgr2vr_w(a, b, IMM3)
+}
+
+/// Generates the instruction `xvinsgr2vr.d xd, rj, ui2`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvinsgr2vr.d, IMM2 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvinsgr2vr_d<const IMM2: u32>(a: v4i64, b: i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM2, 2);
+    __lasx_xvinsgr2vr_d(a, b, IMM2)
+}
+
+/// Generates the instruction `xvreplve0.b xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvreplve0.b))]
+pub unsafe fn lasx_xvreplve0_b(a: v32i8) -> v32i8 {
+    __lasx_xvreplve0_b(a)
+}
+
+/// Generates the instruction `xvreplve0.h xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvreplve0.h))]
+pub unsafe fn lasx_xvreplve0_h(a: v16i16) -> v16i16 {
+    __lasx_xvreplve0_h(a)
+}
+
+/// Generates the instruction `xvreplve0.w xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvreplve0.w))]
+pub unsafe fn lasx_xvreplve0_w(a: v8i32) -> v8i32 {
+    __lasx_xvreplve0_w(a)
+}
+
+/// Generates the instruction `xvreplve0.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvreplve0.d))]
+pub unsafe fn lasx_xvreplve0_d(a: v4i64) -> v4i64 {
+    __lasx_xvreplve0_d(a)
+}
+
+/// Generates the instruction `xvreplve0.q xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvreplve0.q))]
+pub unsafe fn lasx_xvreplve0_q(a: v32i8) -> v32i8 {
+    __lasx_xvreplve0_q(a)
+}
+
+/// Generates the instruction `vext2xv.h.b xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(vext2xv.h.b))]
+pub unsafe fn lasx_vext2xv_h_b(a: v32i8) -> v16i16 {
+    __lasx_vext2xv_h_b(a)
+}
+
+/// Generates the instruction `vext2xv.w.h xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(vext2xv.w.h))]
+pub unsafe fn lasx_vext2xv_w_h(a: v16i16) -> v8i32 {
+    __lasx_vext2xv_w_h(a)
+}
+
+/// Generates the instruction `vext2xv.d.w xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(vext2xv.d.w))]
+pub unsafe fn lasx_vext2xv_d_w(a: v8i32) -> v4i64 {
+    __lasx_vext2xv_d_w(a)
+}
+
+/// Generates the instruction `vext2xv.w.b xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(vext2xv.w.b))]
+pub unsafe fn lasx_vext2xv_w_b(a: v32i8) -> v8i32 {
+    __lasx_vext2xv_w_b(a)
+}
+
+/// Generates the instruction `vext2xv.d.h xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(vext2xv.d.h))]
+pub unsafe fn lasx_vext2xv_d_h(a: v16i16) -> v4i64 {
+    __lasx_vext2xv_d_h(a)
+}
+
+/// Generates the instruction `vext2xv.d.b xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(vext2xv.d.b))]
+pub unsafe fn lasx_vext2xv_d_b(a: v32i8) -> v4i64 {
+    __lasx_vext2xv_d_b(a)
+}
+
+/// Generates the instruction `vext2xv.hu.bu xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(vext2xv.hu.bu))]
+pub unsafe fn lasx_vext2xv_hu_bu(a: v32i8) -> v16i16 {
+    __lasx_vext2xv_hu_bu(a)
+}
+
+/// Generates the instruction `vext2xv.wu.hu xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(vext2xv.wu.hu))]
+pub unsafe fn lasx_vext2xv_wu_hu(a: v16i16) -> v8i32 {
+    __lasx_vext2xv_wu_hu(a)
+}
+
+/// Generates the instruction `vext2xv.du.wu xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(vext2xv.du.wu))]
+pub unsafe fn lasx_vext2xv_du_wu(a: v8i32) -> v4i64 {
+    __lasx_vext2xv_du_wu(a)
+}
+
+/// Generates the instruction `vext2xv.wu.bu xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(vext2xv.wu.bu))]
+pub unsafe fn lasx_vext2xv_wu_bu(a: v32i8) -> v8i32 {
+    __lasx_vext2xv_wu_bu(a)
+}
+
+/// Generates the instruction `vext2xv.du.hu xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(vext2xv.du.hu))]
+pub unsafe fn lasx_vext2xv_du_hu(a: v16i16) -> v4i64 {
+    __lasx_vext2xv_du_hu(a)
+}
+
+/// Generates the instruction `vext2xv.du.bu xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(vext2xv.du.bu))]
+pub unsafe fn lasx_vext2xv_du_bu(a: v32i8) -> v4i64 {
+    __lasx_vext2xv_du_bu(a)
+}
+
+/// Generates the instruction `xvpermi.q xd, xj, ui8`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpermi.q, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvpermi_q<const IMM8: u32>(a: v32i8, b: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lasx_xvpermi_q(a, b, IMM8)
+}
+
+/// Generates the instruction `xvpermi.d xd, xj, ui8`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpermi.d, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvpermi_d<const IMM8: u32>(a: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lasx_xvpermi_d(a, IMM8)
+}
+
+/// Generates the instruction `xvperm.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvperm.w))]
+pub unsafe fn lasx_xvperm_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvperm_w(a, b)
+}
+
+/// Generates the instruction `xvldrepl.b xd, rj, si12`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvldrepl.b, IMM_S12 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvldrepl_b<const IMM_S12: i32>(mem_addr: *const i8) -> v32i8 {
+    static_assert_simm_bits!(IMM_S12, 12);
+    __lasx_xvldrepl_b(mem_addr, IMM_S12)
+}
+
+/// Generates the instruction `xvldrepl.h xd, rj, si11`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvldrepl.h, IMM_S11 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvldrepl_h<const IMM_S11: i32>(mem_addr: *const i8) -> v16i16 {
+    static_assert_simm_bits!(IMM_S11, 11);
+    __lasx_xvldrepl_h(mem_addr, IMM_S11)
+}
+
+/// Generates the instruction `xvldrepl.w xd, rj, si10`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvldrepl.w, IMM_S10 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvldrepl_w<const IMM_S10: i32>(mem_addr: *const i8) -> v8i32 {
+    static_assert_simm_bits!(IMM_S10, 10);
+    __lasx_xvldrepl_w(mem_addr, IMM_S10)
+}
+
+/// Generates the instruction `xvldrepl.d xd, rj, si9`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvldrepl.d, IMM_S9 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvldrepl_d<const IMM_S9: i32>(mem_addr: *const i8) -> v4i64 {
+    static_assert_simm_bits!(IMM_S9, 9);
+    __lasx_xvldrepl_d(mem_addr, IMM_S9)
+}
+
+/// Generates the instruction `xvpickve2gr.w rd, xj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpickve2gr.w, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvpickve2gr_w<const IMM3: u32>(a: v8i32) -> i32 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvpickve2gr_w(a, IMM3)
+}
+
+/// Generates the instruction `xvpickve2gr.wu rd, xj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpickve2gr.wu, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvpickve2gr_wu<const IMM3: u32>(a: v8i32) -> u32 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvpickve2gr_wu(a, IMM3)
+}
+
+/// Generates the instruction `xvpickve2gr.d rd, xj, ui2`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpickve2gr.d, IMM2 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvpickve2gr_d<const IMM2: u32>(a: v4i64) -> i64 {
+    static_assert_uimm_bits!(IMM2, 2);
+    __lasx_xvpickve2gr_d(a, IMM2)
+}
+
+/// Generates the instruction `xvpickve2gr.du rd, xj, ui2`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpickve2gr.du, IMM2 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvpickve2gr_du<const IMM2: u32>(a: v4i64) -> u64 {
+    static_assert_uimm_bits!(IMM2, 2);
+    __lasx_xvpickve2gr_du(a, IMM2)
+}
+
+/// Generates the instruction `xvaddwev.q.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwev.q.d))]
+pub unsafe fn lasx_xvaddwev_q_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvaddwev_q_d(a, b)
+}
+
+/// Generates the instruction `xvaddwev.d.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwev.d.w))]
+pub unsafe fn lasx_xvaddwev_d_w(a: v8i32, b: v8i32) -> v4i64 {
+    __lasx_xvaddwev_d_w(a, b)
+}
+
+/// Generates the instruction `xvaddwev.w.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwev.w.h))]
+pub unsafe fn lasx_xvaddwev_w_h(a: v16i16, b: v16i16) -> v8i32 {
+    __lasx_xvaddwev_w_h(a, b)
+}
+
+/// Generates the instruction `xvaddwev.h.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwev.h.b))]
+pub unsafe fn lasx_xvaddwev_h_b(a: v32i8, b: v32i8) -> v16i16 {
+    __lasx_xvaddwev_h_b(a, b)
+}
+
+/// Generates the instruction `xvaddwev.q.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwev.q.du))]
+pub unsafe fn lasx_xvaddwev_q_du(a: v4u64, b: v4u64) -> v4i64 {
+    __lasx_xvaddwev_q_du(a, b)
+}
+
+/// Generates the instruction `xvaddwev.d.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwev.d.wu))]
+pub unsafe fn lasx_xvaddwev_d_wu(a: v8u32, b: v8u32) -> v4i64 {
+    __lasx_xvaddwev_d_wu(a, b)
+}
+
+/// Generates the instruction `xvaddwev.w.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwev.w.hu))]
+pub unsafe fn lasx_xvaddwev_w_hu(a: v16u16, b: v16u16) -> v8i32 {
+    __lasx_xvaddwev_w_hu(a, b)
+}
+
+/// Generates the instruction `xvaddwev.h.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwev.h.bu))]
+pub unsafe fn lasx_xvaddwev_h_bu(a: v32u8, b: v32u8) -> v16i16 {
+    __lasx_xvaddwev_h_bu(a, b)
+}
+
+/// Generates the instruction `xvsubwev.q.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubwev.q.d))]
+pub unsafe fn lasx_xvsubwev_q_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvsubwev_q_d(a, b)
+}
+
+/// Generates the instruction `xvsubwev.d.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubwev.d.w))]
+pub unsafe fn lasx_xvsubwev_d_w(a: v8i32, b: v8i32) -> v4i64 {
+    __lasx_xvsubwev_d_w(a, b)
+}
+
+/// Generates the instruction `xvsubwev.w.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubwev.w.h))]
+pub unsafe fn lasx_xvsubwev_w_h(a: v16i16, b: v16i16) -> v8i32 {
+    __lasx_xvsubwev_w_h(a, b)
+}
+
+/// Generates the instruction `xvsubwev.h.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubwev.h.b))]
+pub unsafe fn lasx_xvsubwev_h_b(a: v32i8, b: v32i8) -> v16i16 {
+    __lasx_xvsubwev_h_b(a, b)
+}
+
+/// Generates the instruction `xvsubwev.q.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubwev.q.du))]
+pub unsafe fn lasx_xvsubwev_q_du(a: v4u64, b: v4u64) -> v4i64 {
+    __lasx_xvsubwev_q_du(a, b)
+}
+
+/// Generates the instruction `xvsubwev.d.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubwev.d.wu))]
+pub unsafe fn lasx_xvsubwev_d_wu(a: v8u32, b: v8u32) -> v4i64 {
+    __lasx_xvsubwev_d_wu(a, b)
+}
+
+/// Generates the instruction `xvsubwev.w.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubwev.w.hu))]
+pub unsafe fn lasx_xvsubwev_w_hu(a: v16u16, b: v16u16) -> v8i32 {
+    __lasx_xvsubwev_w_hu(a, b)
+}
+
+/// Generates the instruction `xvsubwev.h.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubwev.h.bu))]
+pub unsafe fn lasx_xvsubwev_h_bu(a: v32u8, b: v32u8) -> v16i16 {
+    __lasx_xvsubwev_h_bu(a, b)
+}
+
+/// Generates the instruction `xvmulwev.q.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwev.q.d))]
+pub unsafe fn lasx_xvmulwev_q_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvmulwev_q_d(a, b)
+}
+
+/// Generates the instruction `xvmulwev.d.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwev.d.w))]
+pub unsafe fn lasx_xvmulwev_d_w(a: v8i32, b: v8i32) -> v4i64 {
+    __lasx_xvmulwev_d_w(a, b)
+}
+
+/// Generates the instruction `xvmulwev.w.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwev.w.h))]
+pub unsafe fn lasx_xvmulwev_w_h(a: v16i16, b: v16i16) -> v8i32 {
+    __lasx_xvmulwev_w_h(a, b)
+}
+
+/// Generates the instruction `xvmulwev.h.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwev.h.b))]
+pub unsafe fn lasx_xvmulwev_h_b(a: v32i8, b: v32i8) -> v16i16 {
+    __lasx_xvmulwev_h_b(a, b)
+}
+
+/// Generates the instruction `xvmulwev.q.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwev.q.du))]
+pub unsafe fn lasx_xvmulwev_q_du(a: v4u64, b: v4u64) -> v4i64 {
+    __lasx_xvmulwev_q_du(a, b)
+}
+
+/// Generates the instruction `xvmulwev.d.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwev.d.wu))]
+pub unsafe fn lasx_xvmulwev_d_wu(a: v8u32, b: v8u32) -> v4i64 {
+    __lasx_xvmulwev_d_wu(a, b)
+}
+
+/// Generates the instruction `xvmulwev.w.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwev.w.hu))]
+pub unsafe fn lasx_xvmulwev_w_hu(a: v16u16, b: v16u16) -> v8i32 {
+    __lasx_xvmulwev_w_hu(a, b)
+}
+
+/// Generates the instruction `xvmulwev.h.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwev.h.bu))]
+pub unsafe fn lasx_xvmulwev_h_bu(a: v32u8, b: v32u8) -> v16i16 {
+    __lasx_xvmulwev_h_bu(a, b)
+}
+
+/// Generates the instruction `xvaddwod.q.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwod.q.d))]
+pub unsafe fn lasx_xvaddwod_q_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvaddwod_q_d(a, b)
+}
+
+/// Generates the instruction `xvaddwod.d.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwod.d.w))]
+pub unsafe fn lasx_xvaddwod_d_w(a: v8i32, b: v8i32) -> v4i64 {
+    __lasx_xvaddwod_d_w(a, b)
+}
+
+/// Generates the instruction `xvaddwod.w.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwod.w.h))]
+pub unsafe fn lasx_xvaddwod_w_h(a: v16i16, b: v16i16) -> v8i32 {
+    __lasx_xvaddwod_w_h(a, b)
+}
+
+/// Generates the instruction `xvaddwod.h.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwod.h.b))]
+pub unsafe fn lasx_xvaddwod_h_b(a: v32i8, b: v32i8) -> v16i16 {
+    __lasx_xvaddwod_h_b(a, b)
+}
+
+/// Generates the instruction `xvaddwod.q.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwod.q.du))]
+pub unsafe fn lasx_xvaddwod_q_du(a: v4u64, b: v4u64) -> v4i64 {
+    __lasx_xvaddwod_q_du(a, b)
+}
+
+/// Generates the instruction `xvaddwod.d.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwod.d.wu))]
+pub unsafe fn lasx_xvaddwod_d_wu(a: v8u32, b: v8u32) -> v4i64 {
+    __lasx_xvaddwod_d_wu(a, b)
+}
+
+/// Generates the instruction `xvaddwod.w.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwod.w.hu))]
+pub unsafe fn lasx_xvaddwod_w_hu(a: v16u16, b: v16u16) -> v8i32 {
+    __lasx_xvaddwod_w_hu(a, b)
+}
+
+/// Generates the instruction `xvaddwod.h.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwod.h.bu))]
+pub unsafe fn lasx_xvaddwod_h_bu(a: v32u8, b: v32u8) -> v16i16 {
+    __lasx_xvaddwod_h_bu(a, b)
+}
+
+/// Generates the instruction `xvsubwod.q.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubwod.q.d))]
+pub unsafe fn lasx_xvsubwod_q_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvsubwod_q_d(a, b)
+}
+
+/// Generates the instruction `xvsubwod.d.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubwod.d.w))]
+pub unsafe fn lasx_xvsubwod_d_w(a: v8i32, b: v8i32) -> v4i64 {
+    __lasx_xvsubwod_d_w(a, b)
+}
+
+/// Generates the instruction `xvsubwod.w.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubwod.w.h))]
+pub unsafe fn lasx_xvsubwod_w_h(a: v16i16, b: v16i16) -> v8i32 {
+    __lasx_xvsubwod_w_h(a, b)
+}
+
+/// Generates the instruction `xvsubwod.h.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubwod.h.b))]
+pub unsafe fn lasx_xvsubwod_h_b(a: v32i8, b: v32i8) -> v16i16 {
+    __lasx_xvsubwod_h_b(a, b)
+}
+
+/// Generates the instruction `xvsubwod.q.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubwod.q.du))]
+pub unsafe fn lasx_xvsubwod_q_du(a: v4u64, b: v4u64) -> v4i64 {
+    __lasx_xvsubwod_q_du(a, b)
+}
+
+/// Generates the instruction `xvsubwod.d.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubwod.d.wu))]
+pub unsafe fn lasx_xvsubwod_d_wu(a: v8u32, b: v8u32) -> v4i64 {
+    __lasx_xvsubwod_d_wu(a, b)
+}
+
+/// Generates the instruction `xvsubwod.w.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubwod.w.hu))]
+pub unsafe fn lasx_xvsubwod_w_hu(a: v16u16, b: v16u16) -> v8i32 {
+    __lasx_xvsubwod_w_hu(a, b)
+}
+
+/// Generates the instruction `xvsubwod.h.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsubwod.h.bu))]
+pub unsafe fn lasx_xvsubwod_h_bu(a: v32u8, b: v32u8) -> v16i16 {
+    __lasx_xvsubwod_h_bu(a, b)
+}
+
+/// Generates the instruction `xvmulwod.q.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwod.q.d))]
+pub unsafe fn lasx_xvmulwod_q_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvmulwod_q_d(a, b)
+}
+
+/// Generates the instruction `xvmulwod.d.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwod.d.w))]
+pub unsafe fn lasx_xvmulwod_d_w(a: v8i32, b: v8i32) -> v4i64 {
+    __lasx_xvmulwod_d_w(a, b)
+}
+
+/// Generates the instruction `xvmulwod.w.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwod.w.h))]
+pub unsafe fn lasx_xvmulwod_w_h(a: v16i16, b: v16i16) -> v8i32 {
+    __lasx_xvmulwod_w_h(a, b)
+}
+
+/// Generates the instruction `xvmulwod.h.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwod.h.b))]
+pub unsafe fn lasx_xvmulwod_h_b(a: v32i8, b: v32i8) -> v16i16 {
+    __lasx_xvmulwod_h_b(a, b)
+}
+
+/// Generates the instruction `xvmulwod.q.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwod.q.du))]
+pub unsafe fn lasx_xvmulwod_q_du(a: v4u64, b: v4u64) -> v4i64 {
+    __lasx_xvmulwod_q_du(a, b)
+}
+
+/// Generates the instruction `xvmulwod.d.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwod.d.wu))]
+pub unsafe fn lasx_xvmulwod_d_wu(a: v8u32, b: v8u32) -> v4i64 {
+    __lasx_xvmulwod_d_wu(a, b)
+}
+
+/// Generates the instruction `xvmulwod.w.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwod.w.hu))]
+pub unsafe fn lasx_xvmulwod_w_hu(a: v16u16, b: v16u16) -> v8i32 {
+    __lasx_xvmulwod_w_hu(a, b)
+}
+
+/// Generates the instruction `xvmulwod.h.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwod.h.bu))]
+pub unsafe fn lasx_xvmulwod_h_bu(a: v32u8, b: v32u8) -> v16i16 {
+    __lasx_xvmulwod_h_bu(a, b)
+}
+
+/// Generates the instruction `xvaddwev.d.wu.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwev.d.wu.w))]
+pub unsafe fn lasx_xvaddwev_d_wu_w(a: v8u32, b: v8i32) -> v4i64 {
+    __lasx_xvaddwev_d_wu_w(a, b)
+}
+
+/// Generates the instruction `xvaddwev.w.hu.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwev.w.hu.h))]
+pub unsafe fn lasx_xvaddwev_w_hu_h(a: v16u16, b: v16i16) -> v8i32 {
+    __lasx_xvaddwev_w_hu_h(a, b)
+}
+
+/// Generates the instruction `xvaddwev.h.bu.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwev.h.bu.b))]
+pub unsafe fn lasx_xvaddwev_h_bu_b(a: v32u8, b: v32i8) -> v16i16 {
+    __lasx_xvaddwev_h_bu_b(a, b)
+}
+
+/// Generates the instruction `xvmulwev.d.wu.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwev.d.wu.w))]
+pub unsafe fn lasx_xvmulwev_d_wu_w(a: v8u32, b: v8i32) -> v4i64 {
+    __lasx_xvmulwev_d_wu_w(a, b)
+}
+
+/// Generates the instruction `xvmulwev.w.hu.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwev.w.hu.h))]
+pub unsafe fn lasx_xvmulwev_w_hu_h(a: v16u16, b: v16i16) -> v8i32 {
+    __lasx_xvmulwev_w_hu_h(a, b)
+}
+
+/// Generates the instruction `xvmulwev.h.bu.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwev.h.bu.b))]
+pub unsafe fn lasx_xvmulwev_h_bu_b(a: v32u8, b: v32i8) -> v16i16 {
+    __lasx_xvmulwev_h_bu_b(a, b)
+}
+
+/// Generates the instruction `xvaddwod.d.wu.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwod.d.wu.w))]
+pub unsafe fn lasx_xvaddwod_d_wu_w(a: v8u32, b: v8i32) -> v4i64 {
+    __lasx_xvaddwod_d_wu_w(a, b)
+}
+
+/// Generates the instruction `xvaddwod.w.hu.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwod.w.hu.h))]
+pub unsafe fn lasx_xvaddwod_w_hu_h(a: v16u16, b: v16i16) -> v8i32 {
+    __lasx_xvaddwod_w_hu_h(a, b)
+}
+
+/// Generates the instruction `xvaddwod.h.bu.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwod.h.bu.b))]
+pub unsafe fn lasx_xvaddwod_h_bu_b(a: v32u8, b: v32i8) -> v16i16 {
+    __lasx_xvaddwod_h_bu_b(a, b)
+}
+
+/// Generates the instruction `xvmulwod.d.wu.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwod.d.wu.w))]
+pub unsafe fn lasx_xvmulwod_d_wu_w(a: v8u32, b: v8i32) -> v4i64 {
+    __lasx_xvmulwod_d_wu_w(a, b)
+}
+
+/// Generates the instruction `xvmulwod.w.hu.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwod.w.hu.h))]
+pub unsafe fn lasx_xvmulwod_w_hu_h(a: v16u16, b: v16i16) -> v8i32 {
+    __lasx_xvmulwod_w_hu_h(a, b)
+}
+
+/// Generates the instruction `xvmulwod.h.bu.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwod.h.bu.b))]
+pub unsafe fn lasx_xvmulwod_h_bu_b(a: v32u8, b: v32i8) -> v16i16 {
+    __lasx_xvmulwod_h_bu_b(a, b)
+}
+
+/// Generates the instruction `xvhaddw.q.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvhaddw.q.d))]
+pub unsafe fn lasx_xvhaddw_q_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvhaddw_q_d(a, b)
+}
+
+/// Generates the instruction `xvhaddw.qu.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvhaddw.qu.du))]
+pub unsafe fn lasx_xvhaddw_qu_du(a: v4u64, b: v4u64) -> v4u64 {
+    __lasx_xvhaddw_qu_du(a, b)
+}
+
+/// Generates the instruction `xvhsubw.q.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvhsubw.q.d))]
+pub unsafe fn lasx_xvhsubw_q_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvhsubw_q_d(a, b)
+}
+
+/// Generates the instruction `xvhsubw.qu.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvhsubw.qu.du))]
+pub unsafe fn lasx_xvhsubw_qu_du(a: v4u64, b: v4u64) -> v4u64 {
+    __lasx_xvhsubw_qu_du(a, b)
+}
+
+/// Generates the instruction `xvmaddwev.q.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwev.q.d))]
+pub unsafe fn lasx_xvmaddwev_q_d(a: v4i64, b: v4i64, c: v4i64) -> v4i64 {
+    __lasx_xvmaddwev_q_d(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwev.d.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwev.d.w))]
+pub unsafe fn lasx_xvmaddwev_d_w(a: v4i64, b: v8i32, c: v8i32) -> v4i64 {
+    __lasx_xvmaddwev_d_w(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwev.w.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwev.w.h))]
+pub unsafe fn lasx_xvmaddwev_w_h(a: v8i32, b: v16i16, c: v16i16) -> v8i32 {
+    __lasx_xvmaddwev_w_h(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwev.h.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwev.h.b))]
+pub unsafe fn lasx_xvmaddwev_h_b(a: v16i16, b: v32i8, c: v32i8) -> v16i16 {
+    __lasx_xvmaddwev_h_b(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwev.q.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwev.q.du))]
+pub unsafe fn lasx_xvmaddwev_q_du(a: v4u64, b: v4u64, c: v4u64) -> v4u64 {
+    __lasx_xvmaddwev_q_du(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwev.d.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwev.d.wu))]
+pub unsafe fn lasx_xvmaddwev_d_wu(a: v4u64, b: v8u32, c: v8u32) -> v4u64 {
+    __lasx_xvmaddwev_d_wu(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwev.w.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwev.w.hu))]
+pub unsafe fn lasx_xvmaddwev_w_hu(a: v8u32, b: v16u16, c: v16u16) -> v8u32 {
+    __lasx_xvmaddwev_w_hu(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwev.h.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwev.h.bu))]
+pub unsafe fn lasx_xvmaddwev_h_bu(a: v16u16, b: v32u8, c: v32u8) -> v16u16 {
+    __lasx_xvmaddwev_h_bu(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwod.q.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwod.q.d))]
+pub unsafe fn lasx_xvmaddwod_q_d(a: v4i64, b: v4i64, c: v4i64) -> v4i64 {
+    __lasx_xvmaddwod_q_d(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwod.d.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwod.d.w))]
+pub unsafe fn lasx_xvmaddwod_d_w(a: v4i64, b: v8i32, c: v8i32) -> v4i64 {
+    __lasx_xvmaddwod_d_w(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwod.w.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwod.w.h))]
+pub unsafe fn lasx_xvmaddwod_w_h(a: v8i32, b: v16i16, c: v16i16) -> v8i32 {
+    __lasx_xvmaddwod_w_h(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwod.h.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwod.h.b))]
+pub unsafe fn lasx_xvmaddwod_h_b(a: v16i16, b: v32i8, c: v32i8) -> v16i16 {
+    __lasx_xvmaddwod_h_b(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwod.q.du xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwod.q.du))]
+pub unsafe fn lasx_xvmaddwod_q_du(a: v4u64, b: v4u64, c: v4u64) -> v4u64 {
+    __lasx_xvmaddwod_q_du(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwod.d.wu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwod.d.wu))]
+pub unsafe fn lasx_xvmaddwod_d_wu(a: v4u64, b: v8u32, c: v8u32) -> v4u64 {
+    __lasx_xvmaddwod_d_wu(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwod.w.hu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwod.w.hu))]
+pub unsafe fn lasx_xvmaddwod_w_hu(a: v8u32, b: v16u16, c: v16u16) -> v8u32 {
+    __lasx_xvmaddwod_w_hu(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwod.h.bu xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwod.h.bu))]
+pub unsafe fn lasx_xvmaddwod_h_bu(a: v16u16, b: v32u8, c: v32u8) -> v16u16 {
+    __lasx_xvmaddwod_h_bu(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwev.q.du.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwev.q.du.d))]
+pub unsafe fn lasx_xvmaddwev_q_du_d(a: v4i64, b: v4u64, c: v4i64) -> v4i64 {
+    __lasx_xvmaddwev_q_du_d(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwev.d.wu.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwev.d.wu.w))]
+pub unsafe fn lasx_xvmaddwev_d_wu_w(a: v4i64, b: v8u32, c: v8i32) -> v4i64 {
+    __lasx_xvmaddwev_d_wu_w(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwev.w.hu.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwev.w.hu.h))]
+pub unsafe fn lasx_xvmaddwev_w_hu_h(a: v8i32, b: v16u16, c: v16i16) -> v8i32 {
+    __lasx_xvmaddwev_w_hu_h(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwev.h.bu.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwev.h.bu.b))]
+pub unsafe fn lasx_xvmaddwev_h_bu_b(a: v16i16, b: v32u8, c: v32i8) -> v16i16 {
+    __lasx_xvmaddwev_h_bu_b(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwod.q.du.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwod.q.du.d))]
+pub unsafe fn lasx_xvmaddwod_q_du_d(a: v4i64, b: v4u64, c: v4i64) -> v4i64 {
+    __lasx_xvmaddwod_q_du_d(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwod.d.wu.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwod.d.wu.w))]
+pub unsafe fn lasx_xvmaddwod_d_wu_w(a: v4i64, b: v8u32, c: v8i32) -> v4i64 {
+    __lasx_xvmaddwod_d_wu_w(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwod.w.hu.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwod.w.hu.h))]
+pub unsafe fn lasx_xvmaddwod_w_hu_h(a: v8i32, b: v16u16, c: v16i16) -> v8i32 {
+    __lasx_xvmaddwod_w_hu_h(a, b, c)
+}
+
+/// Generates the instruction `xvmaddwod.h.bu.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmaddwod.h.bu.b))]
+pub unsafe fn lasx_xvmaddwod_h_bu_b(a: v16i16, b: v32u8, c: v32i8) -> v16i16 {
+    __lasx_xvmaddwod_h_bu_b(a, b, c)
+}
+
+/// Generates the instruction `xvrotr.b xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvrotr.b))]
+pub unsafe fn lasx_xvrotr_b(a: v32i8, b: v32i8) -> v32i8 {
+    __lasx_xvrotr_b(a, b)
+}
+
+/// Generates the instruction `xvrotr.h xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvrotr.h))]
+pub unsafe fn lasx_xvrotr_h(a: v16i16, b: v16i16) -> v16i16 {
+    __lasx_xvrotr_h(a, b)
+}
+
+/// Generates the instruction `xvrotr.w xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvrotr.w))]
+pub unsafe fn lasx_xvrotr_w(a: v8i32, b: v8i32) -> v8i32 {
+    __lasx_xvrotr_w(a, b)
+}
+
+/// Generates the instruction `xvrotr.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvrotr.d))]
+pub unsafe fn lasx_xvrotr_d(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvrotr_d(a, b)
+}
+
+/// Generates the instruction `xvadd.q xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvadd.q))]
+pub unsafe fn lasx_xvadd_q(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvadd_q(a, b)
+}
+
+/// Generates the instruction `xvsub.q xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsub.q))]
+pub unsafe fn lasx_xvsub_q(a: v4i64, b: v4i64) -> v4i64 {
+    __lasx_xvsub_q(a, b)
+}
+
+/// Generates the instruction `xvaddwev.q.du.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwev.q.du.d))]
+pub unsafe fn lasx_xvaddwev_q_du_d(a: v4u64, b: v4i64) -> v4i64 {
+    __lasx_xvaddwev_q_du_d(a, b)
+}
+
+/// Generates the instruction `xvaddwod.q.du.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvaddwod.q.du.d))]
+pub unsafe fn lasx_xvaddwod_q_du_d(a: v4u64, b: v4i64) -> v4i64 {
+    __lasx_xvaddwod_q_du_d(a, b)
+}
+
+/// Generates the instruction `xvmulwev.q.du.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwev.q.du.d))]
+pub unsafe fn lasx_xvmulwev_q_du_d(a: v4u64, b: v4i64) -> v4i64 {
+    __lasx_xvmulwev_q_du_d(a, b)
+}
+
+/// Generates the instruction `xvmulwod.q.du.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmulwod.q.du.d))]
+pub unsafe fn lasx_xvmulwod_q_du_d(a: v4u64, b: v4i64) -> v4i64 {
+    __lasx_xvmulwod_q_du_d(a, b)
+}
+
+/// Generates the instruction `xvmskgez.b xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmskgez.b))]
+pub unsafe fn lasx_xvmskgez_b(a: v32i8) -> v32i8 {
+    __lasx_xvmskgez_b(a)
+}
+
+/// Generates the instruction `xvmsknz.b xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvmsknz.b))]
+pub unsafe fn lasx_xvmsknz_b(a: v32i8) -> v32i8 {
+    __lasx_xvmsknz_b(a)
+}
+
+/// Generates the instruction `xvexth.h.b xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvexth.h.b))]
+pub unsafe fn lasx_xvexth_h_b(a: v32i8) -> v16i16 {
+    __lasx_xvexth_h_b(a)
+}
+
+/// Generates the instruction `xvexth.w.h xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvexth.w.h))]
+pub unsafe fn lasx_xvexth_w_h(a: v16i16) -> v8i32 {
+    __lasx_xvexth_w_h(a)
+}
+
+/// Generates the instruction `xvexth.d.w xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvexth.d.w))]
+pub unsafe fn lasx_xvexth_d_w(a: v8i32) -> v4i64 {
+    __lasx_xvexth_d_w(a)
+}
+
+/// Generates the instruction `xvexth.q.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvexth.q.d))]
+pub unsafe fn lasx_xvexth_q_d(a: v4i64) -> v4i64 {
+    __lasx_xvexth_q_d(a)
+}
+
+/// Generates the instruction `xvexth.hu.bu xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvexth.hu.bu))]
+pub unsafe fn lasx_xvexth_hu_bu(a: v32u8) -> v16u16 {
+    __lasx_xvexth_hu_bu(a)
+}
+
+/// Generates the instruction `xvexth.wu.hu xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvexth.wu.hu))]
+pub unsafe fn lasx_xvexth_wu_hu(a: v16u16) -> v8u32 {
+    __lasx_xvexth_wu_hu(a)
+}
+
+/// Generates the instruction `xvexth.du.wu xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvexth.du.wu))]
+pub unsafe fn lasx_xvexth_du_wu(a: v8u32) -> v4u64 {
+    __lasx_xvexth_du_wu(a)
+}
+
+/// Generates the instruction `xvexth.qu.du xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvexth.qu.du))]
+pub unsafe fn lasx_xvexth_qu_du(a: v4u64) -> v4u64 {
+    __lasx_xvexth_qu_du(a)
+}
+
+/// Generates the instruction `xvrotri.b xd, xj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvrotri.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvrotri_b<const IMM3: u32>(a: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvrotri_b(a, IMM3)
+}
+
+/// Generates the instruction `xvrotri.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvrotri.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvrotri_h<const IMM4: u32>(a: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvrotri_h(a, IMM4)
+}
+
+/// Generates the instruction `xvrotri.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvrotri.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvrotri_w<const IMM5: u32>(a: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvrotri_w(a, IMM5)
+}
+
+/// Generates the instruction `xvrotri.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvrotri.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvrotri_d<const IMM6: u32>(a: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvrotri_d(a, IMM6)
+}
+
+/// Generates the instruction `xvextl.q.d xd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvextl.q.d))]
+pub unsafe fn lasx_xvextl_q_d(a: v4i64) -> v4i64 {
+    __lasx_xvextl_q_d(a)
+}
+
+/// Generates the instruction `xvsrlni.b.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrlni.b.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvsrlni_b_h<const IMM4: u32>(a: v32i8, b: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvsrlni_b_h(a, b, IMM4)
+}
+
+/// Generates the instruction `xvsrlni.h.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrlni.h.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvsrlni_h_w<const IMM5: u32>(a: v16i16, b: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvsrlni_h_w(a, b, IMM5)
+}
+
+/// Generates the instruction `xvsrlni.w.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrlni.w.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvsrlni_w_d<const IMM6: u32>(a: v8i32, b: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvsrlni_w_d(a, b, IMM6)
+}
+
+/// Generates the instruction `xvsrlni.d.q xd, xj, ui7`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrlni.d.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvsrlni_d_q<const IMM7: u32>(a: v4i64, b: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lasx_xvsrlni_d_q(a, b, IMM7)
+}
+
+/// Generates the instruction `xvsrlrni.b.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrlrni.b.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvsrlrni_b_h<const IMM4: u32>(a: v32i8, b: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvsrlrni_b_h(a, b, IMM4)
+}
+
+/// Generates the instruction `xvsrlrni.h.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrlrni.h.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvsrlrni_h_w<const IMM5: u32>(a: v16i16, b: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvsrlrni_h_w(a, b, IMM5)
+}
+
+/// Generates the instruction `xvsrlrni.w.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrlrni.w.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvsrlrni_w_d<const IMM6: u32>(a: v8i32, b: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvsrlrni_w_d(a, b, IMM6)
+}
+
+/// Generates the instruction `xvsrlrni.d.q xd, xj, ui7`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrlrni.d.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvsrlrni_d_q<const IMM7: u32>(a: v4i64, b: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lasx_xvsrlrni_d_q(a, b, IMM7)
+}
+
+/// Generates the instruction `xvssrlni.b.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlni.b.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrlni_b_h<const IMM4: u32>(a: v32i8, b: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvssrlni_b_h(a, b, IMM4)
+}
+
+/// Generates the instruction `xvssrlni.h.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlni.h.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrlni_h_w<const IMM5: u32>(a: v16i16, b: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvssrlni_h_w(a, b, IMM5)
+}
+
+/// Generates the instruction `xvssrlni.w.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlni.w.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrlni_w_d<const IMM6: u32>(a: v8i32, b: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvssrlni_w_d(a, b, IMM6)
+}
+
+/// Generates the instruction `xvssrlni.d.q xd, xj, ui7`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlni.d.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrlni_d_q<const IMM7: u32>(a: v4i64, b: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lasx_xvssrlni_d_q(a, b, IMM7)
+}
+
+/// Generates the instruction `xvssrlni.bu.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlni.bu.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrlni_bu_h<const IMM4: u32>(a: v32u8, b: v32i8) -> v32u8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvssrlni_bu_h(a, b, IMM4)
+}
+
+/// Generates the instruction `xvssrlni.hu.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlni.hu.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrlni_hu_w<const IMM5: u32>(a: v16u16, b: v16i16) -> v16u16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvssrlni_hu_w(a, b, IMM5)
+}
+
+/// Generates the instruction `xvssrlni.wu.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlni.wu.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrlni_wu_d<const IMM6: u32>(a: v8u32, b: v8i32) -> v8u32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvssrlni_wu_d(a, b, IMM6)
+}
+
+/// Generates the instruction `xvssrlni.du.q xd, xj, ui7`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlni.du.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrlni_du_q<const IMM7: u32>(a: v4u64, b: v4i64) -> v4u64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lasx_xvssrlni_du_q(a, b, IMM7)
+}
+
+/// Generates the instruction `xvssrlrni.b.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlrni.b.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrlrni_b_h<const IMM4: u32>(a: v32i8, b: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvssrlrni_b_h(a, b, IMM4)
+}
+
+/// Generates the instruction `xvssrlrni.h.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlrni.h.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrlrni_h_w<const IMM5: u32>(a: v16i16, b: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvssrlrni_h_w(a, b, IMM5)
+}
+
+/// Generates the instruction `xvssrlrni.w.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlrni.w.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrlrni_w_d<const IMM6: u32>(a: v8i32, b: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvssrlrni_w_d(a, b, IMM6)
+}
+
+/// Generates the instruction `xvssrlrni.d.q xd, xj, ui7`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlrni.d.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrlrni_d_q<const IMM7: u32>(a: v4i64, b: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lasx_xvssrlrni_d_q(a, b, IMM7)
+}
+
+/// Generates the instruction `xvssrlrni.bu.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlrni.bu.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrlrni_bu_h<const IMM4: u32>(a: v32u8, b: v32i8) -> v32u8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvssrlrni_bu_h(a, b, IMM4)
+}
+
+/// Generates the instruction `xvssrlrni.hu.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlrni.hu.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrlrni_hu_w<const IMM5: u32>(a: v16u16, b: v16i16) -> v16u16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvssrlrni_hu_w(a, b, IMM5)
+}
+
+/// Generates the instruction `xvssrlrni.wu.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlrni.wu.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrlrni_wu_d<const IMM6: u32>(a: v8u32, b: v8i32) -> v8u32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvssrlrni_wu_d(a, b, IMM6)
+}
+
+/// Generates the instruction `xvssrlrni.du.q xd, xj, ui7`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrlrni.du.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrlrni_du_q<const IMM7: u32>(a: v4u64, b: v4i64) -> v4u64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lasx_xvssrlrni_du_q(a, b, IMM7)
+}
+
+/// Generates the instruction `xvsrani.b.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrani.b.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvsrani_b_h<const IMM4: u32>(a: v32i8, b: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvsrani_b_h(a, b, IMM4)
+}
+
+/// Generates the instruction `xvsrani.h.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrani.h.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvsrani_h_w<const IMM5: u32>(a: v16i16, b: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvsrani_h_w(a, b, IMM5)
+}
+
+/// Generates the instruction `xvsrani.w.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrani.w.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvsrani_w_d<const IMM6: u32>(a: v8i32, b: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvsrani_w_d(a, b, IMM6)
+}
+
+/// Generates the instruction `xvsrani.d.q xd, xj, ui7`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrani.d.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvsrani_d_q<const IMM7: u32>(a: v4i64, b: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lasx_xvsrani_d_q(a, b, IMM7)
+}
+
+/// Generates the instruction `xvsrarni.b.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrarni.b.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvsrarni_b_h<const IMM4: u32>(a: v32i8, b: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvsrarni_b_h(a, b, IMM4)
+}
+
+/// Generates the instruction `xvsrarni.h.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrarni.h.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvsrarni_h_w<const IMM5: u32>(a: v16i16, b: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvsrarni_h_w(a, b, IMM5)
+}
+
+/// Generates the instruction `xvsrarni.w.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrarni.w.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvsrarni_w_d<const IMM6: u32>(a: v8i32, b: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvsrarni_w_d(a, b, IMM6)
+}
+
+/// Generates the instruction `xvsrarni.d.q xd, xj, ui7`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsrarni.d.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvsrarni_d_q<const IMM7: u32>(a: v4i64, b: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lasx_xvsrarni_d_q(a, b, IMM7)
+}
+
+/// Generates the instruction `xvssrani.b.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrani.b.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrani_b_h<const IMM4: u32>(a: v32i8, b: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvssrani_b_h(a, b, IMM4)
+}
+
+/// Generates the instruction `xvssrani.h.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrani.h.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrani_h_w<const IMM5: u32>(a: v16i16, b: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvssrani_h_w(a, b, IMM5)
+}
+
+/// Generates the instruction `xvssrani.w.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrani.w.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrani_w_d<const IMM6: u32>(a: v8i32, b: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvssrani_w_d(a, b, IMM6)
+}
+
+/// Generates the instruction `xvssrani.d.q xd, xj, ui7`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrani.d.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrani_d_q<const IMM7: u32>(a: v4i64, b: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lasx_xvssrani_d_q(a, b, IMM7)
+}
+
+/// Generates the instruction `xvssrani.bu.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrani.bu.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrani_bu_h<const IMM4: u32>(a: v32u8, b: v32i8) -> v32u8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvssrani_bu_h(a, b, IMM4)
+}
+
+/// Generates the instruction `xvssrani.hu.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrani.hu.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrani_hu_w<const IMM5: u32>(a: v16u16, b: v16i16) -> v16u16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvssrani_hu_w(a, b, IMM5)
+}
+
+/// Generates the instruction `xvssrani.wu.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrani.wu.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrani_wu_d<const IMM6: u32>(a: v8u32, b: v8i32) -> v8u32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvssrani_wu_d(a, b, IMM6)
+}
+
+/// Generates the instruction `xvssrani.du.q xd, xj, ui7`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrani.du.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrani_du_q<const IMM7: u32>(a: v4u64, b: v4i64) -> v4u64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lasx_xvssrani_du_q(a, b, IMM7)
+}
+
+/// Generates the instruction `xvssrarni.b.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrarni.b.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrarni_b_h<const IMM4: u32>(a: v32i8, b: v32i8) -> v32i8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvssrarni_b_h(a, b, IMM4)
+}
+
+/// Generates the instruction `xvssrarni.h.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrarni.h.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrarni_h_w<const IMM5: u32>(a: v16i16, b: v16i16) -> v16i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvssrarni_h_w(a, b, IMM5)
+}
+
+/// Generates the instruction `xvssrarni.w.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrarni.w.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrarni_w_d<const IMM6: u32>(a: v8i32, b: v8i32) -> v8i32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvssrarni_w_d(a, b, IMM6)
+}
+
+/// Generates the instruction `xvssrarni.d.q xd, xj, ui7`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrarni.d.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrarni_d_q<const IMM7: u32>(a: v4i64, b: v4i64) -> v4i64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lasx_xvssrarni_d_q(a, b, IMM7)
+}
+
+/// Generates the instruction `xvssrarni.bu.h xd, xj, ui4`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrarni.bu.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrarni_bu_h<const IMM4: u32>(a: v32u8, b: v32i8) -> v32u8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lasx_xvssrarni_bu_h(a, b, IMM4)
+}
+
+/// Generates the instruction `xvssrarni.hu.w xd, xj, ui5`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrarni.hu.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrarni_hu_w<const IMM5: u32>(a: v16u16, b: v16i16) -> v16u16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lasx_xvssrarni_hu_w(a, b, IMM5)
+}
+
+/// Generates the instruction `xvssrarni.wu.d xd, xj, ui6`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrarni.wu.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrarni_wu_d<const IMM6: u32>(a: v8u32, b: v8i32) -> v8u32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lasx_xvssrarni_wu_d(a, b, IMM6)
+}
+
+/// Generates the instruction `xvssrarni.du.q xd, xj, ui7`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvssrarni.du.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lasx_xvssrarni_du_q<const IMM7: u32>(a: v4u64, b: v4i64) -> v4u64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lasx_xvssrarni_du_q(a, b, IMM7)
+}
+
+/// Generates the instruction `xvsetallnez.b cd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsetallnez.b))]
+pub unsafe fn lasx_xbnz_b(a: v32u8) -> i32 {
+    __lasx_xbnz_b(a)
+}
+
+/// Generates the instruction `xvsetallnez.d cd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsetallnez.d))]
+pub unsafe fn lasx_xbnz_d(a: v4u64) -> i32 {
+    __lasx_xbnz_d(a)
+}
+
+/// Generates the instruction `xvsetallnez.h cd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsetallnez.h))]
+pub unsafe fn lasx_xbnz_h(a: v16u16) -> i32 {
+    __lasx_xbnz_h(a)
+}
+
+/// Generates the instruction `xvsetnez.v cd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsetnez.v))]
+pub unsafe fn lasx_xbnz_v(a: v32u8) -> i32 {
+    __lasx_xbnz_v(a)
+}
+
+/// Generates the instruction `xvsetallnez.w cd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsetallnez.w))]
+pub unsafe fn lasx_xbnz_w(a: v8u32) -> i32 {
+    __lasx_xbnz_w(a)
+}
+
+/// Generates the instruction `xvsetanyeqz.b cd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsetanyeqz.b))]
+pub unsafe fn lasx_xbz_b(a: v32u8) -> i32 {
+    __lasx_xbz_b(a)
+}
+
+/// Generates the instruction `xvsetanyeqz.d cd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsetanyeqz.d))]
+pub unsafe fn lasx_xbz_d(a: v4u64) -> i32 {
+    __lasx_xbz_d(a)
+}
+
+/// Generates the instruction `xvsetanyeqz.h cd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsetanyeqz.h))]
+pub unsafe fn lasx_xbz_h(a: v16u16) -> i32 {
+    __lasx_xbz_h(a)
+}
+
+/// Generates the instruction `xvseteqz.v cd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvseteqz.v))]
+pub unsafe fn lasx_xbz_v(a: v32u8) -> i32 {
+    __lasx_xbz_v(a)
+}
+
+/// Generates the instruction `xvsetanyeqz.w cd, xj`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvsetanyeqz.w))]
+pub unsafe fn lasx_xbz_w(a: v8u32) -> i32 {
+    __lasx_xbz_w(a)
+}
+
+/// Generates the instruction `xvfcmp.caf.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.caf.d))]
+pub unsafe fn lasx_xvfcmp_caf_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_caf_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.caf.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.caf.s))]
+pub unsafe fn lasx_xvfcmp_caf_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_caf_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.ceq.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.ceq.d))]
+pub unsafe fn lasx_xvfcmp_ceq_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_ceq_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.ceq.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.ceq.s))]
+pub unsafe fn lasx_xvfcmp_ceq_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_ceq_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.cle.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.cle.d))]
+pub unsafe fn lasx_xvfcmp_cle_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_cle_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.cle.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.cle.s))]
+pub unsafe fn lasx_xvfcmp_cle_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_cle_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.clt.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.clt.d))]
+pub unsafe fn lasx_xvfcmp_clt_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_clt_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.clt.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.clt.s))]
+pub unsafe fn lasx_xvfcmp_clt_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_clt_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.cne.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.cne.d))]
+pub unsafe fn lasx_xvfcmp_cne_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_cne_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.cne.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.cne.s))]
+pub unsafe fn lasx_xvfcmp_cne_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_cne_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.cor.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.cor.d))]
+pub unsafe fn lasx_xvfcmp_cor_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_cor_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.cor.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.cor.s))]
+pub unsafe fn lasx_xvfcmp_cor_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_cor_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.cueq.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.cueq.d))]
+pub unsafe fn lasx_xvfcmp_cueq_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_cueq_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.cueq.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.cueq.s))]
+pub unsafe fn lasx_xvfcmp_cueq_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_cueq_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.cule.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.cule.d))]
+pub unsafe fn lasx_xvfcmp_cule_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_cule_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.cule.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.cule.s))]
+pub unsafe fn lasx_xvfcmp_cule_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_cule_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.cult.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.cult.d))]
+pub unsafe fn lasx_xvfcmp_cult_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_cult_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.cult.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.cult.s))]
+pub unsafe fn lasx_xvfcmp_cult_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_cult_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.cun.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.cun.d))]
+pub unsafe fn lasx_xvfcmp_cun_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_cun_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.cune.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.cune.d))]
+pub unsafe fn lasx_xvfcmp_cune_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_cune_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.cune.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.cune.s))]
+pub unsafe fn lasx_xvfcmp_cune_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_cune_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.cun.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.cun.s))]
+pub unsafe fn lasx_xvfcmp_cun_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_cun_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.saf.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.saf.d))]
+pub unsafe fn lasx_xvfcmp_saf_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_saf_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.saf.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.saf.s))]
+pub unsafe fn lasx_xvfcmp_saf_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_saf_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.seq.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.seq.d))]
+pub unsafe fn lasx_xvfcmp_seq_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_seq_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.seq.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.seq.s))]
+pub unsafe fn lasx_xvfcmp_seq_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_seq_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.sle.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.sle.d))]
+pub unsafe fn lasx_xvfcmp_sle_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_sle_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.sle.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.sle.s))]
+pub unsafe fn lasx_xvfcmp_sle_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_sle_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.slt.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.slt.d))]
+pub unsafe fn lasx_xvfcmp_slt_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_slt_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.slt.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.slt.s))]
+pub unsafe fn lasx_xvfcmp_slt_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_slt_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.sne.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.sne.d))]
+pub unsafe fn lasx_xvfcmp_sne_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_sne_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.sne.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.sne.s))]
+pub unsafe fn lasx_xvfcmp_sne_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_sne_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.sor.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.sor.d))]
+pub unsafe fn lasx_xvfcmp_sor_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_sor_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.sor.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.sor.s))]
+pub unsafe fn lasx_xvfcmp_sor_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_sor_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.sueq.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.sueq.d))]
+pub unsafe fn lasx_xvfcmp_sueq_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_sueq_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.sueq.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.sueq.s))]
+pub unsafe fn lasx_xvfcmp_sueq_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_sueq_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.sule.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.sule.d))]
+pub unsafe fn lasx_xvfcmp_sule_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_sule_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.sule.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.sule.s))]
+pub unsafe fn lasx_xvfcmp_sule_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_sule_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.sult.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.sult.d))]
+pub unsafe fn lasx_xvfcmp_sult_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_sult_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.sult.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.sult.s))]
+pub unsafe fn lasx_xvfcmp_sult_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_sult_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.sun.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.sun.d))]
+pub unsafe fn lasx_xvfcmp_sun_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_sun_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.sune.d xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.sune.d))]
+pub unsafe fn lasx_xvfcmp_sune_d(a: v4f64, b: v4f64) -> v4i64 {
+    __lasx_xvfcmp_sune_d(a, b)
+}
+
+/// Generates the instruction `xvfcmp.sune.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.sune.s))]
+pub unsafe fn lasx_xvfcmp_sune_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_sune_s(a, b)
+}
+
+/// Generates the instruction `xvfcmp.sun.s xd, xj, xk`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvfcmp.sun.s))]
+pub unsafe fn lasx_xvfcmp_sun_s(a: v8f32, b: v8f32) -> v8i32 {
+    __lasx_xvfcmp_sun_s(a, b)
+}
+
+/// Generates the instruction `xvpickve.d.f xd, xj, ui2`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpickve.d.f, IMM2 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvpickve_d_f<const IMM2: u32>(a: v4f64) -> v4f64 {
+    static_assert_uimm_bits!(IMM2, 2);
+    __lasx_xvpickve_d_f(a, IMM2)
+}
+
+/// Generates the instruction `xvpickve.w.f xd, xj, ui3`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvpickve.w.f, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lasx_xvpickve_w_f<const IMM3: u32>(a: v8f32) -> v8f32 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lasx_xvpickve_w_f(a, IMM3)
+}
+
+/// Generates the instruction `xvrepli.b xd, si10`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvrepli.b, IMM_S10 = 1))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn lasx_xvrepli_b<const IMM_S10: i32>() -> v32i8 {
+    static_assert_simm_bits!(IMM_S10, 10);
+    __lasx_xvrepli_b(IMM_S10)
+}
+
+/// Generates the instruction `xvrepli.d xd, si10`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvrepli.d, IMM_S10 = 1))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn lasx_xvrepli_d<const IMM_S10: i32>() -> v4i64 {
+    static_assert_simm_bits!(IMM_S10, 10);
+    __lasx_xvrepli_d(IMM_S10)
+}
+
+/// Generates the instruction `xvrepli.h xd, si10`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvrepli.h, IMM_S10 = 1))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn lasx_xvrepli_h<const IMM_S10: i32>() -> v16i16 {
+    static_assert_simm_bits!(IMM_S10, 10);
+    __lasx_xvrepli_h(IMM_S10)
+}
+
+/// Generates the instruction `xvrepli.w xd, si10`.
+#[inline]
+#[target_feature(enable = "lasx")]
+#[cfg_attr(test, assert_instr(xvrepli.w, IMM_S10 = 1))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn lasx_xvrepli_w<const IMM_S10: i32>() -> v8i32 {
+    static_assert_simm_bits!(IMM_S10, 10);
+    __lasx_xvrepli_w(IMM_S10)
+}
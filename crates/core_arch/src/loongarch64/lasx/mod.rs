@@ -0,0 +1,16 @@
+//! LoongArch64 LASX intrinsics
+//!
+//! The 256-bit vector intrinsics of `lasxintrin.h`. The bindings are
+//! generated from `crates/stdarch-gen-loongarch/lasx.spec`.
+
+#![allow(non_camel_case_types)]
+
+mod types;
+pub use self::types::*;
+
+#[rustfmt::skip]
+mod generated;
+pub use self::generated::*;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,930 @@
+use crate::{
+    core_arch::{loongarch64::*, simd::*},
+    mem::transmute,
+};
+use stdarch_test::simd_test;
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvadd_w() {
+    let a = i32x8::new(1, 2, 3, 4, 5, 6, 7, 8);
+    let b = i32x8::new(-1, -2, -3, -4, 1, 2, 3, i32::MAX);
+    let r = i32x8::new(0, 0, 0, 0, 6, 8, 10, i32::MIN + 7);
+    assert_eq!(r, transmute(lasx_xvadd_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvhaddw_d_w() {
+    let a = i32x8::new(1, 2, 3, 4, 5, 6, 7, 8);
+    let b = i32x8::new(10, 20, 30, 40, 50, 60, 70, 80);
+    // Adds the odd elements of `a` to the even elements of `b`.
+    let r = i64x4::new(12, 34, 56, 78);
+    assert_eq!(r, transmute(lasx_xvhaddw_d_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvfmul_d() {
+    let a = f64x4::new(1.0, -2.0, 0.5, 3.0);
+    let b = f64x4::new(2.0, 2.0, -4.0, 0.0);
+    let r = f64x4::new(2.0, -4.0, -2.0, 0.0);
+    assert_eq!(r, transmute(lasx_xvfmul_d(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvreplgr2vr_b() {
+    let r = i8x32::splat(-7);
+    assert_eq!(r, transmute(lasx_xvreplgr2vr_b(-7)));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvpickve2gr_d() {
+    let a = i64x4::new(10, 20, 30, -40);
+    assert_eq!(lasx_xvpickve2gr_d::<3>(transmute(a)), -40);
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xbnz_v() {
+    assert_eq!(lasx_xbnz_v(transmute(u8x32::splat(0))), 0);
+    assert_eq!(lasx_xbnz_v(transmute(u64x4::new(0, 0, 0, 1))), 1);
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvld() {
+    let a = [1i64, 2, 3, 4, 5, 6];
+    let r = i64x4::new(2, 3, 4, 5);
+    assert_eq!(r, transmute(lasx_xvld::<8>(a.as_ptr() as *const i8)));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvst() {
+    let a = i64x4::new(1, 2, 3, 4);
+    let mut r = [0i64; 5];
+    lasx_xvst::<8>(transmute(a), r.as_mut_ptr() as *mut i8);
+    assert_eq!(r, [0, 1, 2, 3, 4]);
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvadd_b() {
+    #[rustfmt::skip]
+    let a = i8x32::new(
+        -100, -71, -42, -13, 16, 45, 74, 103,
+        -124, -95, -66, -37, -8, 21, 50, 79,
+        108, -119, -90, -61, -32, -3, 26, 55,
+        84, 113, -114, -85, -56, -27, 2, 31,
+    );
+    #[rustfmt::skip]
+    let b = i8x32::new(
+        77, 64, 51, 38, 25, 12, -1, -14,
+        -27, -40, -53, -66, -79, -92, -105, -118,
+        125, 112, 99, 86, 73, 60, 47, 34,
+        21, 8, -5, -18, -31, -44, -57, -70,
+    );
+    #[rustfmt::skip]
+    let r = i8x32::new(
+        -23, -7, 9, 25, 41, 57, 73, 89,
+        105, 121, -119, -103, -87, -71, -55, -39,
+        -23, -7, 9, 25, 41, 57, 73, 89,
+        105, 121, -119, -103, -87, -71, -55, -39,
+    );
+    assert_eq!(r, transmute(lasx_xvadd_b(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvsub_d() {
+    let a = i64x4::new(1, -4611686018427387904, 1099511627776, -5);
+    let b = i64x4::new(2, -4611686018427387904, 3, 5);
+    let r = i64x4::new(-1, 0, 1099511627773, -10);
+    assert_eq!(r, transmute(lasx_xvsub_d(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvsadd_hu() {
+    #[rustfmt::skip]
+    let a = u16x16::new(
+        35536, 39635, 43734, 47833, 51932, 56031, 60130, 64229,
+        2792, 6891, 10990, 15089, 19188, 23287, 27386, 31485,
+    );
+    #[rustfmt::skip]
+    let b = u16x16::new(
+        2000, 1223, 446, 65205, 64428, 63651, 62874, 62097,
+        61320, 60543, 59766, 58989, 58212, 57435, 56658, 55881,
+    );
+    #[rustfmt::skip]
+    let r = u16x16::new(
+        37536, 40858, 44180, u16::MAX, u16::MAX, u16::MAX, u16::MAX, u16::MAX,
+        64112, u16::MAX, u16::MAX, u16::MAX, u16::MAX, u16::MAX, u16::MAX, u16::MAX,
+    );
+    assert_eq!(r, transmute(lasx_xvsadd_hu(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvssub_b() {
+    #[rustfmt::skip]
+    let a = i8x32::new(
+        -100, -71, -42, -13, 16, 45, 74, 103,
+        -124, -95, -66, -37, -8, 21, 50, 79,
+        108, -119, -90, -61, -32, -3, 26, 55,
+        84, 113, -114, -85, -56, -27, 2, 31,
+    );
+    #[rustfmt::skip]
+    let b = i8x32::new(
+        77, 64, 51, 38, 25, 12, -1, -14,
+        -27, -40, -53, -66, -79, -92, -105, -118,
+        125, 112, 99, 86, 73, 60, 47, 34,
+        21, 8, -5, -18, -31, -44, -57, -70,
+    );
+    #[rustfmt::skip]
+    let r = i8x32::new(
+        i8::MIN, i8::MIN, -93, -51, -9, 33, 75, 117,
+        -97, -55, -13, 29, 71, 113, i8::MAX, i8::MAX,
+        -17, i8::MIN, i8::MIN, i8::MIN, -105, -63, -21, 21,
+        63, 105, -109, -67, -25, 17, 59, 101,
+    );
+    assert_eq!(r, transmute(lasx_xvssub_b(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvmul_w() {
+    let a = i32x8::new(1, -2, 3, -4, 1073741824, i32::MIN, 100, i32::MAX);
+    let b = i32x8::new(5, 6, -7, 8, 1073741824, -1, -100, 1);
+    let r = i32x8::new(5, -12, -21, -32, 0, i32::MIN, -10000, i32::MAX);
+    assert_eq!(r, transmute(lasx_xvmul_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvmuh_wu() {
+    let a = u32x8::new(
+        1, 4294967294, 3, 4294967292, 1073741824, 2147483648, 100, 2147483647,
+    );
+    let b = u32x8::new(5, 6, 4294967289, 8, 1073741824, u32::MAX, 4294967196, 1);
+    let r = u32x8::new(0, 5, 2, 7, 268435456, 2147483647, 99, 0);
+    assert_eq!(r, transmute(lasx_xvmuh_wu(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvdiv_d() {
+    let a = i64x4::new(100, -100, 1099511627776, 7);
+    let b = i64x4::new(3, 3, -1024, -7);
+    let r = i64x4::new(33, -33, -1073741824, -1);
+    assert_eq!(r, transmute(lasx_xvdiv_d(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvmod_bu() {
+    #[rustfmt::skip]
+    let a = u8x32::new(
+        156, 185, 214, 243, 16, 45, 74, 103,
+        132, 161, 190, 219, 248, 21, 50, 79,
+        108, 137, 166, 195, 224, 253, 26, 55,
+        84, 113, 142, 171, 200, 229, 2, 31,
+    );
+    #[rustfmt::skip]
+    let b = u8x32::new(
+        77, 64, 51, 38, 25, 12, u8::MAX, 242,
+        229, 216, 203, 190, 177, 164, 151, 138,
+        125, 112, 99, 86, 73, 60, 47, 34,
+        21, 8, 251, 238, 225, 212, 199, 186,
+    );
+    #[rustfmt::skip]
+    let r = u8x32::new(
+        2, 57, 10, 15, 16, 9, 74, 103,
+        132, 161, 190, 29, 71, 21, 50, 79,
+        108, 25, 67, 23, 5, 13, 26, 21,
+        0, 1, 142, 171, 200, 17, 2, 31,
+    );
+    assert_eq!(r, transmute(lasx_xvmod_bu(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvmax_b() {
+    #[rustfmt::skip]
+    let a = i8x32::new(
+        -100, -71, -42, -13, 16, 45, 74, 103,
+        -124, -95, -66, -37, -8, 21, 50, 79,
+        108, -119, -90, -61, -32, -3, 26, 55,
+        84, 113, -114, -85, -56, -27, 2, 31,
+    );
+    #[rustfmt::skip]
+    let b = i8x32::new(
+        77, 64, 51, 38, 25, 12, -1, -14,
+        -27, -40, -53, -66, -79, -92, -105, -118,
+        125, 112, 99, 86, 73, 60, 47, 34,
+        21, 8, -5, -18, -31, -44, -57, -70,
+    );
+    #[rustfmt::skip]
+    let r = i8x32::new(
+        77, 64, 51, 38, 25, 45, 74, 103,
+        -27, -40, -53, -37, -8, 21, 50, 79,
+        125, 112, 99, 86, 73, 60, 47, 55,
+        84, 113, -5, -18, -31, -27, 2, 31,
+    );
+    assert_eq!(r, transmute(lasx_xvmax_b(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvmin_hu() {
+    #[rustfmt::skip]
+    let a = u16x16::new(
+        35536, 39635, 43734, 47833, 51932, 56031, 60130, 64229,
+        2792, 6891, 10990, 15089, 19188, 23287, 27386, 31485,
+    );
+    #[rustfmt::skip]
+    let b = u16x16::new(
+        2000, 1223, 446, 65205, 64428, 63651, 62874, 62097,
+        61320, 60543, 59766, 58989, 58212, 57435, 56658, 55881,
+    );
+    #[rustfmt::skip]
+    let r = u16x16::new(
+        2000, 1223, 446, 47833, 51932, 56031, 60130, 62097,
+        2792, 6891, 10990, 15089, 19188, 23287, 27386, 31485,
+    );
+    assert_eq!(r, transmute(lasx_xvmin_hu(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvavgr_b() {
+    #[rustfmt::skip]
+    let a = i8x32::new(
+        -100, -71, -42, -13, 16, 45, 74, 103,
+        -124, -95, -66, -37, -8, 21, 50, 79,
+        108, -119, -90, -61, -32, -3, 26, 55,
+        84, 113, -114, -85, -56, -27, 2, 31,
+    );
+    #[rustfmt::skip]
+    let b = i8x32::new(
+        77, 64, 51, 38, 25, 12, -1, -14,
+        -27, -40, -53, -66, -79, -92, -105, -118,
+        125, 112, 99, 86, 73, 60, 47, 34,
+        21, 8, -5, -18, -31, -44, -57, -70,
+    );
+    #[rustfmt::skip]
+    let r = i8x32::new(
+        -11, -3, 5, 13, 21, 29, 37, 45,
+        -75, -67, -59, -51, -43, -35, -27, -19,
+        117, -3, 5, 13, 21, 29, 37, 45,
+        53, 61, -59, -51, -43, -35, -27, -19,
+    );
+    assert_eq!(r, transmute(lasx_xvavgr_b(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvabsd_h() {
+    #[rustfmt::skip]
+    let a = i16x16::new(
+        -30000, -25901, -21802, -17703, -13604, -9505, -5406, -1307,
+        2792, 6891, 10990, 15089, 19188, 23287, 27386, 31485,
+    );
+    #[rustfmt::skip]
+    let b = i16x16::new(
+        2000, 1223, 446, -331, -1108, -1885, -2662, -3439,
+        -4216, -4993, -5770, -6547, -7324, -8101, -8878, -9655,
+    );
+    #[rustfmt::skip]
+    let r = i16x16::new(
+        32000, 27124, 22248, 17372, 12496, 7620, 2744, 2132,
+        7008, 11884, 16760, 21636, 26512, 31388, -29272, -24396,
+    );
+    assert_eq!(r, transmute(lasx_xvabsd_h(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvmadd_d() {
+    let a = i64x4::new(10, 20, 30, 40);
+    let b = i64x4::new(1, -4611686018427387904, 1099511627776, -5);
+    let c = i64x4::new(3, 0, 1, -1);
+    let r = i64x4::new(13, 20, 1099511627806, 45);
+    assert_eq!(
+        r,
+        transmute(lasx_xvmadd_d(transmute(a), transmute(b), transmute(c)))
+    );
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvsat_w() {
+    let a = i32x8::new(1, -2, 3, -4, 1073741824, i32::MIN, 100, i32::MAX);
+    let r = i32x8::new(1, -2, 3, -4, 32767, -32768, 100, 32767);
+    assert_eq!(r, transmute(lasx_xvsat_w::<15>(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvhaddw_h_b() {
+    #[rustfmt::skip]
+    let a = i8x32::new(
+        -100, -71, -42, -13, 16, 45, 74, 103,
+        -124, -95, -66, -37, -8, 21, 50, 79,
+        108, -119, -90, -61, -32, -3, 26, 55,
+        84, 113, -114, -85, -56, -27, 2, 31,
+    );
+    #[rustfmt::skip]
+    let b = i8x32::new(
+        77, 64, 51, 38, 25, 12, -1, -14,
+        -27, -40, -53, -66, -79, -92, -105, -118,
+        125, 112, 99, 86, 73, 60, 47, 34,
+        21, 8, -5, -18, -31, -44, -57, -70,
+    );
+    #[rustfmt::skip]
+    let r = i16x16::new(
+        6, 38, 70, 102, -122, -90, -58, -26,
+        6, 38, 70, 102, 134, -90, -58, -26,
+    );
+    assert_eq!(r, transmute(lasx_xvhaddw_h_b(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvmulwev_w_h() {
+    #[rustfmt::skip]
+    let a = i16x16::new(
+        -30000, -25901, -21802, -17703, -13604, -9505, -5406, -1307,
+        2792, 6891, 10990, 15089, 19188, 23287, 27386, 31485,
+    );
+    #[rustfmt::skip]
+    let b = i16x16::new(
+        2000, 1223, 446, -331, -1108, -1885, -2662, -3439,
+        -4216, -4993, -5770, -6547, -7324, -8101, -8878, -9655,
+    );
+    let r = i32x8::new(
+        -60000000, -9723692, 15073232, 14390772, -11771072, -63412300, -140532912, -243132908,
+    );
+    assert_eq!(r, transmute(lasx_xvmulwev_w_h(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvseq_w() {
+    let a = i32x8::new(1, -2, 3, -4, 1073741824, i32::MIN, 100, i32::MAX);
+    let b = i32x8::new(1, 2, 3, 4, 1073741824, 0, 100, 0);
+    let r = i32x8::new(-1, 0, -1, 0, -1, 0, -1, 0);
+    assert_eq!(r, transmute(lasx_xvseq_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvslt_du() {
+    let a = u64x4::new(1, 13835058055282163712, 1099511627776, 18446744073709551611);
+    let b = u64x4::new(2, 13835058055282163712, 3, 5);
+    let r = i64x4::new(-1, 0, 0, 0);
+    assert_eq!(r, transmute(lasx_xvslt_du(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvslei_b() {
+    #[rustfmt::skip]
+    let a = i8x32::new(
+        -100, -71, -42, -13, 16, 45, 74, 103,
+        -124, -95, -66, -37, -8, 21, 50, 79,
+        108, -119, -90, -61, -32, -3, 26, 55,
+        84, 113, -114, -85, -56, -27, 2, 31,
+    );
+    #[rustfmt::skip]
+    let r = i8x32::new(
+        -1, -1, -1, -1, 0, 0, 0, 0,
+        -1, -1, -1, -1, -1, 0, 0, 0,
+        0, -1, -1, -1, -1, -1, 0, 0,
+        0, 0, -1, -1, -1, -1, 0, 0,
+    );
+    assert_eq!(r, transmute(lasx_xvslei_b::<0>(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvand_v() {
+    #[rustfmt::skip]
+    let a = u8x32::new(
+        11, 48, 85, 122, 159, 196, 233, 14,
+        51, 88, 125, 162, 199, 236, 17, 54,
+        91, 128, 165, 202, 239, 20, 57, 94,
+        131, 168, 205, 242, 23, 60, 97, 134,
+    );
+    #[rustfmt::skip]
+    let b = u8x32::new(
+        200, 35, 126, 217, 52, 143, 234, 69,
+        160, 251, 86, 177, 12, 103, 194, 29,
+        120, 211, 46, 137, 228, 63, 154, 245,
+        80, 171, 6, 97, 188, 23, 114, 205,
+    );
+    #[rustfmt::skip]
+    let r = u8x32::new(
+        8, 32, 84, 88, 20, 132, 232, 4,
+        32, 88, 84, 160, 4, 100, 0, 20,
+        88, 128, 36, 136, 228, 20, 24, 84,
+        0, 168, 4, 96, 20, 20, 96, 132,
+    );
+    assert_eq!(r, transmute(lasx_xvand_v(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvxor_v() {
+    #[rustfmt::skip]
+    let a = u8x32::new(
+        11, 48, 85, 122, 159, 196, 233, 14,
+        51, 88, 125, 162, 199, 236, 17, 54,
+        91, 128, 165, 202, 239, 20, 57, 94,
+        131, 168, 205, 242, 23, 60, 97, 134,
+    );
+    #[rustfmt::skip]
+    let b = u8x32::new(
+        200, 35, 126, 217, 52, 143, 234, 69,
+        160, 251, 86, 177, 12, 103, 194, 29,
+        120, 211, 46, 137, 228, 63, 154, 245,
+        80, 171, 6, 97, 188, 23, 114, 205,
+    );
+    #[rustfmt::skip]
+    let r = u8x32::new(
+        195, 19, 43, 163, 171, 75, 3, 75,
+        147, 163, 43, 19, 203, 139, 211, 43,
+        35, 83, 139, 67, 11, 43, 163, 171,
+        211, 3, 203, 147, 171, 43, 19, 75,
+    );
+    assert_eq!(r, transmute(lasx_xvxor_v(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvbitsel_v() {
+    #[rustfmt::skip]
+    let a = u8x32::new(
+        11, 48, 85, 122, 159, 196, 233, 14,
+        51, 88, 125, 162, 199, 236, 17, 54,
+        91, 128, 165, 202, 239, 20, 57, 94,
+        131, 168, 205, 242, 23, 60, 97, 134,
+    );
+    #[rustfmt::skip]
+    let b = u8x32::new(
+        200, 35, 126, 217, 52, 143, 234, 69,
+        160, 251, 86, 177, 12, 103, 194, 29,
+        120, 211, 46, 137, 228, 63, 154, 245,
+        80, 171, 6, 97, 188, 23, 114, 205,
+    );
+    #[rustfmt::skip]
+    let c = u8x32::new(
+        7, 60, 113, 166, 219, 16, 69, 122,
+        175, 228, 25, 78, 131, 184, 237, 34,
+        87, 140, 193, 246, 43, 96, 149, 202,
+        u8::MAX, 52, 105, 158, 211, 8, 61, 114,
+    );
+    #[rustfmt::skip]
+    let r = u8x32::new(
+        8, 32, 116, 216, 20, 196, 232, 68,
+        176, 248, 116, 160, 68, 100, 208, 20,
+        88, 128, 36, 136, 228, 52, 184, 212,
+        80, 168, 132, 96, 148, 52, 112, 196,
+    );
+    assert_eq!(
+        r,
+        transmute(lasx_xvbitsel_v(transmute(a), transmute(b), transmute(c)))
+    );
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvbitrevi_h() {
+    #[rustfmt::skip]
+    let a = u16x16::new(
+        35536, 39635, 43734, 47833, 51932, 56031, 60130, 64229,
+        2792, 6891, 10990, 15089, 19188, 23287, 27386, 31485,
+    );
+    #[rustfmt::skip]
+    let r = u16x16::new(
+        35537, 39634, 43735, 47832, 51933, 56030, 60131, 64228,
+        2793, 6890, 10991, 15088, 19189, 23286, 27387, 31484,
+    );
+    assert_eq!(r, transmute(lasx_xvbitrevi_h::<0>(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvclz_w() {
+    let a = i32x8::new(1, -2, 3, -4, 1073741824, i32::MIN, 100, i32::MAX);
+    let r = i32x8::new(31, 0, 30, 0, 1, 0, 25, 1);
+    assert_eq!(r, transmute(lasx_xvclz_w(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvpcnt_d() {
+    let a = i64x4::new(1, -4611686018427387904, 1099511627776, -5);
+    let r = i64x4::new(1, 2, 1, 63);
+    assert_eq!(r, transmute(lasx_xvpcnt_d(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvsll_h() {
+    #[rustfmt::skip]
+    let a = i16x16::new(
+        -30000, -25901, -21802, -17703, -13604, -9505, -5406, -1307,
+        2792, 6891, 10990, 15089, 19188, 23287, 27386, 31485,
+    );
+    #[rustfmt::skip]
+    let b = i16x16::new(
+        0, 1, 2, 3, 4, 5, 6, 7,
+        8, 9, 10, 11, 12, 13, 14, 15,
+    );
+    #[rustfmt::skip]
+    let r = i16x16::new(
+        -30000, 13734, -21672, -10552, -21056, 23520, -18304, 29312,
+        -6144, -10752, -18432, -30720, 16384, -8192, i16::MIN, i16::MIN,
+    );
+    assert_eq!(r, transmute(lasx_xvsll_h(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvsrai_w() {
+    let a = i32x8::new(1, -2, 3, -4, 1073741824, i32::MIN, 100, i32::MAX);
+    let r = i32x8::new(0, -1, 0, -1, 8388608, -16777216, 0, 16777215);
+    assert_eq!(r, transmute(lasx_xvsrai_w::<7>(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvrotri_d() {
+    let a = i64x4::new(1, -4611686018427387904, 1099511627776, -5);
+    let r = i64x4::new(
+        4503599627370496,
+        3377699720527872,
+        268435456,
+        -18014398509481985,
+    );
+    assert_eq!(r, transmute(lasx_xvrotri_d::<12>(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvsrlr_b() {
+    #[rustfmt::skip]
+    let a = i8x32::new(
+        -100, -71, -42, -13, 16, 45, 74, 103,
+        -124, -95, -66, -37, -8, 21, 50, 79,
+        108, -119, -90, -61, -32, -3, 26, 55,
+        84, 113, -114, -85, -56, -27, 2, 31,
+    );
+    #[rustfmt::skip]
+    let b = i8x32::new(
+        0, 1, 2, 3, 4, 5, 6, 7,
+        0, 1, 2, 3, 4, 5, 6, 7,
+        0, 1, 2, 3, 4, 5, 6, 7,
+        0, 1, 2, 3, 4, 5, 6, 7,
+    );
+    #[rustfmt::skip]
+    let r = i8x32::new(
+        -100, 93, 54, 30, 1, 1, 1, 1,
+        -124, 81, 48, 27, 16, 1, 1, 1,
+        108, 69, 42, 24, 14, 8, 0, 0,
+        84, 57, 36, 21, 13, 7, 0, 0,
+    );
+    assert_eq!(r, transmute(lasx_xvsrlr_b(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvilvl_b() {
+    // Interleaves the low half of each 128-bit lane.
+    #[rustfmt::skip]
+    let a = i8x32::new(
+        0, 1, 2, 3, 4, 5, 6, 7,
+        8, 9, 10, 11, 12, 13, 14, 15,
+        16, 17, 18, 19, 20, 21, 22, 23,
+        24, 25, 26, 27, 28, 29, 30, 31,
+    );
+    #[rustfmt::skip]
+    let b = i8x32::new(
+        32, 33, 34, 35, 36, 37, 38, 39,
+        40, 41, 42, 43, 44, 45, 46, 47,
+        48, 49, 50, 51, 52, 53, 54, 55,
+        56, 57, 58, 59, 60, 61, 62, 63,
+    );
+    #[rustfmt::skip]
+    let r = i8x32::new(
+        32, 0, 33, 1, 34, 2, 35, 3,
+        36, 4, 37, 5, 38, 6, 39, 7,
+        48, 16, 49, 17, 50, 18, 51, 19,
+        52, 20, 53, 21, 54, 22, 55, 23,
+    );
+    assert_eq!(r, transmute(lasx_xvilvl_b(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvpickev_w() {
+    // Each 128-bit lane holds the even elements of `b`, then those of `a`.
+    let a = i32x8::new(0, 1, 2, 3, 4, 5, 6, 7);
+    let b = i32x8::new(8, 9, 10, 11, 12, 13, 14, 15);
+    let r = i32x8::new(8, 10, 0, 2, 12, 14, 4, 6);
+    assert_eq!(r, transmute(lasx_xvpickev_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvpackod_d() {
+    let a = i64x4::new(0, 1, 2, 3);
+    let b = i64x4::new(4, 5, 6, 7);
+    let r = i64x4::new(5, 1, 7, 3);
+    assert_eq!(r, transmute(lasx_xvpackod_d(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvshuf4i_h() {
+    #[rustfmt::skip]
+    let a = i16x16::new(
+        0, 1, 2, 3, 4, 5, 6, 7,
+        8, 9, 10, 11, 12, 13, 14, 15,
+    );
+    #[rustfmt::skip]
+    let r = i16x16::new(
+        3, 2, 1, 0, 7, 6, 5, 4,
+        11, 10, 9, 8, 15, 14, 13, 12,
+    );
+    assert_eq!(r, transmute(lasx_xvshuf4i_h::<27>(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvrepl128vei_h() {
+    // Each 128-bit lane is filled with its own element.
+    #[rustfmt::skip]
+    let a = i16x16::new(
+        0, 1, 2, 3, 4, 5, 6, 7,
+        8, 9, 10, 11, 12, 13, 14, 15,
+    );
+    #[rustfmt::skip]
+    let r = i16x16::new(
+        3, 3, 3, 3, 3, 3, 3, 3,
+        11, 11, 11, 11, 11, 11, 11, 11,
+    );
+    assert_eq!(r, transmute(lasx_xvrepl128vei_h::<3>(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvbsll_v() {
+    // Each 128-bit lane is shifted independently.
+    #[rustfmt::skip]
+    let a = i8x32::new(
+        0, 1, 2, 3, 4, 5, 6, 7,
+        8, 9, 10, 11, 12, 13, 14, 15,
+        16, 17, 18, 19, 20, 21, 22, 23,
+        24, 25, 26, 27, 28, 29, 30, 31,
+    );
+    #[rustfmt::skip]
+    let r = i8x32::new(
+        0, 0, 0, 1, 2, 3, 4, 5,
+        6, 7, 8, 9, 10, 11, 12, 13,
+        0, 0, 16, 17, 18, 19, 20, 21,
+        22, 23, 24, 25, 26, 27, 28, 29,
+    );
+    assert_eq!(r, transmute(lasx_xvbsll_v::<2>(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvpermi_d() {
+    let a = i64x4::new(1, -4611686018427387904, 1099511627776, -5);
+    let r = i64x4::new(-5, 1099511627776, -4611686018427387904, 1);
+    assert_eq!(r, transmute(lasx_xvpermi_d::<27>(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvpermi_q() {
+    // Selects the 128-bit lanes: 0 and 1 are the lanes of `b`, 2 and 3 those
+    // of `a`.
+    #[rustfmt::skip]
+    let a = i8x32::new(
+        0, 1, 2, 3, 4, 5, 6, 7,
+        8, 9, 10, 11, 12, 13, 14, 15,
+        16, 17, 18, 19, 20, 21, 22, 23,
+        24, 25, 26, 27, 28, 29, 30, 31,
+    );
+    #[rustfmt::skip]
+    let b = i8x32::new(
+        32, 33, 34, 35, 36, 37, 38, 39,
+        40, 41, 42, 43, 44, 45, 46, 47,
+        48, 49, 50, 51, 52, 53, 54, 55,
+        56, 57, 58, 59, 60, 61, 62, 63,
+    );
+    #[rustfmt::skip]
+    let r = i8x32::new(
+        48, 49, 50, 51, 52, 53, 54, 55,
+        56, 57, 58, 59, 60, 61, 62, 63,
+        0, 1, 2, 3, 4, 5, 6, 7,
+        8, 9, 10, 11, 12, 13, 14, 15,
+    );
+    assert_eq!(
+        r,
+        transmute(lasx_xvpermi_q::<33>(transmute(a), transmute(b)))
+    );
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvperm_w() {
+    let a = i32x8::new(10, 11, 12, 13, 14, 15, 16, 17);
+    let b = i32x8::new(7, 0, 6, 1, 5, 2, 4, 11);
+    let r = i32x8::new(17, 10, 16, 11, 15, 12, 14, 13);
+    assert_eq!(r, transmute(lasx_xvperm_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvinsve0_w() {
+    let a = i32x8::new(0, 1, 2, 3, 4, 5, 6, 7);
+    let b = i32x8::new(10, 11, 12, 13, 14, 15, 16, 17);
+    let r = i32x8::new(0, 1, 2, 3, 4, 10, 6, 7);
+    assert_eq!(
+        r,
+        transmute(lasx_xvinsve0_w::<5>(transmute(a), transmute(b)))
+    );
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvpickve_w() {
+    let a = i32x8::new(10, 11, 12, 13, 14, 15, 16, 17);
+    let r = i32x8::new(16, 0, 0, 0, 0, 0, 0, 0);
+    assert_eq!(r, transmute(lasx_xvpickve_w::<6>(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvreplve0_b() {
+    #[rustfmt::skip]
+    let a = i8x32::new(
+        0, 1, 2, 3, 4, 5, 6, 7,
+        8, 9, 10, 11, 12, 13, 14, 15,
+        16, 17, 18, 19, 20, 21, 22, 23,
+        24, 25, 26, 27, 28, 29, 30, 31,
+    );
+    #[rustfmt::skip]
+    let r = i8x32::new(
+        0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0,
+    );
+    assert_eq!(r, transmute(lasx_xvreplve0_b(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_vext2xv_h_b() {
+    // Sign-extends the low 16 elements.
+    #[rustfmt::skip]
+    let a = i8x32::new(
+        -100, -71, -42, -13, 16, 45, 74, 103,
+        -124, -95, -66, -37, -8, 21, 50, 79,
+        108, -119, -90, -61, -32, -3, 26, 55,
+        84, 113, -114, -85, -56, -27, 2, 31,
+    );
+    #[rustfmt::skip]
+    let r = i16x16::new(
+        -100, -71, -42, -13, 16, 45, 74, 103,
+        -124, -95, -66, -37, -8, 21, 50, 79,
+    );
+    assert_eq!(r, transmute(lasx_vext2xv_h_b(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_vext2xv_wu_bu() {
+    #[rustfmt::skip]
+    let a = i8x32::new(
+        -100, -71, -42, -13, 16, 45, 74, 103,
+        -124, -95, -66, -37, -8, 21, 50, 79,
+        108, -119, -90, -61, -32, -3, 26, 55,
+        84, 113, -114, -85, -56, -27, 2, 31,
+    );
+    let r = i32x8::new(156, 185, 214, 243, 16, 45, 74, 103);
+    assert_eq!(r, transmute(lasx_vext2xv_wu_bu(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvinsgr2vr_d() {
+    let a = i64x4::new(1, -4611686018427387904, 1099511627776, -5);
+    let r = i64x4::new(1, -4611686018427387904, 1099511627776, -9);
+    assert_eq!(r, transmute(lasx_xvinsgr2vr_d::<3>(transmute(a), -9)));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvpickve2gr_wu() {
+    let a = i32x8::new(1, -2, 3, -4, 1073741824, i32::MIN, 100, i32::MAX);
+    assert_eq!(lasx_xvpickve2gr_wu::<5>(transmute(a)), 2147483648);
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvreplgr2vr_w() {
+    let r = i32x8::new(-77, -77, -77, -77, -77, -77, -77, -77);
+    assert_eq!(r, transmute(lasx_xvreplgr2vr_w(-77)));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvmskltz_w() {
+    // The sign bits of each 128-bit lane are gathered in the low bits of
+    // the lane.
+    let a = i32x8::new(1, -2, 3, -4, 1073741824, i32::MIN, 100, i32::MAX);
+    let r = i32x8::new(10, 0, 0, 0, 2, 0, 0, 0);
+    assert_eq!(r, transmute(lasx_xvmskltz_w(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvfadd_s() {
+    let a = f32x8::new(1.5, -2.0, 0.25, 1024.0, 3.0, -0.5, 6.0, 0.0);
+    let b = f32x8::new(0.5, 4.0, -0.25, -8.0, 2.0, 0.5, -3.0, 1.0);
+    let r = f32x8::new(2.0, 2.0, 0.0, 1016.0, 5.0, 0.0, 3.0, 1.0);
+    assert_eq!(r, transmute(lasx_xvfadd_s(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvfdiv_d() {
+    let a = f64x4::new(3.0, -0.125, 10.0, -6.0);
+    let b = f64x4::new(-1.5, 8.0, 4.0, 3.0);
+    let r = f64x4::new(-2.0, -0.015625, 2.5, -2.0);
+    assert_eq!(r, transmute(lasx_xvfdiv_d(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvfmadd_d() {
+    let a = f64x4::new(3.0, -0.125, 10.0, -6.0);
+    let b = f64x4::new(-1.5, 8.0, 4.0, 3.0);
+    let c = f64x4::new(0.5, 2.0, -1.0, 0.25);
+    let r = f64x4::new(-4.0, 1.0, 39.0, -17.75);
+    assert_eq!(
+        r,
+        transmute(lasx_xvfmadd_d(transmute(a), transmute(b), transmute(c)))
+    );
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvfmsub_s() {
+    let a = f32x8::new(1.5, -2.0, 0.25, 1024.0, 3.0, -0.5, 6.0, 0.0);
+    let b = f32x8::new(0.5, 4.0, -0.25, -8.0, 2.0, 0.5, -3.0, 1.0);
+    let c = f32x8::new(1.0, -1.0, 2.0, 0.5, 0.0, 4.0, 1.0, -2.0);
+    let r = f32x8::new(-0.25, -7.0, -2.0625, -8192.5, 6.0, -4.25, -19.0, 2.0);
+    assert_eq!(
+        r,
+        transmute(lasx_xvfmsub_s(transmute(a), transmute(b), transmute(c)))
+    );
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvfmax_d() {
+    let a = f64x4::new(3.0, -0.125, 10.0, -6.0);
+    let b = f64x4::new(-1.5, 8.0, 4.0, 3.0);
+    let r = f64x4::new(3.0, 8.0, 10.0, 3.0);
+    assert_eq!(r, transmute(lasx_xvfmax_d(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvfsqrt_s() {
+    let a = f32x8::new(4.0, 9.0, 0.25, 1.0, 16.0, 64.0, 2.25, 0.0);
+    let r = f32x8::new(2.0, 3.0, 0.5, 1.0, 4.0, 8.0, 1.5, 0.0);
+    assert_eq!(r, transmute(lasx_xvfsqrt_s(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvftintrz_w_s() {
+    let a = f32x8::new(1.75, -1.75, 100.5, -0.5, 2.0, 3.9, -3.9, 0.0);
+    let r = i32x8::new(1, -1, 100, 0, 2, 3, -3, 0);
+    assert_eq!(r, transmute(lasx_xvftintrz_w_s(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvffint_d_l() {
+    let a = i64x4::new(1, -2, 1099511627776, -4503599627370496);
+    let r = f64x4::new(1.0, -2.0, 1099511627776.0, -4503599627370496.0);
+    assert_eq!(r, transmute(lasx_xvffint_d_l(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvfcvth_d_s() {
+    // Converts the high half of each 128-bit lane.
+    let a = f32x8::new(1.5, -2.0, 0.25, 1024.0, 3.0, -0.5, 6.0, 0.0);
+    let r = f64x4::new(0.25, 1024.0, 6.0, 0.0);
+    assert_eq!(r, transmute(lasx_xvfcvth_d_s(transmute(a))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvfcmp_clt_s() {
+    let a = f32x8::new(1.5, -2.0, 0.25, 1024.0, 3.0, -0.5, 6.0, 0.0);
+    let b = f32x8::new(0.5, 4.0, -0.25, -8.0, 2.0, 0.5, -3.0, 1.0);
+    let r = i32x8::new(0, -1, 0, 0, 0, -1, 0, -1);
+    assert_eq!(r, transmute(lasx_xvfcmp_clt_s(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xvfcmp_cueq_d() {
+    // Unordered or equal; -0.0 equals 0.0.
+    let a = f64x4::new(1.0, f64::NAN, 2.0, -0.0);
+    let b = f64x4::new(1.0, 0.0, 3.0, 0.0);
+    let r = i64x4::new(-1, -1, 0, -1);
+    assert_eq!(r, transmute(lasx_xvfcmp_cueq_d(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xbnz_b() {
+    #[rustfmt::skip]
+    let a = u8x32::new(
+        156, 185, 214, 243, 16, 45, 74, 103,
+        132, 161, 190, 219, 248, 21, 50, 79,
+        108, 137, 166, 195, 224, 253, 26, 55,
+        84, 113, 142, 171, 200, 229, 2, 31,
+    );
+    assert_eq!(lasx_xbnz_b(transmute(a)), 1);
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xbz_h() {
+    #[rustfmt::skip]
+    let a = u16x16::new(
+        0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 1,
+    );
+    assert_eq!(lasx_xbz_h(transmute(a)), 1);
+}
+
+#[simd_test(enable = "lasx")]
+unsafe fn test_lasx_xbz_v() {
+    #[rustfmt::skip]
+    let a = u8x32::new(
+        0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 1,
+    );
+    assert_eq!(lasx_xbz_v(transmute(a)), 0);
+}
@@ -0,0 +1,47 @@
+types! {
+    /// LoongArch-specific 256-bit wide vector of 32 packed `i8`.
+    pub struct v32i8(
+        i8, i8, i8, i8, i8, i8, i8, i8,
+        i8, i8, i8, i8, i8, i8, i8, i8,
+        i8, i8, i8, i8, i8, i8, i8, i8,
+        i8, i8, i8, i8, i8, i8, i8, i8,
+    );
+
+    /// LoongArch-specific 256-bit wide vector of 16 packed `i16`.
+    pub struct v16i16(
+        i16, i16, i16, i16, i16, i16, i16, i16,
+        i16, i16, i16, i16, i16, i16, i16, i16,
+    );
+
+    /// LoongArch-specific 256-bit wide vector of 8 packed `i32`.
+    pub struct v8i32(i32, i32, i32, i32, i32, i32, i32, i32);
+
+    /// LoongArch-specific 256-bit wide vector of 4 packed `i64`.
+    pub struct v4i64(i64, i64, i64, i64);
+
+    /// LoongArch-specific 256-bit wide vector of 32 packed `u8`.
+    pub struct v32u8(
+        u8, u8, u8, u8, u8, u8, u8, u8,
+        u8, u8, u8, u8, u8, u8, u8, u8,
+        u8, u8, u8, u8, u8, u8, u8, u8,
+        u8, u8, u8, u8, u8, u8, u8, u8,
+    );
+
+    /// LoongArch-specific 256-bit wide vector of 16 packed `u16`.
+    pub struct v16u16(
+        u16, u16, u16, u16, u16, u16, u16, u16,
+        u16, u16, u16, u16, u16, u16, u16, u16,
+    );
+
+    /// LoongArch-specific 256-bit wide vector of 8 packed `u32`.
+    pub struct v8u32(u32, u32, u32, u32, u32, u32, u32, u32);
+
+    /// LoongArch-specific 256-bit wide vector of 4 packed `u64`.
+    pub struct v4u64(u64, u64, u64, u64);
+
+    /// LoongArch-specific 256-bit wide vector of 8 packed `f32`.
+    pub struct v8f32(f32, f32, f32, f32, f32, f32, f32, f32);
+
+    /// LoongArch-specific 256-bit wide vector of 4 packed `f64`.
+    pub struct v4f64(f64, f64, f64, f64);
+}
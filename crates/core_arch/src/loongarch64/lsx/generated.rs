@@ -0,0 +1,7562 @@
+// This code is automatically generated. DO NOT MODIFY.
+//
+// Instead, modify `crates/stdarch-gen-loongarch/lsx.spec` and run the following command to re-generate this file:
+//
+// ```
+// OUT_DIR=`pwd`/crates/core_arch cargo run -p stdarch-gen-loongarch -- crates/stdarch-gen-loongarch/lsx.spec
+// ```
+
+use super::types::*;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "unadjusted" {
+    #[link_name = "llvm.loongarch.lsx.vsll.b"]
+    fn __lsx_vsll_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsll.h"]
+    fn __lsx_vsll_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsll.w"]
+    fn __lsx_vsll_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsll.d"]
+    fn __lsx_vsll_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vslli.b"]
+    fn __lsx_vslli_b(a: v16i8, b: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vslli.h"]
+    fn __lsx_vslli_h(a: v8i16, b: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vslli.w"]
+    fn __lsx_vslli_w(a: v4i32, b: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vslli.d"]
+    fn __lsx_vslli_d(a: v2i64, b: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsra.b"]
+    fn __lsx_vsra_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsra.h"]
+    fn __lsx_vsra_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsra.w"]
+    fn __lsx_vsra_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsra.d"]
+    fn __lsx_vsra_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsrai.b"]
+    fn __lsx_vsrai_b(a: v16i8, b: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsrai.h"]
+    fn __lsx_vsrai_h(a: v8i16, b: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsrai.w"]
+    fn __lsx_vsrai_w(a: v4i32, b: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsrai.d"]
+    fn __lsx_vsrai_d(a: v2i64, b: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsrar.b"]
+    fn __lsx_vsrar_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsrar.h"]
+    fn __lsx_vsrar_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsrar.w"]
+    fn __lsx_vsrar_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsrar.d"]
+    fn __lsx_vsrar_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsrari.b"]
+    fn __lsx_vsrari_b(a: v16i8, b: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsrari.h"]
+    fn __lsx_vsrari_h(a: v8i16, b: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsrari.w"]
+    fn __lsx_vsrari_w(a: v4i32, b: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsrari.d"]
+    fn __lsx_vsrari_d(a: v2i64, b: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsrl.b"]
+    fn __lsx_vsrl_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsrl.h"]
+    fn __lsx_vsrl_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsrl.w"]
+    fn __lsx_vsrl_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsrl.d"]
+    fn __lsx_vsrl_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsrli.b"]
+    fn __lsx_vsrli_b(a: v16i8, b: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsrli.h"]
+    fn __lsx_vsrli_h(a: v8i16, b: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsrli.w"]
+    fn __lsx_vsrli_w(a: v4i32, b: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsrli.d"]
+    fn __lsx_vsrli_d(a: v2i64, b: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsrlr.b"]
+    fn __lsx_vsrlr_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsrlr.h"]
+    fn __lsx_vsrlr_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsrlr.w"]
+    fn __lsx_vsrlr_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsrlr.d"]
+    fn __lsx_vsrlr_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsrlri.b"]
+    fn __lsx_vsrlri_b(a: v16i8, b: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsrlri.h"]
+    fn __lsx_vsrlri_h(a: v8i16, b: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsrlri.w"]
+    fn __lsx_vsrlri_w(a: v4i32, b: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsrlri.d"]
+    fn __lsx_vsrlri_d(a: v2i64, b: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vbitclr.b"]
+    fn __lsx_vbitclr_b(a: v16u8, b: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vbitclr.h"]
+    fn __lsx_vbitclr_h(a: v8u16, b: v8u16) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vbitclr.w"]
+    fn __lsx_vbitclr_w(a: v4u32, b: v4u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vbitclr.d"]
+    fn __lsx_vbitclr_d(a: v2u64, b: v2u64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vbitclri.b"]
+    fn __lsx_vbitclri_b(a: v16u8, b: u32) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vbitclri.h"]
+    fn __lsx_vbitclri_h(a: v8u16, b: u32) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vbitclri.w"]
+    fn __lsx_vbitclri_w(a: v4u32, b: u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vbitclri.d"]
+    fn __lsx_vbitclri_d(a: v2u64, b: u32) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vbitset.b"]
+    fn __lsx_vbitset_b(a: v16u8, b: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vbitset.h"]
+    fn __lsx_vbitset_h(a: v8u16, b: v8u16) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vbitset.w"]
+    fn __lsx_vbitset_w(a: v4u32, b: v4u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vbitset.d"]
+    fn __lsx_vbitset_d(a: v2u64, b: v2u64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vbitseti.b"]
+    fn __lsx_vbitseti_b(a: v16u8, b: u32) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vbitseti.h"]
+    fn __lsx_vbitseti_h(a: v8u16, b: u32) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vbitseti.w"]
+    fn __lsx_vbitseti_w(a: v4u32, b: u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vbitseti.d"]
+    fn __lsx_vbitseti_d(a: v2u64, b: u32) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vbitrev.b"]
+    fn __lsx_vbitrev_b(a: v16u8, b: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vbitrev.h"]
+    fn __lsx_vbitrev_h(a: v8u16, b: v8u16) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vbitrev.w"]
+    fn __lsx_vbitrev_w(a: v4u32, b: v4u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vbitrev.d"]
+    fn __lsx_vbitrev_d(a: v2u64, b: v2u64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vbitrevi.b"]
+    fn __lsx_vbitrevi_b(a: v16u8, b: u32) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vbitrevi.h"]
+    fn __lsx_vbitrevi_h(a: v8u16, b: u32) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vbitrevi.w"]
+    fn __lsx_vbitrevi_w(a: v4u32, b: u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vbitrevi.d"]
+    fn __lsx_vbitrevi_d(a: v2u64, b: u32) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vadd.b"]
+    fn __lsx_vadd_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vadd.h"]
+    fn __lsx_vadd_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vadd.w"]
+    fn __lsx_vadd_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vadd.d"]
+    fn __lsx_vadd_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vaddi.bu"]
+    fn __lsx_vaddi_bu(a: v16i8, b: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vaddi.hu"]
+    fn __lsx_vaddi_hu(a: v8i16, b: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vaddi.wu"]
+    fn __lsx_vaddi_wu(a: v4i32, b: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vaddi.du"]
+    fn __lsx_vaddi_du(a: v2i64, b: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsub.b"]
+    fn __lsx_vsub_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsub.h"]
+    fn __lsx_vsub_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsub.w"]
+    fn __lsx_vsub_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsub.d"]
+    fn __lsx_vsub_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsubi.bu"]
+    fn __lsx_vsubi_bu(a: v16i8, b: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsubi.hu"]
+    fn __lsx_vsubi_hu(a: v8i16, b: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsubi.wu"]
+    fn __lsx_vsubi_wu(a: v4i32, b: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsubi.du"]
+    fn __lsx_vsubi_du(a: v2i64, b: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmax.b"]
+    fn __lsx_vmax_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vmax.h"]
+    fn __lsx_vmax_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmax.w"]
+    fn __lsx_vmax_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmax.d"]
+    fn __lsx_vmax_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmaxi.b"]
+    fn __lsx_vmaxi_b(a: v16i8, b: i32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vmaxi.h"]
+    fn __lsx_vmaxi_h(a: v8i16, b: i32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmaxi.w"]
+    fn __lsx_vmaxi_w(a: v4i32, b: i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmaxi.d"]
+    fn __lsx_vmaxi_d(a: v2i64, b: i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmax.bu"]
+    fn __lsx_vmax_bu(a: v16u8, b: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vmax.hu"]
+    fn __lsx_vmax_hu(a: v8u16, b: v8u16) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vmax.wu"]
+    fn __lsx_vmax_wu(a: v4u32, b: v4u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vmax.du"]
+    fn __lsx_vmax_du(a: v2u64, b: v2u64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vmaxi.bu"]
+    fn __lsx_vmaxi_bu(a: v16u8, b: u32) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vmaxi.hu"]
+    fn __lsx_vmaxi_hu(a: v8u16, b: u32) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vmaxi.wu"]
+    fn __lsx_vmaxi_wu(a: v4u32, b: u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vmaxi.du"]
+    fn __lsx_vmaxi_du(a: v2u64, b: u32) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vmin.b"]
+    fn __lsx_vmin_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vmin.h"]
+    fn __lsx_vmin_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmin.w"]
+    fn __lsx_vmin_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmin.d"]
+    fn __lsx_vmin_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmini.b"]
+    fn __lsx_vmini_b(a: v16i8, b: i32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vmini.h"]
+    fn __lsx_vmini_h(a: v8i16, b: i32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmini.w"]
+    fn __lsx_vmini_w(a: v4i32, b: i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmini.d"]
+    fn __lsx_vmini_d(a: v2i64, b: i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmin.bu"]
+    fn __lsx_vmin_bu(a: v16u8, b: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vmin.hu"]
+    fn __lsx_vmin_hu(a: v8u16, b: v8u16) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vmin.wu"]
+    fn __lsx_vmin_wu(a: v4u32, b: v4u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vmin.du"]
+    fn __lsx_vmin_du(a: v2u64, b: v2u64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vmini.bu"]
+    fn __lsx_vmini_bu(a: v16u8, b: u32) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vmini.hu"]
+    fn __lsx_vmini_hu(a: v8u16, b: u32) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vmini.wu"]
+    fn __lsx_vmini_wu(a: v4u32, b: u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vmini.du"]
+    fn __lsx_vmini_du(a: v2u64, b: u32) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vseq.b"]
+    fn __lsx_vseq_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vseq.h"]
+    fn __lsx_vseq_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vseq.w"]
+    fn __lsx_vseq_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vseq.d"]
+    fn __lsx_vseq_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vseqi.b"]
+    fn __lsx_vseqi_b(a: v16i8, b: i32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vseqi.h"]
+    fn __lsx_vseqi_h(a: v8i16, b: i32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vseqi.w"]
+    fn __lsx_vseqi_w(a: v4i32, b: i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vseqi.d"]
+    fn __lsx_vseqi_d(a: v2i64, b: i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vslti.b"]
+    fn __lsx_vslti_b(a: v16i8, b: i32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vslt.b"]
+    fn __lsx_vslt_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vslt.h"]
+    fn __lsx_vslt_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vslt.w"]
+    fn __lsx_vslt_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vslt.d"]
+    fn __lsx_vslt_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vslti.h"]
+    fn __lsx_vslti_h(a: v8i16, b: i32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vslti.w"]
+    fn __lsx_vslti_w(a: v4i32, b: i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vslti.d"]
+    fn __lsx_vslti_d(a: v2i64, b: i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vslt.bu"]
+    fn __lsx_vslt_bu(a: v16u8, b: v16u8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vslt.hu"]
+    fn __lsx_vslt_hu(a: v8u16, b: v8u16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vslt.wu"]
+    fn __lsx_vslt_wu(a: v4u32, b: v4u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vslt.du"]
+    fn __lsx_vslt_du(a: v2u64, b: v2u64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vslti.bu"]
+    fn __lsx_vslti_bu(a: v16u8, b: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vslti.hu"]
+    fn __lsx_vslti_hu(a: v8u16, b: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vslti.wu"]
+    fn __lsx_vslti_wu(a: v4u32, b: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vslti.du"]
+    fn __lsx_vslti_du(a: v2u64, b: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsle.b"]
+    fn __lsx_vsle_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsle.h"]
+    fn __lsx_vsle_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsle.w"]
+    fn __lsx_vsle_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsle.d"]
+    fn __lsx_vsle_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vslei.b"]
+    fn __lsx_vslei_b(a: v16i8, b: i32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vslei.h"]
+    fn __lsx_vslei_h(a: v8i16, b: i32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vslei.w"]
+    fn __lsx_vslei_w(a: v4i32, b: i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vslei.d"]
+    fn __lsx_vslei_d(a: v2i64, b: i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsle.bu"]
+    fn __lsx_vsle_bu(a: v16u8, b: v16u8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsle.hu"]
+    fn __lsx_vsle_hu(a: v8u16, b: v8u16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsle.wu"]
+    fn __lsx_vsle_wu(a: v4u32, b: v4u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsle.du"]
+    fn __lsx_vsle_du(a: v2u64, b: v2u64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vslei.bu"]
+    fn __lsx_vslei_bu(a: v16u8, b: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vslei.hu"]
+    fn __lsx_vslei_hu(a: v8u16, b: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vslei.wu"]
+    fn __lsx_vslei_wu(a: v4u32, b: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vslei.du"]
+    fn __lsx_vslei_du(a: v2u64, b: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsat.b"]
+    fn __lsx_vsat_b(a: v16i8, b: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsat.h"]
+    fn __lsx_vsat_h(a: v8i16, b: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsat.w"]
+    fn __lsx_vsat_w(a: v4i32, b: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsat.d"]
+    fn __lsx_vsat_d(a: v2i64, b: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsat.bu"]
+    fn __lsx_vsat_bu(a: v16u8, b: u32) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vsat.hu"]
+    fn __lsx_vsat_hu(a: v8u16, b: u32) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vsat.wu"]
+    fn __lsx_vsat_wu(a: v4u32, b: u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vsat.du"]
+    fn __lsx_vsat_du(a: v2u64, b: u32) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vadda.b"]
+    fn __lsx_vadda_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vadda.h"]
+    fn __lsx_vadda_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vadda.w"]
+    fn __lsx_vadda_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vadda.d"]
+    fn __lsx_vadda_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsadd.b"]
+    fn __lsx_vsadd_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsadd.h"]
+    fn __lsx_vsadd_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsadd.w"]
+    fn __lsx_vsadd_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsadd.d"]
+    fn __lsx_vsadd_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsadd.bu"]
+    fn __lsx_vsadd_bu(a: v16u8, b: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vsadd.hu"]
+    fn __lsx_vsadd_hu(a: v8u16, b: v8u16) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vsadd.wu"]
+    fn __lsx_vsadd_wu(a: v4u32, b: v4u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vsadd.du"]
+    fn __lsx_vsadd_du(a: v2u64, b: v2u64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vavg.b"]
+    fn __lsx_vavg_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vavg.h"]
+    fn __lsx_vavg_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vavg.w"]
+    fn __lsx_vavg_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vavg.d"]
+    fn __lsx_vavg_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vavg.bu"]
+    fn __lsx_vavg_bu(a: v16u8, b: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vavg.hu"]
+    fn __lsx_vavg_hu(a: v8u16, b: v8u16) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vavg.wu"]
+    fn __lsx_vavg_wu(a: v4u32, b: v4u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vavg.du"]
+    fn __lsx_vavg_du(a: v2u64, b: v2u64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vavgr.b"]
+    fn __lsx_vavgr_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vavgr.h"]
+    fn __lsx_vavgr_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vavgr.w"]
+    fn __lsx_vavgr_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vavgr.d"]
+    fn __lsx_vavgr_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vavgr.bu"]
+    fn __lsx_vavgr_bu(a: v16u8, b: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vavgr.hu"]
+    fn __lsx_vavgr_hu(a: v8u16, b: v8u16) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vavgr.wu"]
+    fn __lsx_vavgr_wu(a: v4u32, b: v4u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vavgr.du"]
+    fn __lsx_vavgr_du(a: v2u64, b: v2u64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vssub.b"]
+    fn __lsx_vssub_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vssub.h"]
+    fn __lsx_vssub_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vssub.w"]
+    fn __lsx_vssub_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vssub.d"]
+    fn __lsx_vssub_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vssub.bu"]
+    fn __lsx_vssub_bu(a: v16u8, b: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vssub.hu"]
+    fn __lsx_vssub_hu(a: v8u16, b: v8u16) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vssub.wu"]
+    fn __lsx_vssub_wu(a: v4u32, b: v4u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vssub.du"]
+    fn __lsx_vssub_du(a: v2u64, b: v2u64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vabsd.b"]
+    fn __lsx_vabsd_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vabsd.h"]
+    fn __lsx_vabsd_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vabsd.w"]
+    fn __lsx_vabsd_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vabsd.d"]
+    fn __lsx_vabsd_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vabsd.bu"]
+    fn __lsx_vabsd_bu(a: v16u8, b: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vabsd.hu"]
+    fn __lsx_vabsd_hu(a: v8u16, b: v8u16) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vabsd.wu"]
+    fn __lsx_vabsd_wu(a: v4u32, b: v4u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vabsd.du"]
+    fn __lsx_vabsd_du(a: v2u64, b: v2u64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vmul.b"]
+    fn __lsx_vmul_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vmul.h"]
+    fn __lsx_vmul_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmul.w"]
+    fn __lsx_vmul_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmul.d"]
+    fn __lsx_vmul_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmadd.b"]
+    fn __lsx_vmadd_b(a: v16i8, b: v16i8, c: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vmadd.h"]
+    fn __lsx_vmadd_h(a: v8i16, b: v8i16, c: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmadd.w"]
+    fn __lsx_vmadd_w(a: v4i32, b: v4i32, c: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmadd.d"]
+    fn __lsx_vmadd_d(a: v2i64, b: v2i64, c: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmsub.b"]
+    fn __lsx_vmsub_b(a: v16i8, b: v16i8, c: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vmsub.h"]
+    fn __lsx_vmsub_h(a: v8i16, b: v8i16, c: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmsub.w"]
+    fn __lsx_vmsub_w(a: v4i32, b: v4i32, c: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmsub.d"]
+    fn __lsx_vmsub_d(a: v2i64, b: v2i64, c: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vdiv.b"]
+    fn __lsx_vdiv_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vdiv.h"]
+    fn __lsx_vdiv_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vdiv.w"]
+    fn __lsx_vdiv_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vdiv.d"]
+    fn __lsx_vdiv_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vdiv.bu"]
+    fn __lsx_vdiv_bu(a: v16u8, b: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vdiv.hu"]
+    fn __lsx_vdiv_hu(a: v8u16, b: v8u16) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vdiv.wu"]
+    fn __lsx_vdiv_wu(a: v4u32, b: v4u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vdiv.du"]
+    fn __lsx_vdiv_du(a: v2u64, b: v2u64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vhaddw.h.b"]
+    fn __lsx_vhaddw_h_b(a: v16i8, b: v16i8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vhaddw.w.h"]
+    fn __lsx_vhaddw_w_h(a: v8i16, b: v8i16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vhaddw.d.w"]
+    fn __lsx_vhaddw_d_w(a: v4i32, b: v4i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vhaddw.hu.bu"]
+    fn __lsx_vhaddw_hu_bu(a: v16u8, b: v16u8) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vhaddw.wu.hu"]
+    fn __lsx_vhaddw_wu_hu(a: v8u16, b: v8u16) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vhaddw.du.wu"]
+    fn __lsx_vhaddw_du_wu(a: v4u32, b: v4u32) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vhsubw.h.b"]
+    fn __lsx_vhsubw_h_b(a: v16i8, b: v16i8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vhsubw.w.h"]
+    fn __lsx_vhsubw_w_h(a: v8i16, b: v8i16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vhsubw.d.w"]
+    fn __lsx_vhsubw_d_w(a: v4i32, b: v4i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vhsubw.hu.bu"]
+    fn __lsx_vhsubw_hu_bu(a: v16u8, b: v16u8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vhsubw.wu.hu"]
+    fn __lsx_vhsubw_wu_hu(a: v8u16, b: v8u16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vhsubw.du.wu"]
+    fn __lsx_vhsubw_du_wu(a: v4u32, b: v4u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmod.b"]
+    fn __lsx_vmod_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vmod.h"]
+    fn __lsx_vmod_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmod.w"]
+    fn __lsx_vmod_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmod.d"]
+    fn __lsx_vmod_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmod.bu"]
+    fn __lsx_vmod_bu(a: v16u8, b: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vmod.hu"]
+    fn __lsx_vmod_hu(a: v8u16, b: v8u16) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vmod.wu"]
+    fn __lsx_vmod_wu(a: v4u32, b: v4u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vmod.du"]
+    fn __lsx_vmod_du(a: v2u64, b: v2u64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vreplve.b"]
+    fn __lsx_vreplve_b(a: v16i8, b: i32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vreplve.h"]
+    fn __lsx_vreplve_h(a: v8i16, b: i32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vreplve.w"]
+    fn __lsx_vreplve_w(a: v4i32, b: i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vreplve.d"]
+    fn __lsx_vreplve_d(a: v2i64, b: i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vreplvei.b"]
+    fn __lsx_vreplvei_b(a: v16i8, b: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vreplvei.h"]
+    fn __lsx_vreplvei_h(a: v8i16, b: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vreplvei.w"]
+    fn __lsx_vreplvei_w(a: v4i32, b: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vreplvei.d"]
+    fn __lsx_vreplvei_d(a: v2i64, b: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vpickev.b"]
+    fn __lsx_vpickev_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vpickev.h"]
+    fn __lsx_vpickev_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vpickev.w"]
+    fn __lsx_vpickev_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vpickev.d"]
+    fn __lsx_vpickev_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vpickod.b"]
+    fn __lsx_vpickod_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vpickod.h"]
+    fn __lsx_vpickod_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vpickod.w"]
+    fn __lsx_vpickod_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vpickod.d"]
+    fn __lsx_vpickod_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vilvh.b"]
+    fn __lsx_vilvh_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vilvh.h"]
+    fn __lsx_vilvh_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vilvh.w"]
+    fn __lsx_vilvh_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vilvh.d"]
+    fn __lsx_vilvh_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vilvl.b"]
+    fn __lsx_vilvl_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vilvl.h"]
+    fn __lsx_vilvl_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vilvl.w"]
+    fn __lsx_vilvl_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vilvl.d"]
+    fn __lsx_vilvl_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vpackev.b"]
+    fn __lsx_vpackev_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vpackev.h"]
+    fn __lsx_vpackev_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vpackev.w"]
+    fn __lsx_vpackev_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vpackev.d"]
+    fn __lsx_vpackev_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vpackod.b"]
+    fn __lsx_vpackod_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vpackod.h"]
+    fn __lsx_vpackod_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vpackod.w"]
+    fn __lsx_vpackod_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vpackod.d"]
+    fn __lsx_vpackod_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vshuf.h"]
+    fn __lsx_vshuf_h(a: v8i16, b: v8i16, c: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vshuf.w"]
+    fn __lsx_vshuf_w(a: v4i32, b: v4i32, c: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vshuf.d"]
+    fn __lsx_vshuf_d(a: v2i64, b: v2i64, c: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vand.v"]
+    fn __lsx_vand_v(a: v16u8, b: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vandi.b"]
+    fn __lsx_vandi_b(a: v16u8, b: u32) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vor.v"]
+    fn __lsx_vor_v(a: v16u8, b: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vori.b"]
+    fn __lsx_vori_b(a: v16u8, b: u32) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vnor.v"]
+    fn __lsx_vnor_v(a: v16u8, b: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vnori.b"]
+    fn __lsx_vnori_b(a: v16u8, b: u32) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vxor.v"]
+    fn __lsx_vxor_v(a: v16u8, b: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vxori.b"]
+    fn __lsx_vxori_b(a: v16u8, b: u32) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vbitsel.v"]
+    fn __lsx_vbitsel_v(a: v16u8, b: v16u8, c: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vbitseli.b"]
+    fn __lsx_vbitseli_b(a: v16u8, b: v16u8, c: u32) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vshuf4i.b"]
+    fn __lsx_vshuf4i_b(a: v16i8, b: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vshuf4i.h"]
+    fn __lsx_vshuf4i_h(a: v8i16, b: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vshuf4i.w"]
+    fn __lsx_vshuf4i_w(a: v4i32, b: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vreplgr2vr.b"]
+    fn __lsx_vreplgr2vr_b(a: i32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vreplgr2vr.h"]
+    fn __lsx_vreplgr2vr_h(a: i32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vreplgr2vr.w"]
+    fn __lsx_vreplgr2vr_w(a: i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vreplgr2vr.d"]
+    fn __lsx_vreplgr2vr_d(a: i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vpcnt.b"]
+    fn __lsx_vpcnt_b(a: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vpcnt.h"]
+    fn __lsx_vpcnt_h(a: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vpcnt.w"]
+    fn __lsx_vpcnt_w(a: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vpcnt.d"]
+    fn __lsx_vpcnt_d(a: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vclo.b"]
+    fn __lsx_vclo_b(a: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vclo.h"]
+    fn __lsx_vclo_h(a: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vclo.w"]
+    fn __lsx_vclo_w(a: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vclo.d"]
+    fn __lsx_vclo_d(a: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vclz.b"]
+    fn __lsx_vclz_b(a: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vclz.h"]
+    fn __lsx_vclz_h(a: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vclz.w"]
+    fn __lsx_vclz_w(a: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vclz.d"]
+    fn __lsx_vclz_d(a: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vpickve2gr.b"]
+    fn __lsx_vpickve2gr_b(a: v16i8, b: u32) -> i32;
+    #[link_name = "llvm.loongarch.lsx.vpickve2gr.h"]
+    fn __lsx_vpickve2gr_h(a: v8i16, b: u32) -> i32;
+    #[link_name = "llvm.loongarch.lsx.vpickve2gr.w"]
+    fn __lsx_vpickve2gr_w(a: v4i32, b: u32) -> i32;
+    #[link_name = "llvm.loongarch.lsx.vpickve2gr.d"]
+    fn __lsx_vpickve2gr_d(a: v2i64, b: u32) -> i64;
+    #[link_name = "llvm.loongarch.lsx.vpickve2gr.bu"]
+    fn __lsx_vpickve2gr_bu(a: v16i8, b: u32) -> u32;
+    #[link_name = "llvm.loongarch.lsx.vpickve2gr.hu"]
+    fn __lsx_vpickve2gr_hu(a: v8i16, b: u32) -> u32;
+    #[link_name = "llvm.loongarch.lsx.vpickve2gr.wu"]
+    fn __lsx_vpickve2gr_wu(a: v4i32, b: u32) -> u32;
+    #[link_name = "llvm.loongarch.lsx.vpickve2gr.du"]
+    fn __lsx_vpickve2gr_du(a: v2i64, b: u32) -> u64;
+    #[link_name = "llvm.loongarch.lsx.vinsgr2vr.b"]
+    fn __lsx_vinsgr2vr_b(a: v16i8, b: i32, c: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vinsgr2vr.h"]
+    fn __lsx_vinsgr2vr_h(a: v8i16, b: i32, c: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vinsgr2vr.w"]
+    fn __lsx_vinsgr2vr_w(a: v4i32, b: i32, c: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vinsgr2vr.d"]
+    fn __lsx_vinsgr2vr_d(a: v2i64, b: i64, c: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfadd.s"]
+    fn __lsx_vfadd_s(a: v4f32, b: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfadd.d"]
+    fn __lsx_vfadd_d(a: v2f64, b: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vfsub.s"]
+    fn __lsx_vfsub_s(a: v4f32, b: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfsub.d"]
+    fn __lsx_vfsub_d(a: v2f64, b: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vfmul.s"]
+    fn __lsx_vfmul_s(a: v4f32, b: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfmul.d"]
+    fn __lsx_vfmul_d(a: v2f64, b: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vfdiv.s"]
+    fn __lsx_vfdiv_s(a: v4f32, b: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfdiv.d"]
+    fn __lsx_vfdiv_d(a: v2f64, b: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vfcvt.h.s"]
+    fn __lsx_vfcvt_h_s(a: v4f32, b: v4f32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vfcvt.s.d"]
+    fn __lsx_vfcvt_s_d(a: v2f64, b: v2f64) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfmin.s"]
+    fn __lsx_vfmin_s(a: v4f32, b: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfmin.d"]
+    fn __lsx_vfmin_d(a: v2f64, b: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vfmina.s"]
+    fn __lsx_vfmina_s(a: v4f32, b: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfmina.d"]
+    fn __lsx_vfmina_d(a: v2f64, b: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vfmax.s"]
+    fn __lsx_vfmax_s(a: v4f32, b: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfmax.d"]
+    fn __lsx_vfmax_d(a: v2f64, b: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vfmaxa.s"]
+    fn __lsx_vfmaxa_s(a: v4f32, b: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfmaxa.d"]
+    fn __lsx_vfmaxa_d(a: v2f64, b: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vfclass.s"]
+    fn __lsx_vfclass_s(a: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfclass.d"]
+    fn __lsx_vfclass_d(a: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfsqrt.s"]
+    fn __lsx_vfsqrt_s(a: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfsqrt.d"]
+    fn __lsx_vfsqrt_d(a: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vfrecip.s"]
+    fn __lsx_vfrecip_s(a: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfrecip.d"]
+    fn __lsx_vfrecip_d(a: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vfrint.s"]
+    fn __lsx_vfrint_s(a: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfrint.d"]
+    fn __lsx_vfrint_d(a: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vfrsqrt.s"]
+    fn __lsx_vfrsqrt_s(a: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfrsqrt.d"]
+    fn __lsx_vfrsqrt_d(a: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vflogb.s"]
+    fn __lsx_vflogb_s(a: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vflogb.d"]
+    fn __lsx_vflogb_d(a: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vfcvth.s.h"]
+    fn __lsx_vfcvth_s_h(a: v8i16) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfcvth.d.s"]
+    fn __lsx_vfcvth_d_s(a: v4f32) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vfcvtl.s.h"]
+    fn __lsx_vfcvtl_s_h(a: v8i16) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfcvtl.d.s"]
+    fn __lsx_vfcvtl_d_s(a: v4f32) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vftint.w.s"]
+    fn __lsx_vftint_w_s(a: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vftint.l.d"]
+    fn __lsx_vftint_l_d(a: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vftint.wu.s"]
+    fn __lsx_vftint_wu_s(a: v4f32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vftint.lu.d"]
+    fn __lsx_vftint_lu_d(a: v2f64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vftintrz.w.s"]
+    fn __lsx_vftintrz_w_s(a: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vftintrz.l.d"]
+    fn __lsx_vftintrz_l_d(a: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vftintrz.wu.s"]
+    fn __lsx_vftintrz_wu_s(a: v4f32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vftintrz.lu.d"]
+    fn __lsx_vftintrz_lu_d(a: v2f64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vffint.s.w"]
+    fn __lsx_vffint_s_w(a: v4i32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vffint.d.l"]
+    fn __lsx_vffint_d_l(a: v2i64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vffint.s.wu"]
+    fn __lsx_vffint_s_wu(a: v4u32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vffint.d.lu"]
+    fn __lsx_vffint_d_lu(a: v2u64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vandn.v"]
+    fn __lsx_vandn_v(a: v16u8, b: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vneg.b"]
+    fn __lsx_vneg_b(a: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vneg.h"]
+    fn __lsx_vneg_h(a: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vneg.w"]
+    fn __lsx_vneg_w(a: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vneg.d"]
+    fn __lsx_vneg_d(a: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmuh.b"]
+    fn __lsx_vmuh_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vmuh.h"]
+    fn __lsx_vmuh_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmuh.w"]
+    fn __lsx_vmuh_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmuh.d"]
+    fn __lsx_vmuh_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmuh.bu"]
+    fn __lsx_vmuh_bu(a: v16u8, b: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vmuh.hu"]
+    fn __lsx_vmuh_hu(a: v8u16, b: v8u16) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vmuh.wu"]
+    fn __lsx_vmuh_wu(a: v4u32, b: v4u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vmuh.du"]
+    fn __lsx_vmuh_du(a: v2u64, b: v2u64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vsllwil.h.b"]
+    fn __lsx_vsllwil_h_b(a: v16i8, b: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsllwil.w.h"]
+    fn __lsx_vsllwil_w_h(a: v8i16, b: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsllwil.d.w"]
+    fn __lsx_vsllwil_d_w(a: v4i32, b: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsllwil.hu.bu"]
+    fn __lsx_vsllwil_hu_bu(a: v16u8, b: u32) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vsllwil.wu.hu"]
+    fn __lsx_vsllwil_wu_hu(a: v8u16, b: u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vsllwil.du.wu"]
+    fn __lsx_vsllwil_du_wu(a: v4u32, b: u32) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vsran.b.h"]
+    fn __lsx_vsran_b_h(a: v8i16, b: v8i16) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsran.h.w"]
+    fn __lsx_vsran_h_w(a: v4i32, b: v4i32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsran.w.d"]
+    fn __lsx_vsran_w_d(a: v2i64, b: v2i64) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vssran.b.h"]
+    fn __lsx_vssran_b_h(a: v8i16, b: v8i16) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vssran.h.w"]
+    fn __lsx_vssran_h_w(a: v4i32, b: v4i32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vssran.w.d"]
+    fn __lsx_vssran_w_d(a: v2i64, b: v2i64) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vssran.bu.h"]
+    fn __lsx_vssran_bu_h(a: v8u16, b: v8u16) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vssran.hu.w"]
+    fn __lsx_vssran_hu_w(a: v4u32, b: v4u32) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vssran.wu.d"]
+    fn __lsx_vssran_wu_d(a: v2u64, b: v2u64) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vsrarn.b.h"]
+    fn __lsx_vsrarn_b_h(a: v8i16, b: v8i16) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsrarn.h.w"]
+    fn __lsx_vsrarn_h_w(a: v4i32, b: v4i32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsrarn.w.d"]
+    fn __lsx_vsrarn_w_d(a: v2i64, b: v2i64) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vssrarn.b.h"]
+    fn __lsx_vssrarn_b_h(a: v8i16, b: v8i16) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vssrarn.h.w"]
+    fn __lsx_vssrarn_h_w(a: v4i32, b: v4i32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vssrarn.w.d"]
+    fn __lsx_vssrarn_w_d(a: v2i64, b: v2i64) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vssrarn.bu.h"]
+    fn __lsx_vssrarn_bu_h(a: v8u16, b: v8u16) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vssrarn.hu.w"]
+    fn __lsx_vssrarn_hu_w(a: v4u32, b: v4u32) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vssrarn.wu.d"]
+    fn __lsx_vssrarn_wu_d(a: v2u64, b: v2u64) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vsrln.b.h"]
+    fn __lsx_vsrln_b_h(a: v8i16, b: v8i16) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsrln.h.w"]
+    fn __lsx_vsrln_h_w(a: v4i32, b: v4i32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsrln.w.d"]
+    fn __lsx_vsrln_w_d(a: v2i64, b: v2i64) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vssrln.bu.h"]
+    fn __lsx_vssrln_bu_h(a: v8u16, b: v8u16) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vssrln.hu.w"]
+    fn __lsx_vssrln_hu_w(a: v4u32, b: v4u32) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vssrln.wu.d"]
+    fn __lsx_vssrln_wu_d(a: v2u64, b: v2u64) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vsrlrn.b.h"]
+    fn __lsx_vsrlrn_b_h(a: v8i16, b: v8i16) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsrlrn.h.w"]
+    fn __lsx_vsrlrn_h_w(a: v4i32, b: v4i32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsrlrn.w.d"]
+    fn __lsx_vsrlrn_w_d(a: v2i64, b: v2i64) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vssrlrn.bu.h"]
+    fn __lsx_vssrlrn_bu_h(a: v8u16, b: v8u16) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vssrlrn.hu.w"]
+    fn __lsx_vssrlrn_hu_w(a: v4u32, b: v4u32) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vssrlrn.wu.d"]
+    fn __lsx_vssrlrn_wu_d(a: v2u64, b: v2u64) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vfrstpi.b"]
+    fn __lsx_vfrstpi_b(a: v16i8, b: v16i8, c: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vfrstpi.h"]
+    fn __lsx_vfrstpi_h(a: v8i16, b: v8i16, c: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vfrstp.b"]
+    fn __lsx_vfrstp_b(a: v16i8, b: v16i8, c: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vfrstp.h"]
+    fn __lsx_vfrstp_h(a: v8i16, b: v8i16, c: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vshuf4i.d"]
+    fn __lsx_vshuf4i_d(a: v2i64, b: v2i64, c: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vbsrl.v"]
+    fn __lsx_vbsrl_v(a: v16i8, b: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vbsll.v"]
+    fn __lsx_vbsll_v(a: v16i8, b: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vextrins.b"]
+    fn __lsx_vextrins_b(a: v16i8, b: v16i8, c: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vextrins.h"]
+    fn __lsx_vextrins_h(a: v8i16, b: v8i16, c: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vextrins.w"]
+    fn __lsx_vextrins_w(a: v4i32, b: v4i32, c: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vextrins.d"]
+    fn __lsx_vextrins_d(a: v2i64, b: v2i64, c: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmskltz.b"]
+    fn __lsx_vmskltz_b(a: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vmskltz.h"]
+    fn __lsx_vmskltz_h(a: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmskltz.w"]
+    fn __lsx_vmskltz_w(a: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmskltz.d"]
+    fn __lsx_vmskltz_d(a: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsigncov.b"]
+    fn __lsx_vsigncov_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsigncov.h"]
+    fn __lsx_vsigncov_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsigncov.w"]
+    fn __lsx_vsigncov_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsigncov.d"]
+    fn __lsx_vsigncov_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfmadd.s"]
+    fn __lsx_vfmadd_s(a: v4f32, b: v4f32, c: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfmadd.d"]
+    fn __lsx_vfmadd_d(a: v2f64, b: v2f64, c: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vfmsub.s"]
+    fn __lsx_vfmsub_s(a: v4f32, b: v4f32, c: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfmsub.d"]
+    fn __lsx_vfmsub_d(a: v2f64, b: v2f64, c: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vfnmadd.s"]
+    fn __lsx_vfnmadd_s(a: v4f32, b: v4f32, c: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfnmadd.d"]
+    fn __lsx_vfnmadd_d(a: v2f64, b: v2f64, c: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vfnmsub.s"]
+    fn __lsx_vfnmsub_s(a: v4f32, b: v4f32, c: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfnmsub.d"]
+    fn __lsx_vfnmsub_d(a: v2f64, b: v2f64, c: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vftintrne.w.s"]
+    fn __lsx_vftintrne_w_s(a: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vftintrne.l.d"]
+    fn __lsx_vftintrne_l_d(a: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vftintrp.w.s"]
+    fn __lsx_vftintrp_w_s(a: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vftintrp.l.d"]
+    fn __lsx_vftintrp_l_d(a: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vftintrm.w.s"]
+    fn __lsx_vftintrm_w_s(a: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vftintrm.l.d"]
+    fn __lsx_vftintrm_l_d(a: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vftint.w.d"]
+    fn __lsx_vftint_w_d(a: v2f64, b: v2f64) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vffint.s.l"]
+    fn __lsx_vffint_s_l(a: v2i64, b: v2i64) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vftintrz.w.d"]
+    fn __lsx_vftintrz_w_d(a: v2f64, b: v2f64) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vftintrp.w.d"]
+    fn __lsx_vftintrp_w_d(a: v2f64, b: v2f64) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vftintrm.w.d"]
+    fn __lsx_vftintrm_w_d(a: v2f64, b: v2f64) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vftintrne.w.d"]
+    fn __lsx_vftintrne_w_d(a: v2f64, b: v2f64) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vftintl.l.s"]
+    fn __lsx_vftintl_l_s(a: v4f32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vftinth.l.s"]
+    fn __lsx_vftinth_l_s(a: v4f32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vffinth.d.w"]
+    fn __lsx_vffinth_d_w(a: v4i32) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vffintl.d.w"]
+    fn __lsx_vffintl_d_w(a: v4i32) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vftintrzl.l.s"]
+    fn __lsx_vftintrzl_l_s(a: v4f32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vftintrzh.l.s"]
+    fn __lsx_vftintrzh_l_s(a: v4f32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vftintrpl.l.s"]
+    fn __lsx_vftintrpl_l_s(a: v4f32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vftintrph.l.s"]
+    fn __lsx_vftintrph_l_s(a: v4f32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vftintrml.l.s"]
+    fn __lsx_vftintrml_l_s(a: v4f32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vftintrmh.l.s"]
+    fn __lsx_vftintrmh_l_s(a: v4f32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vftintrnel.l.s"]
+    fn __lsx_vftintrnel_l_s(a: v4f32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vftintrneh.l.s"]
+    fn __lsx_vftintrneh_l_s(a: v4f32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfrintrne.s"]
+    fn __lsx_vfrintrne_s(a: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfrintrne.d"]
+    fn __lsx_vfrintrne_d(a: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vfrintrz.s"]
+    fn __lsx_vfrintrz_s(a: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfrintrz.d"]
+    fn __lsx_vfrintrz_d(a: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vfrintrp.s"]
+    fn __lsx_vfrintrp_s(a: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfrintrp.d"]
+    fn __lsx_vfrintrp_d(a: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vfrintrm.s"]
+    fn __lsx_vfrintrm_s(a: v4f32) -> v4f32;
+    #[link_name = "llvm.loongarch.lsx.vfrintrm.d"]
+    fn __lsx_vfrintrm_d(a: v2f64) -> v2f64;
+    #[link_name = "llvm.loongarch.lsx.vstelm.b"]
+    fn __lsx_vstelm_b(a: v16i8, mem_addr: *mut i8, b: i32, c: u32);
+    #[link_name = "llvm.loongarch.lsx.vstelm.h"]
+    fn __lsx_vstelm_h(a: v8i16, mem_addr: *mut i8, b: i32, c: u32);
+    #[link_name = "llvm.loongarch.lsx.vstelm.w"]
+    fn __lsx_vstelm_w(a: v4i32, mem_addr: *mut i8, b: i32, c: u32);
+    #[link_name = "llvm.loongarch.lsx.vstelm.d"]
+    fn __lsx_vstelm_d(a: v2i64, mem_addr: *mut i8, b: i32, c: u32);
+    #[link_name = "llvm.loongarch.lsx.vaddwev.d.w"]
+    fn __lsx_vaddwev_d_w(a: v4i32, b: v4i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vaddwev.w.h"]
+    fn __lsx_vaddwev_w_h(a: v8i16, b: v8i16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vaddwev.h.b"]
+    fn __lsx_vaddwev_h_b(a: v16i8, b: v16i8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vaddwod.d.w"]
+    fn __lsx_vaddwod_d_w(a: v4i32, b: v4i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vaddwod.w.h"]
+    fn __lsx_vaddwod_w_h(a: v8i16, b: v8i16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vaddwod.h.b"]
+    fn __lsx_vaddwod_h_b(a: v16i8, b: v16i8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vaddwev.d.wu"]
+    fn __lsx_vaddwev_d_wu(a: v4u32, b: v4u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vaddwev.w.hu"]
+    fn __lsx_vaddwev_w_hu(a: v8u16, b: v8u16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vaddwev.h.bu"]
+    fn __lsx_vaddwev_h_bu(a: v16u8, b: v16u8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vaddwod.d.wu"]
+    fn __lsx_vaddwod_d_wu(a: v4u32, b: v4u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vaddwod.w.hu"]
+    fn __lsx_vaddwod_w_hu(a: v8u16, b: v8u16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vaddwod.h.bu"]
+    fn __lsx_vaddwod_h_bu(a: v16u8, b: v16u8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vaddwev.d.wu.w"]
+    fn __lsx_vaddwev_d_wu_w(a: v4u32, b: v4i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vaddwev.w.hu.h"]
+    fn __lsx_vaddwev_w_hu_h(a: v8u16, b: v8i16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vaddwev.h.bu.b"]
+    fn __lsx_vaddwev_h_bu_b(a: v16u8, b: v16i8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vaddwod.d.wu.w"]
+    fn __lsx_vaddwod_d_wu_w(a: v4u32, b: v4i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vaddwod.w.hu.h"]
+    fn __lsx_vaddwod_w_hu_h(a: v8u16, b: v8i16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vaddwod.h.bu.b"]
+    fn __lsx_vaddwod_h_bu_b(a: v16u8, b: v16i8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsubwev.d.w"]
+    fn __lsx_vsubwev_d_w(a: v4i32, b: v4i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsubwev.w.h"]
+    fn __lsx_vsubwev_w_h(a: v8i16, b: v8i16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsubwev.h.b"]
+    fn __lsx_vsubwev_h_b(a: v16i8, b: v16i8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsubwod.d.w"]
+    fn __lsx_vsubwod_d_w(a: v4i32, b: v4i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsubwod.w.h"]
+    fn __lsx_vsubwod_w_h(a: v8i16, b: v8i16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsubwod.h.b"]
+    fn __lsx_vsubwod_h_b(a: v16i8, b: v16i8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsubwev.d.wu"]
+    fn __lsx_vsubwev_d_wu(a: v4u32, b: v4u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsubwev.w.hu"]
+    fn __lsx_vsubwev_w_hu(a: v8u16, b: v8u16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsubwev.h.bu"]
+    fn __lsx_vsubwev_h_bu(a: v16u8, b: v16u8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsubwod.d.wu"]
+    fn __lsx_vsubwod_d_wu(a: v4u32, b: v4u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsubwod.w.hu"]
+    fn __lsx_vsubwod_w_hu(a: v8u16, b: v8u16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsubwod.h.bu"]
+    fn __lsx_vsubwod_h_bu(a: v16u8, b: v16u8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vaddwev.q.d"]
+    fn __lsx_vaddwev_q_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vaddwod.q.d"]
+    fn __lsx_vaddwod_q_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vaddwev.q.du"]
+    fn __lsx_vaddwev_q_du(a: v2u64, b: v2u64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vaddwod.q.du"]
+    fn __lsx_vaddwod_q_du(a: v2u64, b: v2u64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsubwev.q.d"]
+    fn __lsx_vsubwev_q_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsubwod.q.d"]
+    fn __lsx_vsubwod_q_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsubwev.q.du"]
+    fn __lsx_vsubwev_q_du(a: v2u64, b: v2u64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsubwod.q.du"]
+    fn __lsx_vsubwod_q_du(a: v2u64, b: v2u64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vaddwev.q.du.d"]
+    fn __lsx_vaddwev_q_du_d(a: v2u64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vaddwod.q.du.d"]
+    fn __lsx_vaddwod_q_du_d(a: v2u64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmulwev.d.w"]
+    fn __lsx_vmulwev_d_w(a: v4i32, b: v4i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmulwev.w.h"]
+    fn __lsx_vmulwev_w_h(a: v8i16, b: v8i16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmulwev.h.b"]
+    fn __lsx_vmulwev_h_b(a: v16i8, b: v16i8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmulwod.d.w"]
+    fn __lsx_vmulwod_d_w(a: v4i32, b: v4i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmulwod.w.h"]
+    fn __lsx_vmulwod_w_h(a: v8i16, b: v8i16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmulwod.h.b"]
+    fn __lsx_vmulwod_h_b(a: v16i8, b: v16i8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmulwev.d.wu"]
+    fn __lsx_vmulwev_d_wu(a: v4u32, b: v4u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmulwev.w.hu"]
+    fn __lsx_vmulwev_w_hu(a: v8u16, b: v8u16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmulwev.h.bu"]
+    fn __lsx_vmulwev_h_bu(a: v16u8, b: v16u8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmulwod.d.wu"]
+    fn __lsx_vmulwod_d_wu(a: v4u32, b: v4u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmulwod.w.hu"]
+    fn __lsx_vmulwod_w_hu(a: v8u16, b: v8u16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmulwod.h.bu"]
+    fn __lsx_vmulwod_h_bu(a: v16u8, b: v16u8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmulwev.d.wu.w"]
+    fn __lsx_vmulwev_d_wu_w(a: v4u32, b: v4i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmulwev.w.hu.h"]
+    fn __lsx_vmulwev_w_hu_h(a: v8u16, b: v8i16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmulwev.h.bu.b"]
+    fn __lsx_vmulwev_h_bu_b(a: v16u8, b: v16i8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmulwod.d.wu.w"]
+    fn __lsx_vmulwod_d_wu_w(a: v4u32, b: v4i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmulwod.w.hu.h"]
+    fn __lsx_vmulwod_w_hu_h(a: v8u16, b: v8i16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmulwod.h.bu.b"]
+    fn __lsx_vmulwod_h_bu_b(a: v16u8, b: v16i8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmulwev.q.d"]
+    fn __lsx_vmulwev_q_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmulwod.q.d"]
+    fn __lsx_vmulwod_q_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmulwev.q.du"]
+    fn __lsx_vmulwev_q_du(a: v2u64, b: v2u64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmulwod.q.du"]
+    fn __lsx_vmulwod_q_du(a: v2u64, b: v2u64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmulwev.q.du.d"]
+    fn __lsx_vmulwev_q_du_d(a: v2u64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmulwod.q.du.d"]
+    fn __lsx_vmulwod_q_du_d(a: v2u64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vhaddw.q.d"]
+    fn __lsx_vhaddw_q_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vhaddw.qu.du"]
+    fn __lsx_vhaddw_qu_du(a: v2u64, b: v2u64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vhsubw.q.d"]
+    fn __lsx_vhsubw_q_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vhsubw.qu.du"]
+    fn __lsx_vhsubw_qu_du(a: v2u64, b: v2u64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vmaddwev.d.w"]
+    fn __lsx_vmaddwev_d_w(a: v2i64, b: v4i32, c: v4i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmaddwev.w.h"]
+    fn __lsx_vmaddwev_w_h(a: v4i32, b: v8i16, c: v8i16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmaddwev.h.b"]
+    fn __lsx_vmaddwev_h_b(a: v8i16, b: v16i8, c: v16i8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmaddwev.d.wu"]
+    fn __lsx_vmaddwev_d_wu(a: v2u64, b: v4u32, c: v4u32) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vmaddwev.w.hu"]
+    fn __lsx_vmaddwev_w_hu(a: v4u32, b: v8u16, c: v8u16) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vmaddwev.h.bu"]
+    fn __lsx_vmaddwev_h_bu(a: v8u16, b: v16u8, c: v16u8) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vmaddwod.d.w"]
+    fn __lsx_vmaddwod_d_w(a: v2i64, b: v4i32, c: v4i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmaddwod.w.h"]
+    fn __lsx_vmaddwod_w_h(a: v4i32, b: v8i16, c: v8i16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmaddwod.h.b"]
+    fn __lsx_vmaddwod_h_b(a: v8i16, b: v16i8, c: v16i8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmaddwod.d.wu"]
+    fn __lsx_vmaddwod_d_wu(a: v2u64, b: v4u32, c: v4u32) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vmaddwod.w.hu"]
+    fn __lsx_vmaddwod_w_hu(a: v4u32, b: v8u16, c: v8u16) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vmaddwod.h.bu"]
+    fn __lsx_vmaddwod_h_bu(a: v8u16, b: v16u8, c: v16u8) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vmaddwev.d.wu.w"]
+    fn __lsx_vmaddwev_d_wu_w(a: v2i64, b: v4u32, c: v4i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmaddwev.w.hu.h"]
+    fn __lsx_vmaddwev_w_hu_h(a: v4i32, b: v8u16, c: v8i16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmaddwev.h.bu.b"]
+    fn __lsx_vmaddwev_h_bu_b(a: v8i16, b: v16u8, c: v16i8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmaddwod.d.wu.w"]
+    fn __lsx_vmaddwod_d_wu_w(a: v2i64, b: v4u32, c: v4i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmaddwod.w.hu.h"]
+    fn __lsx_vmaddwod_w_hu_h(a: v4i32, b: v8u16, c: v8i16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vmaddwod.h.bu.b"]
+    fn __lsx_vmaddwod_h_bu_b(a: v8i16, b: v16u8, c: v16i8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vmaddwev.q.d"]
+    fn __lsx_vmaddwev_q_d(a: v2i64, b: v2i64, c: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmaddwod.q.d"]
+    fn __lsx_vmaddwod_q_d(a: v2i64, b: v2i64, c: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmaddwev.q.du"]
+    fn __lsx_vmaddwev_q_du(a: v2u64, b: v2u64, c: v2u64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vmaddwod.q.du"]
+    fn __lsx_vmaddwod_q_du(a: v2u64, b: v2u64, c: v2u64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vmaddwev.q.du.d"]
+    fn __lsx_vmaddwev_q_du_d(a: v2i64, b: v2u64, c: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmaddwod.q.du.d"]
+    fn __lsx_vmaddwod_q_du_d(a: v2i64, b: v2u64, c: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vrotr.b"]
+    fn __lsx_vrotr_b(a: v16i8, b: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vrotr.h"]
+    fn __lsx_vrotr_h(a: v8i16, b: v8i16) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vrotr.w"]
+    fn __lsx_vrotr_w(a: v4i32, b: v4i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vrotr.d"]
+    fn __lsx_vrotr_d(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vadd.q"]
+    fn __lsx_vadd_q(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsub.q"]
+    fn __lsx_vsub_q(a: v2i64, b: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vldrepl.b"]
+    fn __lsx_vldrepl_b(mem_addr: *const i8, a: i32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vldrepl.h"]
+    fn __lsx_vldrepl_h(mem_addr: *const i8, a: i32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vldrepl.w"]
+    fn __lsx_vldrepl_w(mem_addr: *const i8, a: i32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vldrepl.d"]
+    fn __lsx_vldrepl_d(mem_addr: *const i8, a: i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vmskgez.b"]
+    fn __lsx_vmskgez_b(a: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vmsknz.b"]
+    fn __lsx_vmsknz_b(a: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vexth.h.b"]
+    fn __lsx_vexth_h_b(a: v16i8) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vexth.w.h"]
+    fn __lsx_vexth_w_h(a: v8i16) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vexth.d.w"]
+    fn __lsx_vexth_d_w(a: v4i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vexth.q.d"]
+    fn __lsx_vexth_q_d(a: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vexth.hu.bu"]
+    fn __lsx_vexth_hu_bu(a: v16u8) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vexth.wu.hu"]
+    fn __lsx_vexth_wu_hu(a: v8u16) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vexth.du.wu"]
+    fn __lsx_vexth_du_wu(a: v4u32) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vexth.qu.du"]
+    fn __lsx_vexth_qu_du(a: v2u64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vrotri.b"]
+    fn __lsx_vrotri_b(a: v16i8, b: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vrotri.h"]
+    fn __lsx_vrotri_h(a: v8i16, b: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vrotri.w"]
+    fn __lsx_vrotri_w(a: v4i32, b: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vrotri.d"]
+    fn __lsx_vrotri_d(a: v2i64, b: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vextl.q.d"]
+    fn __lsx_vextl_q_d(a: v2i64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsrlni.b.h"]
+    fn __lsx_vsrlni_b_h(a: v16i8, b: v16i8, c: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsrlni.h.w"]
+    fn __lsx_vsrlni_h_w(a: v8i16, b: v8i16, c: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsrlni.w.d"]
+    fn __lsx_vsrlni_w_d(a: v4i32, b: v4i32, c: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsrlni.d.q"]
+    fn __lsx_vsrlni_d_q(a: v2i64, b: v2i64, c: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsrlrni.b.h"]
+    fn __lsx_vsrlrni_b_h(a: v16i8, b: v16i8, c: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsrlrni.h.w"]
+    fn __lsx_vsrlrni_h_w(a: v8i16, b: v8i16, c: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsrlrni.w.d"]
+    fn __lsx_vsrlrni_w_d(a: v4i32, b: v4i32, c: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsrlrni.d.q"]
+    fn __lsx_vsrlrni_d_q(a: v2i64, b: v2i64, c: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vssrlni.b.h"]
+    fn __lsx_vssrlni_b_h(a: v16i8, b: v16i8, c: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vssrlni.h.w"]
+    fn __lsx_vssrlni_h_w(a: v8i16, b: v8i16, c: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vssrlni.w.d"]
+    fn __lsx_vssrlni_w_d(a: v4i32, b: v4i32, c: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vssrlni.d.q"]
+    fn __lsx_vssrlni_d_q(a: v2i64, b: v2i64, c: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vssrlni.bu.h"]
+    fn __lsx_vssrlni_bu_h(a: v16u8, b: v16i8, c: u32) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vssrlni.hu.w"]
+    fn __lsx_vssrlni_hu_w(a: v8u16, b: v8i16, c: u32) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vssrlni.wu.d"]
+    fn __lsx_vssrlni_wu_d(a: v4u32, b: v4i32, c: u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vssrlni.du.q"]
+    fn __lsx_vssrlni_du_q(a: v2u64, b: v2i64, c: u32) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vssrlrni.b.h"]
+    fn __lsx_vssrlrni_b_h(a: v16i8, b: v16i8, c: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vssrlrni.h.w"]
+    fn __lsx_vssrlrni_h_w(a: v8i16, b: v8i16, c: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vssrlrni.w.d"]
+    fn __lsx_vssrlrni_w_d(a: v4i32, b: v4i32, c: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vssrlrni.d.q"]
+    fn __lsx_vssrlrni_d_q(a: v2i64, b: v2i64, c: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vssrlrni.bu.h"]
+    fn __lsx_vssrlrni_bu_h(a: v16u8, b: v16i8, c: u32) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vssrlrni.hu.w"]
+    fn __lsx_vssrlrni_hu_w(a: v8u16, b: v8i16, c: u32) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vssrlrni.wu.d"]
+    fn __lsx_vssrlrni_wu_d(a: v4u32, b: v4i32, c: u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vssrlrni.du.q"]
+    fn __lsx_vssrlrni_du_q(a: v2u64, b: v2i64, c: u32) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vsrani.b.h"]
+    fn __lsx_vsrani_b_h(a: v16i8, b: v16i8, c: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsrani.h.w"]
+    fn __lsx_vsrani_h_w(a: v8i16, b: v8i16, c: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsrani.w.d"]
+    fn __lsx_vsrani_w_d(a: v4i32, b: v4i32, c: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsrani.d.q"]
+    fn __lsx_vsrani_d_q(a: v2i64, b: v2i64, c: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vsrarni.b.h"]
+    fn __lsx_vsrarni_b_h(a: v16i8, b: v16i8, c: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vsrarni.h.w"]
+    fn __lsx_vsrarni_h_w(a: v8i16, b: v8i16, c: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vsrarni.w.d"]
+    fn __lsx_vsrarni_w_d(a: v4i32, b: v4i32, c: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vsrarni.d.q"]
+    fn __lsx_vsrarni_d_q(a: v2i64, b: v2i64, c: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vssrani.b.h"]
+    fn __lsx_vssrani_b_h(a: v16i8, b: v16i8, c: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vssrani.h.w"]
+    fn __lsx_vssrani_h_w(a: v8i16, b: v8i16, c: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vssrani.w.d"]
+    fn __lsx_vssrani_w_d(a: v4i32, b: v4i32, c: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vssrani.d.q"]
+    fn __lsx_vssrani_d_q(a: v2i64, b: v2i64, c: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vssrani.bu.h"]
+    fn __lsx_vssrani_bu_h(a: v16u8, b: v16i8, c: u32) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vssrani.hu.w"]
+    fn __lsx_vssrani_hu_w(a: v8u16, b: v8i16, c: u32) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vssrani.wu.d"]
+    fn __lsx_vssrani_wu_d(a: v4u32, b: v4i32, c: u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vssrani.du.q"]
+    fn __lsx_vssrani_du_q(a: v2u64, b: v2i64, c: u32) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vssrarni.b.h"]
+    fn __lsx_vssrarni_b_h(a: v16i8, b: v16i8, c: u32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vssrarni.h.w"]
+    fn __lsx_vssrarni_h_w(a: v8i16, b: v8i16, c: u32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vssrarni.w.d"]
+    fn __lsx_vssrarni_w_d(a: v4i32, b: v4i32, c: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vssrarni.d.q"]
+    fn __lsx_vssrarni_d_q(a: v2i64, b: v2i64, c: u32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vssrarni.bu.h"]
+    fn __lsx_vssrarni_bu_h(a: v16u8, b: v16i8, c: u32) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vssrarni.hu.w"]
+    fn __lsx_vssrarni_hu_w(a: v8u16, b: v8i16, c: u32) -> v8u16;
+    #[link_name = "llvm.loongarch.lsx.vssrarni.wu.d"]
+    fn __lsx_vssrarni_wu_d(a: v4u32, b: v4i32, c: u32) -> v4u32;
+    #[link_name = "llvm.loongarch.lsx.vssrarni.du.q"]
+    fn __lsx_vssrarni_du_q(a: v2u64, b: v2i64, c: u32) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.vpermi.w"]
+    fn __lsx_vpermi_w(a: v4i32, b: v4i32, c: u32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vld"]
+    fn __lsx_vld(mem_addr: *const i8, a: i32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vst"]
+    fn __lsx_vst(a: v16i8, mem_addr: *mut i8, b: i32);
+    #[link_name = "llvm.loongarch.lsx.vssrlrn.b.h"]
+    fn __lsx_vssrlrn_b_h(a: v8i16, b: v8i16) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vssrlrn.h.w"]
+    fn __lsx_vssrlrn_h_w(a: v4i32, b: v4i32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vssrlrn.w.d"]
+    fn __lsx_vssrlrn_w_d(a: v2i64, b: v2i64) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vssrln.b.h"]
+    fn __lsx_vssrln_b_h(a: v8i16, b: v8i16) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vssrln.h.w"]
+    fn __lsx_vssrln_h_w(a: v4i32, b: v4i32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vssrln.w.d"]
+    fn __lsx_vssrln_w_d(a: v2i64, b: v2i64) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vorn.v"]
+    fn __lsx_vorn_v(a: v16u8, b: v16u8) -> v16u8;
+    #[link_name = "llvm.loongarch.lsx.vldi"]
+    fn __lsx_vldi(a: i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vshuf.b"]
+    fn __lsx_vshuf_b(a: v16i8, b: v16i8, c: v16i8) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vldx"]
+    fn __lsx_vldx(mem_addr: *const i8, a: i64) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vstx"]
+    fn __lsx_vstx(a: v16i8, mem_addr: *mut i8, b: i64);
+    #[link_name = "llvm.loongarch.lsx.vextl.qu.du"]
+    fn __lsx_vextl_qu_du(a: v2u64) -> v2u64;
+    #[link_name = "llvm.loongarch.lsx.bnz.b"]
+    fn __lsx_bnz_b(a: v16u8) -> i32;
+    #[link_name = "llvm.loongarch.lsx.bnz.d"]
+    fn __lsx_bnz_d(a: v2u64) -> i32;
+    #[link_name = "llvm.loongarch.lsx.bnz.h"]
+    fn __lsx_bnz_h(a: v8u16) -> i32;
+    #[link_name = "llvm.loongarch.lsx.bnz.v"]
+    fn __lsx_bnz_v(a: v16u8) -> i32;
+    #[link_name = "llvm.loongarch.lsx.bnz.w"]
+    fn __lsx_bnz_w(a: v4u32) -> i32;
+    #[link_name = "llvm.loongarch.lsx.bz.b"]
+    fn __lsx_bz_b(a: v16u8) -> i32;
+    #[link_name = "llvm.loongarch.lsx.bz.d"]
+    fn __lsx_bz_d(a: v2u64) -> i32;
+    #[link_name = "llvm.loongarch.lsx.bz.h"]
+    fn __lsx_bz_h(a: v8u16) -> i32;
+    #[link_name = "llvm.loongarch.lsx.bz.v"]
+    fn __lsx_bz_v(a: v16u8) -> i32;
+    #[link_name = "llvm.loongarch.lsx.bz.w"]
+    fn __lsx_bz_w(a: v4u32) -> i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.caf.d"]
+    fn __lsx_vfcmp_caf_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.caf.s"]
+    fn __lsx_vfcmp_caf_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.ceq.d"]
+    fn __lsx_vfcmp_ceq_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.ceq.s"]
+    fn __lsx_vfcmp_ceq_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.cle.d"]
+    fn __lsx_vfcmp_cle_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.cle.s"]
+    fn __lsx_vfcmp_cle_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.clt.d"]
+    fn __lsx_vfcmp_clt_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.clt.s"]
+    fn __lsx_vfcmp_clt_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.cne.d"]
+    fn __lsx_vfcmp_cne_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.cne.s"]
+    fn __lsx_vfcmp_cne_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.cor.d"]
+    fn __lsx_vfcmp_cor_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.cor.s"]
+    fn __lsx_vfcmp_cor_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.cueq.d"]
+    fn __lsx_vfcmp_cueq_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.cueq.s"]
+    fn __lsx_vfcmp_cueq_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.cule.d"]
+    fn __lsx_vfcmp_cule_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.cule.s"]
+    fn __lsx_vfcmp_cule_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.cult.d"]
+    fn __lsx_vfcmp_cult_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.cult.s"]
+    fn __lsx_vfcmp_cult_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.cun.d"]
+    fn __lsx_vfcmp_cun_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.cune.d"]
+    fn __lsx_vfcmp_cune_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.cune.s"]
+    fn __lsx_vfcmp_cune_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.cun.s"]
+    fn __lsx_vfcmp_cun_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.saf.d"]
+    fn __lsx_vfcmp_saf_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.saf.s"]
+    fn __lsx_vfcmp_saf_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.seq.d"]
+    fn __lsx_vfcmp_seq_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.seq.s"]
+    fn __lsx_vfcmp_seq_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.sle.d"]
+    fn __lsx_vfcmp_sle_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.sle.s"]
+    fn __lsx_vfcmp_sle_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.slt.d"]
+    fn __lsx_vfcmp_slt_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.slt.s"]
+    fn __lsx_vfcmp_slt_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.sne.d"]
+    fn __lsx_vfcmp_sne_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.sne.s"]
+    fn __lsx_vfcmp_sne_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.sor.d"]
+    fn __lsx_vfcmp_sor_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.sor.s"]
+    fn __lsx_vfcmp_sor_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.sueq.d"]
+    fn __lsx_vfcmp_sueq_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.sueq.s"]
+    fn __lsx_vfcmp_sueq_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.sule.d"]
+    fn __lsx_vfcmp_sule_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.sule.s"]
+    fn __lsx_vfcmp_sule_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.sult.d"]
+    fn __lsx_vfcmp_sult_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.sult.s"]
+    fn __lsx_vfcmp_sult_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.sun.d"]
+    fn __lsx_vfcmp_sun_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.sune.d"]
+    fn __lsx_vfcmp_sune_d(a: v2f64, b: v2f64) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.sune.s"]
+    fn __lsx_vfcmp_sune_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vfcmp.sun.s"]
+    fn __lsx_vfcmp_sun_s(a: v4f32, b: v4f32) -> v4i32;
+    #[link_name = "llvm.loongarch.lsx.vrepli.b"]
+    fn __lsx_vrepli_b(a: i32) -> v16i8;
+    #[link_name = "llvm.loongarch.lsx.vrepli.d"]
+    fn __lsx_vrepli_d(a: i32) -> v2i64;
+    #[link_name = "llvm.loongarch.lsx.vrepli.h"]
+    fn __lsx_vrepli_h(a: i32) -> v8i16;
+    #[link_name = "llvm.loongarch.lsx.vrepli.w"]
+    fn __lsx_vrepli_w(a: i32) -> v4i32;
+}
+
+/// Generates the instruction `vsll.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsll.b))]
+pub unsafe fn lsx_vsll_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vsll_b(a, b)
+}
+
+/// Generates the instruction `vsll.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsll.h))]
+pub unsafe fn lsx_vsll_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vsll_h(a, b)
+}
+
+/// Generates the instruction `vsll.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsll.w))]
+pub unsafe fn lsx_vsll_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vsll_w(a, b)
+}
+
+/// Generates the instruction `vsll.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsll.d))]
+pub unsafe fn lsx_vsll_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vsll_d(a, b)
+}
+
+/// Generates the instruction `vslli.b vd, vj, ui3`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslli.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslli_b<const IMM3: u32>(a: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lsx_vslli_b(a, IMM3)
+}
+
+/// Generates the instruction `vslli.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslli.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslli_h<const IMM4: u32>(a: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vslli_h(a, IMM4)
+}
+
+/// Generates the instruction `vslli.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslli.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslli_w<const IMM5: u32>(a: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vslli_w(a, IMM5)
+}
+
+/// Generates the instruction `vslli.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslli.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslli_d<const IMM6: u32>(a: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vslli_d(a, IMM6)
+}
+
+/// Generates the instruction `vsra.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsra.b))]
+pub unsafe fn lsx_vsra_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vsra_b(a, b)
+}
+
+/// Generates the instruction `vsra.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsra.h))]
+pub unsafe fn lsx_vsra_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vsra_h(a, b)
+}
+
+/// Generates the instruction `vsra.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsra.w))]
+pub unsafe fn lsx_vsra_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vsra_w(a, b)
+}
+
+/// Generates the instruction `vsra.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsra.d))]
+pub unsafe fn lsx_vsra_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vsra_d(a, b)
+}
+
+/// Generates the instruction `vsrai.b vd, vj, ui3`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrai.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsrai_b<const IMM3: u32>(a: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lsx_vsrai_b(a, IMM3)
+}
+
+/// Generates the instruction `vsrai.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrai.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsrai_h<const IMM4: u32>(a: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vsrai_h(a, IMM4)
+}
+
+/// Generates the instruction `vsrai.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrai.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsrai_w<const IMM5: u32>(a: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vsrai_w(a, IMM5)
+}
+
+/// Generates the instruction `vsrai.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrai.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsrai_d<const IMM6: u32>(a: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vsrai_d(a, IMM6)
+}
+
+/// Generates the instruction `vsrar.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrar.b))]
+pub unsafe fn lsx_vsrar_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vsrar_b(a, b)
+}
+
+/// Generates the instruction `vsrar.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrar.h))]
+pub unsafe fn lsx_vsrar_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vsrar_h(a, b)
+}
+
+/// Generates the instruction `vsrar.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrar.w))]
+pub unsafe fn lsx_vsrar_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vsrar_w(a, b)
+}
+
+/// Generates the instruction `vsrar.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrar.d))]
+pub unsafe fn lsx_vsrar_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vsrar_d(a, b)
+}
+
+/// Generates the instruction `vsrari.b vd, vj, ui3`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrari.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsrari_b<const IMM3: u32>(a: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lsx_vsrari_b(a, IMM3)
+}
+
+/// Generates the instruction `vsrari.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrari.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsrari_h<const IMM4: u32>(a: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vsrari_h(a, IMM4)
+}
+
+/// Generates the instruction `vsrari.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrari.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsrari_w<const IMM5: u32>(a: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vsrari_w(a, IMM5)
+}
+
+/// Generates the instruction `vsrari.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrari.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsrari_d<const IMM6: u32>(a: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vsrari_d(a, IMM6)
+}
+
+/// Generates the instruction `vsrl.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrl.b))]
+pub unsafe fn lsx_vsrl_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vsrl_b(a, b)
+}
+
+/// Generates the instruction `vsrl.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrl.h))]
+pub unsafe fn lsx_vsrl_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vsrl_h(a, b)
+}
+
+/// Generates the instruction `vsrl.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrl.w))]
+pub unsafe fn lsx_vsrl_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vsrl_w(a, b)
+}
+
+/// Generates the instruction `vsrl.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrl.d))]
+pub unsafe fn lsx_vsrl_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vsrl_d(a, b)
+}
+
+/// Generates the instruction `vsrli.b vd, vj, ui3`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrli.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsrli_b<const IMM3: u32>(a: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lsx_vsrli_b(a, IMM3)
+}
+
+/// Generates the instruction `vsrli.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrli.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsrli_h<const IMM4: u32>(a: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vsrli_h(a, IMM4)
+}
+
+/// Generates the instruction `vsrli.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrli.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsrli_w<const IMM5: u32>(a: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vsrli_w(a, IMM5)
+}
+
+/// Generates the instruction `vsrli.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrli.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsrli_d<const IMM6: u32>(a: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vsrli_d(a, IMM6)
+}
+
+/// Generates the instruction `vsrlr.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrlr.b))]
+pub unsafe fn lsx_vsrlr_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vsrlr_b(a, b)
+}
+
+/// Generates the instruction `vsrlr.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrlr.h))]
+pub unsafe fn lsx_vsrlr_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vsrlr_h(a, b)
+}
+
+/// Generates the instruction `vsrlr.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrlr.w))]
+pub unsafe fn lsx_vsrlr_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vsrlr_w(a, b)
+}
+
+/// Generates the instruction `vsrlr.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrlr.d))]
+pub unsafe fn lsx_vsrlr_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vsrlr_d(a, b)
+}
+
+/// Generates the instruction `vsrlri.b vd, vj, ui3`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrlri.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsrlri_b<const IMM3: u32>(a: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lsx_vsrlri_b(a, IMM3)
+}
+
+/// Generates the instruction `vsrlri.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrlri.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsrlri_h<const IMM4: u32>(a: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vsrlri_h(a, IMM4)
+}
+
+/// Generates the instruction `vsrlri.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrlri.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsrlri_w<const IMM5: u32>(a: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vsrlri_w(a, IMM5)
+}
+
+/// Generates the instruction `vsrlri.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrlri.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsrlri_d<const IMM6: u32>(a: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vsrlri_d(a, IMM6)
+}
+
+/// Generates the instruction `vbitclr.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitclr.b))]
+pub unsafe fn lsx_vbitclr_b(a: v16u8, b: v16u8) -> v16u8 {
+    __lsx_vbitclr_b(a, b)
+}
+
+/// Generates the instruction `vbitclr.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitclr.h))]
+pub unsafe fn lsx_vbitclr_h(a: v8u16, b: v8u16) -> v8u16 {
+    __lsx_vbitclr_h(a, b)
+}
+
+/// Generates the instruction `vbitclr.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitclr.w))]
+pub unsafe fn lsx_vbitclr_w(a: v4u32, b: v4u32) -> v4u32 {
+    __lsx_vbitclr_w(a, b)
+}
+
+/// Generates the instruction `vbitclr.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitclr.d))]
+pub unsafe fn lsx_vbitclr_d(a: v2u64, b: v2u64) -> v2u64 {
+    __lsx_vbitclr_d(a, b)
+}
+
+/// Generates the instruction `vbitclri.b vd, vj, ui3`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitclri.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vbitclri_b<const IMM3: u32>(a: v16u8) -> v16u8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lsx_vbitclri_b(a, IMM3)
+}
+
+/// Generates the instruction `vbitclri.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitclri.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vbitclri_h<const IMM4: u32>(a: v8u16) -> v8u16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vbitclri_h(a, IMM4)
+}
+
+/// Generates the instruction `vbitclri.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitclri.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vbitclri_w<const IMM5: u32>(a: v4u32) -> v4u32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vbitclri_w(a, IMM5)
+}
+
+/// Generates the instruction `vbitclri.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitclri.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vbitclri_d<const IMM6: u32>(a: v2u64) -> v2u64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vbitclri_d(a, IMM6)
+}
+
+/// Generates the instruction `vbitset.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitset.b))]
+pub unsafe fn lsx_vbitset_b(a: v16u8, b: v16u8) -> v16u8 {
+    __lsx_vbitset_b(a, b)
+}
+
+/// Generates the instruction `vbitset.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitset.h))]
+pub unsafe fn lsx_vbitset_h(a: v8u16, b: v8u16) -> v8u16 {
+    __lsx_vbitset_h(a, b)
+}
+
+/// Generates the instruction `vbitset.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitset.w))]
+pub unsafe fn lsx_vbitset_w(a: v4u32, b: v4u32) -> v4u32 {
+    __lsx_vbitset_w(a, b)
+}
+
+/// Generates the instruction `vbitset.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitset.d))]
+pub unsafe fn lsx_vbitset_d(a: v2u64, b: v2u64) -> v2u64 {
+    __lsx_vbitset_d(a, b)
+}
+
+/// Generates the instruction `vbitseti.b vd, vj, ui3`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitseti.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vbitseti_b<const IMM3: u32>(a: v16u8) -> v16u8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lsx_vbitseti_b(a, IMM3)
+}
+
+/// Generates the instruction `vbitseti.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitseti.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vbitseti_h<const IMM4: u32>(a: v8u16) -> v8u16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vbitseti_h(a, IMM4)
+}
+
+/// Generates the instruction `vbitseti.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitseti.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vbitseti_w<const IMM5: u32>(a: v4u32) -> v4u32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vbitseti_w(a, IMM5)
+}
+
+/// Generates the instruction `vbitseti.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitseti.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vbitseti_d<const IMM6: u32>(a: v2u64) -> v2u64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vbitseti_d(a, IMM6)
+}
+
+/// Generates the instruction `vbitrev.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitrev.b))]
+pub unsafe fn lsx_vbitrev_b(a: v16u8, b: v16u8) -> v16u8 {
+    __lsx_vbitrev_b(a, b)
+}
+
+/// Generates the instruction `vbitrev.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitrev.h))]
+pub unsafe fn lsx_vbitrev_h(a: v8u16, b: v8u16) -> v8u16 {
+    __lsx_vbitrev_h(a, b)
+}
+
+/// Generates the instruction `vbitrev.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitrev.w))]
+pub unsafe fn lsx_vbitrev_w(a: v4u32, b: v4u32) -> v4u32 {
+    __lsx_vbitrev_w(a, b)
+}
+
+/// Generates the instruction `vbitrev.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitrev.d))]
+pub unsafe fn lsx_vbitrev_d(a: v2u64, b: v2u64) -> v2u64 {
+    __lsx_vbitrev_d(a, b)
+}
+
+/// Generates the instruction `vbitrevi.b vd, vj, ui3`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitrevi.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vbitrevi_b<const IMM3: u32>(a: v16u8) -> v16u8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lsx_vbitrevi_b(a, IMM3)
+}
+
+/// Generates the instruction `vbitrevi.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitrevi.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vbitrevi_h<const IMM4: u32>(a: v8u16) -> v8u16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vbitrevi_h(a, IMM4)
+}
+
+/// Generates the instruction `vbitrevi.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitrevi.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vbitrevi_w<const IMM5: u32>(a: v4u32) -> v4u32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vbitrevi_w(a, IMM5)
+}
+
+/// Generates the instruction `vbitrevi.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitrevi.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vbitrevi_d<const IMM6: u32>(a: v2u64) -> v2u64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vbitrevi_d(a, IMM6)
+}
+
+/// Generates the instruction `vadd.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vadd.b))]
+pub unsafe fn lsx_vadd_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vadd_b(a, b)
+}
+
+/// Generates the instruction `vadd.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vadd.h))]
+pub unsafe fn lsx_vadd_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vadd_h(a, b)
+}
+
+/// Generates the instruction `vadd.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vadd.w))]
+pub unsafe fn lsx_vadd_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vadd_w(a, b)
+}
+
+/// Generates the instruction `vadd.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vadd.d))]
+pub unsafe fn lsx_vadd_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vadd_d(a, b)
+}
+
+/// Generates the instruction `vaddi.bu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddi.bu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vaddi_bu<const IMM5: u32>(a: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vaddi_bu(a, IMM5)
+}
+
+/// Generates the instruction `vaddi.hu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddi.hu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vaddi_hu<const IMM5: u32>(a: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vaddi_hu(a, IMM5)
+}
+
+/// Generates the instruction `vaddi.wu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddi.wu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vaddi_wu<const IMM5: u32>(a: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vaddi_wu(a, IMM5)
+}
+
+/// Generates the instruction `vaddi.du vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddi.du, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vaddi_du<const IMM5: u32>(a: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vaddi_du(a, IMM5)
+}
+
+/// Generates the instruction `vsub.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsub.b))]
+pub unsafe fn lsx_vsub_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vsub_b(a, b)
+}
+
+/// Generates the instruction `vsub.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsub.h))]
+pub unsafe fn lsx_vsub_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vsub_h(a, b)
+}
+
+/// Generates the instruction `vsub.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsub.w))]
+pub unsafe fn lsx_vsub_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vsub_w(a, b)
+}
+
+/// Generates the instruction `vsub.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsub.d))]
+pub unsafe fn lsx_vsub_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vsub_d(a, b)
+}
+
+/// Generates the instruction `vsubi.bu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubi.bu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsubi_bu<const IMM5: u32>(a: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vsubi_bu(a, IMM5)
+}
+
+/// Generates the instruction `vsubi.hu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubi.hu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsubi_hu<const IMM5: u32>(a: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vsubi_hu(a, IMM5)
+}
+
+/// Generates the instruction `vsubi.wu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubi.wu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsubi_wu<const IMM5: u32>(a: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vsubi_wu(a, IMM5)
+}
+
+/// Generates the instruction `vsubi.du vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubi.du, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsubi_du<const IMM5: u32>(a: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vsubi_du(a, IMM5)
+}
+
+/// Generates the instruction `vmax.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmax.b))]
+pub unsafe fn lsx_vmax_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vmax_b(a, b)
+}
+
+/// Generates the instruction `vmax.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmax.h))]
+pub unsafe fn lsx_vmax_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vmax_h(a, b)
+}
+
+/// Generates the instruction `vmax.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmax.w))]
+pub unsafe fn lsx_vmax_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vmax_w(a, b)
+}
+
+/// Generates the instruction `vmax.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmax.d))]
+pub unsafe fn lsx_vmax_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vmax_d(a, b)
+}
+
+/// Generates the instruction `vmaxi.b vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaxi.b, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vmaxi_b<const IMM_S5: i32>(a: v16i8) -> v16i8 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vmaxi_b(a, IMM_S5)
+}
+
+/// Generates the instruction `vmaxi.h vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaxi.h, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vmaxi_h<const IMM_S5: i32>(a: v8i16) -> v8i16 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vmaxi_h(a, IMM_S5)
+}
+
+/// Generates the instruction `vmaxi.w vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaxi.w, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vmaxi_w<const IMM_S5: i32>(a: v4i32) -> v4i32 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vmaxi_w(a, IMM_S5)
+}
+
+/// Generates the instruction `vmaxi.d vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaxi.d, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vmaxi_d<const IMM_S5: i32>(a: v2i64) -> v2i64 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vmaxi_d(a, IMM_S5)
+}
+
+/// Generates the instruction `vmax.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmax.bu))]
+pub unsafe fn lsx_vmax_bu(a: v16u8, b: v16u8) -> v16u8 {
+    __lsx_vmax_bu(a, b)
+}
+
+/// Generates the instruction `vmax.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmax.hu))]
+pub unsafe fn lsx_vmax_hu(a: v8u16, b: v8u16) -> v8u16 {
+    __lsx_vmax_hu(a, b)
+}
+
+/// Generates the instruction `vmax.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmax.wu))]
+pub unsafe fn lsx_vmax_wu(a: v4u32, b: v4u32) -> v4u32 {
+    __lsx_vmax_wu(a, b)
+}
+
+/// Generates the instruction `vmax.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmax.du))]
+pub unsafe fn lsx_vmax_du(a: v2u64, b: v2u64) -> v2u64 {
+    __lsx_vmax_du(a, b)
+}
+
+/// Generates the instruction `vmaxi.bu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaxi.bu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vmaxi_bu<const IMM5: u32>(a: v16u8) -> v16u8 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vmaxi_bu(a, IMM5)
+}
+
+/// Generates the instruction `vmaxi.hu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaxi.hu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vmaxi_hu<const IMM5: u32>(a: v8u16) -> v8u16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vmaxi_hu(a, IMM5)
+}
+
+/// Generates the instruction `vmaxi.wu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaxi.wu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vmaxi_wu<const IMM5: u32>(a: v4u32) -> v4u32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vmaxi_wu(a, IMM5)
+}
+
+/// Generates the instruction `vmaxi.du vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaxi.du, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vmaxi_du<const IMM5: u32>(a: v2u64) -> v2u64 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vmaxi_du(a, IMM5)
+}
+
+/// Generates the instruction `vmin.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmin.b))]
+pub unsafe fn lsx_vmin_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vmin_b(a, b)
+}
+
+/// Generates the instruction `vmin.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmin.h))]
+pub unsafe fn lsx_vmin_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vmin_h(a, b)
+}
+
+/// Generates the instruction `vmin.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmin.w))]
+pub unsafe fn lsx_vmin_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vmin_w(a, b)
+}
+
+/// Generates the instruction `vmin.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmin.d))]
+pub unsafe fn lsx_vmin_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vmin_d(a, b)
+}
+
+/// Generates the instruction `vmini.b vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmini.b, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vmini_b<const IMM_S5: i32>(a: v16i8) -> v16i8 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vmini_b(a, IMM_S5)
+}
+
+/// Generates the instruction `vmini.h vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmini.h, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vmini_h<const IMM_S5: i32>(a: v8i16) -> v8i16 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vmini_h(a, IMM_S5)
+}
+
+/// Generates the instruction `vmini.w vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmini.w, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vmini_w<const IMM_S5: i32>(a: v4i32) -> v4i32 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vmini_w(a, IMM_S5)
+}
+
+/// Generates the instruction `vmini.d vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmini.d, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vmini_d<const IMM_S5: i32>(a: v2i64) -> v2i64 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vmini_d(a, IMM_S5)
+}
+
+/// Generates the instruction `vmin.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmin.bu))]
+pub unsafe fn lsx_vmin_bu(a: v16u8, b: v16u8) -> v16u8 {
+    __lsx_vmin_bu(a, b)
+}
+
+/// Generates the instruction `vmin.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmin.hu))]
+pub unsafe fn lsx_vmin_hu(a: v8u16, b: v8u16) -> v8u16 {
+    __lsx_vmin_hu(a, b)
+}
+
+/// Generates the instruction `vmin.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmin.wu))]
+pub unsafe fn lsx_vmin_wu(a: v4u32, b: v4u32) -> v4u32 {
+    __lsx_vmin_wu(a, b)
+}
+
+/// Generates the instruction `vmin.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmin.du))]
+pub unsafe fn lsx_vmin_du(a: v2u64, b: v2u64) -> v2u64 {
+    __lsx_vmin_du(a, b)
+}
+
+/// Generates the instruction `vmini.bu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmini.bu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vmini_bu<const IMM5: u32>(a: v16u8) -> v16u8 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vmini_bu(a, IMM5)
+}
+
+/// Generates the instruction `vmini.hu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmini.hu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vmini_hu<const IMM5: u32>(a: v8u16) -> v8u16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vmini_hu(a, IMM5)
+}
+
+/// Generates the instruction `vmini.wu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmini.wu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vmini_wu<const IMM5: u32>(a: v4u32) -> v4u32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vmini_wu(a, IMM5)
+}
+
+/// Generates the instruction `vmini.du vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmini.du, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vmini_du<const IMM5: u32>(a: v2u64) -> v2u64 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vmini_du(a, IMM5)
+}
+
+/// Generates the instruction `vseq.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vseq.b))]
+pub unsafe fn lsx_vseq_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vseq_b(a, b)
+}
+
+/// Generates the instruction `vseq.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vseq.h))]
+pub unsafe fn lsx_vseq_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vseq_h(a, b)
+}
+
+/// Generates the instruction `vseq.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vseq.w))]
+pub unsafe fn lsx_vseq_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vseq_w(a, b)
+}
+
+/// Generates the instruction `vseq.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vseq.d))]
+pub unsafe fn lsx_vseq_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vseq_d(a, b)
+}
+
+/// Generates the instruction `vseqi.b vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vseqi.b, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vseqi_b<const IMM_S5: i32>(a: v16i8) -> v16i8 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vseqi_b(a, IMM_S5)
+}
+
+/// Generates the instruction `vseqi.h vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vseqi.h, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vseqi_h<const IMM_S5: i32>(a: v8i16) -> v8i16 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vseqi_h(a, IMM_S5)
+}
+
+/// Generates the instruction `vseqi.w vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vseqi.w, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vseqi_w<const IMM_S5: i32>(a: v4i32) -> v4i32 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vseqi_w(a, IMM_S5)
+}
+
+/// Generates the instruction `vseqi.d vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vseqi.d, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vseqi_d<const IMM_S5: i32>(a: v2i64) -> v2i64 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vseqi_d(a, IMM_S5)
+}
+
+/// Generates the instruction `vslti.b vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslti.b, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslti_b<const IMM_S5: i32>(a: v16i8) -> v16i8 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vslti_b(a, IMM_S5)
+}
+
+/// Generates the instruction `vslt.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslt.b))]
+pub unsafe fn lsx_vslt_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vslt_b(a, b)
+}
+
+/// Generates the instruction `vslt.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslt.h))]
+pub unsafe fn lsx_vslt_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vslt_h(a, b)
+}
+
+/// Generates the instruction `vslt.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslt.w))]
+pub unsafe fn lsx_vslt_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vslt_w(a, b)
+}
+
+/// Generates the instruction `vslt.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslt.d))]
+pub unsafe fn lsx_vslt_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vslt_d(a, b)
+}
+
+/// Generates the instruction `vslti.h vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslti.h, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslti_h<const IMM_S5: i32>(a: v8i16) -> v8i16 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vslti_h(a, IMM_S5)
+}
+
+/// Generates the instruction `vslti.w vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslti.w, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslti_w<const IMM_S5: i32>(a: v4i32) -> v4i32 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vslti_w(a, IMM_S5)
+}
+
+/// Generates the instruction `vslti.d vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslti.d, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslti_d<const IMM_S5: i32>(a: v2i64) -> v2i64 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vslti_d(a, IMM_S5)
+}
+
+/// Generates the instruction `vslt.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslt.bu))]
+pub unsafe fn lsx_vslt_bu(a: v16u8, b: v16u8) -> v16i8 {
+    __lsx_vslt_bu(a, b)
+}
+
+/// Generates the instruction `vslt.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslt.hu))]
+pub unsafe fn lsx_vslt_hu(a: v8u16, b: v8u16) -> v8i16 {
+    __lsx_vslt_hu(a, b)
+}
+
+/// Generates the instruction `vslt.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslt.wu))]
+pub unsafe fn lsx_vslt_wu(a: v4u32, b: v4u32) -> v4i32 {
+    __lsx_vslt_wu(a, b)
+}
+
+/// Generates the instruction `vslt.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslt.du))]
+pub unsafe fn lsx_vslt_du(a: v2u64, b: v2u64) -> v2i64 {
+    __lsx_vslt_du(a, b)
+}
+
+/// Generates the instruction `vslti.bu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslti.bu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslti_bu<const IMM5: u32>(a: v16u8) -> v16i8 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vslti_bu(a, IMM5)
+}
+
+/// Generates the instruction `vslti.hu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslti.hu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslti_hu<const IMM5: u32>(a: v8u16) -> v8i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vslti_hu(a, IMM5)
+}
+
+/// Generates the instruction `vslti.wu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslti.wu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslti_wu<const IMM5: u32>(a: v4u32) -> v4i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vslti_wu(a, IMM5)
+}
+
+/// Generates the instruction `vslti.du vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslti.du, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslti_du<const IMM5: u32>(a: v2u64) -> v2i64 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vslti_du(a, IMM5)
+}
+
+/// Generates the instruction `vsle.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsle.b))]
+pub unsafe fn lsx_vsle_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vsle_b(a, b)
+}
+
+/// Generates the instruction `vsle.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsle.h))]
+pub unsafe fn lsx_vsle_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vsle_h(a, b)
+}
+
+/// Generates the instruction `vsle.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsle.w))]
+pub unsafe fn lsx_vsle_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vsle_w(a, b)
+}
+
+/// Generates the instruction `vsle.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsle.d))]
+pub unsafe fn lsx_vsle_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vsle_d(a, b)
+}
+
+/// Generates the instruction `vslei.b vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslei.b, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslei_b<const IMM_S5: i32>(a: v16i8) -> v16i8 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vslei_b(a, IMM_S5)
+}
+
+/// Generates the instruction `vslei.h vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslei.h, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslei_h<const IMM_S5: i32>(a: v8i16) -> v8i16 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vslei_h(a, IMM_S5)
+}
+
+/// Generates the instruction `vslei.w vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslei.w, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslei_w<const IMM_S5: i32>(a: v4i32) -> v4i32 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vslei_w(a, IMM_S5)
+}
+
+/// Generates the instruction `vslei.d vd, vj, si5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslei.d, IMM_S5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslei_d<const IMM_S5: i32>(a: v2i64) -> v2i64 {
+    static_assert_simm_bits!(IMM_S5, 5);
+    __lsx_vslei_d(a, IMM_S5)
+}
+
+/// Generates the instruction `vsle.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsle.bu))]
+pub unsafe fn lsx_vsle_bu(a: v16u8, b: v16u8) -> v16i8 {
+    __lsx_vsle_bu(a, b)
+}
+
+/// Generates the instruction `vsle.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsle.hu))]
+pub unsafe fn lsx_vsle_hu(a: v8u16, b: v8u16) -> v8i16 {
+    __lsx_vsle_hu(a, b)
+}
+
+/// Generates the instruction `vsle.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsle.wu))]
+pub unsafe fn lsx_vsle_wu(a: v4u32, b: v4u32) -> v4i32 {
+    __lsx_vsle_wu(a, b)
+}
+
+/// Generates the instruction `vsle.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsle.du))]
+pub unsafe fn lsx_vsle_du(a: v2u64, b: v2u64) -> v2i64 {
+    __lsx_vsle_du(a, b)
+}
+
+/// Generates the instruction `vslei.bu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslei.bu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslei_bu<const IMM5: u32>(a: v16u8) -> v16i8 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vslei_bu(a, IMM5)
+}
+
+/// Generates the instruction `vslei.hu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslei.hu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslei_hu<const IMM5: u32>(a: v8u16) -> v8i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vslei_hu(a, IMM5)
+}
+
+/// Generates the instruction `vslei.wu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslei.wu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslei_wu<const IMM5: u32>(a: v4u32) -> v4i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vslei_wu(a, IMM5)
+}
+
+/// Generates the instruction `vslei.du vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vslei.du, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vslei_du<const IMM5: u32>(a: v2u64) -> v2i64 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vslei_du(a, IMM5)
+}
+
+/// Generates the instruction `vsat.b vd, vj, ui3`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsat.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsat_b<const IMM3: u32>(a: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lsx_vsat_b(a, IMM3)
+}
+
+/// Generates the instruction `vsat.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsat.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsat_h<const IMM4: u32>(a: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vsat_h(a, IMM4)
+}
+
+/// Generates the instruction `vsat.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsat.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsat_w<const IMM5: u32>(a: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vsat_w(a, IMM5)
+}
+
+/// Generates the instruction `vsat.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsat.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsat_d<const IMM6: u32>(a: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vsat_d(a, IMM6)
+}
+
+/// Generates the instruction `vsat.bu vd, vj, ui3`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsat.bu, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsat_bu<const IMM3: u32>(a: v16u8) -> v16u8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lsx_vsat_bu(a, IMM3)
+}
+
+/// Generates the instruction `vsat.hu vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsat.hu, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsat_hu<const IMM4: u32>(a: v8u16) -> v8u16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vsat_hu(a, IMM4)
+}
+
+/// Generates the instruction `vsat.wu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsat.wu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsat_wu<const IMM5: u32>(a: v4u32) -> v4u32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vsat_wu(a, IMM5)
+}
+
+/// Generates the instruction `vsat.du vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsat.du, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsat_du<const IMM6: u32>(a: v2u64) -> v2u64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vsat_du(a, IMM6)
+}
+
+/// Generates the instruction `vadda.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vadda.b))]
+pub unsafe fn lsx_vadda_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vadda_b(a, b)
+}
+
+/// Generates the instruction `vadda.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vadda.h))]
+pub unsafe fn lsx_vadda_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vadda_h(a, b)
+}
+
+/// Generates the instruction `vadda.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vadda.w))]
+pub unsafe fn lsx_vadda_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vadda_w(a, b)
+}
+
+/// Generates the instruction `vadda.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vadda.d))]
+pub unsafe fn lsx_vadda_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vadda_d(a, b)
+}
+
+/// Generates the instruction `vsadd.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsadd.b))]
+pub unsafe fn lsx_vsadd_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vsadd_b(a, b)
+}
+
+/// Generates the instruction `vsadd.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsadd.h))]
+pub unsafe fn lsx_vsadd_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vsadd_h(a, b)
+}
+
+/// Generates the instruction `vsadd.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsadd.w))]
+pub unsafe fn lsx_vsadd_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vsadd_w(a, b)
+}
+
+/// Generates the instruction `vsadd.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsadd.d))]
+pub unsafe fn lsx_vsadd_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vsadd_d(a, b)
+}
+
+/// Generates the instruction `vsadd.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsadd.bu))]
+pub unsafe fn lsx_vsadd_bu(a: v16u8, b: v16u8) -> v16u8 {
+    __lsx_vsadd_bu(a, b)
+}
+
+/// Generates the instruction `vsadd.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsadd.hu))]
+pub unsafe fn lsx_vsadd_hu(a: v8u16, b: v8u16) -> v8u16 {
+    __lsx_vsadd_hu(a, b)
+}
+
+/// Generates the instruction `vsadd.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsadd.wu))]
+pub unsafe fn lsx_vsadd_wu(a: v4u32, b: v4u32) -> v4u32 {
+    __lsx_vsadd_wu(a, b)
+}
+
+/// Generates the instruction `vsadd.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsadd.du))]
+pub unsafe fn lsx_vsadd_du(a: v2u64, b: v2u64) -> v2u64 {
+    __lsx_vsadd_du(a, b)
+}
+
+/// Generates the instruction `vavg.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vavg.b))]
+pub unsafe fn lsx_vavg_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vavg_b(a, b)
+}
+
+/// Generates the instruction `vavg.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vavg.h))]
+pub unsafe fn lsx_vavg_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vavg_h(a, b)
+}
+
+/// Generates the instruction `vavg.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vavg.w))]
+pub unsafe fn lsx_vavg_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vavg_w(a, b)
+}
+
+/// Generates the instruction `vavg.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vavg.d))]
+pub unsafe fn lsx_vavg_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vavg_d(a, b)
+}
+
+/// Generates the instruction `vavg.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vavg.bu))]
+pub unsafe fn lsx_vavg_bu(a: v16u8, b: v16u8) -> v16u8 {
+    __lsx_vavg_bu(a, b)
+}
+
+/// Generates the instruction `vavg.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vavg.hu))]
+pub unsafe fn lsx_vavg_hu(a: v8u16, b: v8u16) -> v8u16 {
+    __lsx_vavg_hu(a, b)
+}
+
+/// Generates the instruction `vavg.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vavg.wu))]
+pub unsafe fn lsx_vavg_wu(a: v4u32, b: v4u32) -> v4u32 {
+    __lsx_vavg_wu(a, b)
+}
+
+/// Generates the instruction `vavg.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vavg.du))]
+pub unsafe fn lsx_vavg_du(a: v2u64, b: v2u64) -> v2u64 {
+    __lsx_vavg_du(a, b)
+}
+
+/// Generates the instruction `vavgr.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vavgr.b))]
+pub unsafe fn lsx_vavgr_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vavgr_b(a, b)
+}
+
+/// Generates the instruction `vavgr.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vavgr.h))]
+pub unsafe fn lsx_vavgr_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vavgr_h(a, b)
+}
+
+/// Generates the instruction `vavgr.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vavgr.w))]
+pub unsafe fn lsx_vavgr_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vavgr_w(a, b)
+}
+
+/// Generates the instruction `vavgr.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vavgr.d))]
+pub unsafe fn lsx_vavgr_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vavgr_d(a, b)
+}
+
+/// Generates the instruction `vavgr.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vavgr.bu))]
+pub unsafe fn lsx_vavgr_bu(a: v16u8, b: v16u8) -> v16u8 {
+    __lsx_vavgr_bu(a, b)
+}
+
+/// Generates the instruction `vavgr.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vavgr.hu))]
+pub unsafe fn lsx_vavgr_hu(a: v8u16, b: v8u16) -> v8u16 {
+    __lsx_vavgr_hu(a, b)
+}
+
+/// Generates the instruction `vavgr.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vavgr.wu))]
+pub unsafe fn lsx_vavgr_wu(a: v4u32, b: v4u32) -> v4u32 {
+    __lsx_vavgr_wu(a, b)
+}
+
+/// Generates the instruction `vavgr.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vavgr.du))]
+pub unsafe fn lsx_vavgr_du(a: v2u64, b: v2u64) -> v2u64 {
+    __lsx_vavgr_du(a, b)
+}
+
+/// Generates the instruction `vssub.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssub.b))]
+pub unsafe fn lsx_vssub_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vssub_b(a, b)
+}
+
+/// Generates the instruction `vssub.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssub.h))]
+pub unsafe fn lsx_vssub_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vssub_h(a, b)
+}
+
+/// Generates the instruction `vssub.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssub.w))]
+pub unsafe fn lsx_vssub_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vssub_w(a, b)
+}
+
+/// Generates the instruction `vssub.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssub.d))]
+pub unsafe fn lsx_vssub_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vssub_d(a, b)
+}
+
+/// Generates the instruction `vssub.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssub.bu))]
+pub unsafe fn lsx_vssub_bu(a: v16u8, b: v16u8) -> v16u8 {
+    __lsx_vssub_bu(a, b)
+}
+
+/// Generates the instruction `vssub.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssub.hu))]
+pub unsafe fn lsx_vssub_hu(a: v8u16, b: v8u16) -> v8u16 {
+    __lsx_vssub_hu(a, b)
+}
+
+/// Generates the instruction `vssub.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssub.wu))]
+pub unsafe fn lsx_vssub_wu(a: v4u32, b: v4u32) -> v4u32 {
+    __lsx_vssub_wu(a, b)
+}
+
+/// Generates the instruction `vssub.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssub.du))]
+pub unsafe fn lsx_vssub_du(a: v2u64, b: v2u64) -> v2u64 {
+    __lsx_vssub_du(a, b)
+}
+
+/// Generates the instruction `vabsd.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vabsd.b))]
+pub unsafe fn lsx_vabsd_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vabsd_b(a, b)
+}
+
+/// Generates the instruction `vabsd.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vabsd.h))]
+pub unsafe fn lsx_vabsd_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vabsd_h(a, b)
+}
+
+/// Generates the instruction `vabsd.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vabsd.w))]
+pub unsafe fn lsx_vabsd_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vabsd_w(a, b)
+}
+
+/// Generates the instruction `vabsd.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vabsd.d))]
+pub unsafe fn lsx_vabsd_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vabsd_d(a, b)
+}
+
+/// Generates the instruction `vabsd.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vabsd.bu))]
+pub unsafe fn lsx_vabsd_bu(a: v16u8, b: v16u8) -> v16u8 {
+    __lsx_vabsd_bu(a, b)
+}
+
+/// Generates the instruction `vabsd.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vabsd.hu))]
+pub unsafe fn lsx_vabsd_hu(a: v8u16, b: v8u16) -> v8u16 {
+    __lsx_vabsd_hu(a, b)
+}
+
+/// Generates the instruction `vabsd.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vabsd.wu))]
+pub unsafe fn lsx_vabsd_wu(a: v4u32, b: v4u32) -> v4u32 {
+    __lsx_vabsd_wu(a, b)
+}
+
+/// Generates the instruction `vabsd.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vabsd.du))]
+pub unsafe fn lsx_vabsd_du(a: v2u64, b: v2u64) -> v2u64 {
+    __lsx_vabsd_du(a, b)
+}
+
+/// Generates the instruction `vmul.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmul.b))]
+pub unsafe fn lsx_vmul_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vmul_b(a, b)
+}
+
+/// Generates the instruction `vmul.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmul.h))]
+pub unsafe fn lsx_vmul_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vmul_h(a, b)
+}
+
+/// Generates the instruction `vmul.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmul.w))]
+pub unsafe fn lsx_vmul_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vmul_w(a, b)
+}
+
+/// Generates the instruction `vmul.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmul.d))]
+pub unsafe fn lsx_vmul_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vmul_d(a, b)
+}
+
+/// Generates the instruction `vmadd.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmadd.b))]
+pub unsafe fn lsx_vmadd_b(a: v16i8, b: v16i8, c: v16i8) -> v16i8 {
+    __lsx_vmadd_b(a, b, c)
+}
+
+/// Generates the instruction `vmadd.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmadd.h))]
+pub unsafe fn lsx_vmadd_h(a: v8i16, b: v8i16, c: v8i16) -> v8i16 {
+    __lsx_vmadd_h(a, b, c)
+}
+
+/// Generates the instruction `vmadd.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmadd.w))]
+pub unsafe fn lsx_vmadd_w(a: v4i32, b: v4i32, c: v4i32) -> v4i32 {
+    __lsx_vmadd_w(a, b, c)
+}
+
+/// Generates the instruction `vmadd.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmadd.d))]
+pub unsafe fn lsx_vmadd_d(a: v2i64, b: v2i64, c: v2i64) -> v2i64 {
+    __lsx_vmadd_d(a, b, c)
+}
+
+/// Generates the instruction `vmsub.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmsub.b))]
+pub unsafe fn lsx_vmsub_b(a: v16i8, b: v16i8, c: v16i8) -> v16i8 {
+    __lsx_vmsub_b(a, b, c)
+}
+
+/// Generates the instruction `vmsub.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmsub.h))]
+pub unsafe fn lsx_vmsub_h(a: v8i16, b: v8i16, c: v8i16) -> v8i16 {
+    __lsx_vmsub_h(a, b, c)
+}
+
+/// Generates the instruction `vmsub.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmsub.w))]
+pub unsafe fn lsx_vmsub_w(a: v4i32, b: v4i32, c: v4i32) -> v4i32 {
+    __lsx_vmsub_w(a, b, c)
+}
+
+/// Generates the instruction `vmsub.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmsub.d))]
+pub unsafe fn lsx_vmsub_d(a: v2i64, b: v2i64, c: v2i64) -> v2i64 {
+    __lsx_vmsub_d(a, b, c)
+}
+
+/// Generates the instruction `vdiv.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vdiv.b))]
+pub unsafe fn lsx_vdiv_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vdiv_b(a, b)
+}
+
+/// Generates the instruction `vdiv.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vdiv.h))]
+pub unsafe fn lsx_vdiv_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vdiv_h(a, b)
+}
+
+/// Generates the instruction `vdiv.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vdiv.w))]
+pub unsafe fn lsx_vdiv_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vdiv_w(a, b)
+}
+
+/// Generates the instruction `vdiv.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vdiv.d))]
+pub unsafe fn lsx_vdiv_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vdiv_d(a, b)
+}
+
+/// Generates the instruction `vdiv.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vdiv.bu))]
+pub unsafe fn lsx_vdiv_bu(a: v16u8, b: v16u8) -> v16u8 {
+    __lsx_vdiv_bu(a, b)
+}
+
+/// Generates the instruction `vdiv.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vdiv.hu))]
+pub unsafe fn lsx_vdiv_hu(a: v8u16, b: v8u16) -> v8u16 {
+    __lsx_vdiv_hu(a, b)
+}
+
+/// Generates the instruction `vdiv.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vdiv.wu))]
+pub unsafe fn lsx_vdiv_wu(a: v4u32, b: v4u32) -> v4u32 {
+    __lsx_vdiv_wu(a, b)
+}
+
+/// Generates the instruction `vdiv.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vdiv.du))]
+pub unsafe fn lsx_vdiv_du(a: v2u64, b: v2u64) -> v2u64 {
+    __lsx_vdiv_du(a, b)
+}
+
+/// Generates the instruction `vhaddw.h.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vhaddw.h.b))]
+pub unsafe fn lsx_vhaddw_h_b(a: v16i8, b: v16i8) -> v8i16 {
+    __lsx_vhaddw_h_b(a, b)
+}
+
+/// Generates the instruction `vhaddw.w.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vhaddw.w.h))]
+pub unsafe fn lsx_vhaddw_w_h(a: v8i16, b: v8i16) -> v4i32 {
+    __lsx_vhaddw_w_h(a, b)
+}
+
+/// Generates the instruction `vhaddw.d.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vhaddw.d.w))]
+pub unsafe fn lsx_vhaddw_d_w(a: v4i32, b: v4i32) -> v2i64 {
+    __lsx_vhaddw_d_w(a, b)
+}
+
+/// Generates the instruction `vhaddw.hu.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vhaddw.hu.bu))]
+pub unsafe fn lsx_vhaddw_hu_bu(a: v16u8, b: v16u8) -> v8u16 {
+    __lsx_vhaddw_hu_bu(a, b)
+}
+
+/// Generates the instruction `vhaddw.wu.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vhaddw.wu.hu))]
+pub unsafe fn lsx_vhaddw_wu_hu(a: v8u16, b: v8u16) -> v4u32 {
+    __lsx_vhaddw_wu_hu(a, b)
+}
+
+/// Generates the instruction `vhaddw.du.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vhaddw.du.wu))]
+pub unsafe fn lsx_vhaddw_du_wu(a: v4u32, b: v4u32) -> v2u64 {
+    __lsx_vhaddw_du_wu(a, b)
+}
+
+/// Generates the instruction `vhsubw.h.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vhsubw.h.b))]
+pub unsafe fn lsx_vhsubw_h_b(a: v16i8, b: v16i8) -> v8i16 {
+    __lsx_vhsubw_h_b(a, b)
+}
+
+/// Generates the instruction `vhsubw.w.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vhsubw.w.h))]
+pub unsafe fn lsx_vhsubw_w_h(a: v8i16, b: v8i16) -> v4i32 {
+    __lsx_vhsubw_w_h(a, b)
+}
+
+/// Generates the instruction `vhsubw.d.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vhsubw.d.w))]
+pub unsafe fn lsx_vhsubw_d_w(a: v4i32, b: v4i32) -> v2i64 {
+    __lsx_vhsubw_d_w(a, b)
+}
+
+/// Generates the instruction `vhsubw.hu.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vhsubw.hu.bu))]
+pub unsafe fn lsx_vhsubw_hu_bu(a: v16u8, b: v16u8) -> v8i16 {
+    __lsx_vhsubw_hu_bu(a, b)
+}
+
+/// Generates the instruction `vhsubw.wu.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vhsubw.wu.hu))]
+pub unsafe fn lsx_vhsubw_wu_hu(a: v8u16, b: v8u16) -> v4i32 {
+    __lsx_vhsubw_wu_hu(a, b)
+}
+
+/// Generates the instruction `vhsubw.du.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vhsubw.du.wu))]
+pub unsafe fn lsx_vhsubw_du_wu(a: v4u32, b: v4u32) -> v2i64 {
+    __lsx_vhsubw_du_wu(a, b)
+}
+
+/// Generates the instruction `vmod.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmod.b))]
+pub unsafe fn lsx_vmod_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vmod_b(a, b)
+}
+
+/// Generates the instruction `vmod.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmod.h))]
+pub unsafe fn lsx_vmod_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vmod_h(a, b)
+}
+
+/// Generates the instruction `vmod.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmod.w))]
+pub unsafe fn lsx_vmod_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vmod_w(a, b)
+}
+
+/// Generates the instruction `vmod.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmod.d))]
+pub unsafe fn lsx_vmod_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vmod_d(a, b)
+}
+
+/// Generates the instruction `vmod.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmod.bu))]
+pub unsafe fn lsx_vmod_bu(a: v16u8, b: v16u8) -> v16u8 {
+    __lsx_vmod_bu(a, b)
+}
+
+/// Generates the instruction `vmod.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmod.hu))]
+pub unsafe fn lsx_vmod_hu(a: v8u16, b: v8u16) -> v8u16 {
+    __lsx_vmod_hu(a, b)
+}
+
+/// Generates the instruction `vmod.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmod.wu))]
+pub unsafe fn lsx_vmod_wu(a: v4u32, b: v4u32) -> v4u32 {
+    __lsx_vmod_wu(a, b)
+}
+
+/// Generates the instruction `vmod.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmod.du))]
+pub unsafe fn lsx_vmod_du(a: v2u64, b: v2u64) -> v2u64 {
+    __lsx_vmod_du(a, b)
+}
+
+/// Generates the instruction `vreplve.b vd, vj, rk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vreplve.b))]
+pub unsafe fn lsx_vreplve_b(a: v16i8, b: i32) -> v16i8 {
+    __lsx_vreplve_b(a, b)
+}
+
+/// Generates the instruction `vreplve.h vd, vj, rk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vreplve.h))]
+pub unsafe fn lsx_vreplve_h(a: v8i16, b: i32) -> v8i16 {
+    __lsx_vreplve_h(a, b)
+}
+
+/// Generates the instruction `vreplve.w vd, vj, rk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vreplve.w))]
+pub unsafe fn lsx_vreplve_w(a: v4i32, b: i32) -> v4i32 {
+    __lsx_vreplve_w(a, b)
+}
+
+/// Generates the instruction `vreplve.d vd, vj, rk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vreplve.d))]
+pub unsafe fn lsx_vreplve_d(a: v2i64, b: i32) -> v2i64 {
+    __lsx_vreplve_d(a, b)
+}
+
+/// Generates the instruction `vreplvei.b vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vreplvei.b, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vreplvei_b<const IMM4: u32>(a: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vreplvei_b(a, IMM4)
+}
+
+/// Generates the instruction `vreplvei.h vd, vj, ui3`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vreplvei.h, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vreplvei_h<const IMM3: u32>(a: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lsx_vreplvei_h(a, IMM3)
+}
+
+/// Generates the instruction `vreplvei.w vd, vj, ui2`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vreplvei.w, IMM2 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vreplvei_w<const IMM2: u32>(a: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM2, 2);
+    __lsx_vreplvei_w(a, IMM2)
+}
+
+/// Generates the instruction `vreplvei.d vd, vj, ui1`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vreplvei.d, IMM1 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vreplvei_d<const IMM1: u32>(a: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM1, 1);
+    __lsx_vreplvei_d(a, IMM1)
+}
+
+/// Generates the instruction `vpickev.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpickev.b))]
+pub unsafe fn lsx_vpickev_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vpickev_b(a, b)
+}
+
+/// Generates the instruction `vpickev.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpickev.h))]
+pub unsafe fn lsx_vpickev_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vpickev_h(a, b)
+}
+
+/// Generates the instruction `vpickev.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpickev.w))]
+pub unsafe fn lsx_vpickev_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vpickev_w(a, b)
+}
+
+/// Generates the instruction `vpickev.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpickev.d))]
+pub unsafe fn lsx_vpickev_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vpickev_d(a, b)
+}
+
+/// Generates the instruction `vpickod.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpickod.b))]
+pub unsafe fn lsx_vpickod_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vpickod_b(a, b)
+}
+
+/// Generates the instruction `vpickod.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpickod.h))]
+pub unsafe fn lsx_vpickod_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vpickod_h(a, b)
+}
+
+/// Generates the instruction `vpickod.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpickod.w))]
+pub unsafe fn lsx_vpickod_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vpickod_w(a, b)
+}
+
+/// Generates the instruction `vpickod.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpickod.d))]
+pub unsafe fn lsx_vpickod_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vpickod_d(a, b)
+}
+
+/// Generates the instruction `vilvh.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vilvh.b))]
+pub unsafe fn lsx_vilvh_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vilvh_b(a, b)
+}
+
+/// Generates the instruction `vilvh.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vilvh.h))]
+pub unsafe fn lsx_vilvh_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vilvh_h(a, b)
+}
+
+/// Generates the instruction `vilvh.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vilvh.w))]
+pub unsafe fn lsx_vilvh_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vilvh_w(a, b)
+}
+
+/// Generates the instruction `vilvh.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vilvh.d))]
+pub unsafe fn lsx_vilvh_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vilvh_d(a, b)
+}
+
+/// Generates the instruction `vilvl.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vilvl.b))]
+pub unsafe fn lsx_vilvl_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vilvl_b(a, b)
+}
+
+/// Generates the instruction `vilvl.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vilvl.h))]
+pub unsafe fn lsx_vilvl_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vilvl_h(a, b)
+}
+
+/// Generates the instruction `vilvl.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vilvl.w))]
+pub unsafe fn lsx_vilvl_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vilvl_w(a, b)
+}
+
+/// Generates the instruction `vilvl.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vilvl.d))]
+pub unsafe fn lsx_vilvl_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vilvl_d(a, b)
+}
+
+/// Generates the instruction `vpackev.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpackev.b))]
+pub unsafe fn lsx_vpackev_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vpackev_b(a, b)
+}
+
+/// Generates the instruction `vpackev.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpackev.h))]
+pub unsafe fn lsx_vpackev_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vpackev_h(a, b)
+}
+
+/// Generates the instruction `vpackev.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpackev.w))]
+pub unsafe fn lsx_vpackev_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vpackev_w(a, b)
+}
+
+/// Generates the instruction `vpackev.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpackev.d))]
+pub unsafe fn lsx_vpackev_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vpackev_d(a, b)
+}
+
+/// Generates the instruction `vpackod.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpackod.b))]
+pub unsafe fn lsx_vpackod_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vpackod_b(a, b)
+}
+
+/// Generates the instruction `vpackod.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpackod.h))]
+pub unsafe fn lsx_vpackod_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vpackod_h(a, b)
+}
+
+/// Generates the instruction `vpackod.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpackod.w))]
+pub unsafe fn lsx_vpackod_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vpackod_w(a, b)
+}
+
+/// Generates the instruction `vpackod.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpackod.d))]
+pub unsafe fn lsx_vpackod_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vpackod_d(a, b)
+}
+
+/// Generates the instruction `vshuf.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vshuf.h))]
+pub unsafe fn lsx_vshuf_h(a: v8i16, b: v8i16, c: v8i16) -> v8i16 {
+    __lsx_vshuf_h(a, b, c)
+}
+
+/// Generates the instruction `vshuf.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vshuf.w))]
+pub unsafe fn lsx_vshuf_w(a: v4i32, b: v4i32, c: v4i32) -> v4i32 {
+    __lsx_vshuf_w(a, b, c)
+}
+
+/// Generates the instruction `vshuf.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vshuf.d))]
+pub unsafe fn lsx_vshuf_d(a: v2i64, b: v2i64, c: v2i64) -> v2i64 {
+    __lsx_vshuf_d(a, b, c)
+}
+
+/// Generates the instruction `vand.v vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vand.v))]
+pub unsafe fn lsx_vand_v(a: v16u8, b: v16u8) -> v16u8 {
+    __lsx_vand_v(a, b)
+}
+
+/// Generates the instruction `vandi.b vd, vj, ui8`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vandi.b, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vandi_b<const IMM8: u32>(a: v16u8) -> v16u8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lsx_vandi_b(a, IMM8)
+}
+
+/// Generates the instruction `vor.v vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vor.v))]
+pub unsafe fn lsx_vor_v(a: v16u8, b: v16u8) -> v16u8 {
+    __lsx_vor_v(a, b)
+}
+
+/// Generates the instruction `vori.b vd, vj, ui8`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vori.b, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vori_b<const IMM8: u32>(a: v16u8) -> v16u8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lsx_vori_b(a, IMM8)
+}
+
+/// Generates the instruction `vnor.v vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vnor.v))]
+pub unsafe fn lsx_vnor_v(a: v16u8, b: v16u8) -> v16u8 {
+    __lsx_vnor_v(a, b)
+}
+
+/// Generates the instruction `vnori.b vd, vj, ui8`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vnori.b, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vnori_b<const IMM8: u32>(a: v16u8) -> v16u8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lsx_vnori_b(a, IMM8)
+}
+
+/// Generates the instruction `vxor.v vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vxor.v))]
+pub unsafe fn lsx_vxor_v(a: v16u8, b: v16u8) -> v16u8 {
+    __lsx_vxor_v(a, b)
+}
+
+/// Generates the instruction `vxori.b vd, vj, ui8`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vxori.b, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vxori_b<const IMM8: u32>(a: v16u8) -> v16u8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lsx_vxori_b(a, IMM8)
+}
+
+/// Generates the instruction `vbitsel.v vd, vj, vk, va`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitsel.v))]
+pub unsafe fn lsx_vbitsel_v(a: v16u8, b: v16u8, c: v16u8) -> v16u8 {
+    __lsx_vbitsel_v(a, b, c)
+}
+
+/// Generates the instruction `vbitseli.b vd, vj, ui8`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbitseli.b, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vbitseli_b<const IMM8: u32>(a: v16u8, b: v16u8) -> v16u8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lsx_vbitseli_b(a, b, IMM8)
+}
+
+/// Generates the instruction `vshuf4i.b vd, vj, ui8`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vshuf4i.b, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vshuf4i_b<const IMM8: u32>(a: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lsx_vshuf4i_b(a, IMM8)
+}
+
+/// Generates the instruction `vshuf4i.h vd, vj, ui8`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vshuf4i.h, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vshuf4i_h<const IMM8: u32>(a: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lsx_vshuf4i_h(a, IMM8)
+}
+
+/// Generates the instruction `vshuf4i.w vd, vj, ui8`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vshuf4i.w, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vshuf4i_w<const IMM8: u32>(a: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lsx_vshuf4i_w(a, IMM8)
+}
+
+/// Generates the instruction `vreplgr2vr.b vd, rj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vreplgr2vr.b))]
+pub unsafe fn lsx_vreplgr2vr_b(a: i32) -> v16i8 {
+    __lsx_vreplgr2vr_b(a)
+}
+
+/// Generates the instruction `vreplgr2vr.h vd, rj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vreplgr2vr.h))]
+pub unsafe fn lsx_vreplgr2vr_h(a: i32) -> v8i16 {
+    __lsx_vreplgr2vr_h(a)
+}
+
+/// Generates the instruction `vreplgr2vr.w vd, rj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vreplgr2vr.w))]
+pub unsafe fn lsx_vreplgr2vr_w(a: i32) -> v4i32 {
+    __lsx_vreplgr2vr_w(a)
+}
+
+/// Generates the instruction `vreplgr2vr.d vd, rj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vreplgr2vr.d))]
+pub unsafe fn lsx_vreplgr2vr_d(a: i64) -> v2i64 {
+    __lsx_vreplgr2vr_d(a)
+}
+
+/// Generates the instruction `vpcnt.b vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpcnt.b))]
+pub unsafe fn lsx_vpcnt_b(a: v16i8) -> v16i8 {
+    __lsx_vpcnt_b(a)
+}
+
+/// Generates the instruction `vpcnt.h vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpcnt.h))]
+pub unsafe fn lsx_vpcnt_h(a: v8i16) -> v8i16 {
+    __lsx_vpcnt_h(a)
+}
+
+/// Generates the instruction `vpcnt.w vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpcnt.w))]
+pub unsafe fn lsx_vpcnt_w(a: v4i32) -> v4i32 {
+    __lsx_vpcnt_w(a)
+}
+
+/// Generates the instruction `vpcnt.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpcnt.d))]
+pub unsafe fn lsx_vpcnt_d(a: v2i64) -> v2i64 {
+    __lsx_vpcnt_d(a)
+}
+
+/// Generates the instruction `vclo.b vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vclo.b))]
+pub unsafe fn lsx_vclo_b(a: v16i8) -> v16i8 {
+    __lsx_vclo_b(a)
+}
+
+/// Generates the instruction `vclo.h vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vclo.h))]
+pub unsafe fn lsx_vclo_h(a: v8i16) -> v8i16 {
+    __lsx_vclo_h(a)
+}
+
+/// Generates the instruction `vclo.w vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vclo.w))]
+pub unsafe fn lsx_vclo_w(a: v4i32) -> v4i32 {
+    __lsx_vclo_w(a)
+}
+
+/// Generates the instruction `vclo.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vclo.d))]
+pub unsafe fn lsx_vclo_d(a: v2i64) -> v2i64 {
+    __lsx_vclo_d(a)
+}
+
+/// Generates the instruction `vclz.b vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vclz.b))]
+pub unsafe fn lsx_vclz_b(a: v16i8) -> v16i8 {
+    __lsx_vclz_b(a)
+}
+
+/// Generates the instruction `vclz.h vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vclz.h))]
+pub unsafe fn lsx_vclz_h(a: v8i16) -> v8i16 {
+    __lsx_vclz_h(a)
+}
+
+/// Generates the instruction `vclz.w vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vclz.w))]
+pub unsafe fn lsx_vclz_w(a: v4i32) -> v4i32 {
+    __lsx_vclz_w(a)
+}
+
+/// Generates the instruction `vclz.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vclz.d))]
+pub unsafe fn lsx_vclz_d(a: v2i64) -> v2i64 {
+    __lsx_vclz_d(a)
+}
+
+/// Generates the instruction `vpickve2gr.b rd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpickve2gr.b, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vpickve2gr_b<const IMM4: u32>(a: v16i8) -> i32 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vpickve2gr_b(a, IMM4)
+}
+
+/// Generates the instruction `vpickve2gr.h rd, vj, ui3`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpickve2gr.h, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vpickve2gr_h<const IMM3: u32>(a: v8i16) -> i32 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lsx_vpickve2gr_h(a, IMM3)
+}
+
+/// Generates the instruction `vpickve2gr.w rd, vj, ui2`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpickve2gr.w, IMM2 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vpickve2gr_w<const IMM2: u32>(a: v4i32) -> i32 {
+    static_assert_uimm_bits!(IMM2, 2);
+    __lsx_vpickve2gr_w(a, IMM2)
+}
+
+/// Generates the instruction `vpickve2gr.d rd, vj, ui1`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpickve2gr.d, IMM1 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vpickve2gr_d<const IMM1: u32>(a: v2i64) -> i64 {
+    static_assert_uimm_bits!(IMM1, 1);
+    __lsx_vpickve2gr_d(a, IMM1)
+}
+
+/// Generates the instruction `vpickve2gr.bu rd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpickve2gr.bu, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vpickve2gr_bu<const IMM4: u32>(a: v16i8) -> u32 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vpickve2gr_bu(a, IMM4)
+}
+
+/// Generates the instruction `vpickve2gr.hu rd, vj, ui3`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpickve2gr.hu, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vpickve2gr_hu<const IMM3: u32>(a: v8i16) -> u32 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lsx_vpickve2gr_hu(a, IMM3)
+}
+
+/// Generates the instruction `vpickve2gr.wu rd, vj, ui2`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpickve2gr.wu, IMM2 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vpickve2gr_wu<const IMM2: u32>(a: v4i32) -> u32 {
+    static_assert_uimm_bits!(IMM2, 2);
+    __lsx_vpickve2gr_wu(a, IMM2)
+}
+
+/// Generates the instruction `vpickve2gr.du rd, vj, ui1`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpickve2gr.du, IMM1 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vpickve2gr_du<const IMM1: u32>(a: v2i64) -> u64 {
+    static_assert_uimm_bits!(IMM1, 1);
+    __lsx_vpickve2gr_du(a, IMM1)
+}
+
+/// Generates the instruction `vinsgr2vr.b vd, rj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vinsgr2vr.b, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vinsgr2vr_b<const IMM4: u32>(a: v16i8, b: i32) -> v16i8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vinsgr2vr_b(a, b, IMM4)
+}
+
+/// Generates the instruction `vinsgr2vr.h vd, rj, ui3`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vinsgr2vr.h, IMM3 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vinsgr2vr_h<const IMM3: u32>(a: v8i16, b: i32) -> v8i16 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lsx_vinsgr2vr_h(a, b, IMM3)
+}
+
+/// Generates the instruction `vinsgr2vr.w vd, rj, ui2`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vinsgr2vr.w, IMM2 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vinsgr2vr_w<const IMM2: u32>(a: v4i32, b: i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM2, 2);
+    __lsx_vinsgr2vr_w(a, b, IMM2)
+}
+
+/// Generates the instruction `vinsgr2vr.d vd, rj, ui1`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vinsgr2vr.d, IMM1 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vinsgr2vr_d<const IMM1: u32>(a: v2i64, b: i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM1, 1);
+    __lsx_vinsgr2vr_d(a, b, IMM1)
+}
+
+/// Generates the instruction `vfadd.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfadd.s))]
+pub unsafe fn lsx_vfadd_s(a: v4f32, b: v4f32) -> v4f32 {
+    __lsx_vfadd_s(a, b)
+}
+
+/// Generates the instruction `vfadd.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfadd.d))]
+pub unsafe fn lsx_vfadd_d(a: v2f64, b: v2f64) -> v2f64 {
+    __lsx_vfadd_d(a, b)
+}
+
+/// Generates the instruction `vfsub.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfsub.s))]
+pub unsafe fn lsx_vfsub_s(a: v4f32, b: v4f32) -> v4f32 {
+    __lsx_vfsub_s(a, b)
+}
+
+/// Generates the instruction `vfsub.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfsub.d))]
+pub unsafe fn lsx_vfsub_d(a: v2f64, b: v2f64) -> v2f64 {
+    __lsx_vfsub_d(a, b)
+}
+
+/// Generates the instruction `vfmul.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfmul.s))]
+pub unsafe fn lsx_vfmul_s(a: v4f32, b: v4f32) -> v4f32 {
+    __lsx_vfmul_s(a, b)
+}
+
+/// Generates the instruction `vfmul.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfmul.d))]
+pub unsafe fn lsx_vfmul_d(a: v2f64, b: v2f64) -> v2f64 {
+    __lsx_vfmul_d(a, b)
+}
+
+/// Generates the instruction `vfdiv.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfdiv.s))]
+pub unsafe fn lsx_vfdiv_s(a: v4f32, b: v4f32) -> v4f32 {
+    __lsx_vfdiv_s(a, b)
+}
+
+/// Generates the instruction `vfdiv.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfdiv.d))]
+pub unsafe fn lsx_vfdiv_d(a: v2f64, b: v2f64) -> v2f64 {
+    __lsx_vfdiv_d(a, b)
+}
+
+/// Generates the instruction `vfcvt.h.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcvt.h.s))]
+pub unsafe fn lsx_vfcvt_h_s(a: v4f32, b: v4f32) -> v8i16 {
+    __lsx_vfcvt_h_s(a, b)
+}
+
+/// Generates the instruction `vfcvt.s.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcvt.s.d))]
+pub unsafe fn lsx_vfcvt_s_d(a: v2f64, b: v2f64) -> v4f32 {
+    __lsx_vfcvt_s_d(a, b)
+}
+
+/// Generates the instruction `vfmin.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfmin.s))]
+pub unsafe fn lsx_vfmin_s(a: v4f32, b: v4f32) -> v4f32 {
+    __lsx_vfmin_s(a, b)
+}
+
+/// Generates the instruction `vfmin.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfmin.d))]
+pub unsafe fn lsx_vfmin_d(a: v2f64, b: v2f64) -> v2f64 {
+    __lsx_vfmin_d(a, b)
+}
+
+/// Generates the instruction `vfmina.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfmina.s))]
+pub unsafe fn lsx_vfmina_s(a: v4f32, b: v4f32) -> v4f32 {
+    __lsx_vfmina_s(a, b)
+}
+
+/// Generates the instruction `vfmina.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfmina.d))]
+pub unsafe fn lsx_vfmina_d(a: v2f64, b: v2f64) -> v2f64 {
+    __lsx_vfmina_d(a, b)
+}
+
+/// Generates the instruction `vfmax.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfmax.s))]
+pub unsafe fn lsx_vfmax_s(a: v4f32, b: v4f32) -> v4f32 {
+    __lsx_vfmax_s(a, b)
+}
+
+/// Generates the instruction `vfmax.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfmax.d))]
+pub unsafe fn lsx_vfmax_d(a: v2f64, b: v2f64) -> v2f64 {
+    __lsx_vfmax_d(a, b)
+}
+
+/// Generates the instruction `vfmaxa.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfmaxa.s))]
+pub unsafe fn lsx_vfmaxa_s(a: v4f32, b: v4f32) -> v4f32 {
+    __lsx_vfmaxa_s(a, b)
+}
+
+/// Generates the instruction `vfmaxa.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfmaxa.d))]
+pub unsafe fn lsx_vfmaxa_d(a: v2f64, b: v2f64) -> v2f64 {
+    __lsx_vfmaxa_d(a, b)
+}
+
+/// Generates the instruction `vfclass.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfclass.s))]
+pub unsafe fn lsx_vfclass_s(a: v4f32) -> v4i32 {
+    __lsx_vfclass_s(a)
+}
+
+/// Generates the instruction `vfclass.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfclass.d))]
+pub unsafe fn lsx_vfclass_d(a: v2f64) -> v2i64 {
+    __lsx_vfclass_d(a)
+}
+
+/// Generates the instruction `vfsqrt.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfsqrt.s))]
+pub unsafe fn lsx_vfsqrt_s(a: v4f32) -> v4f32 {
+    __lsx_vfsqrt_s(a)
+}
+
+/// Generates the instruction `vfsqrt.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfsqrt.d))]
+pub unsafe fn lsx_vfsqrt_d(a: v2f64) -> v2f64 {
+    __lsx_vfsqrt_d(a)
+}
+
+/// Generates the instruction `vfrecip.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfrecip.s))]
+pub unsafe fn lsx_vfrecip_s(a: v4f32) -> v4f32 {
+    __lsx_vfrecip_s(a)
+}
+
+/// Generates the instruction `vfrecip.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfrecip.d))]
+pub unsafe fn lsx_vfrecip_d(a: v2f64) -> v2f64 {
+    __lsx_vfrecip_d(a)
+}
+
+/// Generates the instruction `vfrint.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfrint.s))]
+pub unsafe fn lsx_vfrint_s(a: v4f32) -> v4f32 {
+    __lsx_vfrint_s(a)
+}
+
+/// Generates the instruction `vfrint.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfrint.d))]
+pub unsafe fn lsx_vfrint_d(a: v2f64) -> v2f64 {
+    __lsx_vfrint_d(a)
+}
+
+/// Generates the instruction `vfrsqrt.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfrsqrt.s))]
+pub unsafe fn lsx_vfrsqrt_s(a: v4f32) -> v4f32 {
+    __lsx_vfrsqrt_s(a)
+}
+
+/// Generates the instruction `vfrsqrt.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfrsqrt.d))]
+pub unsafe fn lsx_vfrsqrt_d(a: v2f64) -> v2f64 {
+    __lsx_vfrsqrt_d(a)
+}
+
+/// Generates the instruction `vflogb.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vflogb.s))]
+pub unsafe fn lsx_vflogb_s(a: v4f32) -> v4f32 {
+    __lsx_vflogb_s(a)
+}
+
+/// Generates the instruction `vflogb.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vflogb.d))]
+pub unsafe fn lsx_vflogb_d(a: v2f64) -> v2f64 {
+    __lsx_vflogb_d(a)
+}
+
+/// Generates the instruction `vfcvth.s.h vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcvth.s.h))]
+pub unsafe fn lsx_vfcvth_s_h(a: v8i16) -> v4f32 {
+    __lsx_vfcvth_s_h(a)
+}
+
+/// Generates the instruction `vfcvth.d.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcvth.d.s))]
+pub unsafe fn lsx_vfcvth_d_s(a: v4f32) -> v2f64 {
+    __lsx_vfcvth_d_s(a)
+}
+
+/// Generates the instruction `vfcvtl.s.h vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcvtl.s.h))]
+pub unsafe fn lsx_vfcvtl_s_h(a: v8i16) -> v4f32 {
+    __lsx_vfcvtl_s_h(a)
+}
+
+/// Generates the instruction `vfcvtl.d.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcvtl.d.s))]
+pub unsafe fn lsx_vfcvtl_d_s(a: v4f32) -> v2f64 {
+    __lsx_vfcvtl_d_s(a)
+}
+
+/// Generates the instruction `vftint.w.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftint.w.s))]
+pub unsafe fn lsx_vftint_w_s(a: v4f32) -> v4i32 {
+    __lsx_vftint_w_s(a)
+}
+
+/// Generates the instruction `vftint.l.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftint.l.d))]
+pub unsafe fn lsx_vftint_l_d(a: v2f64) -> v2i64 {
+    __lsx_vftint_l_d(a)
+}
+
+/// Generates the instruction `vftint.wu.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftint.wu.s))]
+pub unsafe fn lsx_vftint_wu_s(a: v4f32) -> v4u32 {
+    __lsx_vftint_wu_s(a)
+}
+
+/// Generates the instruction `vftint.lu.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftint.lu.d))]
+pub unsafe fn lsx_vftint_lu_d(a: v2f64) -> v2u64 {
+    __lsx_vftint_lu_d(a)
+}
+
+/// Generates the instruction `vftintrz.w.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrz.w.s))]
+pub unsafe fn lsx_vftintrz_w_s(a: v4f32) -> v4i32 {
+    __lsx_vftintrz_w_s(a)
+}
+
+/// Generates the instruction `vftintrz.l.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrz.l.d))]
+pub unsafe fn lsx_vftintrz_l_d(a: v2f64) -> v2i64 {
+    __lsx_vftintrz_l_d(a)
+}
+
+/// Generates the instruction `vftintrz.wu.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrz.wu.s))]
+pub unsafe fn lsx_vftintrz_wu_s(a: v4f32) -> v4u32 {
+    __lsx_vftintrz_wu_s(a)
+}
+
+/// Generates the instruction `vftintrz.lu.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrz.lu.d))]
+pub unsafe fn lsx_vftintrz_lu_d(a: v2f64) -> v2u64 {
+    __lsx_vftintrz_lu_d(a)
+}
+
+/// Generates the instruction `vffint.s.w vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vffint.s.w))]
+pub unsafe fn lsx_vffint_s_w(a: v4i32) -> v4f32 {
+    __lsx_vffint_s_w(a)
+}
+
+/// Generates the instruction `vffint.d.l vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vffint.d.l))]
+pub unsafe fn lsx_vffint_d_l(a: v2i64) -> v2f64 {
+    __lsx_vffint_d_l(a)
+}
+
+/// Generates the instruction `vffint.s.wu vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vffint.s.wu))]
+pub unsafe fn lsx_vffint_s_wu(a: v4u32) -> v4f32 {
+    __lsx_vffint_s_wu(a)
+}
+
+/// Generates the instruction `vffint.d.lu vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vffint.d.lu))]
+pub unsafe fn lsx_vffint_d_lu(a: v2u64) -> v2f64 {
+    __lsx_vffint_d_lu(a)
+}
+
+/// Generates the instruction `vandn.v vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vandn.v))]
+pub unsafe fn lsx_vandn_v(a: v16u8, b: v16u8) -> v16u8 {
+    __lsx_vandn_v(a, b)
+}
+
+/// Generates the instruction `vneg.b vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vneg.b))]
+pub unsafe fn lsx_vneg_b(a: v16i8) -> v16i8 {
+    __lsx_vneg_b(a)
+}
+
+/// Generates the instruction `vneg.h vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vneg.h))]
+pub unsafe fn lsx_vneg_h(a: v8i16) -> v8i16 {
+    __lsx_vneg_h(a)
+}
+
+/// Generates the instruction `vneg.w vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vneg.w))]
+pub unsafe fn lsx_vneg_w(a: v4i32) -> v4i32 {
+    __lsx_vneg_w(a)
+}
+
+/// Generates the instruction `vneg.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vneg.d))]
+pub unsafe fn lsx_vneg_d(a: v2i64) -> v2i64 {
+    __lsx_vneg_d(a)
+}
+
+/// Generates the instruction `vmuh.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmuh.b))]
+pub unsafe fn lsx_vmuh_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vmuh_b(a, b)
+}
+
+/// Generates the instruction `vmuh.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmuh.h))]
+pub unsafe fn lsx_vmuh_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vmuh_h(a, b)
+}
+
+/// Generates the instruction `vmuh.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmuh.w))]
+pub unsafe fn lsx_vmuh_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vmuh_w(a, b)
+}
+
+/// Generates the instruction `vmuh.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmuh.d))]
+pub unsafe fn lsx_vmuh_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vmuh_d(a, b)
+}
+
+/// Generates the instruction `vmuh.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmuh.bu))]
+pub unsafe fn lsx_vmuh_bu(a: v16u8, b: v16u8) -> v16u8 {
+    __lsx_vmuh_bu(a, b)
+}
+
+/// Generates the instruction `vmuh.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmuh.hu))]
+pub unsafe fn lsx_vmuh_hu(a: v8u16, b: v8u16) -> v8u16 {
+    __lsx_vmuh_hu(a, b)
+}
+
+/// Generates the instruction `vmuh.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmuh.wu))]
+pub unsafe fn lsx_vmuh_wu(a: v4u32, b: v4u32) -> v4u32 {
+    __lsx_vmuh_wu(a, b)
+}
+
+/// Generates the instruction `vmuh.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmuh.du))]
+pub unsafe fn lsx_vmuh_du(a: v2u64, b: v2u64) -> v2u64 {
+    __lsx_vmuh_du(a, b)
+}
+
+/// Generates the instruction `vsllwil.h.b vd, vj, ui3`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsllwil.h.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsllwil_h_b<const IMM3: u32>(a: v16i8) -> v8i16 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lsx_vsllwil_h_b(a, IMM3)
+}
+
+/// Generates the instruction `vsllwil.w.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsllwil.w.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsllwil_w_h<const IMM4: u32>(a: v8i16) -> v4i32 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vsllwil_w_h(a, IMM4)
+}
+
+/// Generates the instruction `vsllwil.d.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsllwil.d.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsllwil_d_w<const IMM5: u32>(a: v4i32) -> v2i64 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vsllwil_d_w(a, IMM5)
+}
+
+/// Generates the instruction `vsllwil.hu.bu vd, vj, ui3`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsllwil.hu.bu, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsllwil_hu_bu<const IMM3: u32>(a: v16u8) -> v8u16 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lsx_vsllwil_hu_bu(a, IMM3)
+}
+
+/// Generates the instruction `vsllwil.wu.hu vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsllwil.wu.hu, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsllwil_wu_hu<const IMM4: u32>(a: v8u16) -> v4u32 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vsllwil_wu_hu(a, IMM4)
+}
+
+/// Generates the instruction `vsllwil.du.wu vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsllwil.du.wu, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vsllwil_du_wu<const IMM5: u32>(a: v4u32) -> v2u64 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vsllwil_du_wu(a, IMM5)
+}
+
+/// Generates the instruction `vsran.b.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsran.b.h))]
+pub unsafe fn lsx_vsran_b_h(a: v8i16, b: v8i16) -> v16i8 {
+    __lsx_vsran_b_h(a, b)
+}
+
+/// Generates the instruction `vsran.h.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsran.h.w))]
+pub unsafe fn lsx_vsran_h_w(a: v4i32, b: v4i32) -> v8i16 {
+    __lsx_vsran_h_w(a, b)
+}
+
+/// Generates the instruction `vsran.w.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsran.w.d))]
+pub unsafe fn lsx_vsran_w_d(a: v2i64, b: v2i64) -> v4i32 {
+    __lsx_vsran_w_d(a, b)
+}
+
+/// Generates the instruction `vssran.b.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssran.b.h))]
+pub unsafe fn lsx_vssran_b_h(a: v8i16, b: v8i16) -> v16i8 {
+    __lsx_vssran_b_h(a, b)
+}
+
+/// Generates the instruction `vssran.h.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssran.h.w))]
+pub unsafe fn lsx_vssran_h_w(a: v4i32, b: v4i32) -> v8i16 {
+    __lsx_vssran_h_w(a, b)
+}
+
+/// Generates the instruction `vssran.w.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssran.w.d))]
+pub unsafe fn lsx_vssran_w_d(a: v2i64, b: v2i64) -> v4i32 {
+    __lsx_vssran_w_d(a, b)
+}
+
+/// Generates the instruction `vssran.bu.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssran.bu.h))]
+pub unsafe fn lsx_vssran_bu_h(a: v8u16, b: v8u16) -> v16u8 {
+    __lsx_vssran_bu_h(a, b)
+}
+
+/// Generates the instruction `vssran.hu.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssran.hu.w))]
+pub unsafe fn lsx_vssran_hu_w(a: v4u32, b: v4u32) -> v8u16 {
+    __lsx_vssran_hu_w(a, b)
+}
+
+/// Generates the instruction `vssran.wu.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssran.wu.d))]
+pub unsafe fn lsx_vssran_wu_d(a: v2u64, b: v2u64) -> v4u32 {
+    __lsx_vssran_wu_d(a, b)
+}
+
+/// Generates the instruction `vsrarn.b.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrarn.b.h))]
+pub unsafe fn lsx_vsrarn_b_h(a: v8i16, b: v8i16) -> v16i8 {
+    __lsx_vsrarn_b_h(a, b)
+}
+
+/// Generates the instruction `vsrarn.h.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrarn.h.w))]
+pub unsafe fn lsx_vsrarn_h_w(a: v4i32, b: v4i32) -> v8i16 {
+    __lsx_vsrarn_h_w(a, b)
+}
+
+/// Generates the instruction `vsrarn.w.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrarn.w.d))]
+pub unsafe fn lsx_vsrarn_w_d(a: v2i64, b: v2i64) -> v4i32 {
+    __lsx_vsrarn_w_d(a, b)
+}
+
+/// Generates the instruction `vssrarn.b.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrarn.b.h))]
+pub unsafe fn lsx_vssrarn_b_h(a: v8i16, b: v8i16) -> v16i8 {
+    __lsx_vssrarn_b_h(a, b)
+}
+
+/// Generates the instruction `vssrarn.h.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrarn.h.w))]
+pub unsafe fn lsx_vssrarn_h_w(a: v4i32, b: v4i32) -> v8i16 {
+    __lsx_vssrarn_h_w(a, b)
+}
+
+/// Generates the instruction `vssrarn.w.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrarn.w.d))]
+pub unsafe fn lsx_vssrarn_w_d(a: v2i64, b: v2i64) -> v4i32 {
+    __lsx_vssrarn_w_d(a, b)
+}
+
+/// Generates the instruction `vssrarn.bu.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrarn.bu.h))]
+pub unsafe fn lsx_vssrarn_bu_h(a: v8u16, b: v8u16) -> v16u8 {
+    __lsx_vssrarn_bu_h(a, b)
+}
+
+/// Generates the instruction `vssrarn.hu.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrarn.hu.w))]
+pub unsafe fn lsx_vssrarn_hu_w(a: v4u32, b: v4u32) -> v8u16 {
+    __lsx_vssrarn_hu_w(a, b)
+}
+
+/// Generates the instruction `vssrarn.wu.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrarn.wu.d))]
+pub unsafe fn lsx_vssrarn_wu_d(a: v2u64, b: v2u64) -> v4u32 {
+    __lsx_vssrarn_wu_d(a, b)
+}
+
+/// Generates the instruction `vsrln.b.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrln.b.h))]
+pub unsafe fn lsx_vsrln_b_h(a: v8i16, b: v8i16) -> v16i8 {
+    __lsx_vsrln_b_h(a, b)
+}
+
+/// Generates the instruction `vsrln.h.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrln.h.w))]
+pub unsafe fn lsx_vsrln_h_w(a: v4i32, b: v4i32) -> v8i16 {
+    __lsx_vsrln_h_w(a, b)
+}
+
+/// Generates the instruction `vsrln.w.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrln.w.d))]
+pub unsafe fn lsx_vsrln_w_d(a: v2i64, b: v2i64) -> v4i32 {
+    __lsx_vsrln_w_d(a, b)
+}
+
+/// Generates the instruction `vssrln.bu.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrln.bu.h))]
+pub unsafe fn lsx_vssrln_bu_h(a: v8u16, b: v8u16) -> v16u8 {
+    __lsx_vssrln_bu_h(a, b)
+}
+
+/// Generates the instruction `vssrln.hu.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrln.hu.w))]
+pub unsafe fn lsx_vssrln_hu_w(a: v4u32, b: v4u32) -> v8u16 {
+    __lsx_vssrln_hu_w(a, b)
+}
+
+/// Generates the instruction `vssrln.wu.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrln.wu.d))]
+pub unsafe fn lsx_vssrln_wu_d(a: v2u64, b: v2u64) -> v4u32 {
+    __lsx_vssrln_wu_d(a, b)
+}
+
+/// Generates the instruction `vsrlrn.b.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrlrn.b.h))]
+pub unsafe fn lsx_vsrlrn_b_h(a: v8i16, b: v8i16) -> v16i8 {
+    __lsx_vsrlrn_b_h(a, b)
+}
+
+/// Generates the instruction `vsrlrn.h.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrlrn.h.w))]
+pub unsafe fn lsx_vsrlrn_h_w(a: v4i32, b: v4i32) -> v8i16 {
+    __lsx_vsrlrn_h_w(a, b)
+}
+
+/// Generates the instruction `vsrlrn.w.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrlrn.w.d))]
+pub unsafe fn lsx_vsrlrn_w_d(a: v2i64, b: v2i64) -> v4i32 {
+    __lsx_vsrlrn_w_d(a, b)
+}
+
+/// Generates the instruction `vssrlrn.bu.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlrn.bu.h))]
+pub unsafe fn lsx_vssrlrn_bu_h(a: v8u16, b: v8u16) -> v16u8 {
+    __lsx_vssrlrn_bu_h(a, b)
+}
+
+/// Generates the instruction `vssrlrn.hu.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlrn.hu.w))]
+pub unsafe fn lsx_vssrlrn_hu_w(a: v4u32, b: v4u32) -> v8u16 {
+    __lsx_vssrlrn_hu_w(a, b)
+}
+
+/// Generates the instruction `vssrlrn.wu.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlrn.wu.d))]
+pub unsafe fn lsx_vssrlrn_wu_d(a: v2u64, b: v2u64) -> v4u32 {
+    __lsx_vssrlrn_wu_d(a, b)
+}
+
+/// Generates the instruction `vfrstpi.b vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfrstpi.b, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vfrstpi_b<const IMM5: u32>(a: v16i8, b: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vfrstpi_b(a, b, IMM5)
+}
+
+/// Generates the instruction `vfrstpi.h vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfrstpi.h, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vfrstpi_h<const IMM5: u32>(a: v8i16, b: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vfrstpi_h(a, b, IMM5)
+}
+
+/// Generates the instruction `vfrstp.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfrstp.b))]
+pub unsafe fn lsx_vfrstp_b(a: v16i8, b: v16i8, c: v16i8) -> v16i8 {
+    __lsx_vfrstp_b(a, b, c)
+}
+
+/// Generates the instruction `vfrstp.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfrstp.h))]
+pub unsafe fn lsx_vfrstp_h(a: v8i16, b: v8i16, c: v8i16) -> v8i16 {
+    __lsx_vfrstp_h(a, b, c)
+}
+
+/// Generates the instruction `vshuf4i.d vd, vj, ui8`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vshuf4i.d, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vshuf4i_d<const IMM8: u32>(a: v2i64, b: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lsx_vshuf4i_d(a, b, IMM8)
+}
+
+/// Generates the instruction `vbsrl.v vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbsrl.v, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vbsrl_v<const IMM5: u32>(a: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vbsrl_v(a, IMM5)
+}
+
+/// Generates the instruction `vbsll.v vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vbsll.v, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vbsll_v<const IMM5: u32>(a: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vbsll_v(a, IMM5)
+}
+
+/// Generates the instruction `vextrins.b vd, vj, ui8`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vextrins.b, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vextrins_b<const IMM8: u32>(a: v16i8, b: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lsx_vextrins_b(a, b, IMM8)
+}
+
+/// Generates the instruction `vextrins.h vd, vj, ui8`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vextrins.h, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vextrins_h<const IMM8: u32>(a: v8i16, b: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lsx_vextrins_h(a, b, IMM8)
+}
+
+/// Generates the instruction `vextrins.w vd, vj, ui8`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vextrins.w, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vextrins_w<const IMM8: u32>(a: v4i32, b: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lsx_vextrins_w(a, b, IMM8)
+}
+
+/// Generates the instruction `vextrins.d vd, vj, ui8`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vextrins.d, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vextrins_d<const IMM8: u32>(a: v2i64, b: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lsx_vextrins_d(a, b, IMM8)
+}
+
+/// Generates the instruction `vmskltz.b vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmskltz.b))]
+pub unsafe fn lsx_vmskltz_b(a: v16i8) -> v16i8 {
+    __lsx_vmskltz_b(a)
+}
+
+/// Generates the instruction `vmskltz.h vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmskltz.h))]
+pub unsafe fn lsx_vmskltz_h(a: v8i16) -> v8i16 {
+    __lsx_vmskltz_h(a)
+}
+
+/// Generates the instruction `vmskltz.w vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmskltz.w))]
+pub unsafe fn lsx_vmskltz_w(a: v4i32) -> v4i32 {
+    __lsx_vmskltz_w(a)
+}
+
+/// Generates the instruction `vmskltz.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmskltz.d))]
+pub unsafe fn lsx_vmskltz_d(a: v2i64) -> v2i64 {
+    __lsx_vmskltz_d(a)
+}
+
+/// Generates the instruction `vsigncov.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsigncov.b))]
+pub unsafe fn lsx_vsigncov_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vsigncov_b(a, b)
+}
+
+/// Generates the instruction `vsigncov.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsigncov.h))]
+pub unsafe fn lsx_vsigncov_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vsigncov_h(a, b)
+}
+
+/// Generates the instruction `vsigncov.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsigncov.w))]
+pub unsafe fn lsx_vsigncov_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vsigncov_w(a, b)
+}
+
+/// Generates the instruction `vsigncov.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsigncov.d))]
+pub unsafe fn lsx_vsigncov_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vsigncov_d(a, b)
+}
+
+/// Generates the instruction `vfmadd.s vd, vj, vk, va`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfmadd.s))]
+pub unsafe fn lsx_vfmadd_s(a: v4f32, b: v4f32, c: v4f32) -> v4f32 {
+    __lsx_vfmadd_s(a, b, c)
+}
+
+/// Generates the instruction `vfmadd.d vd, vj, vk, va`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfmadd.d))]
+pub unsafe fn lsx_vfmadd_d(a: v2f64, b: v2f64, c: v2f64) -> v2f64 {
+    __lsx_vfmadd_d(a, b, c)
+}
+
+/// Generates the instruction `vfmsub.s vd, vj, vk, va`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfmsub.s))]
+pub unsafe fn lsx_vfmsub_s(a: v4f32, b: v4f32, c: v4f32) -> v4f32 {
+    __lsx_vfmsub_s(a, b, c)
+}
+
+/// Generates the instruction `vfmsub.d vd, vj, vk, va`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfmsub.d))]
+pub unsafe fn lsx_vfmsub_d(a: v2f64, b: v2f64, c: v2f64) -> v2f64 {
+    __lsx_vfmsub_d(a, b, c)
+}
+
+/// Generates the instruction `vfnmadd.s vd, vj, vk, va`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfnmadd.s))]
+pub unsafe fn lsx_vfnmadd_s(a: v4f32, b: v4f32, c: v4f32) -> v4f32 {
+    __lsx_vfnmadd_s(a, b, c)
+}
+
+/// Generates the instruction `vfnmadd.d vd, vj, vk, va`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfnmadd.d))]
+pub unsafe fn lsx_vfnmadd_d(a: v2f64, b: v2f64, c: v2f64) -> v2f64 {
+    __lsx_vfnmadd_d(a, b, c)
+}
+
+/// Generates the instruction `vfnmsub.s vd, vj, vk, va`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfnmsub.s))]
+pub unsafe fn lsx_vfnmsub_s(a: v4f32, b: v4f32, c: v4f32) -> v4f32 {
+    __lsx_vfnmsub_s(a, b, c)
+}
+
+/// Generates the instruction `vfnmsub.d vd, vj, vk, va`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfnmsub.d))]
+pub unsafe fn lsx_vfnmsub_d(a: v2f64, b: v2f64, c: v2f64) -> v2f64 {
+    __lsx_vfnmsub_d(a, b, c)
+}
+
+/// Generates the instruction `vftintrne.w.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrne.w.s))]
+pub unsafe fn lsx_vftintrne_w_s(a: v4f32) -> v4i32 {
+    __lsx_vftintrne_w_s(a)
+}
+
+/// Generates the instruction `vftintrne.l.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrne.l.d))]
+pub unsafe fn lsx_vftintrne_l_d(a: v2f64) -> v2i64 {
+    __lsx_vftintrne_l_d(a)
+}
+
+/// Generates the instruction `vftintrp.w.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrp.w.s))]
+pub unsafe fn lsx_vftintrp_w_s(a: v4f32) -> v4i32 {
+    __lsx_vftintrp_w_s(a)
+}
+
+/// Generates the instruction `vftintrp.l.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrp.l.d))]
+pub unsafe fn lsx_vftintrp_l_d(a: v2f64) -> v2i64 {
+    __lsx_vftintrp_l_d(a)
+}
+
+/// Generates the instruction `vftintrm.w.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrm.w.s))]
+pub unsafe fn lsx_vftintrm_w_s(a: v4f32) -> v4i32 {
+    __lsx_vftintrm_w_s(a)
+}
+
+/// Generates the instruction `vftintrm.l.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrm.l.d))]
+pub unsafe fn lsx_vftintrm_l_d(a: v2f64) -> v2i64 {
+    __lsx_vftintrm_l_d(a)
+}
+
+/// Generates the instruction `vftint.w.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftint.w.d))]
+pub unsafe fn lsx_vftint_w_d(a: v2f64, b: v2f64) -> v4i32 {
+    __lsx_vftint_w_d(a, b)
+}
+
+/// Generates the instruction `vffint.s.l vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vffint.s.l))]
+pub unsafe fn lsx_vffint_s_l(a: v2i64, b: v2i64) -> v4f32 {
+    __lsx_vffint_s_l(a, b)
+}
+
+/// Generates the instruction `vftintrz.w.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrz.w.d))]
+pub unsafe fn lsx_vftintrz_w_d(a: v2f64, b: v2f64) -> v4i32 {
+    __lsx_vftintrz_w_d(a, b)
+}
+
+/// Generates the instruction `vftintrp.w.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrp.w.d))]
+pub unsafe fn lsx_vftintrp_w_d(a: v2f64, b: v2f64) -> v4i32 {
+    __lsx_vftintrp_w_d(a, b)
+}
+
+/// Generates the instruction `vftintrm.w.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrm.w.d))]
+pub unsafe fn lsx_vftintrm_w_d(a: v2f64, b: v2f64) -> v4i32 {
+    __lsx_vftintrm_w_d(a, b)
+}
+
+/// Generates the instruction `vftintrne.w.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrne.w.d))]
+pub unsafe fn lsx_vftintrne_w_d(a: v2f64, b: v2f64) -> v4i32 {
+    __lsx_vftintrne_w_d(a, b)
+}
+
+/// Generates the instruction `vftintl.l.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintl.l.s))]
+pub unsafe fn lsx_vftintl_l_s(a: v4f32) -> v2i64 {
+    __lsx_vftintl_l_s(a)
+}
+
+/// Generates the instruction `vftinth.l.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftinth.l.s))]
+pub unsafe fn lsx_vftinth_l_s(a: v4f32) -> v2i64 {
+    __lsx_vftinth_l_s(a)
+}
+
+/// Generates the instruction `vffinth.d.w vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vffinth.d.w))]
+pub unsafe fn lsx_vffinth_d_w(a: v4i32) -> v2f64 {
+    __lsx_vffinth_d_w(a)
+}
+
+/// Generates the instruction `vffintl.d.w vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vffintl.d.w))]
+pub unsafe fn lsx_vffintl_d_w(a: v4i32) -> v2f64 {
+    __lsx_vffintl_d_w(a)
+}
+
+/// Generates the instruction `vftintrzl.l.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrzl.l.s))]
+pub unsafe fn lsx_vftintrzl_l_s(a: v4f32) -> v2i64 {
+    __lsx_vftintrzl_l_s(a)
+}
+
+/// Generates the instruction `vftintrzh.l.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrzh.l.s))]
+pub unsafe fn lsx_vftintrzh_l_s(a: v4f32) -> v2i64 {
+    __lsx_vftintrzh_l_s(a)
+}
+
+/// Generates the instruction `vftintrpl.l.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrpl.l.s))]
+pub unsafe fn lsx_vftintrpl_l_s(a: v4f32) -> v2i64 {
+    __lsx_vftintrpl_l_s(a)
+}
+
+/// Generates the instruction `vftintrph.l.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrph.l.s))]
+pub unsafe fn lsx_vftintrph_l_s(a: v4f32) -> v2i64 {
+    __lsx_vftintrph_l_s(a)
+}
+
+/// Generates the instruction `vftintrml.l.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrml.l.s))]
+pub unsafe fn lsx_vftintrml_l_s(a: v4f32) -> v2i64 {
+    __lsx_vftintrml_l_s(a)
+}
+
+/// Generates the instruction `vftintrmh.l.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrmh.l.s))]
+pub unsafe fn lsx_vftintrmh_l_s(a: v4f32) -> v2i64 {
+    __lsx_vftintrmh_l_s(a)
+}
+
+/// Generates the instruction `vftintrnel.l.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrnel.l.s))]
+pub unsafe fn lsx_vftintrnel_l_s(a: v4f32) -> v2i64 {
+    __lsx_vftintrnel_l_s(a)
+}
+
+/// Generates the instruction `vftintrneh.l.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vftintrneh.l.s))]
+pub unsafe fn lsx_vftintrneh_l_s(a: v4f32) -> v2i64 {
+    __lsx_vftintrneh_l_s(a)
+}
+
+/// Generates the instruction `vfrintrne.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfrintrne.s))]
+pub unsafe fn lsx_vfrintrne_s(a: v4f32) -> v4f32 {
+    __lsx_vfrintrne_s(a)
+}
+
+/// Generates the instruction `vfrintrne.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfrintrne.d))]
+pub unsafe fn lsx_vfrintrne_d(a: v2f64) -> v2f64 {
+    __lsx_vfrintrne_d(a)
+}
+
+/// Generates the instruction `vfrintrz.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfrintrz.s))]
+pub unsafe fn lsx_vfrintrz_s(a: v4f32) -> v4f32 {
+    __lsx_vfrintrz_s(a)
+}
+
+/// Generates the instruction `vfrintrz.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfrintrz.d))]
+pub unsafe fn lsx_vfrintrz_d(a: v2f64) -> v2f64 {
+    __lsx_vfrintrz_d(a)
+}
+
+/// Generates the instruction `vfrintrp.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfrintrp.s))]
+pub unsafe fn lsx_vfrintrp_s(a: v4f32) -> v4f32 {
+    __lsx_vfrintrp_s(a)
+}
+
+/// Generates the instruction `vfrintrp.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfrintrp.d))]
+pub unsafe fn lsx_vfrintrp_d(a: v2f64) -> v2f64 {
+    __lsx_vfrintrp_d(a)
+}
+
+/// Generates the instruction `vfrintrm.s vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfrintrm.s))]
+pub unsafe fn lsx_vfrintrm_s(a: v4f32) -> v4f32 {
+    __lsx_vfrintrm_s(a)
+}
+
+/// Generates the instruction `vfrintrm.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfrintrm.d))]
+pub unsafe fn lsx_vfrintrm_d(a: v2f64) -> v2f64 {
+    __lsx_vfrintrm_d(a)
+}
+
+/// Generates the instruction `vstelm.b vd, rj, si8, idx`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vstelm.b, IMM_S8 = 1, IMM4 = 1))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn lsx_vstelm_b<const IMM_S8: i32, const IMM4: u32>(a: v16i8, mem_addr: *mut i8) {
+    static_assert_simm_bits!(IMM_S8, 8);
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vstelm_b(a, mem_addr, IMM_S8, IMM4)
+}
+
+/// Generates the instruction `vstelm.h vd, rj, si8, idx`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vstelm.h, IMM_S8 = 1, IMM3 = 1))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn lsx_vstelm_h<const IMM_S8: i32, const IMM3: u32>(a: v8i16, mem_addr: *mut i8) {
+    static_assert_simm_bits!(IMM_S8, 8);
+    static_assert_uimm_bits!(IMM3, 3);
+    __lsx_vstelm_h(a, mem_addr, IMM_S8, IMM3)
+}
+
+/// Generates the instruction `vstelm.w vd, rj, si8, idx`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vstelm.w, IMM_S8 = 1, IMM2 = 1))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn lsx_vstelm_w<const IMM_S8: i32, const IMM2: u32>(a: v4i32, mem_addr: *mut i8) {
+    static_assert_simm_bits!(IMM_S8, 8);
+    static_assert_uimm_bits!(IMM2, 2);
+    __lsx_vstelm_w(a, mem_addr, IMM_S8, IMM2)
+}
+
+/// Generates the instruction `vstelm.d vd, rj, si8, idx`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vstelm.d, IMM_S8 = 1, IMM1 = 1))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn lsx_vstelm_d<const IMM_S8: i32, const IMM1: u32>(a: v2i64, mem_addr: *mut i8) {
+    static_assert_simm_bits!(IMM_S8, 8);
+    static_assert_uimm_bits!(IMM1, 1);
+    __lsx_vstelm_d(a, mem_addr, IMM_S8, IMM1)
+}
+
+/// Generates the instruction `vaddwev.d.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwev.d.w))]
+pub unsafe fn lsx_vaddwev_d_w(a: v4i32, b: v4i32) -> v2i64 {
+    __lsx_vaddwev_d_w(a, b)
+}
+
+/// Generates the instruction `vaddwev.w.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwev.w.h))]
+pub unsafe fn lsx_vaddwev_w_h(a: v8i16, b: v8i16) -> v4i32 {
+    __lsx_vaddwev_w_h(a, b)
+}
+
+/// Generates the instruction `vaddwev.h.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwev.h.b))]
+pub unsafe fn lsx_vaddwev_h_b(a: v16i8, b: v16i8) -> v8i16 {
+    __lsx_vaddwev_h_b(a, b)
+}
+
+/// Generates the instruction `vaddwod.d.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwod.d.w))]
+pub unsafe fn lsx_vaddwod_d_w(a: v4i32, b: v4i32) -> v2i64 {
+    __lsx_vaddwod_d_w(a, b)
+}
+
+/// Generates the instruction `vaddwod.w.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwod.w.h))]
+pub unsafe fn lsx_vaddwod_w_h(a: v8i16, b: v8i16) -> v4i32 {
+    __lsx_vaddwod_w_h(a, b)
+}
+
+/// Generates the instruction `vaddwod.h.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwod.h.b))]
+pub unsafe fn lsx_vaddwod_h_b(a: v16i8, b: v16i8) -> v8i16 {
+    __lsx_vaddwod_h_b(a, b)
+}
+
+/// Generates the instruction `vaddwev.d.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwev.d.wu))]
+pub unsafe fn lsx_vaddwev_d_wu(a: v4u32, b: v4u32) -> v2i64 {
+    __lsx_vaddwev_d_wu(a, b)
+}
+
+/// Generates the instruction `vaddwev.w.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwev.w.hu))]
+pub unsafe fn lsx_vaddwev_w_hu(a: v8u16, b: v8u16) -> v4i32 {
+    __lsx_vaddwev_w_hu(a, b)
+}
+
+/// Generates the instruction `vaddwev.h.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwev.h.bu))]
+pub unsafe fn lsx_vaddwev_h_bu(a: v16u8, b: v16u8) -> v8i16 {
+    __lsx_vaddwev_h_bu(a, b)
+}
+
+/// Generates the instruction `vaddwod.d.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwod.d.wu))]
+pub unsafe fn lsx_vaddwod_d_wu(a: v4u32, b: v4u32) -> v2i64 {
+    __lsx_vaddwod_d_wu(a, b)
+}
+
+/// Generates the instruction `vaddwod.w.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwod.w.hu))]
+pub unsafe fn lsx_vaddwod_w_hu(a: v8u16, b: v8u16) -> v4i32 {
+    __lsx_vaddwod_w_hu(a, b)
+}
+
+/// Generates the instruction `vaddwod.h.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwod.h.bu))]
+pub unsafe fn lsx_vaddwod_h_bu(a: v16u8, b: v16u8) -> v8i16 {
+    __lsx_vaddwod_h_bu(a, b)
+}
+
+/// Generates the instruction `vaddwev.d.wu.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwev.d.wu.w))]
+pub unsafe fn lsx_vaddwev_d_wu_w(a: v4u32, b: v4i32) -> v2i64 {
+    __lsx_vaddwev_d_wu_w(a, b)
+}
+
+/// Generates the instruction `vaddwev.w.hu.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwev.w.hu.h))]
+pub unsafe fn lsx_vaddwev_w_hu_h(a: v8u16, b: v8i16) -> v4i32 {
+    __lsx_vaddwev_w_hu_h(a, b)
+}
+
+/// Generates the instruction `vaddwev.h.bu.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwev.h.bu.b))]
+pub unsafe fn lsx_vaddwev_h_bu_b(a: v16u8, b: v16i8) -> v8i16 {
+    __lsx_vaddwev_h_bu_b(a, b)
+}
+
+/// Generates the instruction `vaddwod.d.wu.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwod.d.wu.w))]
+pub unsafe fn lsx_vaddwod_d_wu_w(a: v4u32, b: v4i32) -> v2i64 {
+    __lsx_vaddwod_d_wu_w(a, b)
+}
+
+/// Generates the instruction `vaddwod.w.hu.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwod.w.hu.h))]
+pub unsafe fn lsx_vaddwod_w_hu_h(a: v8u16, b: v8i16) -> v4i32 {
+    __lsx_vaddwod_w_hu_h(a, b)
+}
+
+/// Generates the instruction `vaddwod.h.bu.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwod.h.bu.b))]
+pub unsafe fn lsx_vaddwod_h_bu_b(a: v16u8, b: v16i8) -> v8i16 {
+    __lsx_vaddwod_h_bu_b(a, b)
+}
+
+/// Generates the instruction `vsubwev.d.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubwev.d.w))]
+pub unsafe fn lsx_vsubwev_d_w(a: v4i32, b: v4i32) -> v2i64 {
+    __lsx_vsubwev_d_w(a, b)
+}
+
+/// Generates the instruction `vsubwev.w.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubwev.w.h))]
+pub unsafe fn lsx_vsubwev_w_h(a: v8i16, b: v8i16) -> v4i32 {
+    __lsx_vsubwev_w_h(a, b)
+}
+
+/// Generates the instruction `vsubwev.h.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubwev.h.b))]
+pub unsafe fn lsx_vsubwev_h_b(a: v16i8, b: v16i8) -> v8i16 {
+    __lsx_vsubwev_h_b(a, b)
+}
+
+/// Generates the instruction `vsubwod.d.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubwod.d.w))]
+pub unsafe fn lsx_vsubwod_d_w(a: v4i32, b: v4i32) -> v2i64 {
+    __lsx_vsubwod_d_w(a, b)
+}
+
+/// Generates the instruction `vsubwod.w.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubwod.w.h))]
+pub unsafe fn lsx_vsubwod_w_h(a: v8i16, b: v8i16) -> v4i32 {
+    __lsx_vsubwod_w_h(a, b)
+}
+
+/// Generates the instruction `vsubwod.h.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubwod.h.b))]
+pub unsafe fn lsx_vsubwod_h_b(a: v16i8, b: v16i8) -> v8i16 {
+    __lsx_vsubwod_h_b(a, b)
+}
+
+/// Generates the instruction `vsubwev.d.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubwev.d.wu))]
+pub unsafe fn lsx_vsubwev_d_wu(a: v4u32, b: v4u32) -> v2i64 {
+    __lsx_vsubwev_d_wu(a, b)
+}
+
+/// Generates the instruction `vsubwev.w.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubwev.w.hu))]
+pub unsafe fn lsx_vsubwev_w_hu(a: v8u16, b: v8u16) -> v4i32 {
+    __lsx_vsubwev_w_hu(a, b)
+}
+
+/// Generates the instruction `vsubwev.h.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubwev.h.bu))]
+pub unsafe fn lsx_vsubwev_h_bu(a: v16u8, b: v16u8) -> v8i16 {
+    __lsx_vsubwev_h_bu(a, b)
+}
+
+/// Generates the instruction `vsubwod.d.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubwod.d.wu))]
+pub unsafe fn lsx_vsubwod_d_wu(a: v4u32, b: v4u32) -> v2i64 {
+    __lsx_vsubwod_d_wu(a, b)
+}
+
+/// Generates the instruction `vsubwod.w.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubwod.w.hu))]
+pub unsafe fn lsx_vsubwod_w_hu(a: v8u16, b: v8u16) -> v4i32 {
+    __lsx_vsubwod_w_hu(a, b)
+}
+
+/// Generates the instruction `vsubwod.h.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubwod.h.bu))]
+pub unsafe fn lsx_vsubwod_h_bu(a: v16u8, b: v16u8) -> v8i16 {
+    __lsx_vsubwod_h_bu(a, b)
+}
+
+/// Generates the instruction `vaddwev.q.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwev.q.d))]
+pub unsafe fn lsx_vaddwev_q_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vaddwev_q_d(a, b)
+}
+
+/// Generates the instruction `vaddwod.q.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwod.q.d))]
+pub unsafe fn lsx_vaddwod_q_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vaddwod_q_d(a, b)
+}
+
+/// Generates the instruction `vaddwev.q.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwev.q.du))]
+pub unsafe fn lsx_vaddwev_q_du(a: v2u64, b: v2u64) -> v2i64 {
+    __lsx_vaddwev_q_du(a, b)
+}
+
+/// Generates the instruction `vaddwod.q.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwod.q.du))]
+pub unsafe fn lsx_vaddwod_q_du(a: v2u64, b: v2u64) -> v2i64 {
+    __lsx_vaddwod_q_du(a, b)
+}
+
+/// Generates the instruction `vsubwev.q.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubwev.q.d))]
+pub unsafe fn lsx_vsubwev_q_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vsubwev_q_d(a, b)
+}
+
+/// Generates the instruction `vsubwod.q.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubwod.q.d))]
+pub unsafe fn lsx_vsubwod_q_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vsubwod_q_d(a, b)
+}
+
+/// Generates the instruction `vsubwev.q.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubwev.q.du))]
+pub unsafe fn lsx_vsubwev_q_du(a: v2u64, b: v2u64) -> v2i64 {
+    __lsx_vsubwev_q_du(a, b)
+}
+
+/// Generates the instruction `vsubwod.q.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsubwod.q.du))]
+pub unsafe fn lsx_vsubwod_q_du(a: v2u64, b: v2u64) -> v2i64 {
+    __lsx_vsubwod_q_du(a, b)
+}
+
+/// Generates the instruction `vaddwev.q.du.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwev.q.du.d))]
+pub unsafe fn lsx_vaddwev_q_du_d(a: v2u64, b: v2i64) -> v2i64 {
+    __lsx_vaddwev_q_du_d(a, b)
+}
+
+/// Generates the instruction `vaddwod.q.du.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vaddwod.q.du.d))]
+pub unsafe fn lsx_vaddwod_q_du_d(a: v2u64, b: v2i64) -> v2i64 {
+    __lsx_vaddwod_q_du_d(a, b)
+}
+
+/// Generates the instruction `vmulwev.d.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwev.d.w))]
+pub unsafe fn lsx_vmulwev_d_w(a: v4i32, b: v4i32) -> v2i64 {
+    __lsx_vmulwev_d_w(a, b)
+}
+
+/// Generates the instruction `vmulwev.w.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwev.w.h))]
+pub unsafe fn lsx_vmulwev_w_h(a: v8i16, b: v8i16) -> v4i32 {
+    __lsx_vmulwev_w_h(a, b)
+}
+
+/// Generates the instruction `vmulwev.h.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwev.h.b))]
+pub unsafe fn lsx_vmulwev_h_b(a: v16i8, b: v16i8) -> v8i16 {
+    __lsx_vmulwev_h_b(a, b)
+}
+
+/// Generates the instruction `vmulwod.d.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwod.d.w))]
+pub unsafe fn lsx_vmulwod_d_w(a: v4i32, b: v4i32) -> v2i64 {
+    __lsx_vmulwod_d_w(a, b)
+}
+
+/// Generates the instruction `vmulwod.w.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwod.w.h))]
+pub unsafe fn lsx_vmulwod_w_h(a: v8i16, b: v8i16) -> v4i32 {
+    __lsx_vmulwod_w_h(a, b)
+}
+
+/// Generates the instruction `vmulwod.h.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwod.h.b))]
+pub unsafe fn lsx_vmulwod_h_b(a: v16i8, b: v16i8) -> v8i16 {
+    __lsx_vmulwod_h_b(a, b)
+}
+
+/// Generates the instruction `vmulwev.d.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwev.d.wu))]
+pub unsafe fn lsx_vmulwev_d_wu(a: v4u32, b: v4u32) -> v2i64 {
+    __lsx_vmulwev_d_wu(a, b)
+}
+
+/// Generates the instruction `vmulwev.w.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwev.w.hu))]
+pub unsafe fn lsx_vmulwev_w_hu(a: v8u16, b: v8u16) -> v4i32 {
+    __lsx_vmulwev_w_hu(a, b)
+}
+
+/// Generates the instruction `vmulwev.h.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwev.h.bu))]
+pub unsafe fn lsx_vmulwev_h_bu(a: v16u8, b: v16u8) -> v8i16 {
+    __lsx_vmulwev_h_bu(a, b)
+}
+
+/// Generates the instruction `vmulwod.d.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwod.d.wu))]
+pub unsafe fn lsx_vmulwod_d_wu(a: v4u32, b: v4u32) -> v2i64 {
+    __lsx_vmulwod_d_wu(a, b)
+}
+
+/// Generates the instruction `vmulwod.w.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwod.w.hu))]
+pub unsafe fn lsx_vmulwod_w_hu(a: v8u16, b: v8u16) -> v4i32 {
+    __lsx_vmulwod_w_hu(a, b)
+}
+
+/// Generates the instruction `vmulwod.h.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwod.h.bu))]
+pub unsafe fn lsx_vmulwod_h_bu(a: v16u8, b: v16u8) -> v8i16 {
+    __lsx_vmulwod_h_bu(a, b)
+}
+
+/// Generates the instruction `vmulwev.d.wu.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwev.d.wu.w))]
+pub unsafe fn lsx_vmulwev_d_wu_w(a: v4u32, b: v4i32) -> v2i64 {
+    __lsx_vmulwev_d_wu_w(a, b)
+}
+
+/// Generates the instruction `vmulwev.w.hu.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwev.w.hu.h))]
+pub unsafe fn lsx_vmulwev_w_hu_h(a: v8u16, b: v8i16) -> v4i32 {
+    __lsx_vmulwev_w_hu_h(a, b)
+}
+
+/// Generates the instruction `vmulwev.h.bu.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwev.h.bu.b))]
+pub unsafe fn lsx_vmulwev_h_bu_b(a: v16u8, b: v16i8) -> v8i16 {
+    __lsx_vmulwev_h_bu_b(a, b)
+}
+
+/// Generates the instruction `vmulwod.d.wu.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwod.d.wu.w))]
+pub unsafe fn lsx_vmulwod_d_wu_w(a: v4u32, b: v4i32) -> v2i64 {
+    __lsx_vmulwod_d_wu_w(a, b)
+}
+
+/// Generates the instruction `vmulwod.w.hu.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwod.w.hu.h))]
+pub unsafe fn lsx_vmulwod_w_hu_h(a: v8u16, b: v8i16) -> v4i32 {
+    __lsx_vmulwod_w_hu_h(a, b)
+}
+
+/// Generates the instruction `vmulwod.h.bu.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwod.h.bu.b))]
+pub unsafe fn lsx_vmulwod_h_bu_b(a: v16u8, b: v16i8) -> v8i16 {
+    __lsx_vmulwod_h_bu_b(a, b)
+}
+
+/// Generates the instruction `vmulwev.q.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwev.q.d))]
+pub unsafe fn lsx_vmulwev_q_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vmulwev_q_d(a, b)
+}
+
+/// Generates the instruction `vmulwod.q.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwod.q.d))]
+pub unsafe fn lsx_vmulwod_q_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vmulwod_q_d(a, b)
+}
+
+/// Generates the instruction `vmulwev.q.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwev.q.du))]
+pub unsafe fn lsx_vmulwev_q_du(a: v2u64, b: v2u64) -> v2i64 {
+    __lsx_vmulwev_q_du(a, b)
+}
+
+/// Generates the instruction `vmulwod.q.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwod.q.du))]
+pub unsafe fn lsx_vmulwod_q_du(a: v2u64, b: v2u64) -> v2i64 {
+    __lsx_vmulwod_q_du(a, b)
+}
+
+/// Generates the instruction `vmulwev.q.du.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwev.q.du.d))]
+pub unsafe fn lsx_vmulwev_q_du_d(a: v2u64, b: v2i64) -> v2i64 {
+    __lsx_vmulwev_q_du_d(a, b)
+}
+
+/// Generates the instruction `vmulwod.q.du.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmulwod.q.du.d))]
+pub unsafe fn lsx_vmulwod_q_du_d(a: v2u64, b: v2i64) -> v2i64 {
+    __lsx_vmulwod_q_du_d(a, b)
+}
+
+/// Generates the instruction `vhaddw.q.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vhaddw.q.d))]
+pub unsafe fn lsx_vhaddw_q_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vhaddw_q_d(a, b)
+}
+
+/// Generates the instruction `vhaddw.qu.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vhaddw.qu.du))]
+pub unsafe fn lsx_vhaddw_qu_du(a: v2u64, b: v2u64) -> v2u64 {
+    __lsx_vhaddw_qu_du(a, b)
+}
+
+/// Generates the instruction `vhsubw.q.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vhsubw.q.d))]
+pub unsafe fn lsx_vhsubw_q_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vhsubw_q_d(a, b)
+}
+
+/// Generates the instruction `vhsubw.qu.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vhsubw.qu.du))]
+pub unsafe fn lsx_vhsubw_qu_du(a: v2u64, b: v2u64) -> v2u64 {
+    __lsx_vhsubw_qu_du(a, b)
+}
+
+/// Generates the instruction `vmaddwev.d.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwev.d.w))]
+pub unsafe fn lsx_vmaddwev_d_w(a: v2i64, b: v4i32, c: v4i32) -> v2i64 {
+    __lsx_vmaddwev_d_w(a, b, c)
+}
+
+/// Generates the instruction `vmaddwev.w.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwev.w.h))]
+pub unsafe fn lsx_vmaddwev_w_h(a: v4i32, b: v8i16, c: v8i16) -> v4i32 {
+    __lsx_vmaddwev_w_h(a, b, c)
+}
+
+/// Generates the instruction `vmaddwev.h.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwev.h.b))]
+pub unsafe fn lsx_vmaddwev_h_b(a: v8i16, b: v16i8, c: v16i8) -> v8i16 {
+    __lsx_vmaddwev_h_b(a, b, c)
+}
+
+/// Generates the instruction `vmaddwev.d.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwev.d.wu))]
+pub unsafe fn lsx_vmaddwev_d_wu(a: v2u64, b: v4u32, c: v4u32) -> v2u64 {
+    __lsx_vmaddwev_d_wu(a, b, c)
+}
+
+/// Generates the instruction `vmaddwev.w.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwev.w.hu))]
+pub unsafe fn lsx_vmaddwev_w_hu(a: v4u32, b: v8u16, c: v8u16) -> v4u32 {
+    __lsx_vmaddwev_w_hu(a, b, c)
+}
+
+/// Generates the instruction `vmaddwev.h.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwev.h.bu))]
+pub unsafe fn lsx_vmaddwev_h_bu(a: v8u16, b: v16u8, c: v16u8) -> v8u16 {
+    __lsx_vmaddwev_h_bu(a, b, c)
+}
+
+/// Generates the instruction `vmaddwod.d.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwod.d.w))]
+pub unsafe fn lsx_vmaddwod_d_w(a: v2i64, b: v4i32, c: v4i32) -> v2i64 {
+    __lsx_vmaddwod_d_w(a, b, c)
+}
+
+/// Generates the instruction `vmaddwod.w.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwod.w.h))]
+pub unsafe fn lsx_vmaddwod_w_h(a: v4i32, b: v8i16, c: v8i16) -> v4i32 {
+    __lsx_vmaddwod_w_h(a, b, c)
+}
+
+/// Generates the instruction `vmaddwod.h.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwod.h.b))]
+pub unsafe fn lsx_vmaddwod_h_b(a: v8i16, b: v16i8, c: v16i8) -> v8i16 {
+    __lsx_vmaddwod_h_b(a, b, c)
+}
+
+/// Generates the instruction `vmaddwod.d.wu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwod.d.wu))]
+pub unsafe fn lsx_vmaddwod_d_wu(a: v2u64, b: v4u32, c: v4u32) -> v2u64 {
+    __lsx_vmaddwod_d_wu(a, b, c)
+}
+
+/// Generates the instruction `vmaddwod.w.hu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwod.w.hu))]
+pub unsafe fn lsx_vmaddwod_w_hu(a: v4u32, b: v8u16, c: v8u16) -> v4u32 {
+    __lsx_vmaddwod_w_hu(a, b, c)
+}
+
+/// Generates the instruction `vmaddwod.h.bu vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwod.h.bu))]
+pub unsafe fn lsx_vmaddwod_h_bu(a: v8u16, b: v16u8, c: v16u8) -> v8u16 {
+    __lsx_vmaddwod_h_bu(a, b, c)
+}
+
+/// Generates the instruction `vmaddwev.d.wu.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwev.d.wu.w))]
+pub unsafe fn lsx_vmaddwev_d_wu_w(a: v2i64, b: v4u32, c: v4i32) -> v2i64 {
+    __lsx_vmaddwev_d_wu_w(a, b, c)
+}
+
+/// Generates the instruction `vmaddwev.w.hu.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwev.w.hu.h))]
+pub unsafe fn lsx_vmaddwev_w_hu_h(a: v4i32, b: v8u16, c: v8i16) -> v4i32 {
+    __lsx_vmaddwev_w_hu_h(a, b, c)
+}
+
+/// Generates the instruction `vmaddwev.h.bu.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwev.h.bu.b))]
+pub unsafe fn lsx_vmaddwev_h_bu_b(a: v8i16, b: v16u8, c: v16i8) -> v8i16 {
+    __lsx_vmaddwev_h_bu_b(a, b, c)
+}
+
+/// Generates the instruction `vmaddwod.d.wu.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwod.d.wu.w))]
+pub unsafe fn lsx_vmaddwod_d_wu_w(a: v2i64, b: v4u32, c: v4i32) -> v2i64 {
+    __lsx_vmaddwod_d_wu_w(a, b, c)
+}
+
+/// Generates the instruction `vmaddwod.w.hu.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwod.w.hu.h))]
+pub unsafe fn lsx_vmaddwod_w_hu_h(a: v4i32, b: v8u16, c: v8i16) -> v4i32 {
+    __lsx_vmaddwod_w_hu_h(a, b, c)
+}
+
+/// Generates the instruction `vmaddwod.h.bu.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwod.h.bu.b))]
+pub unsafe fn lsx_vmaddwod_h_bu_b(a: v8i16, b: v16u8, c: v16i8) -> v8i16 {
+    __lsx_vmaddwod_h_bu_b(a, b, c)
+}
+
+/// Generates the instruction `vmaddwev.q.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwev.q.d))]
+pub unsafe fn lsx_vmaddwev_q_d(a: v2i64, b: v2i64, c: v2i64) -> v2i64 {
+    __lsx_vmaddwev_q_d(a, b, c)
+}
+
+/// Generates the instruction `vmaddwod.q.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwod.q.d))]
+pub unsafe fn lsx_vmaddwod_q_d(a: v2i64, b: v2i64, c: v2i64) -> v2i64 {
+    __lsx_vmaddwod_q_d(a, b, c)
+}
+
+/// Generates the instruction `vmaddwev.q.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwev.q.du))]
+pub unsafe fn lsx_vmaddwev_q_du(a: v2u64, b: v2u64, c: v2u64) -> v2u64 {
+    __lsx_vmaddwev_q_du(a, b, c)
+}
+
+/// Generates the instruction `vmaddwod.q.du vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwod.q.du))]
+pub unsafe fn lsx_vmaddwod_q_du(a: v2u64, b: v2u64, c: v2u64) -> v2u64 {
+    __lsx_vmaddwod_q_du(a, b, c)
+}
+
+/// Generates the instruction `vmaddwev.q.du.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwev.q.du.d))]
+pub unsafe fn lsx_vmaddwev_q_du_d(a: v2i64, b: v2u64, c: v2i64) -> v2i64 {
+    __lsx_vmaddwev_q_du_d(a, b, c)
+}
+
+/// Generates the instruction `vmaddwod.q.du.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmaddwod.q.du.d))]
+pub unsafe fn lsx_vmaddwod_q_du_d(a: v2i64, b: v2u64, c: v2i64) -> v2i64 {
+    __lsx_vmaddwod_q_du_d(a, b, c)
+}
+
+/// Generates the instruction `vrotr.b vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vrotr.b))]
+pub unsafe fn lsx_vrotr_b(a: v16i8, b: v16i8) -> v16i8 {
+    __lsx_vrotr_b(a, b)
+}
+
+/// Generates the instruction `vrotr.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vrotr.h))]
+pub unsafe fn lsx_vrotr_h(a: v8i16, b: v8i16) -> v8i16 {
+    __lsx_vrotr_h(a, b)
+}
+
+/// Generates the instruction `vrotr.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vrotr.w))]
+pub unsafe fn lsx_vrotr_w(a: v4i32, b: v4i32) -> v4i32 {
+    __lsx_vrotr_w(a, b)
+}
+
+/// Generates the instruction `vrotr.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vrotr.d))]
+pub unsafe fn lsx_vrotr_d(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vrotr_d(a, b)
+}
+
+/// Generates the instruction `vadd.q vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vadd.q))]
+pub unsafe fn lsx_vadd_q(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vadd_q(a, b)
+}
+
+/// Generates the instruction `vsub.q vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsub.q))]
+pub unsafe fn lsx_vsub_q(a: v2i64, b: v2i64) -> v2i64 {
+    __lsx_vsub_q(a, b)
+}
+
+/// Generates the instruction `vldrepl.b vd, rj, si12`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vldrepl.b, IMM_S12 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vldrepl_b<const IMM_S12: i32>(mem_addr: *const i8) -> v16i8 {
+    static_assert_simm_bits!(IMM_S12, 12);
+    __lsx_vldrepl_b(mem_addr, IMM_S12)
+}
+
+/// Generates the instruction `vldrepl.h vd, rj, si11`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vldrepl.h, IMM_S11 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vldrepl_h<const IMM_S11: i32>(mem_addr: *const i8) -> v8i16 {
+    static_assert_simm_bits!(IMM_S11, 11);
+    __lsx_vldrepl_h(mem_addr, IMM_S11)
+}
+
+/// Generates the instruction `vldrepl.w vd, rj, si10`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vldrepl.w, IMM_S10 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vldrepl_w<const IMM_S10: i32>(mem_addr: *const i8) -> v4i32 {
+    static_assert_simm_bits!(IMM_S10, 10);
+    __lsx_vldrepl_w(mem_addr, IMM_S10)
+}
+
+/// Generates the instruction `vldrepl.d vd, rj, si9`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vldrepl.d, IMM_S9 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vldrepl_d<const IMM_S9: i32>(mem_addr: *const i8) -> v2i64 {
+    static_assert_simm_bits!(IMM_S9, 9);
+    __lsx_vldrepl_d(mem_addr, IMM_S9)
+}
+
+/// Generates the instruction `vmskgez.b vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmskgez.b))]
+pub unsafe fn lsx_vmskgez_b(a: v16i8) -> v16i8 {
+    __lsx_vmskgez_b(a)
+}
+
+/// Generates the instruction `vmsknz.b vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vmsknz.b))]
+pub unsafe fn lsx_vmsknz_b(a: v16i8) -> v16i8 {
+    __lsx_vmsknz_b(a)
+}
+
+/// Generates the instruction `vexth.h.b vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vexth.h.b))]
+pub unsafe fn lsx_vexth_h_b(a: v16i8) -> v8i16 {
+    __lsx_vexth_h_b(a)
+}
+
+/// Generates the instruction `vexth.w.h vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vexth.w.h))]
+pub unsafe fn lsx_vexth_w_h(a: v8i16) -> v4i32 {
+    __lsx_vexth_w_h(a)
+}
+
+/// Generates the instruction `vexth.d.w vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vexth.d.w))]
+pub unsafe fn lsx_vexth_d_w(a: v4i32) -> v2i64 {
+    __lsx_vexth_d_w(a)
+}
+
+/// Generates the instruction `vexth.q.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vexth.q.d))]
+pub unsafe fn lsx_vexth_q_d(a: v2i64) -> v2i64 {
+    __lsx_vexth_q_d(a)
+}
+
+/// Generates the instruction `vexth.hu.bu vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vexth.hu.bu))]
+pub unsafe fn lsx_vexth_hu_bu(a: v16u8) -> v8u16 {
+    __lsx_vexth_hu_bu(a)
+}
+
+/// Generates the instruction `vexth.wu.hu vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vexth.wu.hu))]
+pub unsafe fn lsx_vexth_wu_hu(a: v8u16) -> v4u32 {
+    __lsx_vexth_wu_hu(a)
+}
+
+/// Generates the instruction `vexth.du.wu vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vexth.du.wu))]
+pub unsafe fn lsx_vexth_du_wu(a: v4u32) -> v2u64 {
+    __lsx_vexth_du_wu(a)
+}
+
+/// Generates the instruction `vexth.qu.du vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vexth.qu.du))]
+pub unsafe fn lsx_vexth_qu_du(a: v2u64) -> v2u64 {
+    __lsx_vexth_qu_du(a)
+}
+
+/// Generates the instruction `vrotri.b vd, vj, ui3`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vrotri.b, IMM3 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vrotri_b<const IMM3: u32>(a: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM3, 3);
+    __lsx_vrotri_b(a, IMM3)
+}
+
+/// Generates the instruction `vrotri.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vrotri.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vrotri_h<const IMM4: u32>(a: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vrotri_h(a, IMM4)
+}
+
+/// Generates the instruction `vrotri.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vrotri.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vrotri_w<const IMM5: u32>(a: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vrotri_w(a, IMM5)
+}
+
+/// Generates the instruction `vrotri.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vrotri.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vrotri_d<const IMM6: u32>(a: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vrotri_d(a, IMM6)
+}
+
+/// Generates the instruction `vextl.q.d vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vextl.q.d))]
+pub unsafe fn lsx_vextl_q_d(a: v2i64) -> v2i64 {
+    __lsx_vextl_q_d(a)
+}
+
+/// Generates the instruction `vsrlni.b.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrlni.b.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vsrlni_b_h<const IMM4: u32>(a: v16i8, b: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vsrlni_b_h(a, b, IMM4)
+}
+
+/// Generates the instruction `vsrlni.h.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrlni.h.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vsrlni_h_w<const IMM5: u32>(a: v8i16, b: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vsrlni_h_w(a, b, IMM5)
+}
+
+/// Generates the instruction `vsrlni.w.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrlni.w.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vsrlni_w_d<const IMM6: u32>(a: v4i32, b: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vsrlni_w_d(a, b, IMM6)
+}
+
+/// Generates the instruction `vsrlni.d.q vd, vj, ui7`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrlni.d.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vsrlni_d_q<const IMM7: u32>(a: v2i64, b: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lsx_vsrlni_d_q(a, b, IMM7)
+}
+
+/// Generates the instruction `vsrlrni.b.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrlrni.b.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vsrlrni_b_h<const IMM4: u32>(a: v16i8, b: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vsrlrni_b_h(a, b, IMM4)
+}
+
+/// Generates the instruction `vsrlrni.h.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrlrni.h.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vsrlrni_h_w<const IMM5: u32>(a: v8i16, b: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vsrlrni_h_w(a, b, IMM5)
+}
+
+/// Generates the instruction `vsrlrni.w.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrlrni.w.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vsrlrni_w_d<const IMM6: u32>(a: v4i32, b: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vsrlrni_w_d(a, b, IMM6)
+}
+
+/// Generates the instruction `vsrlrni.d.q vd, vj, ui7`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrlrni.d.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vsrlrni_d_q<const IMM7: u32>(a: v2i64, b: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lsx_vsrlrni_d_q(a, b, IMM7)
+}
+
+/// Generates the instruction `vssrlni.b.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlni.b.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrlni_b_h<const IMM4: u32>(a: v16i8, b: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vssrlni_b_h(a, b, IMM4)
+}
+
+/// Generates the instruction `vssrlni.h.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlni.h.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrlni_h_w<const IMM5: u32>(a: v8i16, b: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vssrlni_h_w(a, b, IMM5)
+}
+
+/// Generates the instruction `vssrlni.w.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlni.w.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrlni_w_d<const IMM6: u32>(a: v4i32, b: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vssrlni_w_d(a, b, IMM6)
+}
+
+/// Generates the instruction `vssrlni.d.q vd, vj, ui7`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlni.d.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrlni_d_q<const IMM7: u32>(a: v2i64, b: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lsx_vssrlni_d_q(a, b, IMM7)
+}
+
+/// Generates the instruction `vssrlni.bu.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlni.bu.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrlni_bu_h<const IMM4: u32>(a: v16u8, b: v16i8) -> v16u8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vssrlni_bu_h(a, b, IMM4)
+}
+
+/// Generates the instruction `vssrlni.hu.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlni.hu.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrlni_hu_w<const IMM5: u32>(a: v8u16, b: v8i16) -> v8u16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vssrlni_hu_w(a, b, IMM5)
+}
+
+/// Generates the instruction `vssrlni.wu.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlni.wu.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrlni_wu_d<const IMM6: u32>(a: v4u32, b: v4i32) -> v4u32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vssrlni_wu_d(a, b, IMM6)
+}
+
+/// Generates the instruction `vssrlni.du.q vd, vj, ui7`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlni.du.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrlni_du_q<const IMM7: u32>(a: v2u64, b: v2i64) -> v2u64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lsx_vssrlni_du_q(a, b, IMM7)
+}
+
+/// Generates the instruction `vssrlrni.b.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlrni.b.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrlrni_b_h<const IMM4: u32>(a: v16i8, b: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vssrlrni_b_h(a, b, IMM4)
+}
+
+/// Generates the instruction `vssrlrni.h.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlrni.h.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrlrni_h_w<const IMM5: u32>(a: v8i16, b: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vssrlrni_h_w(a, b, IMM5)
+}
+
+/// Generates the instruction `vssrlrni.w.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlrni.w.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrlrni_w_d<const IMM6: u32>(a: v4i32, b: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vssrlrni_w_d(a, b, IMM6)
+}
+
+/// Generates the instruction `vssrlrni.d.q vd, vj, ui7`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlrni.d.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrlrni_d_q<const IMM7: u32>(a: v2i64, b: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lsx_vssrlrni_d_q(a, b, IMM7)
+}
+
+/// Generates the instruction `vssrlrni.bu.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlrni.bu.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrlrni_bu_h<const IMM4: u32>(a: v16u8, b: v16i8) -> v16u8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vssrlrni_bu_h(a, b, IMM4)
+}
+
+/// Generates the instruction `vssrlrni.hu.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlrni.hu.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrlrni_hu_w<const IMM5: u32>(a: v8u16, b: v8i16) -> v8u16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vssrlrni_hu_w(a, b, IMM5)
+}
+
+/// Generates the instruction `vssrlrni.wu.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlrni.wu.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrlrni_wu_d<const IMM6: u32>(a: v4u32, b: v4i32) -> v4u32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vssrlrni_wu_d(a, b, IMM6)
+}
+
+/// Generates the instruction `vssrlrni.du.q vd, vj, ui7`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlrni.du.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrlrni_du_q<const IMM7: u32>(a: v2u64, b: v2i64) -> v2u64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lsx_vssrlrni_du_q(a, b, IMM7)
+}
+
+/// Generates the instruction `vsrani.b.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrani.b.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vsrani_b_h<const IMM4: u32>(a: v16i8, b: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vsrani_b_h(a, b, IMM4)
+}
+
+/// Generates the instruction `vsrani.h.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrani.h.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vsrani_h_w<const IMM5: u32>(a: v8i16, b: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vsrani_h_w(a, b, IMM5)
+}
+
+/// Generates the instruction `vsrani.w.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrani.w.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vsrani_w_d<const IMM6: u32>(a: v4i32, b: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vsrani_w_d(a, b, IMM6)
+}
+
+/// Generates the instruction `vsrani.d.q vd, vj, ui7`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrani.d.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vsrani_d_q<const IMM7: u32>(a: v2i64, b: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lsx_vsrani_d_q(a, b, IMM7)
+}
+
+/// Generates the instruction `vsrarni.b.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrarni.b.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vsrarni_b_h<const IMM4: u32>(a: v16i8, b: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vsrarni_b_h(a, b, IMM4)
+}
+
+/// Generates the instruction `vsrarni.h.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrarni.h.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vsrarni_h_w<const IMM5: u32>(a: v8i16, b: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vsrarni_h_w(a, b, IMM5)
+}
+
+/// Generates the instruction `vsrarni.w.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrarni.w.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vsrarni_w_d<const IMM6: u32>(a: v4i32, b: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vsrarni_w_d(a, b, IMM6)
+}
+
+/// Generates the instruction `vsrarni.d.q vd, vj, ui7`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsrarni.d.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vsrarni_d_q<const IMM7: u32>(a: v2i64, b: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lsx_vsrarni_d_q(a, b, IMM7)
+}
+
+/// Generates the instruction `vssrani.b.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrani.b.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrani_b_h<const IMM4: u32>(a: v16i8, b: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vssrani_b_h(a, b, IMM4)
+}
+
+/// Generates the instruction `vssrani.h.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrani.h.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrani_h_w<const IMM5: u32>(a: v8i16, b: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vssrani_h_w(a, b, IMM5)
+}
+
+/// Generates the instruction `vssrani.w.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrani.w.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrani_w_d<const IMM6: u32>(a: v4i32, b: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vssrani_w_d(a, b, IMM6)
+}
+
+/// Generates the instruction `vssrani.d.q vd, vj, ui7`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrani.d.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrani_d_q<const IMM7: u32>(a: v2i64, b: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lsx_vssrani_d_q(a, b, IMM7)
+}
+
+/// Generates the instruction `vssrani.bu.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrani.bu.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrani_bu_h<const IMM4: u32>(a: v16u8, b: v16i8) -> v16u8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vssrani_bu_h(a, b, IMM4)
+}
+
+/// Generates the instruction `vssrani.hu.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrani.hu.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrani_hu_w<const IMM5: u32>(a: v8u16, b: v8i16) -> v8u16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vssrani_hu_w(a, b, IMM5)
+}
+
+/// Generates the instruction `vssrani.wu.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrani.wu.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrani_wu_d<const IMM6: u32>(a: v4u32, b: v4i32) -> v4u32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vssrani_wu_d(a, b, IMM6)
+}
+
+/// Generates the instruction `vssrani.du.q vd, vj, ui7`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrani.du.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrani_du_q<const IMM7: u32>(a: v2u64, b: v2i64) -> v2u64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lsx_vssrani_du_q(a, b, IMM7)
+}
+
+/// Generates the instruction `vssrarni.b.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrarni.b.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrarni_b_h<const IMM4: u32>(a: v16i8, b: v16i8) -> v16i8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vssrarni_b_h(a, b, IMM4)
+}
+
+/// Generates the instruction `vssrarni.h.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrarni.h.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrarni_h_w<const IMM5: u32>(a: v8i16, b: v8i16) -> v8i16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vssrarni_h_w(a, b, IMM5)
+}
+
+/// Generates the instruction `vssrarni.w.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrarni.w.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrarni_w_d<const IMM6: u32>(a: v4i32, b: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vssrarni_w_d(a, b, IMM6)
+}
+
+/// Generates the instruction `vssrarni.d.q vd, vj, ui7`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrarni.d.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrarni_d_q<const IMM7: u32>(a: v2i64, b: v2i64) -> v2i64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lsx_vssrarni_d_q(a, b, IMM7)
+}
+
+/// Generates the instruction `vssrarni.bu.h vd, vj, ui4`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrarni.bu.h, IMM4 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrarni_bu_h<const IMM4: u32>(a: v16u8, b: v16i8) -> v16u8 {
+    static_assert_uimm_bits!(IMM4, 4);
+    __lsx_vssrarni_bu_h(a, b, IMM4)
+}
+
+/// Generates the instruction `vssrarni.hu.w vd, vj, ui5`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrarni.hu.w, IMM5 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrarni_hu_w<const IMM5: u32>(a: v8u16, b: v8i16) -> v8u16 {
+    static_assert_uimm_bits!(IMM5, 5);
+    __lsx_vssrarni_hu_w(a, b, IMM5)
+}
+
+/// Generates the instruction `vssrarni.wu.d vd, vj, ui6`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrarni.wu.d, IMM6 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrarni_wu_d<const IMM6: u32>(a: v4u32, b: v4i32) -> v4u32 {
+    static_assert_uimm_bits!(IMM6, 6);
+    __lsx_vssrarni_wu_d(a, b, IMM6)
+}
+
+/// Generates the instruction `vssrarni.du.q vd, vj, ui7`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrarni.du.q, IMM7 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vssrarni_du_q<const IMM7: u32>(a: v2u64, b: v2i64) -> v2u64 {
+    static_assert_uimm_bits!(IMM7, 7);
+    __lsx_vssrarni_du_q(a, b, IMM7)
+}
+
+/// Generates the instruction `vpermi.w vd, vj, ui8`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vpermi.w, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vpermi_w<const IMM8: u32>(a: v4i32, b: v4i32) -> v4i32 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lsx_vpermi_w(a, b, IMM8)
+}
+
+/// Generates the instruction `vld vd, rj, si12`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vld, IMM_S12 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lsx_vld<const IMM_S12: i32>(mem_addr: *const i8) -> v16i8 {
+    static_assert_simm_bits!(IMM_S12, 12);
+    __lsx_vld(mem_addr, IMM_S12)
+}
+
+/// Generates the instruction `vst vd, rj, si12`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vst, IMM_S12 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn lsx_vst<const IMM_S12: i32>(a: v16i8, mem_addr: *mut i8) {
+    static_assert_simm_bits!(IMM_S12, 12);
+    __lsx_vst(a, mem_addr, IMM_S12)
+}
+
+/// Generates the instruction `vssrlrn.b.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlrn.b.h))]
+pub unsafe fn lsx_vssrlrn_b_h(a: v8i16, b: v8i16) -> v16i8 {
+    __lsx_vssrlrn_b_h(a, b)
+}
+
+/// Generates the instruction `vssrlrn.h.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlrn.h.w))]
+pub unsafe fn lsx_vssrlrn_h_w(a: v4i32, b: v4i32) -> v8i16 {
+    __lsx_vssrlrn_h_w(a, b)
+}
+
+/// Generates the instruction `vssrlrn.w.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrlrn.w.d))]
+pub unsafe fn lsx_vssrlrn_w_d(a: v2i64, b: v2i64) -> v4i32 {
+    __lsx_vssrlrn_w_d(a, b)
+}
+
+/// Generates the instruction `vssrln.b.h vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrln.b.h))]
+pub unsafe fn lsx_vssrln_b_h(a: v8i16, b: v8i16) -> v16i8 {
+    __lsx_vssrln_b_h(a, b)
+}
+
+/// Generates the instruction `vssrln.h.w vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrln.h.w))]
+pub unsafe fn lsx_vssrln_h_w(a: v4i32, b: v4i32) -> v8i16 {
+    __lsx_vssrln_h_w(a, b)
+}
+
+/// Generates the instruction `vssrln.w.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vssrln.w.d))]
+pub unsafe fn lsx_vssrln_w_d(a: v2i64, b: v2i64) -> v4i32 {
+    __lsx_vssrln_w_d(a, b)
+}
+
+/// Generates the instruction `vorn.v vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vorn.v))]
+pub unsafe fn lsx_vorn_v(a: v16u8, b: v16u8) -> v16u8 {
+    __lsx_vorn_v(a, b)
+}
+
+/// Generates the instruction `vldi vd, i13`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vldi, IMM_S13 = 1))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn lsx_vldi<const IMM_S13: i32>() -> v2i64 {
+    static_assert_simm_bits!(IMM_S13, 13);
+    __lsx_vldi(IMM_S13)
+}
+
+/// Generates the instruction `vshuf.b vd, vj, vk, va`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vshuf.b))]
+pub unsafe fn lsx_vshuf_b(a: v16i8, b: v16i8, c: v16i8) -> v16i8 {
+    __lsx_vshuf_b(a, b, c)
+}
+
+/// Generates the instruction `vldx vd, rj, rk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vldx))]
+pub unsafe fn lsx_vldx(mem_addr: *const i8, a: i64) -> v16i8 {
+    __lsx_vldx(mem_addr, a)
+}
+
+/// Generates the instruction `vstx vd, rj, rk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vstx))]
+pub unsafe fn lsx_vstx(a: v16i8, mem_addr: *mut i8, b: i64) {
+    __lsx_vstx(a, mem_addr, b)
+}
+
+/// Generates the instruction `vextl.qu.du vd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vextl.qu.du))]
+pub unsafe fn lsx_vextl_qu_du(a: v2u64) -> v2u64 {
+    __lsx_vextl_qu_du(a)
+}
+
+/// Generates the instruction `vsetallnez.b cd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsetallnez.b))]
+pub unsafe fn lsx_bnz_b(a: v16u8) -> i32 {
+    __lsx_bnz_b(a)
+}
+
+/// Generates the instruction `vsetallnez.d cd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsetallnez.d))]
+pub unsafe fn lsx_bnz_d(a: v2u64) -> i32 {
+    __lsx_bnz_d(a)
+}
+
+/// Generates the instruction `vsetallnez.h cd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsetallnez.h))]
+pub unsafe fn lsx_bnz_h(a: v8u16) -> i32 {
+    __lsx_bnz_h(a)
+}
+
+/// Generates the instruction `vsetnez.v cd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsetnez.v))]
+pub unsafe fn lsx_bnz_v(a: v16u8) -> i32 {
+    __lsx_bnz_v(a)
+}
+
+/// Generates the instruction `vsetallnez.w cd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsetallnez.w))]
+pub unsafe fn lsx_bnz_w(a: v4u32) -> i32 {
+    __lsx_bnz_w(a)
+}
+
+/// Generates the instruction `vsetanyeqz.b cd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsetanyeqz.b))]
+pub unsafe fn lsx_bz_b(a: v16u8) -> i32 {
+    __lsx_bz_b(a)
+}
+
+/// Generates the instruction `vsetanyeqz.d cd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsetanyeqz.d))]
+pub unsafe fn lsx_bz_d(a: v2u64) -> i32 {
+    __lsx_bz_d(a)
+}
+
+/// Generates the instruction `vsetanyeqz.h cd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsetanyeqz.h))]
+pub unsafe fn lsx_bz_h(a: v8u16) -> i32 {
+    __lsx_bz_h(a)
+}
+
+/// Generates the instruction `vseteqz.v cd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vseteqz.v))]
+pub unsafe fn lsx_bz_v(a: v16u8) -> i32 {
+    __lsx_bz_v(a)
+}
+
+/// Generates the instruction `vsetanyeqz.w cd, vj`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vsetanyeqz.w))]
+pub unsafe fn lsx_bz_w(a: v4u32) -> i32 {
+    __lsx_bz_w(a)
+}
+
+/// Generates the instruction `vfcmp.caf.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.caf.d))]
+pub unsafe fn lsx_vfcmp_caf_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_caf_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.caf.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.caf.s))]
+pub unsafe fn lsx_vfcmp_caf_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_caf_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.ceq.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.ceq.d))]
+pub unsafe fn lsx_vfcmp_ceq_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_ceq_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.ceq.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.ceq.s))]
+pub unsafe fn lsx_vfcmp_ceq_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_ceq_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.cle.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.cle.d))]
+pub unsafe fn lsx_vfcmp_cle_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_cle_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.cle.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.cle.s))]
+pub unsafe fn lsx_vfcmp_cle_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_cle_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.clt.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.clt.d))]
+pub unsafe fn lsx_vfcmp_clt_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_clt_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.clt.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.clt.s))]
+pub unsafe fn lsx_vfcmp_clt_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_clt_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.cne.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.cne.d))]
+pub unsafe fn lsx_vfcmp_cne_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_cne_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.cne.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.cne.s))]
+pub unsafe fn lsx_vfcmp_cne_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_cne_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.cor.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.cor.d))]
+pub unsafe fn lsx_vfcmp_cor_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_cor_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.cor.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.cor.s))]
+pub unsafe fn lsx_vfcmp_cor_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_cor_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.cueq.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.cueq.d))]
+pub unsafe fn lsx_vfcmp_cueq_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_cueq_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.cueq.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.cueq.s))]
+pub unsafe fn lsx_vfcmp_cueq_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_cueq_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.cule.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.cule.d))]
+pub unsafe fn lsx_vfcmp_cule_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_cule_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.cule.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.cule.s))]
+pub unsafe fn lsx_vfcmp_cule_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_cule_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.cult.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.cult.d))]
+pub unsafe fn lsx_vfcmp_cult_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_cult_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.cult.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.cult.s))]
+pub unsafe fn lsx_vfcmp_cult_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_cult_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.cun.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.cun.d))]
+pub unsafe fn lsx_vfcmp_cun_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_cun_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.cune.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.cune.d))]
+pub unsafe fn lsx_vfcmp_cune_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_cune_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.cune.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.cune.s))]
+pub unsafe fn lsx_vfcmp_cune_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_cune_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.cun.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.cun.s))]
+pub unsafe fn lsx_vfcmp_cun_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_cun_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.saf.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.saf.d))]
+pub unsafe fn lsx_vfcmp_saf_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_saf_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.saf.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.saf.s))]
+pub unsafe fn lsx_vfcmp_saf_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_saf_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.seq.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.seq.d))]
+pub unsafe fn lsx_vfcmp_seq_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_seq_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.seq.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.seq.s))]
+pub unsafe fn lsx_vfcmp_seq_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_seq_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.sle.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.sle.d))]
+pub unsafe fn lsx_vfcmp_sle_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_sle_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.sle.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.sle.s))]
+pub unsafe fn lsx_vfcmp_sle_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_sle_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.slt.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.slt.d))]
+pub unsafe fn lsx_vfcmp_slt_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_slt_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.slt.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.slt.s))]
+pub unsafe fn lsx_vfcmp_slt_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_slt_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.sne.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.sne.d))]
+pub unsafe fn lsx_vfcmp_sne_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_sne_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.sne.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.sne.s))]
+pub unsafe fn lsx_vfcmp_sne_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_sne_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.sor.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.sor.d))]
+pub unsafe fn lsx_vfcmp_sor_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_sor_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.sor.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.sor.s))]
+pub unsafe fn lsx_vfcmp_sor_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_sor_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.sueq.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.sueq.d))]
+pub unsafe fn lsx_vfcmp_sueq_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_sueq_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.sueq.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.sueq.s))]
+pub unsafe fn lsx_vfcmp_sueq_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_sueq_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.sule.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.sule.d))]
+pub unsafe fn lsx_vfcmp_sule_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_sule_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.sule.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.sule.s))]
+pub unsafe fn lsx_vfcmp_sule_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_sule_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.sult.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.sult.d))]
+pub unsafe fn lsx_vfcmp_sult_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_sult_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.sult.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.sult.s))]
+pub unsafe fn lsx_vfcmp_sult_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_sult_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.sun.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.sun.d))]
+pub unsafe fn lsx_vfcmp_sun_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_sun_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.sune.d vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.sune.d))]
+pub unsafe fn lsx_vfcmp_sune_d(a: v2f64, b: v2f64) -> v2i64 {
+    __lsx_vfcmp_sune_d(a, b)
+}
+
+/// Generates the instruction `vfcmp.sune.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.sune.s))]
+pub unsafe fn lsx_vfcmp_sune_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_sune_s(a, b)
+}
+
+/// Generates the instruction `vfcmp.sun.s vd, vj, vk`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vfcmp.sun.s))]
+pub unsafe fn lsx_vfcmp_sun_s(a: v4f32, b: v4f32) -> v4i32 {
+    __lsx_vfcmp_sun_s(a, b)
+}
+
+/// Generates the instruction `vrepli.b vd, si10`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vrepli.b, IMM_S10 = 1))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn lsx_vrepli_b<const IMM_S10: i32>() -> v16i8 {
+    static_assert_simm_bits!(IMM_S10, 10);
+    __lsx_vrepli_b(IMM_S10)
+}
+
+/// Generates the instruction `vrepli.d vd, si10`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vrepli.d, IMM_S10 = 1))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn lsx_vrepli_d<const IMM_S10: i32>() -> v2i64 {
+    static_assert_simm_bits!(IMM_S10, 10);
+    __lsx_vrepli_d(IMM_S10)
+}
+
+/// Generates the instruction `vrepli.h vd, si10`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vrepli.h, IMM_S10 = 1))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn lsx_vrepli_h<const IMM_S10: i32>() -> v8i16 {
+    static_assert_simm_bits!(IMM_S10, 10);
+    __lsx_vrepli_h(IMM_S10)
+}
+
+/// Generates the instruction `vrepli.w vd, si10`.
+#[inline]
+#[target_feature(enable = "lsx")]
+#[cfg_attr(test, assert_instr(vrepli.w, IMM_S10 = 1))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn lsx_vrepli_w<const IMM_S10: i32>() -> v4i32 {
+    static_assert_simm_bits!(IMM_S10, 10);
+    __lsx_vrepli_w(IMM_S10)
+}
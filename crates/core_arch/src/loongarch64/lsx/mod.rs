@@ -0,0 +1,16 @@
+//! LoongArch64 LSX intrinsics
+//!
+//! The 128-bit vector intrinsics of `lsxintrin.h`. The bindings are generated
+//! from `crates/stdarch-gen-loongarch/lsx.spec`.
+
+#![allow(non_camel_case_types)]
+
+mod types;
+pub use self::types::*;
+
+#[rustfmt::skip]
+mod generated;
+pub use self::generated::*;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,1741 @@
+use crate::{
+    core_arch::{loongarch64::*, simd::*},
+    mem::transmute,
+};
+use stdarch_test::simd_test;
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vadd_b() {
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        0, 1, 2, 3, 4, 5, 6, 7,
+        8, 9, 10, 11, 12, 13, 14, 15,
+    );
+    #[rustfmt::skip]
+    let b = i8x16::new(
+        15, 14, 13, 12, 11, 10, 9, 8,
+        7, 6, 5, 4, 3, 2, 1, 0,
+    );
+    let r = i8x16::splat(15);
+    assert_eq!(r, transmute(lsx_vadd_b(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vmul_h() {
+    let a = i16x8::new(1, -2, 3, -4, 5, -6, 7, -8);
+    let b = i16x8::splat(-3);
+    let r = i16x8::new(-3, 6, -9, 12, -15, 18, -21, 24);
+    assert_eq!(r, transmute(lsx_vmul_h(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vslli_w() {
+    let a = i32x4::new(1, 2, 3, 4);
+    let r = i32x4::new(8, 16, 24, 32);
+    assert_eq!(r, transmute(lsx_vslli_w::<3>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vseqi_b() {
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        -1, 0, -1, 1, -1, 2, -1, 3,
+        -1, 4, -1, 5, -1, 6, -1, 7,
+    );
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        -1, 0, -1, 0, -1, 0, -1, 0,
+        -1, 0, -1, 0, -1, 0, -1, 0,
+    );
+    assert_eq!(r, transmute(lsx_vseqi_b::<-1>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfadd_s() {
+    let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+    let b = f32x4::new(0.5, -0.5, 1.5, -1.5);
+    let r = f32x4::new(1.5, 1.5, 4.5, 2.5);
+    assert_eq!(r, transmute(lsx_vfadd_s(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vreplgr2vr_d() {
+    let r = i64x2::splat(-5);
+    assert_eq!(r, transmute(lsx_vreplgr2vr_d(-5)));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vrepli_h() {
+    let r = i16x8::splat(-3);
+    assert_eq!(r, transmute(lsx_vrepli_h::<-3>()));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vpickve2gr_w() {
+    let a = i32x4::new(10, 20, 30, 40);
+    assert_eq!(lsx_vpickve2gr_w::<2>(transmute(a)), 30);
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_bz_v() {
+    assert_eq!(lsx_bz_v(transmute(u8x16::splat(0))), 1);
+    assert_eq!(lsx_bz_v(transmute(u64x2::new(0, 1 << 63))), 0);
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vld() {
+    let mut a = [0i8; 32];
+    for (i, x) in a.iter_mut().enumerate() {
+        *x = i as i8;
+    }
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        16, 17, 18, 19, 20, 21, 22, 23,
+        24, 25, 26, 27, 28, 29, 30, 31,
+    );
+    assert_eq!(r, transmute(lsx_vld::<16>(a.as_ptr())));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vst() {
+    let a = i32x4::new(1, 2, 3, 4);
+    let mut r = [0i32; 6];
+    lsx_vst::<8>(transmute(a), r.as_mut_ptr() as *mut i8);
+    assert_eq!(r, [0, 0, 1, 2, 3, 4]);
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vstelm_w() {
+    let a = i32x4::new(1, 2, 3, 4);
+    let mut r = [0i32; 3];
+    lsx_vstelm_w::<4, 3>(transmute(a), r.as_mut_ptr() as *mut i8);
+    assert_eq!(r, [0, 4, 0]);
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vadd_h() {
+    let a = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let b = i16x8::new(7, -1, -2, 500, 4000, 1, -1, -1234);
+    let r = i16x8::new(7, 0, -4, 800, 0, i16::MIN, i16::MAX, 0);
+    assert_eq!(r, transmute(lsx_vadd_h(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsub_w() {
+    let a = i32x4::new(1, -2, i32::MAX, -100000);
+    let b = i32x4::new(3, 5, 1, 70000);
+    let r = i32x4::new(-2, -7, 2147483646, -170000);
+    assert_eq!(r, transmute(lsx_vsub_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vaddi_bu() {
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        0, 1, -2, 3, -4, 5, i8::MAX, i8::MIN,
+        100, -100, 64, -64, 16, -17, 99, -1,
+    );
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        9, 10, 7, 12, 5, 14, -120, -119,
+        109, -91, 73, -55, 25, -8, 108, 8,
+    );
+    assert_eq!(r, transmute(lsx_vaddi_bu::<9>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsubi_hu() {
+    let a = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let r = i16x8::new(-31, -30, -33, 269, -4031, 32736, 32737, 1203);
+    assert_eq!(r, transmute(lsx_vsubi_hu::<31>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vneg_d() {
+    let a = i64x2::new(5, i64::MIN);
+    let r = i64x2::new(-5, i64::MIN);
+    assert_eq!(r, transmute(lsx_vneg_d(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsadd_b() {
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        0, 1, -2, 3, -4, 5, i8::MAX, i8::MIN,
+        100, -100, 64, -64, 16, -17, 99, -1,
+    );
+    #[rustfmt::skip]
+    let b = i8x16::new(
+        5, -1, 2, -3, 4, 5, 1, -1,
+        100, -100, -65, 64, 3, 17, -98, 1,
+    );
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        5, 0, 0, 0, 0, 10, i8::MAX, i8::MIN,
+        i8::MAX, i8::MIN, -1, 0, 19, 0, 1, 0,
+    );
+    assert_eq!(r, transmute(lsx_vsadd_b(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsadd_bu() {
+    #[rustfmt::skip]
+    let a = u8x16::new(
+        0, 1, 254, 3, 252, 5, 127, 128,
+        100, 156, 64, 192, 16, 239, 99, u8::MAX,
+    );
+    #[rustfmt::skip]
+    let b = u8x16::new(
+        5, u8::MAX, 2, 253, 4, 5, 1, u8::MAX,
+        100, 156, 191, 64, 3, 17, 158, 1,
+    );
+    #[rustfmt::skip]
+    let r = u8x16::new(
+        5, u8::MAX, u8::MAX, u8::MAX, u8::MAX, 10, 128, u8::MAX,
+        200, u8::MAX, u8::MAX, u8::MAX, 19, u8::MAX, u8::MAX, u8::MAX,
+    );
+    assert_eq!(r, transmute(lsx_vsadd_bu(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vssub_h() {
+    let a = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let b = i16x8::new(7, -1, -2, 500, 4000, 1, -1, -1234);
+    let r = i16x8::new(-7, 2, 0, -200, -8000, 32766, -32767, 2468);
+    assert_eq!(r, transmute(lsx_vssub_h(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vssub_wu() {
+    let a = u32x4::new(10, 0, u32::MAX, 7);
+    let b = u32x4::new(3, 1, 1, 7);
+    let r = u32x4::new(7, 0, 4294967294, 0);
+    assert_eq!(r, transmute(lsx_vssub_wu(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vhaddw_h_b() {
+    // Adds the odd elements of `a` and the even elements of `b`.
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        11, 15, 107, 67, -53, 119, 43, -85,
+        -117, -33, -21, 19, 75, 71, -85, 123,
+    );
+    #[rustfmt::skip]
+    let b = i8x16::new(
+        0, -46, -78, -96, -100, -90, -66, -28,
+        24, 90, -86, 8, 116, -18, 118, 12,
+    );
+    let r = i16x8::new(15, -11, 19, -151, -9, -67, 187, 241);
+    assert_eq!(r, transmute(lsx_vhaddw_h_b(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vhaddw_du_wu() {
+    // Adds the odd elements of `a` and the even elements of `b`.
+    let a = u32x4::new(11, 15, 107, 67);
+    let b = u32x4::new(0, 4294967250, 4294967218, 4294967200);
+    let r = u64x2::new(15, 4294967285);
+    assert_eq!(r, transmute(lsx_vhaddw_du_wu(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vhsubw_w_h() {
+    // Subtracts the odd elements of `a` and the even elements of `b`.
+    let a = i16x8::new(11, 15, 107, 67, 203, 119, 299, 171);
+    let b = i16x8::new(0, -46, -78, -96, -100, -90, -66, -28);
+    let r = i32x4::new(15, 145, 219, 237);
+    assert_eq!(r, transmute(lsx_vhsubw_w_h(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vaddwev_h_b() {
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        3, -34, -45, -30, 11, 78, -85, 34,
+        -77, 94, 35, 2, -5, 14, 59, -126,
+    );
+    #[rustfmt::skip]
+    let b = i8x16::new(
+        100, 78, 98, -54, -80, 62, -98, -6,
+        124, 78, -102, -118, 72, -2, -42, -6,
+    );
+    let r = i16x8::new(103, 53, -69, -183, 47, -67, 67, 17);
+    assert_eq!(r, transmute(lsx_vaddwev_h_b(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vaddwod_w_hu() {
+    let a = u16x8::new(3, 65502, 65491, 65506, 11, 78, 171, 290);
+    let b = u16x8::new(100, 78, 98, 202, 432, 830, 1438, 2298);
+    let r = i32x4::new(65580, 65708, 908, 2588);
+    assert_eq!(r, transmute(lsx_vaddwod_w_hu(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsubwev_d_w() {
+    let a = i32x4::new(3, -34, -45, -30);
+    let b = i32x4::new(100, 78, 98, 202);
+    let r = i64x2::new(-97, -143);
+    assert_eq!(r, transmute(lsx_vsubwev_d_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vmulwev_h_b() {
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        3, -34, -45, -30, 11, 78, -85, 34,
+        -77, 94, 35, 2, -5, 14, 59, -126,
+    );
+    #[rustfmt::skip]
+    let b = i8x16::new(
+        100, 78, 98, -54, -80, 62, -98, -6,
+        124, 78, -102, -118, 72, -2, -42, -6,
+    );
+    let r = i16x8::new(300, -4410, -880, 8330, -9548, -3570, -360, -2478);
+    assert_eq!(r, transmute(lsx_vmulwev_h_b(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vmulwod_d_wu() {
+    let a = u32x4::new(3, 4294967262, 4294967251, 4294967266);
+    let b = u32x4::new(100, 78, 98, 202);
+    let r = i64x2::new(335007446436, 867583387732);
+    assert_eq!(r, transmute(lsx_vmulwod_d_wu(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vmulwev_w_hu_h() {
+    let a = u16x8::new(3, 65502, 65491, 65506, 11, 78, 171, 290);
+    let b = i16x8::new(100, 78, 98, 202, 432, 830, 1438, 2298);
+    let r = i32x4::new(300, 6418118, 4752, 245898);
+    assert_eq!(r, transmute(lsx_vmulwev_w_hu_h(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vavg_b() {
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        0, 1, -2, 3, -4, 5, i8::MAX, i8::MIN,
+        100, -100, 64, -64, 16, -17, 99, -1,
+    );
+    #[rustfmt::skip]
+    let b = i8x16::new(
+        5, -1, 2, -3, 4, 5, 1, -1,
+        100, -100, -65, 64, 3, 17, -98, 1,
+    );
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        2, 0, 0, 0, 0, 5, 64, -65,
+        100, -100, -1, 0, 9, 0, 0, 0,
+    );
+    assert_eq!(r, transmute(lsx_vavg_b(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vavgr_hu() {
+    let a = u16x8::new(0, 1, 65534, 300, 61536, 32767, 32768, 1234);
+    let b = u16x8::new(7, u16::MAX, 65534, 500, 4000, 1, u16::MAX, 64302);
+    let r = u16x8::new(4, 32768, 65534, 400, 32768, 16384, 49152, 32768);
+    assert_eq!(r, transmute(lsx_vavgr_hu(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vabsd_w() {
+    let a = i32x4::new(1, -2, i32::MAX, -100000);
+    let b = i32x4::new(3, 5, 1, 70000);
+    let r = i32x4::new(2, 7, 2147483646, 170000);
+    assert_eq!(r, transmute(lsx_vabsd_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vadda_h() {
+    let a = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let b = i16x8::new(7, -1, -2, 500, 4000, 1, -1, -1234);
+    let r = i16x8::new(7, 2, 4, 800, 8000, i16::MIN, -32767, 2468);
+    assert_eq!(r, transmute(lsx_vadda_h(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vmax_w() {
+    let a = i32x4::new(1, -2, i32::MAX, -100000);
+    let b = i32x4::new(3, 5, 1, 70000);
+    let r = i32x4::new(3, 5, i32::MAX, 70000);
+    assert_eq!(r, transmute(lsx_vmax_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vmin_bu() {
+    #[rustfmt::skip]
+    let a = u8x16::new(
+        0, 1, 254, 3, 252, 5, 127, 128,
+        100, 156, 64, 192, 16, 239, 99, u8::MAX,
+    );
+    #[rustfmt::skip]
+    let b = u8x16::new(
+        5, u8::MAX, 2, 253, 4, 5, 1, u8::MAX,
+        100, 156, 191, 64, 3, 17, 158, 1,
+    );
+    #[rustfmt::skip]
+    let r = u8x16::new(
+        0, 1, 2, 3, 4, 5, 1, 128,
+        100, 156, 64, 64, 3, 17, 99, 1,
+    );
+    assert_eq!(r, transmute(lsx_vmin_bu(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vmaxi_h() {
+    let a = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let r = i16x8::new(0, 1, -2, 300, -3, i16::MAX, -3, 1234);
+    assert_eq!(r, transmute(lsx_vmaxi_h::<-3>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vmini_bu() {
+    #[rustfmt::skip]
+    let a = u8x16::new(
+        0, 1, 254, 3, 252, 5, 127, 128,
+        100, 156, 64, 192, 16, 239, 99, u8::MAX,
+    );
+    #[rustfmt::skip]
+    let r = u8x16::new(
+        0, 1, 17, 3, 17, 5, 17, 17,
+        17, 17, 17, 17, 16, 17, 17, 17,
+    );
+    assert_eq!(r, transmute(lsx_vmini_bu::<17>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vmul_w() {
+    let a = i32x4::new(1, -2, i32::MAX, -100000);
+    let b = i32x4::new(3, 5, 1, 70000);
+    let r = i32x4::new(3, -10, i32::MAX, 1589934592);
+    assert_eq!(r, transmute(lsx_vmul_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vmuh_h() {
+    let a = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let b = i16x8::new(7, -1, -2, 500, 4000, 1, -1, -1234);
+    let r = i16x8::new(0, -1, 0, 2, -245, 0, 0, -24);
+    assert_eq!(r, transmute(lsx_vmuh_h(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vmuh_bu() {
+    #[rustfmt::skip]
+    let a = u8x16::new(
+        0, 1, 254, 3, 252, 5, 127, 128,
+        100, 156, 64, 192, 16, 239, 99, u8::MAX,
+    );
+    #[rustfmt::skip]
+    let b = u8x16::new(
+        5, u8::MAX, 2, 253, 4, 5, 1, u8::MAX,
+        100, 156, 191, 64, 3, 17, 158, 1,
+    );
+    #[rustfmt::skip]
+    let r = u8x16::new(
+        0, 0, 1, 2, 3, 0, 0, 127,
+        39, 95, 47, 48, 0, 15, 61, 0,
+    );
+    assert_eq!(r, transmute(lsx_vmuh_bu(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vmadd_w() {
+    let a = i32x4::new(10, 20, 30, 40);
+    let b = i32x4::new(1, -2, i32::MAX, -100000);
+    let c = i32x4::new(3, 5, 1, 70000);
+    let r = i32x4::new(13, 10, -2147483619, 1589934632);
+    assert_eq!(
+        r,
+        transmute(lsx_vmadd_w(transmute(a), transmute(b), transmute(c)))
+    );
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vmsub_h() {
+    let a = i16x8::new(1, 2, 3, 4, 5, 6, 7, 8);
+    let b = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let c = i16x8::new(7, -1, -2, 500, 4000, 1, -1, -1234);
+    let r = i16x8::new(1, 3, -1, -18924, 9221, -32761, -32761, 15436);
+    assert_eq!(
+        r,
+        transmute(lsx_vmsub_h(transmute(a), transmute(b), transmute(c)))
+    );
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vmaddwev_h_b() {
+    let a = i16x8::new(-3000, -2000, -1000, 0, 1000, 2000, 3000, 4000);
+    #[rustfmt::skip]
+    let b = i8x16::new(
+        -40, -23, -6, 11, 28, 45, 62, 79,
+        96, 113, -126, -109, -92, -75, -58, -41,
+    );
+    #[rustfmt::skip]
+    let c = i8x16::new(
+        90, 67, 44, 21, -2, -25, -48, -71,
+        -94, -117, 116, 93, 70, 47, 24, 1,
+    );
+    let r = i16x8::new(-6600, -2264, -1056, -2976, -8024, -12616, -3440, 2608);
+    assert_eq!(
+        r,
+        transmute(lsx_vmaddwev_h_b(transmute(a), transmute(b), transmute(c)))
+    );
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vmaddwod_w_hu() {
+    let a = u32x4::new(4294964296, 4294965296, 4294966296, 0);
+    let b = u16x8::new(65496, 65513, 65530, 11, 28, 45, 62, 79);
+    let c = u16x8::new(90, 67, 44, 21, 65534, 65511, 65488, 65465);
+    let r = u32x4::new(4386371, 4294965527, 2946995, 5171735);
+    assert_eq!(
+        r,
+        transmute(lsx_vmaddwod_w_hu(transmute(a), transmute(b), transmute(c)))
+    );
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vdiv_w() {
+    let a = i32x4::new(100, -100, 7, i32::MIN);
+    let b = i32x4::new(7, 7, -2, 1);
+    let r = i32x4::new(14, -14, -3, i32::MIN);
+    assert_eq!(r, transmute(lsx_vdiv_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vmod_h() {
+    let a = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let b = i16x8::new(7, -1, -2, 500, 4000, 1, -1, -1234);
+    let r = i16x8::new(0, 0, 0, 300, 0, 0, 0, 0);
+    assert_eq!(r, transmute(lsx_vmod_h(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vdiv_hu() {
+    let a = u16x8::new(0, 1, 65534, 300, 61536, 32767, 32768, 1234);
+    let b = u16x8::new(7, u16::MAX, 65534, 500, 4000, 1, u16::MAX, 64302);
+    let r = u16x8::new(0, 0, 1, 0, 15, 32767, 0, 0);
+    assert_eq!(r, transmute(lsx_vdiv_hu(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vmod_du() {
+    let a = u64x2::new(1000, u64::MAX);
+    let b = u64x2::new(7, 4294967296);
+    let r = u64x2::new(6, 4294967295);
+    assert_eq!(r, transmute(lsx_vmod_du(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsat_b() {
+    // Saturates to the range of a 4-bit signed integer.
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        0, 1, -2, 3, -4, 5, i8::MAX, i8::MIN,
+        100, -100, 64, -64, 16, -17, 99, -1,
+    );
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        0, 1, -2, 3, -4, 5, 7, -8,
+        7, -8, 7, -8, 7, -8, 7, -1,
+    );
+    assert_eq!(r, transmute(lsx_vsat_b::<3>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsat_hu() {
+    let a = u16x8::new(0, 1, 65534, 300, 61536, 32767, 32768, 1234);
+    let r = u16x8::new(0, 1, 63, 63, 63, 63, 63, 63);
+    assert_eq!(r, transmute(lsx_vsat_hu::<5>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsigncov_b() {
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        0, 1, -2, 3, -4, 5, i8::MAX, i8::MIN,
+        100, -100, 64, -64, 16, -17, 99, -1,
+    );
+    #[rustfmt::skip]
+    let b = i8x16::new(
+        5, -1, 2, -3, 4, 5, 1, -1,
+        100, -100, -65, 64, 3, 17, -98, 1,
+    );
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        0, -1, -2, -3, -4, 5, 1, 1,
+        100, 100, -65, -64, 3, -17, -98, -1,
+    );
+    assert_eq!(r, transmute(lsx_vsigncov_b(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vexth_w_h() {
+    let a = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let r = i32x4::new(-4000, 32767, -32768, 1234);
+    assert_eq!(r, transmute(lsx_vexth_w_h(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vexth_du_wu() {
+    let a = u32x4::new(1, 4294967294, 2147483647, 4294867296);
+    let r = u64x2::new(2147483647, 4294867296);
+    assert_eq!(r, transmute(lsx_vexth_du_wu(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsllwil_h_b() {
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        0, 1, -2, 3, -4, 5, i8::MAX, i8::MIN,
+        100, -100, 64, -64, 16, -17, 99, -1,
+    );
+    let r = i16x8::new(0, 4, -8, 12, -16, 20, 508, -512);
+    assert_eq!(r, transmute(lsx_vsllwil_h_b::<2>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsllwil_wu_hu() {
+    let a = u16x8::new(0, 1, 65534, 300, 61536, 32767, 32768, 1234);
+    let r = u32x4::new(0, 8, 524272, 2400);
+    assert_eq!(r, transmute(lsx_vsllwil_wu_hu::<3>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vseq_h() {
+    let a = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let b = i16x8::new(0, 2, -2, 300, 0, i16::MAX, 0, 1234);
+    let r = i16x8::new(-1, 0, -1, -1, 0, -1, 0, -1);
+    assert_eq!(r, transmute(lsx_vseq_h(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vslt_w() {
+    let a = i32x4::new(1, -2, i32::MAX, -100000);
+    let b = i32x4::new(3, 5, 1, 70000);
+    let r = i32x4::new(-1, -1, 0, -1);
+    assert_eq!(r, transmute(lsx_vslt_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsle_bu() {
+    #[rustfmt::skip]
+    let a = u8x16::new(
+        0, 1, 254, 3, 252, 5, 127, 128,
+        100, 156, 64, 192, 16, 239, 99, u8::MAX,
+    );
+    #[rustfmt::skip]
+    let b = u8x16::new(
+        5, u8::MAX, 2, 253, 4, 5, 1, u8::MAX,
+        100, 156, 191, 64, 3, 17, 158, 1,
+    );
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        -1, -1, 0, -1, 0, -1, 0, -1,
+        -1, -1, -1, 0, 0, 0, -1, 0,
+    );
+    assert_eq!(r, transmute(lsx_vsle_bu(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vslti_h() {
+    let a = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let r = i16x8::new(0, 0, 0, 0, -1, 0, -1, 0);
+    assert_eq!(r, transmute(lsx_vslti_h::<-2>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vslei_wu() {
+    let a = u32x4::new(0, 5, 6, u32::MAX);
+    let r = i32x4::new(-1, -1, 0, 0);
+    assert_eq!(r, transmute(lsx_vslei_wu::<5>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vand_v() {
+    #[rustfmt::skip]
+    let a = u8x16::new(
+        15, 240, 85, 170, 0, u8::MAX, 18, 52,
+        86, 120, 154, 188, 222, 240, 1, 128,
+    );
+    #[rustfmt::skip]
+    let b = u8x16::new(
+        u8::MAX, 15, 170, 170, 1, 16, 33, 67,
+        101, 135, 169, 203, 237, 15, 16, 8,
+    );
+    #[rustfmt::skip]
+    let r = u8x16::new(
+        15, 0, 0, 170, 0, 16, 0, 0,
+        68, 0, 136, 136, 204, 0, 0, 0,
+    );
+    assert_eq!(r, transmute(lsx_vand_v(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vor_v() {
+    #[rustfmt::skip]
+    let a = u8x16::new(
+        15, 240, 85, 170, 0, u8::MAX, 18, 52,
+        86, 120, 154, 188, 222, 240, 1, 128,
+    );
+    #[rustfmt::skip]
+    let b = u8x16::new(
+        u8::MAX, 15, 170, 170, 1, 16, 33, 67,
+        101, 135, 169, 203, 237, 15, 16, 8,
+    );
+    #[rustfmt::skip]
+    let r = u8x16::new(
+        u8::MAX, u8::MAX, u8::MAX, 170, 1, u8::MAX, 51, 119,
+        119, u8::MAX, 187, u8::MAX, u8::MAX, u8::MAX, 17, 136,
+    );
+    assert_eq!(r, transmute(lsx_vor_v(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vxor_v() {
+    #[rustfmt::skip]
+    let a = u8x16::new(
+        15, 240, 85, 170, 0, u8::MAX, 18, 52,
+        86, 120, 154, 188, 222, 240, 1, 128,
+    );
+    #[rustfmt::skip]
+    let b = u8x16::new(
+        u8::MAX, 15, 170, 170, 1, 16, 33, 67,
+        101, 135, 169, 203, 237, 15, 16, 8,
+    );
+    #[rustfmt::skip]
+    let r = u8x16::new(
+        240, u8::MAX, u8::MAX, 0, 1, 239, 51, 119,
+        51, u8::MAX, 51, 119, 51, u8::MAX, 17, 136,
+    );
+    assert_eq!(r, transmute(lsx_vxor_v(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vnor_v() {
+    #[rustfmt::skip]
+    let a = u8x16::new(
+        15, 240, 85, 170, 0, u8::MAX, 18, 52,
+        86, 120, 154, 188, 222, 240, 1, 128,
+    );
+    #[rustfmt::skip]
+    let b = u8x16::new(
+        u8::MAX, 15, 170, 170, 1, 16, 33, 67,
+        101, 135, 169, 203, 237, 15, 16, 8,
+    );
+    #[rustfmt::skip]
+    let r = u8x16::new(
+        0, 0, 0, 85, 254, 0, 204, 136,
+        136, 0, 68, 0, 0, 0, 238, 119,
+    );
+    assert_eq!(r, transmute(lsx_vnor_v(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vandn_v() {
+    #[rustfmt::skip]
+    let a = u8x16::new(
+        15, 240, 85, 170, 0, u8::MAX, 18, 52,
+        86, 120, 154, 188, 222, 240, 1, 128,
+    );
+    #[rustfmt::skip]
+    let b = u8x16::new(
+        u8::MAX, 15, 170, 170, 1, 16, 33, 67,
+        101, 135, 169, 203, 237, 15, 16, 8,
+    );
+    #[rustfmt::skip]
+    let r = u8x16::new(
+        240, 15, 170, 0, 1, 0, 33, 67,
+        33, 135, 33, 67, 33, 15, 16, 8,
+    );
+    assert_eq!(r, transmute(lsx_vandn_v(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vorn_v() {
+    #[rustfmt::skip]
+    let a = u8x16::new(
+        15, 240, 85, 170, 0, u8::MAX, 18, 52,
+        86, 120, 154, 188, 222, 240, 1, 128,
+    );
+    #[rustfmt::skip]
+    let b = u8x16::new(
+        u8::MAX, 15, 170, 170, 1, 16, 33, 67,
+        101, 135, 169, 203, 237, 15, 16, 8,
+    );
+    #[rustfmt::skip]
+    let r = u8x16::new(
+        15, 240, 85, u8::MAX, 254, u8::MAX, 222, 188,
+        222, 120, 222, 188, 222, 240, 239, 247,
+    );
+    assert_eq!(r, transmute(lsx_vorn_v(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vandi_b() {
+    #[rustfmt::skip]
+    let a = u8x16::new(
+        15, 240, 85, 170, 0, u8::MAX, 18, 52,
+        86, 120, 154, 188, 222, 240, 1, 128,
+    );
+    #[rustfmt::skip]
+    let r = u8x16::new(
+        12, 48, 20, 40, 0, 60, 16, 52,
+        20, 56, 24, 60, 28, 48, 0, 0,
+    );
+    assert_eq!(r, transmute(lsx_vandi_b::<60>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vxori_b() {
+    #[rustfmt::skip]
+    let a = u8x16::new(
+        15, 240, 85, 170, 0, u8::MAX, 18, 52,
+        86, 120, 154, 188, 222, 240, 1, 128,
+    );
+    #[rustfmt::skip]
+    let r = u8x16::new(
+        142, 113, 212, 43, 129, 126, 147, 181,
+        215, 249, 27, 61, 95, 113, 128, 1,
+    );
+    assert_eq!(r, transmute(lsx_vxori_b::<129>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vnori_b() {
+    #[rustfmt::skip]
+    let a = u8x16::new(
+        15, 240, 85, 170, 0, u8::MAX, 18, 52,
+        86, 120, 154, 188, 222, 240, 1, 128,
+    );
+    #[rustfmt::skip]
+    let r = u8x16::new(
+        240, 0, 160, 80, 240, 0, 224, 192,
+        160, 128, 96, 64, 32, 0, 240, 112,
+    );
+    assert_eq!(r, transmute(lsx_vnori_b::<15>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vbitsel_v() {
+    #[rustfmt::skip]
+    let a = u8x16::new(
+        15, 240, 85, 170, 0, u8::MAX, 18, 52,
+        86, 120, 154, 188, 222, 240, 1, 128,
+    );
+    #[rustfmt::skip]
+    let b = u8x16::new(
+        u8::MAX, 15, 170, 170, 1, 16, 33, 67,
+        101, 135, 169, 203, 237, 15, 16, 8,
+    );
+    #[rustfmt::skip]
+    let c = u8x16::new(
+        240, 15, 51, 204, u8::MAX, 0, 90, 165,
+        60, 195, 15, 240, 102, 153, 129, 24,
+    );
+    #[rustfmt::skip]
+    let r = u8x16::new(
+        u8::MAX, u8::MAX, 102, 170, 1, u8::MAX, 0, 17,
+        102, 187, 153, 204, 252, 105, 0, 136,
+    );
+    assert_eq!(
+        r,
+        transmute(lsx_vbitsel_v(transmute(a), transmute(b), transmute(c)))
+    );
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vbitseli_b() {
+    #[rustfmt::skip]
+    let a = u8x16::new(
+        15, 240, 85, 170, 0, u8::MAX, 18, 52,
+        86, 120, 154, 188, 222, 240, 1, 128,
+    );
+    #[rustfmt::skip]
+    let b = u8x16::new(
+        u8::MAX, 15, 170, 170, 1, 16, 33, 67,
+        101, 135, 169, 203, 237, 15, 16, 8,
+    );
+    #[rustfmt::skip]
+    let r = u8x16::new(
+        250, 95, 250, 10, 1, 90, 51, 83,
+        115, 223, 59, 91, 123, 95, 16, 8,
+    );
+    assert_eq!(
+        r,
+        transmute(lsx_vbitseli_b::<90>(transmute(a), transmute(b)))
+    );
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vbitclr_w() {
+    let a = u32x4::new(u32::MAX, u32::MAX, 305419896, 8);
+    let b = u32x4::new(0, 31, 36, 3);
+    let r = u32x4::new(4294967294, 2147483647, 305419880, 0);
+    assert_eq!(r, transmute(lsx_vbitclr_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vbitset_h() {
+    let a = u16x8::new(0, 1, 256, 32768, 5, 6, 7, 8);
+    let b = u16x8::new(0, 0, 8, 15, 16, 17, 33, 100);
+    let r = u16x8::new(1, 1, 256, 32768, 5, 6, 7, 24);
+    assert_eq!(r, transmute(lsx_vbitset_h(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vbitrev_b() {
+    #[rustfmt::skip]
+    let a = u8x16::new(
+        15, 240, 85, 170, 0, u8::MAX, 18, 52,
+        86, 120, 154, 188, 222, 240, 1, 128,
+    );
+    #[rustfmt::skip]
+    let b = u8x16::new(
+        u8::MAX, 15, 170, 170, 1, 16, 33, 67,
+        101, 135, 169, 203, 237, 15, 16, 8,
+    );
+    #[rustfmt::skip]
+    let r = u8x16::new(
+        143, 112, 81, 174, 2, 254, 16, 60,
+        118, 248, 152, 180, 254, 112, 0, 129,
+    );
+    assert_eq!(r, transmute(lsx_vbitrev_b(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vbitclri_d() {
+    let a = u64x2::new(u64::MAX, 9223372036854775808);
+    let r = u64x2::new(9223372036854775807, 0);
+    assert_eq!(r, transmute(lsx_vbitclri_d::<63>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vbitseti_w() {
+    let a = u32x4::new(0, 16, 4294901760, 1);
+    let r = u32x4::new(16, 16, 4294901776, 17);
+    assert_eq!(r, transmute(lsx_vbitseti_w::<4>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vbitrevi_h() {
+    let a = u16x8::new(0, 1, 65534, 300, 61536, 32767, 32768, 1234);
+    let r = u16x8::new(32768, 32769, 32766, 33068, 28768, u16::MAX, 0, 34002);
+    assert_eq!(r, transmute(lsx_vbitrevi_h::<15>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vclo_b() {
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        0, 1, -2, 3, -4, 5, i8::MAX, i8::MIN,
+        100, -100, 64, -64, 16, -17, 99, -1,
+    );
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        0, 0, 7, 0, 6, 0, 0, 1,
+        0, 1, 0, 2, 0, 3, 0, 8,
+    );
+    assert_eq!(r, transmute(lsx_vclo_b(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vclz_h() {
+    let a = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let r = i16x8::new(16, 15, 0, 7, 0, 1, 0, 5);
+    assert_eq!(r, transmute(lsx_vclz_h(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vclz_d() {
+    let a = i64x2::new(1, 0);
+    let r = i64x2::new(63, 64);
+    assert_eq!(r, transmute(lsx_vclz_d(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vpcnt_w() {
+    let a = i32x4::new(1, -2, i32::MAX, -100000);
+    let r = i32x4::new(1, 31, 31, 22);
+    assert_eq!(r, transmute(lsx_vpcnt_w(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsll_w() {
+    let a = i32x4::new(1, -2, i32::MAX, -100000);
+    let b = i32x4::new(1, 33, 4, 31);
+    let r = i32x4::new(2, -4, -16, 0);
+    assert_eq!(r, transmute(lsx_vsll_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsrl_h() {
+    let a = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let b = i16x8::new(0, 1, 17, 4, 15, 3, 15, 2);
+    let r = i16x8::new(0, 0, i16::MAX, 18, 1, 4095, 1, 308);
+    assert_eq!(r, transmute(lsx_vsrl_h(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsra_b() {
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        0, 1, -2, 3, -4, 5, i8::MAX, i8::MIN,
+        100, -100, 64, -64, 16, -17, 99, -1,
+    );
+    #[rustfmt::skip]
+    let b = i8x16::new(
+        1, 2, 3, 4, 5, 6, 7, 8,
+        9, 1, 2, 3, 4, 5, 6, 7,
+    );
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        0, 0, -1, 0, -1, 0, 0, i8::MIN,
+        50, -50, 16, -8, 1, -1, 1, -1,
+    );
+    assert_eq!(r, transmute(lsx_vsra_b(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vrotr_w() {
+    let a = i32x4::new(1, -2, i32::MAX, -100000);
+    let b = i32x4::new(4, 8, 31, 32);
+    let r = i32x4::new(268435456, -16777217, -2, -100000);
+    assert_eq!(r, transmute(lsx_vrotr_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vslli_d() {
+    let a = i64x2::new(1099511627776, -3);
+    let r = i64x2::new(35184372088832, -96);
+    assert_eq!(r, transmute(lsx_vslli_d::<5>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsrli_b() {
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        0, 1, -2, 3, -4, 5, i8::MAX, i8::MIN,
+        100, -100, 64, -64, 16, -17, 99, -1,
+    );
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        0, 0, 31, 0, 31, 0, 15, 16,
+        12, 19, 8, 24, 2, 29, 12, 31,
+    );
+    assert_eq!(r, transmute(lsx_vsrli_b::<3>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsrai_h() {
+    let a = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let r = i16x8::new(0, 0, -1, 18, -250, 2047, -2048, 77);
+    assert_eq!(r, transmute(lsx_vsrai_h::<4>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vrotri_b() {
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        0, 1, -2, 3, -4, 5, i8::MAX, i8::MIN,
+        100, -100, 64, -64, 16, -17, 99, -1,
+    );
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        0, 32, -33, 96, -97, -96, -17, 16,
+        -116, -109, 8, 24, 2, -3, 108, -1,
+    );
+    assert_eq!(r, transmute(lsx_vrotri_b::<3>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsrlr_h() {
+    // Rounding shifts add the last bit shifted out.
+    let a = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let b = i16x8::new(0, 1, 1, 4, 12, 3, 15, 2);
+    let r = i16x8::new(0, 1, i16::MAX, 19, 15, 4096, 1, 309);
+    assert_eq!(r, transmute(lsx_vsrlr_h(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsrar_w() {
+    // Rounding shifts add the last bit shifted out.
+    let a = i32x4::new(1, -2, i32::MAX, -100000);
+    let b = i32x4::new(1, 1, 30, 3);
+    let r = i32x4::new(1, -1, 2, -12500);
+    assert_eq!(r, transmute(lsx_vsrar_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsrari_d() {
+    let a = i64x2::new(1000, -1000);
+    let r = i64x2::new(2, -2);
+    assert_eq!(r, transmute(lsx_vsrari_d::<9>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsrln_b_h() {
+    // The narrowed elements are in the low half, the high half is zero.
+    let a = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let b = i16x8::new(0, 1, 2, 3, 4, 8, 15, 16);
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        0, 0, -1, 37, 6, i8::MAX, 1, -46,
+        0, 0, 0, 0, 0, 0, 0, 0,
+    );
+    assert_eq!(r, transmute(lsx_vsrln_b_h(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsran_h_w() {
+    let a = i32x4::new(1, -2, i32::MAX, -100000);
+    let b = i32x4::new(1, 4, 16, 31);
+    let r = i16x8::new(0, -1, i16::MAX, -1, 0, 0, 0, 0);
+    assert_eq!(r, transmute(lsx_vsran_h_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vsrlni_b_h() {
+    // The elements of `b` are narrowed into the low half, those of `a` into
+    // the high half.
+    let a = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let b = i16x8::new(7, -1, -2, 500, 4000, 1, -1, -1234);
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        0, -1, -1, 31, -6, 0, -1, -78,
+        0, 0, -1, 18, 6, -1, 0, 77,
+    );
+    assert_eq!(
+        r,
+        transmute(lsx_vsrlni_b_h::<4>(transmute(a), transmute(b)))
+    );
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vssrani_b_h() {
+    let a = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let b = i16x8::new(7, -1, -2, 500, 4000, 1, -1, -1234);
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        1, -1, -1, 125, i8::MAX, 0, -1, i8::MIN,
+        0, 0, -1, 75, i8::MIN, i8::MAX, i8::MIN, i8::MAX,
+    );
+    assert_eq!(
+        r,
+        transmute(lsx_vssrani_b_h::<2>(transmute(a), transmute(b)))
+    );
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vilvl_b() {
+    // Interleaves the low halves, starting with `b`.
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        0, 1, 2, 3, 4, 5, 6, 7,
+        8, 9, 10, 11, 12, 13, 14, 15,
+    );
+    #[rustfmt::skip]
+    let b = i8x16::new(
+        16, 17, 18, 19, 20, 21, 22, 23,
+        24, 25, 26, 27, 28, 29, 30, 31,
+    );
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        16, 0, 17, 1, 18, 2, 19, 3,
+        20, 4, 21, 5, 22, 6, 23, 7,
+    );
+    assert_eq!(r, transmute(lsx_vilvl_b(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vilvh_h() {
+    let a = i16x8::new(0, 1, 2, 3, 4, 5, 6, 7);
+    let b = i16x8::new(8, 9, 10, 11, 12, 13, 14, 15);
+    let r = i16x8::new(12, 4, 13, 5, 14, 6, 15, 7);
+    assert_eq!(r, transmute(lsx_vilvh_h(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vpackev_w() {
+    let a = i32x4::new(0, 1, 2, 3);
+    let b = i32x4::new(4, 5, 6, 7);
+    let r = i32x4::new(4, 0, 6, 2);
+    assert_eq!(r, transmute(lsx_vpackev_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vpackod_h() {
+    let a = i16x8::new(0, 1, 2, 3, 4, 5, 6, 7);
+    let b = i16x8::new(8, 9, 10, 11, 12, 13, 14, 15);
+    let r = i16x8::new(9, 1, 11, 3, 13, 5, 15, 7);
+    assert_eq!(r, transmute(lsx_vpackod_h(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vpickev_b() {
+    // The even elements of `b` are in the low half, those of `a` in the high half.
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        0, 1, 2, 3, 4, 5, 6, 7,
+        8, 9, 10, 11, 12, 13, 14, 15,
+    );
+    #[rustfmt::skip]
+    let b = i8x16::new(
+        16, 17, 18, 19, 20, 21, 22, 23,
+        24, 25, 26, 27, 28, 29, 30, 31,
+    );
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        16, 18, 20, 22, 24, 26, 28, 30,
+        0, 2, 4, 6, 8, 10, 12, 14,
+    );
+    assert_eq!(r, transmute(lsx_vpickev_b(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vpickod_w() {
+    let a = i32x4::new(0, 1, 2, 3);
+    let b = i32x4::new(4, 5, 6, 7);
+    let r = i32x4::new(5, 7, 1, 3);
+    assert_eq!(r, transmute(lsx_vpickod_w(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vshuf4i_w() {
+    let a = i32x4::new(10, 11, 12, 13);
+    let r = i32x4::new(13, 10, 12, 11);
+    assert_eq!(r, transmute(lsx_vshuf4i_w::<99>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vshuf4i_b() {
+    // Reverses each group of 4 elements.
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        0, 1, 2, 3, 4, 5, 6, 7,
+        8, 9, 10, 11, 12, 13, 14, 15,
+    );
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        3, 2, 1, 0, 7, 6, 5, 4,
+        11, 10, 9, 8, 15, 14, 13, 12,
+    );
+    assert_eq!(r, transmute(lsx_vshuf4i_b::<27>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vshuf_b() {
+    // Indices 0 to 15 select from `b`, 16 to 31 from `a`.
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        16, 17, 18, 19, 20, 21, 22, 23,
+        24, 25, 26, 27, 28, 29, 30, 31,
+    );
+    #[rustfmt::skip]
+    let b = i8x16::new(
+        0, 1, 2, 3, 4, 5, 6, 7,
+        8, 9, 10, 11, 12, 13, 14, 15,
+    );
+    #[rustfmt::skip]
+    let c = i8x16::new(
+        0, 16, 1, 17, 31, 15, 2, 30,
+        8, 24, 3, 19, 5, 21, 7, 23,
+    );
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        0, 16, 1, 17, 31, 15, 2, 30,
+        8, 24, 3, 19, 5, 21, 7, 23,
+    );
+    assert_eq!(
+        r,
+        transmute(lsx_vshuf_b(transmute(a), transmute(b), transmute(c)))
+    );
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vshuf_h() {
+    // The first operand holds the indices: 0 to 7 select from `c`, 8 to 15
+    // from `b`.
+    let a = i16x8::new(3, 9, 0, 17, 8, 15, 6, 1);
+    let b = i16x8::new(10, 11, 12, 13, 14, 15, 16, 17);
+    let c = i16x8::new(20, 21, 22, 23, 24, 25, 26, 27);
+    let r = i16x8::new(23, 11, 20, 21, 10, 17, 26, 21);
+    assert_eq!(
+        r,
+        transmute(lsx_vshuf_h(transmute(a), transmute(b), transmute(c)))
+    );
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vreplve_h() {
+    // The index is taken modulo the number of elements.
+    let a = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let r = i16x8::new(-2, -2, -2, -2, -2, -2, -2, -2);
+    assert_eq!(r, transmute(lsx_vreplve_h(transmute(a), 10)));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vreplvei_w() {
+    let a = i32x4::new(1, -2, i32::MAX, -100000);
+    let r = i32x4::new(-100000, -100000, -100000, -100000);
+    assert_eq!(r, transmute(lsx_vreplvei_w::<3>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vbsll_v() {
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        0, 1, 2, 3, 4, 5, 6, 7,
+        8, 9, 10, 11, 12, 13, 14, 15,
+    );
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        0, 0, 0, 0, 1, 2, 3, 4,
+        5, 6, 7, 8, 9, 10, 11, 12,
+    );
+    assert_eq!(r, transmute(lsx_vbsll_v::<3>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vbsrl_v() {
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        0, 1, 2, 3, 4, 5, 6, 7,
+        8, 9, 10, 11, 12, 13, 14, 15,
+    );
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        5, 6, 7, 8, 9, 10, 11, 12,
+        13, 14, 15, 0, 0, 0, 0, 0,
+    );
+    assert_eq!(r, transmute(lsx_vbsrl_v::<5>(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vpermi_w() {
+    // The low half is selected from `b`, the high half from `a`.
+    let a = i32x4::new(0, 1, 2, 3);
+    let b = i32x4::new(4, 5, 6, 7);
+    let r = i32x4::new(7, 4, 1, 2);
+    assert_eq!(
+        r,
+        transmute(lsx_vpermi_w::<147>(transmute(a), transmute(b)))
+    );
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vextrins_b() {
+    // Copies element 2 of `b` into element 9 of `a`.
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        0, 1, 2, 3, 4, 5, 6, 7,
+        8, 9, 10, 11, 12, 13, 14, 15,
+    );
+    #[rustfmt::skip]
+    let b = i8x16::new(
+        16, 17, 18, 19, 20, 21, 22, 23,
+        24, 25, 26, 27, 28, 29, 30, 31,
+    );
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        0, 1, 2, 3, 4, 5, 6, 7,
+        8, 18, 10, 11, 12, 13, 14, 15,
+    );
+    assert_eq!(
+        r,
+        transmute(lsx_vextrins_b::<146>(transmute(a), transmute(b)))
+    );
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vinsgr2vr_w() {
+    let a = i32x4::new(1, -2, i32::MAX, -100000);
+    let r = i32x4::new(1, -2, -7, -100000);
+    assert_eq!(r, transmute(lsx_vinsgr2vr_w::<2>(transmute(a), -7)));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vreplgr2vr_h() {
+    let r = i16x8::new(-300, -300, -300, -300, -300, -300, -300, -300);
+    assert_eq!(r, transmute(lsx_vreplgr2vr_h(-300)));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vpickve2gr_bu() {
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        0, 1, -2, 3, -4, 5, i8::MAX, i8::MIN,
+        100, -100, 64, -64, 16, -17, 99, -1,
+    );
+    assert_eq!(lsx_vpickve2gr_bu::<7>(transmute(a)), 128);
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vpickve2gr_d() {
+    let a = i64x2::new(1099511627776, -3);
+    assert_eq!(lsx_vpickve2gr_d::<1>(transmute(a)), -3);
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vmskltz_h() {
+    // The sign bits are gathered in the low bits of the first element.
+    let a = i16x8::new(0, 1, -2, 300, -4000, i16::MAX, i16::MIN, 1234);
+    let r = i16x8::new(84, 0, 0, 0, 0, 0, 0, 0);
+    assert_eq!(r, transmute(lsx_vmskltz_h(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vmskltz_b() {
+    #[rustfmt::skip]
+    let a = i8x16::new(
+        0, 1, -2, 3, -4, 5, i8::MAX, i8::MIN,
+        100, -100, 64, -64, 16, -17, 99, -1,
+    );
+    #[rustfmt::skip]
+    let r = i8x16::new(
+        -108, -86, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0,
+    );
+    assert_eq!(r, transmute(lsx_vmskltz_b(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfsub_d() {
+    let a = f64x2::new(3.0, -0.125);
+    let b = f64x2::new(-1.5, 8.0);
+    let r = f64x2::new(4.5, -8.125);
+    assert_eq!(r, transmute(lsx_vfsub_d(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfmul_s() {
+    let a = f32x4::new(1.5, -2.0, 0.25, 1024.0);
+    let b = f32x4::new(0.5, 4.0, -0.25, -8.0);
+    let r = f32x4::new(0.75, -8.0, -0.0625, -8192.0);
+    assert_eq!(r, transmute(lsx_vfmul_s(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfdiv_d() {
+    let a = f64x2::new(3.0, -0.125);
+    let b = f64x2::new(-1.5, 8.0);
+    let r = f64x2::new(-2.0, -0.015625);
+    assert_eq!(r, transmute(lsx_vfdiv_d(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfmadd_s() {
+    let a = f32x4::new(1.5, -2.0, 0.25, 1024.0);
+    let b = f32x4::new(0.5, 4.0, -0.25, -8.0);
+    let c = f32x4::new(1.0, -1.0, 2.0, 0.5);
+    let r = f32x4::new(1.75, -9.0, 1.9375, -8191.5);
+    assert_eq!(
+        r,
+        transmute(lsx_vfmadd_s(transmute(a), transmute(b), transmute(c)))
+    );
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfmsub_d() {
+    let a = f64x2::new(3.0, -0.125);
+    let b = f64x2::new(-1.5, 8.0);
+    let c = f64x2::new(0.5, 2.0);
+    let r = f64x2::new(-5.0, -3.0);
+    assert_eq!(
+        r,
+        transmute(lsx_vfmsub_d(transmute(a), transmute(b), transmute(c)))
+    );
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfnmadd_s() {
+    let a = f32x4::new(1.5, -2.0, 0.25, 1024.0);
+    let b = f32x4::new(0.5, 4.0, -0.25, -8.0);
+    let c = f32x4::new(1.0, -1.0, 2.0, 0.5);
+    let r = f32x4::new(-1.75, 9.0, -1.9375, 8191.5);
+    assert_eq!(
+        r,
+        transmute(lsx_vfnmadd_s(transmute(a), transmute(b), transmute(c)))
+    );
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfnmsub_d() {
+    let a = f64x2::new(3.0, -0.125);
+    let b = f64x2::new(-1.5, 8.0);
+    let c = f64x2::new(0.5, 2.0);
+    let r = f64x2::new(5.0, 3.0);
+    assert_eq!(
+        r,
+        transmute(lsx_vfnmsub_d(transmute(a), transmute(b), transmute(c)))
+    );
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfmax_s() {
+    let a = f32x4::new(1.5, -2.0, 0.25, 1024.0);
+    let b = f32x4::new(0.5, 4.0, -0.25, -8.0);
+    let r = f32x4::new(1.5, 4.0, 0.25, 1024.0);
+    assert_eq!(r, transmute(lsx_vfmax_s(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfmin_d() {
+    let a = f64x2::new(3.0, -0.125);
+    let b = f64x2::new(-1.5, 8.0);
+    let r = f64x2::new(-1.5, -0.125);
+    assert_eq!(r, transmute(lsx_vfmin_d(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfmaxa_s() {
+    let a = f32x4::new(1.5, -2.0, 0.25, 1024.0);
+    let b = f32x4::new(0.5, 4.0, -0.25, -8.0);
+    let r = f32x4::new(1.5, 4.0, 0.25, 1024.0);
+    assert_eq!(r, transmute(lsx_vfmaxa_s(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfmina_d() {
+    let a = f64x2::new(3.0, -0.125);
+    let b = f64x2::new(-1.5, 8.0);
+    let r = f64x2::new(-1.5, -0.125);
+    assert_eq!(r, transmute(lsx_vfmina_d(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfsqrt_d() {
+    let a = f64x2::new(16.0, 0.25);
+    let r = f64x2::new(4.0, 0.5);
+    assert_eq!(r, transmute(lsx_vfsqrt_d(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfrecip_s() {
+    let a = f32x4::new(2.0, -0.5, 4.0, 0.125);
+    let r = f32x4::new(0.5, -2.0, 0.25, 8.0);
+    assert_eq!(r, transmute(lsx_vfrecip_s(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfrsqrt_d() {
+    let a = f64x2::new(4.0, 0.0625);
+    let r = f64x2::new(0.5, 4.0);
+    assert_eq!(r, transmute(lsx_vfrsqrt_d(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vflogb_s() {
+    let a = f32x4::new(8.0, 0.25, 1.0, 1000.0);
+    let r = f32x4::new(3.0, -2.0, 0.0, 9.0);
+    assert_eq!(r, transmute(lsx_vflogb_s(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfclass_d() {
+    let a = f64x2::new(-1.5, 0.0);
+    let r = i64x2::new(8, 512);
+    assert_eq!(r, transmute(lsx_vfclass_d(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfclass_s() {
+    let a = f32x4::new(f32::INFINITY, -0.0, f32::NAN, -f32::INFINITY);
+    let r = i32x4::new(64, 32, 2, 4);
+    assert_eq!(r, transmute(lsx_vfclass_s(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vffint_s_w() {
+    let a = i32x4::new(1, -2, 1048576, -16777216);
+    let r = f32x4::new(1.0, -2.0, 1048576.0, -16777216.0);
+    assert_eq!(r, transmute(lsx_vffint_s_w(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vffint_d_lu() {
+    let a = u64x2::new(3, 9223372036854775808);
+    let r = f64x2::new(3.0, 9.223372036854776e+18);
+    assert_eq!(r, transmute(lsx_vffint_d_lu(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vffint_s_l() {
+    // The elements of `b` are converted into the low half.
+    let a = i64x2::new(1, -2);
+    let b = i64x2::new(3, -4);
+    let r = f32x4::new(3.0, -4.0, 1.0, -2.0);
+    assert_eq!(r, transmute(lsx_vffint_s_l(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vffinth_d_w() {
+    let a = i32x4::new(1, 2, -3, 4);
+    let r = f64x2::new(-3.0, 4.0);
+    assert_eq!(r, transmute(lsx_vffinth_d_w(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vftintrz_w_s() {
+    let a = f32x4::new(1.75, -1.75, 100.5, -0.5);
+    let r = i32x4::new(1, -1, 100, 0);
+    assert_eq!(r, transmute(lsx_vftintrz_w_s(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vftintrne_l_d() {
+    // Rounds to nearest, ties to even.
+    let a = f64x2::new(2.5, -3.5);
+    let r = i64x2::new(2, -4);
+    assert_eq!(r, transmute(lsx_vftintrne_l_d(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vftintrp_w_s() {
+    let a = f32x4::new(1.25, -1.25, 2.0, -0.75);
+    let r = i32x4::new(2, -1, 2, 0);
+    assert_eq!(r, transmute(lsx_vftintrp_w_s(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vftintrm_l_d() {
+    let a = f64x2::new(1.25, -1.25);
+    let r = i64x2::new(1, -2);
+    assert_eq!(r, transmute(lsx_vftintrm_l_d(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vftint_w_s() {
+    // The default rounding mode is to nearest, ties to even.
+    let a = f32x4::new(0.5, 1.5, -2.5, 7.25);
+    let r = i32x4::new(0, 2, -2, 7);
+    assert_eq!(r, transmute(lsx_vftint_w_s(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfcvt_s_d() {
+    // The elements of `b` are converted into the low half.
+    let a = f64x2::new(1.5, -2.0);
+    let b = f64x2::new(0.25, 8.0);
+    let r = f32x4::new(0.25, 8.0, 1.5, -2.0);
+    assert_eq!(r, transmute(lsx_vfcvt_s_d(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfcvtl_d_s() {
+    let a = f32x4::new(1.5, -2.0, 0.25, 1024.0);
+    let r = f64x2::new(1.5, -2.0);
+    assert_eq!(r, transmute(lsx_vfcvtl_d_s(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfcvth_d_s() {
+    let a = f32x4::new(1.5, -2.0, 0.25, 1024.0);
+    let r = f64x2::new(0.25, 1024.0);
+    assert_eq!(r, transmute(lsx_vfcvth_d_s(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfrintrz_s() {
+    let a = f32x4::new(1.75, -1.75, 2.5, -0.25);
+    let r = f32x4::new(1.0, -1.0, 2.0, 0.0);
+    assert_eq!(r, transmute(lsx_vfrintrz_s(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfrint_d() {
+    let a = f64x2::new(2.5, -1.5);
+    let r = f64x2::new(2.0, -2.0);
+    assert_eq!(r, transmute(lsx_vfrint_d(transmute(a))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfcmp_ceq_s() {
+    let a = f32x4::new(1.0, f32::NAN, -2.0, 3.0);
+    let b = f32x4::new(1.0, 1.0, 0.5, f32::NAN);
+    let r = i32x4::new(-1, 0, 0, 0);
+    assert_eq!(r, transmute(lsx_vfcmp_ceq_s(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfcmp_clt_d() {
+    let a = f64x2::new(1.0, -3.0);
+    let b = f64x2::new(2.0, -4.0);
+    let r = i64x2::new(-1, 0);
+    assert_eq!(r, transmute(lsx_vfcmp_clt_d(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfcmp_cle_s() {
+    let a = f32x4::new(1.0, f32::NAN, -2.0, 3.0);
+    let b = f32x4::new(1.0, 1.0, 0.5, f32::NAN);
+    let r = i32x4::new(-1, 0, -1, 0);
+    assert_eq!(r, transmute(lsx_vfcmp_cle_s(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfcmp_cun_s() {
+    let a = f32x4::new(1.0, f32::NAN, -2.0, 3.0);
+    let b = f32x4::new(1.0, 1.0, 0.5, f32::NAN);
+    let r = i32x4::new(0, -1, 0, -1);
+    assert_eq!(r, transmute(lsx_vfcmp_cun_s(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfcmp_cune_d() {
+    // Unordered or not equal.
+    let a = f64x2::new(1.0, f64::NAN);
+    let b = f64x2::new(1.0, 2.0);
+    let r = i64x2::new(0, -1);
+    assert_eq!(r, transmute(lsx_vfcmp_cune_d(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_vfcmp_cor_s() {
+    let a = f32x4::new(1.0, f32::NAN, -2.0, 3.0);
+    let b = f32x4::new(1.0, 1.0, 0.5, f32::NAN);
+    let r = i32x4::new(-1, 0, -1, 0);
+    assert_eq!(r, transmute(lsx_vfcmp_cor_s(transmute(a), transmute(b))));
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_bnz_b() {
+    #[rustfmt::skip]
+    let a = u8x16::new(
+        5, u8::MAX, 2, 253, 4, 5, 1, u8::MAX,
+        100, 156, 191, 64, 3, 17, 158, 1,
+    );
+    assert_eq!(lsx_bnz_b(transmute(a)), 1);
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_bnz_h() {
+    let a = u16x8::new(1, 2, 3, 0, 5, 6, 7, 8);
+    assert_eq!(lsx_bnz_h(transmute(a)), 0);
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_bz_w() {
+    let a = u32x4::new(1, 2, 65536, 3);
+    assert_eq!(lsx_bz_w(transmute(a)), 0);
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_bz_h() {
+    let a = u16x8::new(1, 2, 3, 0, 5, 6, 7, 8);
+    assert_eq!(lsx_bz_h(transmute(a)), 1);
+}
+
+#[simd_test(enable = "lsx")]
+unsafe fn test_lsx_bnz_v() {
+    #[rustfmt::skip]
+    let a = u8x16::new(
+        0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 1,
+    );
+    assert_eq!(lsx_bnz_v(transmute(a)), 1);
+}
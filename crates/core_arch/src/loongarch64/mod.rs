@@ -0,0 +1,399 @@
+//! LoongArch64
+//!
+//! The reference is the [LoongArch Reference Manual - Volume 1: Basic
+//! Architecture], and the names of the intrinsics follow `larchintrin.h`,
+//! `lsxintrin.h` and `lasxintrin.h` of GCC.
+//!
+//! [LoongArch Reference Manual - Volume 1: Basic Architecture]: https://loongson.github.io/LoongArch-Documentation/LoongArch-Vol1-EN.html
+
+mod lasx;
+mod lsx;
+
+pub use self::lasx::*;
+pub use self::lsx::*;
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "unadjusted" {
+    #[link_name = "llvm.loongarch.crc.w.b.w"]
+    fn __crc_w_b_w(a: i32, b: i32) -> i32;
+    #[link_name = "llvm.loongarch.crc.w.h.w"]
+    fn __crc_w_h_w(a: i32, b: i32) -> i32;
+    #[link_name = "llvm.loongarch.crc.w.w.w"]
+    fn __crc_w_w_w(a: i32, b: i32) -> i32;
+    #[link_name = "llvm.loongarch.crc.w.d.w"]
+    fn __crc_w_d_w(a: i64, b: i32) -> i32;
+    #[link_name = "llvm.loongarch.crcc.w.b.w"]
+    fn __crcc_w_b_w(a: i32, b: i32) -> i32;
+    #[link_name = "llvm.loongarch.crcc.w.h.w"]
+    fn __crcc_w_h_w(a: i32, b: i32) -> i32;
+    #[link_name = "llvm.loongarch.crcc.w.w.w"]
+    fn __crcc_w_w_w(a: i32, b: i32) -> i32;
+    #[link_name = "llvm.loongarch.crcc.w.d.w"]
+    fn __crcc_w_d_w(a: i64, b: i32) -> i32;
+    #[link_name = "llvm.loongarch.dbar"]
+    fn __dbar(a: i32);
+    #[link_name = "llvm.loongarch.ibar"]
+    fn __ibar(a: i32);
+    #[link_name = "llvm.loongarch.break"]
+    fn __break(a: i32);
+    #[link_name = "llvm.loongarch.syscall"]
+    fn __syscall(a: i32);
+    #[link_name = "llvm.loongarch.cpucfg"]
+    fn __cpucfg(a: u32) -> u32;
+    #[link_name = "llvm.loongarch.movfcsr2gr"]
+    fn __movfcsr2gr(a: i32) -> i32;
+    #[link_name = "llvm.loongarch.movgr2fcsr"]
+    fn __movgr2fcsr(a: i32, b: i32);
+    #[link_name = "llvm.loongarch.cacop.d"]
+    fn __cacop(a: i64, b: i64, c: i64);
+    #[link_name = "llvm.loongarch.csrrd.d"]
+    fn __csrrd(a: i32) -> i64;
+    #[link_name = "llvm.loongarch.csrwr.d"]
+    fn __csrwr(a: i64, b: i32) -> i64;
+    #[link_name = "llvm.loongarch.csrxchg.d"]
+    fn __csrxchg(a: i64, b: i64, c: i32) -> i64;
+    #[link_name = "llvm.loongarch.iocsrrd.b"]
+    fn __iocsrrd_b(a: i32) -> i32;
+    #[link_name = "llvm.loongarch.iocsrrd.h"]
+    fn __iocsrrd_h(a: i32) -> i32;
+    #[link_name = "llvm.loongarch.iocsrrd.w"]
+    fn __iocsrrd_w(a: i32) -> i32;
+    #[link_name = "llvm.loongarch.iocsrrd.d"]
+    fn __iocsrrd_d(a: i32) -> i64;
+    #[link_name = "llvm.loongarch.iocsrwr.b"]
+    fn __iocsrwr_b(a: i32, b: i32);
+    #[link_name = "llvm.loongarch.iocsrwr.h"]
+    fn __iocsrwr_h(a: i32, b: i32);
+    #[link_name = "llvm.loongarch.iocsrwr.w"]
+    fn __iocsrwr_w(a: i32, b: i32);
+    #[link_name = "llvm.loongarch.iocsrwr.d"]
+    fn __iocsrwr_d(a: i64, b: i32);
+    #[link_name = "llvm.loongarch.asrtle.d"]
+    fn __asrtle(a: i64, b: i64);
+    #[link_name = "llvm.loongarch.asrtgt.d"]
+    fn __asrtgt(a: i64, b: i64);
+    #[link_name = "llvm.loongarch.lddir.d"]
+    fn __lddir(a: i64, b: i64) -> i64;
+    #[link_name = "llvm.loongarch.ldpte.d"]
+    fn __ldpte(a: i64, b: i64);
+}
+
+/// Reads the 64-bit stable counter value and the counter ID.
+#[inline]
+#[cfg_attr(test, assert_instr(rdtime.d))]
+pub unsafe fn rdtime_d() -> (i64, isize) {
+    let val: i64;
+    let tid: isize;
+    asm!("rdtime.d {}, {}", out(reg) val, out(reg) tid, options(readonly, nostack));
+    (val, tid)
+}
+
+/// Reads the lower 32 bits of the stable counter value and the counter ID.
+#[inline]
+#[cfg_attr(test, assert_instr(rdtimel.w))]
+pub unsafe fn rdtimel_w() -> (i32, isize) {
+    let val: i32;
+    let tid: isize;
+    asm!("rdtimel.w {}, {}", out(reg) val, out(reg) tid, options(readonly, nostack));
+    (val, tid)
+}
+
+/// Reads the upper 32 bits of the stable counter value and the counter ID.
+#[inline]
+#[cfg_attr(test, assert_instr(rdtimeh.w))]
+pub unsafe fn rdtimeh_w() -> (i32, isize) {
+    let val: i32;
+    let tid: isize;
+    asm!("rdtimeh.w {}, {}", out(reg) val, out(reg) tid, options(readonly, nostack));
+    (val, tid)
+}
+
+/// Computes the CRC-32 (IEEE 802.3 polynomial) of the byte `a` with the
+/// initial value `b`.
+#[inline]
+#[cfg_attr(test, assert_instr(crc.w.b.w))]
+pub unsafe fn crc_w_b_w(a: i32, b: i32) -> i32 {
+    __crc_w_b_w(a, b)
+}
+
+/// Computes the CRC-32 (IEEE 802.3 polynomial) of the half-word `a` with the
+/// initial value `b`.
+#[inline]
+#[cfg_attr(test, assert_instr(crc.w.h.w))]
+pub unsafe fn crc_w_h_w(a: i32, b: i32) -> i32 {
+    __crc_w_h_w(a, b)
+}
+
+/// Computes the CRC-32 (IEEE 802.3 polynomial) of the word `a` with the
+/// initial value `b`.
+#[inline]
+#[cfg_attr(test, assert_instr(crc.w.w.w))]
+pub unsafe fn crc_w_w_w(a: i32, b: i32) -> i32 {
+    __crc_w_w_w(a, b)
+}
+
+/// Computes the CRC-32 (IEEE 802.3 polynomial) of the double-word `a` with
+/// the initial value `b`.
+#[inline]
+#[cfg_attr(test, assert_instr(crc.w.d.w))]
+pub unsafe fn crc_w_d_w(a: i64, b: i32) -> i32 {
+    __crc_w_d_w(a, b)
+}
+
+/// Computes the CRC-32C (Castagnoli polynomial) of the byte `a` with the
+/// initial value `b`.
+#[inline]
+#[cfg_attr(test, assert_instr(crcc.w.b.w))]
+pub unsafe fn crcc_w_b_w(a: i32, b: i32) -> i32 {
+    __crcc_w_b_w(a, b)
+}
+
+/// Computes the CRC-32C (Castagnoli polynomial) of the half-word `a` with
+/// the initial value `b`.
+#[inline]
+#[cfg_attr(test, assert_instr(crcc.w.h.w))]
+pub unsafe fn crcc_w_h_w(a: i32, b: i32) -> i32 {
+    __crcc_w_h_w(a, b)
+}
+
+/// Computes the CRC-32C (Castagnoli polynomial) of the word `a` with the
+/// initial value `b`.
+#[inline]
+#[cfg_attr(test, assert_instr(crcc.w.w.w))]
+pub unsafe fn crcc_w_w_w(a: i32, b: i32) -> i32 {
+    __crcc_w_w_w(a, b)
+}
+
+/// Computes the CRC-32C (Castagnoli polynomial) of the double-word `a` with
+/// the initial value `b`.
+#[inline]
+#[cfg_attr(test, assert_instr(crcc.w.d.w))]
+pub unsafe fn crcc_w_d_w(a: i64, b: i32) -> i32 {
+    __crcc_w_d_w(a, b)
+}
+
+/// Generates the memory barrier instruction `DBAR` with the hint `IMM15`.
+///
+/// The hint 0 is a full barrier.
+#[inline]
+#[cfg_attr(test, assert_instr(dbar, IMM15 = 0))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn dbar<const IMM15: i32>() {
+    static_assert_uimm_bits!(IMM15, 15);
+    __dbar(IMM15);
+}
+
+/// Generates the instruction fetch barrier instruction `IBAR` with the hint
+/// `IMM15`.
+#[inline]
+#[cfg_attr(test, assert_instr(ibar, IMM15 = 0))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn ibar<const IMM15: i32>() {
+    static_assert_uimm_bits!(IMM15, 15);
+    __ibar(IMM15);
+}
+
+/// Generates the breakpoint instruction `BREAK` with the code `IMM15`.
+#[inline]
+#[cfg_attr(test, assert_instr(break, IMM15 = 1))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn break_<const IMM15: i32>() {
+    static_assert_uimm_bits!(IMM15, 15);
+    __break(IMM15);
+}
+
+/// Generates the system call instruction `SYSCALL` with the code `IMM15`.
+#[inline]
+#[cfg_attr(test, assert_instr(syscall, IMM15 = 1))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn syscall<const IMM15: i32>() {
+    static_assert_uimm_bits!(IMM15, 15);
+    __syscall(IMM15);
+}
+
+/// Reads the CPUCFG configuration word `a`, which describes the features of
+/// the processor.
+#[inline]
+#[cfg_attr(test, assert_instr(cpucfg))]
+pub unsafe fn cpucfg(a: u32) -> u32 {
+    __cpucfg(a)
+}
+
+/// Reads the floating-point control and status register `IMM2`.
+#[inline]
+#[cfg_attr(test, assert_instr(movfcsr2gr, IMM2 = 1))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn movfcsr2gr<const IMM2: i32>() -> i32 {
+    static_assert_uimm_bits!(IMM2, 2);
+    __movfcsr2gr(IMM2)
+}
+
+/// Writes `a` to the floating-point control and status register `IMM2`.
+#[inline]
+#[cfg_attr(test, assert_instr(movgr2fcsr, IMM2 = 1))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn movgr2fcsr<const IMM2: i32>(a: i32) {
+    static_assert_uimm_bits!(IMM2, 2);
+    __movgr2fcsr(IMM2, a);
+}
+
+/// Performs the cache operation `IMM5` on the address `a + IMM_S12`.
+#[inline]
+#[cfg_attr(test, assert_instr(cacop, IMM5 = 1, IMM_S12 = 1))]
+#[rustc_legacy_const_generics(0, 2)]
+pub unsafe fn cacop<const IMM5: i64, const IMM_S12: i64>(a: i64) {
+    static_assert_uimm_bits!(IMM5, 5);
+    static_assert_simm_bits!(IMM_S12, 12);
+    __cacop(IMM5, a, IMM_S12);
+}
+
+/// Reads the control and status register `IMM14`.
+#[inline]
+#[cfg_attr(test, assert_instr(csrrd, IMM14 = 1))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn csrrd<const IMM14: i32>() -> i64 {
+    static_assert_uimm_bits!(IMM14, 14);
+    __csrrd(IMM14)
+}
+
+/// Writes `a` to the control and status register `IMM14`, and returns its
+/// previous value.
+#[inline]
+#[cfg_attr(test, assert_instr(csrwr, IMM14 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn csrwr<const IMM14: i32>(a: i64) -> i64 {
+    static_assert_uimm_bits!(IMM14, 14);
+    __csrwr(a, IMM14)
+}
+
+/// Writes the bits of `a` selected by the mask `b` to the control and
+/// status register `IMM14`, and returns its previous value.
+#[inline]
+#[cfg_attr(test, assert_instr(csrxchg, IMM14 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn csrxchg<const IMM14: i32>(a: i64, b: i64) -> i64 {
+    static_assert_uimm_bits!(IMM14, 14);
+    __csrxchg(a, b, IMM14)
+}
+
+/// Reads the byte at the address `a` of the IOCSR space.
+#[inline]
+#[cfg_attr(test, assert_instr(iocsrrd.b))]
+pub unsafe fn iocsrrd_b(a: i32) -> i32 {
+    __iocsrrd_b(a)
+}
+
+/// Reads the half-word at the address `a` of the IOCSR space.
+#[inline]
+#[cfg_attr(test, assert_instr(iocsrrd.h))]
+pub unsafe fn iocsrrd_h(a: i32) -> i32 {
+    __iocsrrd_h(a)
+}
+
+/// Reads the word at the address `a` of the IOCSR space.
+#[inline]
+#[cfg_attr(test, assert_instr(iocsrrd.w))]
+pub unsafe fn iocsrrd_w(a: i32) -> i32 {
+    __iocsrrd_w(a)
+}
+
+/// Reads the double-word at the address `a` of the IOCSR space.
+#[inline]
+#[cfg_attr(test, assert_instr(iocsrrd.d))]
+pub unsafe fn iocsrrd_d(a: i32) -> i64 {
+    __iocsrrd_d(a)
+}
+
+/// Writes the byte `a` at the address `b` of the IOCSR space.
+#[inline]
+#[cfg_attr(test, assert_instr(iocsrwr.b))]
+pub unsafe fn iocsrwr_b(a: i32, b: i32) {
+    __iocsrwr_b(a, b)
+}
+
+/// Writes the half-word `a` at the address `b` of the IOCSR space.
+#[inline]
+#[cfg_attr(test, assert_instr(iocsrwr.h))]
+pub unsafe fn iocsrwr_h(a: i32, b: i32) {
+    __iocsrwr_h(a, b)
+}
+
+/// Writes the word `a` at the address `b` of the IOCSR space.
+#[inline]
+#[cfg_attr(test, assert_instr(iocsrwr.w))]
+pub unsafe fn iocsrwr_w(a: i32, b: i32) {
+    __iocsrwr_w(a, b)
+}
+
+/// Writes the double-word `a` at the address `b` of the IOCSR space.
+#[inline]
+#[cfg_attr(test, assert_instr(iocsrwr.d))]
+pub unsafe fn iocsrwr_d(a: i64, b: i32) {
+    __iocsrwr_d(a, b)
+}
+
+/// Raises a bound check exception unless `a <= b`.
+#[inline]
+#[cfg_attr(test, assert_instr(asrtle.d))]
+pub unsafe fn asrtle(a: i64, b: i64) {
+    __asrtle(a, b);
+}
+
+/// Raises a bound check exception unless `a > b`.
+#[inline]
+#[cfg_attr(test, assert_instr(asrtgt.d))]
+pub unsafe fn asrtgt(a: i64, b: i64) {
+    __asrtgt(a, b);
+}
+
+/// Loads the page directory entry of level `IMM8` from the directory `a`.
+#[inline]
+#[cfg_attr(test, assert_instr(lddir, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn lddir<const IMM8: i64>(a: i64) -> i64 {
+    static_assert_uimm_bits!(IMM8, 8);
+    __lddir(a, IMM8)
+}
+
+/// Loads the page table entry `IMM8` from the page table `a`.
+#[inline]
+#[cfg_attr(test, assert_instr(ldpte, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn ldpte<const IMM8: i64>(a: i64) {
+    static_assert_uimm_bits!(IMM8, 8);
+    __ldpte(a, IMM8)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::loongarch64::*;
+
+    #[test]
+    fn test_crc_w_w_w() {
+        // CRC-32 of "123456789" is 0xcbf43926.
+        let mut crc = !0;
+        for w in [0x34333231, 0x38373635] {
+            crc = unsafe { crc_w_w_w(w, crc) };
+        }
+        crc = unsafe { crc_w_b_w(0x39, crc) };
+        assert_eq!(!crc as u32, 0xcbf43926);
+    }
+
+    #[test]
+    fn test_crcc_w_d_w() {
+        // CRC-32C of "12345678" is 0x6087809a.
+        let crc = unsafe { crcc_w_d_w(0x3837363534333231, !0) };
+        assert_eq!(!crc as u32, 0x6087809a);
+    }
+
+    #[test]
+    fn test_cpucfg() {
+        // Word 0 is the processor identity, and is never 0.
+        assert_ne!(unsafe { cpucfg(0) }, 0);
+    }
+}
@@ -0,0 +1,2246 @@
+//! MIPS DSP ASE intrinsics
+//!
+//! The reference is MIPS Architecture for Programmers Volume IV-e: The MIPS
+//! DSP Module for the MIPS32 Architecture (MD00374), and the names of the
+//! intrinsics follow the [GCC documentation][gcc], with the `__builtin`
+//! prefix replaced by `_` like for MSA.
+//!
+//! rustc has no target features for the DSP ASE, so the intrinsics are
+//! implemented with inline assembly. Their availability has to be checked
+//! with `is_mips_feature_detected!("dsp")`, or `"dspr2"` for the revision 2
+//! intrinsics, before calling them.
+//!
+//! Most instructions report saturation, carries or comparison results in the
+//! `DSPControl` register, which other intrinsics read, so none of them are
+//! pure. The accumulator intrinsics operate on `$ac0`, whose previous
+//! contents are saved and restored around the instruction because the
+//! compiler may keep the result of a multiplication in it.
+//!
+//! `__builtin_mips_ldx` is missing because LLVM doesn't support the MIPS64
+//! DSP instructions.
+//!
+//! [gcc]: https://gcc.gnu.org/onlinedocs/gcc/MIPS-DSP-Built-in-Functions.html
+
+use crate::arch::asm;
+use crate::mem;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+types! {
+    /// MIPS-specific 32-bit wide vector of 4 packed `i8`.
+    pub struct v4i8(i8, i8, i8, i8);
+
+    /// MIPS-specific 32-bit wide vector of 2 packed `i16`, used as Q15
+    /// fractions.
+    pub struct v2q15(i16, i16);
+}
+
+/// MIPS-specific 32-bit wide vector of 4 packed `i8`, used as Q7 fractions.
+#[allow(non_camel_case_types)]
+pub type v4q7 = v4i8;
+
+/// MIPS-specific 32-bit wide vector of 2 packed `i16`.
+#[allow(non_camel_case_types)]
+pub type v2i16 = v2q15;
+
+/// The types the DSP instructions read from and write to general-purpose
+/// registers.
+trait Gpr: Sized {
+    fn into_gpr(self) -> i32;
+    fn from_gpr(r: i32) -> Self;
+}
+
+impl Gpr for i32 {
+    #[inline(always)]
+    fn into_gpr(self) -> i32 {
+        self
+    }
+    #[inline(always)]
+    fn from_gpr(r: i32) -> Self {
+        r
+    }
+}
+
+impl Gpr for u32 {
+    #[inline(always)]
+    fn into_gpr(self) -> i32 {
+        self as i32
+    }
+    #[inline(always)]
+    fn from_gpr(r: i32) -> Self {
+        r as u32
+    }
+}
+
+impl Gpr for v4i8 {
+    #[inline(always)]
+    fn into_gpr(self) -> i32 {
+        unsafe { mem::transmute(self) }
+    }
+    #[inline(always)]
+    fn from_gpr(r: i32) -> Self {
+        unsafe { mem::transmute(r) }
+    }
+}
+
+impl Gpr for v2q15 {
+    #[inline(always)]
+    fn into_gpr(self) -> i32 {
+        unsafe { mem::transmute(self) }
+    }
+    #[inline(always)]
+    fn from_gpr(r: i32) -> Self {
+        unsafe { mem::transmute(r) }
+    }
+}
+
+// Runs `$instr` on general-purpose registers. The result is `{r}`, which
+// `r <- x` also initializes to `x`, and `void` is for instructions that only
+// write `DSPControl`. Immediates follow the `;`.
+macro_rules! dsp {
+    (void $ase:ident, $instr:literal $(, $op:ident = $val:expr)*) => {
+        asm!(
+            ".set push",
+            concat!(".set ", stringify!($ase)),
+            $instr,
+            ".set pop",
+            $($op = in(reg) Gpr::into_gpr($val),)*
+            options(nomem, nostack),
+        )
+    };
+    ($ase:ident, $instr:literal, r <- $r:expr $(, $op:ident = $val:expr)* $(; $c:ident = $cv:expr)*) => {{
+        let r: i32;
+        asm!(
+            ".set push",
+            concat!(".set ", stringify!($ase)),
+            $instr,
+            ".set pop",
+            r = inlateout(reg) Gpr::into_gpr($r) => r,
+            $($op = in(reg) Gpr::into_gpr($val),)*
+            $($c = const $cv,)*
+            options(nomem, nostack),
+        );
+        Gpr::from_gpr(r)
+    }};
+    ($ase:ident, $instr:literal $(, $op:ident = $val:expr)* $(; $c:ident = $cv:expr)*) => {{
+        let r: i32;
+        asm!(
+            ".set push",
+            concat!(".set ", stringify!($ase)),
+            $instr,
+            ".set pop",
+            r = lateout(reg) r,
+            $($op = in(reg) Gpr::into_gpr($val),)*
+            $($c = const $cv,)*
+            options(nomem, nostack),
+        );
+        Gpr::from_gpr(r)
+    }};
+}
+
+// Runs `$instr` with `$ac0` set to `$acc`, and returns either the new value
+// of the accumulator, or with `=> r` the register `{r}` written by `$instr`.
+macro_rules! dsp_acc {
+    ($ase:ident, $instr:literal, $acc:expr => r $(; $c:ident = $cv:expr)*) => {{
+        let acc: i64 = $acc;
+        let r: i32;
+        asm!(
+            ".set push",
+            concat!(".set ", stringify!($ase)),
+            "mfhi {shi}",
+            "mflo {slo}",
+            "mthi {hi}",
+            "mtlo {lo}",
+            $instr,
+            "mthi {shi}",
+            "mtlo {slo}",
+            ".set pop",
+            hi = in(reg) (acc >> 32) as i32,
+            lo = in(reg) acc as i32,
+            shi = out(reg) _,
+            slo = out(reg) _,
+            r = out(reg) r,
+            $($c = const $cv,)*
+            options(nomem, nostack),
+        );
+        r
+    }};
+    ($ase:ident, $instr:literal, $acc:expr $(, $op:ident = $val:expr)* $(; $c:ident = $cv:expr)*) => {{
+        let acc: i64 = $acc;
+        let (hi, lo): (i32, i32);
+        asm!(
+            ".set push",
+            concat!(".set ", stringify!($ase)),
+            "mfhi {shi}",
+            "mflo {slo}",
+            "mthi {hi}",
+            "mtlo {lo}",
+            $instr,
+            "mfhi {hi}",
+            "mflo {lo}",
+            "mthi {shi}",
+            "mtlo {slo}",
+            ".set pop",
+            hi = inout(reg) (acc >> 32) as i32 => hi,
+            lo = inout(reg) acc as i32 => lo,
+            shi = out(reg) _,
+            slo = out(reg) _,
+            $($op = in(reg) Gpr::into_gpr($val),)*
+            $($c = const $cv,)*
+            options(nomem, nostack),
+        );
+        ((hi as i64) << 32) | (lo as u32 as i64)
+    }};
+}
+
+/// Vector Fractional Add
+///
+/// Adds the Q15 fractions in `a` and `b`, wrapping on overflow.
+#[inline]
+#[cfg_attr(test, assert_instr(addq.ph))]
+pub unsafe fn __mips_addq_ph(a: v2q15, b: v2q15) -> v2q15 {
+    dsp!(dsp, "addq.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Fractional Saturating Add
+///
+/// Adds the Q15 fractions in `a` and `b`, saturating on overflow.
+#[inline]
+#[cfg_attr(test, assert_instr(addq_s.ph))]
+pub unsafe fn __mips_addq_s_ph(a: v2q15, b: v2q15) -> v2q15 {
+    dsp!(dsp, "addq_s.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Fractional Saturating Add
+///
+/// Adds the Q31 fractions `a` and `b`, saturating on overflow.
+#[inline]
+#[cfg_attr(test, assert_instr(addq_s.w))]
+pub unsafe fn __mips_addq_s_w(a: i32, b: i32) -> i32 {
+    dsp!(dsp, "addq_s.w {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Unsigned Add
+///
+/// Adds the unsigned bytes in `a` and `b`, wrapping on overflow.
+#[inline]
+#[cfg_attr(test, assert_instr(addu.qb))]
+pub unsafe fn __mips_addu_qb(a: v4i8, b: v4i8) -> v4i8 {
+    dsp!(dsp, "addu.qb {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Unsigned Saturating Add
+///
+/// Adds the unsigned bytes in `a` and `b`, saturating on overflow.
+#[inline]
+#[cfg_attr(test, assert_instr(addu_s.qb))]
+pub unsafe fn __mips_addu_s_qb(a: v4i8, b: v4i8) -> v4i8 {
+    dsp!(dsp, "addu_s.qb {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Fractional Subtract
+///
+/// Subtracts the Q15 fractions in `b` from those in `a`, wrapping on
+/// overflow.
+#[inline]
+#[cfg_attr(test, assert_instr(subq.ph))]
+pub unsafe fn __mips_subq_ph(a: v2q15, b: v2q15) -> v2q15 {
+    dsp!(dsp, "subq.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Fractional Saturating Subtract
+///
+/// Subtracts the Q15 fractions in `b` from those in `a`, saturating on
+/// overflow.
+#[inline]
+#[cfg_attr(test, assert_instr(subq_s.ph))]
+pub unsafe fn __mips_subq_s_ph(a: v2q15, b: v2q15) -> v2q15 {
+    dsp!(dsp, "subq_s.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Fractional Saturating Subtract
+///
+/// Subtracts the Q31 fraction `b` from `a`, saturating on overflow.
+#[inline]
+#[cfg_attr(test, assert_instr(subq_s.w))]
+pub unsafe fn __mips_subq_s_w(a: i32, b: i32) -> i32 {
+    dsp!(dsp, "subq_s.w {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Unsigned Subtract
+///
+/// Subtracts the unsigned bytes in `b` from those in `a`, wrapping on
+/// overflow.
+#[inline]
+#[cfg_attr(test, assert_instr(subu.qb))]
+pub unsafe fn __mips_subu_qb(a: v4i8, b: v4i8) -> v4i8 {
+    dsp!(dsp, "subu.qb {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Unsigned Saturating Subtract
+///
+/// Subtracts the unsigned bytes in `b` from those in `a`, saturating on
+/// overflow.
+#[inline]
+#[cfg_attr(test, assert_instr(subu_s.qb))]
+pub unsafe fn __mips_subu_s_qb(a: v4i8, b: v4i8) -> v4i8 {
+    dsp!(dsp, "subu_s.qb {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Add Setting Carry
+///
+/// Adds `a` and `b`, and stores the carry out in `DSPControl`.
+#[inline]
+#[cfg_attr(test, assert_instr(addsc))]
+pub unsafe fn __mips_addsc(a: i32, b: i32) -> i32 {
+    dsp!(dsp, "addsc {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Add With Carry
+///
+/// Adds `a`, `b` and the carry stored by [`__mips_addsc`].
+#[inline]
+#[cfg_attr(test, assert_instr(addwc))]
+pub unsafe fn __mips_addwc(a: i32, b: i32) -> i32 {
+    dsp!(dsp, "addwc {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Modular Subtraction
+///
+/// Returns bits 23 to 8 of `b` if `a` is `0`, or `a` minus the low byte of
+/// `b` otherwise, which decrements a circular buffer index.
+#[inline]
+#[cfg_attr(test, assert_instr(modsub))]
+pub unsafe fn __mips_modsub(a: i32, b: i32) -> i32 {
+    dsp!(dsp, "modsub {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Unsigned Reduction Add
+///
+/// Returns the sum of the unsigned bytes in `a`.
+#[inline]
+#[cfg_attr(test, assert_instr(raddu.w.qb))]
+pub unsafe fn __mips_raddu_w_qb(a: v4i8) -> i32 {
+    dsp!(dsp, "raddu.w.qb {r}, {a}", a = a)
+}
+
+/// Vector Fractional Saturating Absolute Value
+///
+/// Returns the absolute values of the Q15 fractions in `a`, saturating
+/// `-1.0` to the largest positive value.
+#[inline]
+#[cfg_attr(test, assert_instr(absq_s.ph))]
+pub unsafe fn __mips_absq_s_ph(a: v2q15) -> v2q15 {
+    dsp!(dsp, "absq_s.ph {r}, {a}", a = a)
+}
+
+/// Fractional Saturating Absolute Value
+///
+/// Returns the absolute value of the Q31 fraction `a`, saturating `-1.0` to
+/// the largest positive value.
+#[inline]
+#[cfg_attr(test, assert_instr(absq_s.w))]
+pub unsafe fn __mips_absq_s_w(a: i32) -> i32 {
+    dsp!(dsp, "absq_s.w {r}, {a}", a = a)
+}
+
+/// Precision Reduce Fractional Halfwords to Bytes
+///
+/// Packs the upper bytes of the halfwords of `a` and then `b`, from left to
+/// right.
+#[inline]
+#[cfg_attr(test, assert_instr(precrq.qb.ph))]
+pub unsafe fn __mips_precrq_qb_ph(a: v2q15, b: v2q15) -> v4i8 {
+    dsp!(dsp, "precrq.qb.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Precision Reduce Fractional Words to Halfwords
+///
+/// Packs the upper halfwords of `a` and `b`, from left to right.
+#[inline]
+#[cfg_attr(test, assert_instr(precrq.ph.w))]
+pub unsafe fn __mips_precrq_ph_w(a: i32, b: i32) -> v2q15 {
+    dsp!(dsp, "precrq.ph.w {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Precision Reduce Fractional Words to Halfwords With Rounding and
+/// Saturation
+///
+/// Rounds the Q31 fractions `a` and `b` to Q15 fractions, and packs them
+/// from left to right.
+#[inline]
+#[cfg_attr(test, assert_instr(precrq_rs.ph.w))]
+pub unsafe fn __mips_precrq_rs_ph_w(a: i32, b: i32) -> v2q15 {
+    dsp!(dsp, "precrq_rs.ph.w {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Precision Reduce Fractional Halfwords to Unsigned Bytes With Saturation
+///
+/// Converts the Q15 fractions of `a` and then `b` to unsigned bytes,
+/// clamping negative values to `0`, and packs them from left to right.
+#[inline]
+#[cfg_attr(test, assert_instr(precrqu_s.qb.ph))]
+pub unsafe fn __mips_precrqu_s_qb_ph(a: v2q15, b: v2q15) -> v4i8 {
+    dsp!(dsp, "precrqu_s.qb.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Precision Expand Fractional Halfword to Word, left
+///
+/// Converts the left Q15 fraction of `a` to a Q31 fraction.
+#[inline]
+#[cfg_attr(test, assert_instr(preceq.w.phl))]
+pub unsafe fn __mips_preceq_w_phl(a: v2q15) -> i32 {
+    dsp!(dsp, "preceq.w.phl {r}, {a}", a = a)
+}
+
+/// Precision Expand Fractional Halfword to Word, right
+///
+/// Converts the right Q15 fraction of `a` to a Q31 fraction.
+#[inline]
+#[cfg_attr(test, assert_instr(preceq.w.phr))]
+pub unsafe fn __mips_preceq_w_phr(a: v2q15) -> i32 {
+    dsp!(dsp, "preceq.w.phr {r}, {a}", a = a)
+}
+
+/// Precision Expand Unsigned Bytes to Fractional Halfwords, left
+///
+/// Converts the two left unsigned bytes of `a` to Q15 fractions.
+#[inline]
+#[cfg_attr(test, assert_instr(precequ.ph.qbl))]
+pub unsafe fn __mips_precequ_ph_qbl(a: v4i8) -> v2q15 {
+    dsp!(dsp, "precequ.ph.qbl {r}, {a}", a = a)
+}
+
+/// Precision Expand Unsigned Bytes to Fractional Halfwords, right
+///
+/// Converts the two right unsigned bytes of `a` to Q15 fractions.
+#[inline]
+#[cfg_attr(test, assert_instr(precequ.ph.qbr))]
+pub unsafe fn __mips_precequ_ph_qbr(a: v4i8) -> v2q15 {
+    dsp!(dsp, "precequ.ph.qbr {r}, {a}", a = a)
+}
+
+/// Precision Expand Unsigned Bytes to Fractional Halfwords, left alternate
+///
+/// Converts the first and third bytes of `a`, from the left, to Q15
+/// fractions.
+#[inline]
+#[cfg_attr(test, assert_instr(precequ.ph.qbla))]
+pub unsafe fn __mips_precequ_ph_qbla(a: v4i8) -> v2q15 {
+    dsp!(dsp, "precequ.ph.qbla {r}, {a}", a = a)
+}
+
+/// Precision Expand Unsigned Bytes to Fractional Halfwords, right alternate
+///
+/// Converts the second and fourth bytes of `a`, from the left, to Q15
+/// fractions.
+#[inline]
+#[cfg_attr(test, assert_instr(precequ.ph.qbra))]
+pub unsafe fn __mips_precequ_ph_qbra(a: v4i8) -> v2q15 {
+    dsp!(dsp, "precequ.ph.qbra {r}, {a}", a = a)
+}
+
+/// Precision Expand Unsigned Bytes to Unsigned Halfwords, left
+///
+/// Zero-extends the two left bytes of `a`.
+#[inline]
+#[cfg_attr(test, assert_instr(preceu.ph.qbl))]
+pub unsafe fn __mips_preceu_ph_qbl(a: v4i8) -> v2q15 {
+    dsp!(dsp, "preceu.ph.qbl {r}, {a}", a = a)
+}
+
+/// Precision Expand Unsigned Bytes to Unsigned Halfwords, right
+///
+/// Zero-extends the two right bytes of `a`.
+#[inline]
+#[cfg_attr(test, assert_instr(preceu.ph.qbr))]
+pub unsafe fn __mips_preceu_ph_qbr(a: v4i8) -> v2q15 {
+    dsp!(dsp, "preceu.ph.qbr {r}, {a}", a = a)
+}
+
+/// Precision Expand Unsigned Bytes to Unsigned Halfwords, left alternate
+///
+/// Zero-extends the first and third bytes of `a`, from the left.
+#[inline]
+#[cfg_attr(test, assert_instr(preceu.ph.qbla))]
+pub unsafe fn __mips_preceu_ph_qbla(a: v4i8) -> v2q15 {
+    dsp!(dsp, "preceu.ph.qbla {r}, {a}", a = a)
+}
+
+/// Precision Expand Unsigned Bytes to Unsigned Halfwords, right alternate
+///
+/// Zero-extends the second and fourth bytes of `a`, from the left.
+#[inline]
+#[cfg_attr(test, assert_instr(preceu.ph.qbra))]
+pub unsafe fn __mips_preceu_ph_qbra(a: v4i8) -> v2q15 {
+    dsp!(dsp, "preceu.ph.qbra {r}, {a}", a = a)
+}
+
+/// Vector Shift Left Logical
+///
+/// Shifts the bytes of `a` left by `SA` bits.
+#[inline]
+#[cfg_attr(test, assert_instr(shll.qb, SA = 3))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __mips_shll_qb<const SA: i32>(a: v4i8) -> v4i8 {
+    static_assert_uimm_bits!(SA, 3);
+    dsp!(dsp, "shll.qb {r}, {a}, {sa}", a = a; sa = SA)
+}
+
+/// Vector Shift Left Logical
+///
+/// Shifts the halfwords of `a` left by `SA` bits.
+#[inline]
+#[cfg_attr(test, assert_instr(shll.ph, SA = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __mips_shll_ph<const SA: i32>(a: v2q15) -> v2q15 {
+    static_assert_uimm_bits!(SA, 4);
+    dsp!(dsp, "shll.ph {r}, {a}, {sa}", a = a; sa = SA)
+}
+
+/// Vector Saturating Shift Left
+///
+/// Shifts the Q15 fractions of `a` left by `SA` bits, saturating on
+/// overflow.
+#[inline]
+#[cfg_attr(test, assert_instr(shll_s.ph, SA = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __mips_shll_s_ph<const SA: i32>(a: v2q15) -> v2q15 {
+    static_assert_uimm_bits!(SA, 4);
+    dsp!(dsp, "shll_s.ph {r}, {a}, {sa}", a = a; sa = SA)
+}
+
+/// Saturating Shift Left
+///
+/// Shifts the Q31 fraction `a` left by `SA` bits, saturating on overflow.
+#[inline]
+#[cfg_attr(test, assert_instr(shll_s.w, SA = 15))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __mips_shll_s_w<const SA: i32>(a: i32) -> i32 {
+    static_assert_uimm_bits!(SA, 5);
+    dsp!(dsp, "shll_s.w {r}, {a}, {sa}", a = a; sa = SA)
+}
+
+/// Vector Shift Right Logical
+///
+/// Shifts the unsigned bytes of `a` right by `SA` bits.
+#[inline]
+#[cfg_attr(test, assert_instr(shrl.qb, SA = 3))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __mips_shrl_qb<const SA: i32>(a: v4i8) -> v4i8 {
+    static_assert_uimm_bits!(SA, 3);
+    dsp!(dsp, "shrl.qb {r}, {a}, {sa}", a = a; sa = SA)
+}
+
+/// Vector Shift Right Arithmetic
+///
+/// Shifts the signed halfwords of `a` right by `SA` bits.
+#[inline]
+#[cfg_attr(test, assert_instr(shra.ph, SA = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __mips_shra_ph<const SA: i32>(a: v2q15) -> v2q15 {
+    static_assert_uimm_bits!(SA, 4);
+    dsp!(dsp, "shra.ph {r}, {a}, {sa}", a = a; sa = SA)
+}
+
+/// Vector Shift Right Arithmetic With Rounding
+///
+/// Shifts the signed halfwords of `a` right by `SA` bits, rounding to the
+/// nearest value.
+#[inline]
+#[cfg_attr(test, assert_instr(shra_r.ph, SA = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __mips_shra_r_ph<const SA: i32>(a: v2q15) -> v2q15 {
+    static_assert_uimm_bits!(SA, 4);
+    dsp!(dsp, "shra_r.ph {r}, {a}, {sa}", a = a; sa = SA)
+}
+
+/// Shift Right Arithmetic With Rounding
+///
+/// Shifts `a` right by `SA` bits, rounding to the nearest value.
+#[inline]
+#[cfg_attr(test, assert_instr(shra_r.w, SA = 15))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __mips_shra_r_w<const SA: i32>(a: i32) -> i32 {
+    static_assert_uimm_bits!(SA, 5);
+    dsp!(dsp, "shra_r.w {r}, {a}, {sa}", a = a; sa = SA)
+}
+
+/// Vector Unsigned Multiply, left
+///
+/// Multiplies the two left unsigned bytes of `a` by the unsigned halfwords
+/// of `b`, saturating on overflow.
+#[inline]
+#[cfg_attr(test, assert_instr(muleu_s.ph.qbl))]
+pub unsafe fn __mips_muleu_s_ph_qbl(a: v4i8, b: v2q15) -> v2q15 {
+    dsp!(dsp, "muleu_s.ph.qbl {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Unsigned Multiply, right
+///
+/// Multiplies the two right unsigned bytes of `a` by the unsigned halfwords
+/// of `b`, saturating on overflow.
+#[inline]
+#[cfg_attr(test, assert_instr(muleu_s.ph.qbr))]
+pub unsafe fn __mips_muleu_s_ph_qbr(a: v4i8, b: v2q15) -> v2q15 {
+    dsp!(dsp, "muleu_s.ph.qbr {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Fractional Multiply With Rounding
+///
+/// Multiplies the Q15 fractions in `a` and `b`, rounding to the nearest
+/// value and saturating `-1.0 * -1.0`.
+#[inline]
+#[cfg_attr(test, assert_instr(mulq_rs.ph))]
+pub unsafe fn __mips_mulq_rs_ph(a: v2q15, b: v2q15) -> v2q15 {
+    dsp!(dsp, "mulq_rs.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Fractional Multiply to Word, left
+///
+/// Multiplies the left Q15 fractions of `a` and `b` into a Q31 fraction,
+/// saturating `-1.0 * -1.0`.
+#[inline]
+#[cfg_attr(test, assert_instr(muleq_s.w.phl))]
+pub unsafe fn __mips_muleq_s_w_phl(a: v2q15, b: v2q15) -> i32 {
+    dsp!(dsp, "muleq_s.w.phl {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Fractional Multiply to Word, right
+///
+/// Multiplies the right Q15 fractions of `a` and `b` into a Q31 fraction,
+/// saturating `-1.0 * -1.0`.
+#[inline]
+#[cfg_attr(test, assert_instr(muleq_s.w.phr))]
+pub unsafe fn __mips_muleq_s_w_phr(a: v2q15, b: v2q15) -> i32 {
+    dsp!(dsp, "muleq_s.w.phr {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Unsigned Dot Product and Add, left
+///
+/// Adds the products of the two left unsigned bytes of `a` and `b` to
+/// `acc`.
+#[inline]
+#[cfg_attr(test, assert_instr(dpau.h.qbl))]
+pub unsafe fn __mips_dpau_h_qbl(acc: i64, a: v4i8, b: v4i8) -> i64 {
+    dsp_acc!(dsp, "dpau.h.qbl $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Unsigned Dot Product and Add, right
+///
+/// Adds the products of the two right unsigned bytes of `a` and `b` to
+/// `acc`.
+#[inline]
+#[cfg_attr(test, assert_instr(dpau.h.qbr))]
+pub unsafe fn __mips_dpau_h_qbr(acc: i64, a: v4i8, b: v4i8) -> i64 {
+    dsp_acc!(dsp, "dpau.h.qbr $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Unsigned Dot Product and Subtract, left
+///
+/// Subtracts the products of the two left unsigned bytes of `a` and `b`
+/// from `acc`.
+#[inline]
+#[cfg_attr(test, assert_instr(dpsu.h.qbl))]
+pub unsafe fn __mips_dpsu_h_qbl(acc: i64, a: v4i8, b: v4i8) -> i64 {
+    dsp_acc!(dsp, "dpsu.h.qbl $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Unsigned Dot Product and Subtract, right
+///
+/// Subtracts the products of the two right unsigned bytes of `a` and `b`
+/// from `acc`.
+#[inline]
+#[cfg_attr(test, assert_instr(dpsu.h.qbr))]
+pub unsafe fn __mips_dpsu_h_qbr(acc: i64, a: v4i8, b: v4i8) -> i64 {
+    dsp_acc!(dsp, "dpsu.h.qbr $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Fractional Dot Product and Add
+///
+/// Adds the Q31 products of the Q15 fractions in `a` and `b` to `acc`,
+/// saturating `-1.0 * -1.0`.
+#[inline]
+#[cfg_attr(test, assert_instr(dpaq_s.w.ph))]
+pub unsafe fn __mips_dpaq_s_w_ph(acc: i64, a: v2q15, b: v2q15) -> i64 {
+    dsp_acc!(dsp, "dpaq_s.w.ph $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Fractional Multiply and Add With Saturation
+///
+/// Adds the Q63 product of the Q31 fractions `a` and `b` to `acc`,
+/// saturating the result.
+#[inline]
+#[cfg_attr(test, assert_instr(dpaq_sa.l.w))]
+pub unsafe fn __mips_dpaq_sa_l_w(acc: i64, a: i32, b: i32) -> i64 {
+    dsp_acc!(dsp, "dpaq_sa.l.w $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Fractional Dot Product and Subtract
+///
+/// Subtracts the Q31 products of the Q15 fractions in `a` and `b` from
+/// `acc`, saturating `-1.0 * -1.0`.
+#[inline]
+#[cfg_attr(test, assert_instr(dpsq_s.w.ph))]
+pub unsafe fn __mips_dpsq_s_w_ph(acc: i64, a: v2q15, b: v2q15) -> i64 {
+    dsp_acc!(dsp, "dpsq_s.w.ph $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Fractional Multiply and Subtract With Saturation
+///
+/// Subtracts the Q63 product of the Q31 fractions `a` and `b` from `acc`,
+/// saturating the result.
+#[inline]
+#[cfg_attr(test, assert_instr(dpsq_sa.l.w))]
+pub unsafe fn __mips_dpsq_sa_l_w(acc: i64, a: i32, b: i32) -> i64 {
+    dsp_acc!(dsp, "dpsq_sa.l.w $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Fractional Multiply, Subtract and Add
+///
+/// Adds the Q31 product of the left Q15 fractions of `a` and `b` to `acc`,
+/// and subtracts the product of the right ones.
+#[inline]
+#[cfg_attr(test, assert_instr(mulsaq_s.w.ph))]
+pub unsafe fn __mips_mulsaq_s_w_ph(acc: i64, a: v2q15, b: v2q15) -> i64 {
+    dsp_acc!(dsp, "mulsaq_s.w.ph $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Fractional Multiply and Add, left
+///
+/// Adds the Q31 product of the left Q15 fractions of `a` and `b` to `acc`.
+#[inline]
+#[cfg_attr(test, assert_instr(maq_s.w.phl))]
+pub unsafe fn __mips_maq_s_w_phl(acc: i64, a: v2q15, b: v2q15) -> i64 {
+    dsp_acc!(dsp, "maq_s.w.phl $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Fractional Multiply and Add, right
+///
+/// Adds the Q31 product of the right Q15 fractions of `a` and `b` to `acc`.
+#[inline]
+#[cfg_attr(test, assert_instr(maq_s.w.phr))]
+pub unsafe fn __mips_maq_s_w_phr(acc: i64, a: v2q15, b: v2q15) -> i64 {
+    dsp_acc!(dsp, "maq_s.w.phr $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Fractional Multiply and Saturating Add, left
+///
+/// Adds the Q31 product of the left Q15 fractions of `a` and `b` to `acc`,
+/// saturating the result to a Q31 fraction.
+#[inline]
+#[cfg_attr(test, assert_instr(maq_sa.w.phl))]
+pub unsafe fn __mips_maq_sa_w_phl(acc: i64, a: v2q15, b: v2q15) -> i64 {
+    dsp_acc!(dsp, "maq_sa.w.phl $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Fractional Multiply and Saturating Add, right
+///
+/// Adds the Q31 product of the right Q15 fractions of `a` and `b` to `acc`,
+/// saturating the result to a Q31 fraction.
+#[inline]
+#[cfg_attr(test, assert_instr(maq_sa.w.phr))]
+pub unsafe fn __mips_maq_sa_w_phr(acc: i64, a: v2q15, b: v2q15) -> i64 {
+    dsp_acc!(dsp, "maq_sa.w.phr $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Bit Reverse
+///
+/// Reverses the order of the low 16 bits of `a`, and clears the others.
+#[inline]
+#[cfg_attr(test, assert_instr(bitrev))]
+pub unsafe fn __mips_bitrev(a: i32) -> i32 {
+    dsp!(dsp, "bitrev {r}, {a}", a = a)
+}
+
+/// Insert Bit Field
+///
+/// Inserts the low bits of `b` into `a`, at the position and with the size
+/// in `DSPControl`.
+#[inline]
+#[cfg_attr(test, assert_instr(insv))]
+pub unsafe fn __mips_insv(a: i32, b: i32) -> i32 {
+    dsp!(dsp, "insv {r}, {b}", r <- a, b = b)
+}
+
+/// Replicate Immediate Byte
+///
+/// Returns a vector with all bytes set to `IMM`.
+#[inline]
+#[cfg_attr(test, assert_instr(repl.qb, IMM = 0x5a))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn __mips_repl_qb<const IMM: i32>() -> v4i8 {
+    static_assert_uimm_bits!(IMM, 8);
+    dsp!(dsp, "repl.qb {r}, {imm}"; imm = IMM)
+}
+
+/// Replicate Immediate Halfword
+///
+/// Returns a vector with both halfwords set to `IMM`, sign-extended.
+#[inline]
+#[cfg_attr(test, assert_instr(repl.ph, IMM = -0x15a))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn __mips_repl_ph<const IMM: i32>() -> v2q15 {
+    static_assert_simm_bits!(IMM, 10);
+    dsp!(dsp, "repl.ph {r}, {imm}"; imm = IMM)
+}
+
+/// Vector Unsigned Compare Equal
+///
+/// Sets the condition bits in `DSPControl` for the bytes of `a` that are
+/// equal to those in `b`.
+#[inline]
+#[cfg_attr(test, assert_instr(cmpu.eq.qb))]
+pub unsafe fn __mips_cmpu_eq_qb(a: v4i8, b: v4i8) {
+    dsp!(void dsp, "cmpu.eq.qb {a}, {b}", a = a, b = b)
+}
+
+/// Vector Unsigned Compare Less Than
+///
+/// Sets the condition bits in `DSPControl` for the unsigned bytes of `a`
+/// that are less than those in `b`.
+#[inline]
+#[cfg_attr(test, assert_instr(cmpu.lt.qb))]
+pub unsafe fn __mips_cmpu_lt_qb(a: v4i8, b: v4i8) {
+    dsp!(void dsp, "cmpu.lt.qb {a}, {b}", a = a, b = b)
+}
+
+/// Vector Unsigned Compare Less Than or Equal
+///
+/// Sets the condition bits in `DSPControl` for the unsigned bytes of `a`
+/// that are less than or equal to those in `b`.
+#[inline]
+#[cfg_attr(test, assert_instr(cmpu.le.qb))]
+pub unsafe fn __mips_cmpu_le_qb(a: v4i8, b: v4i8) {
+    dsp!(void dsp, "cmpu.le.qb {a}, {b}", a = a, b = b)
+}
+
+/// Vector Unsigned Compare Equal to GPR
+///
+/// Returns a mask with bit `i` set if byte `i` of `a`, counted from the
+/// right, is equal to that of `b`.
+#[inline]
+#[cfg_attr(test, assert_instr(cmpgu.eq.qb))]
+pub unsafe fn __mips_cmpgu_eq_qb(a: v4i8, b: v4i8) -> i32 {
+    dsp!(dsp, "cmpgu.eq.qb {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Unsigned Compare Less Than to GPR
+///
+/// Returns a mask with bit `i` set if unsigned byte `i` of `a`, counted from
+/// the right, is less than that of `b`.
+#[inline]
+#[cfg_attr(test, assert_instr(cmpgu.lt.qb))]
+pub unsafe fn __mips_cmpgu_lt_qb(a: v4i8, b: v4i8) -> i32 {
+    dsp!(dsp, "cmpgu.lt.qb {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Unsigned Compare Less Than or Equal to GPR
+///
+/// Returns a mask with bit `i` set if unsigned byte `i` of `a`, counted from
+/// the right, is less than or equal to that of `b`.
+#[inline]
+#[cfg_attr(test, assert_instr(cmpgu.le.qb))]
+pub unsafe fn __mips_cmpgu_le_qb(a: v4i8, b: v4i8) -> i32 {
+    dsp!(dsp, "cmpgu.le.qb {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Compare Equal
+///
+/// Sets the condition bits in `DSPControl` for the halfwords of `a` that
+/// are equal to those in `b`.
+#[inline]
+#[cfg_attr(test, assert_instr(cmp.eq.ph))]
+pub unsafe fn __mips_cmp_eq_ph(a: v2q15, b: v2q15) {
+    dsp!(void dsp, "cmp.eq.ph {a}, {b}", a = a, b = b)
+}
+
+/// Vector Compare Less Than
+///
+/// Sets the condition bits in `DSPControl` for the signed halfwords of `a`
+/// that are less than those in `b`.
+#[inline]
+#[cfg_attr(test, assert_instr(cmp.lt.ph))]
+pub unsafe fn __mips_cmp_lt_ph(a: v2q15, b: v2q15) {
+    dsp!(void dsp, "cmp.lt.ph {a}, {b}", a = a, b = b)
+}
+
+/// Vector Compare Less Than or Equal
+///
+/// Sets the condition bits in `DSPControl` for the signed halfwords of `a`
+/// that are less than or equal to those in `b`.
+#[inline]
+#[cfg_attr(test, assert_instr(cmp.le.ph))]
+pub unsafe fn __mips_cmp_le_ph(a: v2q15, b: v2q15) {
+    dsp!(void dsp, "cmp.le.ph {a}, {b}", a = a, b = b)
+}
+
+/// Vector Pick Bytes
+///
+/// Selects the bytes of `a` whose condition bits in `DSPControl` are set,
+/// and those of `b` otherwise.
+#[inline]
+#[cfg_attr(test, assert_instr(pick.qb))]
+pub unsafe fn __mips_pick_qb(a: v4i8, b: v4i8) -> v4i8 {
+    dsp!(dsp, "pick.qb {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Pick Halfwords
+///
+/// Selects the halfwords of `a` whose condition bits in `DSPControl` are
+/// set, and those of `b` otherwise.
+#[inline]
+#[cfg_attr(test, assert_instr(pick.ph))]
+pub unsafe fn __mips_pick_ph(a: v2q15, b: v2q15) -> v2q15 {
+    dsp!(dsp, "pick.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Pack Halfwords
+///
+/// Packs the right halfword of `a` and the left halfword of `b`, from left
+/// to right.
+#[inline]
+#[cfg_attr(test, assert_instr(packrl.ph))]
+pub unsafe fn __mips_packrl_ph(a: v2q15, b: v2q15) -> v2q15 {
+    dsp!(dsp, "packrl.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Extract Word From Accumulator
+///
+/// Returns the low word of `acc` shifted right by `SHIFT` bits.
+#[inline]
+#[cfg_attr(test, assert_instr(extr.w, SHIFT = 15))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __mips_extr_w<const SHIFT: i32>(acc: i64) -> i32 {
+    static_assert_uimm_bits!(SHIFT, 5);
+    dsp_acc!(dsp, "extr.w {r}, $ac0, {shift}", acc => r; shift = SHIFT)
+}
+
+/// Extract Word From Accumulator With Rounding
+///
+/// Returns the low word of `acc` shifted right by `SHIFT` bits, rounding to
+/// the nearest value.
+#[inline]
+#[cfg_attr(test, assert_instr(extr_r.w, SHIFT = 15))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __mips_extr_r_w<const SHIFT: i32>(acc: i64) -> i32 {
+    static_assert_uimm_bits!(SHIFT, 5);
+    dsp_acc!(dsp, "extr_r.w {r}, $ac0, {shift}", acc => r; shift = SHIFT)
+}
+
+/// Extract Word From Accumulator With Rounding and Saturation
+///
+/// Returns `acc` shifted right by `SHIFT` bits, rounding to the nearest
+/// value and saturating it to a word.
+#[inline]
+#[cfg_attr(test, assert_instr(extr_rs.w, SHIFT = 15))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __mips_extr_rs_w<const SHIFT: i32>(acc: i64) -> i32 {
+    static_assert_uimm_bits!(SHIFT, 5);
+    dsp_acc!(dsp, "extr_rs.w {r}, $ac0, {shift}", acc => r; shift = SHIFT)
+}
+
+/// Extract Halfword From Accumulator With Saturation
+///
+/// Returns `acc` shifted right by `SHIFT` bits, saturated to a signed
+/// halfword.
+#[inline]
+#[cfg_attr(test, assert_instr(extr_s.h, SHIFT = 15))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __mips_extr_s_h<const SHIFT: i32>(acc: i64) -> i32 {
+    static_assert_uimm_bits!(SHIFT, 5);
+    dsp_acc!(dsp, "extr_s.h {r}, $ac0, {shift}", acc => r; shift = SHIFT)
+}
+
+/// Extract Bit Field From Accumulator
+///
+/// Returns the `SIZE + 1` bits of `acc` ending at the position in
+/// `DSPControl`.
+#[inline]
+#[cfg_attr(test, assert_instr(extp, SIZE = 15))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __mips_extp<const SIZE: i32>(acc: i64) -> i32 {
+    static_assert_uimm_bits!(SIZE, 5);
+    dsp_acc!(dsp, "extp {r}, $ac0, {size}", acc => r; size = SIZE)
+}
+
+/// Extract Bit Field From Accumulator and Decrement Position
+///
+/// Returns the `SIZE + 1` bits of `acc` ending at the position in
+/// `DSPControl`, and then decrements the position by `SIZE + 1`.
+#[inline]
+#[cfg_attr(test, assert_instr(extpdp, SIZE = 15))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __mips_extpdp<const SIZE: i32>(acc: i64) -> i32 {
+    static_assert_uimm_bits!(SIZE, 5);
+    dsp_acc!(dsp, "extpdp {r}, $ac0, {size}", acc => r; size = SIZE)
+}
+
+/// Shift Accumulator
+///
+/// Shifts `acc` right by `SHIFT` bits, or left if `SHIFT` is negative.
+#[inline]
+#[cfg_attr(test, assert_instr(shilo, SHIFT = -7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __mips_shilo<const SHIFT: i32>(acc: i64) -> i64 {
+    static_assert_simm_bits!(SHIFT, 6);
+    dsp_acc!(dsp, "shilo $ac0, {shift}", acc; shift = SHIFT)
+}
+
+/// Copy to Accumulator and Increment Position
+///
+/// Shifts the low word of `acc` to the high one, replaces the low word with
+/// `a`, and increments the position in `DSPControl` by 32.
+#[inline]
+#[cfg_attr(test, assert_instr(mthlip))]
+pub unsafe fn __mips_mthlip(acc: i64, a: i32) -> i64 {
+    dsp_acc!(dsp, "mthlip {a}, $ac0", acc, a = a)
+}
+
+/// Write Fields to DSPControl
+///
+/// Writes the fields of `DSPControl` selected by `MASK` from `a`.
+#[inline]
+#[cfg_attr(test, assert_instr(wrdsp, MASK = 31))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __mips_wrdsp<const MASK: i32>(a: i32) {
+    static_assert_uimm_bits!(MASK, 6);
+    asm!(
+        ".set push",
+        ".set dsp",
+        "wrdsp {a}, {mask}",
+        ".set pop",
+        a = in(reg) a,
+        mask = const MASK,
+        options(nomem, nostack),
+    )
+}
+
+/// Read Fields From DSPControl
+///
+/// Reads the fields of `DSPControl` selected by `MASK`, and clears the
+/// others.
+#[inline]
+#[cfg_attr(test, assert_instr(rddsp, MASK = 31))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn __mips_rddsp<const MASK: i32>() -> i32 {
+    static_assert_uimm_bits!(MASK, 6);
+    dsp!(dsp, "rddsp {r}, {mask}"; mask = MASK)
+}
+
+/// Load Unsigned Byte Indexed
+///
+/// Loads the unsigned byte at `index` bytes from `base`.
+#[inline]
+#[cfg_attr(test, assert_instr(lbux))]
+pub unsafe fn __mips_lbux(base: *mut u8, index: i32) -> i32 {
+    let r: i32;
+    asm!(
+        ".set push",
+        ".set dsp",
+        "lbux {r}, {index}({base})",
+        ".set pop",
+        r = lateout(reg) r,
+        base = in(reg) base,
+        index = in(reg) index,
+        options(readonly, nostack),
+    );
+    r
+}
+
+/// Load Halfword Indexed
+///
+/// Loads the signed halfword at `index` bytes from `base`, which has to be
+/// aligned.
+#[inline]
+#[cfg_attr(test, assert_instr(lhx))]
+pub unsafe fn __mips_lhx(base: *mut u8, index: i32) -> i32 {
+    let r: i32;
+    asm!(
+        ".set push",
+        ".set dsp",
+        "lhx {r}, {index}({base})",
+        ".set pop",
+        r = lateout(reg) r,
+        base = in(reg) base,
+        index = in(reg) index,
+        options(readonly, nostack),
+    );
+    r
+}
+
+/// Load Word Indexed
+///
+/// Loads the word at `index` bytes from `base`, which has to be aligned.
+#[inline]
+#[cfg_attr(test, assert_instr(lwx))]
+pub unsafe fn __mips_lwx(base: *mut u8, index: i32) -> i32 {
+    let r: i32;
+    asm!(
+        ".set push",
+        ".set dsp",
+        "lwx {r}, {index}({base})",
+        ".set pop",
+        r = lateout(reg) r,
+        base = in(reg) base,
+        index = in(reg) index,
+        options(readonly, nostack),
+    );
+    r
+}
+
+/// Branch on Greater Than or Equal To Value 32 in DSPControl Pos Field
+///
+/// Returns `1` if the position in `DSPControl` is at least 32, and `0`
+/// otherwise.
+#[inline]
+#[cfg_attr(test, assert_instr(bposge32))]
+pub unsafe fn __mips_bposge32() -> i32 {
+    let r: i32;
+    asm!(
+        ".set push",
+        ".set dsp",
+        ".set noreorder",
+        "li {r}, 1",
+        "bposge32 2f",
+        "nop",
+        "li {r}, 0",
+        "2:",
+        ".set pop",
+        r = out(reg) r,
+        options(nomem, nostack),
+    );
+    r
+}
+
+/// Multiply and Add
+///
+/// Adds the product of `a` and `b` to `acc`.
+#[inline]
+#[cfg_attr(test, assert_instr(madd))]
+pub unsafe fn __mips_madd(acc: i64, a: i32, b: i32) -> i64 {
+    dsp_acc!(dsp, "madd $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Multiply Unsigned and Add
+///
+/// Adds the unsigned product of `a` and `b` to `acc`.
+#[inline]
+#[cfg_attr(test, assert_instr(maddu))]
+pub unsafe fn __mips_maddu(acc: i64, a: u32, b: u32) -> i64 {
+    dsp_acc!(dsp, "maddu $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Multiply and Subtract
+///
+/// Subtracts the product of `a` and `b` from `acc`.
+#[inline]
+#[cfg_attr(test, assert_instr(msub))]
+pub unsafe fn __mips_msub(acc: i64, a: i32, b: i32) -> i64 {
+    dsp_acc!(dsp, "msub $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Multiply Unsigned and Subtract
+///
+/// Subtracts the unsigned product of `a` and `b` from `acc`.
+#[inline]
+#[cfg_attr(test, assert_instr(msubu))]
+pub unsafe fn __mips_msubu(acc: i64, a: u32, b: u32) -> i64 {
+    dsp_acc!(dsp, "msubu $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Multiply Word
+///
+/// Returns the 64-bit product of `a` and `b`.
+#[inline]
+#[cfg_attr(test, assert_instr(mult))]
+pub unsafe fn __mips_mult(a: i32, b: i32) -> i64 {
+    dsp_acc!(dsp, "mult $ac0, {a}, {b}", 0, a = a, b = b)
+}
+
+/// Multiply Unsigned Word
+///
+/// Returns the 64-bit unsigned product of `a` and `b`.
+#[inline]
+#[cfg_attr(test, assert_instr(multu))]
+pub unsafe fn __mips_multu(a: u32, b: u32) -> i64 {
+    dsp_acc!(dsp, "multu $ac0, {a}, {b}", 0, a = a, b = b)
+}
+
+/// Vector Saturating Absolute Value
+///
+/// Returns the absolute values of the Q7 fractions in `a`, saturating
+/// `-1.0` to the largest positive value.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(absq_s.qb))]
+pub unsafe fn __mips_absq_s_qb(a: v4q7) -> v4q7 {
+    dsp!(dspr2, "absq_s.qb {r}, {a}", a = a)
+}
+
+/// Vector Unsigned Add
+///
+/// Adds the unsigned halfwords in `a` and `b`, wrapping on overflow.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(addu.ph))]
+pub unsafe fn __mips_addu_ph(a: v2i16, b: v2i16) -> v2i16 {
+    dsp!(dspr2, "addu.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Unsigned Saturating Add
+///
+/// Adds the unsigned halfwords in `a` and `b`, saturating on overflow.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(addu_s.ph))]
+pub unsafe fn __mips_addu_s_ph(a: v2i16, b: v2i16) -> v2i16 {
+    dsp!(dspr2, "addu_s.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Unsigned Halving Add
+///
+/// Adds the unsigned bytes in `a` and `b`, and halves the sums.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(adduh.qb))]
+pub unsafe fn __mips_adduh_qb(a: v4i8, b: v4i8) -> v4i8 {
+    dsp!(dspr2, "adduh.qb {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Unsigned Halving Add With Rounding
+///
+/// Adds the unsigned bytes in `a` and `b`, and halves the sums, rounding to
+/// the nearest value.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(adduh_r.qb))]
+pub unsafe fn __mips_adduh_r_qb(a: v4i8, b: v4i8) -> v4i8 {
+    dsp!(dspr2, "adduh_r.qb {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Append Bits
+///
+/// Shifts `a` left by `SA` bits, and inserts the low `SA` bits of `b`.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(append, SA = 15))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn __mips_append<const SA: i32>(a: i32, b: i32) -> i32 {
+    static_assert_uimm_bits!(SA, 5);
+    dsp!(dspr2, "append {r}, {b}, {sa}", r <- a, b = b; sa = SA)
+}
+
+/// Byte Align
+///
+/// Shifts `a` left by `BP` bytes, and inserts the high `BP` bytes of `b`.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(balign, BP = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn __mips_balign<const BP: i32>(a: i32, b: i32) -> i32 {
+    static_assert_uimm_bits!(BP, 2);
+    dsp!(dspr2, "balign {r}, {b}, {bp}", r <- a, b = b; bp = BP)
+}
+
+/// Vector Unsigned Compare Equal to GPR and DSPControl
+///
+/// Like [`__mips_cmpgu_eq_qb`], and also sets the condition bits in
+/// `DSPControl`.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(cmpgdu.eq.qb))]
+pub unsafe fn __mips_cmpgdu_eq_qb(a: v4i8, b: v4i8) -> i32 {
+    dsp!(dspr2, "cmpgdu.eq.qb {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Unsigned Compare Less Than to GPR and DSPControl
+///
+/// Like [`__mips_cmpgu_lt_qb`], and also sets the condition bits in
+/// `DSPControl`.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(cmpgdu.lt.qb))]
+pub unsafe fn __mips_cmpgdu_lt_qb(a: v4i8, b: v4i8) -> i32 {
+    dsp!(dspr2, "cmpgdu.lt.qb {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Unsigned Compare Less Than or Equal to GPR and DSPControl
+///
+/// Like [`__mips_cmpgu_le_qb`], and also sets the condition bits in
+/// `DSPControl`.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(cmpgdu.le.qb))]
+pub unsafe fn __mips_cmpgdu_le_qb(a: v4i8, b: v4i8) -> i32 {
+    dsp!(dspr2, "cmpgdu.le.qb {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Dot Product and Add
+///
+/// Adds the products of the signed halfwords in `a` and `b` to `acc`.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(dpa.w.ph))]
+pub unsafe fn __mips_dpa_w_ph(acc: i64, a: v2i16, b: v2i16) -> i64 {
+    dsp_acc!(dspr2, "dpa.w.ph $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Dot Product and Subtract
+///
+/// Subtracts the products of the signed halfwords in `a` and `b` from
+/// `acc`.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(dps.w.ph))]
+pub unsafe fn __mips_dps_w_ph(acc: i64, a: v2i16, b: v2i16) -> i64 {
+    dsp_acc!(dspr2, "dps.w.ph $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Vector Multiply
+///
+/// Multiplies the signed halfwords in `a` and `b`, wrapping on overflow.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(mul.ph))]
+pub unsafe fn __mips_mul_ph(a: v2i16, b: v2i16) -> v2i16 {
+    dsp!(dspr2, "mul.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Saturating Multiply
+///
+/// Multiplies the signed halfwords in `a` and `b`, saturating on overflow.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(mul_s.ph))]
+pub unsafe fn __mips_mul_s_ph(a: v2i16, b: v2i16) -> v2i16 {
+    dsp!(dspr2, "mul_s.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Fractional Multiply With Rounding
+///
+/// Multiplies the Q31 fractions `a` and `b`, rounding to the nearest value
+/// and saturating `-1.0 * -1.0`.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(mulq_rs.w))]
+pub unsafe fn __mips_mulq_rs_w(a: i32, b: i32) -> i32 {
+    dsp!(dspr2, "mulq_rs.w {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Fractional Multiply
+///
+/// Multiplies the Q15 fractions in `a` and `b`, saturating `-1.0 * -1.0`.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(mulq_s.ph))]
+pub unsafe fn __mips_mulq_s_ph(a: v2q15, b: v2q15) -> v2q15 {
+    dsp!(dspr2, "mulq_s.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Fractional Multiply
+///
+/// Multiplies the Q31 fractions `a` and `b`, saturating `-1.0 * -1.0`.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(mulq_s.w))]
+pub unsafe fn __mips_mulq_s_w(a: i32, b: i32) -> i32 {
+    dsp!(dspr2, "mulq_s.w {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Multiply, Subtract and Add
+///
+/// Adds the product of the left signed halfwords of `a` and `b` to `acc`,
+/// and subtracts the product of the right ones.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(mulsa.w.ph))]
+pub unsafe fn __mips_mulsa_w_ph(acc: i64, a: v2i16, b: v2i16) -> i64 {
+    dsp_acc!(dspr2, "mulsa.w.ph $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Precision Reduce Halfwords to Bytes
+///
+/// Packs the low bytes of the halfwords of `a` and then `b`, from left to
+/// right.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(precr.qb.ph))]
+pub unsafe fn __mips_precr_qb_ph(a: v2i16, b: v2i16) -> v4i8 {
+    dsp!(dspr2, "precr.qb.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Precision Reduce Words to Halfwords After a Right Shift
+///
+/// Shifts `a` and `b` right by `SA` bits, and packs their low halfwords
+/// from left to right.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(precr_sra.ph.w, SA = 15))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn __mips_precr_sra_ph_w<const SA: i32>(a: i32, b: i32) -> v2i16 {
+    static_assert_uimm_bits!(SA, 5);
+    dsp!(dspr2, "precr_sra.ph.w {r}, {b}, {sa}", r <- a, b = b; sa = SA)
+}
+
+/// Precision Reduce Words to Halfwords After a Rounding Right Shift
+///
+/// Shifts `a` and `b` right by `SA` bits, rounding to the nearest value,
+/// and packs their low halfwords from left to right.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(precr_sra_r.ph.w, SA = 15))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn __mips_precr_sra_r_ph_w<const SA: i32>(a: i32, b: i32) -> v2i16 {
+    static_assert_uimm_bits!(SA, 5);
+    dsp!(dspr2, "precr_sra_r.ph.w {r}, {b}, {sa}", r <- a, b = b; sa = SA)
+}
+
+/// Prepend Bits
+///
+/// Shifts `a` right by `SA` bits, and inserts the low `SA` bits of `b` on
+/// the left.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(prepend, SA = 15))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn __mips_prepend<const SA: i32>(a: i32, b: i32) -> i32 {
+    static_assert_uimm_bits!(SA, 5);
+    dsp!(dspr2, "prepend {r}, {b}, {sa}", r <- a, b = b; sa = SA)
+}
+
+/// Vector Shift Right Arithmetic
+///
+/// Shifts the signed bytes of `a` right by `SA` bits.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(shra.qb, SA = 3))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __mips_shra_qb<const SA: i32>(a: v4i8) -> v4i8 {
+    static_assert_uimm_bits!(SA, 3);
+    dsp!(dspr2, "shra.qb {r}, {a}, {sa}", a = a; sa = SA)
+}
+
+/// Vector Shift Right Arithmetic With Rounding
+///
+/// Shifts the signed bytes of `a` right by `SA` bits, rounding to the
+/// nearest value.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(shra_r.qb, SA = 3))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __mips_shra_r_qb<const SA: i32>(a: v4i8) -> v4i8 {
+    static_assert_uimm_bits!(SA, 3);
+    dsp!(dspr2, "shra_r.qb {r}, {a}, {sa}", a = a; sa = SA)
+}
+
+/// Vector Shift Right Logical
+///
+/// Shifts the unsigned halfwords of `a` right by `SA` bits.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(shrl.ph, SA = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __mips_shrl_ph<const SA: i32>(a: v2i16) -> v2i16 {
+    static_assert_uimm_bits!(SA, 4);
+    dsp!(dspr2, "shrl.ph {r}, {a}, {sa}", a = a; sa = SA)
+}
+
+/// Vector Unsigned Subtract
+///
+/// Subtracts the unsigned halfwords in `b` from those in `a`, wrapping on
+/// overflow.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(subu.ph))]
+pub unsafe fn __mips_subu_ph(a: v2i16, b: v2i16) -> v2i16 {
+    dsp!(dspr2, "subu.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Unsigned Saturating Subtract
+///
+/// Subtracts the unsigned halfwords in `b` from those in `a`, saturating on
+/// overflow.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(subu_s.ph))]
+pub unsafe fn __mips_subu_s_ph(a: v2i16, b: v2i16) -> v2i16 {
+    dsp!(dspr2, "subu_s.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Unsigned Halving Subtract
+///
+/// Subtracts the unsigned bytes in `b` from those in `a`, and halves the
+/// differences.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(subuh.qb))]
+pub unsafe fn __mips_subuh_qb(a: v4i8, b: v4i8) -> v4i8 {
+    dsp!(dspr2, "subuh.qb {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Unsigned Halving Subtract With Rounding
+///
+/// Subtracts the unsigned bytes in `b` from those in `a`, and halves the
+/// differences, rounding to the nearest value.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(subuh_r.qb))]
+pub unsafe fn __mips_subuh_r_qb(a: v4i8, b: v4i8) -> v4i8 {
+    dsp!(dspr2, "subuh_r.qb {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Fractional Halving Add
+///
+/// Adds the Q15 fractions in `a` and `b`, and halves the sums.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(addqh.ph))]
+pub unsafe fn __mips_addqh_ph(a: v2q15, b: v2q15) -> v2q15 {
+    dsp!(dspr2, "addqh.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Fractional Halving Add With Rounding
+///
+/// Adds the Q15 fractions in `a` and `b`, and halves the sums, rounding to
+/// the nearest value.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(addqh_r.ph))]
+pub unsafe fn __mips_addqh_r_ph(a: v2q15, b: v2q15) -> v2q15 {
+    dsp!(dspr2, "addqh_r.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Fractional Halving Add
+///
+/// Adds the Q31 fractions `a` and `b`, and halves the sum.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(addqh.w))]
+pub unsafe fn __mips_addqh_w(a: i32, b: i32) -> i32 {
+    dsp!(dspr2, "addqh.w {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Fractional Halving Add With Rounding
+///
+/// Adds the Q31 fractions `a` and `b`, and halves the sum, rounding to the
+/// nearest value.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(addqh_r.w))]
+pub unsafe fn __mips_addqh_r_w(a: i32, b: i32) -> i32 {
+    dsp!(dspr2, "addqh_r.w {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Fractional Halving Subtract
+///
+/// Subtracts the Q15 fractions in `b` from those in `a`, and halves the
+/// differences.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(subqh.ph))]
+pub unsafe fn __mips_subqh_ph(a: v2q15, b: v2q15) -> v2q15 {
+    dsp!(dspr2, "subqh.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Vector Fractional Halving Subtract With Rounding
+///
+/// Subtracts the Q15 fractions in `b` from those in `a`, and halves the
+/// differences, rounding to the nearest value.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(subqh_r.ph))]
+pub unsafe fn __mips_subqh_r_ph(a: v2q15, b: v2q15) -> v2q15 {
+    dsp!(dspr2, "subqh_r.ph {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Fractional Halving Subtract
+///
+/// Subtracts the Q31 fraction `b` from `a`, and halves the difference.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(subqh.w))]
+pub unsafe fn __mips_subqh_w(a: i32, b: i32) -> i32 {
+    dsp!(dspr2, "subqh.w {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Fractional Halving Subtract With Rounding
+///
+/// Subtracts the Q31 fraction `b` from `a`, and halves the difference,
+/// rounding to the nearest value.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(subqh_r.w))]
+pub unsafe fn __mips_subqh_r_w(a: i32, b: i32) -> i32 {
+    dsp!(dspr2, "subqh_r.w {r}, {a}, {b}", a = a, b = b)
+}
+
+/// Cross Dot Product and Add
+///
+/// Adds the products of the left signed halfword of `a` and the right one
+/// of `b`, and of the right one of `a` and the left one of `b`, to `acc`.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(dpax.w.ph))]
+pub unsafe fn __mips_dpax_w_ph(acc: i64, a: v2i16, b: v2i16) -> i64 {
+    dsp_acc!(dspr2, "dpax.w.ph $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Cross Dot Product and Subtract
+///
+/// Subtracts the products of the left signed halfword of `a` and the right
+/// one of `b`, and of the right one of `a` and the left one of `b`, from
+/// `acc`.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(dpsx.w.ph))]
+pub unsafe fn __mips_dpsx_w_ph(acc: i64, a: v2i16, b: v2i16) -> i64 {
+    dsp_acc!(dspr2, "dpsx.w.ph $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Cross Fractional Dot Product and Add
+///
+/// Like [`__mips_dpax_w_ph`] for Q15 fractions, with Q31 products
+/// saturating `-1.0 * -1.0`.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(dpaqx_s.w.ph))]
+pub unsafe fn __mips_dpaqx_s_w_ph(acc: i64, a: v2q15, b: v2q15) -> i64 {
+    dsp_acc!(dspr2, "dpaqx_s.w.ph $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Cross Fractional Dot Product and Saturating Add
+///
+/// Like [`__mips_dpaqx_s_w_ph`], saturating the result to a Q31 fraction.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(dpaqx_sa.w.ph))]
+pub unsafe fn __mips_dpaqx_sa_w_ph(acc: i64, a: v2q15, b: v2q15) -> i64 {
+    dsp_acc!(dspr2, "dpaqx_sa.w.ph $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Cross Fractional Dot Product and Subtract
+///
+/// Like [`__mips_dpsx_w_ph`] for Q15 fractions, with Q31 products
+/// saturating `-1.0 * -1.0`.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(dpsqx_s.w.ph))]
+pub unsafe fn __mips_dpsqx_s_w_ph(acc: i64, a: v2q15, b: v2q15) -> i64 {
+    dsp_acc!(dspr2, "dpsqx_s.w.ph $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+/// Cross Fractional Dot Product and Saturating Subtract
+///
+/// Like [`__mips_dpsqx_s_w_ph`], saturating the result to a Q31 fraction.
+///
+/// Requires DSPr2.
+#[inline]
+#[cfg_attr(test, assert_instr(dpsqx_sa.w.ph))]
+pub unsafe fn __mips_dpsqx_sa_w_ph(acc: i64, a: v2q15, b: v2q15) -> i64 {
+    dsp_acc!(dspr2, "dpsqx_sa.w.ph $ac0, {a}, {b}", acc, a = a, b = b)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{core_arch::mips::*, mem::transmute};
+
+    // The inputs and results are written as the contents of the registers,
+    // so that the left and right elements are the same on both endiannesses.
+    fn qb(a: u32) -> v4i8 {
+        unsafe { transmute(a) }
+    }
+
+    fn ph(a: u32) -> v2q15 {
+        unsafe { transmute(a) }
+    }
+
+    fn r<T: super::Gpr>(a: T) -> u32 {
+        a.into_gpr() as u32
+    }
+
+    macro_rules! require {
+        ($feature:tt) => {
+            #[cfg(target_arch = "mips")]
+            let detected = is_mips_feature_detected!($feature);
+            #[cfg(target_arch = "mips64")]
+            let detected = is_mips64_feature_detected!($feature);
+            if !detected {
+                return;
+            }
+        };
+    }
+
+    #[test]
+    fn test_mips_addq_ph() {
+        require!("dsp");
+        unsafe {
+            let (a, b) = (ph(0x7fff_0001), ph(0x0001_0002));
+            assert_eq!(r(__mips_addq_ph(a, b)), 0x8000_0003);
+            assert_eq!(r(__mips_addq_s_ph(a, b)), 0x7fff_0003);
+            assert_eq!(__mips_addq_s_w(i32::MAX, 1), i32::MAX);
+            assert_eq!(__mips_addq_s_w(-5, 3), -2);
+        }
+    }
+
+    #[test]
+    fn test_mips_addu_qb() {
+        require!("dsp");
+        unsafe {
+            let (a, b) = (qb(0xff01_8040), qb(0x0202_8040));
+            assert_eq!(r(__mips_addu_qb(a, b)), 0x0103_0080);
+            assert_eq!(r(__mips_addu_s_qb(a, b)), 0xff03_ff80);
+        }
+    }
+
+    #[test]
+    fn test_mips_subq_ph() {
+        require!("dsp");
+        unsafe {
+            let (a, b) = (ph(0x8000_0005), ph(0x0001_0007));
+            assert_eq!(r(__mips_subq_ph(a, b)), 0x7fff_fffe);
+            assert_eq!(r(__mips_subq_s_ph(a, b)), 0x8000_fffe);
+            assert_eq!(__mips_subq_s_w(i32::MIN, 1), i32::MIN);
+        }
+    }
+
+    #[test]
+    fn test_mips_subu_qb() {
+        require!("dsp");
+        unsafe {
+            let (a, b) = (qb(0x0010_ff05), qb(0x0101_0106));
+            assert_eq!(r(__mips_subu_qb(a, b)), 0xff0f_feff);
+            assert_eq!(r(__mips_subu_s_qb(a, b)), 0x000f_fe00);
+        }
+    }
+
+    #[test]
+    fn test_mips_addsc() {
+        require!("dsp");
+        unsafe {
+            assert_eq!(__mips_addsc(-1, 2), 1);
+            assert_eq!(__mips_addwc(1, 1), 3);
+            assert_eq!(__mips_addsc(1, 1), 2);
+            assert_eq!(__mips_addwc(1, 1), 2);
+        }
+    }
+
+    #[test]
+    fn test_mips_modsub() {
+        require!("dsp");
+        unsafe {
+            // The last index is 0x10 and the decrement 3.
+            assert_eq!(__mips_modsub(5, 0x1003), 2);
+            assert_eq!(__mips_modsub(0, 0x1003), 0x10);
+        }
+    }
+
+    #[test]
+    fn test_mips_raddu_w_qb() {
+        require!("dsp");
+        unsafe {
+            assert_eq!(__mips_raddu_w_qb(qb(0xff01_0203)), 261);
+        }
+    }
+
+    #[test]
+    fn test_mips_absq_s_ph() {
+        require!("dsp");
+        unsafe {
+            assert_eq!(r(__mips_absq_s_ph(ph(0x8000_fffb))), 0x7fff_0005);
+            assert_eq!(__mips_absq_s_w(i32::MIN), i32::MAX);
+            assert_eq!(__mips_absq_s_w(-7), 7);
+        }
+    }
+
+    #[test]
+    fn test_mips_precrq_qb_ph() {
+        require!("dsp");
+        unsafe {
+            let (a, b) = (0x1234_5678, 0x9abc_def0);
+            assert_eq!(r(__mips_precrq_qb_ph(ph(a), ph(b))), 0x1256_9ade);
+            assert_eq!(r(__mips_precrq_ph_w(a as i32, b as i32)), 0x1234_9abc);
+            assert_eq!(
+                r(__mips_precrq_rs_ph_w(0x1234_8000, 0x7fff_8000)),
+                0x1235_7fff
+            );
+            assert_eq!(
+                r(__mips_precrqu_s_qb_ph(ph(0x8000_4000), ph(0x7fff_0100))),
+                0x0080_ff02
+            );
+        }
+    }
+
+    #[test]
+    fn test_mips_preceq_w_phl() {
+        require!("dsp");
+        unsafe {
+            let a = ph(0x1234_5678);
+            assert_eq!(__mips_preceq_w_phl(a), 0x1234_0000);
+            assert_eq!(__mips_preceq_w_phr(a), 0x5678_0000);
+        }
+    }
+
+    #[test]
+    fn test_mips_precequ_ph_qbl() {
+        require!("dsp");
+        unsafe {
+            let a = qb(0x8040_2010);
+            assert_eq!(r(__mips_precequ_ph_qbl(a)), 0x4000_2000);
+            assert_eq!(r(__mips_precequ_ph_qbr(a)), 0x1000_0800);
+            assert_eq!(r(__mips_precequ_ph_qbla(a)), 0x4000_1000);
+            assert_eq!(r(__mips_precequ_ph_qbra(a)), 0x2000_0800);
+            assert_eq!(r(__mips_preceu_ph_qbl(a)), 0x0080_0040);
+            assert_eq!(r(__mips_preceu_ph_qbr(a)), 0x0020_0010);
+            assert_eq!(r(__mips_preceu_ph_qbla(a)), 0x0080_0020);
+            assert_eq!(r(__mips_preceu_ph_qbra(a)), 0x0040_0010);
+        }
+    }
+
+    #[test]
+    fn test_mips_shll_qb() {
+        require!("dsp");
+        unsafe {
+            assert_eq!(r(__mips_shll_qb::<2>(qb(0x8140_2001))), 0x0400_8004);
+            assert_eq!(r(__mips_shrl_qb::<3>(qb(0xff80_1008))), 0x1f10_0201);
+        }
+    }
+
+    #[test]
+    fn test_mips_shll_ph() {
+        require!("dsp");
+        unsafe {
+            let a = ph(0x1234_f00f);
+            assert_eq!(r(__mips_shll_ph::<4>(a)), 0x2340_00f0);
+            assert_eq!(r(__mips_shll_s_ph::<4>(a)), 0x7fff_8000);
+            assert_eq!(__mips_shll_s_w::<4>(0x1000_0000), i32::MAX);
+            assert_eq!(__mips_shll_s_w::<4>(-1), -16);
+        }
+    }
+
+    #[test]
+    fn test_mips_shra_ph() {
+        require!("dsp");
+        unsafe {
+            let a = ph(0x8000_0078);
+            assert_eq!(r(__mips_shra_ph::<4>(a)), 0xf800_0007);
+            assert_eq!(r(__mips_shra_r_ph::<4>(a)), 0xf800_0008);
+            assert_eq!(__mips_shra_r_w::<4>(0x78), 8);
+            assert_eq!(__mips_shra_r_w::<4>(-120), -7);
+        }
+    }
+
+    #[test]
+    fn test_mips_muleu_s_ph_qbl() {
+        require!("dsp");
+        unsafe {
+            let b = ph(0x0100_9000);
+            assert_eq!(r(__mips_muleu_s_ph_qbl(qb(0x0203_0000), b)), 0x0200_ffff);
+            assert_eq!(r(__mips_muleu_s_ph_qbr(qb(0x0000_0203), b)), 0x0200_ffff);
+        }
+    }
+
+    #[test]
+    fn test_mips_mulq_rs_ph() {
+        require!("dsp");
+        unsafe {
+            let a = ph(0x4000_8000);
+            assert_eq!(r(__mips_mulq_rs_ph(a, a)), 0x2000_7fff);
+            let b = ph(0x2000_8000);
+            assert_eq!(__mips_muleq_s_w_phl(a, b), 0x1000_0000);
+            assert_eq!(__mips_muleq_s_w_phr(a, b), i32::MAX);
+        }
+    }
+
+    #[test]
+    fn test_mips_dpau_h_qbl() {
+        require!("dsp");
+        unsafe {
+            let (a, b) = (qb(0x0203_0405), qb(0x0607_0809));
+            assert_eq!(__mips_dpau_h_qbl(10, a, b), 43);
+            assert_eq!(__mips_dpau_h_qbr(10, a, b), 87);
+            assert_eq!(__mips_dpsu_h_qbl(10, a, b), -23);
+            assert_eq!(__mips_dpsu_h_qbr(10, a, b), -67);
+        }
+    }
+
+    #[test]
+    fn test_mips_dpaq_s_w_ph() {
+        require!("dsp");
+        unsafe {
+            let (a, b) = (ph(0x0002_0003), ph(0x0004_0005));
+            assert_eq!(__mips_dpaq_s_w_ph(-100, a, b), -54);
+            assert_eq!(__mips_dpsq_s_w_ph(-100, a, b), -146);
+            assert_eq!(__mips_mulsaq_s_w_ph(0, a, b), -14);
+            let a = ph(0x8000_0000);
+            assert_eq!(__mips_dpaq_s_w_ph(0, a, a), i32::MAX as i64);
+        }
+    }
+
+    #[test]
+    fn test_mips_dpaq_sa_l_w() {
+        require!("dsp");
+        unsafe {
+            let a = 0x4000_0000;
+            assert_eq!(__mips_dpaq_sa_l_w(1, a, a), (1 << 61) + 1);
+            assert_eq!(__mips_dpaq_sa_l_w(i64::MAX, 1, 1), i64::MAX);
+            assert_eq!(__mips_dpsq_sa_l_w(0, a, a), -(1 << 61));
+        }
+    }
+
+    #[test]
+    fn test_mips_maq_s_w_phl() {
+        require!("dsp");
+        unsafe {
+            let (a, b) = (ph(0x0002_0003), ph(0x0004_0005));
+            assert_eq!(__mips_maq_s_w_phl(1, a, b), 17);
+            assert_eq!(__mips_maq_s_w_phr(1, a, b), 31);
+            assert_eq!(__mips_maq_s_w_phl(0x7fff_fff0, a, b), 0x8000_0000);
+            assert_eq!(__mips_maq_sa_w_phl(0x7fff_fff0, a, b), i32::MAX as i64);
+            assert_eq!(__mips_maq_sa_w_phr(1, a, b), 31);
+        }
+    }
+
+    #[test]
+    fn test_mips_bitrev() {
+        require!("dsp");
+        unsafe {
+            assert_eq!(__mips_bitrev(1), 0x8000);
+            assert_eq!(__mips_bitrev(0x1234_0003), 0xc000);
+        }
+    }
+
+    #[test]
+    fn test_mips_insv() {
+        require!("dsp");
+        unsafe {
+            // Sets the position to 8 and the size to 4.
+            __mips_wrdsp::<0b11>(8 | (4 << 7));
+            assert_eq!(__mips_rddsp::<0b11>(), 8 | (4 << 7));
+            assert_eq!(__mips_insv(-1, 5), 0xffff_f5ff_u32 as i32);
+        }
+    }
+
+    #[test]
+    fn test_mips_repl_qb() {
+        require!("dsp");
+        unsafe {
+            assert_eq!(r(__mips_repl_qb::<0x12>()), 0x1212_1212);
+            assert_eq!(r(__mips_repl_ph::<-2>()), 0xfffe_fffe);
+        }
+    }
+
+    #[test]
+    fn test_mips_cmpu_eq_qb() {
+        require!("dsp");
+        unsafe {
+            let (a, b) = (qb(0x0102_0304), qb(0x0100_0300));
+            let (c, d) = (qb(0x1111_1111), qb(0x2222_2222));
+            __mips_cmpu_eq_qb(a, b);
+            assert_eq!(r(__mips_pick_qb(c, d)), 0x1122_1122);
+            __mips_cmpu_lt_qb(b, a);
+            assert_eq!(r(__mips_pick_qb(c, d)), 0x2211_2211);
+            __mips_cmpu_le_qb(a, b);
+            assert_eq!(r(__mips_pick_qb(c, d)), 0x1122_1122);
+            assert_eq!(__mips_cmpgu_eq_qb(a, b), 0b1010);
+            assert_eq!(__mips_cmpgu_lt_qb(a, b), 0);
+            assert_eq!(__mips_cmpgu_lt_qb(b, a), 0b0101);
+            assert_eq!(__mips_cmpgu_le_qb(a, b), 0b1010);
+        }
+    }
+
+    #[test]
+    fn test_mips_cmp_eq_ph() {
+        require!("dsp");
+        unsafe {
+            let (a, b) = (ph(0x8000_0001), ph(0x0000_0001));
+            let (c, d) = (ph(0x1111_2222), ph(0x3333_4444));
+            __mips_cmp_eq_ph(a, b);
+            assert_eq!(r(__mips_pick_ph(c, d)), 0x3333_2222);
+            __mips_cmp_lt_ph(a, b);
+            assert_eq!(r(__mips_pick_ph(c, d)), 0x1111_4444);
+            __mips_cmp_le_ph(a, b);
+            assert_eq!(r(__mips_pick_ph(c, d)), 0x1111_2222);
+        }
+    }
+
+    #[test]
+    fn test_mips_packrl_ph() {
+        require!("dsp");
+        unsafe {
+            let (a, b) = (ph(0x1234_5678), ph(0x9abc_def0));
+            assert_eq!(r(__mips_packrl_ph(a, b)), 0x5678_9abc);
+        }
+    }
+
+    #[test]
+    fn test_mips_extr_w() {
+        require!("dsp");
+        unsafe {
+            assert_eq!(__mips_extr_w::<4>(0x1_0000_0010), 0x1000_0001);
+            assert_eq!(__mips_extr_r_w::<4>(0x18), 2);
+            assert_eq!(__mips_extr_rs_w::<4>(0x10_0000_0000), i32::MAX);
+            assert_eq!(__mips_extr_s_h::<0>(0x12345), 0x7fff);
+            assert_eq!(__mips_extr_s_h::<4>(-0x10), -1);
+        }
+    }
+
+    #[test]
+    fn test_mips_extp() {
+        require!("dsp");
+        unsafe {
+            __mips_wrdsp::<0b1>(15);
+            assert_eq!(__mips_extp::<7>(0xab00), 0xab);
+            assert_eq!(__mips_rddsp::<0b1>(), 15);
+            assert_eq!(__mips_extpdp::<7>(0xab00), 0xab);
+            assert_eq!(__mips_rddsp::<0b1>(), 7);
+        }
+    }
+
+    #[test]
+    fn test_mips_shilo() {
+        require!("dsp");
+        unsafe {
+            assert_eq!(__mips_shilo::<-4>(0x0123), 0x1230);
+            assert_eq!(__mips_shilo::<4>(0x1230), 0x0123);
+        }
+    }
+
+    #[test]
+    fn test_mips_mthlip() {
+        require!("dsp");
+        unsafe {
+            __mips_wrdsp::<0b1>(0);
+            assert_eq!(__mips_bposge32(), 0);
+            assert_eq!(
+                __mips_mthlip(0x1111_1111_2222_2222, 0x3333_3333),
+                0x2222_2222_3333_3333
+            );
+            assert_eq!(__mips_rddsp::<0b1>(), 32);
+            assert_eq!(__mips_bposge32(), 1);
+        }
+    }
+
+    #[test]
+    fn test_mips_lbux() {
+        require!("dsp");
+        let a = u64::from_ne_bytes([1, 2, 3, 4, 5, 6, 7, 8]);
+        let p = &a as *const u64 as *mut u8;
+        unsafe {
+            assert_eq!(__mips_lbux(p, 3), 4);
+            assert_eq!(__mips_lhx(p, 2), i16::from_ne_bytes([3, 4]) as i32);
+            assert_eq!(__mips_lwx(p, 4), i32::from_ne_bytes([5, 6, 7, 8]));
+        }
+    }
+
+    #[test]
+    fn test_mips_madd() {
+        require!("dsp");
+        unsafe {
+            assert_eq!(__mips_mult(-2, 3), -6);
+            assert_eq!(__mips_multu(u32::MAX, 2), 0x1_ffff_fffe);
+            assert_eq!(__mips_madd(10, -2, 3), 4);
+            assert_eq!(
+                __mips_maddu(0, u32::MAX, u32::MAX),
+                0xffff_fffe_0000_0001_u64 as i64
+            );
+            assert_eq!(__mips_msub(10, 2, 3), 4);
+            assert_eq!(__mips_msubu(0x1_0000_0000, 1, 1), 0xffff_ffff);
+        }
+    }
+
+    #[test]
+    fn test_mips_absq_s_qb() {
+        require!("dspr2");
+        unsafe {
+            assert_eq!(r(__mips_absq_s_qb(qb(0x80ff_7f01))), 0x7f01_7f01);
+        }
+    }
+
+    #[test]
+    fn test_mips_addu_ph() {
+        require!("dspr2");
+        unsafe {
+            let (a, b) = (ph(0xffff_0001), ph(0x0002_0002));
+            assert_eq!(r(__mips_addu_ph(a, b)), 0x0001_0003);
+            assert_eq!(r(__mips_addu_s_ph(a, b)), 0xffff_0003);
+            let (a, b) = (ph(0x0001_0005), ph(0x0002_0002));
+            assert_eq!(r(__mips_subu_ph(a, b)), 0xffff_0003);
+            assert_eq!(r(__mips_subu_s_ph(a, b)), 0x0000_0003);
+        }
+    }
+
+    #[test]
+    fn test_mips_adduh_qb() {
+        require!("dspr2");
+        unsafe {
+            let (a, b) = (qb(0x0102_0304), qb(0));
+            assert_eq!(r(__mips_adduh_qb(a, b)), 0x0001_0102);
+            assert_eq!(r(__mips_adduh_r_qb(a, b)), 0x0101_0202);
+            let (a, b) = (qb(0x0a05_0302), qb(0x0201_0101));
+            assert_eq!(r(__mips_subuh_qb(a, b)), 0x0402_0100);
+            assert_eq!(r(__mips_subuh_r_qb(a, b)), 0x0402_0101);
+        }
+    }
+
+    #[test]
+    fn test_mips_append() {
+        require!("dspr2");
+        unsafe {
+            assert_eq!(__mips_append::<8>(0xab, 0x1234_56cd), 0xabcd);
+            assert_eq!(
+                __mips_prepend::<8>(0x1234_5678, 0xab),
+                0xab12_3456_u32 as i32
+            );
+            assert_eq!(
+                __mips_balign::<1>(0x1122_3344, 0xaabb_ccdd_u32 as i32),
+                0x2233_44aa
+            );
+        }
+    }
+
+    #[test]
+    fn test_mips_cmpgdu_eq_qb() {
+        require!("dspr2");
+        unsafe {
+            let (a, b) = (qb(0x0102_0304), qb(0x0100_0300));
+            let (c, d) = (qb(0x1111_1111), qb(0x2222_2222));
+            assert_eq!(__mips_cmpgdu_eq_qb(a, b), 0b1010);
+            assert_eq!(r(__mips_pick_qb(c, d)), 0x1122_1122);
+            assert_eq!(__mips_cmpgdu_lt_qb(b, a), 0b0101);
+            assert_eq!(r(__mips_pick_qb(c, d)), 0x2211_2211);
+            assert_eq!(__mips_cmpgdu_le_qb(a, b), 0b1010);
+        }
+    }
+
+    #[test]
+    fn test_mips_dpa_w_ph() {
+        require!("dspr2");
+        unsafe {
+            let (a, b) = (ph(0x0002_fffd), ph(0x0004_0005));
+            assert_eq!(__mips_dpa_w_ph(0, a, b), -7);
+            assert_eq!(__mips_dps_w_ph(0, a, b), 7);
+            assert_eq!(__mips_dpax_w_ph(0, a, b), -2);
+            assert_eq!(__mips_dpsx_w_ph(0, a, b), 2);
+            assert_eq!(__mips_mulsa_w_ph(0, a, b), 23);
+            assert_eq!(__mips_dpaqx_s_w_ph(0, a, b), -4);
+            assert_eq!(__mips_dpsqx_s_w_ph(0, a, b), 4);
+            let (a, b) = (ph(0x0001_0000), ph(0x0000_0001));
+            let acc = i32::MAX as i64;
+            assert_eq!(__mips_dpaqx_s_w_ph(acc, a, b), acc + 2);
+            assert_eq!(__mips_dpaqx_sa_w_ph(acc, a, b), acc);
+            let acc = i32::MIN as i64;
+            assert_eq!(__mips_dpsqx_sa_w_ph(acc, a, b), acc);
+        }
+    }
+
+    #[test]
+    fn test_mips_mul_ph() {
+        require!("dspr2");
+        unsafe {
+            let (a, b) = (ph(0x0100_fffe), ph(0x0100_0003));
+            assert_eq!(r(__mips_mul_ph(a, b)), 0x0000_fffa);
+            assert_eq!(r(__mips_mul_s_ph(a, b)), 0x7fff_fffa);
+            let a = ph(0x4000_8000);
+            assert_eq!(r(__mips_mulq_s_ph(a, a)), 0x2000_7fff);
+            assert_eq!(__mips_mulq_s_w(0x4000_0000, 0x4000_0000), 0x2000_0000);
+            assert_eq!(__mips_mulq_s_w(i32::MIN, i32::MIN), i32::MAX);
+            assert_eq!(__mips_mulq_rs_w(0x4000_0000, 0x4000_0000), 0x2000_0000);
+            assert_eq!(__mips_mulq_rs_w(i32::MIN, i32::MIN), i32::MAX);
+        }
+    }
+
+    #[test]
+    fn test_mips_precr_qb_ph() {
+        require!("dspr2");
+        unsafe {
+            let (a, b) = (ph(0x1234_5678), ph(0x9abc_def0));
+            assert_eq!(r(__mips_precr_qb_ph(a, b)), 0x3478_bcf0);
+            assert_eq!(
+                r(__mips_precr_sra_ph_w::<4>(0x0001_2340, 0x5670)),
+                0x1234_0567
+            );
+            assert_eq!(r(__mips_precr_sra_r_ph_w::<4>(0x18, 0x08)), 0x0002_0001);
+        }
+    }
+
+    #[test]
+    fn test_mips_shra_qb() {
+        require!("dspr2");
+        unsafe {
+            let a = qb(0x80ff_0203);
+            assert_eq!(r(__mips_shra_qb::<1>(a)), 0xc0ff_0101);
+            assert_eq!(r(__mips_shra_r_qb::<1>(a)), 0xc000_0102);
+            assert_eq!(r(__mips_shrl_ph::<4>(ph(0xf000_0120))), 0x0f00_0012);
+        }
+    }
+
+    #[test]
+    fn test_mips_addqh_ph() {
+        require!("dspr2");
+        unsafe {
+            let (a, b) = (ph(0x7fff_0003), ph(0x7fff_0000));
+            assert_eq!(r(__mips_addqh_ph(a, b)), 0x7fff_0001);
+            assert_eq!(r(__mips_addqh_r_ph(a, b)), 0x7fff_0002);
+            assert_eq!(__mips_addqh_w(i32::MAX, i32::MAX), i32::MAX);
+            assert_eq!(__mips_addqh_w(3, 0), 1);
+            assert_eq!(__mips_addqh_r_w(3, 0), 2);
+        }
+    }
+
+    #[test]
+    fn test_mips_subqh_ph() {
+        require!("dspr2");
+        unsafe {
+            let (a, b) = (ph(0x8000_0003), ph(0x7fff_0000));
+            assert_eq!(r(__mips_subqh_ph(a, b)), 0x8000_0001);
+            assert_eq!(r(__mips_subqh_r_ph(a, b)), 0x8001_0002);
+            assert_eq!(__mips_subqh_w(i32::MIN, i32::MAX), i32::MIN);
+            assert_eq!(__mips_subqh_w(3, 0), 1);
+            assert_eq!(__mips_subqh_r_w(3, 0), 2);
+        }
+    }
+}
@@ -7,6 +7,9 @@ mod msa;
 #[cfg(target_feature = "fp64")]
 pub use self::msa::*;
 
+mod dsp;
+pub use self::dsp::*;
+
 #[cfg(test)]
 use stdarch_test::assert_instr;
 
@@ -16,3 +19,53 @@ use stdarch_test::assert_instr;
 pub unsafe fn break_() -> ! {
     crate::intrinsics::abort()
 }
+
+/// Read Hardware Register
+///
+/// Reads the hardware register `RD`, for example `2` for the cycle counter
+/// or `29` for the thread pointer. Linux emulates the instruction on cores
+/// without it.
+#[inline]
+#[cfg_attr(test, assert_instr(rdhwr, RD = 29))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn rdhwr<const RD: i32>() -> usize {
+    static_assert_uimm_bits!(RD, 5);
+    let r: usize;
+    crate::arch::asm!("rdhwr {}, ${}", out(reg) r, const RD, options(nomem, nostack));
+    r
+}
+
+/// Synchronize Shared Memory
+///
+/// Orders the memory accesses around it. `STYPE` selects the kind of
+/// barrier, `0` being a full completion barrier, and `4`, `16`, `17`, `18`
+/// and `19` the lighter write, full, acquire, release and read ordering
+/// barriers. Cores without them treat all the types as `0`.
+#[inline]
+#[cfg_attr(test, assert_instr(sync, STYPE = 16))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn sync<const STYPE: i32>() {
+    static_assert_uimm_bits!(STYPE, 5);
+    crate::arch::asm!("sync {}", const STYPE, options(nostack));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::mips::*;
+
+    #[test]
+    fn test_rdhwr() {
+        unsafe {
+            // The thread pointer is always set up on Linux.
+            assert_ne!(rdhwr::<29>(), 0);
+        }
+    }
+
+    #[test]
+    fn test_sync() {
+        unsafe {
+            sync::<0>();
+            sync::<16>();
+        }
+    }
+}
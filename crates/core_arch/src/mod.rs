@@ -252,6 +252,26 @@ pub mod arch {
         pub use crate::core_arch::powerpc64::*;
     }
 
+    /// Platform-specific intrinsics for the `s390x` platform.
+    ///
+    /// See the [module documentation](../index.html) for more details.
+    #[cfg(any(target_arch = "s390x", doc))]
+    #[doc(cfg(target_arch = "s390x"))]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub mod s390x {
+        pub use crate::core_arch::s390x::*;
+    }
+
+    /// Platform-specific intrinsics for the `loongarch64` platform.
+    ///
+    /// See the [module documentation](../index.html) for more details.
+    #[cfg(any(target_arch = "loongarch64", doc))]
+    #[doc(cfg(target_arch = "loongarch64"))]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub mod loongarch64 {
+        pub use crate::core_arch::loongarch64::*;
+    }
+
     /// Platform-specific intrinsics for the `NVPTX` platform.
     ///
     /// See the [module documentation](../index.html) for more details.
@@ -299,6 +319,14 @@ mod powerpc;
 #[doc(cfg(target_arch = "powerpc64"))]
 mod powerpc64;
 
+#[cfg(any(target_arch = "s390x", doc))]
+#[doc(cfg(target_arch = "s390x"))]
+mod s390x;
+
+#[cfg(any(target_arch = "loongarch64", doc))]
+#[doc(cfg(target_arch = "loongarch64"))]
+mod loongarch64;
+
 #[cfg(any(target_arch = "nvptx64", doc))]
 #[doc(cfg(target_arch = "nvptx64"))]
 mod nvptx;
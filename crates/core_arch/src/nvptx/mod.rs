@@ -11,8 +11,12 @@
 //! [llvm_docs]:
 //! https://llvm.org/docs/NVPTXUsage.html
 
+use crate::arch::asm;
 use crate::ffi::c_void;
 
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
 #[allow(improper_ctypes)]
 extern "C" {
     #[link_name = "llvm.nvvm.barrier0"]
@@ -41,6 +45,67 @@ extern "C" {
     fn thread_idx_y() -> i32;
     #[link_name = "llvm.nvvm.read.ptx.sreg.tid.z"]
     fn thread_idx_z() -> i32;
+    #[link_name = "llvm.nvvm.read.ptx.sreg.laneid"]
+    fn lane_id() -> i32;
+    #[link_name = "llvm.nvvm.read.ptx.sreg.warpid"]
+    fn warp_id() -> i32;
+    #[link_name = "llvm.nvvm.read.ptx.sreg.clock64"]
+    fn clock64() -> i64;
+    #[link_name = "llvm.nvvm.nanosleep"]
+    fn nanosleep(ns: u32) -> ();
+    #[link_name = "llvm.nvvm.membar.cta"]
+    fn membar_cta() -> ();
+    #[link_name = "llvm.nvvm.membar.gl"]
+    fn membar_gl() -> ();
+    #[link_name = "llvm.nvvm.membar.sys"]
+    fn membar_sys() -> ();
+    #[link_name = "llvm.nvvm.isspacep.global"]
+    fn isspacep_global(ptr: *const c_void) -> bool;
+    #[link_name = "llvm.nvvm.isspacep.shared"]
+    fn isspacep_shared(ptr: *const c_void) -> bool;
+
+    #[link_name = "llvm.nvvm.bar.warp.sync"]
+    fn syncwarp(mask: u32) -> ();
+    #[link_name = "llvm.nvvm.activemask"]
+    fn activemask() -> u32;
+    #[link_name = "llvm.nvvm.vote.all.sync"]
+    fn vote_all_sync(mask: u32, pred: bool) -> bool;
+    #[link_name = "llvm.nvvm.vote.any.sync"]
+    fn vote_any_sync(mask: u32, pred: bool) -> bool;
+    #[link_name = "llvm.nvvm.vote.ballot.sync"]
+    fn vote_ballot_sync(mask: u32, pred: bool) -> u32;
+    #[link_name = "llvm.nvvm.shfl.sync.idx.i32"]
+    fn shfl_sync_idx_i32(mask: u32, value: i32, lane: u32, clamp: u32) -> i32;
+    #[link_name = "llvm.nvvm.shfl.sync.idx.f32"]
+    fn shfl_sync_idx_f32(mask: u32, value: f32, lane: u32, clamp: u32) -> f32;
+    #[link_name = "llvm.nvvm.shfl.sync.up.i32"]
+    fn shfl_sync_up_i32(mask: u32, value: i32, delta: u32, clamp: u32) -> i32;
+    #[link_name = "llvm.nvvm.shfl.sync.up.f32"]
+    fn shfl_sync_up_f32(mask: u32, value: f32, delta: u32, clamp: u32) -> f32;
+    #[link_name = "llvm.nvvm.shfl.sync.down.i32"]
+    fn shfl_sync_down_i32(mask: u32, value: i32, delta: u32, clamp: u32) -> i32;
+    #[link_name = "llvm.nvvm.shfl.sync.down.f32"]
+    fn shfl_sync_down_f32(mask: u32, value: f32, delta: u32, clamp: u32) -> f32;
+    #[link_name = "llvm.nvvm.shfl.sync.bfly.i32"]
+    fn shfl_sync_bfly_i32(mask: u32, value: i32, lane_mask: u32, clamp: u32) -> i32;
+    #[link_name = "llvm.nvvm.shfl.sync.bfly.f32"]
+    fn shfl_sync_bfly_f32(mask: u32, value: f32, lane_mask: u32, clamp: u32) -> f32;
+    #[link_name = "llvm.nvvm.redux.sync.add"]
+    fn redux_sync_add(value: u32, mask: u32) -> u32;
+    #[link_name = "llvm.nvvm.redux.sync.min"]
+    fn redux_sync_min(value: i32, mask: u32) -> i32;
+    #[link_name = "llvm.nvvm.redux.sync.umin"]
+    fn redux_sync_umin(value: u32, mask: u32) -> u32;
+    #[link_name = "llvm.nvvm.redux.sync.max"]
+    fn redux_sync_max(value: i32, mask: u32) -> i32;
+    #[link_name = "llvm.nvvm.redux.sync.umax"]
+    fn redux_sync_umax(value: u32, mask: u32) -> u32;
+    #[link_name = "llvm.nvvm.redux.sync.and"]
+    fn redux_sync_and(value: u32, mask: u32) -> u32;
+    #[link_name = "llvm.nvvm.redux.sync.or"]
+    fn redux_sync_or(value: u32, mask: u32) -> u32;
+    #[link_name = "llvm.nvvm.redux.sync.xor"]
+    fn redux_sync_xor(value: u32, mask: u32) -> u32;
 }
 
 /// Synchronizes all threads in the block.
@@ -127,6 +192,443 @@ pub unsafe fn trap() -> ! {
     crate::intrinsics::abort()
 }
 
+/// Lane index of the thread within its warp.
+#[inline]
+#[cfg_attr(test, assert_instr("%laneid"))]
+pub unsafe fn _lane_id() -> i32 {
+    lane_id()
+}
+
+/// Identifier of the warp the thread is running in.
+///
+/// The identifier isn't stable: it can change over the lifetime of the
+/// thread, for example after it has been preempted.
+#[inline]
+#[cfg_attr(test, assert_instr("%warpid"))]
+pub unsafe fn _warp_id() -> i32 {
+    warp_id()
+}
+
+/// Value of the 64-bit per-multiprocessor cycle counter.
+#[inline]
+#[cfg_attr(test, assert_instr("%clock64"))]
+pub unsafe fn _clock64() -> i64 {
+    clock64()
+}
+
+/// Suspends the thread for approximately `ns` nanoseconds, at most one
+/// millisecond.
+///
+/// Requires `sm_70` or later.
+#[inline]
+#[cfg_attr(test, assert_instr(nanosleep.u32))]
+pub unsafe fn _nanosleep(ns: u32) -> () {
+    nanosleep(ns)
+}
+
+/// Orders the memory accesses of the thread as observed by the other threads
+/// of its block.
+#[inline]
+#[cfg_attr(test, assert_instr(membar.cta))]
+pub unsafe fn _threadfence_block() -> () {
+    membar_cta()
+}
+
+/// Orders the memory accesses of the thread as observed by all the threads of
+/// the device.
+#[inline]
+#[cfg_attr(test, assert_instr(membar.gl))]
+pub unsafe fn _threadfence() -> () {
+    membar_gl()
+}
+
+/// Orders the memory accesses of the thread as observed by all the threads of
+/// the device, the host and peer devices.
+#[inline]
+#[cfg_attr(test, assert_instr(membar.sys))]
+pub unsafe fn _threadfence_system() -> () {
+    membar_sys()
+}
+
+/// Returns whether `ptr` is an address in the global state space.
+#[inline]
+#[cfg_attr(test, assert_instr(isspacep.global))]
+pub unsafe fn _is_global(ptr: *const c_void) -> bool {
+    isspacep_global(ptr)
+}
+
+/// Returns whether `ptr` is an address in the shared state space.
+#[inline]
+#[cfg_attr(test, assert_instr(isspacep.shared))]
+pub unsafe fn _is_shared(ptr: *const c_void) -> bool {
+    isspacep_shared(ptr)
+}
+
+/// Synchronizes the threads of the warp which are set in `mask`.
+#[inline]
+#[cfg_attr(test, assert_instr(bar.warp.sync))]
+pub unsafe fn _syncwarp(mask: u32) -> () {
+    syncwarp(mask)
+}
+
+/// Mask of the threads of the warp which are currently active.
+#[inline]
+#[cfg_attr(test, assert_instr(activemask.b32))]
+pub unsafe fn _activemask() -> u32 {
+    activemask()
+}
+
+/// Returns whether `pred` is true for all the threads of the warp set in
+/// `mask`.
+#[inline]
+#[cfg_attr(test, assert_instr(vote.sync.all.pred))]
+pub unsafe fn _all_sync(mask: u32, pred: bool) -> bool {
+    vote_all_sync(mask, pred)
+}
+
+/// Returns whether `pred` is true for any of the threads of the warp set in
+/// `mask`.
+#[inline]
+#[cfg_attr(test, assert_instr(vote.sync.any.pred))]
+pub unsafe fn _any_sync(mask: u32, pred: bool) -> bool {
+    vote_any_sync(mask, pred)
+}
+
+/// Mask of the threads of the warp set in `mask` for which `pred` is true.
+#[inline]
+#[cfg_attr(test, assert_instr(vote.sync.ballot.b32))]
+pub unsafe fn _ballot_sync(mask: u32, pred: bool) -> u32 {
+    vote_ballot_sync(mask, pred)
+}
+
+/// Returns `value` as passed by the thread with lane index `src_lane`.
+///
+/// All the threads of the warp set in `mask` must execute the same call.
+#[inline]
+#[cfg_attr(test, assert_instr(shfl.sync.idx.b32))]
+pub unsafe fn _shfl_sync_i32(mask: u32, value: i32, src_lane: u32) -> i32 {
+    shfl_sync_idx_i32(mask, value, src_lane, 0x1f)
+}
+
+/// Returns `value` as passed by the thread with lane index `src_lane`.
+///
+/// All the threads of the warp set in `mask` must execute the same call.
+#[inline]
+#[cfg_attr(test, assert_instr(shfl.sync.idx.b32))]
+pub unsafe fn _shfl_sync_f32(mask: u32, value: f32, src_lane: u32) -> f32 {
+    shfl_sync_idx_f32(mask, value, src_lane, 0x1f)
+}
+
+/// Returns `value` as passed by the thread `delta` lanes below the current
+/// one, or the thread's own `value` if there is no such lane.
+///
+/// All the threads of the warp set in `mask` must execute the same call.
+#[inline]
+#[cfg_attr(test, assert_instr(shfl.sync.up.b32))]
+pub unsafe fn _shfl_up_sync_i32(mask: u32, value: i32, delta: u32) -> i32 {
+    shfl_sync_up_i32(mask, value, delta, 0)
+}
+
+/// Returns `value` as passed by the thread `delta` lanes below the current
+/// one, or the thread's own `value` if there is no such lane.
+///
+/// All the threads of the warp set in `mask` must execute the same call.
+#[inline]
+#[cfg_attr(test, assert_instr(shfl.sync.up.b32))]
+pub unsafe fn _shfl_up_sync_f32(mask: u32, value: f32, delta: u32) -> f32 {
+    shfl_sync_up_f32(mask, value, delta, 0)
+}
+
+/// Returns `value` as passed by the thread `delta` lanes above the current
+/// one, or the thread's own `value` if there is no such lane.
+///
+/// All the threads of the warp set in `mask` must execute the same call.
+#[inline]
+#[cfg_attr(test, assert_instr(shfl.sync.down.b32))]
+pub unsafe fn _shfl_down_sync_i32(mask: u32, value: i32, delta: u32) -> i32 {
+    shfl_sync_down_i32(mask, value, delta, 0x1f)
+}
+
+/// Returns `value` as passed by the thread `delta` lanes above the current
+/// one, or the thread's own `value` if there is no such lane.
+///
+/// All the threads of the warp set in `mask` must execute the same call.
+#[inline]
+#[cfg_attr(test, assert_instr(shfl.sync.down.b32))]
+pub unsafe fn _shfl_down_sync_f32(mask: u32, value: f32, delta: u32) -> f32 {
+    shfl_sync_down_f32(mask, value, delta, 0x1f)
+}
+
+/// Returns `value` as passed by the thread whose lane index is the current
+/// one xor `lane_mask`.
+///
+/// All the threads of the warp set in `mask` must execute the same call.
+#[inline]
+#[cfg_attr(test, assert_instr(shfl.sync.bfly.b32))]
+pub unsafe fn _shfl_xor_sync_i32(mask: u32, value: i32, lane_mask: u32) -> i32 {
+    shfl_sync_bfly_i32(mask, value, lane_mask, 0x1f)
+}
+
+/// Returns `value` as passed by the thread whose lane index is the current
+/// one xor `lane_mask`.
+///
+/// All the threads of the warp set in `mask` must execute the same call.
+#[inline]
+#[cfg_attr(test, assert_instr(shfl.sync.bfly.b32))]
+pub unsafe fn _shfl_xor_sync_f32(mask: u32, value: f32, lane_mask: u32) -> f32 {
+    shfl_sync_bfly_f32(mask, value, lane_mask, 0x1f)
+}
+
+/// Sum of `value` over the threads of the warp set in `mask`.
+///
+/// Requires `sm_80` or later.
+#[inline]
+#[cfg_attr(test, assert_instr(redux.sync.add.s32))]
+pub unsafe fn _reduce_add_sync(mask: u32, value: u32) -> u32 {
+    redux_sync_add(value, mask)
+}
+
+/// Minimum of `value` over the threads of the warp set in `mask`.
+///
+/// Requires `sm_80` or later.
+#[inline]
+#[cfg_attr(test, assert_instr(redux.sync.min.s32))]
+pub unsafe fn _reduce_min_sync_i32(mask: u32, value: i32) -> i32 {
+    redux_sync_min(value, mask)
+}
+
+/// Minimum of `value` over the threads of the warp set in `mask`.
+///
+/// Requires `sm_80` or later.
+#[inline]
+#[cfg_attr(test, assert_instr(redux.sync.min.u32))]
+pub unsafe fn _reduce_min_sync_u32(mask: u32, value: u32) -> u32 {
+    redux_sync_umin(value, mask)
+}
+
+/// Maximum of `value` over the threads of the warp set in `mask`.
+///
+/// Requires `sm_80` or later.
+#[inline]
+#[cfg_attr(test, assert_instr(redux.sync.max.s32))]
+pub unsafe fn _reduce_max_sync_i32(mask: u32, value: i32) -> i32 {
+    redux_sync_max(value, mask)
+}
+
+/// Maximum of `value` over the threads of the warp set in `mask`.
+///
+/// Requires `sm_80` or later.
+#[inline]
+#[cfg_attr(test, assert_instr(redux.sync.max.u32))]
+pub unsafe fn _reduce_max_sync_u32(mask: u32, value: u32) -> u32 {
+    redux_sync_umax(value, mask)
+}
+
+/// Bitwise and of `value` over the threads of the warp set in `mask`.
+///
+/// Requires `sm_80` or later.
+#[inline]
+#[cfg_attr(test, assert_instr(redux.sync.and.b32))]
+pub unsafe fn _reduce_and_sync(mask: u32, value: u32) -> u32 {
+    redux_sync_and(value, mask)
+}
+
+/// Bitwise or of `value` over the threads of the warp set in `mask`.
+///
+/// Requires `sm_80` or later.
+#[inline]
+#[cfg_attr(test, assert_instr(redux.sync.or.b32))]
+pub unsafe fn _reduce_or_sync(mask: u32, value: u32) -> u32 {
+    redux_sync_or(value, mask)
+}
+
+/// Bitwise xor of `value` over the threads of the warp set in `mask`.
+///
+/// Requires `sm_80` or later.
+#[inline]
+#[cfg_attr(test, assert_instr(redux.sync.xor.b32))]
+pub unsafe fn _reduce_xor_sync(mask: u32, value: u32) -> u32 {
+    redux_sync_xor(value, mask)
+}
+
+// The atomics below are written in assembly: Rust has no way to express
+// pointers to a given state space, so the generic address is converted
+// explicitly before using the state space specific instruction.
+
+/// Atomically adds `value` to the `u32` at `ptr` in global memory, returning
+/// the previous value.
+#[inline]
+#[cfg_attr(test, assert_instr(atom.global.add.u32))]
+pub unsafe fn _atomic_add_global_u32(ptr: *mut u32, value: u32) -> u32 {
+    let ret: u32;
+    asm!(
+        "cvta.to.global.u64 {ptr}, {ptr};",
+        "atom.global.add.u32 {ret}, [{ptr}], {value};",
+        ptr = inout(reg64) ptr => _,
+        value = in(reg32) value,
+        ret = lateout(reg32) ret,
+        options(nostack),
+    );
+    ret
+}
+
+/// Atomically adds `value` to the `u64` at `ptr` in global memory, returning
+/// the previous value.
+#[inline]
+#[cfg_attr(test, assert_instr(atom.global.add.u64))]
+pub unsafe fn _atomic_add_global_u64(ptr: *mut u64, value: u64) -> u64 {
+    let ret: u64;
+    asm!(
+        "cvta.to.global.u64 {ptr}, {ptr};",
+        "atom.global.add.u64 {ret}, [{ptr}], {value};",
+        ptr = inout(reg64) ptr => _,
+        value = in(reg64) value,
+        ret = lateout(reg64) ret,
+        options(nostack),
+    );
+    ret
+}
+
+/// Atomically adds `value` to the `f32` at `ptr` in global memory, returning
+/// the previous value.
+#[inline]
+#[cfg_attr(test, assert_instr(atom.global.add.f32))]
+pub unsafe fn _atomic_add_global_f32(ptr: *mut f32, value: f32) -> f32 {
+    let ret: f32;
+    asm!(
+        "cvta.to.global.u64 {ptr}, {ptr};",
+        "atom.global.add.f32 {ret}, [{ptr}], {value};",
+        ptr = inout(reg64) ptr => _,
+        value = in(reg32) value,
+        ret = lateout(reg32) ret,
+        options(nostack),
+    );
+    ret
+}
+
+/// Atomically replaces the `u32` at `ptr` in global memory with `value` if it
+/// is equal to `compare`, returning the previous value.
+#[inline]
+#[cfg_attr(test, assert_instr(atom.global.cas.b32))]
+pub unsafe fn _atomic_cas_global_u32(ptr: *mut u32, compare: u32, value: u32) -> u32 {
+    let ret: u32;
+    asm!(
+        "cvta.to.global.u64 {ptr}, {ptr};",
+        "atom.global.cas.b32 {ret}, [{ptr}], {compare}, {value};",
+        ptr = inout(reg64) ptr => _,
+        compare = in(reg32) compare,
+        value = in(reg32) value,
+        ret = lateout(reg32) ret,
+        options(nostack),
+    );
+    ret
+}
+
+/// Atomically replaces the `u64` at `ptr` in global memory with `value` if it
+/// is equal to `compare`, returning the previous value.
+#[inline]
+#[cfg_attr(test, assert_instr(atom.global.cas.b64))]
+pub unsafe fn _atomic_cas_global_u64(ptr: *mut u64, compare: u64, value: u64) -> u64 {
+    let ret: u64;
+    asm!(
+        "cvta.to.global.u64 {ptr}, {ptr};",
+        "atom.global.cas.b64 {ret}, [{ptr}], {compare}, {value};",
+        ptr = inout(reg64) ptr => _,
+        compare = in(reg64) compare,
+        value = in(reg64) value,
+        ret = lateout(reg64) ret,
+        options(nostack),
+    );
+    ret
+}
+
+/// Atomically adds `value` to the `u32` at `ptr` in shared memory, returning
+/// the previous value.
+#[inline]
+#[cfg_attr(test, assert_instr(atom.shared.add.u32))]
+pub unsafe fn _atomic_add_shared_u32(ptr: *mut u32, value: u32) -> u32 {
+    let ret: u32;
+    asm!(
+        "cvta.to.shared.u64 {ptr}, {ptr};",
+        "atom.shared.add.u32 {ret}, [{ptr}], {value};",
+        ptr = inout(reg64) ptr => _,
+        value = in(reg32) value,
+        ret = lateout(reg32) ret,
+        options(nostack),
+    );
+    ret
+}
+
+/// Atomically adds `value` to the `u64` at `ptr` in shared memory, returning
+/// the previous value.
+#[inline]
+#[cfg_attr(test, assert_instr(atom.shared.add.u64))]
+pub unsafe fn _atomic_add_shared_u64(ptr: *mut u64, value: u64) -> u64 {
+    let ret: u64;
+    asm!(
+        "cvta.to.shared.u64 {ptr}, {ptr};",
+        "atom.shared.add.u64 {ret}, [{ptr}], {value};",
+        ptr = inout(reg64) ptr => _,
+        value = in(reg64) value,
+        ret = lateout(reg64) ret,
+        options(nostack),
+    );
+    ret
+}
+
+/// Atomically adds `value` to the `f32` at `ptr` in shared memory, returning
+/// the previous value.
+#[inline]
+#[cfg_attr(test, assert_instr(atom.shared.add.f32))]
+pub unsafe fn _atomic_add_shared_f32(ptr: *mut f32, value: f32) -> f32 {
+    let ret: f32;
+    asm!(
+        "cvta.to.shared.u64 {ptr}, {ptr};",
+        "atom.shared.add.f32 {ret}, [{ptr}], {value};",
+        ptr = inout(reg64) ptr => _,
+        value = in(reg32) value,
+        ret = lateout(reg32) ret,
+        options(nostack),
+    );
+    ret
+}
+
+/// Atomically replaces the `u32` at `ptr` in shared memory with `value` if it
+/// is equal to `compare`, returning the previous value.
+#[inline]
+#[cfg_attr(test, assert_instr(atom.shared.cas.b32))]
+pub unsafe fn _atomic_cas_shared_u32(ptr: *mut u32, compare: u32, value: u32) -> u32 {
+    let ret: u32;
+    asm!(
+        "cvta.to.shared.u64 {ptr}, {ptr};",
+        "atom.shared.cas.b32 {ret}, [{ptr}], {compare}, {value};",
+        ptr = inout(reg64) ptr => _,
+        compare = in(reg32) compare,
+        value = in(reg32) value,
+        ret = lateout(reg32) ret,
+        options(nostack),
+    );
+    ret
+}
+
+/// Atomically replaces the `u64` at `ptr` in shared memory with `value` if it
+/// is equal to `compare`, returning the previous value.
+#[inline]
+#[cfg_attr(test, assert_instr(atom.shared.cas.b64))]
+pub unsafe fn _atomic_cas_shared_u64(ptr: *mut u64, compare: u64, value: u64) -> u64 {
+    let ret: u64;
+    asm!(
+        "cvta.to.shared.u64 {ptr}, {ptr};",
+        "atom.shared.cas.b64 {ret}, [{ptr}], {compare}, {value};",
+        ptr = inout(reg64) ptr => _,
+        compare = in(reg64) compare,
+        value = in(reg64) value,
+        ret = lateout(reg64) ret,
+        options(nostack),
+    );
+    ret
+}
+
 // Basic CUDA syscall declarations.
 extern "C" {
     /// Print formatted output from a kernel to a host-side output stream.
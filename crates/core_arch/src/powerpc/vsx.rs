@@ -3,11 +3,15 @@
 //! The references are: [POWER ISA v2.07B (for POWER8 & POWER8 with NVIDIA
 //! NVlink)] and [POWER ISA v3.0B (for POWER9)].
 //!
+//! The intrinsics added by these ISA versions require the `power8-vector`,
+//! `power8-crypto` or `power9-vector` target features.
+//!
 //! [POWER ISA v2.07B (for POWER8 & POWER8 with NVIDIA NVlink)]: https://ibm.box.com/s/jd5w15gz301s5b5dt375mshpq9c3lh4u
 //! [POWER ISA v3.0B (for POWER9)]: https://ibm.box.com/s/1hzcwkwf8rbju5h9iyf44wm94amnlcrv
 
 #![allow(non_camel_case_types)]
 
+use super::altivec::*;
 use crate::core_arch::simd_llvm::*;
 
 #[cfg(test)]
@@ -32,6 +36,58 @@ types! {
     // pub struct vector_unsigned___int128 = i128x1;
 }
 
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.ctpop.v16i8"]
+    fn vpopcntb(a: vector_unsigned_char) -> vector_unsigned_char;
+    #[link_name = "llvm.ctpop.v8i16"]
+    fn vpopcnth(a: vector_unsigned_short) -> vector_unsigned_short;
+    #[link_name = "llvm.ctpop.v4i32"]
+    fn vpopcntw(a: vector_unsigned_int) -> vector_unsigned_int;
+    #[link_name = "llvm.ctpop.v2i64"]
+    fn vpopcntd(a: vector_unsigned_long) -> vector_unsigned_long;
+
+    #[link_name = "llvm.ppc.altivec.crypto.vcipher"]
+    fn vcipher(a: vector_unsigned_long, b: vector_unsigned_long) -> vector_unsigned_long;
+    #[link_name = "llvm.ppc.altivec.crypto.vcipherlast"]
+    fn vcipherlast(a: vector_unsigned_long, b: vector_unsigned_long) -> vector_unsigned_long;
+    #[link_name = "llvm.ppc.altivec.crypto.vncipher"]
+    fn vncipher(a: vector_unsigned_long, b: vector_unsigned_long) -> vector_unsigned_long;
+    #[link_name = "llvm.ppc.altivec.crypto.vncipherlast"]
+    fn vncipherlast(a: vector_unsigned_long, b: vector_unsigned_long) -> vector_unsigned_long;
+    #[link_name = "llvm.ppc.altivec.crypto.vsbox"]
+    fn vsbox(a: vector_unsigned_long) -> vector_unsigned_long;
+    #[link_name = "llvm.ppc.altivec.crypto.vshasigmaw"]
+    fn vshasigmaw(a: vector_unsigned_int, st: i32, six: i32) -> vector_unsigned_int;
+    #[link_name = "llvm.ppc.altivec.crypto.vshasigmad"]
+    fn vshasigmad(a: vector_unsigned_long, st: i32, six: i32) -> vector_unsigned_long;
+
+    #[link_name = "llvm.ppc.altivec.vcmpneb"]
+    fn vcmpneb(a: vector_signed_char, b: vector_signed_char) -> vector_bool_char;
+    #[link_name = "llvm.ppc.altivec.vcmpneh"]
+    fn vcmpneh(a: vector_signed_short, b: vector_signed_short) -> vector_bool_short;
+    #[link_name = "llvm.ppc.altivec.vcmpnew"]
+    fn vcmpnew(a: vector_signed_int, b: vector_signed_int) -> vector_bool_int;
+
+    #[link_name = "llvm.ppc.altivec.vclzlsbb"]
+    fn vclzlsbb(a: vector_signed_char) -> i32;
+    #[link_name = "llvm.ppc.altivec.vctzlsbb"]
+    fn vctzlsbb(a: vector_signed_char) -> i32;
+
+    #[link_name = "llvm.ppc.vsx.lxvl"]
+    fn lxvl(p: *const u8, len: u64) -> vector_unsigned_int;
+
+    #[link_name = "llvm.ppc.vsx.xvcvhpsp"]
+    fn xvcvhpsp(a: vector_unsigned_short) -> vector_float;
+
+    #[link_name = "llvm.bswap.v8i16"]
+    fn xxbrh(a: vector_unsigned_short) -> vector_unsigned_short;
+    #[link_name = "llvm.bswap.v4i32"]
+    fn xxbrw(a: vector_unsigned_int) -> vector_unsigned_int;
+    #[link_name = "llvm.bswap.v2i64"]
+    fn xxbrd(a: vector_unsigned_long) -> vector_unsigned_long;
+}
+
 mod sealed {
     use super::*;
     use crate::core_arch::simd::*;
@@ -73,6 +129,303 @@ mod sealed {
     vec_xxpermdi! { vector_signed_long }
     vec_xxpermdi! { vector_bool_long }
     vec_xxpermdi! { vector_double }
+
+    pub trait VectorMul {
+        unsafe fn vec_mul(self, b: Self) -> Self;
+    }
+
+    // There is no doubleword vector multiplication before POWER10.
+    #[inline]
+    #[target_feature(enable = "power8-vector")]
+    #[cfg_attr(
+        all(
+            test,
+            target_arch = "powerpc64",
+            not(target_feature = "power10-vector")
+        ),
+        assert_instr(mulld)
+    )]
+    #[cfg_attr(
+        all(test, target_arch = "powerpc64", target_feature = "power10-vector"),
+        assert_instr(vmulld)
+    )]
+    unsafe fn vmulld(a: vector_signed_long, b: vector_signed_long) -> vector_signed_long {
+        simd_mul(a, b)
+    }
+
+    impl VectorMul for vector_signed_long {
+        #[inline]
+        #[target_feature(enable = "power8-vector")]
+        unsafe fn vec_mul(self, b: Self) -> Self {
+            vmulld(self, b)
+        }
+    }
+
+    impl VectorMul for vector_unsigned_long {
+        #[inline]
+        #[target_feature(enable = "power8-vector")]
+        unsafe fn vec_mul(self, b: Self) -> Self {
+            transmute(vmulld(transmute(self), transmute(b)))
+        }
+    }
+
+    pub trait VectorPopcnt {
+        type Result;
+        unsafe fn vec_popcnt(self) -> Self::Result;
+    }
+
+    macro_rules! impl_vec_popcnt {
+        ($fun:ident $instr:ident ($a:ty, $b:ty) -> $r:ty) => {
+            #[inline]
+            #[target_feature(enable = "power8-vector")]
+            #[cfg_attr(test, assert_instr($instr))]
+            unsafe fn $fun(a: $r) -> $r {
+                $instr(a)
+            }
+
+            impl VectorPopcnt for $a {
+                type Result = $r;
+                #[inline]
+                #[target_feature(enable = "power8-vector")]
+                unsafe fn vec_popcnt(self) -> $r {
+                    $fun(transmute(self))
+                }
+            }
+
+            impl VectorPopcnt for $b {
+                type Result = $r;
+                #[inline]
+                #[target_feature(enable = "power8-vector")]
+                unsafe fn vec_popcnt(self) -> $r {
+                    $fun(transmute(self))
+                }
+            }
+        };
+    }
+
+    impl_vec_popcnt! { vec_vpopcntb vpopcntb (vector_signed_char, vector_unsigned_char) -> vector_unsigned_char }
+    impl_vec_popcnt! { vec_vpopcnth vpopcnth (vector_signed_short, vector_unsigned_short) -> vector_unsigned_short }
+    impl_vec_popcnt! { vec_vpopcntw vpopcntw (vector_signed_int, vector_unsigned_int) -> vector_unsigned_int }
+    impl_vec_popcnt! { vec_vpopcntd vpopcntd (vector_signed_long, vector_unsigned_long) -> vector_unsigned_long }
+
+    pub trait VectorShaSigma {
+        unsafe fn vec_shasigma_be<const ST: i32, const SIX: i32>(self) -> Self;
+    }
+
+    #[inline]
+    #[target_feature(enable = "power8-crypto")]
+    #[cfg_attr(test, assert_instr(vshasigmaw, ST = 1, SIX = 0xf))]
+    unsafe fn vec_vshasigmaw<const ST: i32, const SIX: i32>(
+        a: vector_unsigned_int,
+    ) -> vector_unsigned_int {
+        static_assert_uimm_bits!(ST, 1);
+        static_assert_uimm_bits!(SIX, 4);
+        vshasigmaw(a, ST, SIX)
+    }
+
+    #[inline]
+    #[target_feature(enable = "power8-crypto")]
+    #[cfg_attr(test, assert_instr(vshasigmad, ST = 1, SIX = 0xf))]
+    unsafe fn vec_vshasigmad<const ST: i32, const SIX: i32>(
+        a: vector_unsigned_long,
+    ) -> vector_unsigned_long {
+        static_assert_uimm_bits!(ST, 1);
+        static_assert_uimm_bits!(SIX, 4);
+        vshasigmad(a, ST, SIX)
+    }
+
+    impl VectorShaSigma for vector_unsigned_int {
+        #[inline]
+        #[target_feature(enable = "power8-crypto")]
+        unsafe fn vec_shasigma_be<const ST: i32, const SIX: i32>(self) -> Self {
+            vec_vshasigmaw::<ST, SIX>(self)
+        }
+    }
+
+    impl VectorShaSigma for vector_unsigned_long {
+        #[inline]
+        #[target_feature(enable = "power8-crypto")]
+        unsafe fn vec_shasigma_be<const ST: i32, const SIX: i32>(self) -> Self {
+            vec_vshasigmad::<ST, SIX>(self)
+        }
+    }
+
+    pub trait VectorCmpNe<Other> {
+        type Result;
+        unsafe fn vec_cmpne(self, b: Other) -> Self::Result;
+    }
+
+    #[inline]
+    #[target_feature(enable = "power9-vector")]
+    #[cfg_attr(test, assert_instr(vcmpneb))]
+    unsafe fn vec_vcmpneb(a: vector_signed_char, b: vector_signed_char) -> vector_bool_char {
+        vcmpneb(a, b)
+    }
+
+    #[inline]
+    #[target_feature(enable = "power9-vector")]
+    #[cfg_attr(test, assert_instr(vcmpneh))]
+    unsafe fn vec_vcmpneh(a: vector_signed_short, b: vector_signed_short) -> vector_bool_short {
+        vcmpneh(a, b)
+    }
+
+    #[inline]
+    #[target_feature(enable = "power9-vector")]
+    #[cfg_attr(test, assert_instr(vcmpnew))]
+    unsafe fn vec_vcmpnew(a: vector_signed_int, b: vector_signed_int) -> vector_bool_int {
+        vcmpnew(a, b)
+    }
+
+    // There is no doubleword variant of `vcmpne`.
+    #[inline]
+    #[target_feature(enable = "power9-vector")]
+    #[cfg_attr(test, assert_instr(vcmpequd))]
+    unsafe fn vec_vcmpned(a: vector_signed_long, b: vector_signed_long) -> vector_bool_long {
+        simd_ne(a, b)
+    }
+
+    macro_rules! impl_vec_cmpne {
+        ($fun:ident ($($a:ty),*) -> $r:ty) => {
+            $(
+                impl VectorCmpNe<$a> for $a {
+                    type Result = $r;
+                    #[inline]
+                    #[target_feature(enable = "power9-vector")]
+                    unsafe fn vec_cmpne(self, b: $a) -> $r {
+                        $fun(transmute(self), transmute(b))
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_vec_cmpne! { vec_vcmpneb (vector_signed_char, vector_unsigned_char, vector_bool_char) -> vector_bool_char }
+    impl_vec_cmpne! { vec_vcmpneh (vector_signed_short, vector_unsigned_short, vector_bool_short) -> vector_bool_short }
+    impl_vec_cmpne! { vec_vcmpnew (vector_signed_int, vector_unsigned_int, vector_bool_int) -> vector_bool_int }
+    impl_vec_cmpne! { vec_vcmpned (vector_signed_long, vector_unsigned_long, vector_bool_long) -> vector_bool_long }
+
+    pub trait VectorCntlzLsbb {
+        unsafe fn vec_cntlz_lsbb(self) -> i32;
+    }
+
+    // The instructions count from the most significant byte of the register,
+    // which holds the last element on little-endian targets.
+    #[inline]
+    #[target_feature(enable = "power9-vector")]
+    #[cfg_attr(all(test, target_endian = "little"), assert_instr(vctzlsbb))]
+    #[cfg_attr(all(test, target_endian = "big"), assert_instr(vclzlsbb))]
+    unsafe fn vec_vclzlsbb(a: vector_signed_char) -> i32 {
+        if cfg!(target_endian = "little") {
+            vctzlsbb(a)
+        } else {
+            vclzlsbb(a)
+        }
+    }
+
+    impl VectorCntlzLsbb for vector_signed_char {
+        #[inline]
+        #[target_feature(enable = "power9-vector")]
+        unsafe fn vec_cntlz_lsbb(self) -> i32 {
+            vec_vclzlsbb(self)
+        }
+    }
+
+    impl VectorCntlzLsbb for vector_unsigned_char {
+        #[inline]
+        #[target_feature(enable = "power9-vector")]
+        unsafe fn vec_cntlz_lsbb(self) -> i32 {
+            vec_vclzlsbb(transmute(self))
+        }
+    }
+
+    pub trait VectorXlLen {
+        type Result;
+        unsafe fn vec_xl_len(self, len: usize) -> Self::Result;
+    }
+
+    #[inline]
+    #[target_feature(enable = "power9-vector")]
+    #[cfg_attr(test, assert_instr(lxvl))]
+    unsafe fn vec_lxvl(p: *const u8, len: usize) -> vector_unsigned_char {
+        // The length is in the most significant byte of the operand.
+        transmute(lxvl(p, (len as u64) << 56))
+    }
+
+    macro_rules! impl_vec_xl_len {
+        ($($ty:ident -> $r:ident),*) => {
+            $(
+                impl VectorXlLen for *const $ty {
+                    type Result = $r;
+                    #[inline]
+                    #[target_feature(enable = "power9-vector")]
+                    unsafe fn vec_xl_len(self, len: usize) -> $r {
+                        transmute(vec_lxvl(self as *const u8, len))
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_vec_xl_len! {
+        i8 -> vector_signed_char,
+        u8 -> vector_unsigned_char,
+        i16 -> vector_signed_short,
+        u16 -> vector_unsigned_short,
+        i32 -> vector_signed_int,
+        u32 -> vector_unsigned_int,
+        f32 -> vector_float,
+        i64 -> vector_signed_long,
+        u64 -> vector_unsigned_long,
+        f64 -> vector_double
+    }
+
+    pub trait VectorRevb {
+        unsafe fn vec_revb(self) -> Self;
+    }
+
+    macro_rules! impl_vec_revb {
+        ($fun:ident $instr:ident ($u:ty): $($a:ty),*) => {
+            #[inline]
+            #[target_feature(enable = "power9-vector")]
+            #[cfg_attr(test, assert_instr($instr))]
+            unsafe fn $fun(a: $u) -> $u {
+                $instr(a)
+            }
+
+            $(
+                impl VectorRevb for $a {
+                    #[inline]
+                    #[target_feature(enable = "power9-vector")]
+                    unsafe fn vec_revb(self) -> Self {
+                        transmute($fun(transmute(self)))
+                    }
+                }
+            )*
+        };
+    }
+
+    // Reversing the bytes of single-byte elements leaves them unchanged.
+    macro_rules! impl_vec_revb_byte {
+        ($($a:ty),*) => {
+            $(
+                impl VectorRevb for $a {
+                    #[inline]
+                    #[target_feature(enable = "power9-vector")]
+                    unsafe fn vec_revb(self) -> Self {
+                        self
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_vec_revb_byte! { vector_signed_char, vector_unsigned_char, vector_bool_char }
+    impl_vec_revb! { vec_xxbrh xxbrh (vector_unsigned_short):
+    vector_signed_short, vector_unsigned_short, vector_bool_short }
+    impl_vec_revb! { vec_xxbrw xxbrw (vector_unsigned_int):
+    vector_signed_int, vector_unsigned_int, vector_bool_int, vector_float }
+    impl_vec_revb! { vec_xxbrd xxbrd (vector_unsigned_long):
+    vector_signed_long, vector_unsigned_long, vector_bool_long, vector_double }
 }
 
 /// Vector permute.
@@ -87,6 +440,174 @@ where
     a.vec_xxpermdi(b, DM as u8)
 }
 
+/// Vector Multiply
+#[inline]
+#[target_feature(enable = "power8-vector")]
+pub unsafe fn vec_mul<T>(a: T, b: T) -> T
+where
+    T: sealed::VectorMul,
+{
+    a.vec_mul(b)
+}
+
+/// Vector Population Count
+///
+/// Counts the bits set in each element.
+#[inline]
+#[target_feature(enable = "power8-vector")]
+pub unsafe fn vec_popcnt<T>(a: T) -> <T as sealed::VectorPopcnt>::Result
+where
+    T: sealed::VectorPopcnt,
+{
+    a.vec_popcnt()
+}
+
+/// Vector AES Cipher (Big-Endian)
+///
+/// Performs one round of AES encryption of the state `a` with the round key
+/// `b`, with the bytes of both in big-endian order.
+#[inline]
+#[target_feature(enable = "power8-crypto")]
+#[cfg_attr(test, assert_instr(vcipher))]
+pub unsafe fn vec_cipher_be(
+    a: vector_unsigned_char,
+    b: vector_unsigned_char,
+) -> vector_unsigned_char {
+    transmute(vcipher(transmute(a), transmute(b)))
+}
+
+/// Vector AES Cipher Last (Big-Endian)
+///
+/// Performs the final round of AES encryption, which skips `MixColumns`.
+#[inline]
+#[target_feature(enable = "power8-crypto")]
+#[cfg_attr(test, assert_instr(vcipherlast))]
+pub unsafe fn vec_cipherlast_be(
+    a: vector_unsigned_char,
+    b: vector_unsigned_char,
+) -> vector_unsigned_char {
+    transmute(vcipherlast(transmute(a), transmute(b)))
+}
+
+/// Vector AES Inverse Cipher (Big-Endian)
+///
+/// Performs one round of AES decryption of the state `a` with the round key
+/// `b`, with the bytes of both in big-endian order.
+#[inline]
+#[target_feature(enable = "power8-crypto")]
+#[cfg_attr(test, assert_instr(vncipher))]
+pub unsafe fn vec_ncipher_be(
+    a: vector_unsigned_char,
+    b: vector_unsigned_char,
+) -> vector_unsigned_char {
+    transmute(vncipher(transmute(a), transmute(b)))
+}
+
+/// Vector AES Inverse Cipher Last (Big-Endian)
+///
+/// Performs the final round of AES decryption, which skips
+/// `InvMixColumns`.
+#[inline]
+#[target_feature(enable = "power8-crypto")]
+#[cfg_attr(test, assert_instr(vncipherlast))]
+pub unsafe fn vec_ncipherlast_be(
+    a: vector_unsigned_char,
+    b: vector_unsigned_char,
+) -> vector_unsigned_char {
+    transmute(vncipherlast(transmute(a), transmute(b)))
+}
+
+/// Vector AES S-Box (Big-Endian)
+///
+/// Applies the AES `SubBytes` transformation to each byte.
+#[inline]
+#[target_feature(enable = "power8-crypto")]
+#[cfg_attr(test, assert_instr(vsbox))]
+pub unsafe fn vec_sbox_be(a: vector_unsigned_char) -> vector_unsigned_char {
+    transmute(vsbox(transmute(a)))
+}
+
+/// Vector SHA Sigma (Big-Endian)
+///
+/// Computes the SHA-256 (for words) or SHA-512 (for doublewords) sigma
+/// function of each element: the lowercase `σ` functions if `ST` is 0, the
+/// uppercase `Σ` functions otherwise. The bits of `SIX` select between
+/// `σ0`/`Σ0` (0) and `σ1`/`Σ1` (1) for each element.
+#[inline]
+#[target_feature(enable = "power8-crypto")]
+pub unsafe fn vec_shasigma_be<T, const ST: i32, const SIX: i32>(a: T) -> T
+where
+    T: sealed::VectorShaSigma,
+{
+    a.vec_shasigma_be::<ST, SIX>()
+}
+
+/// Vector Compare Not Equal
+///
+/// For each element of the result, all its bits are set if the
+/// corresponding elements of `a` and `b` are not equal, and cleared
+/// otherwise.
+#[inline]
+#[target_feature(enable = "power9-vector")]
+pub unsafe fn vec_cmpne<T, U>(a: T, b: U) -> <T as sealed::VectorCmpNe<U>>::Result
+where
+    T: sealed::VectorCmpNe<U>,
+{
+    a.vec_cmpne(b)
+}
+
+/// Vector Count Leading Zero Least-Significant Bits Byte
+///
+/// Returns the number of leading elements whose least significant bit is
+/// zero.
+#[inline]
+#[target_feature(enable = "power9-vector")]
+pub unsafe fn vec_cntlz_lsbb<T>(a: T) -> i32
+where
+    T: sealed::VectorCntlzLsbb,
+{
+    a.vec_cntlz_lsbb()
+}
+
+/// Vector Load with Length
+///
+/// Loads the first `len` bytes at `p`, and clears the remaining bytes of the
+/// result. At most 16 bytes are loaded.
+#[inline]
+#[target_feature(enable = "power9-vector")]
+pub unsafe fn vec_xl_len<T>(p: T, len: usize) -> <T as sealed::VectorXlLen>::Result
+where
+    T: sealed::VectorXlLen,
+{
+    p.vec_xl_len(len)
+}
+
+/// Vector Extract Single-Precision from Halfwords
+///
+/// Converts the first four half-precision elements of `a` to single
+/// precision.
+#[inline]
+#[target_feature(enable = "power9-vector")]
+#[cfg_attr(test, assert_instr(xvcvhpsp))]
+pub unsafe fn vec_extract_fp32_from_shorth(a: vector_unsigned_short) -> vector_float {
+    // The instruction converts the odd halfwords in big-endian order, which
+    // are the even ones in little-endian order.
+    let a: vector_unsigned_short = simd_shuffle!(a, a, [0, 0, 1, 1, 2, 2, 3, 3]);
+    xvcvhpsp(a)
+}
+
+/// Vector Reverse Bytes
+///
+/// Reverses the order of the bytes of each element.
+#[inline]
+#[target_feature(enable = "power9-vector")]
+pub unsafe fn vec_revb<T>(a: T) -> T
+where
+    T: sealed::VectorRevb,
+{
+    a.vec_revb()
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(target_arch = "powerpc")]
@@ -118,4 +639,208 @@ mod tests {
     test_vec_xxpermdi! {test_vec_xxpermdi_i64x2, i64x2, vector_signed_long, [0], [-1], [2], [-3]}
     test_vec_xxpermdi! {test_vec_xxpermdi_m64x2, m64x2, vector_bool_long, [false], [true], [false], [true]}
     test_vec_xxpermdi! {test_vec_xxpermdi_f64x2, f64x2, vector_double, [0.0], [1.0], [2.0], [3.0]}
+
+    #[simd_test(enable = "power8-vector")]
+    unsafe fn test_vec_mul() {
+        let a: vector_signed_long = transmute(i64x2::new(i64::MAX, -3));
+        let b: vector_signed_long = transmute(i64x2::new(2, 5));
+        assert_eq!(i64x2::new(-2, -15), transmute(vec_mul(a, b)));
+
+        let a: vector_unsigned_long = transmute(u64x2::new(u64::MAX, 3));
+        let b: vector_unsigned_long = transmute(u64x2::new(3, 5));
+        assert_eq!(u64x2::new(u64::MAX - 2, 15), transmute(vec_mul(a, b)));
+    }
+
+    #[simd_test(enable = "power8-vector")]
+    unsafe fn test_vec_popcnt() {
+        let a: vector_signed_char = transmute(i8x16::new(
+            0, 1, 3, 7, 15, 31, 63, 127, -1, -2, -4, -8, -16, -32, -64, -128,
+        ));
+        let r = u8x16::new(0, 1, 2, 3, 4, 5, 6, 7, 8, 7, 6, 5, 4, 3, 2, 1);
+        assert_eq!(r, transmute(vec_popcnt(a)));
+
+        let a: vector_unsigned_short =
+            transmute(u16x8::new(0, 1, 3, 0xff, 0x100, 0xffff, 0x8000, 0x5555));
+        let r = u16x8::new(0, 1, 2, 8, 1, 16, 1, 8);
+        assert_eq!(r, transmute(vec_popcnt(a)));
+
+        let a: vector_signed_int = transmute(i32x4::new(0, -1, i32::MIN, 0x0f0f));
+        assert_eq!(u32x4::new(0, 32, 1, 8), transmute(vec_popcnt(a)));
+
+        let a: vector_unsigned_long = transmute(u64x2::new(u64::MAX, 0x8000_0000_0000_0001));
+        assert_eq!(u64x2::new(64, 2), transmute(vec_popcnt(a)));
+    }
+
+    #[simd_test(enable = "power8-crypto")]
+    unsafe fn test_vec_sbox_be() {
+        let a: vector_unsigned_char = transmute(u8x16::splat(0x00));
+        assert_eq!(u8x16::splat(0x63), transmute(vec_sbox_be(a)));
+
+        let a: vector_unsigned_char = transmute(u8x16::splat(0x53));
+        assert_eq!(u8x16::splat(0xed), transmute(vec_sbox_be(a)));
+    }
+
+    // With every byte of the state equal, `ShiftRows` and `MixColumns` leave
+    // it unchanged, so a round only substitutes the bytes and adds the key.
+    #[simd_test(enable = "power8-crypto")]
+    unsafe fn test_vec_cipher_be() {
+        let a: vector_unsigned_char = transmute(u8x16::splat(0x53));
+        let k = u8x16::new(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        let r = u8x16::new(
+            0xed, 0xec, 0xef, 0xee, 0xe9, 0xe8, 0xeb, 0xea, 0xe5, 0xe4, 0xe7, 0xe6, 0xe1, 0xe0,
+            0xe3, 0xe2,
+        );
+        assert_eq!(r, transmute(vec_cipher_be(a, transmute(k))));
+        assert_eq!(r, transmute(vec_cipherlast_be(a, transmute(k))));
+    }
+
+    #[simd_test(enable = "power8-crypto")]
+    unsafe fn test_vec_ncipher_be() {
+        let a: vector_unsigned_char = transmute(u8x16::splat(0xed));
+
+        // `vncipher` adds the key before `InvMixColumns`.
+        let k: vector_unsigned_char = transmute(u8x16::splat(0x0f));
+        assert_eq!(u8x16::splat(0x5c), transmute(vec_ncipher_be(a, k)));
+
+        let k = u8x16::new(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        let r = u8x16::new(
+            0x53, 0x52, 0x51, 0x50, 0x57, 0x56, 0x55, 0x54, 0x5b, 0x5a, 0x59, 0x58, 0x5f, 0x5e,
+            0x5d, 0x5c,
+        );
+        assert_eq!(r, transmute(vec_ncipherlast_be(a, transmute(k))));
+    }
+
+    #[simd_test(enable = "power8-crypto")]
+    unsafe fn test_vec_shasigma_be() {
+        let a: vector_unsigned_int = transmute(u32x4::splat(0x6a09e667));
+        let r = vec_shasigma_be::<_, 0, 0>(a);
+        assert_eq!(u32x4::splat(0xba0cf582), transmute(r));
+        let r = vec_shasigma_be::<_, 0, 0xf>(a);
+        assert_eq!(u32x4::splat(0xcfe5da3c), transmute(r));
+        let r = vec_shasigma_be::<_, 1, 0>(a);
+        assert_eq!(u32x4::splat(0xce20b47e), transmute(r));
+        let r = vec_shasigma_be::<_, 1, 0xf>(a);
+        assert_eq!(u32x4::splat(0x55b65510), transmute(r));
+
+        let a: vector_unsigned_long = transmute(u64x2::splat(0x6a09e667f3bcc908));
+        let r = vec_shasigma_be::<_, 0, 0>(a);
+        assert_eq!(u64x2::splat(0x3dbae91951caa1df), transmute(r));
+        let r = vec_shasigma_be::<_, 0, 0xf>(a);
+        assert_eq!(u64x2::splat(0xc8c619e73ee44510), transmute(r));
+        let r = vec_shasigma_be::<_, 1, 0>(a);
+        assert_eq!(u64x2::splat(0x08c4db56aac80c2a), transmute(r));
+        let r = vec_shasigma_be::<_, 1, 0xf>(a);
+        assert_eq!(u64x2::splat(0x259a6cc1643336ef), transmute(r));
+    }
+
+    #[simd_test(enable = "power9-vector")]
+    unsafe fn test_vec_cmpne() {
+        let a: vector_signed_char = transmute(i8x16::new(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        ));
+        let b: vector_signed_char = transmute(i8x16::new(
+            0, -1, 2, -3, 4, -5, 6, -7, 8, -9, 10, -11, 12, -13, 14, -15,
+        ));
+        let r = m8x16::new(
+            false, true, false, true, false, true, false, true, false, true, false, true, false,
+            true, false, true,
+        );
+        assert_eq!(r, transmute(vec_cmpne(a, b)));
+
+        let a: vector_unsigned_short = transmute(u16x8::new(0, 1, 2, 3, 4, 5, 6, 7));
+        let b: vector_unsigned_short = transmute(u16x8::new(0, 1, 0, 3, 0, 5, 0, 7));
+        let r = m16x8::new(false, false, true, false, true, false, true, false);
+        assert_eq!(r, transmute(vec_cmpne(a, b)));
+
+        let a: vector_bool_int = transmute(m32x4::new(true, true, false, false));
+        let b: vector_bool_int = transmute(m32x4::new(true, false, true, false));
+        assert_eq!(
+            m32x4::new(false, true, true, false),
+            transmute(vec_cmpne(a, b))
+        );
+
+        let a: vector_signed_long = transmute(i64x2::new(-1, 1));
+        let b: vector_signed_long = transmute(i64x2::new(-1, -1));
+        assert_eq!(m64x2::new(false, true), transmute(vec_cmpne(a, b)));
+    }
+
+    #[simd_test(enable = "power9-vector")]
+    unsafe fn test_vec_cntlz_lsbb() {
+        let a: vector_unsigned_char =
+            transmute(u8x16::new(0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0));
+        assert_eq!(3, vec_cntlz_lsbb(a));
+
+        let a: vector_signed_char = transmute(i8x16::splat(-2));
+        assert_eq!(16, vec_cntlz_lsbb(a));
+
+        let a: vector_signed_char = transmute(i8x16::splat(-1));
+        assert_eq!(0, vec_cntlz_lsbb(a));
+    }
+
+    #[simd_test(enable = "power9-vector")]
+    unsafe fn test_vec_xl_len() {
+        let v: [u8; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let r = u8x16::new(1, 2, 3, 4, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+        assert_eq!(r, transmute(vec_xl_len(v.as_ptr(), 5)));
+        assert_eq!(u8x16::splat(0), transmute(vec_xl_len(v.as_ptr(), 0)));
+
+        let v: [u32; 4] = [1, 2, 3, 4];
+        assert_eq!(u32x4::new(1, 2, 0, 0), transmute(vec_xl_len(v.as_ptr(), 8)));
+        let r = u32x4::new(1, 2, 3, 4);
+        assert_eq!(r, transmute(vec_xl_len(v.as_ptr(), 16)));
+        assert_eq!(r, transmute(vec_xl_len(v.as_ptr(), 255)));
+    }
+
+    #[simd_test(enable = "power9-vector")]
+    #[cfg(target_endian = "little")]
+    unsafe fn test_vec_xl_len_partial_element() {
+        // The loaded bytes fill the elements from element 0, each element in
+        // memory order, so a partial element only gets its low-order bytes.
+        let v: [u16; 8] = [
+            0x0102, 0x0304, 0x0506, 0x0708, 0x090a, 0x0b0c, 0x0d0e, 0x0f10,
+        ];
+        let r = u16x8::new(0x0102, 0x0004, 0, 0, 0, 0, 0, 0);
+        assert_eq!(r, transmute(vec_xl_len(v.as_ptr(), 3)));
+
+        let v: [u64; 2] = [0x0102_0304_0506_0708, 0x090a_0b0c_0d0e_0f10];
+        let r = u64x2::new(0x0102_0304_0506_0708, 0x000e_0f10);
+        assert_eq!(r, transmute(vec_xl_len(v.as_ptr(), 11)));
+    }
+
+    #[simd_test(enable = "power9-vector")]
+    unsafe fn test_vec_extract_fp32_from_shorth() {
+        let a: vector_unsigned_short =
+            transmute(u16x8::new(0x3c00, 0xc000, 0x3800, 0x7c00, 0, 0, 0, 0));
+        let r = f32x4::new(1.0, -2.0, 0.5, f32::INFINITY);
+        assert_eq!(r, transmute(vec_extract_fp32_from_shorth(a)));
+    }
+
+    #[simd_test(enable = "power9-vector")]
+    unsafe fn test_vec_revb() {
+        let a: vector_unsigned_char = transmute(u8x16::new(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        ));
+        assert_eq!(
+            u8x16::new(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15),
+            transmute(vec_revb(a))
+        );
+
+        let a: vector_signed_short = transmute(i16x8::new(0x0102, 0x0304, 0, 0, 0, 0, 0, -1));
+        let r = i16x8::new(0x0201, 0x0403, 0, 0, 0, 0, 0, -1);
+        assert_eq!(r, transmute(vec_revb(a)));
+
+        let a: vector_unsigned_int = transmute(u32x4::new(0x01020304, 0, 0xff, 0));
+        let r = u32x4::new(0x04030201, 0, 0xff000000, 0);
+        assert_eq!(r, transmute(vec_revb(a)));
+
+        let a: vector_float = transmute(u32x4::splat(0x0000803f));
+        assert_eq!(f32x4::splat(1.0), transmute(vec_revb(a)));
+
+        let a: vector_unsigned_long = transmute(u64x2::new(0x0102030405060708, 1));
+        let r = u64x2::new(0x0807060504030201, 0x0100000000000000);
+        assert_eq!(r, transmute(vec_revb(a)));
+
+        let a: vector_double = transmute(u64x2::splat(0x000000000000f03f));
+        assert_eq!(f64x2::splat(1.0), transmute(vec_revb(a)));
+    }
 }
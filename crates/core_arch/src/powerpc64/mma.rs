@@ -0,0 +1,406 @@
+//! POWER10 Matrix-Multiply Assist (MMA) intrinsics.
+//!
+//! The reference is the [Power ISA v3.1], and the names of the intrinsics
+//! follow the [GCC documentation].
+//!
+//! MMA is an optional facility of Power ISA 3.1, so its availability has to
+//! be checked with `is_powerpc64_feature_detected!("mma")` before calling
+//! these functions. LLVM models the accumulators as `<512 x i1>` values, which
+//! cannot be expressed in Rust, so the intrinsics are implemented with inline
+//! assembly instead.
+//!
+//! `__vector_quad` and `__vector_pair` hold the contents of their registers
+//! in memory order. An accumulator is primed from a `__vector_quad` before
+//! every operation and deprimed into it afterwards, so there are no separate
+//! prime (`xxmtacc`) and deprime (`xxmfacc`) intrinsics.
+//!
+//! [Power ISA v3.1]: https://files.openpower.foundation/s/dAYSdGzTfW4j2r2
+//! [GCC documentation]: https://gcc.gnu.org/onlinedocs/gcc/PowerPC-Matrix-Multiply-Assist-Built-in-Functions.html
+
+#![allow(non_camel_case_types)]
+
+use crate::arch::asm;
+use crate::core_arch::powerpc::*;
+use crate::mem;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// A 512-bit accumulator of the Matrix-Multiply Assist facility.
+#[derive(Copy, Clone, Debug)]
+#[repr(C, align(64))]
+pub struct __vector_quad([vector_unsigned_char; 4]);
+
+/// A pair of 128-bit vectors, as loaded and stored by `lxvp` and `stxvp`.
+#[derive(Copy, Clone, Debug)]
+#[repr(C, align(32))]
+pub struct __vector_pair([vector_unsigned_char; 2]);
+
+// The registers of an accumulator or pair are in reverse memory order on
+// little-endian targets.
+#[inline(always)]
+fn to_vsrs<const N: usize>(v: [vector_unsigned_char; N]) -> [vector_unsigned_char; N] {
+    let mut r = v;
+    if cfg!(target_endian = "little") {
+        for i in 0..N {
+            r[i] = v[N - 1 - i];
+        }
+    }
+    r
+}
+
+// Workaround ptr::copy_nonoverlapping not being inlined
+extern "rust-intrinsic" {
+    #[rustc_const_stable(feature = "const_intrinsic_copy", since = "1.63.0")]
+    #[rustc_nounwind]
+    fn copy_nonoverlapping<T>(src: *const T, dst: *mut T, count: usize);
+}
+
+#[inline(always)]
+fn from_vsrs<const N: usize>(v: [vector_unsigned_char; N]) -> [vector_unsigned_char; N] {
+    to_vsrs(v)
+}
+
+macro_rules! ger {
+    ($(#[$doc:meta])* $name:ident, $instr:ident, $a:ident: $a_ty:ident) => {
+        $(#[$doc])*
+        #[inline]
+        #[target_feature(enable = "power10-vector")]
+        #[cfg_attr(test, assert_instr($instr))]
+        pub unsafe fn $name(acc: *mut __vector_quad, $a: $a_ty, b: vector_unsigned_char) {
+            let (q0, q1, q2, q3);
+            ger!(@asm $instr, $a: $a_ty, b, out(q0, q1, q2, q3));
+            (*acc).0 = from_vsrs([q0, q1, q2, q3]);
+        }
+    };
+    ($(#[$doc:meta])* $name:ident, $instr:ident, $a:ident: $a_ty:ident, acc) => {
+        $(#[$doc])*
+        #[inline]
+        #[target_feature(enable = "power10-vector")]
+        #[cfg_attr(test, assert_instr($instr))]
+        pub unsafe fn $name(acc: *mut __vector_quad, $a: $a_ty, b: vector_unsigned_char) {
+            let [mut q0, mut q1, mut q2, mut q3] = to_vsrs((*acc).0);
+            ger!(@asm $instr, $a: $a_ty, b, inout(q0, q1, q2, q3));
+            (*acc).0 = from_vsrs([q0, q1, q2, q3]);
+        }
+    };
+    // The accumulator is always ACC[0], which overlaps VSRs 0 to 3.
+    (@asm $instr:ident, $a:ident: vector_unsigned_char, $b:ident, $dir:ident($($q:ident),*)) => {
+        ger!(@asm_impl $instr, concat!("{", stringify!($a), "}"), $b, $dir($($q),*),
+             $a = in(vsreg) $a)
+    };
+    // The pair operand has to be an even/odd register pair, outside of the
+    // accumulator.
+    (@asm $instr:ident, $a:ident: __vector_pair, $b:ident, $dir:ident($($q:ident),*)) => {{
+        let [p0, p1] = to_vsrs($a.0);
+        ger!(@asm_impl $instr, "32", $b, $dir($($q),*),
+             in("vs32") p0, in("vs33") p1)
+    }};
+    (@asm_impl $instr:ident, $a:expr, $b:ident, inout($q0:ident, $q1:ident, $q2:ident, $q3:ident),
+     $($operands:tt)*) => {
+        asm!(
+            "xxmtacc 0",
+            concat!(stringify!($instr), " 0, ", $a, ", {", stringify!($b), "}"),
+            "xxmfacc 0",
+            $($operands)*,
+            $b = in(vsreg) $b,
+            inout("vs0") $q0, inout("vs1") $q1, inout("vs2") $q2, inout("vs3") $q3,
+            options(pure, nomem, nostack, preserves_flags),
+        )
+    };
+    (@asm_impl $instr:ident, $a:expr, $b:ident, out($q0:ident, $q1:ident, $q2:ident, $q3:ident),
+     $($operands:tt)*) => {
+        asm!(
+            concat!(stringify!($instr), " 0, ", $a, ", {", stringify!($b), "}"),
+            "xxmfacc 0",
+            $($operands)*,
+            $b = in(vsreg) $b,
+            out("vs0") $q0, out("vs1") $q1, out("vs2") $q2, out("vs3") $q3,
+            options(pure, nomem, nostack, preserves_flags),
+        )
+    };
+}
+
+ger! {
+    /// Sets `acc` to the outer product of the single-precision elements of
+    /// `a` and `b`: element `j` of row `i` is `a[i] * b[j]`.
+    __builtin_mma_xvf32ger, xvf32ger, a: vector_unsigned_char
+}
+ger! {
+    /// Adds the outer product of the single-precision elements of `a` and
+    /// `b` to `acc`.
+    __builtin_mma_xvf32gerpp, xvf32gerpp, a: vector_unsigned_char, acc
+}
+ger! {
+    /// Subtracts `acc` from the outer product of the single-precision
+    /// elements of `a` and `b`.
+    __builtin_mma_xvf32gerpn, xvf32gerpn, a: vector_unsigned_char, acc
+}
+ger! {
+    /// Subtracts the outer product of the single-precision elements of `a`
+    /// and `b` from `acc`.
+    __builtin_mma_xvf32gernp, xvf32gernp, a: vector_unsigned_char, acc
+}
+ger! {
+    /// Subtracts `acc` from the negated outer product of the
+    /// single-precision elements of `a` and `b`.
+    __builtin_mma_xvf32gernn, xvf32gernn, a: vector_unsigned_char, acc
+}
+ger! {
+    /// Sets `acc` to the outer product of the four double-precision elements
+    /// of `a` and the two of `b`: element `j` of row `i` is `a[i] * b[j]`.
+    __builtin_mma_xvf64ger, xvf64ger, a: __vector_pair
+}
+ger! {
+    /// Adds the outer product of the double-precision elements of `a` and
+    /// `b` to `acc`.
+    __builtin_mma_xvf64gerpp, xvf64gerpp, a: __vector_pair, acc
+}
+ger! {
+    /// Subtracts `acc` from the outer product of the double-precision
+    /// elements of `a` and `b`.
+    __builtin_mma_xvf64gerpn, xvf64gerpn, a: __vector_pair, acc
+}
+ger! {
+    /// Subtracts the outer product of the double-precision elements of `a`
+    /// and `b` from `acc`.
+    __builtin_mma_xvf64gernp, xvf64gernp, a: __vector_pair, acc
+}
+ger! {
+    /// Subtracts `acc` from the negated outer product of the
+    /// double-precision elements of `a` and `b`.
+    __builtin_mma_xvf64gernn, xvf64gernn, a: __vector_pair, acc
+}
+
+/// Sets all the elements of `acc` to zero.
+#[inline]
+#[target_feature(enable = "power10-vector")]
+#[cfg_attr(test, assert_instr(xxsetaccz))]
+pub unsafe fn __builtin_mma_xxsetaccz(acc: *mut __vector_quad) {
+    let (q0, q1, q2, q3);
+    asm!(
+        "xxsetaccz 0",
+        "xxmfacc 0",
+        out("vs0") q0, out("vs1") q1, out("vs2") q2, out("vs3") q3,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    (*acc).0 = from_vsrs([q0, q1, q2, q3]);
+}
+
+/// Primes `acc` with the rows `a`, `b`, `c` and `d`.
+#[inline]
+#[target_feature(enable = "power10-vector")]
+pub unsafe fn __builtin_mma_assemble_acc(
+    acc: *mut __vector_quad,
+    a: vector_unsigned_char,
+    b: vector_unsigned_char,
+    c: vector_unsigned_char,
+    d: vector_unsigned_char,
+) {
+    (*acc).0 = [a, b, c, d];
+}
+
+/// Stores the four rows of `acc` to `res`, which does not need to be
+/// aligned.
+#[inline]
+#[target_feature(enable = "power10-vector")]
+pub unsafe fn __builtin_mma_disassemble_acc(res: *mut u8, acc: *mut __vector_quad) {
+    copy_nonoverlapping(acc as *const u8, res, mem::size_of::<__vector_quad>());
+}
+
+/// Builds `pair` from the vectors `a` and `b`.
+#[inline]
+#[target_feature(enable = "power10-vector")]
+pub unsafe fn __builtin_vsx_assemble_pair(
+    pair: *mut __vector_pair,
+    a: vector_unsigned_char,
+    b: vector_unsigned_char,
+) {
+    (*pair).0 = [a, b];
+}
+
+/// Stores the two vectors of `pair` to `res`, which does not need to be
+/// aligned.
+#[inline]
+#[target_feature(enable = "power10-vector")]
+pub unsafe fn __builtin_vsx_disassemble_pair(res: *mut u8, pair: *mut __vector_pair) {
+    copy_nonoverlapping(pair as *const u8, res, mem::size_of::<__vector_pair>());
+}
+
+/// Vector Load Pair
+///
+/// Loads the pair at `off` bytes from `p` with a single 32-byte load.
+#[inline]
+#[target_feature(enable = "power10-vector")]
+#[cfg_attr(test, assert_instr(lxvp))]
+pub unsafe fn vec_lxvp(off: isize, p: *const __vector_pair) -> __vector_pair {
+    let (p0, p1);
+    asm!(
+        "lxvp 32, 0({p})",
+        p = in(reg_nonzero) p.byte_offset(off),
+        out("vs32") p0, out("vs33") p1,
+        options(pure, readonly, nostack, preserves_flags),
+    );
+    __vector_pair(from_vsrs([p0, p1]))
+}
+
+/// Vector Store Pair
+///
+/// Stores `a` at `off` bytes from `p` with a single 32-byte store.
+#[inline]
+#[target_feature(enable = "power10-vector")]
+#[cfg_attr(test, assert_instr(stxvp))]
+pub unsafe fn vec_stxvp(a: __vector_pair, off: isize, p: *mut __vector_pair) {
+    let [p0, p1] = to_vsrs(a.0);
+    asm!(
+        "stxvp 32, 0({p})",
+        p = in(reg_nonzero) p.byte_offset(off),
+        in("vs32") p0, in("vs33") p1,
+        options(nostack, preserves_flags),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::powerpc64::*;
+
+    use crate::core_arch::simd::*;
+    use crate::mem::transmute;
+    use stdarch_test::simd_test;
+
+    // MMA is optional in Power ISA 3.1, and has no target feature of its own.
+    macro_rules! require_mma {
+        () => {
+            if !is_powerpc64_feature_detected!("mma") {
+                return;
+            }
+        };
+    }
+
+    unsafe fn rows_f32(acc: &mut __vector_quad) -> [[f32; 4]; 4] {
+        let mut rows = [[0.0; 4]; 4];
+        __builtin_mma_disassemble_acc(rows.as_mut_ptr() as *mut u8, acc);
+        rows
+    }
+
+    unsafe fn rows_f64(acc: &mut __vector_quad) -> [[f64; 2]; 4] {
+        let mut rows = [[0.0; 2]; 4];
+        __builtin_mma_disassemble_acc(rows.as_mut_ptr() as *mut u8, acc);
+        rows
+    }
+
+    unsafe fn acc_f32(rows: [[f32; 4]; 4]) -> __vector_quad {
+        let mut acc = transmute([0u8; 64]);
+        let [a, b, c, d]: [vector_unsigned_char; 4] = rows.map(|row| transmute(row));
+        __builtin_mma_assemble_acc(&mut acc, a, b, c, d);
+        acc
+    }
+
+    #[simd_test(enable = "power10-vector")]
+    unsafe fn test_assemble_acc() {
+        let rows = [
+            [0.0, 1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0, 7.0],
+            [8.0, 9.0, 10.0, 11.0],
+            [12.0, 13.0, 14.0, 15.0],
+        ];
+        assert_eq!(rows, rows_f32(&mut acc_f32(rows)));
+
+        let mut pair = transmute([0u8; 32]);
+        let a: vector_unsigned_char = transmute(u64x2::new(0, 1));
+        let b: vector_unsigned_char = transmute(u64x2::new(2, 3));
+        __builtin_vsx_assemble_pair(&mut pair, a, b);
+        let mut r = [0u64; 4];
+        __builtin_vsx_disassemble_pair(r.as_mut_ptr() as *mut u8, &mut pair);
+        assert_eq!([0, 1, 2, 3], r);
+    }
+
+    #[simd_test(enable = "power10-vector")]
+    unsafe fn test_xxsetaccz() {
+        require_mma!();
+        let mut acc = acc_f32([[1.0; 4]; 4]);
+        __builtin_mma_xxsetaccz(&mut acc);
+        assert_eq!([[0.0; 4]; 4], rows_f32(&mut acc));
+    }
+
+    #[simd_test(enable = "power10-vector")]
+    unsafe fn test_xvf32ger() {
+        require_mma!();
+        let a: vector_unsigned_char = transmute(f32x4::new(1.0, 2.0, 3.0, 4.0));
+        let b: vector_unsigned_char = transmute(f32x4::new(1.0, 10.0, 100.0, 1000.0));
+        let c = [[1.0, 2.0, 3.0, 4.0]; 4];
+
+        let mut acc = acc_f32(c);
+        __builtin_mma_xvf32ger(&mut acc, a, b);
+        let product = [
+            [1.0, 10.0, 100.0, 1000.0],
+            [2.0, 20.0, 200.0, 2000.0],
+            [3.0, 30.0, 300.0, 3000.0],
+            [4.0, 40.0, 400.0, 4000.0],
+        ];
+        assert_eq!(product, rows_f32(&mut acc));
+
+        let expected =
+            |f: fn(f32, f32) -> f32| product.map(|row| [0, 1, 2, 3].map(|j| f(row[j], c[0][j])));
+
+        let mut acc = acc_f32(c);
+        __builtin_mma_xvf32gerpp(&mut acc, a, b);
+        assert_eq!(expected(|p, c| p + c), rows_f32(&mut acc));
+
+        let mut acc = acc_f32(c);
+        __builtin_mma_xvf32gerpn(&mut acc, a, b);
+        assert_eq!(expected(|p, c| p - c), rows_f32(&mut acc));
+
+        let mut acc = acc_f32(c);
+        __builtin_mma_xvf32gernp(&mut acc, a, b);
+        assert_eq!(expected(|p, c| -p + c), rows_f32(&mut acc));
+
+        let mut acc = acc_f32(c);
+        __builtin_mma_xvf32gernn(&mut acc, a, b);
+        assert_eq!(expected(|p, c| -p - c), rows_f32(&mut acc));
+    }
+
+    #[simd_test(enable = "power10-vector")]
+    unsafe fn test_xvf64ger() {
+        require_mma!();
+        let a = [1.0f64, 2.0, 3.0, 4.0];
+        let a = vec_lxvp(0, a.as_ptr() as *const __vector_pair);
+        let b: vector_unsigned_char = transmute(f64x2::new(1.0, 10.0));
+        let product = [[1.0, 10.0], [2.0, 20.0], [3.0, 30.0], [4.0, 40.0]];
+
+        let mut acc = transmute([0u8; 64]);
+        __builtin_mma_xvf64ger(&mut acc, a, b);
+        assert_eq!(product, rows_f64(&mut acc));
+
+        let scaled = |k: f64| product.map(|row| row.map(|p| k * p));
+
+        // 2P = P + P
+        __builtin_mma_xvf64gerpp(&mut acc, a, b);
+        assert_eq!(scaled(2.0), rows_f64(&mut acc));
+
+        // -P = P - 2P
+        __builtin_mma_xvf64gerpn(&mut acc, a, b);
+        assert_eq!(scaled(-1.0), rows_f64(&mut acc));
+
+        // -2P = -P + -P
+        __builtin_mma_xvf64gernp(&mut acc, a, b);
+        assert_eq!(scaled(-2.0), rows_f64(&mut acc));
+
+        // P = -P - -2P
+        __builtin_mma_xvf64gernn(&mut acc, a, b);
+        assert_eq!(product, rows_f64(&mut acc));
+    }
+
+    #[simd_test(enable = "power10-vector")]
+    unsafe fn test_vec_lxvp() {
+        let v: [u64; 6] = [0, 1, 2, 3, 4, 5];
+        let mut pair = vec_lxvp(16, v.as_ptr() as *const __vector_pair);
+        let mut r = [0u64; 4];
+        __builtin_vsx_disassemble_pair(r.as_mut_ptr() as *mut u8, &mut pair);
+        assert_eq!([2, 3, 4, 5], r);
+
+        let mut r = [0u64; 6];
+        vec_stxvp(pair, 8, r.as_mut_ptr() as *mut __vector_pair);
+        assert_eq!([0, 2, 3, 4, 5, 0], r);
+    }
+}
@@ -5,4 +5,10 @@
 //!
 //! [64-Bit ELF V2 ABI Specification - Power Architecture]: http://openpowerfoundation.org/wp-content/uploads/resources/leabi/leabi-20170510.pdf
 
+mod mma;
+pub use self::mma::*;
+
+mod scalar;
+pub use self::scalar::*;
+
 pub use crate::core_arch::powerpc::*;
@@ -0,0 +1,321 @@
+//! Scalar fixed-point and storage-control instructions.
+//!
+//! The reference is the [Power ISA v3.1], and the names of the intrinsics
+//! follow the [GCC documentation].
+//!
+//! Most of these instructions were added after the baseline of the
+//! big-endian `powerpc64` targets, and rustc has no target features for
+//! them, so they are implemented with inline assembly. The ISA version each
+//! one needs is documented on the function.
+//!
+//! [Power ISA v3.1]: https://files.openpower.foundation/s/dAYSdGzTfW4j2r2
+//! [GCC documentation]: https://gcc.gnu.org/onlinedocs/gcc/Basic-PowerPC-Built-in-Functions.html
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Deliver A Random Number
+///
+/// Returns a conditioned 64-bit random number, or `u64::MAX as i64` if none
+/// was available.
+///
+/// Requires Power ISA 3.0, check with
+/// `is_powerpc64_feature_detected!("darn")`.
+#[inline]
+#[cfg_attr(test, assert_instr(darn))]
+pub unsafe fn __builtin_darn() -> i64 {
+    let r: i64;
+    asm!("darn {}, 1", out(reg) r, options(nomem, nostack, preserves_flags));
+    r
+}
+
+/// Deliver A Random Number
+///
+/// Returns a raw 64-bit random number, or `u64::MAX as i64` if none was
+/// available.
+///
+/// Requires Power ISA 3.0, check with
+/// `is_powerpc64_feature_detected!("darn")`.
+#[inline]
+#[cfg_attr(test, assert_instr(darn))]
+pub unsafe fn __builtin_darn_raw() -> i64 {
+    let r: i64;
+    asm!("darn {}, 2", out(reg) r, options(nomem, nostack, preserves_flags));
+    r
+}
+
+/// Deliver A Random Number
+///
+/// Returns a conditioned 32-bit random number, or `u32::MAX as i32` if none
+/// was available.
+///
+/// Requires Power ISA 3.0, check with
+/// `is_powerpc64_feature_detected!("darn")`.
+#[inline]
+#[cfg_attr(test, assert_instr(darn))]
+pub unsafe fn __builtin_darn_32() -> i32 {
+    let r: u64;
+    asm!("darn {}, 0", out(reg) r, options(nomem, nostack, preserves_flags));
+    r as i32
+}
+
+/// Bit Permute Doubleword
+///
+/// Each byte `i` of `a` is the index of a bit of `b`, counted from the most
+/// significant bit. Bit `7 - i` of the result is that bit, or `0` if the
+/// index is 64 or more.
+///
+/// Requires Power ISA 2.06.
+#[inline]
+#[cfg_attr(test, assert_instr(bpermd))]
+pub unsafe fn __builtin_bpermd(a: i64, b: i64) -> i64 {
+    let r: i64;
+    asm!(
+        "bpermd {}, {}, {}",
+        lateout(reg) r,
+        in(reg) a,
+        in(reg) b,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Compare Bytes
+///
+/// Each byte of the result is `0xff` if the corresponding bytes of `a` and
+/// `b` are equal, and `0` otherwise.
+///
+/// Requires Power ISA 2.05.
+#[inline]
+#[cfg_attr(test, assert_instr(cmpb))]
+pub unsafe fn __builtin_cmpb(a: i64, b: i64) -> i64 {
+    let r: i64;
+    asm!(
+        "cmpb {}, {}, {}",
+        lateout(reg) r,
+        in(reg) a,
+        in(reg) b,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Population Count Bytes
+///
+/// Each byte of the result is the number of bits set in the corresponding
+/// byte of `a`.
+#[inline]
+#[cfg_attr(test, assert_instr(popcntb))]
+pub unsafe fn __builtin_ppc_popcntb(a: u64) -> u64 {
+    let r: u64;
+    asm!(
+        "popcntb {}, {}",
+        lateout(reg) r,
+        in(reg) a,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Move From Time Base
+///
+/// Returns the 64-bit time base register.
+#[inline]
+#[cfg_attr(test, assert_instr(mftb))]
+pub unsafe fn __builtin_ppc_mftb() -> u64 {
+    let r: u64;
+    asm!("mftb {}", out(reg) r, options(nomem, nostack, preserves_flags));
+    r
+}
+
+/// Divide Doubleword Extended
+///
+/// Divides `a` shifted left by 64 bits by `b`. The result is undefined if
+/// `b` is `0` or the quotient does not fit in 64 bits.
+///
+/// Requires Power ISA 2.06.
+#[inline]
+#[cfg_attr(test, assert_instr(divde))]
+pub unsafe fn __builtin_divde(a: i64, b: i64) -> i64 {
+    let r: i64;
+    asm!(
+        "divde {}, {}, {}",
+        lateout(reg) r,
+        in(reg) a,
+        in(reg) b,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Divide Doubleword Extended Unsigned
+///
+/// Divides `a` shifted left by 64 bits by `b`. The result is undefined if
+/// `b` is `0` or the quotient does not fit in 64 bits.
+///
+/// Requires Power ISA 2.06.
+#[inline]
+#[cfg_attr(test, assert_instr(divdeu))]
+pub unsafe fn __builtin_divdeu(a: u64, b: u64) -> u64 {
+    let r: u64;
+    asm!(
+        "divdeu {}, {}, {}",
+        lateout(reg) r,
+        in(reg) a,
+        in(reg) b,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Data Cache Block Touch
+///
+/// Hints that the block containing `p` will be loaded from soon.
+#[inline]
+#[cfg_attr(test, assert_instr(dcbt))]
+pub unsafe fn __builtin_ppc_dcbt(p: *const u8) {
+    asm!("dcbt 0, {}", in(reg) p, options(readonly, nostack, preserves_flags));
+}
+
+/// Data Cache Block Touch for Store
+///
+/// Hints that the block containing `p` will be stored to soon.
+#[inline]
+#[cfg_attr(test, assert_instr(dcbtst))]
+pub unsafe fn __builtin_ppc_dcbtst(p: *const u8) {
+    asm!("dcbtst 0, {}", in(reg) p, options(readonly, nostack, preserves_flags));
+}
+
+/// Data Cache Block Touch, transient
+///
+/// Hints that the block containing `p` will be loaded from soon, and is
+/// likely to be used only briefly.
+///
+/// Requires Power ISA 2.06.
+#[inline]
+#[cfg_attr(test, assert_instr(dcbt))]
+pub unsafe fn __builtin_ppc_dcbtt(p: *const u8) {
+    asm!("dcbtt 0, {}", in(reg) p, options(readonly, nostack, preserves_flags));
+}
+
+/// Data Cache Block Touch for Store, transient
+///
+/// Hints that the block containing `p` will be stored to soon, and is
+/// likely to be used only briefly.
+///
+/// Requires Power ISA 2.06.
+#[inline]
+#[cfg_attr(test, assert_instr(dcbtst))]
+pub unsafe fn __builtin_ppc_dcbtstt(p: *const u8) {
+    asm!("dcbtstt 0, {}", in(reg) p, options(readonly, nostack, preserves_flags));
+}
+
+/// Trap Word Immediate
+///
+/// Traps if the low 32 bits of `a` are not `0`.
+#[inline]
+#[cfg_attr(test, assert_instr(twnei))]
+pub unsafe fn __builtin_ppc_trap(a: i32) {
+    asm!("twnei {}, 0", in(reg) a, options(nomem, nostack));
+}
+
+/// Trap Doubleword Immediate
+///
+/// Traps if `a` is not `0`.
+#[inline]
+#[cfg_attr(test, assert_instr(tdnei))]
+pub unsafe fn __builtin_ppc_trapd(a: i64) {
+    asm!("tdnei {}, 0", in(reg) a, options(nomem, nostack));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::powerpc64::*;
+
+    #[test]
+    fn test_darn() {
+        if !is_powerpc64_feature_detected!("darn") {
+            return;
+        }
+        unsafe {
+            // The results are random, so only check that the instructions
+            // execute.
+            let _ = __builtin_darn();
+            let _ = __builtin_darn_raw();
+            let _ = __builtin_darn_32();
+        }
+    }
+
+    #[test]
+    fn test_bpermd() {
+        unsafe {
+            // Selects bits 0 (the MSB), 63, 1 and an out of range index.
+            let a = i64::from_be_bytes([0, 63, 1, 64, 255, 255, 255, 255]);
+            let b = i64::MIN | 1;
+            assert_eq!(__builtin_bpermd(a, b), 0b1100_0000);
+            assert_eq!(__builtin_bpermd(a, 0), 0);
+        }
+    }
+
+    #[test]
+    fn test_cmpb() {
+        unsafe {
+            let a = 0x0102_0304_0506_0708;
+            let b = 0x0100_0300_0500_0700;
+            assert_eq!(__builtin_cmpb(a, b), 0xff00_ff00_ff00_ff00_u64 as i64);
+            assert_eq!(__builtin_cmpb(a, a), -1);
+        }
+    }
+
+    #[test]
+    fn test_popcntb() {
+        unsafe {
+            assert_eq!(
+                __builtin_ppc_popcntb(0xff01_0300_0f80_7f00),
+                0x0801_0200_0401_0700
+            );
+        }
+    }
+
+    #[test]
+    fn test_mftb() {
+        unsafe {
+            let a = __builtin_ppc_mftb();
+            let b = __builtin_ppc_mftb();
+            assert!(b >= a);
+        }
+    }
+
+    #[test]
+    fn test_divde() {
+        unsafe {
+            // (1 << 64) / (1 << 62), (-1 << 64) / (1 << 62), (1 << 64) / (1 << 63)
+            // and (3 << 64) / (2^64 - 1).
+            assert_eq!(__builtin_divde(1, 1 << 62), 4);
+            assert_eq!(__builtin_divde(-1, 1 << 62), -4);
+            assert_eq!(__builtin_divdeu(1, 1 << 63), 2);
+            assert_eq!(__builtin_divdeu(3, u64::MAX), 3);
+        }
+    }
+
+    #[test]
+    fn test_dcbt() {
+        let a = [0_u8; 64];
+        unsafe {
+            __builtin_ppc_dcbt(a.as_ptr());
+            __builtin_ppc_dcbtst(a.as_ptr());
+            __builtin_ppc_dcbtt(a.as_ptr());
+            __builtin_ppc_dcbtstt(a.as_ptr());
+        }
+    }
+
+    #[test]
+    fn test_trap() {
+        unsafe {
+            __builtin_ppc_trap(0);
+            __builtin_ppc_trapd(0);
+        }
+    }
+}
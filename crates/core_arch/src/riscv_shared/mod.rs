@@ -5,6 +5,19 @@ pub use p::*;
 
 use crate::arch::asm;
 
+// The `assert_instr` tests need the extensions enabled for the whole crate,
+// see ci/run.sh.
+#[cfg(all(
+    test,
+    any(
+        target_feature = "zawrs",
+        target_feature = "zicbom",
+        target_feature = "zicboz",
+        target_feature = "zicbop"
+    )
+))]
+use stdarch_test::assert_instr;
+
 /// Generates the `PAUSE` instruction
 ///
 /// The PAUSE instruction is a HINT that indicates the current hart's rate of instruction retirement
@@ -14,6 +27,43 @@ pub fn pause() {
     unsafe { asm!(".insn i 0x0F, 0, x0, x0, 0x010", options(nomem, nostack)) }
 }
 
+/// Generates the `WRS.NTO` instruction
+///
+/// The WRS.NTO instruction stalls the current hart while the reservation set registered by a
+/// previous `LR` instruction is valid. The stall ends when the reservation set becomes invalid,
+/// for example because another hart stored to it, or when an interrupt is pending. There is
+/// no time limit on the stall; the execution environment may still raise an exception after an
+/// implementation-defined bound if it does not allow unbounded waits in the current mode.
+///
+/// This instruction is included in the `Zawrs` extension. It is used together with an `LR`
+/// instruction to wait for a store to a memory location, e.g. in the polling loop of a spin-lock.
+///
+/// This function is unsafe because the instruction raises an illegal-instruction exception on
+/// harts that do not implement the `Zawrs` extension.
+#[inline]
+#[cfg_attr(all(test, target_feature = "zawrs"), assert_instr(wrs.nto))]
+pub unsafe fn wrs_nto() {
+    // asm!("wrs.nto", options(nostack))
+    asm!(".insn i 0x73, 0, x0, x0, 0x00d", options(nostack))
+}
+
+/// Generates the `WRS.STO` instruction
+///
+/// The WRS.STO instruction behaves like `WRS.NTO`, except that the stall is also terminated after
+/// a short, implementation-defined timeout. It is suited for polling loops that need to back off
+/// periodically.
+///
+/// This instruction is included in the `Zawrs` extension.
+///
+/// This function is unsafe because the instruction raises an illegal-instruction exception on
+/// harts that do not implement the `Zawrs` extension.
+#[inline]
+#[cfg_attr(all(test, target_feature = "zawrs"), assert_instr(wrs.sto))]
+pub unsafe fn wrs_sto() {
+    // asm!("wrs.sto", options(nostack))
+    asm!(".insn i 0x73, 0, x0, x0, 0x01d", options(nostack))
+}
+
 /// Generates the `NOP` instruction
 ///
 /// The NOP instruction does not change any architecturally visible state, except for
@@ -45,6 +95,121 @@ pub unsafe fn fence_i() {
     asm!("fence.i", options(nostack))
 }
 
+/// Generates the `CBO.CLEAN` instruction
+///
+/// The CBO.CLEAN instruction performs a clean operation on the cache block that contains the
+/// effective address `addr`. A clean operation writes any modified data in the cache block
+/// back to memory, and leaves a valid copy of the block in the caches.
+///
+/// This instruction is included in the `Zicbom` extension. The size of a cache block is
+/// implementation specific; it can be discovered from the device tree or ACPI tables.
+///
+/// This function is unsafe because the instruction raises an illegal-instruction exception
+/// unless the execution environment has enabled it for the current privilege mode.
+#[inline]
+#[cfg_attr(all(test, target_feature = "zicbom"), assert_instr(cbo.clean))]
+pub unsafe fn cbo_clean(addr: *const u8) {
+    // asm!("cbo.clean ({})", in(reg) addr, options(nostack))
+    asm!(".insn i 0x0F, 2, x0, {}, 0x001", in(reg) addr, options(nostack))
+}
+
+/// Generates the `CBO.FLUSH` instruction
+///
+/// The CBO.FLUSH instruction performs a flush operation on the cache block that contains the
+/// effective address `addr`. A flush operation writes any modified data in the cache block
+/// back to memory, and then invalidates the copy of the block held in the caches.
+///
+/// This instruction is included in the `Zicbom` extension.
+///
+/// This function is unsafe because the instruction raises an illegal-instruction exception
+/// unless the execution environment has enabled it for the current privilege mode.
+#[inline]
+#[cfg_attr(all(test, target_feature = "zicbom"), assert_instr(cbo.flush))]
+pub unsafe fn cbo_flush(addr: *const u8) {
+    // asm!("cbo.flush ({})", in(reg) addr, options(nostack))
+    asm!(".insn i 0x0F, 2, x0, {}, 0x002", in(reg) addr, options(nostack))
+}
+
+/// Generates the `CBO.INVAL` instruction
+///
+/// The CBO.INVAL instruction performs an invalidate operation on the cache block that contains
+/// the effective address `addr`. Any modified data held in the caches for that block is
+/// discarded without being written back to memory, unless the execution environment has
+/// configured the instruction to perform a flush instead.
+///
+/// This instruction is included in the `Zicbom` extension.
+///
+/// This function is unsafe because discarding modified data makes previous stores to any byte
+/// of the cache block disappear, and because the instruction raises an illegal-instruction
+/// exception unless the execution environment has enabled it for the current privilege mode.
+#[inline]
+#[cfg_attr(all(test, target_feature = "zicbom"), assert_instr(cbo.inval))]
+pub unsafe fn cbo_inval(addr: *mut u8) {
+    // asm!("cbo.inval ({})", in(reg) addr, options(nostack))
+    asm!(".insn i 0x0F, 2, x0, {}, 0x000", in(reg) addr, options(nostack))
+}
+
+/// Generates the `CBO.ZERO` instruction
+///
+/// The CBO.ZERO instruction stores zeros to the full set of bytes of the cache block that
+/// contains the effective address `addr`. The size of a cache block is implementation specific,
+/// so the caller must know it before using this function.
+///
+/// This instruction is included in the `Zicboz` extension.
+///
+/// This function is unsafe because it writes to every byte of a cache block which is
+/// effectively a store to any memory around `addr`, and because the instruction raises an
+/// illegal-instruction exception unless the execution environment has enabled it for the
+/// current privilege mode.
+#[inline]
+#[cfg_attr(all(test, target_feature = "zicboz"), assert_instr(cbo.zero))]
+pub unsafe fn cbo_zero(addr: *mut u8) {
+    // asm!("cbo.zero ({})", in(reg) addr, options(nostack))
+    asm!(".insn i 0x0F, 2, x0, {}, 0x004", in(reg) addr, options(nostack))
+}
+
+/// Generates the `PREFETCH.I` instruction
+///
+/// The PREFETCH.I instruction is a HINT indicating that the cache block containing the
+/// effective address `addr` is likely to be accessed by an instruction fetch in the near future.
+///
+/// This instruction is included in the `Zicbop` extension. It is encoded as an `ORI` with
+/// destination `x0`, so it executes as a no-op on harts that do not implement `Zicbop`.
+#[inline]
+#[cfg_attr(all(test, target_feature = "zicbop"), assert_instr(prefetch.i))]
+pub fn prefetch_i(addr: *const u8) {
+    // asm!("prefetch.i 0({})", in(reg) addr, options(nostack))
+    unsafe { asm!(".insn i 0x13, 6, x0, {}, 0x000", in(reg) addr, options(nostack)) }
+}
+
+/// Generates the `PREFETCH.R` instruction
+///
+/// The PREFETCH.R instruction is a HINT indicating that the cache block containing the
+/// effective address `addr` is likely to be accessed by a data read in the near future.
+///
+/// This instruction is included in the `Zicbop` extension. It is encoded as an `ORI` with
+/// destination `x0`, so it executes as a no-op on harts that do not implement `Zicbop`.
+#[inline]
+#[cfg_attr(all(test, target_feature = "zicbop"), assert_instr(prefetch.r))]
+pub fn prefetch_r(addr: *const u8) {
+    // asm!("prefetch.r 0({})", in(reg) addr, options(nostack))
+    unsafe { asm!(".insn i 0x13, 6, x0, {}, 0x001", in(reg) addr, options(nostack)) }
+}
+
+/// Generates the `PREFETCH.W` instruction
+///
+/// The PREFETCH.W instruction is a HINT indicating that the cache block containing the
+/// effective address `addr` is likely to be accessed by a data write in the near future.
+///
+/// This instruction is included in the `Zicbop` extension. It is encoded as an `ORI` with
+/// destination `x0`, so it executes as a no-op on harts that do not implement `Zicbop`.
+#[inline]
+#[cfg_attr(all(test, target_feature = "zicbop"), assert_instr(prefetch.w))]
+pub fn prefetch_w(addr: *const u8) {
+    // asm!("prefetch.w 0({})", in(reg) addr, options(nostack))
+    unsafe { asm!(".insn i 0x13, 6, x0, {}, 0x003", in(reg) addr, options(nostack)) }
+}
+
 /// Supervisor memory management fence for given virtual address and address space
 ///
 /// The fence orders only reads and writes made to leaf page table entries corresponding to
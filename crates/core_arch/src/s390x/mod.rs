@@ -0,0 +1,7 @@
+//! `s390x` intrinsics
+
+#[macro_use]
+mod macros;
+
+mod vector;
+pub use self::vector::*;
@@ -0,0 +1,878 @@
+//! z/Architecture vector facility intrinsics.
+//!
+//! The intrinsics follow the `vecintrin.h` interface of GCC and Clang. The
+//! reference is the z/Architecture Principles of Operation, chapters 21 to 24
+//! ("Vector Overview and Support Instructions" to "Vector Floating-Point
+//! Instructions").
+//!
+//! The vector facility was introduced with z13, vector-enhancements facility 1
+//! with z14 and vector-enhancements facility 2 with z15.
+
+#![allow(non_camel_case_types)]
+
+use crate::{
+    core_arch::{simd::*, simd_llvm::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+types! {
+    /// s390x-specific 128-bit wide vector of sixteen packed `i8`
+    pub struct vector_signed_char(i8, i8, i8, i8, i8, i8, i8, i8,
+                                  i8, i8, i8, i8, i8, i8, i8, i8);
+    /// s390x-specific 128-bit wide vector of sixteen packed `u8`
+    pub struct vector_unsigned_char(u8, u8, u8, u8, u8, u8, u8, u8,
+                                    u8, u8, u8, u8, u8, u8, u8, u8);
+    /// s390x-specific 128-bit wide vector mask of sixteen packed elements
+    pub struct vector_bool_char(i8, i8, i8, i8, i8, i8, i8, i8,
+                                i8, i8, i8, i8, i8, i8, i8, i8);
+    /// s390x-specific 128-bit wide vector of eight packed `i16`
+    pub struct vector_signed_short(i16, i16, i16, i16, i16, i16, i16, i16);
+    /// s390x-specific 128-bit wide vector of eight packed `u16`
+    pub struct vector_unsigned_short(u16, u16, u16, u16, u16, u16, u16, u16);
+    /// s390x-specific 128-bit wide vector mask of eight packed elements
+    pub struct vector_bool_short(i16, i16, i16, i16, i16, i16, i16, i16);
+    /// s390x-specific 128-bit wide vector of four packed `i32`
+    pub struct vector_signed_int(i32, i32, i32, i32);
+    /// s390x-specific 128-bit wide vector of four packed `u32`
+    pub struct vector_unsigned_int(u32, u32, u32, u32);
+    /// s390x-specific 128-bit wide vector mask of four packed elements
+    pub struct vector_bool_int(i32, i32, i32, i32);
+    /// s390x-specific 128-bit wide vector of two packed `i64`
+    pub struct vector_signed_long_long(i64, i64);
+    /// s390x-specific 128-bit wide vector of two packed `u64`
+    pub struct vector_unsigned_long_long(u64, u64);
+    /// s390x-specific 128-bit wide vector mask of two packed elements
+    pub struct vector_bool_long_long(i64, i64);
+    /// s390x-specific 128-bit wide vector of four packed `f32`
+    pub struct vector_float(f32, f32, f32, f32);
+    /// s390x-specific 128-bit wide vector of two packed `f64`
+    pub struct vector_double(f64, f64);
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.s390.vperm"]
+    fn vperm(
+        a: vector_signed_char,
+        b: vector_signed_char,
+        c: vector_unsigned_char,
+    ) -> vector_signed_char;
+
+    #[link_name = "llvm.s390.vll"]
+    fn vll(byte_index: u32, ptr: *const u8) -> vector_signed_char;
+    #[link_name = "llvm.s390.vstl"]
+    fn vstl(a: vector_signed_char, byte_index: u32, ptr: *mut u8);
+
+    #[link_name = "llvm.s390.vgfmb"]
+    fn vgfmb(a: vector_unsigned_char, b: vector_unsigned_char) -> vector_unsigned_short;
+    #[link_name = "llvm.s390.vgfmh"]
+    fn vgfmh(a: vector_unsigned_short, b: vector_unsigned_short) -> vector_unsigned_int;
+    #[link_name = "llvm.s390.vgfmf"]
+    fn vgfmf(a: vector_unsigned_int, b: vector_unsigned_int) -> vector_unsigned_long_long;
+    #[link_name = "llvm.s390.vgfmg"]
+    fn vgfmg(a: vector_unsigned_long_long, b: vector_unsigned_long_long) -> vector_unsigned_char;
+
+    #[link_name = "llvm.s390.vbperm"]
+    fn vbperm(a: vector_unsigned_char, b: vector_unsigned_char) -> vector_unsigned_long_long;
+
+    #[link_name = "llvm.s390.vsld"]
+    fn vsld(a: vector_unsigned_char, b: vector_unsigned_char, c: u32) -> vector_unsigned_char;
+    #[link_name = "llvm.s390.vsrd"]
+    fn vsrd(a: vector_unsigned_char, b: vector_unsigned_char, c: u32) -> vector_unsigned_char;
+}
+
+#[cfg(test)]
+macro_rules! s_t_l {
+    (i8x16) => {
+        vector_signed_char
+    };
+    (i16x8) => {
+        vector_signed_short
+    };
+    (i32x4) => {
+        vector_signed_int
+    };
+    (i64x2) => {
+        vector_signed_long_long
+    };
+
+    (u8x16) => {
+        vector_unsigned_char
+    };
+    (u16x8) => {
+        vector_unsigned_short
+    };
+    (u32x4) => {
+        vector_unsigned_int
+    };
+    (u64x2) => {
+        vector_unsigned_long_long
+    };
+
+    (m8x16) => {
+        vector_bool_char
+    };
+    (m16x8) => {
+        vector_bool_short
+    };
+    (m32x4) => {
+        vector_bool_int
+    };
+    (m64x2) => {
+        vector_bool_long_long
+    };
+
+    (f32x4) => {
+        vector_float
+    };
+    (f64x2) => {
+        vector_double
+    };
+}
+
+mod sealed {
+    use super::*;
+
+    test_impl! { vec_vab(a: vector_signed_char, b: vector_signed_char) -> vector_signed_char [simd_add, vab] }
+    test_impl! { vec_vah(a: vector_signed_short, b: vector_signed_short) -> vector_signed_short [simd_add, vah] }
+    test_impl! { vec_vaf(a: vector_signed_int, b: vector_signed_int) -> vector_signed_int [simd_add, vaf] }
+    test_impl! { vec_vag(a: vector_signed_long_long, b: vector_signed_long_long) -> vector_signed_long_long [simd_add, vag] }
+    test_impl! { vec_vfadb(a: vector_double, b: vector_double) -> vector_double [simd_add, vfadb] }
+    test_impl! { vec_vfasb(a: vector_float, b: vector_float) -> vector_float [simd_add, vfasb] "vector-enhancements-1" }
+
+    pub trait VectorAdd<Other> {
+        type Result;
+        unsafe fn vec_add(self, other: Other) -> Self::Result;
+    }
+
+    impl_vec_trait! { [VectorAdd vec_add] vec_vab (vector_signed_char, vector_signed_char) -> vector_signed_char }
+    impl_vec_trait! { [VectorAdd vec_add] vec_vab (vector_unsigned_char, vector_unsigned_char) -> vector_unsigned_char }
+    impl_vec_trait! { [VectorAdd vec_add] vec_vah (vector_signed_short, vector_signed_short) -> vector_signed_short }
+    impl_vec_trait! { [VectorAdd vec_add] vec_vah (vector_unsigned_short, vector_unsigned_short) -> vector_unsigned_short }
+    impl_vec_trait! { [VectorAdd vec_add] vec_vaf (vector_signed_int, vector_signed_int) -> vector_signed_int }
+    impl_vec_trait! { [VectorAdd vec_add] vec_vaf (vector_unsigned_int, vector_unsigned_int) -> vector_unsigned_int }
+    impl_vec_trait! { [VectorAdd vec_add] vec_vag (vector_signed_long_long, vector_signed_long_long) -> vector_signed_long_long }
+    impl_vec_trait! { [VectorAdd vec_add] vec_vag (vector_unsigned_long_long, vector_unsigned_long_long) -> vector_unsigned_long_long }
+    impl_vec_trait! { [VectorAdd vec_add] vec_vfadb (vector_double, vector_double) -> vector_double }
+    impl_vec_trait! { [VectorAdd vec_add "vector-enhancements-1"] vec_vfasb (vector_float, vector_float) -> vector_float }
+
+    #[inline]
+    #[target_feature(enable = "vector")]
+    #[cfg_attr(test, assert_instr(vperm))]
+    pub unsafe fn vec_vperm(
+        a: vector_signed_char,
+        b: vector_signed_char,
+        c: vector_unsigned_char,
+    ) -> vector_signed_char {
+        vperm(a, b, c)
+    }
+
+    pub trait VectorPerm {
+        unsafe fn vec_perm(self, b: Self, c: vector_unsigned_char) -> Self;
+    }
+
+    macro_rules! vector_perm {
+        {$impl: ident} => {
+            impl VectorPerm for $impl {
+                #[inline]
+                #[target_feature(enable = "vector")]
+                unsafe fn vec_perm(self, b: Self, c: vector_unsigned_char) -> Self {
+                    transmute(vec_vperm(transmute(self), transmute(b), c))
+                }
+            }
+        }
+    }
+
+    vector_perm! { vector_signed_char }
+    vector_perm! { vector_unsigned_char }
+    vector_perm! { vector_bool_char }
+
+    vector_perm! { vector_signed_short }
+    vector_perm! { vector_unsigned_short }
+    vector_perm! { vector_bool_short }
+
+    vector_perm! { vector_signed_int }
+    vector_perm! { vector_unsigned_int }
+    vector_perm! { vector_bool_int }
+
+    vector_perm! { vector_signed_long_long }
+    vector_perm! { vector_unsigned_long_long }
+    vector_perm! { vector_bool_long_long }
+
+    vector_perm! { vector_float }
+    vector_perm! { vector_double }
+
+    #[inline]
+    #[target_feature(enable = "vector")]
+    #[cfg_attr(test, assert_instr(vsel))]
+    pub unsafe fn vec_vsel(
+        a: vector_unsigned_char,
+        b: vector_unsigned_char,
+        c: vector_unsigned_char,
+    ) -> vector_unsigned_char {
+        let not_c = simd_xor(c, transmute(u8x16::splat(0xff)));
+        simd_or(simd_and(a, not_c), simd_and(b, c))
+    }
+
+    pub trait VectorSel<Mask> {
+        unsafe fn vec_sel(self, b: Self, c: Mask) -> Self;
+    }
+
+    macro_rules! vector_sel {
+        ($ty: ty, $unsigned: ty, $bool: ty) => {
+            vector_sel! { $ty, $unsigned }
+            vector_sel! { $ty, $bool }
+        };
+        ($ty: ty, $mask: ty) => {
+            impl VectorSel<$mask> for $ty {
+                #[inline]
+                #[target_feature(enable = "vector")]
+                unsafe fn vec_sel(self, b: Self, c: $mask) -> Self {
+                    transmute(vec_vsel(transmute(self), transmute(b), transmute(c)))
+                }
+            }
+        };
+    }
+
+    vector_sel! { vector_signed_char, vector_unsigned_char, vector_bool_char }
+    vector_sel! { vector_unsigned_char, vector_unsigned_char, vector_bool_char }
+    vector_sel! { vector_bool_char, vector_unsigned_char, vector_bool_char }
+    vector_sel! { vector_signed_short, vector_unsigned_short, vector_bool_short }
+    vector_sel! { vector_unsigned_short, vector_unsigned_short, vector_bool_short }
+    vector_sel! { vector_bool_short, vector_unsigned_short, vector_bool_short }
+    vector_sel! { vector_signed_int, vector_unsigned_int, vector_bool_int }
+    vector_sel! { vector_unsigned_int, vector_unsigned_int, vector_bool_int }
+    vector_sel! { vector_bool_int, vector_unsigned_int, vector_bool_int }
+    vector_sel! { vector_signed_long_long, vector_unsigned_long_long, vector_bool_long_long }
+    vector_sel! { vector_unsigned_long_long, vector_unsigned_long_long, vector_bool_long_long }
+    vector_sel! { vector_bool_long_long, vector_unsigned_long_long, vector_bool_long_long }
+    vector_sel! { vector_float, vector_unsigned_int, vector_bool_int }
+    vector_sel! { vector_double, vector_unsigned_long_long, vector_bool_long_long }
+
+    #[inline]
+    #[target_feature(enable = "vector")]
+    #[cfg_attr(test, assert_instr(vll))]
+    pub unsafe fn vec_vll(ptr: *const u8, byte_index: u32) -> vector_signed_char {
+        vll(byte_index, ptr)
+    }
+
+    #[inline]
+    #[target_feature(enable = "vector")]
+    #[cfg_attr(test, assert_instr(vstl))]
+    pub unsafe fn vec_vstl(a: vector_signed_char, ptr: *mut u8, byte_index: u32) {
+        vstl(a, byte_index, ptr)
+    }
+
+    pub trait VectorLoadLen {
+        type Result;
+        unsafe fn vec_load_len(self, byte_index: u32) -> Self::Result;
+    }
+
+    pub trait VectorStoreLen {
+        type ElementType;
+        unsafe fn vec_store_len(self, ptr: *mut Self::ElementType, byte_index: u32);
+    }
+
+    macro_rules! impl_vec_load_store_len {
+        ($elem: ty, $vec: ty) => {
+            impl VectorLoadLen for *const $elem {
+                type Result = $vec;
+                #[inline]
+                #[target_feature(enable = "vector")]
+                unsafe fn vec_load_len(self, byte_index: u32) -> Self::Result {
+                    transmute(vec_vll(self as *const u8, byte_index))
+                }
+            }
+
+            impl VectorStoreLen for $vec {
+                type ElementType = $elem;
+                #[inline]
+                #[target_feature(enable = "vector")]
+                unsafe fn vec_store_len(self, ptr: *mut $elem, byte_index: u32) {
+                    vec_vstl(transmute(self), ptr as *mut u8, byte_index)
+                }
+            }
+        };
+    }
+
+    impl_vec_load_store_len! { i8, vector_signed_char }
+    impl_vec_load_store_len! { u8, vector_unsigned_char }
+    impl_vec_load_store_len! { i16, vector_signed_short }
+    impl_vec_load_store_len! { u16, vector_unsigned_short }
+    impl_vec_load_store_len! { i32, vector_signed_int }
+    impl_vec_load_store_len! { u32, vector_unsigned_int }
+    impl_vec_load_store_len! { i64, vector_signed_long_long }
+    impl_vec_load_store_len! { u64, vector_unsigned_long_long }
+    impl_vec_load_store_len! { f32, vector_float }
+    impl_vec_load_store_len! { f64, vector_double }
+
+    test_impl! { vec_vgfmb(a: vector_unsigned_char, b: vector_unsigned_char) -> vector_unsigned_short [vgfmb, vgfmb] }
+    test_impl! { vec_vgfmh(a: vector_unsigned_short, b: vector_unsigned_short) -> vector_unsigned_int [vgfmh, vgfmh] }
+    test_impl! { vec_vgfmf(a: vector_unsigned_int, b: vector_unsigned_int) -> vector_unsigned_long_long [vgfmf, vgfmf] }
+
+    pub trait VectorGfmsum<Other> {
+        type Result;
+        unsafe fn vec_gfmsum(self, b: Other) -> Self::Result;
+    }
+
+    impl_vec_trait! { [VectorGfmsum vec_gfmsum] vec_vgfmb (vector_unsigned_char, vector_unsigned_char) -> vector_unsigned_short }
+    impl_vec_trait! { [VectorGfmsum vec_gfmsum] vec_vgfmh (vector_unsigned_short, vector_unsigned_short) -> vector_unsigned_int }
+    impl_vec_trait! { [VectorGfmsum vec_gfmsum] vec_vgfmf (vector_unsigned_int, vector_unsigned_int) -> vector_unsigned_long_long }
+
+    test_impl! { vec_vfmadb(a: vector_double, b: vector_double, c: vector_double) -> vector_double [simd_fma, vfmadb] }
+    test_impl! { vec_vfmasb(a: vector_float, b: vector_float, c: vector_float) -> vector_float [simd_fma, vfmasb] "vector-enhancements-1" }
+
+    pub trait VectorMadd {
+        unsafe fn vec_madd(self, b: Self, c: Self) -> Self;
+    }
+
+    impl VectorMadd for vector_double {
+        #[inline]
+        #[target_feature(enable = "vector")]
+        unsafe fn vec_madd(self, b: Self, c: Self) -> Self {
+            vec_vfmadb(self, b, c)
+        }
+    }
+
+    impl VectorMadd for vector_float {
+        #[inline]
+        #[target_feature(enable = "vector-enhancements-1")]
+        unsafe fn vec_madd(self, b: Self, c: Self) -> Self {
+            vec_vfmasb(self, b, c)
+        }
+    }
+
+    #[inline]
+    #[target_feature(enable = "vector-enhancements-1")]
+    #[cfg_attr(test, assert_instr(vnn))]
+    pub unsafe fn vec_vnn(
+        a: vector_unsigned_char,
+        b: vector_unsigned_char,
+    ) -> vector_unsigned_char {
+        simd_xor(simd_and(a, b), transmute(u8x16::splat(0xff)))
+    }
+
+    #[inline]
+    #[target_feature(enable = "vector-enhancements-1")]
+    #[cfg_attr(test, assert_instr(voc))]
+    pub unsafe fn vec_voc(
+        a: vector_unsigned_char,
+        b: vector_unsigned_char,
+    ) -> vector_unsigned_char {
+        simd_or(a, simd_xor(b, transmute(u8x16::splat(0xff))))
+    }
+
+    #[inline]
+    #[target_feature(enable = "vector-enhancements-1")]
+    #[cfg_attr(test, assert_instr(vnx))]
+    pub unsafe fn vec_vnx(
+        a: vector_unsigned_char,
+        b: vector_unsigned_char,
+    ) -> vector_unsigned_char {
+        simd_xor(simd_xor(a, b), transmute(u8x16::splat(0xff)))
+    }
+
+    pub trait VectorNand {
+        unsafe fn vec_nand(self, b: Self) -> Self;
+    }
+
+    pub trait VectorOrc {
+        unsafe fn vec_orc(self, b: Self) -> Self;
+    }
+
+    pub trait VectorEqv {
+        unsafe fn vec_eqv(self, b: Self) -> Self;
+    }
+
+    macro_rules! impl_vec_logic {
+        ($($ty: ident),*) => {$(
+            impl VectorNand for $ty {
+                #[inline]
+                #[target_feature(enable = "vector-enhancements-1")]
+                unsafe fn vec_nand(self, b: Self) -> Self {
+                    transmute(vec_vnn(transmute(self), transmute(b)))
+                }
+            }
+
+            impl VectorOrc for $ty {
+                #[inline]
+                #[target_feature(enable = "vector-enhancements-1")]
+                unsafe fn vec_orc(self, b: Self) -> Self {
+                    transmute(vec_voc(transmute(self), transmute(b)))
+                }
+            }
+
+            impl VectorEqv for $ty {
+                #[inline]
+                #[target_feature(enable = "vector-enhancements-1")]
+                unsafe fn vec_eqv(self, b: Self) -> Self {
+                    transmute(vec_vnx(transmute(self), transmute(b)))
+                }
+            }
+        )*};
+    }
+
+    impl_vec_logic! {
+        vector_signed_char, vector_unsigned_char, vector_bool_char,
+        vector_signed_short, vector_unsigned_short, vector_bool_short,
+        vector_signed_int, vector_unsigned_int, vector_bool_int,
+        vector_signed_long_long, vector_unsigned_long_long, vector_bool_long_long
+    }
+
+    #[inline]
+    #[target_feature(enable = "vector-enhancements-2")]
+    #[cfg_attr(test, assert_instr(vsld, C = 1))]
+    pub unsafe fn vec_vsld<const C: u32>(
+        a: vector_unsigned_char,
+        b: vector_unsigned_char,
+    ) -> vector_unsigned_char {
+        static_assert_uimm_bits!(C, 3);
+        vsld(a, b, C)
+    }
+
+    #[inline]
+    #[target_feature(enable = "vector-enhancements-2")]
+    #[cfg_attr(test, assert_instr(vsrd, C = 1))]
+    pub unsafe fn vec_vsrd<const C: u32>(
+        a: vector_unsigned_char,
+        b: vector_unsigned_char,
+    ) -> vector_unsigned_char {
+        static_assert_uimm_bits!(C, 3);
+        vsrd(a, b, C)
+    }
+
+    pub trait VectorShiftDouble {
+        unsafe fn vec_sldb<const C: u32>(self, b: Self) -> Self;
+        unsafe fn vec_srdb<const C: u32>(self, b: Self) -> Self;
+    }
+
+    macro_rules! impl_vec_shift_double {
+        ($($ty: ident),*) => {$(
+            impl VectorShiftDouble for $ty {
+                #[inline]
+                #[target_feature(enable = "vector-enhancements-2")]
+                unsafe fn vec_sldb<const C: u32>(self, b: Self) -> Self {
+                    transmute(vec_vsld::<C>(transmute(self), transmute(b)))
+                }
+
+                #[inline]
+                #[target_feature(enable = "vector-enhancements-2")]
+                unsafe fn vec_srdb<const C: u32>(self, b: Self) -> Self {
+                    transmute(vec_vsrd::<C>(transmute(self), transmute(b)))
+                }
+            }
+        )*};
+    }
+
+    impl_vec_shift_double! {
+        vector_signed_char, vector_unsigned_char,
+        vector_signed_short, vector_unsigned_short,
+        vector_signed_int, vector_unsigned_int,
+        vector_signed_long_long, vector_unsigned_long_long,
+        vector_float, vector_double
+    }
+
+    test_impl! { vec_vcefb(a: vector_signed_int) -> vector_float [simd_cast, vcefb] "vector-enhancements-2" }
+    test_impl! { vec_vcelfb(a: vector_unsigned_int) -> vector_float [simd_cast, vcelfb] "vector-enhancements-2" }
+
+    pub trait VectorFloat {
+        type Result;
+        unsafe fn vec_float(self) -> Self::Result;
+    }
+
+    impl_vec_trait! { [VectorFloat vec_float "vector-enhancements-2"] vec_vcefb (vector_signed_int) -> vector_float }
+    impl_vec_trait! { [VectorFloat vec_float "vector-enhancements-2"] vec_vcelfb (vector_unsigned_int) -> vector_float }
+}
+
+/// Vector add.
+///
+/// Adding `vector_float`s requires the vector-enhancements facility 1.
+#[inline]
+#[target_feature(enable = "vector")]
+pub unsafe fn vec_add<T, U>(a: T, b: U) -> <T as sealed::VectorAdd<U>>::Result
+where
+    T: sealed::VectorAdd<U>,
+{
+    a.vec_add(b)
+}
+
+/// Vector permute.
+///
+/// Each byte of `c` selects a byte of the 32-byte concatenation of `a` and
+/// `b`, in big-endian byte order.
+#[inline]
+#[target_feature(enable = "vector")]
+pub unsafe fn vec_perm<T>(a: T, b: T, c: vector_unsigned_char) -> T
+where
+    T: sealed::VectorPerm,
+{
+    a.vec_perm(b, c)
+}
+
+/// Vector select.
+///
+/// Each bit of the result is the bit of `b` if the corresponding bit of `c`
+/// is set, and the bit of `a` otherwise.
+#[inline]
+#[target_feature(enable = "vector")]
+pub unsafe fn vec_sel<T, U>(a: T, b: T, c: U) -> T
+where
+    T: sealed::VectorSel<U>,
+{
+    a.vec_sel(b, c)
+}
+
+/// Vector load with length.
+///
+/// Loads the bytes at `ptr` up to the byte index `byte_index`, that is
+/// `byte_index + 1` bytes but at most 16, and sets the remaining bytes of the
+/// vector to zero.
+#[inline]
+#[target_feature(enable = "vector")]
+pub unsafe fn vec_load_len<T>(ptr: T, byte_index: u32) -> <T as sealed::VectorLoadLen>::Result
+where
+    T: sealed::VectorLoadLen,
+{
+    ptr.vec_load_len(byte_index)
+}
+
+/// Vector store with length.
+///
+/// Stores the bytes of `a` up to the byte index `byte_index`, that is
+/// `byte_index + 1` bytes but at most 16, to `ptr`.
+#[inline]
+#[target_feature(enable = "vector")]
+pub unsafe fn vec_store_len<T>(
+    a: T,
+    ptr: *mut <T as sealed::VectorStoreLen>::ElementType,
+    byte_index: u32,
+) where
+    T: sealed::VectorStoreLen,
+{
+    a.vec_store_len(ptr, byte_index)
+}
+
+/// Vector Galois field multiply sum.
+///
+/// Multiplies the even and odd elements of `a` and `b` as polynomials over
+/// GF(2) and adds (XORs) each pair of products into a double-width element.
+#[inline]
+#[target_feature(enable = "vector")]
+pub unsafe fn vec_gfmsum<T, U>(a: T, b: U) -> <T as sealed::VectorGfmsum<U>>::Result
+where
+    T: sealed::VectorGfmsum<U>,
+{
+    a.vec_gfmsum(b)
+}
+
+/// Vector Galois field multiply sum of the two 64-bit elements, producing a
+/// 128-bit result.
+#[inline]
+#[target_feature(enable = "vector")]
+#[cfg_attr(test, assert_instr(vgfmg))]
+pub unsafe fn vec_gfmsum_128(
+    a: vector_unsigned_long_long,
+    b: vector_unsigned_long_long,
+) -> vector_unsigned_char {
+    vgfmg(a, b)
+}
+
+/// Vector fused multiply-add: `a * b + c`.
+///
+/// Multiplying `vector_float`s requires the vector-enhancements facility 1.
+#[inline]
+#[target_feature(enable = "vector")]
+pub unsafe fn vec_madd<T>(a: T, b: T, c: T) -> T
+where
+    T: sealed::VectorMadd,
+{
+    a.vec_madd(b, c)
+}
+
+/// Vector NAND.
+#[inline]
+#[target_feature(enable = "vector-enhancements-1")]
+pub unsafe fn vec_nand<T>(a: T, b: T) -> T
+where
+    T: sealed::VectorNand,
+{
+    a.vec_nand(b)
+}
+
+/// Vector OR with complement: `a | !b`.
+#[inline]
+#[target_feature(enable = "vector-enhancements-1")]
+pub unsafe fn vec_orc<T>(a: T, b: T) -> T
+where
+    T: sealed::VectorOrc,
+{
+    a.vec_orc(b)
+}
+
+/// Vector equivalence: `!(a ^ b)`.
+#[inline]
+#[target_feature(enable = "vector-enhancements-1")]
+pub unsafe fn vec_eqv<T>(a: T, b: T) -> T
+where
+    T: sealed::VectorEqv,
+{
+    a.vec_eqv(b)
+}
+
+/// Vector bit permute.
+///
+/// Each byte of `b` is the index of a bit of `a`, counting from the most
+/// significant bit; indices of 128 or more select a zero bit. The 16 selected
+/// bits are returned in the low halfword of the first element.
+#[inline]
+#[target_feature(enable = "vector-enhancements-1")]
+#[cfg_attr(test, assert_instr(vbperm))]
+pub unsafe fn vec_bperm_u128(
+    a: vector_unsigned_char,
+    b: vector_unsigned_char,
+) -> vector_unsigned_long_long {
+    vbperm(a, b)
+}
+
+/// Vector shift left double by bit.
+///
+/// Shifts the 256-bit concatenation of `a` and `b` left by `C` bits and
+/// returns its leftmost 128 bits.
+#[inline]
+#[target_feature(enable = "vector-enhancements-2")]
+pub unsafe fn vec_sldb<T, const C: u32>(a: T, b: T) -> T
+where
+    T: sealed::VectorShiftDouble,
+{
+    static_assert_uimm_bits!(C, 3);
+    a.vec_sldb::<C>(b)
+}
+
+/// Vector shift right double by bit.
+///
+/// Shifts the 256-bit concatenation of `a` and `b` right by `C` bits and
+/// returns its rightmost 128 bits.
+#[inline]
+#[target_feature(enable = "vector-enhancements-2")]
+pub unsafe fn vec_srdb<T, const C: u32>(a: T, b: T) -> T
+where
+    T: sealed::VectorShiftDouble,
+{
+    static_assert_uimm_bits!(C, 3);
+    a.vec_srdb::<C>(b)
+}
+
+/// Vector convert from 32-bit integers to `f32`.
+#[inline]
+#[target_feature(enable = "vector-enhancements-2")]
+pub unsafe fn vec_float<T>(a: T) -> <T as sealed::VectorFloat>::Result
+where
+    T: sealed::VectorFloat,
+{
+    a.vec_float()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::arch::s390x::*;
+
+    use crate::core_arch::simd::*;
+    use std::mem::transmute;
+    use stdarch_test::simd_test;
+
+    macro_rules! test_vec_2 {
+        { $name: ident, $fn:ident, $ty: ident, [$($a:expr),+], [$($b:expr),+], [$($d:expr),+] } => {
+            test_vec_2! { $name, $fn, $ty -> $ty, [$($a),+], [$($b),+], [$($d),+] }
+        };
+        { $name: ident, $fn:ident, $ty: ident -> $ty_out: ident, [$($a:expr),+], [$($b:expr),+], [$($d:expr),+] } => {
+            test_vec_2! { $name, $fn, "vector", $ty -> $ty_out, [$($a),+], [$($b),+], [$($d),+] }
+        };
+        { $name: ident, $fn:ident, $feature:tt, $ty: ident -> $ty_out: ident, [$($a:expr),+], [$($b:expr),+], [$($d:expr),+] } => {
+            #[simd_test(enable = $feature)]
+            unsafe fn $name() {
+                let a: s_t_l!($ty) = transmute($ty::new($($a),+));
+                let b: s_t_l!($ty) = transmute($ty::new($($b),+));
+
+                let d = $ty_out::new($($d),+);
+                let r: $ty_out = transmute($fn(a, b));
+                assert_eq!(d, r);
+            }
+        };
+    }
+
+    test_vec_2! { test_vec_add_i8x16, vec_add, i8x16,
+    [1, -2, 3, -4, 5, -6, 7, -8, 9, -10, 11, -12, 13, -14, 15, i8::MAX],
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+    [2, -1, 4, -3, 6, -5, 8, -7, 10, -9, 12, -11, 14, -13, 16, i8::MIN] }
+    test_vec_2! { test_vec_add_u16x8, vec_add, u16x8,
+    [0, 1, 2, 3, 4, 5, 6, u16::MAX],
+    [10, 10, 10, 10, 10, 10, 10, 1],
+    [10, 11, 12, 13, 14, 15, 16, 0] }
+    test_vec_2! { test_vec_add_i32x4, vec_add, i32x4,
+    [1, -1, i32::MAX, 0],
+    [2, -2, 1, 0],
+    [3, -3, i32::MIN, 0] }
+    test_vec_2! { test_vec_add_u64x2, vec_add, u64x2,
+    [u64::MAX, 1],
+    [1, u64::MAX - 1],
+    [0, u64::MAX] }
+    test_vec_2! { test_vec_add_f64x2, vec_add, f64x2,
+    [1.5, -2.0],
+    [0.25, 4.0],
+    [1.75, 2.0] }
+    test_vec_2! { test_vec_add_f32x4, vec_add, "vector-enhancements-1", f32x4 -> f32x4,
+    [1.5, -2.0, 0.0, 8.0],
+    [0.25, 4.0, -1.0, 8.0],
+    [1.75, 2.0, -1.0, 16.0] }
+
+    test_vec_2! { test_vec_gfmsum_u8x16, vec_gfmsum, u8x16 -> u16x8,
+    [3, 5, 0xff, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [3, 3, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0b1010, 0x1fe, 0, 0, 0, 0, 0, 0] }
+    test_vec_2! { test_vec_gfmsum_u32x4, vec_gfmsum, u32x4 -> u64x2,
+    [0x8000_0000, 1, 3, 0],
+    [0x8000_0000, 1, 0xffff_ffff, 0],
+    [0x4000_0000_0000_0001, 0x1_0000_0001] }
+    test_vec_2! { test_vec_gfmsum_128, vec_gfmsum_128, u64x2 -> u8x16,
+    [1 << 63, 0],
+    [2, 0],
+    [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0] }
+
+    test_vec_2! { test_vec_nand_u32x4, vec_nand, "vector-enhancements-1", u32x4 -> u32x4,
+    [0, 0xffff_ffff, 0xf0f0_f0f0, 0x1234_5678],
+    [0, 0xffff_ffff, 0xff00_ff00, 0xffff_ffff],
+    [0xffff_ffff, 0, 0x0fff_0fff, 0xedcb_a987] }
+    test_vec_2! { test_vec_orc_u32x4, vec_orc, "vector-enhancements-1", u32x4 -> u32x4,
+    [0, 0xffff_ffff, 0xf0f0_f0f0, 0x1234_5678],
+    [0, 0xffff_ffff, 0xff00_ff00, 0xffff_ffff],
+    [0xffff_ffff, 0xffff_ffff, 0xf0ff_f0ff, 0x1234_5678] }
+    test_vec_2! { test_vec_eqv_u32x4, vec_eqv, "vector-enhancements-1", u32x4 -> u32x4,
+    [0, 0xffff_ffff, 0xf0f0_f0f0, 0x1234_5678],
+    [0, 0xffff_ffff, 0xff00_ff00, 0xffff_ffff],
+    [0xffff_ffff, 0xffff_ffff, 0xf00f_f00f, 0x1234_5678] }
+    test_vec_2! { test_vec_bperm_u128, vec_bperm_u128, "vector-enhancements-1", u8x16 -> u64x2,
+    [0x80, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 8, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255],
+    [0xc000, 0] }
+
+    macro_rules! test_vec_perm {
+        {$name:ident,
+         $shorttype:ident, $longtype:ident,
+         [$($a:expr),+], [$($b:expr),+], [$($c:expr),+], [$($d:expr),+]} => {
+            #[simd_test(enable = "vector")]
+            unsafe fn $name() {
+                let a: $longtype = transmute($shorttype::new($($a),+));
+                let b: $longtype = transmute($shorttype::new($($b),+));
+                let c: vector_unsigned_char = transmute(u8x16::new($($c),+));
+                let d = $shorttype::new($($d),+);
+
+                let r: $shorttype = transmute(vec_perm(a, b, c));
+                assert_eq!(d, r);
+            }
+        }
+    }
+
+    test_vec_perm! {test_vec_perm_u8x16,
+    u8x16, vector_unsigned_char,
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115],
+    [0x00, 0x01, 0x10, 0x11, 0x02, 0x03, 0x12, 0x13,
+     0x04, 0x05, 0x14, 0x15, 0x06, 0x07, 0x16, 0x17],
+    [0, 1, 100, 101, 2, 3, 102, 103, 4, 5, 104, 105, 6, 7, 106, 107]}
+    test_vec_perm! {test_vec_perm_i16x8,
+    i16x8, vector_signed_short,
+    [0, 1, 2, 3, 4, 5, 6, 7],
+    [10, 11, 12, 13, 14, 15, 16, 17],
+    [0x00, 0x01, 0x10, 0x11, 0x02, 0x03, 0x12, 0x13,
+     0x04, 0x05, 0x14, 0x15, 0x06, 0x07, 0x16, 0x17],
+    [0, 10, 1, 11, 2, 12, 3, 13]}
+    test_vec_perm! {test_vec_perm_f64x2,
+    f64x2, vector_double,
+    [0.0, 1.0],
+    [2.0, 3.0],
+    [0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07],
+    [3.0, 0.0]}
+
+    #[simd_test(enable = "vector")]
+    unsafe fn test_vec_sel() {
+        let a: vector_signed_int = transmute(i32x4::new(1, 2, 3, 4));
+        let b: vector_signed_int = transmute(i32x4::new(-1, -2, -3, -4));
+        let c: vector_bool_int = transmute(m32x4::new(true, false, false, true));
+        let r: i32x4 = transmute(vec_sel(a, b, c));
+        assert_eq!(r, i32x4::new(-1, 2, 3, -4));
+
+        let c: vector_unsigned_int = transmute(u32x4::new(0xffff_0000, 0, 0xf, 0));
+        let r: i32x4 = transmute(vec_sel(a, b, c));
+        assert_eq!(r, i32x4::new(-65535, 2, 13, 4));
+    }
+
+    #[simd_test(enable = "vector")]
+    unsafe fn test_vec_load_len() {
+        let data: [u8; 17] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17];
+
+        let r: u8x16 = transmute(vec_load_len(data.as_ptr(), 3));
+        assert_eq!(
+            r,
+            u8x16::new(1, 2, 3, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+        );
+
+        let r: u8x16 = transmute(vec_load_len(data.as_ptr(), 100));
+        assert_eq!(
+            r,
+            u8x16::new(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16)
+        );
+
+        let data: [i32; 4] = [-1, -2, -3, -4];
+        let r: i32x4 = transmute(vec_load_len(data.as_ptr(), 7));
+        assert_eq!(r, i32x4::new(-1, -2, 0, 0));
+    }
+
+    #[simd_test(enable = "vector")]
+    unsafe fn test_vec_store_len() {
+        let a: vector_unsigned_short = transmute(u16x8::new(1, 2, 3, 4, 5, 6, 7, 8));
+        let mut out = [0u16; 8];
+        vec_store_len(a, out.as_mut_ptr(), 5);
+        assert_eq!(out, [1, 2, 3, 0, 0, 0, 0, 0]);
+    }
+
+    #[simd_test(enable = "vector")]
+    unsafe fn test_vec_madd_f64x2() {
+        let a: vector_double = transmute(f64x2::new(2.0, -1.5));
+        let b: vector_double = transmute(f64x2::new(3.0, 2.0));
+        let c: vector_double = transmute(f64x2::new(0.5, 1.0));
+        let r: f64x2 = transmute(vec_madd(a, b, c));
+        assert_eq!(r, f64x2::new(6.5, -2.0));
+    }
+
+    #[simd_test(enable = "vector-enhancements-2")]
+    unsafe fn test_vec_sldb_srdb() {
+        let a: vector_unsigned_char =
+            transmute(u8x16::new(0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1));
+        let b: vector_unsigned_char = transmute(u8x16::new(
+            0xf0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ));
+
+        let r: u8x16 = transmute(vec_sldb::<_, 4>(a, b));
+        let d = u8x16::new(0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x1f);
+        assert_eq!(r, d);
+
+        let r: u8x16 = transmute(vec_srdb::<_, 4>(a, b));
+        let d = u8x16::new(0x1f, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+        assert_eq!(r, d);
+    }
+
+    #[simd_test(enable = "vector-enhancements-2")]
+    unsafe fn test_vec_float() {
+        let a: vector_signed_int = transmute(i32x4::new(-1, 0, 2, i32::MIN));
+        let r: f32x4 = transmute(vec_float(a));
+        assert_eq!(r, f32x4::new(-1.0, 0.0, 2.0, -2147483648.0));
+
+        let a: vector_unsigned_int = transmute(u32x4::new(1, 0, 2, u32::MAX));
+        let r: f32x4 = transmute(vec_float(a));
+        assert_eq!(r, f32x4::new(1.0, 0.0, 2.0, 4294967296.0));
+    }
+}
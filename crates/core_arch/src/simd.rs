@@ -243,6 +243,26 @@ simd_ty!(
 simd_ty!(i32x4[i32]: i32, i32, i32, i32 | x0, x1, x2, x3);
 simd_ty!(i64x2[i64]: i64, i64 | x0, x1);
 
+#[cfg(all(target_family = "wasm", target_feature = "fp16"))]
+simd_ty!(f16x4[f16]: f16, f16, f16, f16 | x0, x1, x2, x3);
+#[cfg(all(target_family = "wasm", target_feature = "fp16"))]
+simd_ty!(
+    f16x8[f16]: f16,
+    f16,
+    f16,
+    f16,
+    f16,
+    f16,
+    f16,
+    f16 | x0,
+    x1,
+    x2,
+    x3,
+    x4,
+    x5,
+    x6,
+    x7
+);
 simd_ty!(f32x4[f32]: f32, f32, f32, f32 | x0, x1, x2, x3);
 simd_ty!(f64x2[f64]: f64, f64 | x0, x1);
 simd_ty!(f64x4[f64]: f64, f64, f64, f64 | x0, x1, x2, x3);
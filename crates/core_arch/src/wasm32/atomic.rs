@@ -1,3 +1,5 @@
+use crate::sync::atomic::{fence, Ordering};
+
 #[cfg(test)]
 use stdarch_test::assert_instr;
 
@@ -91,3 +93,19 @@ pub unsafe fn memory_atomic_wait64(ptr: *mut i64, expression: i64, timeout_ns: i
 pub unsafe fn memory_atomic_notify(ptr: *mut i32, waiters: u32) -> u32 {
     llvm_atomic_notify(ptr, waiters as i32) as u32
 }
+
+/// Corresponding intrinsic to wasm's [`atomic.fence` instruction][instr]
+///
+/// This function is a sequentially consistent fence which orders all memory
+/// accesses, atomic or not, before and after it. It is equivalent to
+/// `core::sync::atomic::fence(Ordering::SeqCst)` when the `atomics` feature
+/// is enabled.
+///
+/// [instr]: https://webassembly.github.io/threads/core/syntax/instructions.html#syntax-instr-atomic-memory
+#[inline]
+#[cfg_attr(test, assert_instr(atomic.fence))]
+#[target_feature(enable = "atomics")]
+#[doc(alias("atomic.fence"))]
+pub fn atomic_fence() {
+    fence(Ordering::SeqCst)
+}
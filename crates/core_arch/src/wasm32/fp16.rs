@@ -0,0 +1,625 @@
+//! This module implements the [WebAssembly half-precision ISA] proposal.
+//!
+//! The proposal adds `f16x8` versions of the floating point instructions of
+//! `simd128`, interpreting a `v128` as eight IEEE 754 binary16 lanes. Scalar
+//! lane values are passed in and out as `f32`, as they are by the
+//! instructions themselves.
+//!
+//! These intrinsics are only available when the crate is built with the
+//! `fp16` target feature enabled.
+//!
+//! [WebAssembly half-precision ISA]:
+//! https://github.com/WebAssembly/half-precision/blob/main/proposals/half-precision/Overview.md
+
+use super::v128;
+use crate::core_arch::{simd, simd_llvm::*};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.wasm.splat.f16x8"]
+    fn llvm_f16x8_splat(a: f32) -> simd::f16x8;
+    #[link_name = "llvm.wasm.extract.lane.f16x8"]
+    fn llvm_f16x8_extract_lane(a: simd::f16x8, lane: u32) -> f32;
+    #[link_name = "llvm.wasm.replace.lane.f16x8"]
+    fn llvm_f16x8_replace_lane(a: simd::f16x8, lane: u32, val: f32) -> simd::f16x8;
+
+    #[link_name = "llvm.ceil.v8f16"]
+    fn llvm_f16x8_ceil(a: simd::f16x8) -> simd::f16x8;
+    #[link_name = "llvm.floor.v8f16"]
+    fn llvm_f16x8_floor(a: simd::f16x8) -> simd::f16x8;
+    #[link_name = "llvm.trunc.v8f16"]
+    fn llvm_f16x8_trunc(a: simd::f16x8) -> simd::f16x8;
+    #[link_name = "llvm.nearbyint.v8f16"]
+    fn llvm_f16x8_nearest(a: simd::f16x8) -> simd::f16x8;
+    #[link_name = "llvm.fabs.v8f16"]
+    fn llvm_f16x8_abs(a: simd::f16x8) -> simd::f16x8;
+    #[link_name = "llvm.sqrt.v8f16"]
+    fn llvm_f16x8_sqrt(a: simd::f16x8) -> simd::f16x8;
+    #[link_name = "llvm.minimum.v8f16"]
+    fn llvm_f16x8_min(a: simd::f16x8, b: simd::f16x8) -> simd::f16x8;
+    #[link_name = "llvm.maximum.v8f16"]
+    fn llvm_f16x8_max(a: simd::f16x8, b: simd::f16x8) -> simd::f16x8;
+    #[link_name = "llvm.wasm.pmin.v8f16"]
+    fn llvm_f16x8_pmin(a: simd::f16x8, b: simd::f16x8) -> simd::f16x8;
+    #[link_name = "llvm.wasm.pmax.v8f16"]
+    fn llvm_f16x8_pmax(a: simd::f16x8, b: simd::f16x8) -> simd::f16x8;
+
+    #[link_name = "llvm.wasm.relaxed.madd.v8f16"]
+    fn llvm_f16x8_relaxed_madd(a: simd::f16x8, b: simd::f16x8, c: simd::f16x8) -> simd::f16x8;
+    #[link_name = "llvm.wasm.relaxed.nmadd.v8f16"]
+    fn llvm_f16x8_relaxed_nmadd(a: simd::f16x8, b: simd::f16x8, c: simd::f16x8) -> simd::f16x8;
+
+    #[link_name = "llvm.fptosi.sat.v8i16.v8f16"]
+    fn llvm_i16x8_trunc_sat_f16x8_s(a: simd::f16x8) -> simd::i16x8;
+    #[link_name = "llvm.fptoui.sat.v8i16.v8f16"]
+    fn llvm_i16x8_trunc_sat_f16x8_u(a: simd::f16x8) -> simd::i16x8;
+}
+
+/// Creates a vector with identical lanes.
+///
+/// Constructs a vector with `a`, rounded to half precision, replicated to all
+/// 8 lanes.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.splat))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.splat"))]
+pub fn f16x8_splat(a: f32) -> v128 {
+    unsafe { llvm_f16x8_splat(a).v128() }
+}
+
+/// Extracts a lane from a 128-bit vector interpreted as 8 packed f16 numbers.
+///
+/// Extracts the scalar value of lane specified in the immediate mode operand
+/// `N` from `a`, widened to `f32`. If `N` is out of bounds then it is a
+/// compile time error.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.extract_lane, N = 1))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.extract_lane"))]
+pub fn f16x8_extract_lane<const N: usize>(a: v128) -> f32 {
+    static_assert!(N < 8);
+    unsafe { llvm_f16x8_extract_lane(a.as_f16x8(), N as u32) }
+}
+
+/// Replaces a lane from a 128-bit vector interpreted as 8 packed f16 numbers.
+///
+/// Replaces the scalar value of lane specified in the immediate mode operand
+/// `N` from `a` with `val` rounded to half precision. If `N` is out of bounds
+/// then it is a compile time error.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.replace_lane, N = 1))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.replace_lane"))]
+pub fn f16x8_replace_lane<const N: usize>(a: v128, val: f32) -> v128 {
+    static_assert!(N < 8);
+    unsafe { llvm_f16x8_replace_lane(a.as_f16x8(), N as u32, val).v128() }
+}
+
+/// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
+/// floating point numbers.
+///
+/// Returns a new vector where each lane is all ones if the corresponding input
+/// elements were equal, or all zeros otherwise.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.eq))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.eq"))]
+pub fn f16x8_eq(a: v128, b: v128) -> v128 {
+    unsafe { simd_eq::<_, simd::i16x8>(a.as_f16x8(), b.as_f16x8()).v128() }
+}
+
+/// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
+/// floating point numbers.
+///
+/// Returns a new vector where each lane is all ones if the corresponding input
+/// elements were not equal, or all zeros otherwise.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.ne))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.ne"))]
+pub fn f16x8_ne(a: v128, b: v128) -> v128 {
+    unsafe { simd_ne::<_, simd::i16x8>(a.as_f16x8(), b.as_f16x8()).v128() }
+}
+
+/// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
+/// floating point numbers.
+///
+/// Returns a new vector where each lane is all ones if the lane-wise left
+/// element is less than the right element, or all zeros otherwise.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.lt))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.lt"))]
+pub fn f16x8_lt(a: v128, b: v128) -> v128 {
+    unsafe { simd_lt::<_, simd::i16x8>(a.as_f16x8(), b.as_f16x8()).v128() }
+}
+
+/// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
+/// floating point numbers.
+///
+/// Returns a new vector where each lane is all ones if the lane-wise left
+/// element is greater than the right element, or all zeros otherwise.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.gt))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.gt"))]
+pub fn f16x8_gt(a: v128, b: v128) -> v128 {
+    unsafe { simd_gt::<_, simd::i16x8>(a.as_f16x8(), b.as_f16x8()).v128() }
+}
+
+/// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
+/// floating point numbers.
+///
+/// Returns a new vector where each lane is all ones if the lane-wise left
+/// element is less than or equal to the right element, or all zeros otherwise.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.le))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.le"))]
+pub fn f16x8_le(a: v128, b: v128) -> v128 {
+    unsafe { simd_le::<_, simd::i16x8>(a.as_f16x8(), b.as_f16x8()).v128() }
+}
+
+/// Compares two 128-bit vectors as if they were two vectors of 8 sixteen-bit
+/// floating point numbers.
+///
+/// Returns a new vector where each lane is all ones if the lane-wise left
+/// element is greater than or equal to the right element, or all zeros otherwise.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.ge))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.ge"))]
+pub fn f16x8_ge(a: v128, b: v128) -> v128 {
+    unsafe { simd_ge::<_, simd::i16x8>(a.as_f16x8(), b.as_f16x8()).v128() }
+}
+
+/// Lane-wise rounding to the nearest integral value not smaller than the input.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.ceil))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.ceil"))]
+pub fn f16x8_ceil(a: v128) -> v128 {
+    unsafe { llvm_f16x8_ceil(a.as_f16x8()).v128() }
+}
+
+/// Lane-wise rounding to the nearest integral value not greater than the input.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.floor))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.floor"))]
+pub fn f16x8_floor(a: v128) -> v128 {
+    unsafe { llvm_f16x8_floor(a.as_f16x8()).v128() }
+}
+
+/// Lane-wise rounding to the nearest integral value with the magnitude not
+/// larger than the input.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.trunc))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.trunc"))]
+pub fn f16x8_trunc(a: v128) -> v128 {
+    unsafe { llvm_f16x8_trunc(a.as_f16x8()).v128() }
+}
+
+/// Lane-wise rounding to the nearest integral value; if two values are equally
+/// near, rounds to the even one.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.nearest))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.nearest"))]
+pub fn f16x8_nearest(a: v128) -> v128 {
+    unsafe { llvm_f16x8_nearest(a.as_f16x8()).v128() }
+}
+
+/// Calculates the absolute value of each lane of a 128-bit vector interpreted
+/// as eight 16-bit floating point numbers.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.abs))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.abs"))]
+pub fn f16x8_abs(a: v128) -> v128 {
+    unsafe { llvm_f16x8_abs(a.as_f16x8()).v128() }
+}
+
+/// Negates each lane of a 128-bit vector interpreted as eight 16-bit floating
+/// point numbers.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.neg))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.neg"))]
+pub fn f16x8_neg(a: v128) -> v128 {
+    unsafe { simd_neg(a.as_f16x8()).v128() }
+}
+
+/// Calculates the square root of each lane of a 128-bit vector interpreted as
+/// eight 16-bit floating point numbers.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.sqrt))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.sqrt"))]
+pub fn f16x8_sqrt(a: v128) -> v128 {
+    unsafe { llvm_f16x8_sqrt(a.as_f16x8()).v128() }
+}
+
+/// Lane-wise addition of two 128-bit vectors interpreted as eight 16-bit
+/// floating point numbers.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.add))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.add"))]
+pub fn f16x8_add(a: v128, b: v128) -> v128 {
+    unsafe { simd_add(a.as_f16x8(), b.as_f16x8()).v128() }
+}
+
+/// Lane-wise subtraction of two 128-bit vectors interpreted as eight 16-bit
+/// floating point numbers.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.sub))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.sub"))]
+pub fn f16x8_sub(a: v128, b: v128) -> v128 {
+    unsafe { simd_sub(a.as_f16x8(), b.as_f16x8()).v128() }
+}
+
+/// Lane-wise multiplication of two 128-bit vectors interpreted as eight 16-bit
+/// floating point numbers.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.mul))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.mul"))]
+pub fn f16x8_mul(a: v128, b: v128) -> v128 {
+    unsafe { simd_mul(a.as_f16x8(), b.as_f16x8()).v128() }
+}
+
+/// Lane-wise division of two 128-bit vectors interpreted as eight 16-bit
+/// floating point numbers.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.div))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.div"))]
+pub fn f16x8_div(a: v128, b: v128) -> v128 {
+    unsafe { simd_div(a.as_f16x8(), b.as_f16x8()).v128() }
+}
+
+/// Calculates the lane-wise minimum of two 128-bit vectors interpreted
+/// as eight 16-bit floating point numbers.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.min))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.min"))]
+pub fn f16x8_min(a: v128, b: v128) -> v128 {
+    unsafe { llvm_f16x8_min(a.as_f16x8(), b.as_f16x8()).v128() }
+}
+
+/// Calculates the lane-wise maximum of two 128-bit vectors interpreted
+/// as eight 16-bit floating point numbers.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.max))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.max"))]
+pub fn f16x8_max(a: v128, b: v128) -> v128 {
+    unsafe { llvm_f16x8_max(a.as_f16x8(), b.as_f16x8()).v128() }
+}
+
+/// Lane-wise minimum value, defined as `b < a ? b : a`
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.pmin))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.pmin"))]
+pub fn f16x8_pmin(a: v128, b: v128) -> v128 {
+    unsafe { llvm_f16x8_pmin(a.as_f16x8(), b.as_f16x8()).v128() }
+}
+
+/// Lane-wise maximum value, defined as `a < b ? b : a`
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.pmax))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.pmax"))]
+pub fn f16x8_pmax(a: v128, b: v128) -> v128 {
+    unsafe { llvm_f16x8_pmax(a.as_f16x8(), b.as_f16x8()).v128() }
+}
+
+/// Computes `a * b + c` with either one rounding or two roundings.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.relaxed_madd))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.relaxed_madd"))]
+pub fn f16x8_relaxed_madd(a: v128, b: v128, c: v128) -> v128 {
+    unsafe { llvm_f16x8_relaxed_madd(a.as_f16x8(), b.as_f16x8(), c.as_f16x8()).v128() }
+}
+
+/// Computes `-a * b + c` with either one rounding or two roundings.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.relaxed_nmadd))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.relaxed_nmadd"))]
+pub fn f16x8_relaxed_nmadd(a: v128, b: v128, c: v128) -> v128 {
+    unsafe { llvm_f16x8_relaxed_nmadd(a.as_f16x8(), b.as_f16x8(), c.as_f16x8()).v128() }
+}
+
+/// Converts a 128-bit vector interpreted as eight 16-bit floating point numbers
+/// into a 128-bit vector of eight 16-bit signed integers.
+///
+/// NaN is converted to 0 and if it's out of bounds it becomes the nearest
+/// representable integer.
+#[inline]
+#[cfg_attr(test, assert_instr(i16x8.trunc_sat_f16x8_s))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("i16x8.trunc_sat_f16x8_s"))]
+pub fn i16x8_trunc_sat_f16x8(a: v128) -> v128 {
+    unsafe { llvm_i16x8_trunc_sat_f16x8_s(a.as_f16x8()).v128() }
+}
+
+/// Converts a 128-bit vector interpreted as eight 16-bit floating point numbers
+/// into a 128-bit vector of eight 16-bit unsigned integers.
+///
+/// NaN is converted to 0 and if it's out of bounds it becomes the nearest
+/// representable integer.
+#[inline]
+#[cfg_attr(test, assert_instr(i16x8.trunc_sat_f16x8_u))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("i16x8.trunc_sat_f16x8_u"))]
+pub fn u16x8_trunc_sat_f16x8(a: v128) -> v128 {
+    unsafe { llvm_i16x8_trunc_sat_f16x8_u(a.as_f16x8()).v128() }
+}
+
+/// Converts a 128-bit vector interpreted as eight 16-bit signed integers into a
+/// 128-bit vector of eight 16-bit floating point numbers.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.convert_i16x8_s))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.convert_i16x8_s"))]
+pub fn f16x8_convert_i16x8(a: v128) -> v128 {
+    unsafe { simd_cast::<_, simd::f16x8>(a.as_i16x8()).v128() }
+}
+
+/// Converts a 128-bit vector interpreted as eight 16-bit unsigned integers into
+/// a 128-bit vector of eight 16-bit floating point numbers.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.convert_i16x8_u))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.convert_i16x8_u"))]
+pub fn f16x8_convert_u16x8(a: v128) -> v128 {
+    unsafe { simd_cast::<_, simd::f16x8>(a.as_u16x8()).v128() }
+}
+
+/// Conversion of the four single-precision floating point lanes to the four
+/// lower half-precision lanes of the result.
+///
+/// The four higher lanes of the result are initialized to zero. Values which
+/// aren't representable in half precision are rounded to the nearest-even
+/// representable number.
+#[inline]
+#[cfg_attr(test, assert_instr(f16x8.demote_f32x4_zero))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f16x8.demote_f32x4_zero"))]
+pub fn f16x8_demote_f32x4_zero(a: v128) -> v128 {
+    unsafe {
+        simd_cast::<simd::f32x8, simd::f16x8>(simd_shuffle!(
+            a.as_f32x4(),
+            simd::f32x4::splat(0.0),
+            [0, 1, 2, 3, 4, 5, 6, 7]
+        ))
+        .v128()
+    }
+}
+
+/// Conversion of the four lower half-precision floating point lanes to the
+/// four single-precision lanes of the result.
+#[inline]
+#[cfg_attr(test, assert_instr(f32x4.promote_low_f16x8))]
+#[target_feature(enable = "simd128,fp16")]
+#[doc(alias("f32x4.promote_low_f16x8"))]
+pub fn f32x4_promote_low_f16x8(a: v128) -> v128 {
+    unsafe {
+        simd_cast::<simd::f16x4, simd::f32x4>(simd_shuffle!(
+            a.as_f16x8(),
+            a.as_f16x8(),
+            [0, 1, 2, 3]
+        ))
+        .v128()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::simd128::*;
+    use super::*;
+    use std::mem::transmute;
+
+    fn compare_bytes(a: v128, b: v128) {
+        let a: [u16; 8] = unsafe { transmute(a) };
+        let b: [u16; 8] = unsafe { transmute(b) };
+        assert_eq!(a, b);
+    }
+
+    fn lanes(a: v128) -> [f32; 8] {
+        [
+            f16x8_extract_lane::<0>(a),
+            f16x8_extract_lane::<1>(a),
+            f16x8_extract_lane::<2>(a),
+            f16x8_extract_lane::<3>(a),
+            f16x8_extract_lane::<4>(a),
+            f16x8_extract_lane::<5>(a),
+            f16x8_extract_lane::<6>(a),
+            f16x8_extract_lane::<7>(a),
+        ]
+    }
+
+    // Bit patterns of the binary16 values `[1.0, -2.0, 0.5, 3.0, -0.0,
+    // 65504.0, inf, nan]`.
+    const A: [u16; 8] = [
+        0x3c00, 0xc000, 0x3800, 0x4200, 0x8000, 0x7bff, 0x7c00, 0x7e00,
+    ];
+
+    fn a() -> v128 {
+        unsafe { transmute(A) }
+    }
+
+    #[test]
+    fn test_splat_lanes() {
+        compare_bytes(f16x8_splat(1.0), u16x8_splat(0x3c00));
+        compare_bytes(f16x8_splat(-2.0), u16x8_splat(0xc000));
+        // Rounded to nearest-even, and overflows to infinity.
+        compare_bytes(f16x8_splat(1.0 + 1.0 / 4096.0), u16x8_splat(0x3c00));
+        compare_bytes(f16x8_splat(1.0e6), u16x8_splat(0x7c00));
+
+        let l = lanes(a());
+        assert_eq!(
+            &l[..7],
+            &[1.0, -2.0, 0.5, 3.0, -0.0, 65504.0, f32::INFINITY]
+        );
+        assert!(l[7].is_nan());
+        assert!(l[4].is_sign_negative());
+
+        let b = f16x8_replace_lane::<3>(a(), 0.25);
+        assert_eq!(f16x8_extract_lane::<3>(b), 0.25);
+        assert_eq!(f16x8_extract_lane::<2>(b), 0.5);
+    }
+
+    #[test]
+    fn test_arith() {
+        let two = f16x8_splat(2.0);
+        let l = lanes(f16x8_add(a(), two));
+        assert_eq!(&l[..7], &[3.0, 0.0, 2.5, 5.0, 2.0, 65504.0, f32::INFINITY]);
+        let l = lanes(f16x8_sub(a(), two));
+        assert_eq!(
+            &l[..7],
+            &[-1.0, -4.0, -1.5, 1.0, -2.0, 65504.0, f32::INFINITY]
+        );
+        let l = lanes(f16x8_mul(a(), two));
+        assert_eq!(
+            &l[..7],
+            &[2.0, -4.0, 1.0, 6.0, -0.0, f32::INFINITY, f32::INFINITY]
+        );
+        let l = lanes(f16x8_div(a(), two));
+        assert_eq!(
+            &l[..7],
+            &[0.5, -1.0, 0.25, 1.5, -0.0, 32752.0, f32::INFINITY]
+        );
+        let l = lanes(f16x8_neg(a()));
+        assert_eq!(
+            &l[..7],
+            &[-1.0, 2.0, -0.5, -3.0, 0.0, -65504.0, f32::NEG_INFINITY]
+        );
+        let l = lanes(f16x8_abs(a()));
+        assert_eq!(&l[..7], &[1.0, 2.0, 0.5, 3.0, 0.0, 65504.0, f32::INFINITY]);
+        assert!(l[4].is_sign_positive());
+        let l = lanes(f16x8_sqrt(f16x8_splat(6.25)));
+        assert_eq!(l, [2.5; 8]);
+    }
+
+    #[test]
+    fn test_rounding() {
+        let v = unsafe {
+            // `[1.5, -1.5, 2.5, -2.5, 0.75, -0.75, 5.5, -0.0]`
+            transmute::<[u16; 8], v128>([
+                0x3e00, 0xbe00, 0x4100, 0xc100, 0x3a00, 0xba00, 0x4580, 0x8000,
+            ])
+        };
+        assert_eq!(
+            lanes(f16x8_ceil(v)),
+            [2.0, -1.0, 3.0, -2.0, 1.0, -0.0, 6.0, -0.0]
+        );
+        assert_eq!(
+            lanes(f16x8_floor(v)),
+            [1.0, -2.0, 2.0, -3.0, 0.0, -1.0, 5.0, -0.0]
+        );
+        assert_eq!(
+            lanes(f16x8_trunc(v)),
+            [1.0, -1.0, 2.0, -2.0, 0.0, -0.0, 5.0, -0.0]
+        );
+        assert_eq!(
+            lanes(f16x8_nearest(v)),
+            [2.0, -2.0, 2.0, -2.0, 1.0, -1.0, 6.0, -0.0]
+        );
+    }
+
+    #[test]
+    fn test_min_max() {
+        let b = f16x8_splat(0.75);
+        let l = lanes(f16x8_min(a(), b));
+        assert_eq!(&l[..7], &[0.75, -2.0, 0.5, 0.75, -0.0, 0.75, 0.75]);
+        assert!(l[7].is_nan());
+        let l = lanes(f16x8_max(a(), b));
+        assert_eq!(
+            &l[..7],
+            &[1.0, 0.75, 0.75, 3.0, 0.75, 65504.0, f32::INFINITY]
+        );
+        assert!(l[7].is_nan());
+        // The pseudo-min and pseudo-max return the first operand for NaN.
+        let l = lanes(f16x8_pmin(b, a()));
+        assert_eq!(l, [0.75, -2.0, 0.5, 0.75, -0.0, 0.75, 0.75, 0.75]);
+        let l = lanes(f16x8_pmax(b, a()));
+        assert_eq!(
+            l,
+            [1.0, 0.75, 0.75, 3.0, 0.75, 65504.0, f32::INFINITY, 0.75]
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        let b = f16x8_splat(1.0);
+        compare_bytes(f16x8_eq(a(), b), i16x8(-1, 0, 0, 0, 0, 0, 0, 0));
+        compare_bytes(f16x8_ne(a(), b), i16x8(0, -1, -1, -1, -1, -1, -1, -1));
+        compare_bytes(f16x8_lt(a(), b), i16x8(0, -1, -1, 0, -1, 0, 0, 0));
+        compare_bytes(f16x8_gt(a(), b), i16x8(0, 0, 0, -1, 0, -1, -1, 0));
+        compare_bytes(f16x8_le(a(), b), i16x8(-1, -1, -1, 0, -1, 0, 0, 0));
+        compare_bytes(f16x8_ge(a(), b), i16x8(-1, 0, 0, -1, 0, -1, -1, 0));
+    }
+
+    #[test]
+    fn test_madd() {
+        // All of these are exact in half precision, so there is no difference
+        // between one and two roundings.
+        let b = f16x8_splat(4.0);
+        let c = f16x8_splat(0.5);
+        let l = lanes(f16x8_relaxed_madd(a(), b, c));
+        assert_eq!(&l[..5], &[4.5, -7.5, 2.5, 12.5, 0.5]);
+        let l = lanes(f16x8_relaxed_nmadd(a(), b, c));
+        assert_eq!(&l[..5], &[-3.5, 8.5, -1.5, -11.5, 0.5]);
+    }
+
+    #[test]
+    fn test_conversions() {
+        compare_bytes(
+            i16x8_trunc_sat_f16x8(a()),
+            i16x8(1, -2, 0, 3, 0, i16::MAX, i16::MAX, 0),
+        );
+        compare_bytes(
+            u16x8_trunc_sat_f16x8(a()),
+            u16x8(1, 0, 0, 3, 0, 65504, u16::MAX, 0),
+        );
+        assert_eq!(
+            lanes(f16x8_convert_i16x8(i16x8(
+                1, -2, 3, -4, 2049, -32768, 0, 32767
+            ))),
+            [1.0, -2.0, 3.0, -4.0, 2048.0, -32768.0, 0.0, 32768.0]
+        );
+        assert_eq!(
+            lanes(f16x8_convert_u16x8(u16x8(
+                1, 2, 3, 4, 2049, 65504, 0, 65535
+            ))),
+            [1.0, 2.0, 3.0, 4.0, 2048.0, 65504.0, 0.0, f32::INFINITY]
+        );
+
+        let d = f16x8_demote_f32x4_zero(f32x4(1.0, -2.5, 1.0e6, 1.0 / 3.0));
+        assert_eq!(
+            lanes(d),
+            [1.0, -2.5, f32::INFINITY, 0.333251953125, 0.0, 0.0, 0.0, 0.0]
+        );
+        let p = f32x4_promote_low_f16x8(a());
+        assert_eq!(
+            [
+                f32x4_extract_lane::<0>(p),
+                f32x4_extract_lane::<1>(p),
+                f32x4_extract_lane::<2>(p),
+                f32x4_extract_lane::<3>(p),
+            ],
+            [1.0, -2.0, 0.5, 3.0]
+        );
+    }
+}
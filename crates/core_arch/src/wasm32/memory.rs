@@ -1,3 +1,5 @@
+use crate::arch::asm;
+
 #[cfg(test)]
 use stdarch_test::assert_instr;
 
@@ -18,6 +20,7 @@ extern "C" {
 /// memory, so it is required that zero is passed in. The argument is present to
 /// be forward-compatible with future WebAssembly revisions. If a nonzero
 /// argument is passed to this function it will currently unconditionally abort.
+/// See [`multi_memory_size`] for other memories.
 ///
 /// [instr]: http://webassembly.github.io/spec/core/exec/instructions.html#exec-memory-size
 #[inline]
@@ -43,6 +46,7 @@ pub fn memory_size<const MEM: u32>() -> usize {
 /// memory, so it is required that zero is passed in. The argument is present to
 /// be forward-compatible with future WebAssembly revisions. If a nonzero
 /// argument is passed to this function it will currently unconditionally abort.
+/// See [`multi_memory_grow`] for other memories.
 ///
 /// [instr]: http://webassembly.github.io/spec/core/exec/instructions.html#exec-memory-grow
 #[inline]
@@ -56,3 +60,203 @@ pub fn memory_grow<const MEM: u32>(delta: usize) -> usize {
         llvm_memory_grow(MEM, delta)
     }
 }
+
+/// Corresponding intrinsic to wasm's [`memory.size` instruction][instr] for
+/// any memory
+///
+/// This function returns the current size, in pages, of the memory with index
+/// `MEM`. Indices other than zero are only valid in modules which declare that
+/// many memories, which requires the [multi-memory proposal][mm].
+///
+/// [instr]: http://webassembly.github.io/spec/core/exec/instructions.html#exec-memory-size
+/// [mm]: https://github.com/WebAssembly/multi-memory
+#[inline]
+#[cfg_attr(test, assert_instr("memory.size", MEM = 0))]
+#[doc(alias("memory.size"))]
+pub fn multi_memory_size<const MEM: u32>() -> usize {
+    unsafe { llvm_memory_size(MEM) }
+}
+
+/// Corresponding intrinsic to wasm's [`memory.grow` instruction][instr] for
+/// any memory
+///
+/// This function attempts to grow the memory with index `MEM` by `delta`
+/// pages, returning the previous size in pages or `usize::MAX` if the memory
+/// cannot be grown. Indices other than zero are only valid in modules which
+/// declare that many memories, which requires the [multi-memory
+/// proposal][mm].
+///
+/// [instr]: http://webassembly.github.io/spec/core/exec/instructions.html#exec-memory-grow
+/// [mm]: https://github.com/WebAssembly/multi-memory
+#[inline]
+#[cfg_attr(test, assert_instr("memory.grow", MEM = 0))]
+#[doc(alias("memory.grow"))]
+pub fn multi_memory_grow<const MEM: u32>(delta: usize) -> usize {
+    unsafe { llvm_memory_grow(MEM, delta) }
+}
+
+/// Corresponding intrinsic to wasm's [`memory.copy` instruction][instr]
+///
+/// This function copies `n` bytes from `src` in the memory with index `SRC`
+/// to `dst` in the memory with index `DST`. The two regions may overlap; the
+/// copy behaves as if the source was first copied to a temporary buffer.
+///
+/// Indices other than zero are only valid in modules which declare that many
+/// memories, which requires the [multi-memory proposal][mm]. Note that for
+/// such memories `dst` and `src` are addresses within that memory rather than
+/// pointers which Rust code can dereference.
+///
+/// # Traps
+///
+/// Traps if either `src + n` or `dst + n` is out of bounds of its memory.
+///
+/// [instr]: https://webassembly.github.io/spec/core/syntax/instructions.html#syntax-instr-memory
+/// [mm]: https://github.com/WebAssembly/multi-memory
+#[inline]
+#[cfg_attr(test, assert_instr("memory.copy", DST = 0, SRC = 0))]
+#[target_feature(enable = "bulk-memory")]
+#[doc(alias("memory.copy"))]
+pub unsafe fn memory_copy<const DST: u32, const SRC: u32>(dst: *mut u8, src: *const u8, n: usize) {
+    asm!(
+        "local.get {}",
+        "local.get {}",
+        "local.get {}",
+        "memory.copy {}, {}",
+        in(local) dst,
+        in(local) src,
+        in(local) n,
+        const DST,
+        const SRC,
+        options(nostack),
+    );
+}
+
+/// Corresponding intrinsic to wasm's [`memory.fill` instruction][instr]
+///
+/// This function sets `n` bytes starting at `dst` in the memory with index
+/// `MEM` to the value `val`.
+///
+/// Indices other than zero are only valid in modules which declare that many
+/// memories, which requires the [multi-memory proposal][mm].
+///
+/// # Traps
+///
+/// Traps if `dst + n` is out of bounds of the memory.
+///
+/// [instr]: https://webassembly.github.io/spec/core/syntax/instructions.html#syntax-instr-memory
+/// [mm]: https://github.com/WebAssembly/multi-memory
+#[inline]
+#[cfg_attr(test, assert_instr("memory.fill", MEM = 0))]
+#[target_feature(enable = "bulk-memory")]
+#[doc(alias("memory.fill"))]
+pub unsafe fn memory_fill<const MEM: u32>(dst: *mut u8, val: u8, n: usize) {
+    asm!(
+        "local.get {}",
+        "local.get {}",
+        "local.get {}",
+        "memory.fill {}",
+        in(local) dst,
+        in(local) val as u32,
+        in(local) n,
+        const MEM,
+        options(nostack),
+    );
+}
+
+/// Corresponding intrinsic to wasm's [`memory.init` instruction][instr]
+///
+/// This function copies `n` bytes starting at `offset` within the passive
+/// data segment with index `DATA` to `dst` in the memory with index `MEM`.
+///
+/// Data segment indices refer to the final, linked module. They are not
+/// relocated by the linker, so this intrinsic is mostly useful for code which
+/// controls the layout of the module, for example by post-processing it. Note
+/// that the data count section is required for this instruction to validate,
+/// which `wasm-ld` only emits when linking with a shared memory.
+///
+/// # Traps
+///
+/// Traps if `offset + n` is out of bounds of the data segment, if `dst + n`
+/// is out of bounds of the memory, or if the segment has already been
+/// dropped with [`data_drop`] and `n` is nonzero.
+///
+/// [instr]: https://webassembly.github.io/spec/core/syntax/instructions.html#syntax-instr-memory
+// Modules only contain a data count section when linked with a shared memory,
+// so only check the instruction in builds with atomics, which CI runs for
+// wasm32 in addition to the default build.
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "atomics"),
+    assert_instr("memory.init", MEM = 0, DATA = 0)
+)]
+#[target_feature(enable = "bulk-memory")]
+#[doc(alias("memory.init"))]
+pub unsafe fn memory_init<const MEM: u32, const DATA: u32>(dst: *mut u8, offset: u32, n: u32) {
+    asm!(
+        "local.get {}",
+        "local.get {}",
+        "local.get {}",
+        "memory.init {}, {}",
+        in(local) dst,
+        in(local) offset,
+        in(local) n,
+        const DATA,
+        const MEM,
+        options(nostack),
+    );
+}
+
+/// Corresponding intrinsic to wasm's [`data.drop` instruction][instr]
+///
+/// This function drops the passive data segment with index `DATA`, allowing
+/// the engine to release its contents. Any later [`memory_init`] from this
+/// segment with a nonzero length traps.
+///
+/// As with [`memory_init`] the index refers to the final, linked module and
+/// the module must contain a data count section.
+///
+/// [instr]: https://webassembly.github.io/spec/core/syntax/instructions.html#syntax-instr-memory
+#[inline]
+#[cfg_attr(
+    all(test, target_feature = "atomics"),
+    assert_instr("data.drop", DATA = 0)
+)]
+#[target_feature(enable = "bulk-memory")]
+#[doc(alias("data.drop"))]
+pub unsafe fn data_drop<const DATA: u32>() {
+    asm!("data.drop {}", const DATA, options(nostack));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multi_memory_grow() {
+        let size = multi_memory_size::<0>();
+        assert_eq!(size, memory_size::<0>());
+        assert_eq!(multi_memory_grow::<0>(1), size);
+        assert_eq!(multi_memory_size::<0>(), size + 1);
+        assert_eq!(multi_memory_grow::<0>(usize::MAX), usize::MAX);
+        assert_eq!(memory_size::<0>(), size + 1);
+    }
+
+    #[test]
+    fn test_memory_copy() {
+        let mut buf = [0u8, 1, 2, 3, 4, 5, 6, 7];
+        let p = buf.as_mut_ptr();
+        unsafe { memory_copy::<0, 0>(p.add(2), p, 4) };
+        assert_eq!(buf, [0, 1, 0, 1, 2, 3, 6, 7]);
+        unsafe { memory_copy::<0, 0>(p, p.add(3), 5) };
+        assert_eq!(buf, [1, 2, 3, 6, 7, 3, 6, 7]);
+    }
+
+    #[test]
+    fn test_memory_fill() {
+        let mut buf = [0u8; 8];
+        unsafe { memory_fill::<0>(buf.as_mut_ptr().add(1), 0xa5, 6) };
+        assert_eq!(buf, [0, 0xa5, 0xa5, 0xa5, 0xa5, 0xa5, 0xa5, 0]);
+        unsafe { memory_fill::<0>(buf.as_mut_ptr(), 1, 0) };
+        assert_eq!(buf[0], 0);
+    }
+}
@@ -12,9 +12,17 @@ pub use self::simd128::*;
 mod relaxed_simd;
 pub use self::relaxed_simd::*;
 
+#[cfg(target_feature = "fp16")]
+mod fp16;
+#[cfg(target_feature = "fp16")]
+pub use self::fp16::*;
+
 mod memory;
 pub use self::memory::*;
 
+mod table;
+pub use self::table::*;
+
 /// Generates the [`unreachable`] instruction, which causes an unconditional [trap].
 ///
 /// This function is safe to call and immediately aborts the execution.
@@ -42,9 +42,10 @@ types! {
 }
 
 macro_rules! conversions {
-    ($(($name:ident = $ty:ty))*) => {
+    ($($(#[$attr:meta])* ($name:ident = $ty:ty))*) => {
         impl v128 {
             $(
+                $(#[$attr])*
                 #[inline(always)]
                 pub(crate) fn $name(self) -> $ty {
                     unsafe { mem::transmute(self) }
@@ -52,6 +53,7 @@ macro_rules! conversions {
             )*
         }
         $(
+            $(#[$attr])*
             impl $ty {
                 #[inline(always)]
                 #[rustc_const_stable(feature = "wasm_simd_const", since = "1.56.0")]
@@ -72,6 +74,8 @@ conversions! {
     (as_i16x8 = simd::i16x8)
     (as_i32x4 = simd::i32x4)
     (as_i64x2 = simd::i64x2)
+    #[cfg(target_feature = "fp16")]
+    (as_f16x8 = simd::f16x8)
     (as_f32x4 = simd::f32x4)
     (as_f64x2 = simd::f64x2)
 }
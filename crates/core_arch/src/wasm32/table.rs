@@ -0,0 +1,159 @@
+//! Intrinsics for the table instructions of the [reference-types] and
+//! [bulk-memory] proposals.
+//!
+//! Rust has no representation of `funcref` or `externref` values, so only the
+//! instructions which don't produce or consume reference values are exposed
+//! here. Instructions which add slots to a table fill them with `ref.null
+//! func`, which means they only validate for tables of `funcref`.
+//!
+//! The `TABLE` arguments are the numerical index of the table in the final,
+//! linked module. When `wasm-ld` emits the indirect function table it is table
+//! zero.
+//!
+//! [reference-types]: https://github.com/WebAssembly/reference-types
+//! [bulk-memory]: https://github.com/WebAssembly/bulk-memory-operations
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Corresponding intrinsic to wasm's [`table.size` instruction][instr]
+///
+/// This function returns the current number of elements in the table with
+/// index `TABLE`.
+///
+/// [instr]: https://webassembly.github.io/spec/core/syntax/instructions.html#syntax-instr-table
+#[inline]
+#[cfg_attr(test, assert_instr("table.size", TABLE = 0))]
+#[target_feature(enable = "reference-types")]
+#[doc(alias("table.size"))]
+pub fn table_size<const TABLE: u32>() -> usize {
+    let ret: usize;
+    unsafe {
+        asm!(
+            "table.size {}",
+            "local.set {}",
+            const TABLE,
+            out(local) ret,
+            options(nomem, nostack),
+        );
+    }
+    ret
+}
+
+/// Corresponding intrinsic to wasm's [`table.grow` instruction][instr]
+///
+/// This function attempts to grow the table with index `TABLE` by `delta`
+/// elements, initializing them to `ref.null func`. If the table is
+/// successfully grown then its previous size is returned, otherwise
+/// `usize::MAX` is returned.
+///
+/// [instr]: https://webassembly.github.io/spec/core/syntax/instructions.html#syntax-instr-table
+#[inline]
+#[cfg_attr(test, assert_instr("table.grow", TABLE = 0))]
+#[target_feature(enable = "reference-types")]
+#[doc(alias("table.grow"))]
+pub unsafe fn table_grow<const TABLE: u32>(delta: usize) -> usize {
+    let ret: usize;
+    asm!(
+        "ref.null_func",
+        "local.get {}",
+        "table.grow {}",
+        "local.set {}",
+        in(local) delta,
+        const TABLE,
+        lateout(local) ret,
+        options(nostack),
+    );
+    ret
+}
+
+/// Corresponding intrinsic to wasm's [`table.fill` instruction][instr]
+///
+/// This function sets `n` elements starting at `index` in the table with
+/// index `TABLE` to `ref.null func`.
+///
+/// # Traps
+///
+/// Traps if `index + n` is out of bounds of the table.
+///
+/// [instr]: https://webassembly.github.io/spec/core/syntax/instructions.html#syntax-instr-table
+#[inline]
+#[cfg_attr(test, assert_instr("table.fill", TABLE = 0))]
+#[target_feature(enable = "reference-types")]
+#[doc(alias("table.fill"))]
+pub unsafe fn table_fill<const TABLE: u32>(index: usize, n: usize) {
+    asm!(
+        "local.get {}",
+        "ref.null_func",
+        "local.get {}",
+        "table.fill {}",
+        in(local) index,
+        in(local) n,
+        const TABLE,
+        options(nostack),
+    );
+}
+
+/// Corresponding intrinsic to wasm's [`table.copy` instruction][instr]
+///
+/// This function copies `n` elements starting at `src` in the table with index
+/// `SRC` to `dst` in the table with index `DST`. The two ranges may overlap.
+///
+/// # Traps
+///
+/// Traps if either `src + n` or `dst + n` is out of bounds of its table.
+///
+/// [instr]: https://webassembly.github.io/spec/core/syntax/instructions.html#syntax-instr-table
+#[inline]
+#[cfg_attr(test, assert_instr("table.copy", DST = 0, SRC = 0))]
+#[target_feature(enable = "bulk-memory")]
+#[doc(alias("table.copy"))]
+pub unsafe fn table_copy<const DST: u32, const SRC: u32>(dst: usize, src: usize, n: usize) {
+    asm!(
+        "local.get {}",
+        "local.get {}",
+        "local.get {}",
+        "table.copy {}, {}",
+        in(local) dst,
+        in(local) src,
+        in(local) n,
+        const DST,
+        const SRC,
+        options(nostack),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `wasm-ld` only makes the indirect function table growable when passed
+    // `--growable-table`, which CI does for wasm32.
+    #[test]
+    fn test_table_grow() {
+        unsafe {
+            let size = table_size::<0>();
+            assert!(size > 0);
+            assert_eq!(table_grow::<0>(0), size);
+            assert_eq!(table_grow::<0>(3), size);
+            assert_eq!(table_size::<0>(), size + 3);
+
+            // A table can't have more than `u32::MAX` elements.
+            assert_eq!(table_grow::<0>(usize::MAX), usize::MAX);
+            assert_eq!(table_size::<0>(), size + 3);
+        }
+    }
+
+    #[test]
+    fn test_table_fill_copy() {
+        unsafe {
+            let size = table_size::<0>();
+            table_fill::<0>(size, 0);
+            table_copy::<0, 0>(size, 0, 0);
+            table_copy::<0, 0>(1, 1, size - 1);
+            assert_eq!(table_size::<0>(), size);
+        }
+    }
+}
@@ -0,0 +1,267 @@
+//! Prints the features detected by `std_detect` and the raw values they were
+//! detected from, as JSON.
+//!
+//! This is meant to be attached to bug reports about run-time feature
+//! detection:
+//!
+//!     cargo +nightly run -p std_detect --bin std_detect_dump > host.json
+//!
+//! The output contains:
+//!
+//! * `features`: every feature with its detected state, as reported by
+//!   `std_detect::detect::features()`.
+//! * `cpuid` and `xcr0` (x86): all the basic and extended CPUID leaves, in the
+//!   raw format of `cpuid -r -1`, and the value of `XCR0`.
+//! * `hwcap` and `hwcap2` (Linux): the `AT_HWCAP` and `AT_HWCAP2` entries of
+//!   the ELF auxiliary vector, as passed by the kernel.
+//! * `id_registers` (aarch64 Linux): the ID registers that `std_detect` reads
+//!   through the `mrs` emulation of the kernel, if `HWCAP_CPUID` is set.
+//! * `cpuinfo_flags` (Linux): the feature flags of the first CPU in
+//!   `/proc/cpuinfo`.
+//!
+//! The output can be saved as a test fixture: the tests of x86 detect the
+//! features from the `cpuid` and `xcr0` fields, and the tests of aarch64 from
+//! the `hwcap`, `hwcap2` and `id_registers` fields, and compare them with
+//! `features`. The tests also check the dump of the host named by the
+//! `STD_DETECT_DUMP` environment variable, which CI records before running
+//! them.
+
+#![feature(stdsimd)]
+
+use std::fmt::Write;
+
+fn main() {
+    let mut json = String::from("{\n");
+    field(&mut json, "arch", &string(std::env::consts::ARCH));
+    field(&mut json, "os", &string(std::env::consts::OS));
+
+    let features: Vec<String> = std_detect::detect::features()
+        .map(|(name, enabled)| format!("{}: {enabled}", string(name)))
+        .collect();
+    field(&mut json, "features", &object(&features));
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        let (leaves, xcr0) = x86::dump();
+        let leaves: Vec<String> = leaves.iter().map(|l| string(l)).collect();
+        field(&mut json, "cpuid", &array(&leaves));
+        if let Some(xcr0) = xcr0 {
+            field(&mut json, "xcr0", &string(&format!("{xcr0:#018x}")));
+        }
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        let (hwcap, hwcap2) = hwcaps();
+        field(&mut json, "hwcap", &string(&format!("{hwcap:#x}")));
+        field(&mut json, "hwcap2", &string(&format!("{hwcap2:#x}")));
+        #[cfg(target_arch = "aarch64")]
+        if hwcap & (1 << 11) != 0 {
+            // HWCAP_CPUID: the kernel emulates `mrs` for the ID registers.
+            let registers: Vec<String> = aarch64::id_registers()
+                .iter()
+                .map(|(name, value)| {
+                    format!("{}: {}", string(name), string(&format!("{value:#018x}")))
+                })
+                .collect();
+            field(&mut json, "id_registers", &object(&registers));
+        }
+        if let Some(flags) = cpuinfo_flags() {
+            let flags: Vec<String> = flags.split_whitespace().map(string).collect();
+            field(&mut json, "cpuinfo_flags", &array(&flags));
+        }
+    }
+
+    // Remove the comma after the last field.
+    json.truncate(json.len() - 2);
+    json.push_str("\n}");
+    println!("{json}");
+}
+
+/// Appends `"name": value,` to `json`.
+fn field(json: &mut String, name: &str, value: &str) {
+    writeln!(json, "  {}: {value},", string(name)).unwrap();
+}
+
+/// Formats `s` as a JSON string.
+fn string(s: &str) -> String {
+    let mut out = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Formats `items` as a JSON array, one item per line.
+fn array(items: &[String]) -> String {
+    if items.is_empty() {
+        return String::from("[]");
+    }
+    format!("[\n    {}\n  ]", items.join(",\n    "))
+}
+
+/// Formats `members`, which are `"name": value` pairs, as a JSON object, one
+/// member per line.
+fn object(members: &[String]) -> String {
+    if members.is_empty() {
+        return String::from("{}");
+    }
+    format!("{{\n    {}\n  }}", members.join(",\n    "))
+}
+
+/// Returns the `AT_HWCAP` and `AT_HWCAP2` entries of the auxiliary vector.
+///
+/// They are read from `/proc/self/auxv` rather than with `getauxval`, because
+/// glibc replaces `AT_HWCAP` with its own bits on x86.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn hwcaps() -> (usize, usize) {
+    const AT_HWCAP: usize = 16;
+    const AT_HWCAP2: usize = 26;
+    let (mut hwcap, mut hwcap2) = (0, 0);
+    match std::fs::read("/proc/self/auxv") {
+        Ok(auxv) => {
+            let size = std::mem::size_of::<usize>();
+            let mut words = auxv
+                .chunks_exact(size)
+                .map(|word| usize::from_ne_bytes(word.try_into().unwrap()));
+            while let (Some(key), Some(value)) = (words.next(), words.next()) {
+                match key {
+                    AT_HWCAP => hwcap = value,
+                    AT_HWCAP2 => hwcap2 = value,
+                    _ => {}
+                }
+            }
+        }
+        // SAFETY: `getauxval` returns 0 for keys that are not present.
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        Err(_) => unsafe {
+            hwcap = libc::getauxval(libc::AT_HWCAP) as usize;
+            hwcap2 = libc::getauxval(libc::AT_HWCAP2) as usize;
+        },
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        Err(_) => {}
+    }
+    (hwcap, hwcap2)
+}
+
+/// Returns the feature flags of the first CPU listed in `/proc/cpuinfo`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn cpuinfo_flags() -> Option<String> {
+    // The name of the field that lists the features of the CPU.
+    let field = if cfg!(any(target_arch = "x86", target_arch = "x86_64")) {
+        "flags"
+    } else if cfg!(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "loongarch64"
+    )) {
+        "Features"
+    } else if cfg!(target_arch = "s390x") {
+        "features"
+    } else if cfg!(any(target_arch = "riscv32", target_arch = "riscv64")) {
+        "isa"
+    } else if cfg!(any(target_arch = "powerpc", target_arch = "powerpc64")) {
+        "cpu"
+    } else if cfg!(any(target_arch = "mips", target_arch = "mips64")) {
+        "ASEs implemented"
+    } else {
+        return None;
+    };
+    let cpuinfo = std::fs::read_to_string("/proc/cpuinfo").ok()?;
+    cpuinfo.lines().find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        (name.trim() == field).then(|| value.trim().to_string())
+    })
+}
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64 {
+    use std::arch::asm;
+
+    /// Returns the values of the ID registers that `std_detect` decodes.
+    ///
+    /// This must only be called if the kernel emulates `mrs` (`HWCAP_CPUID`).
+    pub(crate) fn id_registers() -> [(&'static str, u64); 4] {
+        macro_rules! mrs {
+            ($name:literal) => {{
+                let value: u64;
+                // SAFETY: the kernel emulates `mrs` for the ID registers.
+                unsafe {
+                    asm!(
+                        concat!("mrs {}, ", $name),
+                        out(reg) value,
+                        options(pure, nomem, preserves_flags, nostack)
+                    );
+                }
+                ($name, value)
+            }};
+        }
+        [
+            mrs!("ID_AA64ISAR0_EL1"),
+            mrs!("ID_AA64ISAR1_EL1"),
+            mrs!("ID_AA64MMFR2_EL1"),
+            mrs!("ID_AA64PFR0_EL1"),
+        ]
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    /// Returns all the basic and extended CPUID leaves, in the raw format of
+    /// `cpuid -r -1`, and the value of `XCR0` if the OS supports `xgetbv`.
+    pub(crate) fn dump() -> (Vec<String>, Option<u64>) {
+        let mut leaves = vec![];
+        // SAFETY: CPUID is available on every x86 CPU that can run this binary.
+        let max_basic_leaf = unsafe { __cpuid(0) }.eax;
+        let max_extended_leaf = unsafe { __cpuid(0x8000_0000) }.eax;
+        for leaf in (0..=max_basic_leaf).chain(0x8000_0000..=max_extended_leaf) {
+            let subleaves = match leaf {
+                // The maximum sub-leaf is reported in EAX of sub-leaf 0.
+                0x7 | 0x24 => 0..=unsafe { __cpuid_count(leaf, 0) }.eax,
+                // Sub-leaf 1 reports the XSAVE extensions.
+                0xd => 0..=1,
+                // Sub-leaves are enumerated until the end marker below.
+                0x4 | 0xb | 0x1f | 0x8000_001d => 0..=63,
+                _ => 0..=0,
+            };
+            for subleaf in subleaves {
+                let r = unsafe { __cpuid_count(leaf, subleaf) };
+                let end = match leaf {
+                    // The cache type in EAX[4:0] is null.
+                    0x4 | 0x8000_001d => r.eax & 0x1f == 0,
+                    // The level type in ECX[15:8] is invalid.
+                    0xb | 0x1f => (r.ecx >> 8) & 0xff == 0,
+                    _ => false,
+                };
+                if subleaf != 0 && end {
+                    break;
+                }
+                leaves.push(format!(
+                    "{leaf:#010x} {subleaf:#04x}: eax={:#010x} ebx={:#010x} ecx={:#010x} edx={:#010x}",
+                    r.eax, r.ebx, r.ecx, r.edx
+                ));
+            }
+        }
+
+        // `xgetbv` is only available if the OS enabled it (CPUID.1:ECX.OSXSAVE).
+        let osxsave = unsafe { __cpuid(1) }.ecx & (1 << 27) != 0;
+        let xcr0 = osxsave.then(|| unsafe { xcr0() });
+        (leaves, xcr0)
+    }
+
+    #[target_feature(enable = "xsave")]
+    unsafe fn xcr0() -> u64 {
+        _xgetbv(0)
+    }
+}
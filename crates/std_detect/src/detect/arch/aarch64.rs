@@ -8,9 +8,11 @@ features! {
     /// This macro tests, at runtime, whether an `aarch64` feature is enabled on aarch64 platforms.
     /// Currently most features are only supported on linux-based platforms.
     ///
-    /// This macro takes one argument which is a string literal of the feature being tested for.
-    /// The feature names are mostly taken from their FEAT_* definitions in the [ARM Architecture
-    /// Reference Manual][docs].
+    /// This macro takes one or more arguments which are string literals of the features being
+    /// tested for, and returns `true` if all of them are detected. Testing several features with a
+    /// single invocation, like `is_aarch64_feature_detected!("sve2", "i8mm")`, compiles to a
+    /// single test of the feature cache. The feature names are mostly taken from their FEAT_*
+    /// definitions in the [ARM Architecture Reference Manual][docs].
     ///
     /// ## Supported arguments
     ///
@@ -55,6 +57,17 @@ features! {
     /// * `"sha2"` - FEAT_SHA1 & FEAT_SHA256
     /// * `"sha3"` - FEAT_SHA512 & FEAT_SHA3
     /// * `"sm4"` - FEAT_SM3 & FEAT_SM4
+    /// * `"afp"` - FEAT_AFP
+    /// * `"rpres"` - FEAT_RPRES
+    /// * `"wfxt"` - FEAT_WFxT
+    /// * `"ebf16"` - FEAT_EBF16
+    /// * `"cssc"` - FEAT_CSSC
+    /// * `"rprfm"` - FEAT_RPRFM
+    /// * `"mops"` - FEAT_MOPS
+    /// * `"hbc"` - FEAT_HBC
+    /// * `"sve2p1"` - FEAT_SVE2p1
+    /// * `"sme"` - FEAT_SME
+    /// * `"sme2"` - FEAT_SME2
     ///
     /// [docs]: https://developer.arm.com/documentation/ddi0487/latest
     #[stable(feature = "simd_aarch64", since = "1.60.0")]
@@ -150,4 +163,26 @@ features! {
     /// FEAT_SHA512 & FEAT_SHA3 (SHA2-512 & SHA3 instructions)
     @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] sm4: "sm4";
     /// FEAT_SM3 & FEAT_SM4 (SM3 & SM4 instructions)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] afp: "afp";
+    /// FEAT_AFP (Alternate floating-point behavior)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] rpres: "rpres";
+    /// FEAT_RPRES (increased precision of reciprocal estimates)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] wfxt: "wfxt";
+    /// FEAT_WFxT (WFE and WFI instructions with timeout)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] ebf16: "ebf16";
+    /// FEAT_EBF16 (extended BFloat16 behavior)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] cssc: "cssc";
+    /// FEAT_CSSC (common short sequence compression instructions)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] rprfm: "rprfm";
+    /// FEAT_RPRFM (range prefetch hint)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] mops: "mops";
+    /// FEAT_MOPS (memory copy and memory set instructions)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] hbc: "hbc";
+    /// FEAT_HBC (hinted conditional branches)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sve2p1: "sve2p1";
+    /// FEAT_SVE2p1 (Scalable Vector Extension 2.1)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sme: "sme";
+    /// FEAT_SME (Scalable Matrix Extension)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sme2: "sme2";
+    /// FEAT_SME2 (Scalable Matrix Extension 2)
 }
@@ -0,0 +1,24 @@
+//! Run-time feature detection on LoongArch.
+
+features! {
+    @TARGET: loongarch;
+    @CFG: target_arch = "loongarch64";
+    @MACRO_NAME: is_loongarch_feature_detected;
+    @MACRO_ATTRS:
+    /// Checks if `loongarch` feature is enabled.
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] f: "f";
+    /// Single-precision floating-point
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] d: "d";
+    /// Double-precision floating-point
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] lsx: "lsx";
+    /// LoongArch SIMD Extension (LSX, 128-bit vectors)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] lasx: "lasx";
+    /// LoongArch Advanced SIMD Extension (LASX, 256-bit vectors)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] lbt: "lbt";
+    /// LoongArch Binary Translation extension
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] lvz: "lvz";
+    /// LoongArch Virtualization extension
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] ual: "ual";
+    /// Unaligned memory accesses
+}
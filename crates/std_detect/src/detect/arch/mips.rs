@@ -9,4 +9,10 @@ features! {
     #[unstable(feature = "stdsimd", issue = "27731")]
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] msa: "msa";
     /// MIPS SIMD Architecture (MSA)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] dsp: "dsp";
+    /// MIPS DSP ASE
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] dspr2: "dspr2";
+    /// MIPS DSP ASE Revision 2
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] dspr3: "dspr3";
+    /// MIPS DSP ASE Revision 3
 }
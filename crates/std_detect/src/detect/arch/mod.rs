@@ -19,6 +19,10 @@ mod powerpc64;
 mod mips;
 #[macro_use]
 mod mips64;
+#[macro_use]
+mod s390x;
+#[macro_use]
+mod loongarch;
 
 cfg_if! {
     if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
@@ -37,11 +41,17 @@ cfg_if! {
         pub use mips::*;
     } else if #[cfg(target_arch = "mips64")] {
         pub use mips64::*;
+    } else if #[cfg(target_arch = "s390x")] {
+        pub use s390x::*;
+    } else if #[cfg(target_arch = "loongarch64")] {
+        pub use loongarch::*;
     } else {
         // Unimplemented architecture:
         #[doc(hidden)]
         pub(crate) enum Feature {
-            Null
+            Null,
+            // Do not add variants after last:
+            _last
         }
         #[doc(hidden)]
         pub mod __is_feature_detected {}
@@ -13,4 +13,26 @@ features! {
     /// VSX
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] power8: "power8";
     /// Power8
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] power8_altivec: "power8-altivec";
+    /// Power ISA 2.07 AltiVec instructions (POWER8)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] power8_vector: "power8-vector";
+    /// Power ISA 2.07 vector instructions (POWER8)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] power8_crypto: "power8-crypto";
+    /// Power ISA 2.07 vector cryptography instructions (POWER8)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] power9_altivec: "power9-altivec";
+    /// Power ISA 3.0 AltiVec instructions (POWER9)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] power9_vector: "power9-vector";
+    /// Power ISA 3.0 vector instructions (POWER9)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] power10_vector: "power10-vector";
+    /// Power ISA 3.1 vector instructions (POWER10)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] mma: "mma";
+    /// Matrix-Multiply Assist (POWER10)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] crypto: "crypto";
+    /// Vector cryptography instructions (POWER8)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] htm: "htm";
+    /// Hardware Transactional Memory (POWER8 and POWER9)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] darn: "darn";
+    /// Deliver A Random Number instruction (POWER9)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] scv: "scv";
+    /// System Call Vectored instruction (POWER9)
 }
@@ -33,6 +33,10 @@ features! {
     /// * Zicsr: `"zicsr"`
     /// * Zicntr: `"zicntr"`
     /// * Zihpm: `"zihpm"`
+    /// * Zicbom: `"zicbom"`
+    /// * Zicboz: `"zicboz"`
+    /// * Zicbop: `"zicbop"`
+    /// * Zawrs: `"zawrs"`
     /// * F: `"f"`
     /// * D: `"d"`
     /// * Q: `"q"`
@@ -118,6 +122,14 @@ features! {
     /// "Zicntr", Standard Extension for Base Counters and Timers
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zihpm: "zihpm";
     /// "Zihpm", Standard Extension for Hardware Performance Counters
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zicbom: "zicbom";
+    /// "Zicbom", Standard Extension for Cache-Block Management Instructions
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zicboz: "zicboz";
+    /// "Zicboz", Standard Extension for Cache-Block Zero Instructions
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zicbop: "zicbop";
+    /// "Zicbop", Standard Extension for Cache-Block Prefetch Instructions
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zawrs: "zawrs";
+    /// "Zawrs", Standard Extension for Wait-on-Reservation-Set Instructions
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] f: "f";
     /// "F" Standard Extension for Single-Precision Floating-Point
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] d: "d";
@@ -0,0 +1,18 @@
+//! Run-time feature detection on s390x.
+
+features! {
+    @TARGET: s390x;
+    @CFG: target_arch = "s390x";
+    @MACRO_NAME: is_s390x_feature_detected;
+    @MACRO_ATTRS:
+    /// Checks if `s390x` feature is enabled.
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] vector: "vector";
+    /// z/Architecture vector facility (z13)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] vector_enhancements_1: "vector-enhancements-1";
+    /// Vector-enhancements facility 1 (z14)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] vector_enhancements_2: "vector-enhancements-2";
+    /// Vector-enhancements facility 2 (z15)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] nnp_assist: "nnp-assist";
+    /// Neural-network-processing-assist facility (z16)
+}
@@ -28,16 +28,21 @@ features! {
     /// compile time unless the specified feature is already enabled for the entire
     /// crate. Runtime detection currently relies mostly on the `cpuid` instruction.
     ///
-    /// This macro only takes one argument which is a string literal of the feature
-    /// being tested for. The feature names supported are the lowercase versions of
-    /// the ones defined by Intel in [their documentation][docs].
+    /// This macro takes one or more arguments which are string literals of the
+    /// features being tested for, and returns `true` if all of them are
+    /// detected. The feature names supported are the lowercase versions of the
+    /// ones defined by Intel in [their documentation][docs].
+    ///
+    /// Testing several features with a single invocation, like
+    /// `is_x86_feature_detected!("avx2", "fma")`, is cheaper than combining
+    /// separate invocations: it compiles to a single test of the feature cache,
+    /// and features that are enabled at compile time are not tested at all.
     ///
     /// ## Supported arguments
     ///
-    /// This macro supports the same names that `#[target_feature]` supports. Unlike
-    /// `#[target_feature]`, however, this macro does not support names separated
-    /// with a comma. Instead testing for multiple features must be done through
-    /// separate macro invocations for now.
+    /// This macro supports the same names that `#[target_feature]` supports.
+    /// Unlike `#[target_feature]`, the names are passed as separate arguments
+    /// instead of a single comma-separated string.
     ///
     /// Supported arguments are:
     ///
@@ -93,6 +98,23 @@ features! {
     /// * `"rtm"`
     /// * `"movbe"`
     /// * `"ermsb"`
+    /// * `"avx10.1-256"`
+    /// * `"avx10.1-512"`
+    ///
+    /// ## Wider encodings of `"gfni"`, `"vaes"` and `"vpclmulqdq"`
+    ///
+    /// These instructions have encodings of several vector widths, and
+    /// each width is only usable if the OS enabled the matching register
+    /// state in `XCR0`. The three features only report the narrowest
+    /// encoding:
+    ///
+    /// * `"gfni"` reports the 128-bit SSE encoding. The 256-bit VEX encoding
+    ///   also requires `"avx"`, and the 512-bit EVEX encoding `"avx512f"`.
+    /// * `"vaes"` and `"vpclmulqdq"` report the 256-bit VEX encoding. The
+    ///   512-bit EVEX encoding also requires `"avx512f"`.
+    ///
+    /// For example, `is_x86_feature_detected!("vaes", "avx512f")` checks for
+    /// the 512-bit `vaesenc`.
     ///
     /// [docs]: https://software.intel.com/sites/landingpage/IntrinsicsGuide
     #[stable(feature = "simd_x86", since = "1.27.0")]
@@ -156,11 +178,23 @@ features! {
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512vbmi2: "avx512vbmi2";
     /// AVX-512 VBMI2 (Additional byte, word, dword and qword capabilities)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] gfni: "gfni";
-    /// AVX-512 GFNI (Galois Field New Instruction)
+    /// GFNI (Galois Field New Instructions)
+    ///
+    /// This only covers the SSE encoding, which only needs the SSE state in
+    /// `XCR0`: the 256-bit VEX encoding also requires `avx`, and the 512-bit
+    /// EVEX encoding `avx512f`.
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] vaes: "vaes";
-    /// AVX-512 VAES (Vector AES instruction)
+    /// VAES (Vector AES instructions)
+    ///
+    /// This covers the 256-bit VEX encoding, which needs the AVX state in
+    /// `XCR0`: the 512-bit EVEX encoding also requires `avx512f`, i.e. the
+    /// AVX-512 state.
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] vpclmulqdq: "vpclmulqdq";
-    /// AVX-512 VPCLMULQDQ (Vector PCLMULQDQ instructions)
+    /// VPCLMULQDQ (Vector PCLMULQDQ instructions)
+    ///
+    /// This covers the 256-bit VEX encoding, which needs the AVX state in
+    /// `XCR0`: the 512-bit EVEX encoding also requires `avx512f`, i.e. the
+    /// AVX-512 state.
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512vnni: "avx512vnni";
     /// AVX-512 VNNI (Vector Neural Network Instructions)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512bitalg: "avx512bitalg";
@@ -203,4 +237,8 @@ features! {
     /// MOVBE (Move Data After Swapping Bytes)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] ermsb: "ermsb";
     /// ERMSB, Enhanced REP MOVSB and STOSB
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] avx10_1_256: "avx10.1-256";
+    /// AVX10.1 with 256-bit vectors
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] avx10_1_512: "avx10.1-512";
+    /// AVX10.1 with 512-bit vectors
 }
@@ -7,77 +7,101 @@ use core::sync::atomic::Ordering;
 
 use core::sync::atomic::AtomicUsize;
 
-/// Sets the `bit` of `x`.
-#[inline]
-const fn set_bit(x: u64, bit: u32) -> u64 {
-    x | 1 << bit
-}
+/// Maximum number of features that can be cached.
+///
+/// This is derived from the `Feature` enum of the current target, so adding
+/// new features never overflows the cache.
+const CACHE_CAPACITY: u32 = super::Feature::_last as u32;
 
-/// Tests the `bit` of `x`.
-#[inline]
-const fn test_bit(x: u64, bit: u32) -> bool {
-    x & (1 << bit) != 0
-}
+/// Number of `Cache` words required to store `CACHE_CAPACITY` features.
+const CACHE_WORDS: usize = CACHE_CAPACITY.div_ceil(Cache::CAPACITY) as usize;
 
-/// Unset the `bit of `x`.
+/// Splits a feature `bit` into the index of the cache word that stores it,
+/// and the position of the bit within that word.
 #[inline]
-const fn unset_bit(x: u64, bit: u32) -> u64 {
-    x & !(1 << bit)
+const fn word_and_bit(bit: u32) -> (usize, u32) {
+    ((bit / Cache::CAPACITY) as usize, bit % Cache::CAPACITY)
 }
 
-/// Maximum number of features that can be cached.
-const CACHE_CAPACITY: u32 = 62;
-
 /// This type is used to initialize the cache
+///
+/// It stores the features using the same layout as `CACHE`, that is, each word
+/// contains `Cache::CAPACITY` features and the most significant bit is never
+/// set.
 #[derive(Copy, Clone)]
-pub(crate) struct Initializer(u64);
+pub(crate) struct Initializer([usize; CACHE_WORDS]);
 
 #[allow(clippy::use_self)]
 impl Default for Initializer {
     fn default() -> Self {
-        Initializer(0)
+        Initializer([0; CACHE_WORDS])
     }
 }
 
-// NOTE: the `debug_assert!` would catch that we do not add more Features than
-// the one fitting our cache.
+// NOTE: the `debug_assert!` would catch that we do not use a bit that does not
+// belong to any Feature.
 impl Initializer {
+    /// An initializer without any bit set.
+    pub(crate) const EMPTY: Self = Initializer([0; CACHE_WORDS]);
+
     /// Tests the `bit` of the cache.
     #[inline]
     pub(crate) fn test(self, bit: u32) -> bool {
-        debug_assert!(
-            bit < CACHE_CAPACITY,
-            "too many features, time to increase the cache size!"
-        );
-        test_bit(self.0, bit)
+        debug_assert!(bit < CACHE_CAPACITY, "feature bit out-of-bounds");
+        let (idx, bit) = word_and_bit(bit);
+        self.0[idx] & (1 << bit) != 0
     }
 
     /// Sets the `bit` of the cache.
     #[inline]
     pub(crate) fn set(&mut self, bit: u32) {
-        debug_assert!(
-            bit < CACHE_CAPACITY,
-            "too many features, time to increase the cache size!"
-        );
-        let v = self.0;
-        self.0 = set_bit(v, bit);
+        debug_assert!(bit < CACHE_CAPACITY, "feature bit out-of-bounds");
+        let (idx, bit) = word_and_bit(bit);
+        self.0[idx] |= 1 << bit;
     }
 
     /// Unsets the `bit` of the cache.
     #[inline]
     pub(crate) fn unset(&mut self, bit: u32) {
-        debug_assert!(
-            bit < CACHE_CAPACITY,
-            "too many features, time to increase the cache size!"
-        );
-        let v = self.0;
-        self.0 = unset_bit(v, bit);
+        debug_assert!(bit < CACHE_CAPACITY, "feature bit out-of-bounds");
+        let (idx, bit) = word_and_bit(bit);
+        self.0[idx] &= !(1 << bit);
+    }
+
+    /// Returns a copy with the `bit` set, usable in constants.
+    #[inline]
+    pub(crate) const fn with(mut self, bit: u32) -> Self {
+        let (idx, bit) = word_and_bit(bit);
+        self.0[idx] |= 1 << bit;
+        self
+    }
+
+    /// Returns `true` if no bit is set.
+    #[inline]
+    pub(crate) const fn is_empty(&self) -> bool {
+        let mut idx = 0;
+        while idx < CACHE_WORDS {
+            if self.0[idx] != 0 {
+                return false;
+            }
+            idx += 1;
+        }
+        true
+    }
+
+    /// Returns `true` if all the bits of `mask` are set.
+    #[inline]
+    pub(crate) fn contains(self, mask: Initializer) -> bool {
+        self.0.iter().zip(mask.0.iter()).all(|(&v, &m)| v & m == m)
     }
 }
 
 /// This global variable is a cache of the features supported by the CPU.
-// Note: on x64, we only use the first slot
-static CACHE: [Cache; 2] = [Cache::uninitialized(), Cache::uninitialized()];
+///
+/// It is a bitset split over as many words as the current target needs; each
+/// word is initialized independently, and the first access to an
+/// uninitialized word detects the features and initializes all of them.
+static CACHE: [Cache; CACHE_WORDS] = [Cache::UNINITIALIZED; CACHE_WORDS];
 
 /// Feature cache with capacity for `size_of::<usize::MAX>() * 8 - 1` features.
 ///
@@ -94,11 +118,9 @@ impl Cache {
     const MASK: usize = (1 << Cache::CAPACITY) - 1;
     const INITIALIZED_BIT: usize = 1usize << Cache::CAPACITY;
 
-    /// Creates an uninitialized cache.
+    /// An uninitialized cache.
     #[allow(clippy::declare_interior_mutable_const)]
-    const fn uninitialized() -> Self {
-        Cache(AtomicUsize::new(0))
-    }
+    const UNINITIALIZED: Self = Cache(AtomicUsize::new(0));
 
     /// Is the `bit` in the cache set? Returns `None` if the cache has not been initialized.
     #[inline]
@@ -107,7 +129,19 @@ impl Cache {
         if cached == 0 {
             None
         } else {
-            Some(test_bit(cached as u64, bit))
+            Some(cached & (1 << bit) != 0)
+        }
+    }
+
+    /// Are all the bits of `mask` set in the cache? Returns `None` if the cache
+    /// has not been initialized.
+    #[inline]
+    pub(crate) fn test_mask(&self, mask: usize) -> Option<bool> {
+        let cached = self.0.load(Ordering::Relaxed);
+        if cached == 0 {
+            None
+        } else {
+            Some(cached & mask == mask)
         }
     }
 
@@ -131,10 +165,12 @@ cfg_if::cfg_if! {
             if !env.is_null() {
                 let len = unsafe { libc::strlen(env) };
                 let env = unsafe { core::slice::from_raw_parts(env as *const u8, len) };
-                if let Ok(disable) = core::str::from_utf8(env) {
-                    for v in disable.split(" ") {
-                        let _ = super::Feature::from_str(v).map(|v| value.unset(v as u32));
-                    }
+                if let Ok(overrides) = core::str::from_utf8(env) {
+                    // Features can only be disabled: enabling one that the CPU
+                    // lacks would let safe code execute its instructions.
+                    // Entries that enable features, and unknown feature names,
+                    // are ignored.
+                    let _ = apply_overrides(&mut value, overrides, false);
                 }
             }
             do_initialize(value);
@@ -149,10 +185,74 @@ cfg_if::cfg_if! {
     }
 }
 
+/// Applies a list of feature overrides to `value`.
+///
+/// `overrides` is a list of feature names separated by whitespace. Each name
+/// can be prefixed with:
+///
+/// * `-`: the feature is disabled,
+/// * `+`: the feature is enabled, even if it was not detected.
+///
+/// A name without prefix disables the feature. Enabling features is only
+/// allowed if `allow_enable` is `true`.
+///
+/// All the valid entries are applied. The first entry that names an unknown
+/// feature, or that enables a feature when that is not allowed, is returned
+/// as an error.
+#[cfg(any(
+    feature = "std_detect_env_override",
+    feature = "std_detect_test_override"
+))]
+pub(crate) fn apply_overrides<'a>(
+    value: &mut Initializer,
+    overrides: &'a str,
+    allow_enable: bool,
+) -> Result<(), &'a str> {
+    let mut result = Ok(());
+    for entry in overrides.split_whitespace() {
+        let (enable, name) = match entry.as_bytes()[0] {
+            b'+' => (true, &entry[1..]),
+            b'-' => (false, &entry[1..]),
+            _ => (false, entry),
+        };
+        match super::Feature::from_str(name) {
+            Ok(feature) if !enable => value.unset(feature as u32),
+            Ok(feature) if allow_enable => value.set(feature as u32),
+            _ => {
+                if result.is_ok() {
+                    result = Err(entry);
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Returns the features stored in the cache, detecting them first if the
+/// cache has not been initialized yet.
+#[cfg(feature = "std_detect_test_override")]
+pub(crate) fn current() -> Initializer {
+    if CACHE[0].test(0).is_none() {
+        return detect_and_initialize();
+    }
+    let mut value = Initializer::default();
+    for (word, cache) in value.0.iter_mut().zip(CACHE.iter()) {
+        *word = cache.0.load(Ordering::Relaxed) & Cache::MASK;
+    }
+    value
+}
+
+/// Replaces the features stored in the cache with `value`.
+#[cfg(feature = "std_detect_test_override")]
+pub(crate) fn store(value: Initializer) {
+    do_initialize(value);
+}
+
 #[inline]
 fn do_initialize(value: Initializer) {
-    CACHE[0].initialize((value.0) as usize & Cache::MASK);
-    CACHE[1].initialize((value.0 >> Cache::CAPACITY) as usize & Cache::MASK);
+    for (cache, &word) in CACHE.iter().zip(value.0.iter()) {
+        cache.initialize(word & Cache::MASK);
+    }
 }
 
 // We only have to detect features once, and it's fairly costly, so hint to LLVM
@@ -173,22 +273,128 @@ fn detect_and_initialize() -> Initializer {
 /// initializes it with the result of `os::detect_features()`.
 ///
 /// On its first invocation, it detects the CPU features and caches them in the
-/// `CACHE` global variable as an `AtomicU64`.
+/// `CACHE` global variable as an array of `AtomicUsize`.
 ///
 /// It uses the `Feature` variant to index into this variable as a bitset. If
 /// the bit is set, the feature is enabled, and otherwise it is disabled.
 ///
 /// If the feature `std_detect_env_override` is enabled looks for the env
 /// variable `RUST_STD_DETECT_UNSTABLE` and uses its content to disable
-/// Features that would had been otherwise detected.
+/// Features, see `apply_overrides` for the syntax.
 #[inline]
 pub(crate) fn test(bit: u32) -> bool {
-    let (relative_bit, idx) = if bit < Cache::CAPACITY {
-        (bit, 0)
-    } else {
-        (bit - Cache::CAPACITY, 1)
-    };
+    let (idx, relative_bit) = word_and_bit(bit);
     CACHE[idx]
         .test(relative_bit)
         .unwrap_or_else(|| detect_and_initialize().test(bit))
 }
+
+/// Tests that all the bits of `mask` are set in the storage, initializing it
+/// like `test` does.
+///
+/// Words of `mask` without any bit set are not loaded, so when `mask` is a
+/// constant this compiles to a single load and compare on most targets.
+#[inline]
+pub(crate) fn test_all(mask: Initializer) -> bool {
+    for (idx, &word) in mask.0.iter().enumerate() {
+        if word == 0 {
+            continue;
+        }
+        match CACHE[idx].test_mask(word) {
+            Some(true) => {}
+            Some(false) => return false,
+            None => return detect_and_initialize().contains(mask),
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn initializer_set_test_unset() {
+        let mut value = Initializer::default();
+        for bit in 0..CACHE_CAPACITY {
+            assert!(!value.test(bit));
+            value.set(bit);
+            assert!(value.test(bit));
+        }
+        for bit in (0..CACHE_CAPACITY).step_by(2) {
+            value.unset(bit);
+        }
+        for bit in 0..CACHE_CAPACITY {
+            assert_eq!(value.test(bit), bit % 2 == 1);
+        }
+        for word in value.0 {
+            assert_eq!(word & !Cache::MASK, 0);
+        }
+    }
+
+    #[test]
+    fn initializer_mask() {
+        assert!(Initializer::EMPTY.is_empty());
+        let last = CACHE_CAPACITY - 1;
+        let mask = Initializer::EMPTY.with(0).with(last);
+        assert!(!mask.is_empty());
+
+        let mut value = Initializer::default();
+        value.set(0);
+        assert!(!value.contains(mask));
+        value.set(last);
+        assert!(value.contains(mask));
+        assert!(value.contains(Initializer::EMPTY));
+        value.unset(0);
+        assert!(!value.contains(mask));
+    }
+
+    #[test]
+    fn word_and_bit_never_uses_the_initialized_bit() {
+        assert_eq!(word_and_bit(0), (0, 0));
+        assert_eq!(word_and_bit(Cache::CAPACITY - 1), (0, Cache::CAPACITY - 1));
+        assert_eq!(word_and_bit(Cache::CAPACITY), (1, 0));
+        assert_eq!(word_and_bit(2 * Cache::CAPACITY + 3), (2, 3));
+    }
+
+    #[cfg(any(
+        feature = "std_detect_env_override",
+        feature = "std_detect_test_override"
+    ))]
+    #[test]
+    fn overrides() {
+        use super::super::Feature;
+
+        let names: std::vec::Vec<&str> = super::super::features().map(|(name, _)| name).collect();
+        let (a, b, c) = (names[0], names[1], names[2]);
+        let bit = |name| Feature::from_str(name).unwrap() as u32;
+
+        let mut value = Initializer::default();
+        value.set(bit(a));
+        value.set(bit(b));
+        assert_eq!(
+            apply_overrides(&mut value, &format!("{a}  -{b}\t+{c}"), true),
+            Ok(())
+        );
+        assert!(!value.test(bit(a)));
+        assert!(!value.test(bit(b)));
+        assert!(value.test(bit(c)));
+
+        // Enabling is rejected, but the other entries are still applied.
+        let mut value = Initializer::default();
+        value.set(bit(a));
+        let overrides = format!("+{b} -{a}");
+        assert_eq!(
+            apply_overrides(&mut value, &overrides, false),
+            Err(&*format!("+{b}"))
+        );
+        assert!(!value.test(bit(a)));
+        assert!(!value.test(bit(b)));
+
+        let mut value = Initializer::default();
+        assert_eq!(
+            apply_overrides(&mut value, "-not-a-feature", true),
+            Err("-not-a-feature")
+        );
+    }
+}
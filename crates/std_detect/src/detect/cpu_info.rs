@@ -0,0 +1,279 @@
+//! Structured information about the CPU that goes beyond boolean features.
+//!
+//! The information is gathered from the same sources that are used for
+//! run-time feature detection (e.g. `cpuid` on x86, `MIDR_EL1` and the
+//! auxiliary vector on AArch64), plus a couple of OS interfaces that expose
+//! cache and vector length information.
+//!
+//! Unlike features, this information is not cached: every call to
+//! [`cpu_info`] queries the hardware and the OS again.
+
+#![allow(dead_code)] // not used on all platforms
+
+use cfg_if::cfg_if;
+use core::fmt;
+
+/// Maximum number of cache descriptors stored in a [`CpuInfo`].
+const MAX_CACHES: usize = 8;
+
+/// The type of a CPU cache.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub enum CacheType {
+    /// Data cache.
+    Data,
+    /// Instruction cache.
+    Instruction,
+    /// Unified data and instruction cache.
+    Unified,
+}
+
+/// Description of one level of the CPU cache hierarchy.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct CacheInfo {
+    pub(crate) level: u8,
+    pub(crate) cache_type: CacheType,
+    pub(crate) size: u32,
+    pub(crate) line_size: u32,
+    pub(crate) associativity: u32,
+    pub(crate) shared_by: u32,
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl CacheInfo {
+    /// Level of the cache, starting at 1.
+    #[inline]
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// Type of the cache.
+    #[inline]
+    pub fn cache_type(&self) -> CacheType {
+        self.cache_type
+    }
+
+    /// Total size of the cache in bytes.
+    #[inline]
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// Size of a cache line in bytes.
+    #[inline]
+    pub fn line_size(&self) -> u32 {
+        self.line_size
+    }
+
+    /// Number of ways of associativity, or 0 if unknown.
+    #[inline]
+    pub fn associativity(&self) -> u32 {
+        self.associativity
+    }
+
+    /// Maximum number of logical CPUs sharing this cache, or 0 if unknown.
+    #[inline]
+    pub fn shared_by(&self) -> u32 {
+        self.shared_by
+    }
+}
+
+/// The type of a core in a hybrid CPU.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub enum CoreType {
+    /// A core optimized for performance (e.g. Intel "Core" P-cores).
+    Performance,
+    /// A core optimized for power efficiency (e.g. Intel "Atom" E-cores).
+    Efficiency,
+}
+
+/// Structured information about the CPU the current thread is running on.
+///
+/// Every piece of information is optional because it depends on both the
+/// architecture and the operating system: an accessor returns `None` (or no
+/// items) when the information is not available.
+///
+/// Some of the information, like the core type on hybrid CPUs, is specific
+/// to the logical CPU that executed the query. Pin the current thread to a
+/// CPU before calling [`cpu_info`] if that matters.
+#[derive(Clone, PartialEq, Eq)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct CpuInfo {
+    pub(crate) vendor: [u8; 16],
+    pub(crate) vendor_len: usize,
+    pub(crate) family: Option<u32>,
+    pub(crate) model: Option<u32>,
+    pub(crate) stepping: Option<u32>,
+    pub(crate) midr: Option<u64>,
+    pub(crate) core_type: Option<CoreType>,
+    pub(crate) cache_line_size: Option<u32>,
+    pub(crate) vector_length: Option<u32>,
+    pub(crate) caches: [Option<CacheInfo>; MAX_CACHES],
+    pub(crate) num_caches: usize,
+}
+
+impl Default for CpuInfo {
+    #[inline]
+    fn default() -> Self {
+        CpuInfo {
+            vendor: [0; 16],
+            vendor_len: 0,
+            family: None,
+            model: None,
+            stepping: None,
+            midr: None,
+            core_type: None,
+            cache_line_size: None,
+            vector_length: None,
+            caches: [None; MAX_CACHES],
+            num_caches: 0,
+        }
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Debug for CpuInfo {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct Caches<'a>(&'a CpuInfo);
+        impl fmt::Debug for Caches<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list().entries(self.0.caches()).finish()
+            }
+        }
+        f.debug_struct("CpuInfo")
+            .field("vendor", &self.vendor())
+            .field("family", &self.family)
+            .field("model", &self.model)
+            .field("stepping", &self.stepping)
+            .field("midr", &self.midr)
+            .field("core_type", &self.core_type)
+            .field("cache_line_size", &self.cache_line_size)
+            .field("vector_length", &self.vector_length)
+            .field("caches", &Caches(self))
+            .finish()
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl CpuInfo {
+    /// Name of the CPU vendor.
+    ///
+    /// On x86 this is the vendor identification string returned by `cpuid`,
+    /// e.g. `"GenuineIntel"` or `"AuthenticAMD"`. On AArch64 this is the name
+    /// of the implementer encoded in `MIDR_EL1`, e.g. `"ARM"` or `"Apple"`.
+    #[inline]
+    pub fn vendor(&self) -> Option<&str> {
+        match self.vendor_len {
+            0 => None,
+            len => core::str::from_utf8(&self.vendor[..len]).ok(),
+        }
+    }
+
+    /// CPU family.
+    ///
+    /// On x86 this is the display family, that is, the family identifier with
+    /// the extended family added in. On AArch64 this is the `Architecture`
+    /// field of `MIDR_EL1`.
+    #[inline]
+    pub fn family(&self) -> Option<u32> {
+        self.family
+    }
+
+    /// CPU model.
+    ///
+    /// On x86 this is the display model, that is, the model identifier with
+    /// the extended model added in where applicable. On AArch64 this is the
+    /// `PartNum` field of `MIDR_EL1`.
+    #[inline]
+    pub fn model(&self) -> Option<u32> {
+        self.model
+    }
+
+    /// CPU stepping.
+    ///
+    /// On AArch64 this is the `Revision` field of `MIDR_EL1`.
+    #[inline]
+    pub fn stepping(&self) -> Option<u32> {
+        self.stepping
+    }
+
+    /// Raw value of the `MIDR_EL1` register (AArch64 only).
+    ///
+    /// On big.LITTLE systems the different clusters have different `MIDR_EL1`
+    /// values, so this identifies the core the query ran on.
+    #[inline]
+    pub fn midr(&self) -> Option<u64> {
+        self.midr
+    }
+
+    /// Type of the core on hybrid CPUs.
+    ///
+    /// This is `None` on CPUs that are not hybrid.
+    #[inline]
+    pub fn core_type(&self) -> Option<CoreType> {
+        self.core_type
+    }
+
+    /// Size in bytes of the smallest data cache line.
+    #[inline]
+    pub fn cache_line_size(&self) -> Option<u32> {
+        self.cache_line_size
+    }
+
+    /// Length in bytes of the scalable vector registers.
+    ///
+    /// This is the SVE vector length on AArch64, and `vlenb` on RISC-V.
+    #[inline]
+    pub fn vector_length(&self) -> Option<u32> {
+        self.vector_length
+    }
+
+    /// Descriptions of the CPU caches, in the order reported by the CPU or
+    /// the OS.
+    #[inline]
+    pub fn caches(&self) -> impl Iterator<Item = CacheInfo> + '_ {
+        self.caches[..self.num_caches].iter().flatten().copied()
+    }
+
+    /// Sets the vendor name, truncating it if it does not fit.
+    pub(crate) fn set_vendor(&mut self, vendor: &[u8]) {
+        let len = vendor.len().min(self.vendor.len());
+        self.vendor[..len].copy_from_slice(&vendor[..len]);
+        self.vendor_len = len;
+    }
+
+    /// Appends a cache description, ignoring it if there is no room left.
+    pub(crate) fn push_cache(&mut self, cache: CacheInfo) {
+        if self.num_caches < MAX_CACHES {
+            self.caches[self.num_caches] = Some(cache);
+            self.num_caches += 1;
+        }
+    }
+}
+
+/// Queries structured information about the CPU the current thread is
+/// running on.
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn cpu_info() -> CpuInfo {
+    cfg_if! {
+        if #[cfg(miri)] {
+            CpuInfo::default()
+        } else if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
+            super::os::cpu_info()
+        } else if #[cfg(all(
+            any(target_os = "linux", target_os = "android"),
+            feature = "libc",
+            any(target_arch = "aarch64", target_arch = "riscv32", target_arch = "riscv64"),
+        ))] {
+            super::os::cpu_info()
+        } else {
+            CpuInfo::default()
+        }
+    }
+}
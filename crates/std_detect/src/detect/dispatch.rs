@@ -0,0 +1,239 @@
+//! Function multiversioning: lazily resolved function pointers.
+//!
+//! A [`Dispatch`] cell plays the role of an ELF `ifunc`: the first call runs a
+//! resolver that uses run-time feature detection to pick the best
+//! implementation of a function, and later calls go straight to the chosen
+//! implementation through a cached function pointer.
+
+use core::mem;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// A function pointer that is resolved on first use.
+///
+/// `F` is a function pointer type, e.g. `fn(&[u8]) -> u32`, see [`DispatchFn`]
+/// for the supported signatures. The resolver is called the first time
+/// [`get`](Self::get) is called, and the function pointer it returns is
+/// cached for all the following calls. If several
+/// threads race to resolve the pointer, the resolver can run more than once,
+/// so it should be cheap and always return the same function.
+///
+/// # Example
+///
+/// ```
+/// #![feature(stdsimd)]
+/// # #[macro_use] extern crate std_detect;
+/// use std_detect::detect::Dispatch;
+///
+/// fn sum_fallback(x: &[u32]) -> u32 {
+///     x.iter().sum()
+/// }
+///
+/// #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+/// fn sum_avx2(x: &[u32]) -> u32 {
+///     #[target_feature(enable = "avx2")]
+///     unsafe fn imp(x: &[u32]) -> u32 {
+///         // The compiler can vectorize this loop with AVX2.
+///         x.iter().sum()
+///     }
+///     // SAFETY: this is only selected if AVX2 is detected.
+///     unsafe { imp(x) }
+/// }
+///
+/// static SUM: Dispatch<fn(&[u32]) -> u32> = Dispatch::new(|| {
+///     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+///     if is_x86_feature_detected!("avx2") {
+///         return sum_avx2;
+///     }
+///     sum_fallback
+/// });
+///
+/// # fn main() {
+/// assert_eq!(SUM.get()(&[1, 2, 3]), 6);
+/// # }
+/// ```
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct Dispatch<F> {
+    resolved: AtomicPtr<()>,
+    resolve: fn() -> F,
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl<F: DispatchFn> Dispatch<F> {
+    /// Creates a cell that calls `resolve` on first use to pick the function.
+    #[inline]
+    pub const fn new(resolve: fn() -> F) -> Self {
+        Dispatch {
+            resolved: AtomicPtr::new(ptr::null_mut()),
+            resolve,
+        }
+    }
+
+    /// Returns the resolved function, running the resolver if this is the
+    /// first call.
+    #[inline]
+    pub fn get(&self) -> F {
+        // Function pointers only point to code, so `Relaxed` is enough: no
+        // other memory needs to be synchronized with the pointer.
+        let resolved = self.resolved.load(Ordering::Relaxed);
+        if resolved.is_null() {
+            self.resolve_and_store()
+        } else {
+            // SAFETY: `resolved` was stored by `resolve_and_store` from an `F`.
+            unsafe { F::from_addr(resolved) }
+        }
+    }
+
+    /// Forgets the resolved function, so that the next call to
+    /// [`get`](Self::get) runs the resolver again.
+    ///
+    /// This is useful in tests that override the detected features.
+    #[inline]
+    pub fn reset(&self) {
+        self.resolved.store(ptr::null_mut(), Ordering::Relaxed);
+    }
+
+    #[cold]
+    fn resolve_and_store(&self) -> F {
+        let f = (self.resolve)();
+        self.resolved.store(f.addr(), Ordering::Relaxed);
+        f
+    }
+}
+
+/// Function pointer types that a [`Dispatch`] cell can hold.
+///
+/// This trait is sealed: it is implemented for the `fn` and `unsafe fn`
+/// pointers with up to 3 arguments, each of which can be a value or a
+/// reference, e.g. `fn(&[u8], &mut [u8])` or `unsafe fn(*const u8, usize) ->
+/// u32`. References in the return type are not supported.
+///
+/// Functions with `#[target_feature]` attributes are `unsafe fn`s, and safe
+/// functions coerce to `unsafe fn` pointers, so `unsafe fn` pointers can hold
+/// every implementation of a function.
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub trait DispatchFn: Copy + sealed::Sealed {}
+
+mod sealed {
+    /// Conversions between a function pointer and its address.
+    pub trait Sealed: Sized {
+        fn addr(self) -> *mut ();
+
+        /// # Safety
+        ///
+        /// `addr` must have been returned by `addr` on a `Self`.
+        unsafe fn from_addr(addr: *mut ()) -> Self;
+    }
+}
+
+/// Implements `DispatchFn` for the `fn` and `unsafe fn` pointers whose
+/// arguments are `$arg`, passed by value, by shared or by mutable reference.
+macro_rules! impl_dispatch_fn {
+    ($($arg:ident $lt:lifetime),*) => {
+        impl_dispatch_fn!(@expand [] [] []; $($arg $lt),*);
+    };
+    (@expand [$($lts:tt)*] [$($generics:tt)*] [$($args:tt)*];
+     $arg:ident $lt:lifetime $(, $rest:ident $rest_lt:lifetime)*) => {
+        impl_dispatch_fn!(@expand [$($lts)*] [$($generics)* $arg,] [$($args)* $arg,];
+                          $($rest $rest_lt),*);
+        impl_dispatch_fn!(@expand [$($lts)* $lt,] [$($generics)* $arg: ?Sized,]
+                          [$($args)* &$lt $arg,]; $($rest $rest_lt),*);
+        impl_dispatch_fn!(@expand [$($lts)* $lt,] [$($generics)* $arg: ?Sized,]
+                          [$($args)* &$lt mut $arg,]; $($rest $rest_lt),*);
+    };
+    (@expand [$($lts:tt)*] [$($generics:tt)*] [$($args:tt)*];) => {
+        impl_dispatch_fn!(@impl [$($generics)*] for<$($lts)*> fn($($args)*) -> R);
+        impl_dispatch_fn!(@impl [$($generics)*] for<$($lts)*> unsafe fn($($args)*) -> R);
+    };
+    (@impl [$($generics:tt)*] $f:ty) => {
+        // `fn(&'x T)` and `for<'a> fn(&'a T)` are different types, but
+        // coherence warns about the impls for both, see rust-lang/rust#56105.
+        #[unstable(feature = "stdsimd", issue = "27731")]
+        #[allow(coherence_leak_check)]
+        impl<$($generics)* R> DispatchFn for $f {}
+
+        #[allow(coherence_leak_check)]
+        impl<$($generics)* R> sealed::Sealed for $f {
+            #[inline]
+            fn addr(self) -> *mut () {
+                self as *mut ()
+            }
+
+            #[inline]
+            unsafe fn from_addr(addr: *mut ()) -> Self {
+                // SAFETY: function pointers have the size of `*mut ()`, and
+                // the caller guarantees that `addr` is the address of a `Self`.
+                mem::transmute::<*mut (), Self>(addr)
+            }
+        }
+    };
+}
+
+impl_dispatch_fn!();
+impl_dispatch_fn!(A 'a);
+impl_dispatch_fn!(A 'a, B 'b);
+impl_dispatch_fn!(A 'a, B 'b, C 'c);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::{AtomicBool, AtomicUsize};
+
+    fn add_one(x: u32) -> u32 {
+        x + 1
+    }
+
+    fn add_two(x: u32) -> u32 {
+        x + 2
+    }
+
+    #[test]
+    fn resolves_once() {
+        static RESOLVED: AtomicUsize = AtomicUsize::new(0);
+        static F: Dispatch<fn(u32) -> u32> = Dispatch::new(|| {
+            RESOLVED.fetch_add(1, Ordering::Relaxed);
+            add_one
+        });
+        assert_eq!(F.get()(1), 2);
+        assert_eq!(F.get()(2), 3);
+        assert_eq!(RESOLVED.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn reset() {
+        static TWO: AtomicBool = AtomicBool::new(false);
+        static F: Dispatch<fn(u32) -> u32> = Dispatch::new(|| {
+            if TWO.load(Ordering::Relaxed) {
+                add_two
+            } else {
+                add_one
+            }
+        });
+        assert_eq!(F.get()(1), 2);
+        TWO.store(true, Ordering::Relaxed);
+        assert_eq!(F.get()(1), 2);
+        F.reset();
+        assert_eq!(F.get()(1), 3);
+    }
+
+    #[test]
+    fn unsafe_fn() {
+        unsafe fn read(x: *const u32) -> u32 {
+            *x
+        }
+        static F: Dispatch<unsafe fn(*const u32) -> u32> = Dispatch::new(|| read);
+        assert_eq!(unsafe { F.get()(&7) }, 7);
+    }
+
+    #[test]
+    fn references() {
+        fn copy(src: &[u8], dst: &mut [u8], n: usize) -> usize {
+            dst[..n].copy_from_slice(&src[..n]);
+            n
+        }
+        static F: Dispatch<fn(&[u8], &mut [u8], usize) -> usize> = Dispatch::new(|| copy);
+        let mut dst = [0; 3];
+        assert_eq!(F.get()(&[1, 2, 3], &mut dst, 2), 2);
+        assert_eq!(dst, [1, 2, 0]);
+    }
+}
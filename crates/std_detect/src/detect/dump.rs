@@ -0,0 +1,66 @@
+//! Parsing of the JSON printed by the `std_detect_dump` binary, for the tests
+//! that use it as a fixture.
+//!
+//! The output of `std_detect_dump` has one member or array item per line, so
+//! this only looks at lines instead of parsing JSON.
+
+use std::string::String;
+use std::vec::Vec;
+
+/// Returns the `"name": value` members of the object `name`.
+pub(crate) fn object<'a>(json: &'a str, name: &str) -> Vec<(&'a str, &'a str)> {
+    let start = format!("\"{name}\": {{");
+    json.lines()
+        .map(str::trim)
+        .skip_while(|line| *line != start)
+        .skip(1)
+        .take_while(|line| !line.starts_with('}'))
+        .filter_map(member)
+        .collect()
+}
+
+/// Returns the items of the array of strings `name`.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub(crate) fn array<'a>(json: &'a str, name: &str) -> Vec<&'a str> {
+    let start = format!("\"{name}\": [");
+    json.lines()
+        .map(str::trim)
+        .skip_while(|line| *line != start)
+        .skip(1)
+        .take_while(|line| !line.starts_with(']'))
+        .map(|line| line.trim_end_matches(',').trim_matches('"'))
+        .collect()
+}
+
+/// Returns the value of the string member `name` of the outermost object,
+/// e.g. `0x2` for `"hwcap2": "0x2"`.
+#[cfg(target_arch = "aarch64")]
+pub(crate) fn string<'a>(json: &'a str, name: &str) -> Option<&'a str> {
+    json.lines()
+        .filter(|line| line.starts_with("  \""))
+        .filter_map(|line| member(line.trim()))
+        .find(|(n, _)| *n == name)
+        .map(|(_, value)| value)
+}
+
+/// Returns the names of the features that are reported as detected.
+pub(crate) fn features(json: &str) -> Vec<&str> {
+    object(json, "features")
+        .into_iter()
+        .filter(|(_, enabled)| *enabled == "true")
+        .map(|(name, _)| name)
+        .collect()
+}
+
+/// Returns the dump of the host named by the `STD_DETECT_DUMP` environment
+/// variable, which CI records before running the tests, see ci/run.sh.
+pub(crate) fn host() -> Option<String> {
+    let path = std::env::var_os("STD_DETECT_DUMP")?;
+    Some(std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{path:?}: {e}")))
+}
+
+/// Parses `"name": value`, removing the quotes of string values.
+fn member(line: &str) -> Option<(&str, &str)> {
+    let (name, value) = line.trim_end_matches(',').split_once(": ")?;
+    Some((name.trim_matches('"'), value.trim_matches('"')))
+}
@@ -0,0 +1,446 @@
+//! CPU level queries.
+//!
+//! A level is a named set of features that binaries are commonly built
+//! for, like the [x86-64 psABI] micro-architecture levels or the Arm
+//! architecture versions. Levels are built on top of the `Feature` tables:
+//! a level is supported if all of its features are detected, so a level
+//! query agrees with the `is_{arch}_feature_detected!` macros, including when
+//! features are disabled with `RUST_STD_DETECT_UNSTABLE`.
+//!
+//! Only features that can be detected at run-time are checked. For example,
+//! x86-64-v2 also requires `LAHF`/`SAHF` in 64-bit mode, which is not
+//! detected, so it is assumed to be present.
+//!
+//! [x86-64 psABI]: https://gitlab.com/x86-psABIs/x86-64-ABI
+
+#![allow(dead_code)] // not used on all platforms
+
+use super::{cache, check_for, Feature};
+use cfg_if::cfg_if;
+use core::fmt;
+
+/// A named set of features.
+pub(crate) struct Level {
+    name: &'static str,
+    /// Bit `i` is set if this level includes the level at index `i` of the
+    /// table, including itself.
+    includes: u32,
+    /// The features that this level adds to the levels it includes.
+    features: &'static [Feature],
+}
+
+cfg_if! {
+    if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
+        /// The x86-64 psABI micro-architecture levels.
+        const LEVELS: &[Level] = &[
+            Level {
+                name: "x86-64",
+                includes: 0b1,
+                features: &[Feature::fxsr, Feature::mmx, Feature::sse, Feature::sse2],
+            },
+            Level {
+                name: "x86-64-v2",
+                includes: 0b11,
+                features: &[
+                    Feature::cmpxchg16b,
+                    Feature::popcnt,
+                    Feature::sse3,
+                    Feature::sse4_1,
+                    Feature::sse4_2,
+                    Feature::ssse3,
+                ],
+            },
+            Level {
+                name: "x86-64-v3",
+                includes: 0b111,
+                features: &[
+                    Feature::avx,
+                    Feature::avx2,
+                    Feature::bmi1,
+                    Feature::bmi2,
+                    Feature::f16c,
+                    Feature::fma,
+                    Feature::lzcnt,
+                    Feature::movbe,
+                    Feature::xsave,
+                ],
+            },
+            Level {
+                name: "x86-64-v4",
+                includes: 0b1111,
+                features: &[
+                    Feature::avx512f,
+                    Feature::avx512bw,
+                    Feature::avx512cd,
+                    Feature::avx512dq,
+                    Feature::avx512vl,
+                ],
+            },
+        ];
+    } else if #[cfg(target_arch = "aarch64")] {
+        /// The Arm A-profile architecture versions, restricted to their
+        /// mandatory features that can be detected.
+        ///
+        /// Armv9.x-A includes Armv8.(x+5)-A. Later versions only add features
+        /// that are not detected yet, so they are not listed.
+        const LEVELS: &[Level] = &[
+            Level {
+                name: "armv8-a",
+                includes: 0b1,
+                features: &[Feature::fp, Feature::asimd],
+            },
+            Level {
+                name: "armv8.1-a",
+                includes: 0b11,
+                features: &[Feature::crc, Feature::lse, Feature::rdm],
+            },
+            Level {
+                name: "armv8.2-a",
+                includes: 0b111,
+                features: &[Feature::dpb],
+            },
+            Level {
+                name: "armv8.3-a",
+                includes: 0b1111,
+                features: &[
+                    Feature::rcpc,
+                    Feature::paca,
+                    Feature::pacg,
+                    Feature::jsconv,
+                    Feature::fcma,
+                ],
+            },
+            Level {
+                name: "armv8.4-a",
+                includes: 0b1_1111,
+                features: &[
+                    Feature::dotprod,
+                    Feature::dit,
+                    Feature::flagm,
+                    Feature::lse2,
+                    Feature::rcpc2,
+                ],
+            },
+            Level {
+                name: "armv8.5-a",
+                includes: 0b11_1111,
+                features: &[
+                    Feature::sb,
+                    Feature::ssbs,
+                    Feature::dpb2,
+                    Feature::frintts,
+                    Feature::bti,
+                ],
+            },
+            Level {
+                name: "armv8.6-a",
+                includes: 0b111_1111,
+                features: &[Feature::bf16, Feature::i8mm],
+            },
+            Level {
+                name: "armv9-a",
+                includes: 0b1011_1111,
+                features: &[Feature::sve, Feature::sve2],
+            },
+            Level {
+                name: "armv9.1-a",
+                includes: 0b1_1111_1111,
+                features: &[],
+            },
+        ];
+    } else {
+        const LEVELS: &[Level] = &[];
+    }
+}
+
+/// The levels supported by the CPU, see [`cpu_level`].
+#[derive(Copy, Clone)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct CpuLevel {
+    levels: &'static [Level],
+    detected: cache::Initializer,
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Debug for CpuLevel {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CpuLevel")
+            .field("highest", &self.highest())
+            .field("next", &self.next())
+            .finish()
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl CpuLevel {
+    /// Name of the highest supported level, e.g. `"x86-64-v3"` or
+    /// `"armv8.2-a"`.
+    ///
+    /// Returns `None` if not even the baseline level is supported, or if
+    /// there are no levels for the target architecture.
+    #[inline]
+    pub fn highest(&self) -> Option<&'static str> {
+        self.highest_index().map(|i| self.levels[i].name)
+    }
+
+    /// Name of the lowest level above [`highest`](Self::highest) that is not
+    /// supported, or `None` if all the levels are supported.
+    #[inline]
+    pub fn next(&self) -> Option<&'static str> {
+        let start = self.highest_index().map_or(0, |i| i + 1);
+        (start..self.levels.len())
+            .find(|&i| !self.supports_index(i))
+            .map(|i| self.levels[i].name)
+    }
+
+    /// Returns whether `level` is supported, or `None` if `level` is not
+    /// the name of a level of the target architecture.
+    #[inline]
+    pub fn supports(&self, level: &str) -> Option<bool> {
+        self.index(level).map(|i| self.supports_index(i))
+    }
+
+    /// Names of the features of `level` that are not detected, or `None` if
+    /// `level` is not the name of a level of the target architecture.
+    ///
+    /// This includes the features of the levels that `level` includes.
+    #[inline]
+    pub fn missing(&self, level: &str) -> Option<impl Iterator<Item = &'static str> + '_> {
+        self.index(level)
+            .map(|i| self.missing_features(i).map(Feature::to_str))
+    }
+
+    /// Names of all the levels of the target architecture, from the lowest
+    /// to the highest.
+    #[inline]
+    pub fn levels(&self) -> impl Iterator<Item = &'static str> {
+        self.levels.iter().map(|level| level.name)
+    }
+
+    fn index(&self, name: &str) -> Option<usize> {
+        self.levels.iter().position(|level| level.name == name)
+    }
+
+    fn missing_features(&self, index: usize) -> impl Iterator<Item = Feature> + '_ {
+        let includes = self.levels[index].includes;
+        let detected = self.detected;
+        self.levels
+            .iter()
+            .enumerate()
+            .filter(move |&(i, _)| includes & (1 << i) != 0)
+            .flat_map(|(_, level)| level.features.iter().copied())
+            .filter(move |&feature| !detected.test(feature as u32))
+    }
+
+    fn supports_index(&self, index: usize) -> bool {
+        self.missing_features(index).next().is_none()
+    }
+
+    fn highest_index(&self) -> Option<usize> {
+        (0..self.levels.len())
+            .rev()
+            .find(|&i| self.supports_index(i))
+    }
+
+    /// Evaluates the levels against the `detected` features.
+    pub(crate) fn from_features(detected: cache::Initializer) -> Self {
+        CpuLevel {
+            levels: LEVELS,
+            detected,
+        }
+    }
+}
+
+/// Queries which CPU levels are supported.
+///
+/// On x86 and x86_64 the levels are the x86-64 psABI micro-architecture
+/// levels, from `"x86-64"` to `"x86-64-v4"`. On AArch64 they are the Arm
+/// architecture versions, from `"armv8-a"` to `"armv9.1-a"`. There are no
+/// levels on the other architectures.
+///
+/// ```
+/// # #![feature(stdsimd)]
+/// let level = std_detect::detect::cpu_level();
+/// if level.supports("x86-64-v3") == Some(false) {
+///     let missing: Vec<_> = level.missing("x86-64-v3").unwrap().collect();
+///     println!("x86-64-v3 is not supported, missing: {missing:?}");
+/// }
+/// ```
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn cpu_level() -> CpuLevel {
+    let mut detected = cache::Initializer::default();
+    for level in LEVELS {
+        for &feature in level.features {
+            if check_for(feature) {
+                detected.set(feature as u32);
+            }
+        }
+    }
+    CpuLevel::from_features(detected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn includes() {
+        // A level includes itself and is only included by levels after it.
+        for (i, level) in LEVELS.iter().enumerate() {
+            assert!(level.includes & (1 << i) != 0, "{}", level.name);
+            assert!(level.includes >> (i + 1) == 0, "{}", level.name);
+        }
+    }
+
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(miri)))]
+    #[test]
+    fn x86() {
+        use super::super::os::{detect_features_from, CpuidDump};
+
+        let level = |name: &str, xcr0: Option<&str>| {
+            let path = std::format!(
+                "{}/src/detect/test_data/x86/{name}.cpuid",
+                env!("CARGO_MANIFEST_DIR")
+            );
+            let mut dump = std::fs::read_to_string(path).unwrap();
+            if let Some(xcr0) = xcr0 {
+                let start = dump.find("xcr0=").unwrap();
+                dump.replace_range(start.., xcr0);
+            }
+            CpuLevel::from_features(detect_features_from(&CpuidDump::parse(&dump)))
+        };
+
+        let cascade_lake = level("cascade-lake", None);
+        assert_eq!(cascade_lake.highest(), Some("x86-64-v4"));
+        assert_eq!(cascade_lake.next(), None);
+        assert_eq!(cascade_lake.missing("x86-64-v4").unwrap().count(), 0);
+
+        let alder_lake = level("alder-lake", None);
+        assert_eq!(alder_lake.highest(), Some("x86-64-v3"));
+        assert_eq!(alder_lake.next(), Some("x86-64-v4"));
+        assert_eq!(alder_lake.supports("x86-64-v4"), Some(false));
+        assert_eq!(
+            alder_lake.missing("x86-64-v4").unwrap().collect::<Vec<_>>(),
+            ["avx512f", "avx512bw", "avx512cd", "avx512dq", "avx512vl"]
+        );
+
+        // An OS without AVX support in XCR0 also limits the level.
+        let no_avx = level("cascade-lake", Some("xcr0=0x3"));
+        assert_eq!(no_avx.highest(), Some("x86-64-v2"));
+        let missing: Vec<_> = no_avx.missing("x86-64-v3").unwrap().collect();
+        assert!(missing.contains(&"avx"), "{missing:?}");
+        assert!(!missing.contains(&"sse4.2"), "{missing:?}");
+
+        let zen2 = level("zen2", None);
+        assert_eq!(zen2.highest(), Some("x86-64-v3"));
+
+        assert!(zen2.supports("armv8-a").is_none());
+        assert!(zen2.missing("x86-64-v5").is_none());
+        assert_eq!(zen2.levels().count(), 4);
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    #[test]
+    fn aarch64() {
+        let level = |features: &[Feature]| {
+            let mut detected = cache::Initializer::default();
+            for &f in features {
+                detected.set(f as u32);
+            }
+            CpuLevel::from_features(detected)
+        };
+        let v8_2 = [
+            Feature::fp,
+            Feature::asimd,
+            Feature::crc,
+            Feature::lse,
+            Feature::rdm,
+            Feature::dpb,
+        ];
+
+        // Cortex-A55: Armv8.2-A, plus RCpc and DotProd from later versions.
+        let a55 = level(&[&v8_2[..], &[Feature::rcpc, Feature::dotprod]].concat());
+        assert_eq!(a55.highest(), Some("armv8.2-a"));
+        assert_eq!(a55.next(), Some("armv8.3-a"));
+        assert_eq!(
+            a55.missing("armv8.3-a").unwrap().collect::<Vec<_>>(),
+            ["paca", "pacg", "jsconv", "fcma"]
+        );
+
+        // Armv9-A requires Armv8.5-A and SVE2, but not Armv8.6-A.
+        let v8_5 = [
+            &v8_2[..],
+            &[
+                Feature::rcpc,
+                Feature::paca,
+                Feature::pacg,
+                Feature::jsconv,
+                Feature::fcma,
+                Feature::dotprod,
+                Feature::dit,
+                Feature::flagm,
+                Feature::lse2,
+                Feature::rcpc2,
+                Feature::sb,
+                Feature::ssbs,
+                Feature::dpb2,
+                Feature::frintts,
+                Feature::bti,
+            ],
+        ]
+        .concat();
+        let v9 = level(&[&v8_5[..], &[Feature::sve, Feature::sve2]].concat());
+        assert_eq!(v9.highest(), Some("armv9-a"));
+        assert_eq!(v9.supports("armv8.6-a"), Some(false));
+        assert_eq!(
+            v9.missing("armv9.1-a").unwrap().collect::<Vec<_>>(),
+            ["bf16", "i8mm"]
+        );
+
+        assert_eq!(level(&[]).highest(), None);
+        assert_eq!(level(&[]).next(), Some("armv8-a"));
+    }
+
+    #[test]
+    fn host() {
+        let level = cpu_level();
+        for (i, l) in LEVELS.iter().enumerate() {
+            let included = || {
+                LEVELS
+                    .iter()
+                    .enumerate()
+                    .filter(move |&(j, _)| l.includes & (1 << j) != 0)
+                    .map(|(_, included)| included)
+            };
+            // A level is supported if and only if all its features, and those
+            // of the levels it includes, are detected.
+            let detected = included().all(|l| l.features.iter().all(|&f| check_for(f)));
+            assert_eq!(level.supports(l.name), Some(detected), "{}", l.name);
+            assert_eq!(
+                level.missing(l.name).unwrap().next().is_none(),
+                detected,
+                "{}",
+                l.name
+            );
+            // A supported level implies the levels it includes.
+            if detected {
+                for included in included() {
+                    assert_eq!(level.supports(included.name), Some(true), "{}", l.name);
+                }
+            }
+            assert_eq!(level.levels().nth(i), Some(l.name));
+        }
+        if let Some(highest) = level.highest() {
+            assert_eq!(level.supports(highest), Some(true));
+        }
+        if let Some(next) = level.next() {
+            assert_eq!(level.supports(next), Some(false));
+        }
+        // SSE2 is part of the x86_64 baseline, and so is the first level.
+        #[cfg(target_arch = "x86_64")]
+        assert!(level.highest().is_some());
+    }
+}
@@ -1,6 +1,15 @@
 #[macro_export]
 #[allow_internal_unstable(stdsimd)]
 macro_rules! detect_feature {
+    (@mask $feature:tt, $feature_lit:tt) => {
+        $crate::detect_feature!(@mask $feature, $feature_lit : $feature_lit)
+    };
+    (@mask $feature:tt, $feature_lit:tt : $($target_feature_lit:tt),*) => {
+        (
+            $(cfg!(target_feature = $target_feature_lit) ||)* false,
+            $crate::detect::__is_feature_detected::__bit::$feature,
+        )
+    };
     ($feature:tt, $feature_lit:tt) => {
         $crate::detect_feature!($feature, $feature_lit : $feature_lit)
     };
@@ -29,6 +38,25 @@ macro_rules! features {
         #[cfg($cfg)]
         #[doc(cfg($cfg))]
         macro_rules! $macro_name {
+            $(
+                (@mask $feature_lit) => {
+                    $crate::detect_feature!(@mask $feature, $feature_lit $(: $($target_feature_lit),*)?)
+                };
+            )*
+            $(
+                (@mask $bind_feature) => {
+                    {
+                        $(
+                            #[$deprecate_attr] macro_rules! deprecated_feature { {} => {}; }
+                            deprecated_feature! {};
+                        )?
+                        $crate::$macro_name!(@mask $feature_impl)
+                    }
+                };
+            )*
+            (@mask $t:tt) => {
+                $crate::$macro_name!($t)
+            };
             $(
                 ($feature_lit) => {
                     $crate::detect_feature!($feature, $feature_lit $(: $($target_feature_lit),*)?)
@@ -58,6 +86,15 @@ macro_rules! features {
             ($t:tt,) => {
                     $crate::$macro_name!($t);
             };
+            // `$$` escapes the repetitions of this macro from `features!`.
+            ($$first:tt, $$($$rest:tt),+ $$(,)?) => {
+                {
+                    const MASK: $crate::detect::__FeatureMask = $crate::detect::__FeatureMask::EMPTY
+                        .with($crate::$macro_name!(@mask $$first))
+                        $$(.with($crate::$macro_name!(@mask $$rest)))+;
+                    MASK.test()
+                }
+            };
             ($t:tt) => {
                 compile_error!(
                     concat!(
@@ -102,6 +139,12 @@ macro_rules! features {
             ($t:tt,) => {
                     $crate::$macro_name!($t);
             };
+            ($$first:tt, $$($$rest:tt),+ $$(,)?) => {
+                {
+                    $$($crate::$macro_name!($$rest);)+
+                    $crate::$macro_name!($$first)
+                }
+            };
             ($t:tt) => {
                 compile_error!(
                     concat!(
@@ -119,7 +162,7 @@ macro_rules! features {
         #[doc(hidden)]
         #[allow(non_camel_case_types)]
         #[derive(Copy, Clone)]
-        #[repr(u8)]
+        #[repr(u16)]
         #[unstable(feature = "stdsimd_internal", issue = "none")]
         #[cfg($cfg)]
         pub(crate) enum Feature {
@@ -134,13 +177,20 @@ macro_rules! features {
 
         #[cfg($cfg)]
         impl Feature {
+            /// All the features, in declaration order.
+            #[cfg(test)]
+            pub(crate) const ALL: &'static [Feature] = &[$(Feature::$feature),*];
+
             pub(crate) fn to_str(self) -> &'static str {
                 match self {
                     $(Feature::$feature => $feature_lit,)*
                     Feature::_last => unreachable!(),
                 }
             }
-            #[cfg(feature = "std_detect_env_override")]
+            #[cfg(any(
+                feature = "std_detect_env_override",
+                feature = "std_detect_test_override"
+            ))]
             pub(crate) fn from_str(s: &str) -> Result<Feature, ()> {
                 match s {
                     $($feature_lit => Ok(Feature::$feature),)*
@@ -169,6 +219,21 @@ macro_rules! features {
                     $crate::detect::check_for($crate::detect::Feature::$feature)
                 }
             )*
+
+            /// The bit of each feature in the cache, used by the
+            /// multi-feature form of the macro.
+            ///
+            /// PLEASE: do not use this, it is an implementation detail
+            /// subject to change.
+            #[doc(hidden)]
+            #[allow(non_upper_case_globals)]
+            pub mod __bit {
+                $(
+                    #[doc(hidden)]
+                    #[$stability_attr]
+                    pub const $feature: u32 = $crate::detect::Feature::$feature as u32;
+                )*
+            }
         }
     };
 }
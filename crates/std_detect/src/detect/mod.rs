@@ -33,6 +33,39 @@ pub(crate) use self::arch::Feature;
 
 mod bit;
 mod cache;
+mod cpu_info;
+mod dispatch;
+#[cfg(all(
+    test,
+    any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
+))]
+mod dump;
+mod level;
+
+pub use self::cpu_info::{cpu_info, CacheInfo, CacheType, CoreType, CpuInfo};
+pub use self::dispatch::{Dispatch, DispatchFn};
+pub use self::level::{cpu_level, CpuLevel};
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(miri)))]
+mod x86_avx10;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(miri)))]
+pub use self::x86_avx10::{x86_avx10, X86Avx10};
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(miri)))]
+mod x86_topology;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(miri),
+    any(target_os = "linux", target_os = "android"),
+    feature = "libc"
+))]
+pub use self::x86_topology::for_each_x86_cpu_topology;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(miri)))]
+pub use self::x86_topology::{x86_cpu_topology, X86CpuTopology};
+
+#[cfg(feature = "std_detect_test_override")]
+mod overrides;
+#[cfg(feature = "std_detect_test_override")]
+pub use self::overrides::{disable_features, override_features, FeatureOverrideGuard};
 
 cfg_if! {
     if #[cfg(miri)] {
@@ -48,6 +81,9 @@ cfg_if! {
         #[path = "os/x86.rs"]
         mod os;
     } else if #[cfg(all(any(target_os = "linux", target_os = "android"), feature = "libc"))] {
+        #[cfg(target_arch = "aarch64")]
+        #[path = "os/aarch64.rs"]
+        mod aarch64;
         #[path = "os/linux/mod.rs"]
         mod os;
     } else if #[cfg(all(target_os = "freebsd", feature = "libc"))] {
@@ -78,6 +114,38 @@ fn check_for(x: Feature) -> bool {
     cache::test(x as u32)
 }
 
+/// A set of features that the multi-feature form of the
+/// `is_{arch}_feature_detected!` macros tests at once.
+///
+/// PLEASE: do not use this, it is an implementation detail subject to change.
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+#[unstable(feature = "stdsimd_internal", issue = "none")]
+pub struct __FeatureMask(cache::Initializer);
+
+#[unstable(feature = "stdsimd_internal", issue = "none")]
+impl __FeatureMask {
+    /// The empty set.
+    pub const EMPTY: Self = __FeatureMask(cache::Initializer::EMPTY);
+
+    /// Adds the feature `bit` to the set, unless `enabled` is `true` because
+    /// the feature is enabled at compile time.
+    #[inline]
+    pub const fn with(self, (enabled, bit): (bool, u32)) -> Self {
+        if enabled {
+            self
+        } else {
+            __FeatureMask(self.0.with(bit))
+        }
+    }
+
+    /// Returns `true` if all the features of the set are detected.
+    #[inline]
+    pub fn test(self) -> bool {
+        self.0.is_empty() || cache::test_all(self.0)
+    }
+}
+
 /// Returns an `Iterator<Item=(&'static str, bool)>` where
 /// `Item.0` is the feature name, and `Item.1` is a `bool` which
 /// is `true` if the feature is supported by the host and `false` otherwise.
@@ -95,8 +163,10 @@ pub fn features() -> impl Iterator<Item = (&'static str, bool)> {
             target_arch = "powerpc64",
             target_arch = "mips",
             target_arch = "mips64",
+            target_arch = "s390x",
+            target_arch = "loongarch64",
         ))] {
-            (0_u8..Feature::_last as u8).map(|discriminant: u8| {
+            (0_u16..Feature::_last as u16).map(|discriminant: u16| {
                 #[allow(bindings_with_variant_name)] // RISC-V has Feature::f
                 let f: Feature = unsafe { core::mem::transmute(discriminant) };
                 let name: &'static str = f.to_str();
@@ -128,3 +128,136 @@ pub(crate) fn parse_system_registers(
 fn bits_shift(x: u64, high: usize, low: usize) -> u64 {
     (x >> low) & ((1 << (high - low + 1)) - 1)
 }
+
+/// Decodes `(name, value)` pairs of ID registers, where the values are
+/// hexadecimal, with [`parse_system_registers`].
+///
+/// Registers that are missing read as zero, except `ID_AA64PFR0_EL1` which is
+/// then unknown.
+#[cfg(test)]
+pub(crate) fn parse_named_registers<'a>(
+    registers: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> cache::Initializer {
+    let mut aa64isar0 = 0;
+    let mut aa64isar1 = 0;
+    let mut aa64mmfr2 = 0;
+    let mut aa64pfr0 = None;
+    for (name, value) in registers {
+        let value = u64::from_str_radix(value.trim_start_matches("0x"), 16)
+            .unwrap_or_else(|_| panic!("invalid hex number `{value}`"));
+        match name {
+            "ID_AA64ISAR0_EL1" => aa64isar0 = value,
+            "ID_AA64ISAR1_EL1" => aa64isar1 = value,
+            "ID_AA64MMFR2_EL1" => aa64mmfr2 = value,
+            "ID_AA64PFR0_EL1" => aa64pfr0 = Some(value),
+            _ => panic!("unknown ID register `{name}`"),
+        }
+    }
+    parse_system_registers(aa64isar0, aa64isar1, aa64mmfr2, aa64pfr0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ID_AA64PFR0_EL1.FP[19:16] and .AdvSIMD[23:20]: 0b0000 if implemented,
+    // 0b0001 if implemented with half-precision support, and 0b1111 if not
+    // implemented.
+    const PFR0_FP: u64 = 0x0000_0000_0000_0000;
+    const PFR0_FP16: u64 = 0x0000_0000_0011_0000;
+    const PFR0_NO_FP: u64 = 0x0000_0000_00ff_0000;
+
+    fn enabled(value: cache::Initializer, features: &[Feature]) -> bool {
+        features.iter().all(|&f| value.test(f as u32))
+    }
+
+    fn disabled(value: cache::Initializer, features: &[Feature]) -> bool {
+        features.iter().all(|&f| !value.test(f as u32))
+    }
+
+    #[test]
+    fn fp_and_asimd() {
+        let value = parse_system_registers(0, 0, 0, Some(PFR0_FP));
+        assert!(enabled(value, &[Feature::fp, Feature::asimd]));
+        assert!(disabled(value, &[Feature::fp16]));
+
+        let value = parse_system_registers(0, 0, 0, Some(PFR0_FP16));
+        assert!(enabled(
+            value,
+            &[Feature::fp, Feature::fp16, Feature::asimd]
+        ));
+
+        let value = parse_system_registers(0, 0, 0, Some(PFR0_NO_FP));
+        assert!(disabled(value, &[Feature::fp, Feature::asimd]));
+
+        // Half-precision FP without half-precision AdvSIMD.
+        let value = parse_system_registers(0, 0, 0, Some(0x0000_0000_0001_0000));
+        assert!(enabled(value, &[Feature::fp, Feature::fp16]));
+        assert!(disabled(value, &[Feature::asimd]));
+
+        // ID_AA64PFR0_EL1.SVE[35:32].
+        let value = parse_system_registers(0, 0, 0, Some(PFR0_FP16 | 1 << 32));
+        assert!(enabled(value, &[Feature::sve]));
+    }
+
+    #[test]
+    fn isar0() {
+        // ID_AA64ISAR0_EL1.AES[7:4]: 0b0001 for AES, 0b0010 for AES and
+        // PMULL. .SHA1[11:8] and .SHA2[15:12] are both needed for `sha2`.
+        let value = parse_system_registers(0x0000_0000_0000_1110, 0, 0, Some(PFR0_FP16));
+        assert!(enabled(value, &[Feature::aes, Feature::sha2]));
+        assert!(disabled(value, &[Feature::pmull]));
+        let value = parse_system_registers(0x0000_0000_0000_0120, 0, 0, Some(PFR0_FP16));
+        assert!(enabled(value, &[Feature::aes, Feature::pmull]));
+        assert!(disabled(value, &[Feature::sha2]));
+        // They are AdvSIMD instructions.
+        let value = parse_system_registers(0x0000_0000_0000_1110, 0, 0, Some(PFR0_NO_FP));
+        assert!(disabled(value, &[Feature::aes, Feature::sha2]));
+
+        // .CRC32[19:16], .Atomic[23:20] (0b0010 for LSE), .TME[27:24],
+        // .RDM[31:28] and .DP[47:44].
+        let value = parse_system_registers(0x0000_1000_1121_0000, 0, 0, Some(PFR0_FP16));
+        assert!(enabled(
+            value,
+            &[
+                Feature::crc,
+                Feature::lse,
+                Feature::tme,
+                Feature::rdm,
+                Feature::dotprod
+            ]
+        ));
+        let value = parse_system_registers(0x0000_0000_0010_0000, 0, 0, Some(PFR0_FP16));
+        assert!(disabled(value, &[Feature::lse]));
+    }
+
+    #[test]
+    fn isar1_and_mmfr2() {
+        // ID_AA64ISAR1_EL1.APA[7:4] or .API[11:8] for `paca`, .GPA[27:24] or
+        // .GPI[31:28] for `pacg`, and .LRCPC[23:20].
+        let value = parse_system_registers(0, 0x0000_0000_0110_0010, 0, Some(PFR0_FP16));
+        assert!(enabled(
+            value,
+            &[Feature::paca, Feature::pacg, Feature::rcpc]
+        ));
+        let value = parse_system_registers(0, 0x0000_0000_1000_0100, 0, Some(PFR0_FP16));
+        assert!(enabled(value, &[Feature::paca, Feature::pacg]));
+        assert!(disabled(value, &[Feature::rcpc]));
+
+        // ID_AA64MMFR2_EL1.AT[35:32].
+        let value = parse_system_registers(0, 0, 1 << 32, Some(PFR0_FP16));
+        assert!(enabled(value, &[Feature::lse2]));
+    }
+
+    #[test]
+    fn no_pfr0() {
+        // Without ID_AA64PFR0_EL1 the FP and SIMD features are unknown, as on
+        // OpenBSD < 7.3.
+        let value = parse_system_registers(0x0000_0000_0021_1120, 0, 0, None);
+        assert!(disabled(
+            value,
+            &[Feature::fp, Feature::asimd, Feature::aes]
+        ));
+        assert!(enabled(value, &[Feature::crc, Feature::lse]));
+    }
+}
@@ -1,10 +1,16 @@
 //! Run-time feature detection for Aarch64 on Linux.
 
 use super::auxvec;
-use crate::detect::{bit, cache, Feature};
+use crate::detect::{bit, cache, CpuInfo, Feature};
+use core::arch::asm;
 
 /// Try to read the features from the auxiliary vector, and if that fails, try
 /// to read them from /proc/cpuinfo.
+///
+/// If the auxiliary vector reports `HWCAP_CPUID`, the features decoded from
+/// the ID registers are added to the ones reported by the `HWCAP` bits. This
+/// detects the features that the kernel exposes in the ID registers but for
+/// which it has no `HWCAP` bit, like FEAT_TME.
 pub(crate) fn detect_features() -> cache::Initializer {
     #[cfg(target_os = "android")]
     let is_exynos9810 = {
@@ -27,8 +33,7 @@ pub(crate) fn detect_features() -> cache::Initializer {
     let is_exynos9810 = false;
 
     if let Ok(auxv) = auxvec::auxv() {
-        let hwcap: AtHwcap = auxv.into();
-        return hwcap.cache(is_exynos9810);
+        return features_from(auxv, is_exynos9810, super::super::aarch64::detect_features);
     }
     #[cfg(feature = "std_detect_file_io")]
     if let Ok(c) = super::cpuinfo::CpuInfo::new() {
@@ -38,6 +43,137 @@ pub(crate) fn detect_features() -> cache::Initializer {
     cache::Initializer::default()
 }
 
+/// Decodes the features reported by the auxiliary vector, and by the ID
+/// registers, which `id_registers` decodes, if the kernel emulates `mrs`.
+fn features_from(
+    auxv: auxvec::AuxVec,
+    is_exynos9810: bool,
+    id_registers: impl FnOnce() -> cache::Initializer,
+) -> cache::Initializer {
+    let hwcap: AtHwcap = auxv.into();
+    let mut value = hwcap.cache(is_exynos9810);
+    // If HWCAP_CPUID is set the kernel emulates `mrs` for the ID registers
+    // (Linux >= 4.11), and reports the features supported by all the cores.
+    // This is not trusted on Exynos 9810, see above.
+    if bit::test(auxv.hwcap, 11) && !is_exynos9810 {
+        add_features(&mut value, id_registers());
+    }
+    value
+}
+
+/// Sets in `value` all the features set in `other`.
+fn add_features(value: &mut cache::Initializer, other: cache::Initializer) {
+    for bit in 0..Feature::_last as u32 {
+        if other.test(bit) {
+            value.set(bit);
+        }
+    }
+}
+
+/// `prctl` option to query the SVE vector length, from [linux/prctl.h][prctl].
+///
+/// [prctl]: https://github.com/torvalds/linux/blob/master/include/uapi/linux/prctl.h
+const PR_SVE_GET_VL: libc::c_int = 51;
+const PR_SVE_VL_LEN_MASK: libc::c_int = 0xffff;
+
+/// Queries `MIDR_EL1`, the cache hierarchy and the SVE vector length.
+pub(crate) fn cpu_info() -> CpuInfo {
+    let mut info = CpuInfo::default();
+    let auxv = auxvec::auxv().ok();
+
+    // If HWCAP_CPUID is set the kernel emulates `mrs` for the ID registers,
+    // which returns the `MIDR_EL1` of the core executing the instruction.
+    // Otherwise fall back to the values of the first core in /proc/cpuinfo.
+    let midr = match auxv {
+        Some(auxv) if bit::test(auxv.hwcap, 11) => {
+            let midr: u64;
+            unsafe {
+                asm!(
+                    "mrs {}, MIDR_EL1",
+                    out(reg) midr,
+                    options(pure, nomem, preserves_flags, nostack)
+                );
+            }
+            Some(midr)
+        }
+        #[cfg(feature = "std_detect_file_io")]
+        _ => super::cpuinfo::CpuInfo::new()
+            .ok()
+            .and_then(|c| midr_from_cpuinfo(&c)),
+        #[cfg(not(feature = "std_detect_file_io"))]
+        _ => None,
+    };
+    if let Some(midr) = midr {
+        decode_midr(&mut info, midr);
+    }
+
+    // Linux allows EL0 to read CTR_EL0, whose DminLine field is the log2 of
+    // the number of words in the smallest data cache line.
+    let ctr: u64;
+    unsafe {
+        asm!(
+            "mrs {}, CTR_EL0",
+            out(reg) ctr,
+            options(pure, nomem, preserves_flags, nostack)
+        );
+    }
+    info.cache_line_size = Some(4 << ((ctr >> 16) & 0xf));
+
+    if auxv.is_some_and(|auxv| bit::test(auxv.hwcap, 22)) {
+        let vl = unsafe { libc::prctl(PR_SVE_GET_VL) };
+        if vl >= 0 {
+            info.vector_length = Some((vl & PR_SVE_VL_LEN_MASK) as u32);
+        }
+    }
+
+    #[cfg(feature = "std_detect_file_io")]
+    super::sysfs::read_caches(&mut info);
+
+    info
+}
+
+/// Reconstructs `MIDR_EL1` from the `CPU *` fields of /proc/cpuinfo.
+#[cfg(feature = "std_detect_file_io")]
+fn midr_from_cpuinfo(c: &super::cpuinfo::CpuInfo) -> Option<u64> {
+    let hex = |field: &str| {
+        let v = c.field(field).value()?;
+        u64::from_str_radix(v.trim_start_matches("0x"), 16).ok()
+    };
+    let implementer = hex("CPU implementer")?;
+    let variant = hex("CPU variant")?;
+    let part = hex("CPU part")?;
+    let revision = hex("CPU revision")?;
+    // The architecture field is always 0xF on ARMv7 and later, while
+    // /proc/cpuinfo reports the architecture version instead.
+    Some(implementer << 24 | variant << 20 | 0xf << 16 | part << 4 | revision)
+}
+
+/// Fills in the vendor, family, model and stepping from `MIDR_EL1`.
+fn decode_midr(info: &mut CpuInfo, midr: u64) {
+    info.midr = Some(midr);
+    let vendor: &[u8] = match (midr >> 24) & 0xff {
+        0x41 => b"ARM",
+        0x42 => b"Broadcom",
+        0x43 => b"Cavium",
+        0x46 => b"Fujitsu",
+        0x48 => b"HiSilicon",
+        0x4e => b"NVIDIA",
+        0x50 => b"APM",
+        0x51 => b"Qualcomm",
+        0x53 => b"Samsung",
+        0x56 => b"Marvell",
+        0x61 => b"Apple",
+        0x69 => b"Intel",
+        0x6d => b"Microsoft",
+        0xc0 => b"Ampere",
+        _ => b"",
+    };
+    info.set_vendor(vendor);
+    info.family = Some(((midr >> 16) & 0xf) as u32);
+    info.model = Some(((midr >> 4) & 0xfff) as u32);
+    info.stepping = Some((midr & 0xf) as u32);
+}
+
 /// These values are part of the platform-specific [asm/hwcap.h][hwcap] .
 ///
 /// The names match those used for cpuinfo.
@@ -99,6 +235,23 @@ struct AtHwcap {
     rng: bool,
     bti: bool,
     mte: bool,
+    // ecv: Not detected.
+    afp: bool,
+    rpres: bool,
+    // mte3: Not detected.
+    sme: bool,
+    // smei16i64, smef64f64, smei8i32, smef16f32, smeb16f32, smef32f32,
+    // smefa64: See sme feature.
+    wfxt: bool,
+    ebf16: bool,
+    // sveebf16: See ebf16 feature.
+    cssc: bool,
+    rprfm: bool,
+    sve2p1: bool,
+    sme2: bool,
+    // sme2p1, smei16i32, smebi32i32, smeb16b16, smef16f16: See sme2 feature.
+    mops: bool,
+    hbc: bool,
 }
 
 impl From<auxvec::AuxVec> for AtHwcap {
@@ -156,6 +309,22 @@ impl From<auxvec::AuxVec> for AtHwcap {
             rng: bit::test(auxv.hwcap2, 16),
             bti: bit::test(auxv.hwcap2, 17),
             mte: bit::test(auxv.hwcap2, 18),
+            // ecv: bit::test(auxv.hwcap2, 19),
+            afp: bit::test(auxv.hwcap2, 20),
+            rpres: bit::test(auxv.hwcap2, 21),
+            // mte3: bit::test(auxv.hwcap2, 22),
+            sme: bit::test(auxv.hwcap2, 23),
+            // smei16i64 to smefa64: bits 24 to 30.
+            wfxt: bit::test(auxv.hwcap2, 31),
+            ebf16: bit::test(auxv.hwcap2, 32),
+            // sveebf16: bit::test(auxv.hwcap2, 33),
+            cssc: bit::test(auxv.hwcap2, 34),
+            rprfm: bit::test(auxv.hwcap2, 35),
+            sve2p1: bit::test(auxv.hwcap2, 36),
+            sme2: bit::test(auxv.hwcap2, 37),
+            // sme2p1 to smef16f16: bits 38 to 42.
+            mops: bit::test(auxv.hwcap2, 43),
+            hbc: bit::test(auxv.hwcap2, 44),
         }
     }
 }
@@ -220,6 +389,20 @@ impl From<super::cpuinfo::CpuInfo> for AtHwcap {
             rng: f.has("rng"),
             bti: f.has("bti"),
             mte: f.has("mte"),
+            // ecv: f.has("ecv"),
+            afp: f.has("afp"),
+            rpres: f.has("rpres"),
+            // mte3: f.has("mte3"),
+            sme: f.has("sme"),
+            wfxt: f.has("wfxt"),
+            ebf16: f.has("ebf16"),
+            // sveebf16: f.has("sveebf16"),
+            cssc: f.has("cssc"),
+            rprfm: f.has("rprfm"),
+            sve2p1: f.has("sve2p1"),
+            sme2: f.has("sme2"),
+            mops: f.has("mops"),
+            hbc: f.has("hbc"),
         }
     }
 }
@@ -327,6 +510,24 @@ impl AtHwcap {
                 self.svesha3 && sve2 && self.sha512 && self.sha3 && self.sha1 && self.sha2,
             );
             enable_feature(Feature::sve2_bitperm, self.svebitperm && self.sve2);
+            // SVE2.1 requires SVE2
+            enable_feature(Feature::sve2p1, self.sve2p1 && sve2);
+
+            // SME requires BF16, and SME2 requires SME
+            let sme = self.sme && self.bf16;
+            enable_feature(Feature::sme, sme);
+            enable_feature(Feature::sme2, self.sme2 && sme);
+
+            // EBF16 requires BF16
+            enable_feature(Feature::ebf16, self.ebf16 && self.bf16);
+            // AFP and RPRES change the behavior of FP and ASIMD instructions
+            enable_feature(Feature::afp, self.afp && self.fp);
+            enable_feature(Feature::rpres, self.rpres && asimd);
+            enable_feature(Feature::wfxt, self.wfxt);
+            enable_feature(Feature::cssc, self.cssc);
+            enable_feature(Feature::rprfm, self.rprfm);
+            enable_feature(Feature::mops, self.mops);
+            enable_feature(Feature::hbc, self.hbc);
         }
         value
     }
@@ -335,6 +536,187 @@ impl AtHwcap {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::detect::dump;
+    use std::vec::Vec;
+
+    /// Checks that the features detected from the `hwcap`, `hwcap2` and
+    /// `id_registers` of a dump of `std_detect_dump` are the ones that it
+    /// reported.
+    fn check_dump(name: &str, json: &str) {
+        let hex = |name| {
+            let value = dump::string(json, name).unwrap_or_else(|| panic!("no `{name}`"));
+            usize::from_str_radix(value.trim_start_matches("0x"), 16).unwrap()
+        };
+        let auxv = auxvec::AuxVec {
+            hwcap: hex("hwcap"),
+            hwcap2: hex("hwcap2"),
+        };
+        let value = features_from(auxv, false, || {
+            crate::detect::aarch64::parse_named_registers(dump::object(json, "id_registers"))
+        });
+        let mut detected: Vec<&str> = Feature::ALL
+            .iter()
+            .filter(|&&f| value.test(f as u32))
+            .map(|f| f.to_str())
+            .collect();
+        let mut recorded = dump::features(json);
+        detected.sort_unstable();
+        recorded.sort_unstable();
+        assert_eq!(
+            detected, recorded,
+            "features detected from the dump of {name}"
+        );
+    }
+
+    #[test]
+    fn host_dump() {
+        if let Some(json) = dump::host() {
+            check_dump("the host", &json);
+        }
+    }
+
+    #[test]
+    fn midr() {
+        // Cortex-A76 r3p1
+        let mut info = CpuInfo::default();
+        decode_midr(&mut info, 0x413f_d0b1);
+        assert_eq!(info.vendor(), Some("ARM"));
+        assert_eq!(info.family(), Some(0xf));
+        assert_eq!(info.model(), Some(0xd0b));
+        assert_eq!(info.stepping(), Some(1));
+        assert_eq!(info.midr(), Some(0x413f_d0b1));
+    }
+
+    #[cfg(feature = "std_detect_file_io")]
+    #[test]
+    fn midr_from_cpuinfo_fields() {
+        let c = super::super::cpuinfo::CpuInfo::from_str(
+            "processor\t: 0
+BogoMIPS\t: 50.00
+Features\t: fp asimd evtstrm aes pmull sha1 sha2 crc32 atomics fphp asimdhp cpuid
+CPU implementer\t: 0x41
+CPU architecture: 8
+CPU variant\t: 0x3
+CPU part\t: 0xd0b
+CPU revision\t: 1
+",
+        )
+        .unwrap();
+        assert_eq!(midr_from_cpuinfo(&c), Some(0x413f_d0b1));
+    }
+
+    #[test]
+    fn id_registers() {
+        // HWCAP_FP, HWCAP_ASIMD and HWCAP_CPUID: LSE, DotProd and FEAT_TME,
+        // which has no HWCAP bit, are read from ID_AA64ISAR0_EL1.Atomic[23:20],
+        // .DP[47:44] and .TME[27:24].
+        let auxv = auxvec::AuxVec {
+            hwcap: 1 << 0 | 1 << 1 | 1 << 11,
+            hwcap2: 0,
+        };
+        let aa64isar0 = 0x0000_1000_0120_0000;
+        let id_registers =
+            || crate::detect::aarch64::parse_system_registers(aa64isar0, 0, 0, Some(0x0011_0000));
+        let value = features_from(auxv, false, id_registers);
+        for f in [
+            Feature::fp,
+            Feature::asimd,
+            Feature::lse,
+            Feature::dotprod,
+            Feature::tme,
+        ] {
+            assert!(value.test(f as u32), "{}", f.to_str());
+        }
+
+        // The ID registers are only read if the kernel emulates `mrs`, and
+        // are not trusted on Exynos 9810.
+        let hwcap = auxvec::AuxVec {
+            hwcap: 1 << 0 | 1 << 1,
+            hwcap2: 0,
+        };
+        for (auxv, is_exynos9810) in [(hwcap, false), (auxv, true)] {
+            let value = features_from(auxv, is_exynos9810, id_registers);
+            assert!(!value.test(Feature::lse as u32));
+            assert!(!value.test(Feature::tme as u32));
+        }
+    }
+
+    /// The /proc/cpuinfo in `test_data/aarch64/{name}.cpuinfo`.
+    #[cfg(feature = "std_detect_file_io")]
+    macro_rules! cpuinfo {
+        ($name:literal) => {
+            AtHwcap::from(
+                super::super::cpuinfo::CpuInfo::from_str(include_str!(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/src/detect/test_data/aarch64/",
+                    $name,
+                    ".cpuinfo"
+                )))
+                .unwrap(),
+            )
+            .cache(false)
+        };
+    }
+
+    /// The features of Neoverse N1, which Neoverse V1 also supports.
+    #[cfg(feature = "std_detect_file_io")]
+    const NEOVERSE_N1: &[Feature] = &[
+        Feature::fp,
+        Feature::fp16,
+        Feature::asimd,
+        Feature::aes,
+        Feature::pmull,
+        Feature::sha2,
+        Feature::crc,
+        Feature::lse,
+        Feature::rdm,
+        Feature::rcpc,
+        Feature::dpb,
+        Feature::dotprod,
+        Feature::ssbs,
+    ];
+
+    #[cfg(feature = "std_detect_file_io")]
+    #[test]
+    fn neoverse_n1() {
+        let value = cpuinfo!("neoverse-n1");
+        for &f in NEOVERSE_N1 {
+            assert!(value.test(f as u32), "{}", f.to_str());
+        }
+        for f in [Feature::sve, Feature::lse2, Feature::paca, Feature::rcpc2] {
+            assert!(!value.test(f as u32), "{}", f.to_str());
+        }
+    }
+
+    #[cfg(feature = "std_detect_file_io")]
+    #[test]
+    fn neoverse_v1() {
+        let value = cpuinfo!("neoverse-v1");
+        let v1 = [
+            Feature::sve,
+            Feature::fhm,
+            Feature::jsconv,
+            Feature::fcma,
+            Feature::sha3,
+            Feature::sm4,
+            Feature::lse2,
+            Feature::rcpc2,
+            Feature::dit,
+            Feature::flagm,
+            Feature::paca,
+            Feature::pacg,
+            Feature::dpb2,
+            Feature::i8mm,
+            Feature::bf16,
+            Feature::rand,
+        ];
+        for &f in NEOVERSE_N1.iter().chain(&v1) {
+            assert!(value.test(f as u32), "{}", f.to_str());
+        }
+        for f in [Feature::sve2, Feature::bti, Feature::mte, Feature::sb] {
+            assert!(!value.test(f as u32), "{}", f.to_str());
+        }
+    }
 
     #[cfg(feature = "std_detect_file_io")]
     mod auxv_from_file {
@@ -412,5 +794,82 @@ mod tests {
                 }
             );
         }
+
+        #[test]
+        fn linux_hwcap2_sme2_aarch64() {
+            let file = concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/src/detect/test_data/linux-hwcap2-sme2-aarch64.auxv"
+            );
+            println!("file: {file}");
+            let v = auxv_from_file(file).unwrap();
+            println!("HWCAP : 0x{:0x}", v.hwcap);
+            println!("HWCAP2: 0x{:0x}", v.hwcap2);
+            let hwcap = AtHwcap::from(v);
+            assert_eq!(
+                hwcap,
+                AtHwcap {
+                    sve: true,
+                    sve2: true,
+                    i8mm: true,
+                    bf16: true,
+                    // HWCAP2 bits 20 and above.
+                    afp: true,
+                    rpres: true,
+                    sme: true,
+                    wfxt: true,
+                    ebf16: true,
+                    cssc: true,
+                    rprfm: true,
+                    sve2p1: true,
+                    sme2: true,
+                    mops: true,
+                    hbc: true,
+                    ..baseline_hwcaps()
+                }
+            );
+
+            // The same features, as listed in /proc/cpuinfo.
+            let c = super::super::super::cpuinfo::CpuInfo::from_str(
+                "processor\t: 0
+BogoMIPS\t: 2000.00
+Features\t: fp asimd evtstrm aes pmull sha1 sha2 crc32 atomics fphp asimdhp cpuid asimdrdm lrcpc dcpop asimddp sve ssbs sve2 i8mm bf16 afp rpres sme wfxt ebf16 cssc rprfm sve2p1 sme2 mops hbc
+CPU implementer\t: 0x41
+",
+            )
+            .unwrap();
+            assert_eq!(AtHwcap::from(c), hwcap);
+
+            let value = hwcap.cache(false);
+            for f in [
+                Feature::sve2p1,
+                Feature::sme,
+                Feature::sme2,
+                Feature::mops,
+                Feature::hbc,
+                Feature::cssc,
+                Feature::rprfm,
+                Feature::wfxt,
+                Feature::ebf16,
+                Feature::rpres,
+                Feature::afp,
+            ] {
+                assert!(value.test(f as u32), "{}", f.to_str());
+            }
+        }
+
+        #[test]
+        fn sme_requires_bf16() {
+            let hwcap = AtHwcap {
+                sme: true,
+                sme2: true,
+                ebf16: true,
+                ..baseline_hwcaps()
+            };
+            let value = hwcap.cache(false);
+            assert!(!value.test(Feature::sme as u32));
+            assert!(!value.test(Feature::sme2 as u32));
+            assert!(!value.test(Feature::ebf16 as u32));
+        }
     }
 }
@@ -82,7 +82,9 @@ pub(crate) fn auxv() -> Result<AuxVec, ()> {
                 target_arch = "riscv32",
                 target_arch = "riscv64",
                 target_arch = "mips",
-                target_arch = "mips64"
+                target_arch = "mips64",
+                target_arch = "s390x",
+                target_arch = "loongarch64"
             ))]
             {
                 // Zero could indicate that no features were detected, but it's also used to
@@ -128,7 +130,9 @@ pub(crate) fn auxv() -> Result<AuxVec, ()> {
             target_arch = "riscv32",
             target_arch = "riscv64",
             target_arch = "mips",
-            target_arch = "mips64"
+            target_arch = "mips64",
+            target_arch = "s390x",
+            target_arch = "loongarch64"
         ))]
         {
             let hwcap = unsafe { libc::getauxval(AT_HWCAP as libc::c_ulong) as usize };
@@ -221,6 +225,8 @@ fn auxv_from_buf(buf: &[usize]) -> Result<AuxVec, ()> {
         target_arch = "riscv64",
         target_arch = "mips",
         target_arch = "mips64",
+        target_arch = "s390x",
+        target_arch = "loongarch64",
     ))]
     {
         for el in buf.chunks(2) {
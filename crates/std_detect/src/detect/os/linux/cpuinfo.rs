@@ -33,7 +33,7 @@ impl CpuInfo {
     }
 
     #[cfg(test)]
-    fn from_str(other: &str) -> Result<Self, ()> {
+    pub(crate) fn from_str(other: &str) -> Result<Self, ()> {
         Ok(Self {
             raw: String::from(other),
         })
@@ -60,6 +60,10 @@ impl<'a> CpuInfoField<'a> {
             Some(f) => CpuInfoField::<'b>(Some(f.trim())),
         }
     }
+    /// Returns the trimmed value of the field, if it exists.
+    pub(crate) fn value(&self) -> Option<&'a str> {
+        self.0
+    }
     /// Does the field exist?
     #[cfg(test)]
     pub(crate) fn exists(&self) -> bool {
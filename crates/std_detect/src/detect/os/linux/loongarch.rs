@@ -0,0 +1,92 @@
+//! Run-time feature detection for LoongArch on Linux.
+
+use super::auxvec;
+use crate::detect::{bit, cache, Feature};
+
+/// Try to read the features from the auxiliary vector.
+pub(crate) fn detect_features() -> cache::Initializer {
+    if let Ok(auxv) = auxvec::auxv() {
+        return features_from_auxv(auxv);
+    }
+    // Every LoongArch kernel provides `AT_HWCAP`.
+    cache::Initializer::default()
+}
+
+/// Decodes the `AT_HWCAP` bits.
+fn features_from_auxv(auxv: auxvec::AuxVec) -> cache::Initializer {
+    let mut value = cache::Initializer::default();
+    let enable_feature = |value: &mut cache::Initializer, feature, enable| {
+        if enable {
+            value.set(feature as u32);
+        }
+    };
+
+    // The values are part of the platform-specific [asm/hwcap.h][hwcap]
+    //
+    // [hwcap]: https://github.com/torvalds/linux/blob/master/arch/loongarch/include/uapi/asm/hwcap.h
+    //
+    // HWCAP_LOONGARCH_FPU is only set for processors with both single and
+    // double-precision floating-point, which the lp64d ABI requires.
+    let fpu = bit::test(auxv.hwcap, 3); // HWCAP_LOONGARCH_FPU
+
+    // The vector registers extend the floating-point registers.
+    let lsx = fpu && bit::test(auxv.hwcap, 4); // HWCAP_LOONGARCH_LSX
+    let lasx = lsx && bit::test(auxv.hwcap, 5); // HWCAP_LOONGARCH_LASX
+
+    enable_feature(&mut value, Feature::f, fpu);
+    enable_feature(&mut value, Feature::d, fpu);
+    enable_feature(&mut value, Feature::lsx, lsx);
+    enable_feature(&mut value, Feature::lasx, lasx);
+    enable_feature(&mut value, Feature::ual, bit::test(auxv.hwcap, 2)); // HWCAP_LOONGARCH_UAL
+    enable_feature(&mut value, Feature::lvz, bit::test(auxv.hwcap, 9)); // HWCAP_LOONGARCH_LVZ
+
+    // HWCAP_LOONGARCH_LBT_{X86,ARM,MIPS}: the scratch registers and flags of
+    // LBT are shared by the three emulated architectures.
+    enable_feature(&mut value, Feature::lbt, auxv.hwcap & (0b111 << 10) != 0);
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn features(hwcap: usize) -> [bool; 7] {
+        let value = features_from_auxv(auxvec::AuxVec { hwcap });
+        [
+            Feature::f,
+            Feature::d,
+            Feature::lsx,
+            Feature::lasx,
+            Feature::lbt,
+            Feature::lvz,
+            Feature::ual,
+        ]
+        .map(|feature| value.test(feature as u32))
+    }
+
+    #[test]
+    fn loongson_3a5000() {
+        // cpucfg, lam, ual, fpu, lsx, lasx, crc32, complex, crypto, lvz,
+        // lbt_x86, lbt_arm and lbt_mips.
+        assert_eq!(features(0x1fff), [true; 7]);
+    }
+
+    #[test]
+    fn lsx_only() {
+        // cpucfg, lam, ual, fpu, lsx, crc32, complex and crypto, without the
+        // LASX, LVZ and LBT extensions.
+        assert_eq!(
+            features(0x1df),
+            [true, true, true, false, false, false, true]
+        );
+    }
+
+    #[test]
+    fn no_fpu() {
+        // The vector extensions are unusable without the FPU.
+        assert_eq!(
+            features(0x1fff & !(1 << 3)),
+            [false, false, false, false, true, true, true]
+        );
+    }
+}
@@ -6,6 +6,15 @@ use crate::detect::{bit, cache, Feature};
 /// Try to read the features from the auxiliary vector, and if that fails, try
 /// to read them from `/proc/cpuinfo`.
 pub(crate) fn detect_features() -> cache::Initializer {
+    if let Ok(auxv) = auxvec::auxv() {
+        return features_from_auxv(auxv);
+    }
+    // TODO: fall back via `cpuinfo`.
+    cache::Initializer::default()
+}
+
+/// Decodes the `AT_HWCAP` bits.
+fn features_from_auxv(auxv: auxvec::AuxVec) -> cache::Initializer {
     let mut value = cache::Initializer::default();
     let enable_feature = |value: &mut cache::Initializer, f, enable| {
         if enable {
@@ -15,11 +24,42 @@ pub(crate) fn detect_features() -> cache::Initializer {
 
     // The values are part of the platform-specific [asm/hwcap.h][hwcap]
     //
-    // [hwcap]: https://github.com/torvalds/linux/blob/master/arch/arm64/include/uapi/asm/hwcap.h
-    if let Ok(auxv) = auxvec::auxv() {
-        enable_feature(&mut value, Feature::msa, bit::test(auxv.hwcap, 1));
-        return value;
-    }
-    // TODO: fall back via `cpuinfo`.
+    // [hwcap]: https://github.com/torvalds/linux/blob/master/arch/mips/include/uapi/asm/hwcap.h
+    enable_feature(&mut value, Feature::msa, bit::test(auxv.hwcap, 1));
+    let dsp = bit::test(auxv.hwcap, 7);
+    enable_feature(&mut value, Feature::dsp, dsp);
+    // DSPr2 and DSPr3 are revisions of the DSP ASE, and extend it.
+    enable_feature(&mut value, Feature::dspr2, dsp && bit::test(auxv.hwcap, 8));
+    enable_feature(&mut value, Feature::dspr3, dsp && bit::test(auxv.hwcap, 9));
     value
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(hwcap: usize, expected: &[Feature]) {
+        let value = features_from_auxv(auxvec::AuxVec { hwcap });
+        for f in [Feature::msa, Feature::dsp, Feature::dspr2, Feature::dspr3] {
+            assert_eq!(
+                value.test(f as u32),
+                expected.iter().any(|&e| e as u32 == f as u32),
+                "{} with AT_HWCAP = {hwcap:#x}",
+                f.to_str()
+            );
+        }
+    }
+
+    #[test]
+    fn hwcap() {
+        check(0, &[]);
+        // A 74Kc: DSP and DSPr2.
+        check(0x180, &[Feature::dsp, Feature::dspr2]);
+        // An I6400: R6 and MSA.
+        check(0x3, &[Feature::msa]);
+        // Everything but MSA, including unknown bits.
+        check(!0x2, &[Feature::dsp, Feature::dspr2, Feature::dspr3]);
+        // DSPr2 and DSPr3 without DSP.
+        check(0x300, &[]);
+    }
+}
@@ -8,6 +8,9 @@ mod auxvec;
 #[cfg(feature = "std_detect_file_io")]
 mod cpuinfo;
 
+#[cfg(feature = "std_detect_file_io")]
+mod sysfs;
+
 #[cfg(feature = "std_detect_file_io")]
 fn read_file(path: &str) -> Result<Vec<u8>, ()> {
     let mut path = Vec::from(path.as_bytes());
@@ -41,19 +44,25 @@ fn read_file(path: &str) -> Result<Vec<u8>, ()> {
 cfg_if::cfg_if! {
     if #[cfg(target_arch = "aarch64")] {
         mod aarch64;
-        pub(crate) use self::aarch64::detect_features;
+        pub(crate) use self::aarch64::{cpu_info, detect_features};
     } else if #[cfg(target_arch = "arm")] {
         mod arm;
         pub(crate) use self::arm::detect_features;
     } else if #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))] {
         mod riscv;
-        pub(crate) use self::riscv::detect_features;
+        pub(crate) use self::riscv::{cpu_info, detect_features};
     } else if #[cfg(any(target_arch = "mips", target_arch = "mips64"))] {
         mod mips;
         pub(crate) use self::mips::detect_features;
     } else if #[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))] {
         mod powerpc;
         pub(crate) use self::powerpc::detect_features;
+    } else if #[cfg(target_arch = "s390x")] {
+        mod s390x;
+        pub(crate) use self::s390x::detect_features;
+    } else if #[cfg(target_arch = "loongarch64")] {
+        mod loongarch;
+        pub(crate) use self::loongarch::detect_features;
     } else {
         use crate::detect::cache;
         /// Performs run-time feature detection.
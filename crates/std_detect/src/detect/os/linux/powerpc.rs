@@ -6,6 +6,26 @@ use crate::detect::{cache, Feature};
 /// Try to read the features from the auxiliary vector, and if that fails, try
 /// to read them from /proc/cpuinfo.
 pub(crate) fn detect_features() -> cache::Initializer {
+    if let Ok(auxv) = auxvec::auxv() {
+        return features_from_auxv(auxv);
+    }
+
+    let mut value = cache::Initializer::default();
+
+    // PowerPC's /proc/cpuinfo lacks a proper Feature field,
+    // but `altivec` support is indicated in the `cpu` field.
+    #[cfg(feature = "std_detect_file_io")]
+    if let Ok(c) = super::cpuinfo::CpuInfo::new() {
+        if c.field("cpu").has("altivec") {
+            value.set(Feature::altivec as u32);
+        }
+        return value;
+    }
+    value
+}
+
+/// Decodes the `AT_HWCAP` and `AT_HWCAP2` bits.
+fn features_from_auxv(auxv: auxvec::AuxVec) -> cache::Initializer {
     let mut value = cache::Initializer::default();
     let enable_feature = |value: &mut cache::Initializer, f, enable| {
         if enable {
@@ -16,21 +36,201 @@ pub(crate) fn detect_features() -> cache::Initializer {
     // The values are part of the platform-specific [asm/cputable.h][cputable]
     //
     // [cputable]: https://github.com/torvalds/linux/blob/master/arch/powerpc/include/uapi/asm/cputable.h
-    if let Ok(auxv) = auxvec::auxv() {
-        // note: the PowerPC values are the mask to do the test (instead of the
-        // index of the bit to test like in ARM and Aarch64)
-        enable_feature(&mut value, Feature::altivec, auxv.hwcap & 0x10000000 != 0);
-        enable_feature(&mut value, Feature::vsx, auxv.hwcap & 0x00000080 != 0);
-        enable_feature(&mut value, Feature::power8, auxv.hwcap2 & 0x80000000 != 0);
-        return value;
+    //
+    // note: the PowerPC values are the mask to do the test (instead of the
+    // index of the bit to test like in ARM and Aarch64)
+    let altivec = auxv.hwcap & 0x10000000 != 0; // PPC_FEATURE_HAS_ALTIVEC
+    let vsx = auxv.hwcap & 0x00000080 != 0; // PPC_FEATURE_HAS_VSX
+    let arch_2_07 = auxv.hwcap2 & 0x80000000 != 0; // PPC_FEATURE2_ARCH_2_07
+    let arch_3_00 = auxv.hwcap2 & 0x00800000 != 0; // PPC_FEATURE2_ARCH_3_00
+    let arch_3_1 = auxv.hwcap2 & 0x00040000 != 0; // PPC_FEATURE2_ARCH_3_1
+
+    enable_feature(&mut value, Feature::altivec, altivec);
+    enable_feature(&mut value, Feature::vsx, vsx);
+    enable_feature(&mut value, Feature::power8, arch_2_07);
+    let power8_altivec = altivec && arch_2_07;
+    enable_feature(&mut value, Feature::power8_altivec, power8_altivec);
+    enable_feature(
+        &mut value,
+        Feature::power9_altivec,
+        power8_altivec && arch_3_00,
+    );
+    // The vector extensions of each ISA version require VSX.
+    enable_feature(&mut value, Feature::power8_vector, vsx && power8_altivec);
+    let power9_vector = vsx && arch_3_00;
+    let power10_vector = power9_vector && arch_3_1;
+    enable_feature(&mut value, Feature::power9_vector, power9_vector);
+    enable_feature(&mut value, Feature::power10_vector, power10_vector);
+    // PPC_FEATURE2_MMA: MMA operates on the VSX registers.
+    enable_feature(
+        &mut value,
+        Feature::mma,
+        power10_vector && auxv.hwcap2 & 0x00020000 != 0,
+    );
+    // PPC_FEATURE2_VEC_CRYPTO: vector instructions.
+    let crypto = altivec && auxv.hwcap2 & 0x02000000 != 0;
+    enable_feature(&mut value, Feature::crypto, crypto);
+    enable_feature(&mut value, Feature::power8_crypto, crypto && power8_altivec);
+    // PPC_FEATURE2_HTM: the kernel clears it when transactions are disabled.
+    enable_feature(&mut value, Feature::htm, auxv.hwcap2 & 0x40000000 != 0);
+    // PPC_FEATURE2_DARN
+    enable_feature(&mut value, Feature::darn, auxv.hwcap2 & 0x00200000 != 0);
+    // PPC_FEATURE2_SCV
+    enable_feature(&mut value, Feature::scv, auxv.hwcap2 & 0x00100000 != 0);
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `AT_HWCAP` of a little-endian POWER8, POWER9 or POWER10: 32 and 64-bit,
+    /// altivec, FPU, MMU, SMT, icache snoop, DFP, ISA 2.06, VSX, perfmon
+    /// compat and true little-endian.
+    const HWCAP: usize = 0xdc0065c2;
+
+    fn check(hwcap2: usize, expected: &[Feature]) {
+        let value = features_from_auxv(auxvec::AuxVec {
+            hwcap: HWCAP,
+            hwcap2,
+        });
+        for f in [
+            Feature::power8,
+            Feature::power8_altivec,
+            Feature::power8_vector,
+            Feature::power8_crypto,
+            Feature::power9_altivec,
+            Feature::power9_vector,
+            Feature::power10_vector,
+            Feature::mma,
+            Feature::crypto,
+            Feature::htm,
+            Feature::darn,
+            Feature::scv,
+        ] {
+            assert_eq!(
+                value.test(f as u32),
+                expected.iter().any(|&e| e as u32 == f as u32),
+                "{} with AT_HWCAP2 = {hwcap2:#x}",
+                f.to_str()
+            );
+        }
+        assert!(value.test(Feature::altivec as u32));
+        assert!(value.test(Feature::vsx as u32));
     }
 
-    // PowerPC's /proc/cpuinfo lacks a proper Feature field,
-    // but `altivec` support is indicated in the `cpu` field.
-    #[cfg(feature = "std_detect_file_io")]
-    if let Ok(c) = super::cpuinfo::CpuInfo::new() {
-        enable_feature(&mut value, Feature::altivec, c.field("cpu").has("altivec"));
-        return value;
+    #[test]
+    fn power8() {
+        // ISA 2.07, HTM, DSCR, EBB, isel, TAR and vector crypto.
+        check(
+            0xfe000000,
+            &[
+                Feature::power8,
+                Feature::power8_altivec,
+                Feature::power8_vector,
+                Feature::power8_crypto,
+                Feature::crypto,
+                Feature::htm,
+            ],
+        );
+    }
+
+    #[test]
+    fn power9() {
+        // POWER8 without HTM, plus ISA 3.00, IEEE128, darn and scv.
+        check(
+            0xbef00000,
+            &[
+                Feature::power8,
+                Feature::power8_altivec,
+                Feature::power8_vector,
+                Feature::power8_crypto,
+                Feature::crypto,
+                Feature::power9_altivec,
+                Feature::power9_vector,
+                Feature::darn,
+                Feature::scv,
+            ],
+        );
+    }
+
+    #[test]
+    fn power10() {
+        // POWER9, plus ISA 3.1 and MMA.
+        check(
+            0xbef60000,
+            &[
+                Feature::power8,
+                Feature::power8_altivec,
+                Feature::power8_vector,
+                Feature::power8_crypto,
+                Feature::crypto,
+                Feature::power9_altivec,
+                Feature::power9_vector,
+                Feature::darn,
+                Feature::scv,
+                Feature::power10_vector,
+                Feature::mma,
+            ],
+        );
+    }
+
+    #[test]
+    fn no_vsx() {
+        // ISA 3.1 without VSX: the vector extensions and MMA are unusable.
+        let value = features_from_auxv(auxvec::AuxVec {
+            hwcap: HWCAP & !0x00000080,
+            hwcap2: 0xbef60000,
+        });
+        assert!(value.test(Feature::altivec as u32));
+        assert!(!value.test(Feature::vsx as u32));
+        assert!(value.test(Feature::power9_altivec as u32));
+        assert!(!value.test(Feature::power8_vector as u32));
+        assert!(!value.test(Feature::power9_vector as u32));
+        assert!(!value.test(Feature::power10_vector as u32));
+        assert!(!value.test(Feature::mma as u32));
+        assert!(value.test(Feature::darn as u32));
+    }
+
+    // The auxv dumps are little-endian and 64-bit.
+    #[cfg(all(
+        feature = "std_detect_file_io",
+        target_arch = "powerpc64",
+        target_endian = "little"
+    ))]
+    mod auxv_from_file {
+        use super::super::auxvec::auxv_from_file;
+        use super::*;
+
+        #[test]
+        fn linux_power8_powerpc64le() {
+            let file = concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/src/detect/test_data/linux-power8-powerpc64le.auxv"
+            );
+            println!("file: {file}");
+            let v = auxv_from_file(file).unwrap();
+            assert_eq!(v.hwcap, HWCAP);
+            assert_eq!(v.hwcap2, 0xfe000000);
+            let value = features_from_auxv(v);
+            assert!(value.test(Feature::htm as u32));
+            assert!(!value.test(Feature::power9_vector as u32));
+        }
+
+        #[test]
+        fn linux_power10_powerpc64le() {
+            let file = concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/src/detect/test_data/linux-power10-powerpc64le.auxv"
+            );
+            println!("file: {file}");
+            let v = auxv_from_file(file).unwrap();
+            assert_eq!(v.hwcap, HWCAP);
+            assert_eq!(v.hwcap2, 0xbef60000);
+            let value = features_from_auxv(v);
+            assert!(value.test(Feature::power10_vector as u32));
+            assert!(value.test(Feature::mma as u32));
+            assert!(!value.test(Feature::htm as u32));
+        }
     }
-    value
 }
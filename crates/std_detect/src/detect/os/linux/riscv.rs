@@ -1,7 +1,52 @@
 //! Run-time feature detection for RISC-V on Linux.
 
 use super::auxvec;
-use crate::detect::{bit, cache, Feature};
+use crate::detect::{bit, cache, CacheType, CpuInfo, Feature};
+use core::arch::asm;
+
+// The values are part of the platform-specific [asm/hwprobe.h][hwprobe]
+//
+// [hwprobe]: https://github.com/torvalds/linux/blob/master/arch/riscv/include/uapi/asm/hwprobe.h
+const SYS_RISCV_HWPROBE: libc::c_long = 258;
+const RISCV_HWPROBE_KEY_IMA_EXT_0: i64 = 4;
+const RISCV_HWPROBE_EXT_ZICBOZ: u64 = 1 << 6;
+const RISCV_HWPROBE_EXT_ZAWRS: u64 = 1 << 48;
+const RISCV_HWPROBE_EXT_ZICBOM: u64 = 1 << 55;
+
+#[allow(non_camel_case_types)]
+#[repr(C)]
+struct riscv_hwprobe {
+    key: i64,
+    value: u64,
+}
+
+/// Queries the `RISCV_HWPROBE_KEY_IMA_EXT_0` key for the extensions
+/// supported by all online harts.
+///
+/// Returns `None` if the kernel does not support `riscv_hwprobe`, or if it
+/// does not know the key.
+fn hwprobe_ima_ext_0() -> Option<u64> {
+    let mut pair = riscv_hwprobe {
+        key: RISCV_HWPROBE_KEY_IMA_EXT_0,
+        value: 0,
+    };
+    // An empty CPU set means "all online CPUs".
+    let ret = unsafe {
+        libc::syscall(
+            SYS_RISCV_HWPROBE,
+            &mut pair as *mut riscv_hwprobe,
+            1_usize,
+            0_usize,
+            core::ptr::null_mut::<libc::c_ulong>(),
+            0_u32,
+        )
+    };
+    // The kernel sets the key to -1 if it is not recognized.
+    if ret != 0 || pair.key == -1 {
+        return None;
+    }
+    Some(pair.value)
+}
 
 /// Read list of supported features from the auxiliary vector.
 pub(crate) fn detect_features() -> cache::Initializer {
@@ -65,9 +110,89 @@ pub(crate) fn detect_features() -> cache::Initializer {
         Feature::m,
         bit::test(auxv.hwcap, (b'm' - b'a').into()),
     );
+
+    // Multi-letter extensions are not reported in the auxiliary vector, but
+    // Linux 6.4 and newer can report some of them through `riscv_hwprobe`.
+    if let Some(ext) = hwprobe_ima_ext_0() {
+        enable_feature(
+            &mut value,
+            Feature::zicboz,
+            ext & RISCV_HWPROBE_EXT_ZICBOZ != 0,
+        );
+        enable_feature(
+            &mut value,
+            Feature::zawrs,
+            ext & RISCV_HWPROBE_EXT_ZAWRS != 0,
+        );
+        enable_feature(
+            &mut value,
+            Feature::zicbom,
+            ext & RISCV_HWPROBE_EXT_ZICBOM != 0,
+        );
+    }
+    // `riscv_hwprobe` doesn't report Zicbop, but the `isa` field of
+    // /proc/cpuinfo lists it when all harts implement it.
+    #[cfg(feature = "std_detect_file_io")]
+    if let Ok(c) = super::cpuinfo::CpuInfo::new() {
+        if let Some(isa) = c.field("isa").value() {
+            enable_feature(&mut value, Feature::zicbop, isa_has(isa, "zicbop"));
+        }
+    }
+
     // FIXME: Auxvec does not show supervisor feature support, but this mode may be useful
     // to detect when Rust is used to write Linux kernel modules.
     // These should be more than Auxvec way to detect supervisor features.
 
     value
 }
+
+/// Returns whether the ISA string `isa`, e.g. `rv64imafdc_zicbom_zicbop`, lists
+/// the multi-letter extension `ext`.
+#[cfg(feature = "std_detect_file_io")]
+fn isa_has(isa: &str, ext: &str) -> bool {
+    isa.split('_').skip(1).any(|e| e == ext)
+}
+
+/// Queries the cache hierarchy and the vector register length.
+pub(crate) fn cpu_info() -> CpuInfo {
+    let mut info = CpuInfo::default();
+
+    // Reading `vlenb` traps if the "V" extension is not available.
+    let has_v = auxvec::auxv().is_ok_and(|auxv| bit::test(auxv.hwcap, (b'v' - b'a').into()));
+    if has_v {
+        let vlenb: usize;
+        // csrr {}, vlenb
+        unsafe { asm!("csrr {}, 0xc22", out(reg) vlenb, options(nomem, nostack)) };
+        info.vector_length = Some(vlenb as u32);
+    }
+
+    #[cfg(feature = "std_detect_file_io")]
+    super::sysfs::read_caches(&mut info);
+
+    let cache_line_size = info
+        .caches()
+        .filter(|c| c.cache_type() != CacheType::Instruction)
+        .map(|c| c.line_size())
+        .filter(|&size| size != 0)
+        .min();
+    info.cache_line_size = cache_line_size;
+
+    info
+}
+
+#[cfg(all(test, feature = "std_detect_file_io"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn isa_extensions() {
+        let isa = "rv64imafdcv_zicbom_zicbop_zicboz_zicntr_zicsr_zifencei_zihintpause";
+        assert!(isa_has(isa, "zicbop"));
+        assert!(isa_has(isa, "zihintpause"));
+        assert!(!isa_has(isa, "zawrs"));
+        // Single-letter extensions and prefixes of names aren't matched.
+        assert!(!isa_has(isa, "v"));
+        assert!(!isa_has(isa, "zicb"));
+        assert!(!isa_has("rv64imafdc", "zicbop"));
+    }
+}
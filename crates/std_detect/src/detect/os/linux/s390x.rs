@@ -0,0 +1,84 @@
+//! Run-time feature detection for s390x on Linux.
+
+use super::auxvec;
+use crate::detect::{bit, cache, Feature};
+
+/// Try to read the features from the auxiliary vector.
+pub(crate) fn detect_features() -> cache::Initializer {
+    if let Ok(auxv) = auxvec::auxv() {
+        return features_from_auxv(auxv);
+    }
+    // The features are also listed in the `features` field of
+    // `/proc/cpuinfo`, but every kernel that supports the vector facility
+    // provides `AT_HWCAP`.
+    cache::Initializer::default()
+}
+
+/// Decodes the `AT_HWCAP` bits.
+fn features_from_auxv(auxv: auxvec::AuxVec) -> cache::Initializer {
+    let mut value = cache::Initializer::default();
+    let enable_feature = |value: &mut cache::Initializer, f, enable| {
+        if enable {
+            value.set(f as u32);
+        }
+    };
+
+    // The values are part of the platform-specific [asm/elf.h][elf_h]
+    //
+    // [elf_h]: https://github.com/torvalds/linux/blob/master/arch/s390/include/asm/elf.h
+    //
+    // The kernel clears HWCAP_S390_VXRS if the vector registers are not saved
+    // on context switches, and the facilities below all operate on them.
+    let vector = bit::test(auxv.hwcap, 11); // HWCAP_S390_VXRS
+    let vxrs_ext = vector && bit::test(auxv.hwcap, 13); // HWCAP_S390_VXRS_EXT
+    let vxrs_ext2 = vxrs_ext && bit::test(auxv.hwcap, 15); // HWCAP_S390_VXRS_EXT2
+    let nnpa = vector && bit::test(auxv.hwcap, 20); // HWCAP_S390_NNPA
+
+    enable_feature(&mut value, Feature::vector, vector);
+    enable_feature(&mut value, Feature::vector_enhancements_1, vxrs_ext);
+    enable_feature(&mut value, Feature::vector_enhancements_2, vxrs_ext2);
+    enable_feature(&mut value, Feature::nnp_assist, nnpa);
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn features(hwcap: usize) -> [bool; 4] {
+        let value = features_from_auxv(auxvec::AuxVec { hwcap });
+        [
+            Feature::vector,
+            Feature::vector_enhancements_1,
+            Feature::vector_enhancements_2,
+            Feature::nnp_assist,
+        ]
+        .map(|f| value.test(f as u32))
+    }
+
+    #[test]
+    fn z13() {
+        // esan3, zarch, stfle, msa, ldisp, eimm, dfp, edat, etf3eh, highgprs,
+        // te and vx.
+        assert_eq!(features(0x0fff), [true, false, false, false]);
+    }
+
+    #[test]
+    fn z15() {
+        // z13 plus vxd, vxe, gs, vxe2, vxp, sort and dflt.
+        assert_eq!(features(0x7ffff), [true, true, true, false]);
+    }
+
+    #[test]
+    fn z16() {
+        // z15 plus vxp2 and nnpa.
+        assert_eq!(features(0x1fffff), [true, true, true, true]);
+    }
+
+    #[test]
+    fn no_vector() {
+        // A kernel booted with `novx` clears HWCAP_S390_VXRS but not the bits
+        // of the later facilities.
+        assert_eq!(features(0x1fffff & !(1 << 11)), [false; 4]);
+    }
+}
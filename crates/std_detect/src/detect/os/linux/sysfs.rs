@@ -0,0 +1,135 @@
+//! Reads the CPU cache hierarchy from `/sys/devices/system/cpu`.
+#![allow(dead_code)]
+
+use crate::detect::{CacheInfo, CacheType, CpuInfo};
+use alloc::{format, string::String};
+
+/// Reads the caches of the CPU the current thread is running on from
+/// `/sys/devices/system/cpu/cpu*/cache/index*` and appends them to `info`.
+///
+/// See the [kernel documentation][sysfs_cache] for the meaning of each file.
+///
+/// [sysfs_cache]: https://www.kernel.org/doc/Documentation/ABI/testing/sysfs-devices-system-cpu
+pub(crate) fn read_caches(info: &mut CpuInfo) {
+    let cpu = match unsafe { libc::sched_getcpu() } {
+        -1 => 0,
+        cpu => cpu,
+    };
+    for index in 0.. {
+        let dir = format!("/sys/devices/system/cpu/cpu{cpu}/cache/index{index}");
+        let read = |file: &str| -> Option<String> {
+            let raw = super::read_file(&format!("{dir}/{file}")).ok()?;
+            String::from_utf8(raw).ok()
+        };
+        let (Some(level), Some(cache_type), Some(size)) =
+            (read("level"), read("type"), read("size"))
+        else {
+            break;
+        };
+        let cache = parse_cache(
+            &level,
+            &cache_type,
+            &size,
+            read("coherency_line_size").as_deref(),
+            read("ways_of_associativity").as_deref(),
+            read("shared_cpu_list").as_deref(),
+        );
+        if let Some(cache) = cache {
+            info.push_cache(cache);
+        }
+    }
+}
+
+/// Builds a `CacheInfo` from the contents of the sysfs cache files.
+fn parse_cache(
+    level: &str,
+    cache_type: &str,
+    size: &str,
+    line_size: Option<&str>,
+    ways: Option<&str>,
+    shared_cpu_list: Option<&str>,
+) -> Option<CacheInfo> {
+    let parse = |v: Option<&str>| v.and_then(|v| v.trim().parse::<u32>().ok()).unwrap_or(0);
+    Some(CacheInfo {
+        level: level.trim().parse().ok()?,
+        cache_type: match cache_type.trim() {
+            "Data" => CacheType::Data,
+            "Instruction" => CacheType::Instruction,
+            "Unified" => CacheType::Unified,
+            _ => return None,
+        },
+        size: parse_size(size)?,
+        line_size: parse(line_size),
+        associativity: parse(ways),
+        shared_by: shared_cpu_list.map(count_cpu_list).unwrap_or(0),
+    })
+}
+
+/// Parses a size like `32K` or `8M` into bytes.
+fn parse_size(size: &str) -> Option<u32> {
+    let size = size.trim();
+    let (digits, multiplier) = match size.as_bytes().last()? {
+        b'K' => (&size[..size.len() - 1], 1024),
+        b'M' => (&size[..size.len() - 1], 1024 * 1024),
+        b'G' => (&size[..size.len() - 1], 1024 * 1024 * 1024),
+        _ => (size, 1),
+    };
+    digits.parse::<u32>().ok()?.checked_mul(multiplier)
+}
+
+/// Counts the CPUs in a list like `0-3,8,10-11`.
+fn count_cpu_list(list: &str) -> u32 {
+    list.trim()
+        .split(',')
+        .filter_map(|range| match range.split_once('-') {
+            Some((first, last)) => {
+                let first: u32 = first.parse().ok()?;
+                let last: u32 = last.parse().ok()?;
+                last.checked_sub(first).map(|n| n + 1)
+            }
+            None => range.parse::<u32>().ok().map(|_| 1),
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size() {
+        assert_eq!(parse_size("64K\n"), Some(64 * 1024));
+        assert_eq!(parse_size("8M"), Some(8 * 1024 * 1024));
+        assert_eq!(parse_size("512"), Some(512));
+        assert_eq!(parse_size(""), None);
+    }
+
+    #[test]
+    fn cpu_list() {
+        assert_eq!(count_cpu_list("0\n"), 1);
+        assert_eq!(count_cpu_list("0-3"), 4);
+        assert_eq!(count_cpu_list("0-3,8,10-11"), 7);
+    }
+
+    #[test]
+    fn cache() {
+        // Neoverse-N1 L2
+        let l2 = parse_cache(
+            "2\n",
+            "Unified\n",
+            "1024K\n",
+            Some("64\n"),
+            Some("8\n"),
+            Some("0\n"),
+        )
+        .unwrap();
+        assert_eq!(l2.level(), 2);
+        assert_eq!(l2.cache_type(), CacheType::Unified);
+        assert_eq!(l2.size(), 1024 * 1024);
+        assert_eq!(l2.line_size(), 64);
+        assert_eq!(l2.associativity(), 8);
+        assert_eq!(l2.shared_by(), 1);
+
+        assert_eq!(parse_cache("1", "Unknown", "32K", None, None, None), None);
+    }
+}
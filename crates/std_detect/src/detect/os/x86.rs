@@ -7,7 +7,8 @@ use core::arch::x86_64::*;
 
 use core::mem;
 
-use crate::detect::{bit, cache, Feature};
+use crate::detect::x86_avx10::decode_avx10;
+use crate::detect::{bit, cache, CacheInfo, CacheType, CoreType, CpuInfo, Feature};
 
 /// Run-time feature detection on x86 works by using the CPUID instruction.
 ///
@@ -24,19 +25,61 @@ use crate::detect::{bit, cache, Feature};
 /// [wiki_cpuid]: https://en.wikipedia.org/wiki/CPUID
 /// [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
 /// [amd64_ref]: http://support.amd.com/TechDocs/24594.pdf
-#[allow(clippy::similar_names)]
 pub(crate) fn detect_features() -> cache::Initializer {
+    detect_features_from(&HostCpu)
+}
+
+/// A source of `cpuid` and `xgetbv` results.
+///
+/// Detection reads the CPU through this trait instead of executing the
+/// instructions directly, so that it can be tested against CPUID dumps of
+/// CPUs that are not available to run the tests on.
+pub(crate) trait CpuidSource {
+    /// Returns `true` if the CPU supports the `cpuid` instruction.
+    fn has_cpuid(&self) -> bool;
+
+    /// Executes `cpuid` with `EAX = leaf` and `ECX = subleaf`.
+    ///
+    /// Only called if `has_cpuid` returned `true`.
+    fn cpuid(&self, leaf: u32, subleaf: u32) -> CpuidResult;
+
+    /// Reads the extended control register `xcr`.
+    ///
+    /// Only called if the CPU supports `xsave` and the OS has set `osxsave`.
+    fn xgetbv(&self, xcr: u32) -> u64;
+}
+
+/// Executes `cpuid` and `xgetbv` on the CPU the current thread is running on.
+pub(crate) struct HostCpu;
+
+impl CpuidSource for HostCpu {
+    fn has_cpuid(&self) -> bool {
+        has_cpuid()
+    }
+
+    fn cpuid(&self, leaf: u32, subleaf: u32) -> CpuidResult {
+        // This is safe because callers check `has_cpuid` first.
+        unsafe { __cpuid_count(leaf, subleaf) }
+    }
+
+    fn xgetbv(&self, xcr: u32) -> u64 {
+        // This is safe because callers check that the CPU supports `xsave`
+        // and that the OS has set `osxsave`.
+        unsafe { _xgetbv(xcr) }
+    }
+}
+
+/// Detects the features reported by `cpu`, see [`detect_features`].
+#[allow(clippy::similar_names)]
+pub(crate) fn detect_features_from(cpu: &impl CpuidSource) -> cache::Initializer {
     let mut value = cache::Initializer::default();
 
     // If the x86 CPU does not support the CPUID instruction then it is too
     // old to support any of the currently-detectable features.
-    if !has_cpuid() {
+    if !cpu.has_cpuid() {
         return value;
     }
 
-    // Calling `__cpuid`/`__cpuid_count` from here on is safe because the CPU
-    // has `cpuid` support.
-
     // 0. EAX = 0: Basic Information:
     // - EAX returns the "Highest Function Parameter", that is, the maximum
     // leaf value for subsequent calls of `cpuinfo` in range [0,
@@ -48,7 +91,7 @@ pub(crate) fn detect_features() -> cache::Initializer {
             ebx,
             ecx,
             edx,
-        } = __cpuid(0);
+        } = cpu.cpuid(0, 0);
         let vendor_id: [[u8; 4]; 3] = [
             mem::transmute(ebx),
             mem::transmute(edx),
@@ -69,15 +112,28 @@ pub(crate) fn detect_features() -> cache::Initializer {
         ecx: proc_info_ecx,
         edx: proc_info_edx,
         ..
-    } = unsafe { __cpuid(0x0000_0001_u32) };
+    } = cpu.cpuid(0x0000_0001_u32, 0);
 
     // EAX = 7, ECX = 0: Queries "Extended Features";
     // Contains information about bmi,bmi2, and avx2 support.
-    let (extended_features_ebx, extended_features_ecx) = if max_basic_leaf >= 7 {
-        let CpuidResult { ebx, ecx, .. } = unsafe { __cpuid(0x0000_0007_u32) };
-        (ebx, ecx)
+    let (
+        max_extended_features_subleaf,
+        extended_features_ebx,
+        extended_features_ecx,
+        extended_features_edx,
+    ) = if max_basic_leaf >= 7 {
+        let CpuidResult { eax, ebx, ecx, edx } = cpu.cpuid(0x0000_0007_u32, 0);
+        (eax, ebx, ecx, edx)
     } else {
-        (0, 0) // CPUID does not support "Extended Features"
+        (0, 0, 0, 0) // CPUID does not support "Extended Features"
+    };
+
+    // EAX = 7, ECX = 1: Queries "Extended Features" sub-leaf 1;
+    // Contains information about avx512bf16 support.
+    let extended_features1_eax = if max_basic_leaf >= 7 && max_extended_features_subleaf >= 1 {
+        cpu.cpuid(0x0000_0007_u32, 1).eax
+    } else {
+        0
     };
 
     // EAX = 0x8000_0000, ECX = 0: Get Highest Extended Function Supported
@@ -86,12 +142,12 @@ pub(crate) fn detect_features() -> cache::Initializer {
     let CpuidResult {
         eax: extended_max_basic_leaf,
         ..
-    } = unsafe { __cpuid(0x8000_0000_u32) };
+    } = cpu.cpuid(0x8000_0000_u32, 0);
 
     // EAX = 0x8000_0001, ECX=0: Queries "Extended Processor Info and Feature
     // Bits"
     let extended_proc_info_ecx = if extended_max_basic_leaf >= 1 {
-        let CpuidResult { ecx, .. } = unsafe { __cpuid(0x8000_0001_u32) };
+        let CpuidResult { ecx, .. } = cpu.cpuid(0x8000_0001_u32, 0);
         ecx
     } else {
         0
@@ -131,6 +187,11 @@ pub(crate) fn detect_features() -> cache::Initializer {
 
         enable(extended_features_ebx, 9, Feature::ermsb);
 
+        // GFNI has SSE, VEX and EVEX encodings. `Feature::gfni` covers the SSE
+        // encoding, which only uses the SSE registers: the wider encodings
+        // are gated on `avx` and `avx512f` by the user.
+        enable(extended_features_ecx, 8, Feature::gfni);
+
         // `XSAVE` and `AVX` support:
         let cpu_xsave = bit::test(proc_info_ecx as usize, 26);
         if cpu_xsave {
@@ -157,9 +218,9 @@ pub(crate) fn detect_features() -> cache::Initializer {
                 //
                 // by setting the corresponding bits of `XCR0` to `1`.
                 //
-                // This is safe because the CPU supports `xsave`
+                // `xgetbv` can be used because the CPU supports `xsave`
                 // and the OS has set `osxsave`.
-                let xcr0 = unsafe { _xgetbv(0) };
+                let xcr0 = cpu.xgetbv(0);
                 // Test `XCR0.SSE[1]` and `XCR0.AVX[2]` with the mask `0b110 == 6`:
                 let os_avx_support = xcr0 & 6 == 6;
                 // Test `XCR0.AVX-512[7:5]` with the mask `0b1110_0000 == 224`:
@@ -187,12 +248,17 @@ pub(crate) fn detect_features() -> cache::Initializer {
                         let CpuidResult {
                             eax: proc_extended_state1_eax,
                             ..
-                        } = unsafe { __cpuid_count(0xd_u32, 1) };
+                        } = cpu.cpuid(0xd_u32, 1);
                         enable(proc_extended_state1_eax, 0, Feature::xsaveopt);
                         enable(proc_extended_state1_eax, 1, Feature::xsavec);
                         enable(proc_extended_state1_eax, 3, Feature::xsaves);
                     }
 
+                    // VAES and VPCLMULQDQ have no SSE encoding, their VEX
+                    // encoding uses 256-bit wide registers:
+                    enable(extended_features_ecx, 9, Feature::vaes);
+                    enable(extended_features_ecx, 10, Feature::vpclmulqdq);
+
                     // FMA (uses 256-bit wide registers):
                     enable(proc_info_ecx, 12, Feature::fma);
 
@@ -212,15 +278,21 @@ pub(crate) fn detect_features() -> cache::Initializer {
                         enable(extended_features_ebx, 30, Feature::avx512bw);
                         enable(extended_features_ebx, 31, Feature::avx512vl);
                         enable(extended_features_ecx, 1, Feature::avx512vbmi);
-                        enable(extended_features_ecx, 5, Feature::avx512bf16);
+                        enable(extended_features1_eax, 5, Feature::avx512bf16);
                         enable(extended_features_ecx, 6, Feature::avx512vbmi2);
-                        enable(extended_features_ecx, 8, Feature::gfni);
-                        enable(extended_features_ecx, 8, Feature::avx512vp2intersect);
-                        enable(extended_features_ecx, 9, Feature::vaes);
-                        enable(extended_features_ecx, 10, Feature::vpclmulqdq);
+                        enable(extended_features_edx, 8, Feature::avx512vp2intersect);
                         enable(extended_features_ecx, 11, Feature::avx512vnni);
                         enable(extended_features_ecx, 12, Feature::avx512bitalg);
                         enable(extended_features_ecx, 14, Feature::avx512vpopcntdq);
+
+                        // AVX10 uses the AVX-512 state at every vector
+                        // length. EAX = 0x24 reports the version and the
+                        // supported vector lengths in EBX:
+                        if decode_avx10(cpu).is_some() {
+                            let avx10_ebx = cpu.cpuid(0x0000_0024_u32, 0).ebx;
+                            enable(avx10_ebx, 17, Feature::avx10_1_256);
+                            enable(avx10_ebx, 18, Feature::avx10_1_512);
+                        }
                     }
                 }
             }
@@ -274,3 +346,816 @@ pub(crate) fn detect_features() -> cache::Initializer {
 
     value
 }
+
+/// Queries the vendor, family/model/stepping, caches and core type with the
+/// CPUID instruction.
+pub(crate) fn cpu_info() -> CpuInfo {
+    cpu_info_from(&HostCpu)
+}
+
+/// Queries the information of [`cpu_info`] from `cpu`.
+fn cpu_info_from(cpu: &impl CpuidSource) -> CpuInfo {
+    let mut info = CpuInfo::default();
+
+    if !cpu.has_cpuid() {
+        return info;
+    }
+
+    let CpuidResult {
+        eax: max_basic_leaf,
+        ebx,
+        ecx,
+        edx,
+    } = cpu.cpuid(0, 0);
+    let mut vendor_id = [0_u8; 12];
+    vendor_id[0..4].copy_from_slice(&ebx.to_ne_bytes());
+    vendor_id[4..8].copy_from_slice(&edx.to_ne_bytes());
+    vendor_id[8..12].copy_from_slice(&ecx.to_ne_bytes());
+    info.set_vendor(&vendor_id);
+
+    if max_basic_leaf < 1 {
+        return info;
+    }
+
+    // EAX = 1: "Processor Info and Feature Bits"
+    let CpuidResult {
+        eax: signature,
+        ebx: proc_info_ebx,
+        ..
+    } = cpu.cpuid(0x0000_0001_u32, 0);
+    let (family, model, stepping) = decode_signature(signature);
+    info.family = Some(family);
+    info.model = Some(model);
+    info.stepping = Some(stepping);
+
+    info.core_type = hybrid_core_type(cpu);
+
+    if let Some(leaf) = cache_parameters_leaf(cpu) {
+        // Each sub-leaf describes one cache, until one reports the null type.
+        for subleaf in 0..32 {
+            let CpuidResult { eax, ebx, ecx, .. } = cpu.cpuid(leaf, subleaf);
+            match decode_cache_parameters(eax, ebx, ecx) {
+                Some(cache) => info.push_cache(cache),
+                None => break,
+            }
+        }
+    }
+
+    // Prefer the smallest line size of the data caches, and fall back to the
+    // `CLFLUSH` line size otherwise.
+    let cache_line_size = info
+        .caches()
+        .filter(|c| c.cache_type() != CacheType::Instruction)
+        .map(|c| c.line_size())
+        .min()
+        .or(match (proc_info_ebx >> 8) & 0xff {
+            0 => None,
+            n => Some(n * 8),
+        });
+    info.cache_line_size = cache_line_size;
+
+    info
+}
+
+/// Decodes the processor signature of `cpuid` leaf 1 into the display
+/// `(family, model, stepping)`.
+fn decode_signature(eax: u32) -> (u32, u32, u32) {
+    let stepping = eax & 0xf;
+    let model = (eax >> 4) & 0xf;
+    let family = (eax >> 8) & 0xf;
+    let extended_model = (eax >> 16) & 0xf;
+    let extended_family = (eax >> 20) & 0xff;
+
+    let display_family = if family == 0xf {
+        family + extended_family
+    } else {
+        family
+    };
+    let display_model = if family == 0x6 || family == 0xf {
+        (extended_model << 4) + model
+    } else {
+        model
+    };
+    (display_family, display_model, stepping)
+}
+
+/// Returns the type of the core that executes `cpuid` on hybrid CPUs, and
+/// `None` on other CPUs.
+pub(crate) fn hybrid_core_type(cpu: &impl CpuidSource) -> Option<CoreType> {
+    // EAX = 7, EDX[15]: the CPU is hybrid, and EAX = 0x1A reports the type of
+    // the core that executed `cpuid`.
+    if cpu.cpuid(0, 0).eax < 0x1a || !bit::test(cpu.cpuid(0x0000_0007_u32, 0).edx as usize, 15) {
+        return None;
+    }
+    decode_core_type(cpu.cpuid(0x0000_001a_u32, 0).eax)
+}
+
+/// Decodes the core type reported in EAX[31:24] of `cpuid` leaf 0x1A.
+fn decode_core_type(eax: u32) -> Option<CoreType> {
+    match eax >> 24 {
+        0x20 => Some(CoreType::Efficiency),
+        0x40 => Some(CoreType::Performance),
+        _ => None,
+    }
+}
+
+/// Returns the `cpuid` leaf that enumerates the cache hierarchy, if any.
+///
+/// Intel reports the cache hierarchy in leaf 4, AMD and Hygon with the same
+/// layout in leaf 0x8000_001D if the "topology extensions" are supported.
+pub(crate) fn cache_parameters_leaf(cpu: &impl CpuidSource) -> Option<u32> {
+    let CpuidResult {
+        eax: max_basic_leaf,
+        ebx,
+        ecx,
+        edx,
+    } = cpu.cpuid(0, 0);
+    let is_intel = [ebx, edx, ecx] == [0x756e_6547, 0x4965_6e69, 0x6c65_746e];
+    let CpuidResult {
+        eax: extended_max_basic_leaf,
+        ..
+    } = cpu.cpuid(0x8000_0000_u32, 0);
+    if is_intel && max_basic_leaf >= 4 {
+        Some(0x0000_0004_u32)
+    } else if extended_max_basic_leaf >= 0x8000_001d
+        && bit::test(cpu.cpuid(0x8000_0001_u32, 0).ecx as usize, 22)
+    {
+        Some(0x8000_001d_u32)
+    } else {
+        None
+    }
+}
+
+/// Decodes a "Deterministic Cache Parameters" sub-leaf of `cpuid` leaf 4 (or
+/// leaf 0x8000_001D on AMD). Returns `None` for the null cache type that
+/// terminates the list.
+pub(crate) fn decode_cache_parameters(eax: u32, ebx: u32, ecx: u32) -> Option<CacheInfo> {
+    let cache_type = match eax & 0x1f {
+        1 => CacheType::Data,
+        2 => CacheType::Instruction,
+        3 => CacheType::Unified,
+        _ => return None,
+    };
+    let level = ((eax >> 5) & 0x7) as u8;
+    let shared_by = ((eax >> 14) & 0xfff) + 1;
+    let line_size = (ebx & 0xfff) + 1;
+    let partitions = ((ebx >> 12) & 0x3ff) + 1;
+    let associativity = ((ebx >> 22) & 0x3ff) + 1;
+    let sets = ecx.wrapping_add(1);
+    Some(CacheInfo {
+        level,
+        cache_type,
+        size: associativity
+            .wrapping_mul(partitions)
+            .wrapping_mul(line_size)
+            .wrapping_mul(sets),
+        line_size,
+        associativity,
+        shared_by,
+    })
+}
+
+/// A CPUID dump, used to test detection against CPUs that are not available
+/// to run the tests on.
+///
+/// Dumps use the raw format of `cpuid -r -1`, plus a line with the value of
+/// `XCR0`. Leaves missing from the dump read as zero.
+///
+/// ```text
+///    0x00000000 0x00: eax=0x00000016 ebx=0x756e6547 ecx=0x6c65746e edx=0x49656e69
+/// xcr0=0x00000000000002e7
+/// ```
+///
+/// The JSON output of the `std_detect_dump` binary is also accepted: its
+/// `cpuid` array contains lines in the same format.
+#[cfg(test)]
+pub(crate) struct CpuidDump {
+    leaves: std::vec::Vec<(u32, u32, CpuidResult)>,
+    xcr0: u64,
+}
+
+#[cfg(test)]
+impl CpuidDump {
+    /// Parses a dump, panicking on malformed lines.
+    pub(crate) fn parse(dump: &str) -> Self {
+        let hex = |s: &str| {
+            u64::from_str_radix(s.trim_start_matches("0x"), 16)
+                .unwrap_or_else(|_| panic!("invalid hex number `{s}`"))
+        };
+        let json = dump.trim_start().starts_with('{');
+        let mut leaves = vec![];
+        let mut xcr0 = 0;
+        for line in dump.lines().map(str::trim) {
+            let line = if json {
+                // `std_detect_dump` prints one JSON value per line.
+                let line = line.trim_end_matches(',');
+                if let Some(value) = line.strip_prefix("\"xcr0\": ") {
+                    xcr0 = hex(value.trim_matches('"'));
+                    continue;
+                }
+                if !line.starts_with("\"0x") {
+                    continue;
+                }
+                line.trim_matches('"')
+            } else {
+                line
+            };
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(value) = line.strip_prefix("xcr0=") {
+                xcr0 = hex(value);
+                continue;
+            }
+            let fields: std::vec::Vec<&str> = line.split_whitespace().collect();
+            let [leaf, subleaf, eax, ebx, ecx, edx] = fields[..] else {
+                panic!("invalid CPUID dump line `{line}`");
+            };
+            let reg = |field: &str, name: &str| match field.strip_prefix(name) {
+                Some(value) => hex(value) as u32,
+                None => panic!("expected `{name}` in CPUID dump line `{line}`"),
+            };
+            leaves.push((
+                hex(leaf) as u32,
+                hex(subleaf.trim_end_matches(':')) as u32,
+                CpuidResult {
+                    eax: reg(eax, "eax="),
+                    ebx: reg(ebx, "ebx="),
+                    ecx: reg(ecx, "ecx="),
+                    edx: reg(edx, "edx="),
+                },
+            ));
+        }
+        CpuidDump { leaves, xcr0 }
+    }
+
+    /// Builds a dump from the /proc/cpuinfo of Linux, for CPUs of which no
+    /// CPUID dump is available.
+    ///
+    /// Linux reports each CPUID feature bit that it knows as one of the
+    /// `flags`, so the leaves read by detection are rebuilt from them with
+    /// [`LINUX_CPUID_FLAGS`]. Linux doesn't report `OSXSAVE`, which it always
+    /// sets if `XSAVE` is supported, nor XCR0, but it removes the flags of the
+    /// extensions whose register state it doesn't enable: XCR0 is rebuilt from
+    /// the flags as well.
+    pub(crate) fn from_cpuinfo(cpuinfo: &str) -> Self {
+        let field = |name: &str| {
+            cpuinfo
+                .lines()
+                .filter_map(|line| line.split_once(':'))
+                .find(|(key, _)| key.trim() == name)
+                .map(|(_, value)| value.trim())
+                .unwrap_or_else(|| panic!("no `{name}` in /proc/cpuinfo"))
+        };
+        let flags: std::vec::Vec<&str> = field("flags").split_whitespace().collect();
+        let has = |flag| flags.contains(&flag);
+
+        let vendor = field("vendor_id").as_bytes();
+        let vendor = |i: usize| u32::from_le_bytes(vendor[i..i + 4].try_into().unwrap());
+        let max_leaf = field("cpuid level").parse().unwrap();
+
+        let mut leaves = vec![
+            (0x0, 0, [max_leaf, vendor(0), vendor(8), vendor(4)]),
+            (0x1, 0, [0; 4]),
+            (0x7, 0, [1, 0, 0, 0]),
+            (0x7, 1, [0; 4]),
+            (0xd, 1, [0; 4]),
+            (0x8000_0000, 0, [0x8000_0001, 0, 0, 0]),
+            (0x8000_0001, 0, [0; 4]),
+        ];
+        for &(flag, leaf, subleaf, reg, bit) in LINUX_CPUID_FLAGS {
+            if has(flag) {
+                let (_, _, regs) = leaves
+                    .iter_mut()
+                    .find(|e| (e.0, e.1) == (leaf, subleaf))
+                    .unwrap();
+                regs[reg] |= 1 << bit;
+            }
+        }
+        if has("xsave") {
+            // OSXSAVE
+            leaves[1].2[2] |= 1 << 27;
+        }
+
+        let mut xcr0 = 0b11;
+        if has("avx") {
+            xcr0 |= 0b100;
+        }
+        if has("avx512f") {
+            xcr0 |= 0b1110_0000;
+        }
+        if has("amx_tile") {
+            xcr0 |= 0b11 << 17;
+        }
+        CpuidDump {
+            leaves: leaves
+                .into_iter()
+                .map(|(leaf, subleaf, [eax, ebx, ecx, edx])| {
+                    (leaf, subleaf, CpuidResult { eax, ebx, ecx, edx })
+                })
+                .collect(),
+            xcr0,
+        }
+    }
+
+    /// Builds a dump from `(leaf, subleaf, eax, ebx, ecx, edx)` tuples.
+    pub(crate) fn from_leaves(leaves: &[(u32, u32, u32, u32, u32, u32)]) -> Self {
+        CpuidDump {
+            leaves: leaves
+                .iter()
+                .map(|&(leaf, subleaf, eax, ebx, ecx, edx)| {
+                    (leaf, subleaf, CpuidResult { eax, ebx, ecx, edx })
+                })
+                .collect(),
+            xcr0: 0,
+        }
+    }
+
+    /// Sets the value of XCR0, which is 0 in dumps built with
+    /// [`CpuidDump::from_leaves`].
+    pub(crate) fn with_xcr0(mut self, xcr0: u64) -> Self {
+        self.xcr0 = xcr0;
+        self
+    }
+}
+
+/// The flags of /proc/cpuinfo for the CPUID feature bits read by detection,
+/// from `arch/x86/include/asm/cpufeatures.h` in Linux, as `(flag, leaf,
+/// sub-leaf, register, bit)` with the registers numbered from EAX to EDX.
+#[cfg(test)]
+const LINUX_CPUID_FLAGS: &[(&str, u32, u32, usize, u32)] = &[
+    ("tsc", 0x1, 0, 3, 4),
+    ("mmx", 0x1, 0, 3, 23),
+    ("fxsr", 0x1, 0, 3, 24),
+    ("sse", 0x1, 0, 3, 25),
+    ("sse2", 0x1, 0, 3, 26),
+    ("pni", 0x1, 0, 2, 0),
+    ("pclmulqdq", 0x1, 0, 2, 1),
+    ("ssse3", 0x1, 0, 2, 9),
+    ("fma", 0x1, 0, 2, 12),
+    ("cx16", 0x1, 0, 2, 13),
+    ("sse4_1", 0x1, 0, 2, 19),
+    ("sse4_2", 0x1, 0, 2, 20),
+    ("movbe", 0x1, 0, 2, 22),
+    ("popcnt", 0x1, 0, 2, 23),
+    ("aes", 0x1, 0, 2, 25),
+    ("xsave", 0x1, 0, 2, 26),
+    ("avx", 0x1, 0, 2, 28),
+    ("f16c", 0x1, 0, 2, 29),
+    ("rdrand", 0x1, 0, 2, 30),
+    ("bmi1", 0x7, 0, 1, 3),
+    ("avx2", 0x7, 0, 1, 5),
+    ("bmi2", 0x7, 0, 1, 8),
+    ("erms", 0x7, 0, 1, 9),
+    ("rtm", 0x7, 0, 1, 11),
+    ("avx512f", 0x7, 0, 1, 16),
+    ("avx512dq", 0x7, 0, 1, 17),
+    ("rdseed", 0x7, 0, 1, 18),
+    ("adx", 0x7, 0, 1, 19),
+    ("avx512ifma", 0x7, 0, 1, 21),
+    ("avx512pf", 0x7, 0, 1, 26),
+    ("avx512er", 0x7, 0, 1, 27),
+    ("avx512cd", 0x7, 0, 1, 28),
+    ("sha_ni", 0x7, 0, 1, 29),
+    ("avx512bw", 0x7, 0, 1, 30),
+    ("avx512vl", 0x7, 0, 1, 31),
+    ("avx512vbmi", 0x7, 0, 2, 1),
+    ("avx512_vbmi2", 0x7, 0, 2, 6),
+    ("gfni", 0x7, 0, 2, 8),
+    ("vaes", 0x7, 0, 2, 9),
+    ("vpclmulqdq", 0x7, 0, 2, 10),
+    ("avx512_vnni", 0x7, 0, 2, 11),
+    ("avx512_bitalg", 0x7, 0, 2, 12),
+    ("avx512_vpopcntdq", 0x7, 0, 2, 14),
+    ("avx512_vp2intersect", 0x7, 0, 3, 8),
+    ("avx_vnni", 0x7, 1, 0, 4),
+    ("avx512_bf16", 0x7, 1, 0, 5),
+    ("xsaveopt", 0xd, 1, 0, 0),
+    ("xsavec", 0xd, 1, 0, 1),
+    ("xgetbv1", 0xd, 1, 0, 2),
+    ("xsaves", 0xd, 1, 0, 3),
+    ("abm", 0x8000_0001, 0, 2, 5),
+    ("sse4a", 0x8000_0001, 0, 2, 6),
+    ("tbm", 0x8000_0001, 0, 2, 21),
+];
+
+#[cfg(test)]
+impl CpuidSource for CpuidDump {
+    fn has_cpuid(&self) -> bool {
+        true
+    }
+
+    fn cpuid(&self, leaf: u32, subleaf: u32) -> CpuidResult {
+        self.leaves
+            .iter()
+            .find(|e| e.0 == leaf && e.1 == subleaf)
+            .map(|e| e.2)
+            .unwrap_or(CpuidResult {
+                eax: 0,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            })
+    }
+
+    fn xgetbv(&self, xcr: u32) -> u64 {
+        assert_eq!(xcr, 0, "only XCR0 is recorded in CPUID dumps");
+        self.xcr0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::dump;
+    use std::vec::Vec;
+
+    /// Checks that the features detected from `dump` are exactly `expected`.
+    fn check(name: &str, dump: &CpuidDump, expected: &[&[&str]]) {
+        let value = detect_features_from(dump);
+        let mut detected: Vec<&str> = Feature::ALL
+            .iter()
+            .filter(|&&f| value.test(f as u32))
+            .map(|f| f.to_str())
+            .collect();
+        let mut expected: Vec<&str> = expected.concat();
+        detected.sort_unstable();
+        expected.sort_unstable();
+        assert_eq!(detected, expected, "features detected on {name}");
+    }
+
+    /// The CPUID dump in `test_data/x86/{name}.cpuid`.
+    macro_rules! dump {
+        ($name:literal) => {
+            include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/src/detect/test_data/x86/",
+                $name,
+                ".cpuid"
+            ))
+        };
+    }
+
+    /// The dump rebuilt from the /proc/cpuinfo in
+    /// `test_data/x86/{name}.cpuinfo`.
+    macro_rules! cpuinfo {
+        ($name:literal) => {
+            CpuidDump::from_cpuinfo(include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/src/detect/test_data/x86/",
+                $name,
+                ".cpuinfo"
+            )))
+        };
+    }
+
+    /// Recorded with the `std_detect_dump` binary in a KVM guest on an
+    /// Emerald Rapids host.
+    const EMERALD_RAPIDS_KVM: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/src/detect/test_data/x86/emerald-rapids-kvm.json"
+    ));
+
+    /// Features that all the x86-64-v3 CPUs in the corpus support.
+    const X86_64_V3: &[&str] = &[
+        "tsc",
+        "mmx",
+        "fxsr",
+        "sse",
+        "sse2",
+        "sse3",
+        "ssse3",
+        "sse4.1",
+        "sse4.2",
+        "popcnt",
+        "cmpxchg16b",
+        "aes",
+        "pclmulqdq",
+        "rdrand",
+        "rdseed",
+        "adx",
+        "movbe",
+        "xsave",
+        "xsaveopt",
+        "xsavec",
+        "avx",
+        "avx2",
+        "fma",
+        "f16c",
+        "bmi1",
+        "bmi2",
+        "lzcnt",
+    ];
+
+    /// The AVX-512 subsets of Skylake-X.
+    const AVX512_SKX: &[&str] = &["avx512f", "avx512cd", "avx512bw", "avx512dq", "avx512vl"];
+
+    /// The AVX-512 subsets, and the extensions with EVEX encodings, added by
+    /// Ice Lake.
+    const AVX512_ICL: &[&str] = &[
+        "avx512ifma",
+        "avx512vbmi",
+        "avx512vbmi2",
+        "avx512vnni",
+        "avx512bitalg",
+        "avx512vpopcntdq",
+        "gfni",
+        "vaes",
+        "vpclmulqdq",
+    ];
+
+    #[test]
+    fn skylake_x() {
+        // The kernel of this host doesn't report `xsaves`.
+        check(
+            "skylake-x",
+            &cpuinfo!("skylake-x"),
+            &[X86_64_V3, AVX512_SKX, &["ermsb", "rtm"]],
+        );
+    }
+
+    #[test]
+    fn cascade_lake() {
+        check(
+            "cascade-lake",
+            &CpuidDump::parse(dump!("cascade-lake")),
+            &[X86_64_V3, AVX512_SKX, &["xsaves", "ermsb", "avx512vnni"]],
+        );
+    }
+
+    #[test]
+    fn tiger_lake() {
+        check(
+            "tiger-lake",
+            &CpuidDump::parse(dump!("tiger-lake")),
+            &[
+                X86_64_V3,
+                AVX512_SKX,
+                AVX512_ICL,
+                &["xsaves", "ermsb", "sha", "avx512vp2intersect"],
+            ],
+        );
+    }
+
+    #[test]
+    fn alder_lake() {
+        check(
+            "alder-lake",
+            &CpuidDump::parse(dump!("alder-lake")),
+            &[
+                X86_64_V3,
+                &["xsaves", "ermsb", "sha", "gfni", "vaes", "vpclmulqdq"],
+            ],
+        );
+    }
+
+    #[test]
+    fn sapphire_rapids() {
+        // TSX is disabled on this host.
+        check(
+            "sapphire-rapids",
+            &cpuinfo!("sapphire-rapids"),
+            &[
+                X86_64_V3,
+                AVX512_SKX,
+                AVX512_ICL,
+                &["xsaves", "ermsb", "sha", "avx512bf16"],
+            ],
+        );
+    }
+
+    #[test]
+    fn zen2() {
+        check(
+            "zen2",
+            &CpuidDump::parse(dump!("zen2")),
+            &[X86_64_V3, &["xsaves", "sha", "sse4a"]],
+        );
+    }
+
+    #[test]
+    fn zen4() {
+        check(
+            "zen4",
+            &cpuinfo!("zen4"),
+            &[
+                X86_64_V3,
+                AVX512_SKX,
+                AVX512_ICL,
+                &["xsaves", "ermsb", "sha", "sse4a", "avx512bf16"],
+            ],
+        );
+    }
+
+    #[test]
+    fn zen5() {
+        check(
+            "zen5",
+            &cpuinfo!("zen5"),
+            &[
+                X86_64_V3,
+                AVX512_SKX,
+                AVX512_ICL,
+                &[
+                    "xsaves",
+                    "ermsb",
+                    "sha",
+                    "sse4a",
+                    "avx512bf16",
+                    "avx512vp2intersect",
+                ],
+            ],
+        );
+    }
+
+    /// The name of each feature in the `flags` of `/proc/cpuinfo` on Linux.
+    const LINUX_FLAGS: &[(&str, &str)] = &[
+        ("aes", "aes"),
+        ("pclmulqdq", "pclmulqdq"),
+        ("rdrand", "rdrand"),
+        ("rdseed", "rdseed"),
+        ("tsc", "tsc"),
+        ("mmx", "mmx"),
+        ("sse", "sse"),
+        ("sse2", "sse2"),
+        ("sse3", "pni"),
+        ("ssse3", "ssse3"),
+        ("sse4.1", "sse4_1"),
+        ("sse4.2", "sse4_2"),
+        ("sse4a", "sse4a"),
+        ("sha", "sha_ni"),
+        ("avx", "avx"),
+        ("avx2", "avx2"),
+        ("avx512f", "avx512f"),
+        ("avx512cd", "avx512cd"),
+        ("avx512er", "avx512er"),
+        ("avx512pf", "avx512pf"),
+        ("avx512bw", "avx512bw"),
+        ("avx512dq", "avx512dq"),
+        ("avx512vl", "avx512vl"),
+        ("avx512ifma", "avx512ifma"),
+        ("avx512vbmi", "avx512vbmi"),
+        ("avx512vpopcntdq", "avx512_vpopcntdq"),
+        ("avx512vbmi2", "avx512_vbmi2"),
+        ("gfni", "gfni"),
+        ("vaes", "vaes"),
+        ("vpclmulqdq", "vpclmulqdq"),
+        ("avx512vnni", "avx512_vnni"),
+        ("avx512bitalg", "avx512_bitalg"),
+        ("avx512bf16", "avx512_bf16"),
+        ("avx512vp2intersect", "avx512_vp2intersect"),
+        ("f16c", "f16c"),
+        ("fma", "fma"),
+        ("bmi1", "bmi1"),
+        ("bmi2", "bmi2"),
+        ("lzcnt", "abm"),
+        ("tbm", "tbm"),
+        ("popcnt", "popcnt"),
+        ("fxsr", "fxsr"),
+        ("xsave", "xsave"),
+        ("xsaveopt", "xsaveopt"),
+        ("xsaves", "xsaves"),
+        ("xsavec", "xsavec"),
+        ("cmpxchg16b", "cx16"),
+        ("adx", "adx"),
+        ("rtm", "rtm"),
+        ("movbe", "movbe"),
+        ("ermsb", "erms"),
+        ("avx10.1-256", "avx10"),
+        ("avx10.1-512", "avx10"),
+    ];
+
+    #[test]
+    fn std_detect_dump_json() {
+        let json = EMERALD_RAPIDS_KVM;
+        let value = detect_features_from(&CpuidDump::parse(json));
+
+        // The kernel decodes CPUID and XCR0 independently of `std_detect`,
+        // so the features detected from the dump must match the flags of
+        // `/proc/cpuinfo` that were recorded with it.
+        let flags = dump::array(json, "cpuinfo_flags");
+        assert!(flags.contains(&"avx512f"), "{flags:?}");
+        for &feature in Feature::ALL {
+            let name = feature.to_str();
+            let (_, flag) = LINUX_FLAGS
+                .iter()
+                .find(|(f, _)| *f == name)
+                .unwrap_or_else(|| panic!("no Linux flag for `{name}`"));
+            assert_eq!(
+                value.test(feature as u32),
+                flags.contains(flag),
+                "{name} (Linux flag `{flag}`)"
+            );
+        }
+
+        // The features reported by `std_detect_dump` when it was recorded.
+        check(
+            "emerald-rapids-kvm",
+            &CpuidDump::parse(json),
+            &[&dump::features(json)],
+        );
+    }
+
+    #[test]
+    fn host_dump() {
+        if let Some(json) = dump::host() {
+            check("host", &CpuidDump::parse(&json), &[&dump::features(&json)]);
+        }
+    }
+
+    #[test]
+    fn no_os_avx512_support() {
+        // Emerald Rapids running on an OS that only enables AVX in XCR0: the
+        // VEX encodings of GFNI, VAES and VPCLMULQDQ are still usable.
+        let dump = EMERALD_RAPIDS_KVM.replace("\"0x00000000000602e7\"", "\"0x7\"");
+        let value = detect_features_from(&CpuidDump::parse(&dump));
+        assert!(value.test(Feature::avx2 as u32));
+        assert!(!value.test(Feature::avx512f as u32));
+        assert!(value.test(Feature::gfni as u32));
+        assert!(value.test(Feature::vaes as u32));
+        assert!(value.test(Feature::vpclmulqdq as u32));
+    }
+
+    #[test]
+    fn no_os_avx_support() {
+        // Cascade Lake running on an OS that does not enable AVX in XCR0.
+        let dump = dump!("cascade-lake").replace("xcr0=0x00000000000002e7", "xcr0=0x3");
+        let value = detect_features_from(&CpuidDump::parse(&dump));
+        assert!(value.test(Feature::sse4_2 as u32));
+        assert!(!value.test(Feature::avx as u32));
+        assert!(!value.test(Feature::avx512f as u32));
+        // SKL052: BMI is not trusted on Intel CPUs without AVX.
+        assert!(!value.test(Feature::bmi1 as u32));
+
+        // Alder Lake in the same situation: only the SSE encoding of GFNI is
+        // usable.
+        let dump = dump!("alder-lake").replace("xcr0=0x0000000000000207", "xcr0=0x3");
+        let value = detect_features_from(&CpuidDump::parse(&dump));
+        assert!(value.test(Feature::gfni as u32));
+        assert!(!value.test(Feature::vaes as u32));
+        assert!(!value.test(Feature::vpclmulqdq as u32));
+    }
+
+    #[test]
+    fn signature() {
+        // Skylake-X, Family 6 Model 85 Stepping 4
+        assert_eq!(decode_signature(0x0005_0654), (6, 85, 4));
+        // Zen 4, Family 25 Model 97 Stepping 2
+        assert_eq!(decode_signature(0x00a6_0f12), (25, 97, 2));
+        // Pentium 4, Family 15 Model 2 Stepping 9
+        assert_eq!(decode_signature(0x0000_0f29), (15, 2, 9));
+        // i486, the extended model is ignored for families other than 6 and 15
+        assert_eq!(decode_signature(0x0001_0435), (4, 3, 5));
+    }
+
+    #[test]
+    fn core_type() {
+        // Alder Lake
+        assert_eq!(decode_core_type(0x4000_0001), Some(CoreType::Performance));
+        assert_eq!(decode_core_type(0x2000_0001), Some(CoreType::Efficiency));
+        assert_eq!(decode_core_type(0), None);
+    }
+
+    #[test]
+    fn cache_parameters() {
+        // Skylake-X L1d: 32 KiB, 8-way, 64 B lines, shared by 2 threads.
+        let l1d = decode_cache_parameters(0x1c00_4121, 0x01c0_003f, 0x0000_003f).unwrap();
+        assert_eq!(l1d.level(), 1);
+        assert_eq!(l1d.cache_type(), CacheType::Data);
+        assert_eq!(l1d.size(), 32 * 1024);
+        assert_eq!(l1d.line_size(), 64);
+        assert_eq!(l1d.associativity(), 8);
+        assert_eq!(l1d.shared_by(), 2);
+
+        // Skylake-X L2: 1 MiB, 16-way.
+        let l2 = decode_cache_parameters(0x1c00_4143, 0x03c0_003f, 0x0000_03ff).unwrap();
+        assert_eq!(l2.level(), 2);
+        assert_eq!(l2.cache_type(), CacheType::Unified);
+        assert_eq!(l2.size(), 1024 * 1024);
+        assert_eq!(l2.associativity(), 16);
+
+        // Null cache type terminates the list.
+        assert_eq!(decode_cache_parameters(0, 0, 0), None);
+    }
+
+    #[test]
+    fn cache_line_size() {
+        // An Intel CPU whose L2, listed first, has longer lines than its L1d.
+        let leaves = [
+            (0x0, 0, 0x4, 0x756e_6547, 0x6c65_746e, 0x4965_6e69),
+            (0x1, 0, 0x0005_0654, 0x0010_0800, 0, 0),
+            (0x4, 0, 0x0000_0043, 0x03c0_007f, 0x0000_03ff, 0),
+            (0x4, 1, 0x0000_0022, 0x01c0_001f, 0x0000_003f, 0),
+            (0x4, 2, 0x0000_0021, 0x01c0_003f, 0x0000_003f, 0),
+        ];
+        let info = cpu_info_from(&CpuidDump::from_leaves(&leaves));
+        assert_eq!(info.caches().count(), 3);
+        // The L1i has the shortest lines, but isn't a data cache.
+        assert_eq!(info.cache_line_size(), Some(64));
+
+        // Without cache parameters, the `CLFLUSH` line size is used.
+        let info = cpu_info_from(&CpuidDump::from_leaves(&leaves[..2]));
+        assert_eq!(info.caches().count(), 0);
+        assert_eq!(info.cache_line_size(), Some(64));
+    }
+}
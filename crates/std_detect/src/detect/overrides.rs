@@ -0,0 +1,170 @@
+//! Scoped overrides of the detected features, for testing.
+//!
+//! Code that dispatches on run-time feature detection has one code path per
+//! set of features, but only the path matching the host CPU is exercised by
+//! default. The functions in this module change the result of feature
+//! detection until the returned guard is dropped, so that a test suite can
+//! exercise every path in a single process.
+//!
+//! The overrides are global to the process: they are observed by all the
+//! threads, and by the `is_{arch}_feature_detected!` macros, `features()`,
+//! and every other query. Tests that use them must not run concurrently with
+//! tests that depend on feature detection, e.g. by serializing them with a
+//! `Mutex`. Results of feature detection that the program has already stored
+//! somewhere else (e.g. a resolved function pointer) are not affected.
+//!
+//! Features that are enabled at compile-time with `-C target-feature` are
+//! always reported as detected by the macros, whatever the overrides say.
+
+use super::cache;
+
+/// Restores the features that were detected before the override when
+/// dropped.
+///
+/// Guards must be dropped in the reverse order of their creation.
+#[must_use = "the override is undone when the guard is dropped"]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct FeatureOverrideGuard {
+    previous: cache::Initializer,
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl Drop for FeatureOverrideGuard {
+    #[inline]
+    fn drop(&mut self) {
+        cache::store(self.previous);
+    }
+}
+
+/// Applies `overrides` on top of the current features, or panics.
+fn apply(overrides: &str, allow_enable: bool) -> FeatureOverrideGuard {
+    let previous = cache::current();
+    let mut value = previous;
+    if let Err(entry) = cache::apply_overrides(&mut value, overrides, allow_enable) {
+        panic!("invalid feature override `{entry}`");
+    }
+    cache::store(value);
+    FeatureOverrideGuard { previous }
+}
+
+/// Makes feature detection report the features in `features` as not
+/// available until the returned guard is dropped.
+///
+/// `features` is a whitespace separated list of feature names, as accepted
+/// by the `is_{arch}_feature_detected!` macro of the current target. Each
+/// name can optionally be prefixed with `-`.
+///
+/// # Panics
+///
+/// Panics if `features` contains an unknown feature name, or a name prefixed
+/// with `+`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let _guard = std_detect::detect::disable_features("avx2 avx512f");
+/// assert!(!is_x86_feature_detected!("avx2"));
+/// ```
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn disable_features(features: &str) -> FeatureOverrideGuard {
+    apply(features, false)
+}
+
+/// Makes feature detection report the features in `overrides` as available
+/// or not until the returned guard is dropped.
+///
+/// `overrides` is a whitespace separated list of feature names, where names
+/// prefixed with `+` are enabled, and names prefixed with `-` or without
+/// prefix are disabled. Unlike the `RUST_STD_DETECT_UNSTABLE` environment
+/// variable, which can only disable features, this can enable features the
+/// CPU does not support.
+///
+/// # Safety
+///
+/// Enabling a feature that the CPU does not support leads to code using
+/// instructions that do not exist, which is undefined behavior. The caller
+/// must ensure that the code that runs while the override is active does not
+/// execute such instructions.
+///
+/// # Panics
+///
+/// Panics if `overrides` contains an unknown feature name.
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub unsafe fn override_features(overrides: &str) -> FeatureOverrideGuard {
+    apply(overrides, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::features;
+    use std::sync::{Mutex, MutexGuard};
+
+    /// Serializes the tests, since the overrides are global.
+    fn lock() -> MutexGuard<'static, ()> {
+        static LOCK: Mutex<()> = Mutex::new(());
+        // One of the tests panics on purpose while holding the lock.
+        LOCK.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn detected(name: &str) -> bool {
+        features().find(|&(f, _)| f == name).unwrap().1
+    }
+
+    #[test]
+    fn disable_and_restore() {
+        let _lock = lock();
+        let Some((name, _)) = features().find(|&(_, enabled)| enabled) else {
+            return;
+        };
+        {
+            let _guard = disable_features(name);
+            assert!(!detected(name));
+        }
+        assert!(detected(name));
+    }
+
+    #[test]
+    fn nested() {
+        let _lock = lock();
+        let enabled: std::vec::Vec<_> = features().filter(|&(_, e)| e).map(|(f, _)| f).collect();
+        if enabled.len() < 2 {
+            return;
+        }
+        let (a, b) = (enabled[0], enabled[1]);
+        let outer = disable_features(a);
+        let inner = disable_features(&format!("-{b}"));
+        assert!(!detected(a));
+        assert!(!detected(b));
+        drop(inner);
+        assert!(!detected(a));
+        assert!(detected(b));
+        drop(outer);
+        assert!(detected(a));
+        assert!(detected(b));
+    }
+
+    #[test]
+    fn enable() {
+        let _lock = lock();
+        let Some((name, _)) = features().find(|&(_, enabled)| !enabled) else {
+            return;
+        };
+        {
+            // Nothing in this scope executes instructions of `name`.
+            let _guard = unsafe { override_features(&format!("+{name}")) };
+            assert!(detected(name));
+        }
+        assert!(!detected(name));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid feature override `+")]
+    fn disable_rejects_enable() {
+        let _lock = lock();
+        let (name, _) = features().next().unwrap();
+        let _guard = disable_features(&format!("+{name}"));
+    }
+}
@@ -0,0 +1,187 @@
+//! AVX10 version and vector length queries.
+//!
+//! AVX10 is a converged vector ISA that is enumerated by a version number
+//! instead of by individual feature bits: each version is a superset of the
+//! previous ones. CPUID leaf 7 sub-leaf 1 EDX\[19\] reports whether AVX10 is
+//! supported, and leaf 0x24 reports the version and the vector lengths.
+//!
+//! AVX10 uses the AVX-512 register state at every vector length, so it is
+//! only usable if the OS enabled the `opmask`, `ZMM_Hi256` and `Hi16_ZMM`
+//! state components in `XCR0`.
+//!
+//! References:
+//!
+//! - [Intel Advanced Vector Extensions 10 Architecture Specification][avx10_ref].
+//!
+//! [avx10_ref]: https://www.intel.com/content/www/us/en/content-details/784267/intel-advanced-vector-extensions-10-intel-avx10-architecture-specification.html
+
+use super::os::{CpuidSource, HostCpu};
+use super::{check_for, Feature};
+
+/// The AVX10 version and maximum vector length supported by an x86 CPU.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct X86Avx10 {
+    pub(crate) version: u8,
+    pub(crate) max_vector_length: u32,
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl X86Avx10 {
+    /// AVX10 version, starting at 1.
+    ///
+    /// Each version includes all the instructions of the previous ones.
+    #[inline]
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Maximum vector length in bits: 256 or 512.
+    #[inline]
+    pub fn max_vector_length(&self) -> u32 {
+        self.max_vector_length
+    }
+}
+
+/// Returns the AVX10 version and maximum vector length supported by the CPU
+/// and the OS, or `None` if AVX10 is not available.
+///
+/// This is consistent with `is_x86_feature_detected!("avx10.1-256")` and
+/// `is_x86_feature_detected!("avx10.1-512")`, including when those features
+/// are disabled with `RUST_STD_DETECT_UNSTABLE`.
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn x86_avx10() -> Option<X86Avx10> {
+    // Run-time detection has already checked that CPUID is available.
+    if !check_for(Feature::avx10_1_256) {
+        return None;
+    }
+    let mut avx10 = decode_avx10(&HostCpu)?;
+    if !check_for(Feature::avx10_1_512) {
+        avx10.max_vector_length = avx10.max_vector_length.min(256);
+    }
+    Some(avx10)
+}
+
+/// Decodes the AVX10 version and maximum vector length from CPUID leaf 0x24.
+///
+/// This only checks CPU support: callers must check that the OS enabled the
+/// AVX-512 state components.
+pub(crate) fn decode_avx10(cpu: &impl CpuidSource) -> Option<X86Avx10> {
+    if cpu.cpuid(0, 0).eax < 0x24 || cpu.cpuid(7, 0).eax < 1 || cpu.cpuid(7, 1).edx & (1 << 19) == 0
+    {
+        return None;
+    }
+    let ebx = cpu.cpuid(0x24, 0).ebx;
+    let version = (ebx & 0xff) as u8;
+    let max_vector_length = if ebx & (1 << 18) != 0 {
+        512
+    } else if ebx & (1 << 17) != 0 {
+        256
+    } else {
+        // Every AVX10 implementation supports at least 256-bit vectors.
+        return None;
+    };
+    (version >= 1).then_some(X86Avx10 {
+        version,
+        max_vector_length,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::os::{detect_features_from, CpuidDump};
+    use super::*;
+
+    /// The leaves read by the detection of AVX10.1 with vectors of up to
+    /// `max_vector_length` bits, as documented in the AVX10 specification.
+    ///
+    /// No CPUID dump of an AVX10 CPU, e.g. Granite Rapids, is available, so
+    /// this only has the leaves that AVX10 detection depends on: leaf 7
+    /// sub-leaf 1 reports AVX10 and no other extension, and XCR0 enables the
+    /// AVX-512 state.
+    fn avx10_1(max_vector_length: u32) -> CpuidDump {
+        // Version 1 in EBX[7:0]; EBX[17] reports 256-bit vectors, and EBX[18]
+        // 512-bit vectors.
+        let lengths = if max_vector_length == 512 { 0b11 } else { 0b01 };
+        CpuidDump::from_leaves(&[
+            (0x0, 0, 0x0000_0024, 0x756e_6547, 0x6c65_746e, 0x4965_6e69),
+            // OSXSAVE and XSAVE.
+            (0x1, 0, 0x000a_06d0, 0x0000_0800, 0x0c00_0000, 0x0000_0000),
+            (0x7, 0, 0x0000_0001, 0x0000_0000, 0x0000_0000, 0x0000_0000),
+            (0x7, 1, 0x0000_0000, 0x0000_0000, 0x0000_0000, 1 << 19),
+            (
+                0x24,
+                0,
+                0x0000_0000,
+                lengths << 17 | 1,
+                0x0000_0000,
+                0x0000_0000,
+            ),
+        ])
+        .with_xcr0(0xe7)
+    }
+
+    const EMERALD_RAPIDS_KVM: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/src/detect/test_data/x86/emerald-rapids-kvm.json"
+    ));
+
+    #[test]
+    fn avx10_512() {
+        assert_eq!(
+            decode_avx10(&avx10_1(512)),
+            Some(X86Avx10 {
+                version: 1,
+                max_vector_length: 512,
+            })
+        );
+    }
+
+    #[test]
+    fn avx10_256() {
+        assert_eq!(
+            decode_avx10(&avx10_1(256)),
+            Some(X86Avx10 {
+                version: 1,
+                max_vector_length: 256,
+            })
+        );
+    }
+
+    #[test]
+    fn features() {
+        let value = detect_features_from(&avx10_1(512));
+        assert!(value.test(Feature::avx10_1_256 as u32));
+        assert!(value.test(Feature::avx10_1_512 as u32));
+
+        let value = detect_features_from(&avx10_1(256));
+        assert!(value.test(Feature::avx10_1_256 as u32));
+        assert!(!value.test(Feature::avx10_1_512 as u32));
+    }
+
+    #[test]
+    fn no_avx10() {
+        assert_eq!(decode_avx10(&CpuidDump::parse(EMERALD_RAPIDS_KVM)), None);
+    }
+
+    #[test]
+    fn host() {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::__cpuid_count;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::__cpuid_count;
+
+        let avx10 = x86_avx10();
+        assert_eq!(avx10.is_some(), check_for(Feature::avx10_1_256));
+        if let Some(avx10) = avx10 {
+            // Leaf 0x24 is read directly rather than through `decode_avx10`.
+            let ebx = unsafe { __cpuid_count(0x24, 0) }.ebx;
+            assert_eq!(avx10.version(), (ebx & 0xff) as u8);
+            assert_eq!(
+                avx10.max_vector_length() == 512,
+                check_for(Feature::avx10_1_512)
+            );
+        }
+    }
+}
@@ -0,0 +1,434 @@
+//! x86 CPU topology and hybrid core type queries.
+//!
+//! The topology of a logical CPU is derived from its x2APIC ID, which CPUID
+//! splits into fields identifying the SMT thread, the core and the package:
+//!
+//! - leaf 0x1F ("V2 Extended Topology Enumeration") or, if it is not
+//!   available, leaf 0xB ("Extended Topology Enumeration") report the width
+//!   of each of these fields;
+//! - leaf 4 (Intel) or 0x8000_001D (AMD) report how many APIC IDs share each
+//!   cache, from which the ID of the L2 and L3 instances follow;
+//! - leaf 0x1A reports whether the logical CPU is a Performance or an
+//!   Efficiency core on hybrid CPUs.
+//!
+//! CPUID only describes the logical CPU that executes it, so the topology of
+//! the whole system is obtained by running it on each logical CPU in turn.
+//!
+//! References:
+//!
+//! - "Intel 64 Architecture Processor Topology Enumeration" white paper.
+//! - [Intel 64 and IA-32 Architectures Software Developer's Manual Volume 2][intel64_ref], CPUID.
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::CpuidResult;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::CpuidResult;
+
+use super::os::{
+    cache_parameters_leaf, decode_cache_parameters, hybrid_core_type, CpuidSource, HostCpu,
+};
+use super::{CacheType, CoreType};
+
+/// Topology of one x86 logical CPU.
+///
+/// Two logical CPUs with the same package and core IDs are SMT siblings, and
+/// two logical CPUs with the same L2 (or L3) ID share that cache.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct X86CpuTopology {
+    x2apic_id: u32,
+    smt_id: u32,
+    core_id: u32,
+    package_id: u32,
+    core_type: Option<CoreType>,
+    l2_id: Option<u32>,
+    l3_id: Option<u32>,
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl X86CpuTopology {
+    /// The x2APIC ID (or the initial APIC ID on CPUs without x2APIC
+    /// topology enumeration) of the logical CPU.
+    #[inline]
+    pub fn x2apic_id(&self) -> u32 {
+        self.x2apic_id
+    }
+
+    /// Index of the logical CPU within its core.
+    #[inline]
+    pub fn smt_id(&self) -> u32 {
+        self.smt_id
+    }
+
+    /// Index of the core within its package.
+    ///
+    /// Core indices are not necessarily contiguous.
+    #[inline]
+    pub fn core_id(&self) -> u32 {
+        self.core_id
+    }
+
+    /// Index of the package (socket).
+    #[inline]
+    pub fn package_id(&self) -> u32 {
+        self.package_id
+    }
+
+    /// Type of the core on hybrid CPUs, `None` on other CPUs.
+    #[inline]
+    pub fn core_type(&self) -> Option<CoreType> {
+        self.core_type
+    }
+
+    /// Identifier of the L2 cache instance used by the logical CPU.
+    ///
+    /// This is the lowest x2APIC ID of the logical CPUs that can share it.
+    #[inline]
+    pub fn l2_id(&self) -> Option<u32> {
+        self.l2_id
+    }
+
+    /// Identifier of the L3 cache instance used by the logical CPU.
+    ///
+    /// This is the lowest x2APIC ID of the logical CPUs that can share it.
+    #[inline]
+    pub fn l3_id(&self) -> Option<u32> {
+        self.l3_id
+    }
+}
+
+/// Returns the topology of the logical CPU the current thread is running on.
+///
+/// The thread can be migrated to another logical CPU at any time, so the
+/// result is only meaningful if the thread is pinned to a single CPU.
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn x86_cpu_topology() -> X86CpuTopology {
+    // CPUID is available on all the CPUs that can run x86_64 code, and on all
+    // the CPUs that are supported by the x86 targets.
+    decode_topology(&HostCpu)
+}
+
+/// Calls `f` with the index and the topology of each logical CPU that the
+/// current thread is allowed to run on.
+///
+/// This pins the current thread to each CPU in turn, and restores its
+/// original CPU affinity before returning. Returns `false` if the affinity of
+/// the thread could not be queried or changed.
+#[cfg(all(any(target_os = "linux", target_os = "android"), feature = "libc"))]
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn for_each_x86_cpu_topology(mut f: impl FnMut(usize, X86CpuTopology)) -> bool {
+    use core::mem;
+
+    unsafe {
+        let size = mem::size_of::<libc::cpu_set_t>();
+        let mut original: libc::cpu_set_t = mem::zeroed();
+        if libc::sched_getaffinity(0, size, &mut original) != 0 {
+            return false;
+        }
+        let mut ok = true;
+        for cpu in 0..libc::CPU_SETSIZE as usize {
+            if !libc::CPU_ISSET(cpu, &original) {
+                continue;
+            }
+            let mut single: libc::cpu_set_t = mem::zeroed();
+            libc::CPU_SET(cpu, &mut single);
+            if libc::sched_setaffinity(0, size, &single) != 0 {
+                ok = false;
+                break;
+            }
+            f(cpu, x86_cpu_topology());
+        }
+        libc::sched_setaffinity(0, size, &original) == 0 && ok
+    }
+}
+
+/// Number of bits needed to represent `n` distinct values.
+fn ceil_log2(n: u32) -> u32 {
+    match n {
+        0 | 1 => 0,
+        n => 32 - (n - 1).leading_zeros(),
+    }
+}
+
+/// Decodes the topology of a logical CPU from its CPUID leaves.
+pub(crate) fn decode_topology(cpu: &impl CpuidSource) -> X86CpuTopology {
+    let cpuid = |leaf, subleaf| cpu.cpuid(leaf, subleaf);
+    let CpuidResult {
+        eax: max_basic_leaf,
+        ebx: vendor0,
+        ..
+    } = cpuid(0, 0);
+    // "Genu" of "GenuineIntel".
+    let is_intel = vendor0 == 0x756e_6547;
+    let CpuidResult {
+        ebx: proc_info_ebx,
+        edx: proc_info_edx,
+        ..
+    } = cpuid(1, 0);
+
+    // Prefer leaf 0x1F, which also enumerates module, tile and die levels,
+    // over leaf 0xB. A leaf is valid if sub-leaf 0 reports processors.
+    let topology_leaf = [0x1f, 0xb]
+        .into_iter()
+        .find(|&leaf| max_basic_leaf >= leaf && cpuid(leaf, 0).ebx != 0);
+
+    let (x2apic_id, smt_shift, package_shift) = match topology_leaf {
+        Some(leaf) => {
+            let mut x2apic_id = 0;
+            let mut smt_shift = 0;
+            let mut package_shift = 0;
+            for subleaf in 0..8 {
+                let CpuidResult { eax, ecx, edx, .. } = cpuid(leaf, subleaf);
+                let level_type = (ecx >> 8) & 0xff;
+                if level_type == 0 {
+                    break;
+                }
+                x2apic_id = edx;
+                let shift = eax & 0x1f;
+                // Level type 1 is SMT; all the levels above it up to the
+                // package are folded into the "core" field.
+                if level_type == 1 {
+                    smt_shift = shift;
+                }
+                package_shift = shift;
+            }
+            (x2apic_id, smt_shift, package_shift)
+        }
+        None => {
+            // Legacy enumeration: leaf 1 reports the number of addressable
+            // logical processor IDs per package if HTT is set, and leaf 4
+            // the number of addressable core IDs per package on Intel.
+            let x2apic_id = proc_info_ebx >> 24;
+            let logical_per_package = if proc_info_edx & (1 << 28) != 0 {
+                (proc_info_ebx >> 16) & 0xff
+            } else {
+                1
+            };
+            let cores_per_package = if is_intel && max_basic_leaf >= 4 {
+                (cpuid(4, 0).eax >> 26) + 1
+            } else {
+                logical_per_package
+            };
+            let package_shift = ceil_log2(logical_per_package);
+            let smt_shift = ceil_log2(logical_per_package / cores_per_package.max(1));
+            (x2apic_id, smt_shift, package_shift)
+        }
+    };
+
+    let mask = |shift: u32| (1_u32.checked_shl(shift).unwrap_or(0)).wrapping_sub(1);
+    let smt_id = x2apic_id & mask(smt_shift);
+    let core_id = (x2apic_id & mask(package_shift)) >> smt_shift;
+    let package_id = x2apic_id.checked_shr(package_shift).unwrap_or(0);
+
+    let mut l2_id = None;
+    let mut l3_id = None;
+    if let Some(leaf) = cache_parameters_leaf(cpu) {
+        for subleaf in 0..32 {
+            let CpuidResult { eax, ebx, ecx, .. } = cpuid(leaf, subleaf);
+            let Some(cache) = decode_cache_parameters(eax, ebx, ecx) else {
+                break;
+            };
+            if cache.cache_type() == CacheType::Instruction {
+                continue;
+            }
+            let sharing_shift = ceil_log2(cache.shared_by());
+            let id = Some(x2apic_id & !mask(sharing_shift));
+            match cache.level() {
+                2 => l2_id = id,
+                3 => l3_id = id,
+                _ => {}
+            }
+        }
+    }
+
+    X86CpuTopology {
+        x2apic_id,
+        smt_id,
+        core_id,
+        package_id,
+        core_type: hybrid_core_type(cpu),
+        l2_id,
+        l3_id,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::os::CpuidDump;
+    use super::*;
+
+    macro_rules! dump {
+        ($name:literal) => {
+            CpuidDump::parse(include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/src/detect/test_data/x86/",
+                $name,
+                ".cpuid"
+            )))
+        };
+    }
+
+    #[test]
+    fn alder_lake_p_core() {
+        // The dump was recorded on the first thread of the first P-core.
+        let t = decode_topology(&dump!("alder-lake"));
+        assert_eq!(t.x2apic_id(), 0);
+        assert_eq!(t.smt_id(), 0);
+        assert_eq!(t.core_id(), 0);
+        assert_eq!(t.package_id(), 0);
+        assert_eq!(t.core_type(), Some(CoreType::Performance));
+        assert_eq!(t.l2_id(), Some(0));
+        assert_eq!(t.l3_id(), Some(0));
+    }
+
+    #[test]
+    fn hybrid_e_core() {
+        // The leaves that describe the topology, as the E-core with x2APIC ID
+        // 0x2a of an Alder Lake CPU reports them: leaves 1, 0xB and 0x1F
+        // report the APIC ID of the logical CPU, leaf 0x1A its core type, and
+        // leaf 4 an L2 shared by the 4 E-cores of a module.
+        let t = decode_topology(&CpuidDump::from_leaves(&[
+            (0x0, 0, 0x0000_0020, 0x756e_6547, 0x6c65_746e, 0x4965_6e69),
+            (0x1, 0, 0x0009_0672, 0x2a80_0800, 0x7ffa_fbff, 0xbfeb_fbff),
+            (0x4, 0, 0xfc00_4121, 0x02c0_003f, 0x0000_003f, 0x0000_0000),
+            (0x4, 1, 0xfc00_4122, 0x01c0_003f, 0x0000_003f, 0x0000_0000),
+            (0x4, 2, 0xfc00_c143, 0x03c0_003f, 0x0000_07ff, 0x0000_0000),
+            (0x4, 3, 0xfc1f_c163, 0x0240_003f, 0x0000_9fff, 0x0000_0004),
+            (0x7, 0, 0x0000_0002, 0x239c_27eb, 0x98c0_27bc, 0xfc1c_c410),
+            (0xb, 0, 0x0000_0001, 0x0000_0001, 0x0000_0100, 0x0000_002a),
+            (0xb, 1, 0x0000_0007, 0x0000_0014, 0x0000_0201, 0x0000_002a),
+            (0x1a, 0, 0x2000_0001, 0x0000_0000, 0x0000_0000, 0x0000_0000),
+            (0x1f, 0, 0x0000_0001, 0x0000_0001, 0x0000_0100, 0x0000_002a),
+            (0x1f, 1, 0x0000_0007, 0x0000_0014, 0x0000_0201, 0x0000_002a),
+            (0x1f, 2, 0x0000_0000, 0x0000_0000, 0x0000_0002, 0x0000_002a),
+        ]));
+        assert_eq!(t.x2apic_id(), 0x2a);
+        assert_eq!(t.smt_id(), 0);
+        assert_eq!(t.core_id(), 0x15);
+        assert_eq!(t.package_id(), 0);
+        assert_eq!(t.core_type(), Some(CoreType::Efficiency));
+        assert_eq!(t.l2_id(), Some(0x28));
+        assert_eq!(t.l3_id(), Some(0));
+    }
+
+    #[test]
+    fn cascade_lake() {
+        // Second thread of a core in the fourth socket; leaf 0x1F is not
+        // supported, so leaf 0xB is used.
+        let t = decode_topology(&dump!("cascade-lake"));
+        assert_eq!(t.x2apic_id(), 0xc7);
+        assert_eq!(t.smt_id(), 1);
+        assert_eq!(t.core_id(), 3);
+        assert_eq!(t.package_id(), 3);
+        assert_eq!(t.core_type(), None);
+        assert_eq!(t.l2_id(), Some(0xc6));
+        assert_eq!(t.l3_id(), Some(0xc0));
+    }
+
+    #[test]
+    fn zen2() {
+        // AMD reports the caches in leaf 0x8000_001D.
+        let t = decode_topology(&dump!("zen2"));
+        assert_eq!(t.x2apic_id(), 0);
+        assert_eq!(t.core_type(), None);
+        assert_eq!(t.l2_id(), Some(0));
+        assert_eq!(t.l3_id(), Some(0));
+    }
+
+    #[test]
+    fn legacy_enumeration() {
+        // A dual-core CPU with only the legacy enumeration through leaves 1
+        // and 4, as on the Core 2: the second core reports initial APIC ID 1
+        // in leaf 1, and leaf 4 reports 2 cores per package and an L2 shared
+        // by both.
+        let t = decode_topology(&CpuidDump::from_leaves(&[
+            (0x0, 0, 0x0000_000d, 0x756e_6547, 0x6c65_746e, 0x4965_6e69),
+            (0x1, 0, 0x0001_067a, 0x0102_0800, 0x0408_e3fd, 0xbfeb_fbff),
+            (0x4, 0, 0x0400_0121, 0x01c0_003f, 0x0000_003f, 0x0000_0001),
+            (0x4, 1, 0x0400_0122, 0x01c0_003f, 0x0000_003f, 0x0000_0001),
+            (0x4, 2, 0x0400_4143, 0x01c0_003f, 0x0000_0fff, 0x0000_0001),
+        ]));
+        assert_eq!(t.x2apic_id(), 1);
+        assert_eq!(t.smt_id(), 0);
+        assert_eq!(t.core_id(), 1);
+        assert_eq!(t.package_id(), 0);
+        assert_eq!(t.core_type(), None);
+        assert_eq!(t.l2_id(), Some(0));
+        assert_eq!(t.l3_id(), None);
+    }
+
+    #[test]
+    fn ceil_log2_values() {
+        assert_eq!(ceil_log2(0), 0);
+        assert_eq!(ceil_log2(1), 0);
+        assert_eq!(ceil_log2(2), 1);
+        assert_eq!(ceil_log2(3), 2);
+        assert_eq!(ceil_log2(8), 3);
+        assert_eq!(ceil_log2(128), 7);
+    }
+
+    /// Checks the properties that hold for the topology of any logical CPU.
+    fn check_host_cpu(t: X86CpuTopology) {
+        // Cache IDs are the lowest x2APIC ID of the CPUs sharing the cache.
+        assert!(t.l2_id().map_or(true, |id| id <= t.x2apic_id()), "{t:?}");
+        assert!(t.l3_id().map_or(true, |id| id <= t.x2apic_id()), "{t:?}");
+        assert!(t.l3_id() <= t.l2_id() || t.l3_id().is_none(), "{t:?}");
+        assert_eq!(t.core_type(), hybrid_core_type(&HostCpu), "{t:?}");
+    }
+
+    #[test]
+    fn host() {
+        check_host_cpu(x86_cpu_topology());
+    }
+
+    #[cfg(all(any(target_os = "linux", target_os = "android"), feature = "libc"))]
+    #[test]
+    fn host_all_cpus() {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::__cpuid_count;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::__cpuid_count;
+        use std::collections::HashSet;
+        use std::vec::Vec;
+
+        let mut cpus = Vec::new();
+        assert!(for_each_x86_cpu_topology(|_, t| {
+            // The thread is pinned while `f` runs: the x2APIC ID must be the
+            // one reported by leaf 0xB, or the initial APIC ID of leaf 1.
+            let expected = unsafe {
+                if __cpuid_count(0, 0).eax >= 0xb && __cpuid_count(0xb, 0).ebx != 0 {
+                    __cpuid_count(0xb, 0).edx
+                } else {
+                    __cpuid_count(1, 0).ebx >> 24
+                }
+            };
+            assert_eq!(t.x2apic_id(), expected, "{t:?}");
+            check_host_cpu(t);
+            cpus.push(t);
+        }));
+        assert!(!cpus.is_empty());
+
+        // Each logical CPU has a distinct x2APIC ID and position.
+        let ids: HashSet<_> = cpus.iter().map(|t| t.x2apic_id()).collect();
+        assert_eq!(ids.len(), cpus.len());
+        let positions: HashSet<_> = cpus
+            .iter()
+            .map(|t| (t.package_id(), t.core_id(), t.smt_id()))
+            .collect();
+        assert_eq!(positions.len(), cpus.len());
+
+        // SMT siblings share their core's type and caches.
+        for a in &cpus {
+            for b in &cpus {
+                if (a.package_id(), a.core_id()) == (b.package_id(), b.core_id()) {
+                    assert_eq!(a.core_type(), b.core_type(), "{a:?} {b:?}");
+                    assert_eq!(a.l2_id(), b.l2_id(), "{a:?} {b:?}");
+                }
+            }
+        }
+    }
+}
@@ -11,9 +11,17 @@
 //! * `mips64`: [`is_mips64_feature_detected`]
 //! * `powerpc`: [`is_powerpc_feature_detected`]
 //! * `powerpc64`: [`is_powerpc64_feature_detected`]
+//! * `s390x`: [`is_s390x_feature_detected`]
+//! * `loongarch64`: [`is_loongarch_feature_detected`]
 
 #![unstable(feature = "stdsimd", issue = "27731")]
-#![feature(staged_api, stdsimd, doc_cfg, allow_internal_unstable)]
+#![feature(
+    staged_api,
+    stdsimd,
+    doc_cfg,
+    allow_internal_unstable,
+    macro_metavar_expr
+)]
 #![deny(rust_2018_idioms)]
 #![allow(clippy::shadow_reuse)]
 #![deny(clippy::missing_inline_in_public_items)]
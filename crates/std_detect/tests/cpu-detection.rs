@@ -5,8 +5,12 @@
     target_arch = "aarch64",
     target_arch = "x86",
     target_arch = "x86_64",
+    target_arch = "mips",
+    target_arch = "mips64",
     target_arch = "powerpc",
-    target_arch = "powerpc64"
+    target_arch = "powerpc64",
+    target_arch = "s390x",
+    target_arch = "loongarch64"
 ))]
 
 #[macro_use]
@@ -17,6 +21,7 @@ fn all() {
     for (f, e) in std_detect::detect::features() {
         println!("{f}: {e}");
     }
+    println!("{:#?}", std_detect::detect::cpu_info());
 }
 
 #[test]
@@ -92,6 +97,17 @@ fn aarch64_linux() {
     println!("sha2: {}", is_aarch64_feature_detected!("sha2"));
     println!("sha3: {}", is_aarch64_feature_detected!("sha3"));
     println!("sm4: {}", is_aarch64_feature_detected!("sm4"));
+    println!("afp: {}", is_aarch64_feature_detected!("afp"));
+    println!("rpres: {}", is_aarch64_feature_detected!("rpres"));
+    println!("wfxt: {}", is_aarch64_feature_detected!("wfxt"));
+    println!("ebf16: {}", is_aarch64_feature_detected!("ebf16"));
+    println!("cssc: {}", is_aarch64_feature_detected!("cssc"));
+    println!("rprfm: {}", is_aarch64_feature_detected!("rprfm"));
+    println!("mops: {}", is_aarch64_feature_detected!("mops"));
+    println!("hbc: {}", is_aarch64_feature_detected!("hbc"));
+    println!("sve2p1: {}", is_aarch64_feature_detected!("sve2p1"));
+    println!("sme: {}", is_aarch64_feature_detected!("sme"));
+    println!("sme2: {}", is_aarch64_feature_detected!("sme2"));
 }
 
 #[test]
@@ -133,12 +149,59 @@ fn aarch64_bsd() {
     println!("sha2: {:?}", is_aarch64_feature_detected!("sha2"));
 }
 
+#[test]
+#[cfg(all(target_arch = "mips", target_os = "linux"))]
+fn mips_linux() {
+    println!("msa: {}", is_mips_feature_detected!("msa"));
+    println!("dsp: {}", is_mips_feature_detected!("dsp"));
+    println!("dspr2: {}", is_mips_feature_detected!("dspr2"));
+    println!("dspr3: {}", is_mips_feature_detected!("dspr3"));
+}
+
+#[test]
+#[cfg(all(target_arch = "mips64", target_os = "linux"))]
+fn mips64_linux() {
+    println!("msa: {}", is_mips64_feature_detected!("msa"));
+    println!("dsp: {}", is_mips64_feature_detected!("dsp"));
+    println!("dspr2: {}", is_mips64_feature_detected!("dspr2"));
+    println!("dspr3: {}", is_mips64_feature_detected!("dspr3"));
+}
+
 #[test]
 #[cfg(all(target_arch = "powerpc", target_os = "linux"))]
 fn powerpc_linux() {
     println!("altivec: {}", is_powerpc_feature_detected!("altivec"));
     println!("vsx: {}", is_powerpc_feature_detected!("vsx"));
     println!("power8: {}", is_powerpc_feature_detected!("power8"));
+    println!(
+        "power8-altivec: {}",
+        is_powerpc_feature_detected!("power8-altivec")
+    );
+    println!(
+        "power8-vector: {}",
+        is_powerpc_feature_detected!("power8-vector")
+    );
+    println!(
+        "power8-crypto: {}",
+        is_powerpc_feature_detected!("power8-crypto")
+    );
+    println!(
+        "power9-altivec: {}",
+        is_powerpc_feature_detected!("power9-altivec")
+    );
+    println!(
+        "power9-vector: {}",
+        is_powerpc_feature_detected!("power9-vector")
+    );
+    println!(
+        "power10-vector: {}",
+        is_powerpc_feature_detected!("power10-vector")
+    );
+    println!("mma: {}", is_powerpc_feature_detected!("mma"));
+    println!("crypto: {}", is_powerpc_feature_detected!("crypto"));
+    println!("htm: {}", is_powerpc_feature_detected!("htm"));
+    println!("darn: {}", is_powerpc_feature_detected!("darn"));
+    println!("scv: {}", is_powerpc_feature_detected!("scv"));
 }
 
 #[test]
@@ -147,6 +210,62 @@ fn powerpc64_linux() {
     println!("altivec: {}", is_powerpc64_feature_detected!("altivec"));
     println!("vsx: {}", is_powerpc64_feature_detected!("vsx"));
     println!("power8: {}", is_powerpc64_feature_detected!("power8"));
+    println!(
+        "power8-altivec: {}",
+        is_powerpc64_feature_detected!("power8-altivec")
+    );
+    println!(
+        "power8-vector: {}",
+        is_powerpc64_feature_detected!("power8-vector")
+    );
+    println!(
+        "power8-crypto: {}",
+        is_powerpc64_feature_detected!("power8-crypto")
+    );
+    println!(
+        "power9-altivec: {}",
+        is_powerpc64_feature_detected!("power9-altivec")
+    );
+    println!(
+        "power9-vector: {}",
+        is_powerpc64_feature_detected!("power9-vector")
+    );
+    println!(
+        "power10-vector: {}",
+        is_powerpc64_feature_detected!("power10-vector")
+    );
+    println!("mma: {}", is_powerpc64_feature_detected!("mma"));
+    println!("crypto: {}", is_powerpc64_feature_detected!("crypto"));
+    println!("htm: {}", is_powerpc64_feature_detected!("htm"));
+    println!("darn: {}", is_powerpc64_feature_detected!("darn"));
+    println!("scv: {}", is_powerpc64_feature_detected!("scv"));
+}
+
+#[test]
+#[cfg(all(target_arch = "s390x", target_os = "linux"))]
+fn s390x_linux() {
+    println!("vector: {}", is_s390x_feature_detected!("vector"));
+    println!(
+        "vector-enhancements-1: {}",
+        is_s390x_feature_detected!("vector-enhancements-1")
+    );
+    println!(
+        "vector-enhancements-2: {}",
+        is_s390x_feature_detected!("vector-enhancements-2")
+    );
+    println!("nnp-assist: {}", is_s390x_feature_detected!("nnp-assist"));
+}
+
+#[test]
+#[cfg(all(target_arch = "loongarch64", target_os = "linux"))]
+fn loongarch_linux() {
+    println!("f: {}", is_loongarch_feature_detected!("f"));
+    println!("d: {}", is_loongarch_feature_detected!("d"));
+    println!("lsx: {}", is_loongarch_feature_detected!("lsx"));
+    println!("lasx: {}", is_loongarch_feature_detected!("lasx"));
+    println!("lbt: {}", is_loongarch_feature_detected!("lbt"));
+    println!("lvz: {}", is_loongarch_feature_detected!("lvz"));
+    println!("ual: {}", is_loongarch_feature_detected!("ual"));
 }
 
 #[test]
@@ -222,3 +341,68 @@ fn x86_deprecated() {
         is_x86_feature_detected!("avx512vpclmulqdq")
     );
 }
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86_dispatch {
+    use std_detect::detect::Dispatch;
+
+    fn count_ones_fallback(x: &[u64]) -> u32 {
+        x.iter().map(|x| x.count_ones()).sum()
+    }
+
+    #[target_feature(enable = "popcnt")]
+    unsafe fn count_ones_popcnt(x: &[u64]) -> u32 {
+        x.iter().map(|x| x.count_ones()).sum()
+    }
+
+    static COUNT_ONES: Dispatch<unsafe fn(&[u64]) -> u32> = Dispatch::new(|| {
+        if is_x86_feature_detected!("popcnt", "sse4.2") {
+            count_ones_popcnt
+        } else {
+            count_ones_fallback
+        }
+    });
+
+    #[test]
+    fn dispatch() {
+        // SAFETY: `count_ones_popcnt` is only selected if `popcnt` is detected.
+        assert_eq!(unsafe { COUNT_ONES.get()(&[u64::MAX, 1]) }, 65);
+    }
+}
+
+#[cfg(all(
+    target_arch = "aarch64",
+    any(target_os = "linux", target_os = "android")
+))]
+mod aarch64_dispatch {
+    use std_detect::detect::Dispatch;
+
+    fn dot_fallback(a: &[u8], b: &[u8]) -> u32 {
+        a.iter()
+            .zip(b)
+            .map(|(&a, &b)| u32::from(a) * u32::from(b))
+            .sum()
+    }
+
+    #[target_feature(enable = "neon,dotprod")]
+    unsafe fn dot_dotprod(a: &[u8], b: &[u8]) -> u32 {
+        a.iter()
+            .zip(b)
+            .map(|(&a, &b)| u32::from(a) * u32::from(b))
+            .sum()
+    }
+
+    static DOT: Dispatch<unsafe fn(&[u8], &[u8]) -> u32> = Dispatch::new(|| {
+        if is_aarch64_feature_detected!("asimd", "dotprod") {
+            dot_dotprod
+        } else {
+            dot_fallback
+        }
+    });
+
+    #[test]
+    fn dispatch() {
+        // SAFETY: `dot_dotprod` is only selected if `dotprod` is detected.
+        assert_eq!(unsafe { DOT.get()(&[1, 2, 3], &[4, 5, 6]) }, 32);
+    }
+}
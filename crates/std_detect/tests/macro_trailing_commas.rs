@@ -7,7 +7,9 @@
     target_arch = "x86",
     target_arch = "x86_64",
     target_arch = "powerpc",
-    target_arch = "powerpc64"
+    target_arch = "powerpc64",
+    target_arch = "s390x",
+    target_arch = "loongarch64"
 ))]
 #[macro_use]
 extern crate std_detect;
@@ -27,6 +29,8 @@ fn arm_linux() {
 fn aarch64_linux() {
     let _ = is_aarch64_feature_detected!("fp");
     let _ = is_aarch64_feature_detected!("fp",);
+    let _ = is_aarch64_feature_detected!("fp", "asimd");
+    let _ = is_aarch64_feature_detected!("fp", "asimd",);
 }
 
 #[test]
@@ -43,9 +47,25 @@ fn powerpc64_linux() {
     let _ = is_powerpc64_feature_detected!("altivec",);
 }
 
+#[test]
+#[cfg(all(target_arch = "s390x", target_os = "linux"))]
+fn s390x_linux() {
+    let _ = is_s390x_feature_detected!("vector");
+    let _ = is_s390x_feature_detected!("vector",);
+}
+
+#[test]
+#[cfg(all(target_arch = "loongarch64", target_os = "linux"))]
+fn loongarch_linux() {
+    let _ = is_loongarch_feature_detected!("lsx");
+    let _ = is_loongarch_feature_detected!("lsx",);
+}
+
 #[test]
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 fn x86_all() {
     let _ = is_x86_feature_detected!("sse");
     let _ = is_x86_feature_detected!("sse",);
+    let _ = is_x86_feature_detected!("sse", "sse2");
+    let _ = is_x86_feature_detected!("sse", "sse2",);
 }
@@ -66,6 +66,9 @@ fn dump() {
     println!("adx: {:?}", is_x86_feature_detected!("adx"));
     println!("rtm: {:?}", is_x86_feature_detected!("rtm"));
     println!("movbe: {:?}", is_x86_feature_detected!("movbe"));
+    println!("avx10.1-256: {:?}", is_x86_feature_detected!("avx10.1-256"));
+    println!("avx10.1-512: {:?}", is_x86_feature_detected!("avx10.1-512"));
+    println!("avx10: {:?}", std_detect::detect::x86_avx10());
 }
 
 #[cfg(feature = "std_detect_env_override")]
@@ -73,8 +76,8 @@ fn dump() {
 fn env_override_no_avx() {
     if let Ok(disable) = std::env::var("RUST_STD_DETECT_UNSTABLE") {
         let information = cupid::master().unwrap();
-        for d in disable.split(" ") {
-            match d {
+        for d in disable.split_whitespace() {
+            match d.strip_prefix('-').unwrap_or(d) {
                 "avx" => {
                     if information.avx() {
                         assert_ne!(is_x86_feature_detected!("avx"), information.avx())
@@ -91,6 +94,34 @@ fn env_override_no_avx() {
     }
 }
 
+#[test]
+#[allow(deprecated)]
+fn multiple_features() {
+    assert_eq!(
+        is_x86_feature_detected!("avx2", "fma", "bmi2"),
+        is_x86_feature_detected!("avx2")
+            && is_x86_feature_detected!("fma")
+            && is_x86_feature_detected!("bmi2")
+    );
+    assert_eq!(
+        is_x86_feature_detected!("avx512f", "avx512vl", "gfni", "vaes", "avx10.1-512"),
+        is_x86_feature_detected!("avx512f")
+            && is_x86_feature_detected!("avx512vl")
+            && is_x86_feature_detected!("gfni")
+            && is_x86_feature_detected!("vaes")
+            && is_x86_feature_detected!("avx10.1-512")
+    );
+    // Aliases and deprecated names resolve to the same features.
+    assert_eq!(
+        is_x86_feature_detected!("abm", "avx512gfni"),
+        is_x86_feature_detected!("lzcnt", "gfni")
+    );
+    // `sse2` is enabled at compile time on x86_64, so this does not even read
+    // the feature cache.
+    #[cfg(target_arch = "x86_64")]
+    assert!(is_x86_feature_detected!("sse", "sse2"));
+}
+
 #[test]
 fn compare_with_cupid() {
     let information = cupid::master().unwrap();
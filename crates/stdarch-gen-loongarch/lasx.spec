@@ -0,0 +1,3684 @@
+// This code is automatically generated. DO NOT MODIFY.
+// ```
+// OUT_DIR=`pwd`/crates/stdarch-gen-loongarch cargo run -p stdarch-gen-loongarch -- crates/stdarch-gen-loongarch/lasxintrin.h
+// ```
+
+/// lasx_xvsll_b
+name = lasx_xvsll_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvsll_h
+name = lasx_xvsll_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvsll_w
+name = lasx_xvsll_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvsll_d
+name = lasx_xvsll_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvslli_b
+name = lasx_xvslli_b
+asm-fmts = xd, xj, ui3
+data-types = V32QI, V32QI, UQI
+
+/// lasx_xvslli_h
+name = lasx_xvslli_h
+asm-fmts = xd, xj, ui4
+data-types = V16HI, V16HI, UQI
+
+/// lasx_xvslli_w
+name = lasx_xvslli_w
+asm-fmts = xd, xj, ui5
+data-types = V8SI, V8SI, UQI
+
+/// lasx_xvslli_d
+name = lasx_xvslli_d
+asm-fmts = xd, xj, ui6
+data-types = V4DI, V4DI, UQI
+
+/// lasx_xvsra_b
+name = lasx_xvsra_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvsra_h
+name = lasx_xvsra_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvsra_w
+name = lasx_xvsra_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvsra_d
+name = lasx_xvsra_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvsrai_b
+name = lasx_xvsrai_b
+asm-fmts = xd, xj, ui3
+data-types = V32QI, V32QI, UQI
+
+/// lasx_xvsrai_h
+name = lasx_xvsrai_h
+asm-fmts = xd, xj, ui4
+data-types = V16HI, V16HI, UQI
+
+/// lasx_xvsrai_w
+name = lasx_xvsrai_w
+asm-fmts = xd, xj, ui5
+data-types = V8SI, V8SI, UQI
+
+/// lasx_xvsrai_d
+name = lasx_xvsrai_d
+asm-fmts = xd, xj, ui6
+data-types = V4DI, V4DI, UQI
+
+/// lasx_xvsrar_b
+name = lasx_xvsrar_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvsrar_h
+name = lasx_xvsrar_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvsrar_w
+name = lasx_xvsrar_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvsrar_d
+name = lasx_xvsrar_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvsrari_b
+name = lasx_xvsrari_b
+asm-fmts = xd, xj, ui3
+data-types = V32QI, V32QI, UQI
+
+/// lasx_xvsrari_h
+name = lasx_xvsrari_h
+asm-fmts = xd, xj, ui4
+data-types = V16HI, V16HI, UQI
+
+/// lasx_xvsrari_w
+name = lasx_xvsrari_w
+asm-fmts = xd, xj, ui5
+data-types = V8SI, V8SI, UQI
+
+/// lasx_xvsrari_d
+name = lasx_xvsrari_d
+asm-fmts = xd, xj, ui6
+data-types = V4DI, V4DI, UQI
+
+/// lasx_xvsrl_b
+name = lasx_xvsrl_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvsrl_h
+name = lasx_xvsrl_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvsrl_w
+name = lasx_xvsrl_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvsrl_d
+name = lasx_xvsrl_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvsrli_b
+name = lasx_xvsrli_b
+asm-fmts = xd, xj, ui3
+data-types = V32QI, V32QI, UQI
+
+/// lasx_xvsrli_h
+name = lasx_xvsrli_h
+asm-fmts = xd, xj, ui4
+data-types = V16HI, V16HI, UQI
+
+/// lasx_xvsrli_w
+name = lasx_xvsrli_w
+asm-fmts = xd, xj, ui5
+data-types = V8SI, V8SI, UQI
+
+/// lasx_xvsrli_d
+name = lasx_xvsrli_d
+asm-fmts = xd, xj, ui6
+data-types = V4DI, V4DI, UQI
+
+/// lasx_xvsrlr_b
+name = lasx_xvsrlr_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvsrlr_h
+name = lasx_xvsrlr_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvsrlr_w
+name = lasx_xvsrlr_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvsrlr_d
+name = lasx_xvsrlr_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvsrlri_b
+name = lasx_xvsrlri_b
+asm-fmts = xd, xj, ui3
+data-types = V32QI, V32QI, UQI
+
+/// lasx_xvsrlri_h
+name = lasx_xvsrlri_h
+asm-fmts = xd, xj, ui4
+data-types = V16HI, V16HI, UQI
+
+/// lasx_xvsrlri_w
+name = lasx_xvsrlri_w
+asm-fmts = xd, xj, ui5
+data-types = V8SI, V8SI, UQI
+
+/// lasx_xvsrlri_d
+name = lasx_xvsrlri_d
+asm-fmts = xd, xj, ui6
+data-types = V4DI, V4DI, UQI
+
+/// lasx_xvbitclr_b
+name = lasx_xvbitclr_b
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV32QI, UV32QI
+
+/// lasx_xvbitclr_h
+name = lasx_xvbitclr_h
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV16HI, UV16HI
+
+/// lasx_xvbitclr_w
+name = lasx_xvbitclr_w
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV8SI, UV8SI
+
+/// lasx_xvbitclr_d
+name = lasx_xvbitclr_d
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV4DI, UV4DI
+
+/// lasx_xvbitclri_b
+name = lasx_xvbitclri_b
+asm-fmts = xd, xj, ui3
+data-types = UV32QI, UV32QI, UQI
+
+/// lasx_xvbitclri_h
+name = lasx_xvbitclri_h
+asm-fmts = xd, xj, ui4
+data-types = UV16HI, UV16HI, UQI
+
+/// lasx_xvbitclri_w
+name = lasx_xvbitclri_w
+asm-fmts = xd, xj, ui5
+data-types = UV8SI, UV8SI, UQI
+
+/// lasx_xvbitclri_d
+name = lasx_xvbitclri_d
+asm-fmts = xd, xj, ui6
+data-types = UV4DI, UV4DI, UQI
+
+/// lasx_xvbitset_b
+name = lasx_xvbitset_b
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV32QI, UV32QI
+
+/// lasx_xvbitset_h
+name = lasx_xvbitset_h
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV16HI, UV16HI
+
+/// lasx_xvbitset_w
+name = lasx_xvbitset_w
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV8SI, UV8SI
+
+/// lasx_xvbitset_d
+name = lasx_xvbitset_d
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV4DI, UV4DI
+
+/// lasx_xvbitseti_b
+name = lasx_xvbitseti_b
+asm-fmts = xd, xj, ui3
+data-types = UV32QI, UV32QI, UQI
+
+/// lasx_xvbitseti_h
+name = lasx_xvbitseti_h
+asm-fmts = xd, xj, ui4
+data-types = UV16HI, UV16HI, UQI
+
+/// lasx_xvbitseti_w
+name = lasx_xvbitseti_w
+asm-fmts = xd, xj, ui5
+data-types = UV8SI, UV8SI, UQI
+
+/// lasx_xvbitseti_d
+name = lasx_xvbitseti_d
+asm-fmts = xd, xj, ui6
+data-types = UV4DI, UV4DI, UQI
+
+/// lasx_xvbitrev_b
+name = lasx_xvbitrev_b
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV32QI, UV32QI
+
+/// lasx_xvbitrev_h
+name = lasx_xvbitrev_h
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV16HI, UV16HI
+
+/// lasx_xvbitrev_w
+name = lasx_xvbitrev_w
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV8SI, UV8SI
+
+/// lasx_xvbitrev_d
+name = lasx_xvbitrev_d
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV4DI, UV4DI
+
+/// lasx_xvbitrevi_b
+name = lasx_xvbitrevi_b
+asm-fmts = xd, xj, ui3
+data-types = UV32QI, UV32QI, UQI
+
+/// lasx_xvbitrevi_h
+name = lasx_xvbitrevi_h
+asm-fmts = xd, xj, ui4
+data-types = UV16HI, UV16HI, UQI
+
+/// lasx_xvbitrevi_w
+name = lasx_xvbitrevi_w
+asm-fmts = xd, xj, ui5
+data-types = UV8SI, UV8SI, UQI
+
+/// lasx_xvbitrevi_d
+name = lasx_xvbitrevi_d
+asm-fmts = xd, xj, ui6
+data-types = UV4DI, UV4DI, UQI
+
+/// lasx_xvadd_b
+name = lasx_xvadd_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvadd_h
+name = lasx_xvadd_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvadd_w
+name = lasx_xvadd_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvadd_d
+name = lasx_xvadd_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvaddi_bu
+name = lasx_xvaddi_bu
+asm-fmts = xd, xj, ui5
+data-types = V32QI, V32QI, UQI
+
+/// lasx_xvaddi_hu
+name = lasx_xvaddi_hu
+asm-fmts = xd, xj, ui5
+data-types = V16HI, V16HI, UQI
+
+/// lasx_xvaddi_wu
+name = lasx_xvaddi_wu
+asm-fmts = xd, xj, ui5
+data-types = V8SI, V8SI, UQI
+
+/// lasx_xvaddi_du
+name = lasx_xvaddi_du
+asm-fmts = xd, xj, ui5
+data-types = V4DI, V4DI, UQI
+
+/// lasx_xvsub_b
+name = lasx_xvsub_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvsub_h
+name = lasx_xvsub_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvsub_w
+name = lasx_xvsub_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvsub_d
+name = lasx_xvsub_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvsubi_bu
+name = lasx_xvsubi_bu
+asm-fmts = xd, xj, ui5
+data-types = V32QI, V32QI, UQI
+
+/// lasx_xvsubi_hu
+name = lasx_xvsubi_hu
+asm-fmts = xd, xj, ui5
+data-types = V16HI, V16HI, UQI
+
+/// lasx_xvsubi_wu
+name = lasx_xvsubi_wu
+asm-fmts = xd, xj, ui5
+data-types = V8SI, V8SI, UQI
+
+/// lasx_xvsubi_du
+name = lasx_xvsubi_du
+asm-fmts = xd, xj, ui5
+data-types = V4DI, V4DI, UQI
+
+/// lasx_xvmax_b
+name = lasx_xvmax_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvmax_h
+name = lasx_xvmax_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvmax_w
+name = lasx_xvmax_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvmax_d
+name = lasx_xvmax_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvmaxi_b
+name = lasx_xvmaxi_b
+asm-fmts = xd, xj, si5
+data-types = V32QI, V32QI, QI
+
+/// lasx_xvmaxi_h
+name = lasx_xvmaxi_h
+asm-fmts = xd, xj, si5
+data-types = V16HI, V16HI, QI
+
+/// lasx_xvmaxi_w
+name = lasx_xvmaxi_w
+asm-fmts = xd, xj, si5
+data-types = V8SI, V8SI, QI
+
+/// lasx_xvmaxi_d
+name = lasx_xvmaxi_d
+asm-fmts = xd, xj, si5
+data-types = V4DI, V4DI, QI
+
+/// lasx_xvmax_bu
+name = lasx_xvmax_bu
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV32QI, UV32QI
+
+/// lasx_xvmax_hu
+name = lasx_xvmax_hu
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV16HI, UV16HI
+
+/// lasx_xvmax_wu
+name = lasx_xvmax_wu
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV8SI, UV8SI
+
+/// lasx_xvmax_du
+name = lasx_xvmax_du
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV4DI, UV4DI
+
+/// lasx_xvmaxi_bu
+name = lasx_xvmaxi_bu
+asm-fmts = xd, xj, ui5
+data-types = UV32QI, UV32QI, UQI
+
+/// lasx_xvmaxi_hu
+name = lasx_xvmaxi_hu
+asm-fmts = xd, xj, ui5
+data-types = UV16HI, UV16HI, UQI
+
+/// lasx_xvmaxi_wu
+name = lasx_xvmaxi_wu
+asm-fmts = xd, xj, ui5
+data-types = UV8SI, UV8SI, UQI
+
+/// lasx_xvmaxi_du
+name = lasx_xvmaxi_du
+asm-fmts = xd, xj, ui5
+data-types = UV4DI, UV4DI, UQI
+
+/// lasx_xvmin_b
+name = lasx_xvmin_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvmin_h
+name = lasx_xvmin_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvmin_w
+name = lasx_xvmin_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvmin_d
+name = lasx_xvmin_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvmini_b
+name = lasx_xvmini_b
+asm-fmts = xd, xj, si5
+data-types = V32QI, V32QI, QI
+
+/// lasx_xvmini_h
+name = lasx_xvmini_h
+asm-fmts = xd, xj, si5
+data-types = V16HI, V16HI, QI
+
+/// lasx_xvmini_w
+name = lasx_xvmini_w
+asm-fmts = xd, xj, si5
+data-types = V8SI, V8SI, QI
+
+/// lasx_xvmini_d
+name = lasx_xvmini_d
+asm-fmts = xd, xj, si5
+data-types = V4DI, V4DI, QI
+
+/// lasx_xvmin_bu
+name = lasx_xvmin_bu
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV32QI, UV32QI
+
+/// lasx_xvmin_hu
+name = lasx_xvmin_hu
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV16HI, UV16HI
+
+/// lasx_xvmin_wu
+name = lasx_xvmin_wu
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV8SI, UV8SI
+
+/// lasx_xvmin_du
+name = lasx_xvmin_du
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV4DI, UV4DI
+
+/// lasx_xvmini_bu
+name = lasx_xvmini_bu
+asm-fmts = xd, xj, ui5
+data-types = UV32QI, UV32QI, UQI
+
+/// lasx_xvmini_hu
+name = lasx_xvmini_hu
+asm-fmts = xd, xj, ui5
+data-types = UV16HI, UV16HI, UQI
+
+/// lasx_xvmini_wu
+name = lasx_xvmini_wu
+asm-fmts = xd, xj, ui5
+data-types = UV8SI, UV8SI, UQI
+
+/// lasx_xvmini_du
+name = lasx_xvmini_du
+asm-fmts = xd, xj, ui5
+data-types = UV4DI, UV4DI, UQI
+
+/// lasx_xvseq_b
+name = lasx_xvseq_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvseq_h
+name = lasx_xvseq_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvseq_w
+name = lasx_xvseq_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvseq_d
+name = lasx_xvseq_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvseqi_b
+name = lasx_xvseqi_b
+asm-fmts = xd, xj, si5
+data-types = V32QI, V32QI, QI
+
+/// lasx_xvseqi_h
+name = lasx_xvseqi_h
+asm-fmts = xd, xj, si5
+data-types = V16HI, V16HI, QI
+
+/// lasx_xvseqi_w
+name = lasx_xvseqi_w
+asm-fmts = xd, xj, si5
+data-types = V8SI, V8SI, QI
+
+/// lasx_xvseqi_d
+name = lasx_xvseqi_d
+asm-fmts = xd, xj, si5
+data-types = V4DI, V4DI, QI
+
+/// lasx_xvslt_b
+name = lasx_xvslt_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvslt_h
+name = lasx_xvslt_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvslt_w
+name = lasx_xvslt_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvslt_d
+name = lasx_xvslt_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvslti_b
+name = lasx_xvslti_b
+asm-fmts = xd, xj, si5
+data-types = V32QI, V32QI, QI
+
+/// lasx_xvslti_h
+name = lasx_xvslti_h
+asm-fmts = xd, xj, si5
+data-types = V16HI, V16HI, QI
+
+/// lasx_xvslti_w
+name = lasx_xvslti_w
+asm-fmts = xd, xj, si5
+data-types = V8SI, V8SI, QI
+
+/// lasx_xvslti_d
+name = lasx_xvslti_d
+asm-fmts = xd, xj, si5
+data-types = V4DI, V4DI, QI
+
+/// lasx_xvslt_bu
+name = lasx_xvslt_bu
+asm-fmts = xd, xj, xk
+data-types = V32QI, UV32QI, UV32QI
+
+/// lasx_xvslt_hu
+name = lasx_xvslt_hu
+asm-fmts = xd, xj, xk
+data-types = V16HI, UV16HI, UV16HI
+
+/// lasx_xvslt_wu
+name = lasx_xvslt_wu
+asm-fmts = xd, xj, xk
+data-types = V8SI, UV8SI, UV8SI
+
+/// lasx_xvslt_du
+name = lasx_xvslt_du
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV4DI, UV4DI
+
+/// lasx_xvslti_bu
+name = lasx_xvslti_bu
+asm-fmts = xd, xj, ui5
+data-types = V32QI, UV32QI, UQI
+
+/// lasx_xvslti_hu
+name = lasx_xvslti_hu
+asm-fmts = xd, xj, ui5
+data-types = V16HI, UV16HI, UQI
+
+/// lasx_xvslti_wu
+name = lasx_xvslti_wu
+asm-fmts = xd, xj, ui5
+data-types = V8SI, UV8SI, UQI
+
+/// lasx_xvslti_du
+name = lasx_xvslti_du
+asm-fmts = xd, xj, ui5
+data-types = V4DI, UV4DI, UQI
+
+/// lasx_xvsle_b
+name = lasx_xvsle_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvsle_h
+name = lasx_xvsle_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvsle_w
+name = lasx_xvsle_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvsle_d
+name = lasx_xvsle_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvslei_b
+name = lasx_xvslei_b
+asm-fmts = xd, xj, si5
+data-types = V32QI, V32QI, QI
+
+/// lasx_xvslei_h
+name = lasx_xvslei_h
+asm-fmts = xd, xj, si5
+data-types = V16HI, V16HI, QI
+
+/// lasx_xvslei_w
+name = lasx_xvslei_w
+asm-fmts = xd, xj, si5
+data-types = V8SI, V8SI, QI
+
+/// lasx_xvslei_d
+name = lasx_xvslei_d
+asm-fmts = xd, xj, si5
+data-types = V4DI, V4DI, QI
+
+/// lasx_xvsle_bu
+name = lasx_xvsle_bu
+asm-fmts = xd, xj, xk
+data-types = V32QI, UV32QI, UV32QI
+
+/// lasx_xvsle_hu
+name = lasx_xvsle_hu
+asm-fmts = xd, xj, xk
+data-types = V16HI, UV16HI, UV16HI
+
+/// lasx_xvsle_wu
+name = lasx_xvsle_wu
+asm-fmts = xd, xj, xk
+data-types = V8SI, UV8SI, UV8SI
+
+/// lasx_xvsle_du
+name = lasx_xvsle_du
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV4DI, UV4DI
+
+/// lasx_xvslei_bu
+name = lasx_xvslei_bu
+asm-fmts = xd, xj, ui5
+data-types = V32QI, UV32QI, UQI
+
+/// lasx_xvslei_hu
+name = lasx_xvslei_hu
+asm-fmts = xd, xj, ui5
+data-types = V16HI, UV16HI, UQI
+
+/// lasx_xvslei_wu
+name = lasx_xvslei_wu
+asm-fmts = xd, xj, ui5
+data-types = V8SI, UV8SI, UQI
+
+/// lasx_xvslei_du
+name = lasx_xvslei_du
+asm-fmts = xd, xj, ui5
+data-types = V4DI, UV4DI, UQI
+
+/// lasx_xvsat_b
+name = lasx_xvsat_b
+asm-fmts = xd, xj, ui3
+data-types = V32QI, V32QI, UQI
+
+/// lasx_xvsat_h
+name = lasx_xvsat_h
+asm-fmts = xd, xj, ui4
+data-types = V16HI, V16HI, UQI
+
+/// lasx_xvsat_w
+name = lasx_xvsat_w
+asm-fmts = xd, xj, ui5
+data-types = V8SI, V8SI, UQI
+
+/// lasx_xvsat_d
+name = lasx_xvsat_d
+asm-fmts = xd, xj, ui6
+data-types = V4DI, V4DI, UQI
+
+/// lasx_xvsat_bu
+name = lasx_xvsat_bu
+asm-fmts = xd, xj, ui3
+data-types = UV32QI, UV32QI, UQI
+
+/// lasx_xvsat_hu
+name = lasx_xvsat_hu
+asm-fmts = xd, xj, ui4
+data-types = UV16HI, UV16HI, UQI
+
+/// lasx_xvsat_wu
+name = lasx_xvsat_wu
+asm-fmts = xd, xj, ui5
+data-types = UV8SI, UV8SI, UQI
+
+/// lasx_xvsat_du
+name = lasx_xvsat_du
+asm-fmts = xd, xj, ui6
+data-types = UV4DI, UV4DI, UQI
+
+/// lasx_xvadda_b
+name = lasx_xvadda_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvadda_h
+name = lasx_xvadda_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvadda_w
+name = lasx_xvadda_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvadda_d
+name = lasx_xvadda_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvsadd_b
+name = lasx_xvsadd_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvsadd_h
+name = lasx_xvsadd_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvsadd_w
+name = lasx_xvsadd_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvsadd_d
+name = lasx_xvsadd_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvsadd_bu
+name = lasx_xvsadd_bu
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV32QI, UV32QI
+
+/// lasx_xvsadd_hu
+name = lasx_xvsadd_hu
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV16HI, UV16HI
+
+/// lasx_xvsadd_wu
+name = lasx_xvsadd_wu
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV8SI, UV8SI
+
+/// lasx_xvsadd_du
+name = lasx_xvsadd_du
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV4DI, UV4DI
+
+/// lasx_xvavg_b
+name = lasx_xvavg_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvavg_h
+name = lasx_xvavg_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvavg_w
+name = lasx_xvavg_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvavg_d
+name = lasx_xvavg_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvavg_bu
+name = lasx_xvavg_bu
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV32QI, UV32QI
+
+/// lasx_xvavg_hu
+name = lasx_xvavg_hu
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV16HI, UV16HI
+
+/// lasx_xvavg_wu
+name = lasx_xvavg_wu
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV8SI, UV8SI
+
+/// lasx_xvavg_du
+name = lasx_xvavg_du
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV4DI, UV4DI
+
+/// lasx_xvavgr_b
+name = lasx_xvavgr_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvavgr_h
+name = lasx_xvavgr_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvavgr_w
+name = lasx_xvavgr_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvavgr_d
+name = lasx_xvavgr_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvavgr_bu
+name = lasx_xvavgr_bu
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV32QI, UV32QI
+
+/// lasx_xvavgr_hu
+name = lasx_xvavgr_hu
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV16HI, UV16HI
+
+/// lasx_xvavgr_wu
+name = lasx_xvavgr_wu
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV8SI, UV8SI
+
+/// lasx_xvavgr_du
+name = lasx_xvavgr_du
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV4DI, UV4DI
+
+/// lasx_xvssub_b
+name = lasx_xvssub_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvssub_h
+name = lasx_xvssub_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvssub_w
+name = lasx_xvssub_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvssub_d
+name = lasx_xvssub_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvssub_bu
+name = lasx_xvssub_bu
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV32QI, UV32QI
+
+/// lasx_xvssub_hu
+name = lasx_xvssub_hu
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV16HI, UV16HI
+
+/// lasx_xvssub_wu
+name = lasx_xvssub_wu
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV8SI, UV8SI
+
+/// lasx_xvssub_du
+name = lasx_xvssub_du
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV4DI, UV4DI
+
+/// lasx_xvabsd_b
+name = lasx_xvabsd_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvabsd_h
+name = lasx_xvabsd_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvabsd_w
+name = lasx_xvabsd_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvabsd_d
+name = lasx_xvabsd_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvabsd_bu
+name = lasx_xvabsd_bu
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV32QI, UV32QI
+
+/// lasx_xvabsd_hu
+name = lasx_xvabsd_hu
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV16HI, UV16HI
+
+/// lasx_xvabsd_wu
+name = lasx_xvabsd_wu
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV8SI, UV8SI
+
+/// lasx_xvabsd_du
+name = lasx_xvabsd_du
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV4DI, UV4DI
+
+/// lasx_xvmul_b
+name = lasx_xvmul_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvmul_h
+name = lasx_xvmul_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvmul_w
+name = lasx_xvmul_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvmul_d
+name = lasx_xvmul_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvmadd_b
+name = lasx_xvmadd_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI, V32QI
+
+/// lasx_xvmadd_h
+name = lasx_xvmadd_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI, V16HI
+
+/// lasx_xvmadd_w
+name = lasx_xvmadd_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI, V8SI
+
+/// lasx_xvmadd_d
+name = lasx_xvmadd_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI, V4DI
+
+/// lasx_xvmsub_b
+name = lasx_xvmsub_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI, V32QI
+
+/// lasx_xvmsub_h
+name = lasx_xvmsub_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI, V16HI
+
+/// lasx_xvmsub_w
+name = lasx_xvmsub_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI, V8SI
+
+/// lasx_xvmsub_d
+name = lasx_xvmsub_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI, V4DI
+
+/// lasx_xvdiv_b
+name = lasx_xvdiv_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvdiv_h
+name = lasx_xvdiv_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvdiv_w
+name = lasx_xvdiv_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvdiv_d
+name = lasx_xvdiv_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvdiv_bu
+name = lasx_xvdiv_bu
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV32QI, UV32QI
+
+/// lasx_xvdiv_hu
+name = lasx_xvdiv_hu
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV16HI, UV16HI
+
+/// lasx_xvdiv_wu
+name = lasx_xvdiv_wu
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV8SI, UV8SI
+
+/// lasx_xvdiv_du
+name = lasx_xvdiv_du
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV4DI, UV4DI
+
+/// lasx_xvhaddw_h_b
+name = lasx_xvhaddw_h_b
+asm-fmts = xd, xj, xk
+data-types = V16HI, V32QI, V32QI
+
+/// lasx_xvhaddw_w_h
+name = lasx_xvhaddw_w_h
+asm-fmts = xd, xj, xk
+data-types = V8SI, V16HI, V16HI
+
+/// lasx_xvhaddw_d_w
+name = lasx_xvhaddw_d_w
+asm-fmts = xd, xj, xk
+data-types = V4DI, V8SI, V8SI
+
+/// lasx_xvhaddw_hu_bu
+name = lasx_xvhaddw_hu_bu
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV32QI, UV32QI
+
+/// lasx_xvhaddw_wu_hu
+name = lasx_xvhaddw_wu_hu
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV16HI, UV16HI
+
+/// lasx_xvhaddw_du_wu
+name = lasx_xvhaddw_du_wu
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV8SI, UV8SI
+
+/// lasx_xvhsubw_h_b
+name = lasx_xvhsubw_h_b
+asm-fmts = xd, xj, xk
+data-types = V16HI, V32QI, V32QI
+
+/// lasx_xvhsubw_w_h
+name = lasx_xvhsubw_w_h
+asm-fmts = xd, xj, xk
+data-types = V8SI, V16HI, V16HI
+
+/// lasx_xvhsubw_d_w
+name = lasx_xvhsubw_d_w
+asm-fmts = xd, xj, xk
+data-types = V4DI, V8SI, V8SI
+
+/// lasx_xvhsubw_hu_bu
+name = lasx_xvhsubw_hu_bu
+asm-fmts = xd, xj, xk
+data-types = V16HI, UV32QI, UV32QI
+
+/// lasx_xvhsubw_wu_hu
+name = lasx_xvhsubw_wu_hu
+asm-fmts = xd, xj, xk
+data-types = V8SI, UV16HI, UV16HI
+
+/// lasx_xvhsubw_du_wu
+name = lasx_xvhsubw_du_wu
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV8SI, UV8SI
+
+/// lasx_xvmod_b
+name = lasx_xvmod_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvmod_h
+name = lasx_xvmod_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvmod_w
+name = lasx_xvmod_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvmod_d
+name = lasx_xvmod_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvmod_bu
+name = lasx_xvmod_bu
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV32QI, UV32QI
+
+/// lasx_xvmod_hu
+name = lasx_xvmod_hu
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV16HI, UV16HI
+
+/// lasx_xvmod_wu
+name = lasx_xvmod_wu
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV8SI, UV8SI
+
+/// lasx_xvmod_du
+name = lasx_xvmod_du
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV4DI, UV4DI
+
+/// lasx_xvrepl128vei_b
+name = lasx_xvrepl128vei_b
+asm-fmts = xd, xj, ui4
+data-types = V32QI, V32QI, UQI
+
+/// lasx_xvrepl128vei_h
+name = lasx_xvrepl128vei_h
+asm-fmts = xd, xj, ui3
+data-types = V16HI, V16HI, UQI
+
+/// lasx_xvrepl128vei_w
+name = lasx_xvrepl128vei_w
+asm-fmts = xd, xj, ui2
+data-types = V8SI, V8SI, UQI
+
+/// lasx_xvrepl128vei_d
+name = lasx_xvrepl128vei_d
+asm-fmts = xd, xj, ui1
+data-types = V4DI, V4DI, UQI
+
+/// lasx_xvpickev_b
+name = lasx_xvpickev_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvpickev_h
+name = lasx_xvpickev_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvpickev_w
+name = lasx_xvpickev_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvpickev_d
+name = lasx_xvpickev_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvpickod_b
+name = lasx_xvpickod_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvpickod_h
+name = lasx_xvpickod_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvpickod_w
+name = lasx_xvpickod_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvpickod_d
+name = lasx_xvpickod_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvilvh_b
+name = lasx_xvilvh_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvilvh_h
+name = lasx_xvilvh_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvilvh_w
+name = lasx_xvilvh_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvilvh_d
+name = lasx_xvilvh_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvilvl_b
+name = lasx_xvilvl_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvilvl_h
+name = lasx_xvilvl_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvilvl_w
+name = lasx_xvilvl_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvilvl_d
+name = lasx_xvilvl_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvpackev_b
+name = lasx_xvpackev_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvpackev_h
+name = lasx_xvpackev_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvpackev_w
+name = lasx_xvpackev_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvpackev_d
+name = lasx_xvpackev_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvpackod_b
+name = lasx_xvpackod_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvpackod_h
+name = lasx_xvpackod_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvpackod_w
+name = lasx_xvpackod_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvpackod_d
+name = lasx_xvpackod_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvshuf_b
+name = lasx_xvshuf_b
+asm-fmts = xd, xj, xk, xa
+data-types = V32QI, V32QI, V32QI, V32QI
+
+/// lasx_xvshuf_h
+name = lasx_xvshuf_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI, V16HI
+
+/// lasx_xvshuf_w
+name = lasx_xvshuf_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI, V8SI
+
+/// lasx_xvshuf_d
+name = lasx_xvshuf_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI, V4DI
+
+/// lasx_xvand_v
+name = lasx_xvand_v
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV32QI, UV32QI
+
+/// lasx_xvandi_b
+name = lasx_xvandi_b
+asm-fmts = xd, xj, ui8
+data-types = UV32QI, UV32QI, UQI
+
+/// lasx_xvor_v
+name = lasx_xvor_v
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV32QI, UV32QI
+
+/// lasx_xvori_b
+name = lasx_xvori_b
+asm-fmts = xd, xj, ui8
+data-types = UV32QI, UV32QI, UQI
+
+/// lasx_xvnor_v
+name = lasx_xvnor_v
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV32QI, UV32QI
+
+/// lasx_xvnori_b
+name = lasx_xvnori_b
+asm-fmts = xd, xj, ui8
+data-types = UV32QI, UV32QI, UQI
+
+/// lasx_xvxor_v
+name = lasx_xvxor_v
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV32QI, UV32QI
+
+/// lasx_xvxori_b
+name = lasx_xvxori_b
+asm-fmts = xd, xj, ui8
+data-types = UV32QI, UV32QI, UQI
+
+/// lasx_xvbitsel_v
+name = lasx_xvbitsel_v
+asm-fmts = xd, xj, xk, xa
+data-types = UV32QI, UV32QI, UV32QI, UV32QI
+
+/// lasx_xvbitseli_b
+name = lasx_xvbitseli_b
+asm-fmts = xd, xj, ui8
+data-types = UV32QI, UV32QI, UV32QI, USI
+
+/// lasx_xvshuf4i_b
+name = lasx_xvshuf4i_b
+asm-fmts = xd, xj, ui8
+data-types = V32QI, V32QI, USI
+
+/// lasx_xvshuf4i_h
+name = lasx_xvshuf4i_h
+asm-fmts = xd, xj, ui8
+data-types = V16HI, V16HI, USI
+
+/// lasx_xvshuf4i_w
+name = lasx_xvshuf4i_w
+asm-fmts = xd, xj, ui8
+data-types = V8SI, V8SI, USI
+
+/// lasx_xvreplgr2vr_b
+name = lasx_xvreplgr2vr_b
+asm-fmts = xd, rj
+data-types = V32QI, SI
+
+/// lasx_xvreplgr2vr_h
+name = lasx_xvreplgr2vr_h
+asm-fmts = xd, rj
+data-types = V16HI, SI
+
+/// lasx_xvreplgr2vr_w
+name = lasx_xvreplgr2vr_w
+asm-fmts = xd, rj
+data-types = V8SI, SI
+
+/// lasx_xvreplgr2vr_d
+name = lasx_xvreplgr2vr_d
+asm-fmts = xd, rj
+data-types = V4DI, DI
+
+/// lasx_xvpcnt_b
+name = lasx_xvpcnt_b
+asm-fmts = xd, xj
+data-types = V32QI, V32QI
+
+/// lasx_xvpcnt_h
+name = lasx_xvpcnt_h
+asm-fmts = xd, xj
+data-types = V16HI, V16HI
+
+/// lasx_xvpcnt_w
+name = lasx_xvpcnt_w
+asm-fmts = xd, xj
+data-types = V8SI, V8SI
+
+/// lasx_xvpcnt_d
+name = lasx_xvpcnt_d
+asm-fmts = xd, xj
+data-types = V4DI, V4DI
+
+/// lasx_xvclo_b
+name = lasx_xvclo_b
+asm-fmts = xd, xj
+data-types = V32QI, V32QI
+
+/// lasx_xvclo_h
+name = lasx_xvclo_h
+asm-fmts = xd, xj
+data-types = V16HI, V16HI
+
+/// lasx_xvclo_w
+name = lasx_xvclo_w
+asm-fmts = xd, xj
+data-types = V8SI, V8SI
+
+/// lasx_xvclo_d
+name = lasx_xvclo_d
+asm-fmts = xd, xj
+data-types = V4DI, V4DI
+
+/// lasx_xvclz_b
+name = lasx_xvclz_b
+asm-fmts = xd, xj
+data-types = V32QI, V32QI
+
+/// lasx_xvclz_h
+name = lasx_xvclz_h
+asm-fmts = xd, xj
+data-types = V16HI, V16HI
+
+/// lasx_xvclz_w
+name = lasx_xvclz_w
+asm-fmts = xd, xj
+data-types = V8SI, V8SI
+
+/// lasx_xvclz_d
+name = lasx_xvclz_d
+asm-fmts = xd, xj
+data-types = V4DI, V4DI
+
+/// lasx_xvfadd_s
+name = lasx_xvfadd_s
+asm-fmts = xd, xj, xk
+data-types = V8SF, V8SF, V8SF
+
+/// lasx_xvfadd_d
+name = lasx_xvfadd_d
+asm-fmts = xd, xj, xk
+data-types = V4DF, V4DF, V4DF
+
+/// lasx_xvfsub_s
+name = lasx_xvfsub_s
+asm-fmts = xd, xj, xk
+data-types = V8SF, V8SF, V8SF
+
+/// lasx_xvfsub_d
+name = lasx_xvfsub_d
+asm-fmts = xd, xj, xk
+data-types = V4DF, V4DF, V4DF
+
+/// lasx_xvfmul_s
+name = lasx_xvfmul_s
+asm-fmts = xd, xj, xk
+data-types = V8SF, V8SF, V8SF
+
+/// lasx_xvfmul_d
+name = lasx_xvfmul_d
+asm-fmts = xd, xj, xk
+data-types = V4DF, V4DF, V4DF
+
+/// lasx_xvfdiv_s
+name = lasx_xvfdiv_s
+asm-fmts = xd, xj, xk
+data-types = V8SF, V8SF, V8SF
+
+/// lasx_xvfdiv_d
+name = lasx_xvfdiv_d
+asm-fmts = xd, xj, xk
+data-types = V4DF, V4DF, V4DF
+
+/// lasx_xvfcvt_h_s
+name = lasx_xvfcvt_h_s
+asm-fmts = xd, xj, xk
+data-types = V16HI, V8SF, V8SF
+
+/// lasx_xvfcvt_s_d
+name = lasx_xvfcvt_s_d
+asm-fmts = xd, xj, xk
+data-types = V8SF, V4DF, V4DF
+
+/// lasx_xvfmin_s
+name = lasx_xvfmin_s
+asm-fmts = xd, xj, xk
+data-types = V8SF, V8SF, V8SF
+
+/// lasx_xvfmin_d
+name = lasx_xvfmin_d
+asm-fmts = xd, xj, xk
+data-types = V4DF, V4DF, V4DF
+
+/// lasx_xvfmina_s
+name = lasx_xvfmina_s
+asm-fmts = xd, xj, xk
+data-types = V8SF, V8SF, V8SF
+
+/// lasx_xvfmina_d
+name = lasx_xvfmina_d
+asm-fmts = xd, xj, xk
+data-types = V4DF, V4DF, V4DF
+
+/// lasx_xvfmax_s
+name = lasx_xvfmax_s
+asm-fmts = xd, xj, xk
+data-types = V8SF, V8SF, V8SF
+
+/// lasx_xvfmax_d
+name = lasx_xvfmax_d
+asm-fmts = xd, xj, xk
+data-types = V4DF, V4DF, V4DF
+
+/// lasx_xvfmaxa_s
+name = lasx_xvfmaxa_s
+asm-fmts = xd, xj, xk
+data-types = V8SF, V8SF, V8SF
+
+/// lasx_xvfmaxa_d
+name = lasx_xvfmaxa_d
+asm-fmts = xd, xj, xk
+data-types = V4DF, V4DF, V4DF
+
+/// lasx_xvfclass_s
+name = lasx_xvfclass_s
+asm-fmts = xd, xj
+data-types = V8SI, V8SF
+
+/// lasx_xvfclass_d
+name = lasx_xvfclass_d
+asm-fmts = xd, xj
+data-types = V4DI, V4DF
+
+/// lasx_xvfsqrt_s
+name = lasx_xvfsqrt_s
+asm-fmts = xd, xj
+data-types = V8SF, V8SF
+
+/// lasx_xvfsqrt_d
+name = lasx_xvfsqrt_d
+asm-fmts = xd, xj
+data-types = V4DF, V4DF
+
+/// lasx_xvfrecip_s
+name = lasx_xvfrecip_s
+asm-fmts = xd, xj
+data-types = V8SF, V8SF
+
+/// lasx_xvfrecip_d
+name = lasx_xvfrecip_d
+asm-fmts = xd, xj
+data-types = V4DF, V4DF
+
+/// lasx_xvfrint_s
+name = lasx_xvfrint_s
+asm-fmts = xd, xj
+data-types = V8SF, V8SF
+
+/// lasx_xvfrint_d
+name = lasx_xvfrint_d
+asm-fmts = xd, xj
+data-types = V4DF, V4DF
+
+/// lasx_xvfrsqrt_s
+name = lasx_xvfrsqrt_s
+asm-fmts = xd, xj
+data-types = V8SF, V8SF
+
+/// lasx_xvfrsqrt_d
+name = lasx_xvfrsqrt_d
+asm-fmts = xd, xj
+data-types = V4DF, V4DF
+
+/// lasx_xvflogb_s
+name = lasx_xvflogb_s
+asm-fmts = xd, xj
+data-types = V8SF, V8SF
+
+/// lasx_xvflogb_d
+name = lasx_xvflogb_d
+asm-fmts = xd, xj
+data-types = V4DF, V4DF
+
+/// lasx_xvfcvth_s_h
+name = lasx_xvfcvth_s_h
+asm-fmts = xd, xj
+data-types = V8SF, V16HI
+
+/// lasx_xvfcvth_d_s
+name = lasx_xvfcvth_d_s
+asm-fmts = xd, xj
+data-types = V4DF, V8SF
+
+/// lasx_xvfcvtl_s_h
+name = lasx_xvfcvtl_s_h
+asm-fmts = xd, xj
+data-types = V8SF, V16HI
+
+/// lasx_xvfcvtl_d_s
+name = lasx_xvfcvtl_d_s
+asm-fmts = xd, xj
+data-types = V4DF, V8SF
+
+/// lasx_xvftint_w_s
+name = lasx_xvftint_w_s
+asm-fmts = xd, xj
+data-types = V8SI, V8SF
+
+/// lasx_xvftint_l_d
+name = lasx_xvftint_l_d
+asm-fmts = xd, xj
+data-types = V4DI, V4DF
+
+/// lasx_xvftint_wu_s
+name = lasx_xvftint_wu_s
+asm-fmts = xd, xj
+data-types = UV8SI, V8SF
+
+/// lasx_xvftint_lu_d
+name = lasx_xvftint_lu_d
+asm-fmts = xd, xj
+data-types = UV4DI, V4DF
+
+/// lasx_xvftintrz_w_s
+name = lasx_xvftintrz_w_s
+asm-fmts = xd, xj
+data-types = V8SI, V8SF
+
+/// lasx_xvftintrz_l_d
+name = lasx_xvftintrz_l_d
+asm-fmts = xd, xj
+data-types = V4DI, V4DF
+
+/// lasx_xvftintrz_wu_s
+name = lasx_xvftintrz_wu_s
+asm-fmts = xd, xj
+data-types = UV8SI, V8SF
+
+/// lasx_xvftintrz_lu_d
+name = lasx_xvftintrz_lu_d
+asm-fmts = xd, xj
+data-types = UV4DI, V4DF
+
+/// lasx_xvffint_s_w
+name = lasx_xvffint_s_w
+asm-fmts = xd, xj
+data-types = V8SF, V8SI
+
+/// lasx_xvffint_d_l
+name = lasx_xvffint_d_l
+asm-fmts = xd, xj
+data-types = V4DF, V4DI
+
+/// lasx_xvffint_s_wu
+name = lasx_xvffint_s_wu
+asm-fmts = xd, xj
+data-types = V8SF, UV8SI
+
+/// lasx_xvffint_d_lu
+name = lasx_xvffint_d_lu
+asm-fmts = xd, xj
+data-types = V4DF, UV4DI
+
+/// lasx_xvreplve_b
+name = lasx_xvreplve_b
+asm-fmts = xd, xj, rk
+data-types = V32QI, V32QI, SI
+
+/// lasx_xvreplve_h
+name = lasx_xvreplve_h
+asm-fmts = xd, xj, rk
+data-types = V16HI, V16HI, SI
+
+/// lasx_xvreplve_w
+name = lasx_xvreplve_w
+asm-fmts = xd, xj, rk
+data-types = V8SI, V8SI, SI
+
+/// lasx_xvreplve_d
+name = lasx_xvreplve_d
+asm-fmts = xd, xj, rk
+data-types = V4DI, V4DI, SI
+
+/// lasx_xvpermi_w
+name = lasx_xvpermi_w
+asm-fmts = xd, xj, ui8
+data-types = V8SI, V8SI, V8SI, USI
+
+/// lasx_xvandn_v
+name = lasx_xvandn_v
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV32QI, UV32QI
+
+/// lasx_xvneg_b
+name = lasx_xvneg_b
+asm-fmts = xd, xj
+data-types = V32QI, V32QI
+
+/// lasx_xvneg_h
+name = lasx_xvneg_h
+asm-fmts = xd, xj
+data-types = V16HI, V16HI
+
+/// lasx_xvneg_w
+name = lasx_xvneg_w
+asm-fmts = xd, xj
+data-types = V8SI, V8SI
+
+/// lasx_xvneg_d
+name = lasx_xvneg_d
+asm-fmts = xd, xj
+data-types = V4DI, V4DI
+
+/// lasx_xvmuh_b
+name = lasx_xvmuh_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvmuh_h
+name = lasx_xvmuh_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvmuh_w
+name = lasx_xvmuh_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvmuh_d
+name = lasx_xvmuh_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvmuh_bu
+name = lasx_xvmuh_bu
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV32QI, UV32QI
+
+/// lasx_xvmuh_hu
+name = lasx_xvmuh_hu
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV16HI, UV16HI
+
+/// lasx_xvmuh_wu
+name = lasx_xvmuh_wu
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV8SI, UV8SI
+
+/// lasx_xvmuh_du
+name = lasx_xvmuh_du
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV4DI, UV4DI
+
+/// lasx_xvsllwil_h_b
+name = lasx_xvsllwil_h_b
+asm-fmts = xd, xj, ui3
+data-types = V16HI, V32QI, UQI
+
+/// lasx_xvsllwil_w_h
+name = lasx_xvsllwil_w_h
+asm-fmts = xd, xj, ui4
+data-types = V8SI, V16HI, UQI
+
+/// lasx_xvsllwil_d_w
+name = lasx_xvsllwil_d_w
+asm-fmts = xd, xj, ui5
+data-types = V4DI, V8SI, UQI
+
+/// lasx_xvsllwil_hu_bu
+name = lasx_xvsllwil_hu_bu
+asm-fmts = xd, xj, ui3
+data-types = UV16HI, UV32QI, UQI
+
+/// lasx_xvsllwil_wu_hu
+name = lasx_xvsllwil_wu_hu
+asm-fmts = xd, xj, ui4
+data-types = UV8SI, UV16HI, UQI
+
+/// lasx_xvsllwil_du_wu
+name = lasx_xvsllwil_du_wu
+asm-fmts = xd, xj, ui5
+data-types = UV4DI, UV8SI, UQI
+
+/// lasx_xvsran_b_h
+name = lasx_xvsran_b_h
+asm-fmts = xd, xj, xk
+data-types = V32QI, V16HI, V16HI
+
+/// lasx_xvsran_h_w
+name = lasx_xvsran_h_w
+asm-fmts = xd, xj, xk
+data-types = V16HI, V8SI, V8SI
+
+/// lasx_xvsran_w_d
+name = lasx_xvsran_w_d
+asm-fmts = xd, xj, xk
+data-types = V8SI, V4DI, V4DI
+
+/// lasx_xvssran_b_h
+name = lasx_xvssran_b_h
+asm-fmts = xd, xj, xk
+data-types = V32QI, V16HI, V16HI
+
+/// lasx_xvssran_h_w
+name = lasx_xvssran_h_w
+asm-fmts = xd, xj, xk
+data-types = V16HI, V8SI, V8SI
+
+/// lasx_xvssran_w_d
+name = lasx_xvssran_w_d
+asm-fmts = xd, xj, xk
+data-types = V8SI, V4DI, V4DI
+
+/// lasx_xvssran_bu_h
+name = lasx_xvssran_bu_h
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV16HI, UV16HI
+
+/// lasx_xvssran_hu_w
+name = lasx_xvssran_hu_w
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV8SI, UV8SI
+
+/// lasx_xvssran_wu_d
+name = lasx_xvssran_wu_d
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV4DI, UV4DI
+
+/// lasx_xvsrarn_b_h
+name = lasx_xvsrarn_b_h
+asm-fmts = xd, xj, xk
+data-types = V32QI, V16HI, V16HI
+
+/// lasx_xvsrarn_h_w
+name = lasx_xvsrarn_h_w
+asm-fmts = xd, xj, xk
+data-types = V16HI, V8SI, V8SI
+
+/// lasx_xvsrarn_w_d
+name = lasx_xvsrarn_w_d
+asm-fmts = xd, xj, xk
+data-types = V8SI, V4DI, V4DI
+
+/// lasx_xvssrarn_b_h
+name = lasx_xvssrarn_b_h
+asm-fmts = xd, xj, xk
+data-types = V32QI, V16HI, V16HI
+
+/// lasx_xvssrarn_h_w
+name = lasx_xvssrarn_h_w
+asm-fmts = xd, xj, xk
+data-types = V16HI, V8SI, V8SI
+
+/// lasx_xvssrarn_w_d
+name = lasx_xvssrarn_w_d
+asm-fmts = xd, xj, xk
+data-types = V8SI, V4DI, V4DI
+
+/// lasx_xvssrarn_bu_h
+name = lasx_xvssrarn_bu_h
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV16HI, UV16HI
+
+/// lasx_xvssrarn_hu_w
+name = lasx_xvssrarn_hu_w
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV8SI, UV8SI
+
+/// lasx_xvssrarn_wu_d
+name = lasx_xvssrarn_wu_d
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV4DI, UV4DI
+
+/// lasx_xvsrln_b_h
+name = lasx_xvsrln_b_h
+asm-fmts = xd, xj, xk
+data-types = V32QI, V16HI, V16HI
+
+/// lasx_xvsrln_h_w
+name = lasx_xvsrln_h_w
+asm-fmts = xd, xj, xk
+data-types = V16HI, V8SI, V8SI
+
+/// lasx_xvsrln_w_d
+name = lasx_xvsrln_w_d
+asm-fmts = xd, xj, xk
+data-types = V8SI, V4DI, V4DI
+
+/// lasx_xvssrln_bu_h
+name = lasx_xvssrln_bu_h
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV16HI, UV16HI
+
+/// lasx_xvssrln_hu_w
+name = lasx_xvssrln_hu_w
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV8SI, UV8SI
+
+/// lasx_xvssrln_wu_d
+name = lasx_xvssrln_wu_d
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV4DI, UV4DI
+
+/// lasx_xvsrlrn_b_h
+name = lasx_xvsrlrn_b_h
+asm-fmts = xd, xj, xk
+data-types = V32QI, V16HI, V16HI
+
+/// lasx_xvsrlrn_h_w
+name = lasx_xvsrlrn_h_w
+asm-fmts = xd, xj, xk
+data-types = V16HI, V8SI, V8SI
+
+/// lasx_xvsrlrn_w_d
+name = lasx_xvsrlrn_w_d
+asm-fmts = xd, xj, xk
+data-types = V8SI, V4DI, V4DI
+
+/// lasx_xvssrlrn_bu_h
+name = lasx_xvssrlrn_bu_h
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV16HI, UV16HI
+
+/// lasx_xvssrlrn_hu_w
+name = lasx_xvssrlrn_hu_w
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV8SI, UV8SI
+
+/// lasx_xvssrlrn_wu_d
+name = lasx_xvssrlrn_wu_d
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV4DI, UV4DI
+
+/// lasx_xvfrstpi_b
+name = lasx_xvfrstpi_b
+asm-fmts = xd, xj, ui5
+data-types = V32QI, V32QI, V32QI, UQI
+
+/// lasx_xvfrstpi_h
+name = lasx_xvfrstpi_h
+asm-fmts = xd, xj, ui5
+data-types = V16HI, V16HI, V16HI, UQI
+
+/// lasx_xvfrstp_b
+name = lasx_xvfrstp_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI, V32QI
+
+/// lasx_xvfrstp_h
+name = lasx_xvfrstp_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI, V16HI
+
+/// lasx_xvshuf4i_d
+name = lasx_xvshuf4i_d
+asm-fmts = xd, xj, ui8
+data-types = V4DI, V4DI, V4DI, USI
+
+/// lasx_xvbsrl_v
+name = lasx_xvbsrl_v
+asm-fmts = xd, xj, ui5
+data-types = V32QI, V32QI, UQI
+
+/// lasx_xvbsll_v
+name = lasx_xvbsll_v
+asm-fmts = xd, xj, ui5
+data-types = V32QI, V32QI, UQI
+
+/// lasx_xvextrins_b
+name = lasx_xvextrins_b
+asm-fmts = xd, xj, ui8
+data-types = V32QI, V32QI, V32QI, USI
+
+/// lasx_xvextrins_h
+name = lasx_xvextrins_h
+asm-fmts = xd, xj, ui8
+data-types = V16HI, V16HI, V16HI, USI
+
+/// lasx_xvextrins_w
+name = lasx_xvextrins_w
+asm-fmts = xd, xj, ui8
+data-types = V8SI, V8SI, V8SI, USI
+
+/// lasx_xvextrins_d
+name = lasx_xvextrins_d
+asm-fmts = xd, xj, ui8
+data-types = V4DI, V4DI, V4DI, USI
+
+/// lasx_xvmskltz_b
+name = lasx_xvmskltz_b
+asm-fmts = xd, xj
+data-types = V32QI, V32QI
+
+/// lasx_xvmskltz_h
+name = lasx_xvmskltz_h
+asm-fmts = xd, xj
+data-types = V16HI, V16HI
+
+/// lasx_xvmskltz_w
+name = lasx_xvmskltz_w
+asm-fmts = xd, xj
+data-types = V8SI, V8SI
+
+/// lasx_xvmskltz_d
+name = lasx_xvmskltz_d
+asm-fmts = xd, xj
+data-types = V4DI, V4DI
+
+/// lasx_xvsigncov_b
+name = lasx_xvsigncov_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvsigncov_h
+name = lasx_xvsigncov_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvsigncov_w
+name = lasx_xvsigncov_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvsigncov_d
+name = lasx_xvsigncov_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvfmadd_s
+name = lasx_xvfmadd_s
+asm-fmts = xd, xj, xk, xa
+data-types = V8SF, V8SF, V8SF, V8SF
+
+/// lasx_xvfmadd_d
+name = lasx_xvfmadd_d
+asm-fmts = xd, xj, xk, xa
+data-types = V4DF, V4DF, V4DF, V4DF
+
+/// lasx_xvfmsub_s
+name = lasx_xvfmsub_s
+asm-fmts = xd, xj, xk, xa
+data-types = V8SF, V8SF, V8SF, V8SF
+
+/// lasx_xvfmsub_d
+name = lasx_xvfmsub_d
+asm-fmts = xd, xj, xk, xa
+data-types = V4DF, V4DF, V4DF, V4DF
+
+/// lasx_xvfnmadd_s
+name = lasx_xvfnmadd_s
+asm-fmts = xd, xj, xk, xa
+data-types = V8SF, V8SF, V8SF, V8SF
+
+/// lasx_xvfnmadd_d
+name = lasx_xvfnmadd_d
+asm-fmts = xd, xj, xk, xa
+data-types = V4DF, V4DF, V4DF, V4DF
+
+/// lasx_xvfnmsub_s
+name = lasx_xvfnmsub_s
+asm-fmts = xd, xj, xk, xa
+data-types = V8SF, V8SF, V8SF, V8SF
+
+/// lasx_xvfnmsub_d
+name = lasx_xvfnmsub_d
+asm-fmts = xd, xj, xk, xa
+data-types = V4DF, V4DF, V4DF, V4DF
+
+/// lasx_xvftintrne_w_s
+name = lasx_xvftintrne_w_s
+asm-fmts = xd, xj
+data-types = V8SI, V8SF
+
+/// lasx_xvftintrne_l_d
+name = lasx_xvftintrne_l_d
+asm-fmts = xd, xj
+data-types = V4DI, V4DF
+
+/// lasx_xvftintrp_w_s
+name = lasx_xvftintrp_w_s
+asm-fmts = xd, xj
+data-types = V8SI, V8SF
+
+/// lasx_xvftintrp_l_d
+name = lasx_xvftintrp_l_d
+asm-fmts = xd, xj
+data-types = V4DI, V4DF
+
+/// lasx_xvftintrm_w_s
+name = lasx_xvftintrm_w_s
+asm-fmts = xd, xj
+data-types = V8SI, V8SF
+
+/// lasx_xvftintrm_l_d
+name = lasx_xvftintrm_l_d
+asm-fmts = xd, xj
+data-types = V4DI, V4DF
+
+/// lasx_xvftint_w_d
+name = lasx_xvftint_w_d
+asm-fmts = xd, xj, xk
+data-types = V8SI, V4DF, V4DF
+
+/// lasx_xvffint_s_l
+name = lasx_xvffint_s_l
+asm-fmts = xd, xj, xk
+data-types = V8SF, V4DI, V4DI
+
+/// lasx_xvftintrz_w_d
+name = lasx_xvftintrz_w_d
+asm-fmts = xd, xj, xk
+data-types = V8SI, V4DF, V4DF
+
+/// lasx_xvftintrp_w_d
+name = lasx_xvftintrp_w_d
+asm-fmts = xd, xj, xk
+data-types = V8SI, V4DF, V4DF
+
+/// lasx_xvftintrm_w_d
+name = lasx_xvftintrm_w_d
+asm-fmts = xd, xj, xk
+data-types = V8SI, V4DF, V4DF
+
+/// lasx_xvftintrne_w_d
+name = lasx_xvftintrne_w_d
+asm-fmts = xd, xj, xk
+data-types = V8SI, V4DF, V4DF
+
+/// lasx_xvftinth_l_s
+name = lasx_xvftinth_l_s
+asm-fmts = xd, xj
+data-types = V4DI, V8SF
+
+/// lasx_xvftintl_l_s
+name = lasx_xvftintl_l_s
+asm-fmts = xd, xj
+data-types = V4DI, V8SF
+
+/// lasx_xvffinth_d_w
+name = lasx_xvffinth_d_w
+asm-fmts = xd, xj
+data-types = V4DF, V8SI
+
+/// lasx_xvffintl_d_w
+name = lasx_xvffintl_d_w
+asm-fmts = xd, xj
+data-types = V4DF, V8SI
+
+/// lasx_xvftintrzh_l_s
+name = lasx_xvftintrzh_l_s
+asm-fmts = xd, xj
+data-types = V4DI, V8SF
+
+/// lasx_xvftintrzl_l_s
+name = lasx_xvftintrzl_l_s
+asm-fmts = xd, xj
+data-types = V4DI, V8SF
+
+/// lasx_xvftintrph_l_s
+name = lasx_xvftintrph_l_s
+asm-fmts = xd, xj
+data-types = V4DI, V8SF
+
+/// lasx_xvftintrpl_l_s
+name = lasx_xvftintrpl_l_s
+asm-fmts = xd, xj
+data-types = V4DI, V8SF
+
+/// lasx_xvftintrmh_l_s
+name = lasx_xvftintrmh_l_s
+asm-fmts = xd, xj
+data-types = V4DI, V8SF
+
+/// lasx_xvftintrml_l_s
+name = lasx_xvftintrml_l_s
+asm-fmts = xd, xj
+data-types = V4DI, V8SF
+
+/// lasx_xvftintrneh_l_s
+name = lasx_xvftintrneh_l_s
+asm-fmts = xd, xj
+data-types = V4DI, V8SF
+
+/// lasx_xvftintrnel_l_s
+name = lasx_xvftintrnel_l_s
+asm-fmts = xd, xj
+data-types = V4DI, V8SF
+
+/// lasx_xvfrintrne_s
+name = lasx_xvfrintrne_s
+asm-fmts = xd, xj
+data-types = V8SF, V8SF
+
+/// lasx_xvfrintrne_d
+name = lasx_xvfrintrne_d
+asm-fmts = xd, xj
+data-types = V4DF, V4DF
+
+/// lasx_xvfrintrz_s
+name = lasx_xvfrintrz_s
+asm-fmts = xd, xj
+data-types = V8SF, V8SF
+
+/// lasx_xvfrintrz_d
+name = lasx_xvfrintrz_d
+asm-fmts = xd, xj
+data-types = V4DF, V4DF
+
+/// lasx_xvfrintrp_s
+name = lasx_xvfrintrp_s
+asm-fmts = xd, xj
+data-types = V8SF, V8SF
+
+/// lasx_xvfrintrp_d
+name = lasx_xvfrintrp_d
+asm-fmts = xd, xj
+data-types = V4DF, V4DF
+
+/// lasx_xvfrintrm_s
+name = lasx_xvfrintrm_s
+asm-fmts = xd, xj
+data-types = V8SF, V8SF
+
+/// lasx_xvfrintrm_d
+name = lasx_xvfrintrm_d
+asm-fmts = xd, xj
+data-types = V4DF, V4DF
+
+/// lasx_xvld
+name = lasx_xvld
+asm-fmts = xd, rj, si12
+data-types = V32QI, CVPOINTER, SI
+
+/// lasx_xvst
+name = lasx_xvst
+asm-fmts = xd, rj, si12
+data-types = VOID, V32QI, CVPOINTER, SI
+
+/// lasx_xvstelm_b
+name = lasx_xvstelm_b
+asm-fmts = xd, rj, si8, idx
+data-types = VOID, V32QI, CVPOINTER, SI, UQI
+
+/// lasx_xvstelm_h
+name = lasx_xvstelm_h
+asm-fmts = xd, rj, si8, idx
+data-types = VOID, V16HI, CVPOINTER, SI, UQI
+
+/// lasx_xvstelm_w
+name = lasx_xvstelm_w
+asm-fmts = xd, rj, si8, idx
+data-types = VOID, V8SI, CVPOINTER, SI, UQI
+
+/// lasx_xvstelm_d
+name = lasx_xvstelm_d
+asm-fmts = xd, rj, si8, idx
+data-types = VOID, V4DI, CVPOINTER, SI, UQI
+
+/// lasx_xvinsve0_w
+name = lasx_xvinsve0_w
+asm-fmts = xd, xj, ui3
+data-types = V8SI, V8SI, V8SI, UQI
+
+/// lasx_xvinsve0_d
+name = lasx_xvinsve0_d
+asm-fmts = xd, xj, ui2
+data-types = V4DI, V4DI, V4DI, UQI
+
+/// lasx_xvpickve_w
+name = lasx_xvpickve_w
+asm-fmts = xd, xj, ui3
+data-types = V8SI, V8SI, UQI
+
+/// lasx_xvpickve_d
+name = lasx_xvpickve_d
+asm-fmts = xd, xj, ui2
+data-types = V4DI, V4DI, UQI
+
+/// lasx_xvssrlrn_b_h
+name = lasx_xvssrlrn_b_h
+asm-fmts = xd, xj, xk
+data-types = V32QI, V16HI, V16HI
+
+/// lasx_xvssrlrn_h_w
+name = lasx_xvssrlrn_h_w
+asm-fmts = xd, xj, xk
+data-types = V16HI, V8SI, V8SI
+
+/// lasx_xvssrlrn_w_d
+name = lasx_xvssrlrn_w_d
+asm-fmts = xd, xj, xk
+data-types = V8SI, V4DI, V4DI
+
+/// lasx_xvssrln_b_h
+name = lasx_xvssrln_b_h
+asm-fmts = xd, xj, xk
+data-types = V32QI, V16HI, V16HI
+
+/// lasx_xvssrln_h_w
+name = lasx_xvssrln_h_w
+asm-fmts = xd, xj, xk
+data-types = V16HI, V8SI, V8SI
+
+/// lasx_xvssrln_w_d
+name = lasx_xvssrln_w_d
+asm-fmts = xd, xj, xk
+data-types = V8SI, V4DI, V4DI
+
+/// lasx_xvorn_v
+name = lasx_xvorn_v
+asm-fmts = xd, xj, xk
+data-types = UV32QI, UV32QI, UV32QI
+
+/// lasx_xvldi
+name = lasx_xvldi
+asm-fmts = xd, i13
+data-types = V4DI, HI
+
+/// lasx_xvldx
+name = lasx_xvldx
+asm-fmts = xd, rj, rk
+data-types = V32QI, CVPOINTER, DI
+
+/// lasx_xvstx
+name = lasx_xvstx
+asm-fmts = xd, rj, rk
+data-types = VOID, V32QI, CVPOINTER, DI
+
+/// lasx_xvextl_qu_du
+name = lasx_xvextl_qu_du
+asm-fmts = xd, xj
+data-types = UV4DI, UV4DI
+
+/// lasx_xvinsgr2vr_w
+name = lasx_xvinsgr2vr_w
+asm-fmts = xd, rj, ui3
+data-types = V8SI, V8SI, SI, UQI
+
+/// lasx_xvinsgr2vr_d
+name = lasx_xvinsgr2vr_d
+asm-fmts = xd, rj, ui2
+data-types = V4DI, V4DI, DI, UQI
+
+/// lasx_xvreplve0_b
+name = lasx_xvreplve0_b
+asm-fmts = xd, xj
+data-types = V32QI, V32QI
+
+/// lasx_xvreplve0_h
+name = lasx_xvreplve0_h
+asm-fmts = xd, xj
+data-types = V16HI, V16HI
+
+/// lasx_xvreplve0_w
+name = lasx_xvreplve0_w
+asm-fmts = xd, xj
+data-types = V8SI, V8SI
+
+/// lasx_xvreplve0_d
+name = lasx_xvreplve0_d
+asm-fmts = xd, xj
+data-types = V4DI, V4DI
+
+/// lasx_xvreplve0_q
+name = lasx_xvreplve0_q
+asm-fmts = xd, xj
+data-types = V32QI, V32QI
+
+/// lasx_vext2xv_h_b
+name = lasx_vext2xv_h_b
+asm-fmts = xd, xj
+data-types = V16HI, V32QI
+
+/// lasx_vext2xv_w_h
+name = lasx_vext2xv_w_h
+asm-fmts = xd, xj
+data-types = V8SI, V16HI
+
+/// lasx_vext2xv_d_w
+name = lasx_vext2xv_d_w
+asm-fmts = xd, xj
+data-types = V4DI, V8SI
+
+/// lasx_vext2xv_w_b
+name = lasx_vext2xv_w_b
+asm-fmts = xd, xj
+data-types = V8SI, V32QI
+
+/// lasx_vext2xv_d_h
+name = lasx_vext2xv_d_h
+asm-fmts = xd, xj
+data-types = V4DI, V16HI
+
+/// lasx_vext2xv_d_b
+name = lasx_vext2xv_d_b
+asm-fmts = xd, xj
+data-types = V4DI, V32QI
+
+/// lasx_vext2xv_hu_bu
+name = lasx_vext2xv_hu_bu
+asm-fmts = xd, xj
+data-types = V16HI, V32QI
+
+/// lasx_vext2xv_wu_hu
+name = lasx_vext2xv_wu_hu
+asm-fmts = xd, xj
+data-types = V8SI, V16HI
+
+/// lasx_vext2xv_du_wu
+name = lasx_vext2xv_du_wu
+asm-fmts = xd, xj
+data-types = V4DI, V8SI
+
+/// lasx_vext2xv_wu_bu
+name = lasx_vext2xv_wu_bu
+asm-fmts = xd, xj
+data-types = V8SI, V32QI
+
+/// lasx_vext2xv_du_hu
+name = lasx_vext2xv_du_hu
+asm-fmts = xd, xj
+data-types = V4DI, V16HI
+
+/// lasx_vext2xv_du_bu
+name = lasx_vext2xv_du_bu
+asm-fmts = xd, xj
+data-types = V4DI, V32QI
+
+/// lasx_xvpermi_q
+name = lasx_xvpermi_q
+asm-fmts = xd, xj, ui8
+data-types = V32QI, V32QI, V32QI, USI
+
+/// lasx_xvpermi_d
+name = lasx_xvpermi_d
+asm-fmts = xd, xj, ui8
+data-types = V4DI, V4DI, USI
+
+/// lasx_xvperm_w
+name = lasx_xvperm_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvldrepl_b
+name = lasx_xvldrepl_b
+asm-fmts = xd, rj, si12
+data-types = V32QI, CVPOINTER, SI
+
+/// lasx_xvldrepl_h
+name = lasx_xvldrepl_h
+asm-fmts = xd, rj, si11
+data-types = V16HI, CVPOINTER, SI
+
+/// lasx_xvldrepl_w
+name = lasx_xvldrepl_w
+asm-fmts = xd, rj, si10
+data-types = V8SI, CVPOINTER, SI
+
+/// lasx_xvldrepl_d
+name = lasx_xvldrepl_d
+asm-fmts = xd, rj, si9
+data-types = V4DI, CVPOINTER, SI
+
+/// lasx_xvpickve2gr_w
+name = lasx_xvpickve2gr_w
+asm-fmts = rd, xj, ui3
+data-types = SI, V8SI, UQI
+
+/// lasx_xvpickve2gr_wu
+name = lasx_xvpickve2gr_wu
+asm-fmts = rd, xj, ui3
+data-types = USI, V8SI, UQI
+
+/// lasx_xvpickve2gr_d
+name = lasx_xvpickve2gr_d
+asm-fmts = rd, xj, ui2
+data-types = DI, V4DI, UQI
+
+/// lasx_xvpickve2gr_du
+name = lasx_xvpickve2gr_du
+asm-fmts = rd, xj, ui2
+data-types = UDI, V4DI, UQI
+
+/// lasx_xvaddwev_q_d
+name = lasx_xvaddwev_q_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvaddwev_d_w
+name = lasx_xvaddwev_d_w
+asm-fmts = xd, xj, xk
+data-types = V4DI, V8SI, V8SI
+
+/// lasx_xvaddwev_w_h
+name = lasx_xvaddwev_w_h
+asm-fmts = xd, xj, xk
+data-types = V8SI, V16HI, V16HI
+
+/// lasx_xvaddwev_h_b
+name = lasx_xvaddwev_h_b
+asm-fmts = xd, xj, xk
+data-types = V16HI, V32QI, V32QI
+
+/// lasx_xvaddwev_q_du
+name = lasx_xvaddwev_q_du
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV4DI, UV4DI
+
+/// lasx_xvaddwev_d_wu
+name = lasx_xvaddwev_d_wu
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV8SI, UV8SI
+
+/// lasx_xvaddwev_w_hu
+name = lasx_xvaddwev_w_hu
+asm-fmts = xd, xj, xk
+data-types = V8SI, UV16HI, UV16HI
+
+/// lasx_xvaddwev_h_bu
+name = lasx_xvaddwev_h_bu
+asm-fmts = xd, xj, xk
+data-types = V16HI, UV32QI, UV32QI
+
+/// lasx_xvsubwev_q_d
+name = lasx_xvsubwev_q_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvsubwev_d_w
+name = lasx_xvsubwev_d_w
+asm-fmts = xd, xj, xk
+data-types = V4DI, V8SI, V8SI
+
+/// lasx_xvsubwev_w_h
+name = lasx_xvsubwev_w_h
+asm-fmts = xd, xj, xk
+data-types = V8SI, V16HI, V16HI
+
+/// lasx_xvsubwev_h_b
+name = lasx_xvsubwev_h_b
+asm-fmts = xd, xj, xk
+data-types = V16HI, V32QI, V32QI
+
+/// lasx_xvsubwev_q_du
+name = lasx_xvsubwev_q_du
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV4DI, UV4DI
+
+/// lasx_xvsubwev_d_wu
+name = lasx_xvsubwev_d_wu
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV8SI, UV8SI
+
+/// lasx_xvsubwev_w_hu
+name = lasx_xvsubwev_w_hu
+asm-fmts = xd, xj, xk
+data-types = V8SI, UV16HI, UV16HI
+
+/// lasx_xvsubwev_h_bu
+name = lasx_xvsubwev_h_bu
+asm-fmts = xd, xj, xk
+data-types = V16HI, UV32QI, UV32QI
+
+/// lasx_xvmulwev_q_d
+name = lasx_xvmulwev_q_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvmulwev_d_w
+name = lasx_xvmulwev_d_w
+asm-fmts = xd, xj, xk
+data-types = V4DI, V8SI, V8SI
+
+/// lasx_xvmulwev_w_h
+name = lasx_xvmulwev_w_h
+asm-fmts = xd, xj, xk
+data-types = V8SI, V16HI, V16HI
+
+/// lasx_xvmulwev_h_b
+name = lasx_xvmulwev_h_b
+asm-fmts = xd, xj, xk
+data-types = V16HI, V32QI, V32QI
+
+/// lasx_xvmulwev_q_du
+name = lasx_xvmulwev_q_du
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV4DI, UV4DI
+
+/// lasx_xvmulwev_d_wu
+name = lasx_xvmulwev_d_wu
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV8SI, UV8SI
+
+/// lasx_xvmulwev_w_hu
+name = lasx_xvmulwev_w_hu
+asm-fmts = xd, xj, xk
+data-types = V8SI, UV16HI, UV16HI
+
+/// lasx_xvmulwev_h_bu
+name = lasx_xvmulwev_h_bu
+asm-fmts = xd, xj, xk
+data-types = V16HI, UV32QI, UV32QI
+
+/// lasx_xvaddwod_q_d
+name = lasx_xvaddwod_q_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvaddwod_d_w
+name = lasx_xvaddwod_d_w
+asm-fmts = xd, xj, xk
+data-types = V4DI, V8SI, V8SI
+
+/// lasx_xvaddwod_w_h
+name = lasx_xvaddwod_w_h
+asm-fmts = xd, xj, xk
+data-types = V8SI, V16HI, V16HI
+
+/// lasx_xvaddwod_h_b
+name = lasx_xvaddwod_h_b
+asm-fmts = xd, xj, xk
+data-types = V16HI, V32QI, V32QI
+
+/// lasx_xvaddwod_q_du
+name = lasx_xvaddwod_q_du
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV4DI, UV4DI
+
+/// lasx_xvaddwod_d_wu
+name = lasx_xvaddwod_d_wu
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV8SI, UV8SI
+
+/// lasx_xvaddwod_w_hu
+name = lasx_xvaddwod_w_hu
+asm-fmts = xd, xj, xk
+data-types = V8SI, UV16HI, UV16HI
+
+/// lasx_xvaddwod_h_bu
+name = lasx_xvaddwod_h_bu
+asm-fmts = xd, xj, xk
+data-types = V16HI, UV32QI, UV32QI
+
+/// lasx_xvsubwod_q_d
+name = lasx_xvsubwod_q_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvsubwod_d_w
+name = lasx_xvsubwod_d_w
+asm-fmts = xd, xj, xk
+data-types = V4DI, V8SI, V8SI
+
+/// lasx_xvsubwod_w_h
+name = lasx_xvsubwod_w_h
+asm-fmts = xd, xj, xk
+data-types = V8SI, V16HI, V16HI
+
+/// lasx_xvsubwod_h_b
+name = lasx_xvsubwod_h_b
+asm-fmts = xd, xj, xk
+data-types = V16HI, V32QI, V32QI
+
+/// lasx_xvsubwod_q_du
+name = lasx_xvsubwod_q_du
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV4DI, UV4DI
+
+/// lasx_xvsubwod_d_wu
+name = lasx_xvsubwod_d_wu
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV8SI, UV8SI
+
+/// lasx_xvsubwod_w_hu
+name = lasx_xvsubwod_w_hu
+asm-fmts = xd, xj, xk
+data-types = V8SI, UV16HI, UV16HI
+
+/// lasx_xvsubwod_h_bu
+name = lasx_xvsubwod_h_bu
+asm-fmts = xd, xj, xk
+data-types = V16HI, UV32QI, UV32QI
+
+/// lasx_xvmulwod_q_d
+name = lasx_xvmulwod_q_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvmulwod_d_w
+name = lasx_xvmulwod_d_w
+asm-fmts = xd, xj, xk
+data-types = V4DI, V8SI, V8SI
+
+/// lasx_xvmulwod_w_h
+name = lasx_xvmulwod_w_h
+asm-fmts = xd, xj, xk
+data-types = V8SI, V16HI, V16HI
+
+/// lasx_xvmulwod_h_b
+name = lasx_xvmulwod_h_b
+asm-fmts = xd, xj, xk
+data-types = V16HI, V32QI, V32QI
+
+/// lasx_xvmulwod_q_du
+name = lasx_xvmulwod_q_du
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV4DI, UV4DI
+
+/// lasx_xvmulwod_d_wu
+name = lasx_xvmulwod_d_wu
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV8SI, UV8SI
+
+/// lasx_xvmulwod_w_hu
+name = lasx_xvmulwod_w_hu
+asm-fmts = xd, xj, xk
+data-types = V8SI, UV16HI, UV16HI
+
+/// lasx_xvmulwod_h_bu
+name = lasx_xvmulwod_h_bu
+asm-fmts = xd, xj, xk
+data-types = V16HI, UV32QI, UV32QI
+
+/// lasx_xvaddwev_d_wu_w
+name = lasx_xvaddwev_d_wu_w
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV8SI, V8SI
+
+/// lasx_xvaddwev_w_hu_h
+name = lasx_xvaddwev_w_hu_h
+asm-fmts = xd, xj, xk
+data-types = V8SI, UV16HI, V16HI
+
+/// lasx_xvaddwev_h_bu_b
+name = lasx_xvaddwev_h_bu_b
+asm-fmts = xd, xj, xk
+data-types = V16HI, UV32QI, V32QI
+
+/// lasx_xvmulwev_d_wu_w
+name = lasx_xvmulwev_d_wu_w
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV8SI, V8SI
+
+/// lasx_xvmulwev_w_hu_h
+name = lasx_xvmulwev_w_hu_h
+asm-fmts = xd, xj, xk
+data-types = V8SI, UV16HI, V16HI
+
+/// lasx_xvmulwev_h_bu_b
+name = lasx_xvmulwev_h_bu_b
+asm-fmts = xd, xj, xk
+data-types = V16HI, UV32QI, V32QI
+
+/// lasx_xvaddwod_d_wu_w
+name = lasx_xvaddwod_d_wu_w
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV8SI, V8SI
+
+/// lasx_xvaddwod_w_hu_h
+name = lasx_xvaddwod_w_hu_h
+asm-fmts = xd, xj, xk
+data-types = V8SI, UV16HI, V16HI
+
+/// lasx_xvaddwod_h_bu_b
+name = lasx_xvaddwod_h_bu_b
+asm-fmts = xd, xj, xk
+data-types = V16HI, UV32QI, V32QI
+
+/// lasx_xvmulwod_d_wu_w
+name = lasx_xvmulwod_d_wu_w
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV8SI, V8SI
+
+/// lasx_xvmulwod_w_hu_h
+name = lasx_xvmulwod_w_hu_h
+asm-fmts = xd, xj, xk
+data-types = V8SI, UV16HI, V16HI
+
+/// lasx_xvmulwod_h_bu_b
+name = lasx_xvmulwod_h_bu_b
+asm-fmts = xd, xj, xk
+data-types = V16HI, UV32QI, V32QI
+
+/// lasx_xvhaddw_q_d
+name = lasx_xvhaddw_q_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvhaddw_qu_du
+name = lasx_xvhaddw_qu_du
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV4DI, UV4DI
+
+/// lasx_xvhsubw_q_d
+name = lasx_xvhsubw_q_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvhsubw_qu_du
+name = lasx_xvhsubw_qu_du
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV4DI, UV4DI
+
+/// lasx_xvmaddwev_q_d
+name = lasx_xvmaddwev_q_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI, V4DI
+
+/// lasx_xvmaddwev_d_w
+name = lasx_xvmaddwev_d_w
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V8SI, V8SI
+
+/// lasx_xvmaddwev_w_h
+name = lasx_xvmaddwev_w_h
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V16HI, V16HI
+
+/// lasx_xvmaddwev_h_b
+name = lasx_xvmaddwev_h_b
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V32QI, V32QI
+
+/// lasx_xvmaddwev_q_du
+name = lasx_xvmaddwev_q_du
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV4DI, UV4DI, UV4DI
+
+/// lasx_xvmaddwev_d_wu
+name = lasx_xvmaddwev_d_wu
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV4DI, UV8SI, UV8SI
+
+/// lasx_xvmaddwev_w_hu
+name = lasx_xvmaddwev_w_hu
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV8SI, UV16HI, UV16HI
+
+/// lasx_xvmaddwev_h_bu
+name = lasx_xvmaddwev_h_bu
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV16HI, UV32QI, UV32QI
+
+/// lasx_xvmaddwod_q_d
+name = lasx_xvmaddwod_q_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI, V4DI
+
+/// lasx_xvmaddwod_d_w
+name = lasx_xvmaddwod_d_w
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V8SI, V8SI
+
+/// lasx_xvmaddwod_w_h
+name = lasx_xvmaddwod_w_h
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V16HI, V16HI
+
+/// lasx_xvmaddwod_h_b
+name = lasx_xvmaddwod_h_b
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V32QI, V32QI
+
+/// lasx_xvmaddwod_q_du
+name = lasx_xvmaddwod_q_du
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV4DI, UV4DI, UV4DI
+
+/// lasx_xvmaddwod_d_wu
+name = lasx_xvmaddwod_d_wu
+asm-fmts = xd, xj, xk
+data-types = UV4DI, UV4DI, UV8SI, UV8SI
+
+/// lasx_xvmaddwod_w_hu
+name = lasx_xvmaddwod_w_hu
+asm-fmts = xd, xj, xk
+data-types = UV8SI, UV8SI, UV16HI, UV16HI
+
+/// lasx_xvmaddwod_h_bu
+name = lasx_xvmaddwod_h_bu
+asm-fmts = xd, xj, xk
+data-types = UV16HI, UV16HI, UV32QI, UV32QI
+
+/// lasx_xvmaddwev_q_du_d
+name = lasx_xvmaddwev_q_du_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, UV4DI, V4DI
+
+/// lasx_xvmaddwev_d_wu_w
+name = lasx_xvmaddwev_d_wu_w
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, UV8SI, V8SI
+
+/// lasx_xvmaddwev_w_hu_h
+name = lasx_xvmaddwev_w_hu_h
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, UV16HI, V16HI
+
+/// lasx_xvmaddwev_h_bu_b
+name = lasx_xvmaddwev_h_bu_b
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, UV32QI, V32QI
+
+/// lasx_xvmaddwod_q_du_d
+name = lasx_xvmaddwod_q_du_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, UV4DI, V4DI
+
+/// lasx_xvmaddwod_d_wu_w
+name = lasx_xvmaddwod_d_wu_w
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, UV8SI, V8SI
+
+/// lasx_xvmaddwod_w_hu_h
+name = lasx_xvmaddwod_w_hu_h
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, UV16HI, V16HI
+
+/// lasx_xvmaddwod_h_bu_b
+name = lasx_xvmaddwod_h_bu_b
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, UV32QI, V32QI
+
+/// lasx_xvrotr_b
+name = lasx_xvrotr_b
+asm-fmts = xd, xj, xk
+data-types = V32QI, V32QI, V32QI
+
+/// lasx_xvrotr_h
+name = lasx_xvrotr_h
+asm-fmts = xd, xj, xk
+data-types = V16HI, V16HI, V16HI
+
+/// lasx_xvrotr_w
+name = lasx_xvrotr_w
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SI, V8SI
+
+/// lasx_xvrotr_d
+name = lasx_xvrotr_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvadd_q
+name = lasx_xvadd_q
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvsub_q
+name = lasx_xvsub_q
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DI, V4DI
+
+/// lasx_xvaddwev_q_du_d
+name = lasx_xvaddwev_q_du_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV4DI, V4DI
+
+/// lasx_xvaddwod_q_du_d
+name = lasx_xvaddwod_q_du_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV4DI, V4DI
+
+/// lasx_xvmulwev_q_du_d
+name = lasx_xvmulwev_q_du_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV4DI, V4DI
+
+/// lasx_xvmulwod_q_du_d
+name = lasx_xvmulwod_q_du_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, UV4DI, V4DI
+
+/// lasx_xvmskgez_b
+name = lasx_xvmskgez_b
+asm-fmts = xd, xj
+data-types = V32QI, V32QI
+
+/// lasx_xvmsknz_b
+name = lasx_xvmsknz_b
+asm-fmts = xd, xj
+data-types = V32QI, V32QI
+
+/// lasx_xvexth_h_b
+name = lasx_xvexth_h_b
+asm-fmts = xd, xj
+data-types = V16HI, V32QI
+
+/// lasx_xvexth_w_h
+name = lasx_xvexth_w_h
+asm-fmts = xd, xj
+data-types = V8SI, V16HI
+
+/// lasx_xvexth_d_w
+name = lasx_xvexth_d_w
+asm-fmts = xd, xj
+data-types = V4DI, V8SI
+
+/// lasx_xvexth_q_d
+name = lasx_xvexth_q_d
+asm-fmts = xd, xj
+data-types = V4DI, V4DI
+
+/// lasx_xvexth_hu_bu
+name = lasx_xvexth_hu_bu
+asm-fmts = xd, xj
+data-types = UV16HI, UV32QI
+
+/// lasx_xvexth_wu_hu
+name = lasx_xvexth_wu_hu
+asm-fmts = xd, xj
+data-types = UV8SI, UV16HI
+
+/// lasx_xvexth_du_wu
+name = lasx_xvexth_du_wu
+asm-fmts = xd, xj
+data-types = UV4DI, UV8SI
+
+/// lasx_xvexth_qu_du
+name = lasx_xvexth_qu_du
+asm-fmts = xd, xj
+data-types = UV4DI, UV4DI
+
+/// lasx_xvrotri_b
+name = lasx_xvrotri_b
+asm-fmts = xd, xj, ui3
+data-types = V32QI, V32QI, UQI
+
+/// lasx_xvrotri_h
+name = lasx_xvrotri_h
+asm-fmts = xd, xj, ui4
+data-types = V16HI, V16HI, UQI
+
+/// lasx_xvrotri_w
+name = lasx_xvrotri_w
+asm-fmts = xd, xj, ui5
+data-types = V8SI, V8SI, UQI
+
+/// lasx_xvrotri_d
+name = lasx_xvrotri_d
+asm-fmts = xd, xj, ui6
+data-types = V4DI, V4DI, UQI
+
+/// lasx_xvextl_q_d
+name = lasx_xvextl_q_d
+asm-fmts = xd, xj
+data-types = V4DI, V4DI
+
+/// lasx_xvsrlni_b_h
+name = lasx_xvsrlni_b_h
+asm-fmts = xd, xj, ui4
+data-types = V32QI, V32QI, V32QI, USI
+
+/// lasx_xvsrlni_h_w
+name = lasx_xvsrlni_h_w
+asm-fmts = xd, xj, ui5
+data-types = V16HI, V16HI, V16HI, USI
+
+/// lasx_xvsrlni_w_d
+name = lasx_xvsrlni_w_d
+asm-fmts = xd, xj, ui6
+data-types = V8SI, V8SI, V8SI, USI
+
+/// lasx_xvsrlni_d_q
+name = lasx_xvsrlni_d_q
+asm-fmts = xd, xj, ui7
+data-types = V4DI, V4DI, V4DI, USI
+
+/// lasx_xvsrlrni_b_h
+name = lasx_xvsrlrni_b_h
+asm-fmts = xd, xj, ui4
+data-types = V32QI, V32QI, V32QI, USI
+
+/// lasx_xvsrlrni_h_w
+name = lasx_xvsrlrni_h_w
+asm-fmts = xd, xj, ui5
+data-types = V16HI, V16HI, V16HI, USI
+
+/// lasx_xvsrlrni_w_d
+name = lasx_xvsrlrni_w_d
+asm-fmts = xd, xj, ui6
+data-types = V8SI, V8SI, V8SI, USI
+
+/// lasx_xvsrlrni_d_q
+name = lasx_xvsrlrni_d_q
+asm-fmts = xd, xj, ui7
+data-types = V4DI, V4DI, V4DI, USI
+
+/// lasx_xvssrlni_b_h
+name = lasx_xvssrlni_b_h
+asm-fmts = xd, xj, ui4
+data-types = V32QI, V32QI, V32QI, USI
+
+/// lasx_xvssrlni_h_w
+name = lasx_xvssrlni_h_w
+asm-fmts = xd, xj, ui5
+data-types = V16HI, V16HI, V16HI, USI
+
+/// lasx_xvssrlni_w_d
+name = lasx_xvssrlni_w_d
+asm-fmts = xd, xj, ui6
+data-types = V8SI, V8SI, V8SI, USI
+
+/// lasx_xvssrlni_d_q
+name = lasx_xvssrlni_d_q
+asm-fmts = xd, xj, ui7
+data-types = V4DI, V4DI, V4DI, USI
+
+/// lasx_xvssrlni_bu_h
+name = lasx_xvssrlni_bu_h
+asm-fmts = xd, xj, ui4
+data-types = UV32QI, UV32QI, V32QI, USI
+
+/// lasx_xvssrlni_hu_w
+name = lasx_xvssrlni_hu_w
+asm-fmts = xd, xj, ui5
+data-types = UV16HI, UV16HI, V16HI, USI
+
+/// lasx_xvssrlni_wu_d
+name = lasx_xvssrlni_wu_d
+asm-fmts = xd, xj, ui6
+data-types = UV8SI, UV8SI, V8SI, USI
+
+/// lasx_xvssrlni_du_q
+name = lasx_xvssrlni_du_q
+asm-fmts = xd, xj, ui7
+data-types = UV4DI, UV4DI, V4DI, USI
+
+/// lasx_xvssrlrni_b_h
+name = lasx_xvssrlrni_b_h
+asm-fmts = xd, xj, ui4
+data-types = V32QI, V32QI, V32QI, USI
+
+/// lasx_xvssrlrni_h_w
+name = lasx_xvssrlrni_h_w
+asm-fmts = xd, xj, ui5
+data-types = V16HI, V16HI, V16HI, USI
+
+/// lasx_xvssrlrni_w_d
+name = lasx_xvssrlrni_w_d
+asm-fmts = xd, xj, ui6
+data-types = V8SI, V8SI, V8SI, USI
+
+/// lasx_xvssrlrni_d_q
+name = lasx_xvssrlrni_d_q
+asm-fmts = xd, xj, ui7
+data-types = V4DI, V4DI, V4DI, USI
+
+/// lasx_xvssrlrni_bu_h
+name = lasx_xvssrlrni_bu_h
+asm-fmts = xd, xj, ui4
+data-types = UV32QI, UV32QI, V32QI, USI
+
+/// lasx_xvssrlrni_hu_w
+name = lasx_xvssrlrni_hu_w
+asm-fmts = xd, xj, ui5
+data-types = UV16HI, UV16HI, V16HI, USI
+
+/// lasx_xvssrlrni_wu_d
+name = lasx_xvssrlrni_wu_d
+asm-fmts = xd, xj, ui6
+data-types = UV8SI, UV8SI, V8SI, USI
+
+/// lasx_xvssrlrni_du_q
+name = lasx_xvssrlrni_du_q
+asm-fmts = xd, xj, ui7
+data-types = UV4DI, UV4DI, V4DI, USI
+
+/// lasx_xvsrani_b_h
+name = lasx_xvsrani_b_h
+asm-fmts = xd, xj, ui4
+data-types = V32QI, V32QI, V32QI, USI
+
+/// lasx_xvsrani_h_w
+name = lasx_xvsrani_h_w
+asm-fmts = xd, xj, ui5
+data-types = V16HI, V16HI, V16HI, USI
+
+/// lasx_xvsrani_w_d
+name = lasx_xvsrani_w_d
+asm-fmts = xd, xj, ui6
+data-types = V8SI, V8SI, V8SI, USI
+
+/// lasx_xvsrani_d_q
+name = lasx_xvsrani_d_q
+asm-fmts = xd, xj, ui7
+data-types = V4DI, V4DI, V4DI, USI
+
+/// lasx_xvsrarni_b_h
+name = lasx_xvsrarni_b_h
+asm-fmts = xd, xj, ui4
+data-types = V32QI, V32QI, V32QI, USI
+
+/// lasx_xvsrarni_h_w
+name = lasx_xvsrarni_h_w
+asm-fmts = xd, xj, ui5
+data-types = V16HI, V16HI, V16HI, USI
+
+/// lasx_xvsrarni_w_d
+name = lasx_xvsrarni_w_d
+asm-fmts = xd, xj, ui6
+data-types = V8SI, V8SI, V8SI, USI
+
+/// lasx_xvsrarni_d_q
+name = lasx_xvsrarni_d_q
+asm-fmts = xd, xj, ui7
+data-types = V4DI, V4DI, V4DI, USI
+
+/// lasx_xvssrani_b_h
+name = lasx_xvssrani_b_h
+asm-fmts = xd, xj, ui4
+data-types = V32QI, V32QI, V32QI, USI
+
+/// lasx_xvssrani_h_w
+name = lasx_xvssrani_h_w
+asm-fmts = xd, xj, ui5
+data-types = V16HI, V16HI, V16HI, USI
+
+/// lasx_xvssrani_w_d
+name = lasx_xvssrani_w_d
+asm-fmts = xd, xj, ui6
+data-types = V8SI, V8SI, V8SI, USI
+
+/// lasx_xvssrani_d_q
+name = lasx_xvssrani_d_q
+asm-fmts = xd, xj, ui7
+data-types = V4DI, V4DI, V4DI, USI
+
+/// lasx_xvssrani_bu_h
+name = lasx_xvssrani_bu_h
+asm-fmts = xd, xj, ui4
+data-types = UV32QI, UV32QI, V32QI, USI
+
+/// lasx_xvssrani_hu_w
+name = lasx_xvssrani_hu_w
+asm-fmts = xd, xj, ui5
+data-types = UV16HI, UV16HI, V16HI, USI
+
+/// lasx_xvssrani_wu_d
+name = lasx_xvssrani_wu_d
+asm-fmts = xd, xj, ui6
+data-types = UV8SI, UV8SI, V8SI, USI
+
+/// lasx_xvssrani_du_q
+name = lasx_xvssrani_du_q
+asm-fmts = xd, xj, ui7
+data-types = UV4DI, UV4DI, V4DI, USI
+
+/// lasx_xvssrarni_b_h
+name = lasx_xvssrarni_b_h
+asm-fmts = xd, xj, ui4
+data-types = V32QI, V32QI, V32QI, USI
+
+/// lasx_xvssrarni_h_w
+name = lasx_xvssrarni_h_w
+asm-fmts = xd, xj, ui5
+data-types = V16HI, V16HI, V16HI, USI
+
+/// lasx_xvssrarni_w_d
+name = lasx_xvssrarni_w_d
+asm-fmts = xd, xj, ui6
+data-types = V8SI, V8SI, V8SI, USI
+
+/// lasx_xvssrarni_d_q
+name = lasx_xvssrarni_d_q
+asm-fmts = xd, xj, ui7
+data-types = V4DI, V4DI, V4DI, USI
+
+/// lasx_xvssrarni_bu_h
+name = lasx_xvssrarni_bu_h
+asm-fmts = xd, xj, ui4
+data-types = UV32QI, UV32QI, V32QI, USI
+
+/// lasx_xvssrarni_hu_w
+name = lasx_xvssrarni_hu_w
+asm-fmts = xd, xj, ui5
+data-types = UV16HI, UV16HI, V16HI, USI
+
+/// lasx_xvssrarni_wu_d
+name = lasx_xvssrarni_wu_d
+asm-fmts = xd, xj, ui6
+data-types = UV8SI, UV8SI, V8SI, USI
+
+/// lasx_xvssrarni_du_q
+name = lasx_xvssrarni_du_q
+asm-fmts = xd, xj, ui7
+data-types = UV4DI, UV4DI, V4DI, USI
+
+/// lasx_xbnz_b
+name = lasx_xbnz_b
+asm-fmts = cd, xj
+data-types = SI, UV32QI
+
+/// lasx_xbnz_d
+name = lasx_xbnz_d
+asm-fmts = cd, xj
+data-types = SI, UV4DI
+
+/// lasx_xbnz_h
+name = lasx_xbnz_h
+asm-fmts = cd, xj
+data-types = SI, UV16HI
+
+/// lasx_xbnz_v
+name = lasx_xbnz_v
+asm-fmts = cd, xj
+data-types = SI, UV32QI
+
+/// lasx_xbnz_w
+name = lasx_xbnz_w
+asm-fmts = cd, xj
+data-types = SI, UV8SI
+
+/// lasx_xbz_b
+name = lasx_xbz_b
+asm-fmts = cd, xj
+data-types = SI, UV32QI
+
+/// lasx_xbz_d
+name = lasx_xbz_d
+asm-fmts = cd, xj
+data-types = SI, UV4DI
+
+/// lasx_xbz_h
+name = lasx_xbz_h
+asm-fmts = cd, xj
+data-types = SI, UV16HI
+
+/// lasx_xbz_v
+name = lasx_xbz_v
+asm-fmts = cd, xj
+data-types = SI, UV32QI
+
+/// lasx_xbz_w
+name = lasx_xbz_w
+asm-fmts = cd, xj
+data-types = SI, UV8SI
+
+/// lasx_xvfcmp_caf_d
+name = lasx_xvfcmp_caf_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_caf_s
+name = lasx_xvfcmp_caf_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_ceq_d
+name = lasx_xvfcmp_ceq_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_ceq_s
+name = lasx_xvfcmp_ceq_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_cle_d
+name = lasx_xvfcmp_cle_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_cle_s
+name = lasx_xvfcmp_cle_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_clt_d
+name = lasx_xvfcmp_clt_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_clt_s
+name = lasx_xvfcmp_clt_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_cne_d
+name = lasx_xvfcmp_cne_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_cne_s
+name = lasx_xvfcmp_cne_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_cor_d
+name = lasx_xvfcmp_cor_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_cor_s
+name = lasx_xvfcmp_cor_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_cueq_d
+name = lasx_xvfcmp_cueq_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_cueq_s
+name = lasx_xvfcmp_cueq_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_cule_d
+name = lasx_xvfcmp_cule_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_cule_s
+name = lasx_xvfcmp_cule_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_cult_d
+name = lasx_xvfcmp_cult_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_cult_s
+name = lasx_xvfcmp_cult_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_cun_d
+name = lasx_xvfcmp_cun_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_cune_d
+name = lasx_xvfcmp_cune_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_cune_s
+name = lasx_xvfcmp_cune_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_cun_s
+name = lasx_xvfcmp_cun_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_saf_d
+name = lasx_xvfcmp_saf_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_saf_s
+name = lasx_xvfcmp_saf_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_seq_d
+name = lasx_xvfcmp_seq_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_seq_s
+name = lasx_xvfcmp_seq_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_sle_d
+name = lasx_xvfcmp_sle_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_sle_s
+name = lasx_xvfcmp_sle_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_slt_d
+name = lasx_xvfcmp_slt_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_slt_s
+name = lasx_xvfcmp_slt_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_sne_d
+name = lasx_xvfcmp_sne_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_sne_s
+name = lasx_xvfcmp_sne_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_sor_d
+name = lasx_xvfcmp_sor_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_sor_s
+name = lasx_xvfcmp_sor_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_sueq_d
+name = lasx_xvfcmp_sueq_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_sueq_s
+name = lasx_xvfcmp_sueq_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_sule_d
+name = lasx_xvfcmp_sule_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_sule_s
+name = lasx_xvfcmp_sule_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_sult_d
+name = lasx_xvfcmp_sult_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_sult_s
+name = lasx_xvfcmp_sult_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_sun_d
+name = lasx_xvfcmp_sun_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_sune_d
+name = lasx_xvfcmp_sune_d
+asm-fmts = xd, xj, xk
+data-types = V4DI, V4DF, V4DF
+
+/// lasx_xvfcmp_sune_s
+name = lasx_xvfcmp_sune_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvfcmp_sun_s
+name = lasx_xvfcmp_sun_s
+asm-fmts = xd, xj, xk
+data-types = V8SI, V8SF, V8SF
+
+/// lasx_xvpickve_d_f
+name = lasx_xvpickve_d_f
+asm-fmts = xd, xj, ui2
+data-types = V4DF, V4DF, UQI
+
+/// lasx_xvpickve_w_f
+name = lasx_xvpickve_w_f
+asm-fmts = xd, xj, ui3
+data-types = V8SF, V8SF, UQI
+
+/// lasx_xvrepli_b
+name = lasx_xvrepli_b
+asm-fmts = xd, si10
+data-types = V32QI, HI
+
+/// lasx_xvrepli_d
+name = lasx_xvrepli_d
+asm-fmts = xd, si10
+data-types = V4DI, HI
+
+/// lasx_xvrepli_h
+name = lasx_xvrepli_h
+asm-fmts = xd, si10
+data-types = V16HI, HI
+
+/// lasx_xvrepli_w
+name = lasx_xvrepli_w
+asm-fmts = xd, si10
+data-types = V8SI, HI
@@ -0,0 +1,3584 @@
+// This code is automatically generated. DO NOT MODIFY.
+// ```
+// OUT_DIR=`pwd`/crates/stdarch-gen-loongarch cargo run -p stdarch-gen-loongarch -- crates/stdarch-gen-loongarch/lsxintrin.h
+// ```
+
+/// lsx_vsll_b
+name = lsx_vsll_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vsll_h
+name = lsx_vsll_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vsll_w
+name = lsx_vsll_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vsll_d
+name = lsx_vsll_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vslli_b
+name = lsx_vslli_b
+asm-fmts = vd, vj, ui3
+data-types = V16QI, V16QI, UQI
+
+/// lsx_vslli_h
+name = lsx_vslli_h
+asm-fmts = vd, vj, ui4
+data-types = V8HI, V8HI, UQI
+
+/// lsx_vslli_w
+name = lsx_vslli_w
+asm-fmts = vd, vj, ui5
+data-types = V4SI, V4SI, UQI
+
+/// lsx_vslli_d
+name = lsx_vslli_d
+asm-fmts = vd, vj, ui6
+data-types = V2DI, V2DI, UQI
+
+/// lsx_vsra_b
+name = lsx_vsra_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vsra_h
+name = lsx_vsra_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vsra_w
+name = lsx_vsra_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vsra_d
+name = lsx_vsra_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vsrai_b
+name = lsx_vsrai_b
+asm-fmts = vd, vj, ui3
+data-types = V16QI, V16QI, UQI
+
+/// lsx_vsrai_h
+name = lsx_vsrai_h
+asm-fmts = vd, vj, ui4
+data-types = V8HI, V8HI, UQI
+
+/// lsx_vsrai_w
+name = lsx_vsrai_w
+asm-fmts = vd, vj, ui5
+data-types = V4SI, V4SI, UQI
+
+/// lsx_vsrai_d
+name = lsx_vsrai_d
+asm-fmts = vd, vj, ui6
+data-types = V2DI, V2DI, UQI
+
+/// lsx_vsrar_b
+name = lsx_vsrar_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vsrar_h
+name = lsx_vsrar_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vsrar_w
+name = lsx_vsrar_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vsrar_d
+name = lsx_vsrar_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vsrari_b
+name = lsx_vsrari_b
+asm-fmts = vd, vj, ui3
+data-types = V16QI, V16QI, UQI
+
+/// lsx_vsrari_h
+name = lsx_vsrari_h
+asm-fmts = vd, vj, ui4
+data-types = V8HI, V8HI, UQI
+
+/// lsx_vsrari_w
+name = lsx_vsrari_w
+asm-fmts = vd, vj, ui5
+data-types = V4SI, V4SI, UQI
+
+/// lsx_vsrari_d
+name = lsx_vsrari_d
+asm-fmts = vd, vj, ui6
+data-types = V2DI, V2DI, UQI
+
+/// lsx_vsrl_b
+name = lsx_vsrl_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vsrl_h
+name = lsx_vsrl_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vsrl_w
+name = lsx_vsrl_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vsrl_d
+name = lsx_vsrl_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vsrli_b
+name = lsx_vsrli_b
+asm-fmts = vd, vj, ui3
+data-types = V16QI, V16QI, UQI
+
+/// lsx_vsrli_h
+name = lsx_vsrli_h
+asm-fmts = vd, vj, ui4
+data-types = V8HI, V8HI, UQI
+
+/// lsx_vsrli_w
+name = lsx_vsrli_w
+asm-fmts = vd, vj, ui5
+data-types = V4SI, V4SI, UQI
+
+/// lsx_vsrli_d
+name = lsx_vsrli_d
+asm-fmts = vd, vj, ui6
+data-types = V2DI, V2DI, UQI
+
+/// lsx_vsrlr_b
+name = lsx_vsrlr_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vsrlr_h
+name = lsx_vsrlr_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vsrlr_w
+name = lsx_vsrlr_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vsrlr_d
+name = lsx_vsrlr_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vsrlri_b
+name = lsx_vsrlri_b
+asm-fmts = vd, vj, ui3
+data-types = V16QI, V16QI, UQI
+
+/// lsx_vsrlri_h
+name = lsx_vsrlri_h
+asm-fmts = vd, vj, ui4
+data-types = V8HI, V8HI, UQI
+
+/// lsx_vsrlri_w
+name = lsx_vsrlri_w
+asm-fmts = vd, vj, ui5
+data-types = V4SI, V4SI, UQI
+
+/// lsx_vsrlri_d
+name = lsx_vsrlri_d
+asm-fmts = vd, vj, ui6
+data-types = V2DI, V2DI, UQI
+
+/// lsx_vbitclr_b
+name = lsx_vbitclr_b
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV16QI, UV16QI
+
+/// lsx_vbitclr_h
+name = lsx_vbitclr_h
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV8HI, UV8HI
+
+/// lsx_vbitclr_w
+name = lsx_vbitclr_w
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV4SI, UV4SI
+
+/// lsx_vbitclr_d
+name = lsx_vbitclr_d
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV2DI, UV2DI
+
+/// lsx_vbitclri_b
+name = lsx_vbitclri_b
+asm-fmts = vd, vj, ui3
+data-types = UV16QI, UV16QI, UQI
+
+/// lsx_vbitclri_h
+name = lsx_vbitclri_h
+asm-fmts = vd, vj, ui4
+data-types = UV8HI, UV8HI, UQI
+
+/// lsx_vbitclri_w
+name = lsx_vbitclri_w
+asm-fmts = vd, vj, ui5
+data-types = UV4SI, UV4SI, UQI
+
+/// lsx_vbitclri_d
+name = lsx_vbitclri_d
+asm-fmts = vd, vj, ui6
+data-types = UV2DI, UV2DI, UQI
+
+/// lsx_vbitset_b
+name = lsx_vbitset_b
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV16QI, UV16QI
+
+/// lsx_vbitset_h
+name = lsx_vbitset_h
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV8HI, UV8HI
+
+/// lsx_vbitset_w
+name = lsx_vbitset_w
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV4SI, UV4SI
+
+/// lsx_vbitset_d
+name = lsx_vbitset_d
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV2DI, UV2DI
+
+/// lsx_vbitseti_b
+name = lsx_vbitseti_b
+asm-fmts = vd, vj, ui3
+data-types = UV16QI, UV16QI, UQI
+
+/// lsx_vbitseti_h
+name = lsx_vbitseti_h
+asm-fmts = vd, vj, ui4
+data-types = UV8HI, UV8HI, UQI
+
+/// lsx_vbitseti_w
+name = lsx_vbitseti_w
+asm-fmts = vd, vj, ui5
+data-types = UV4SI, UV4SI, UQI
+
+/// lsx_vbitseti_d
+name = lsx_vbitseti_d
+asm-fmts = vd, vj, ui6
+data-types = UV2DI, UV2DI, UQI
+
+/// lsx_vbitrev_b
+name = lsx_vbitrev_b
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV16QI, UV16QI
+
+/// lsx_vbitrev_h
+name = lsx_vbitrev_h
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV8HI, UV8HI
+
+/// lsx_vbitrev_w
+name = lsx_vbitrev_w
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV4SI, UV4SI
+
+/// lsx_vbitrev_d
+name = lsx_vbitrev_d
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV2DI, UV2DI
+
+/// lsx_vbitrevi_b
+name = lsx_vbitrevi_b
+asm-fmts = vd, vj, ui3
+data-types = UV16QI, UV16QI, UQI
+
+/// lsx_vbitrevi_h
+name = lsx_vbitrevi_h
+asm-fmts = vd, vj, ui4
+data-types = UV8HI, UV8HI, UQI
+
+/// lsx_vbitrevi_w
+name = lsx_vbitrevi_w
+asm-fmts = vd, vj, ui5
+data-types = UV4SI, UV4SI, UQI
+
+/// lsx_vbitrevi_d
+name = lsx_vbitrevi_d
+asm-fmts = vd, vj, ui6
+data-types = UV2DI, UV2DI, UQI
+
+/// lsx_vadd_b
+name = lsx_vadd_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vadd_h
+name = lsx_vadd_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vadd_w
+name = lsx_vadd_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vadd_d
+name = lsx_vadd_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vaddi_bu
+name = lsx_vaddi_bu
+asm-fmts = vd, vj, ui5
+data-types = V16QI, V16QI, UQI
+
+/// lsx_vaddi_hu
+name = lsx_vaddi_hu
+asm-fmts = vd, vj, ui5
+data-types = V8HI, V8HI, UQI
+
+/// lsx_vaddi_wu
+name = lsx_vaddi_wu
+asm-fmts = vd, vj, ui5
+data-types = V4SI, V4SI, UQI
+
+/// lsx_vaddi_du
+name = lsx_vaddi_du
+asm-fmts = vd, vj, ui5
+data-types = V2DI, V2DI, UQI
+
+/// lsx_vsub_b
+name = lsx_vsub_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vsub_h
+name = lsx_vsub_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vsub_w
+name = lsx_vsub_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vsub_d
+name = lsx_vsub_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vsubi_bu
+name = lsx_vsubi_bu
+asm-fmts = vd, vj, ui5
+data-types = V16QI, V16QI, UQI
+
+/// lsx_vsubi_hu
+name = lsx_vsubi_hu
+asm-fmts = vd, vj, ui5
+data-types = V8HI, V8HI, UQI
+
+/// lsx_vsubi_wu
+name = lsx_vsubi_wu
+asm-fmts = vd, vj, ui5
+data-types = V4SI, V4SI, UQI
+
+/// lsx_vsubi_du
+name = lsx_vsubi_du
+asm-fmts = vd, vj, ui5
+data-types = V2DI, V2DI, UQI
+
+/// lsx_vmax_b
+name = lsx_vmax_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vmax_h
+name = lsx_vmax_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vmax_w
+name = lsx_vmax_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vmax_d
+name = lsx_vmax_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vmaxi_b
+name = lsx_vmaxi_b
+asm-fmts = vd, vj, si5
+data-types = V16QI, V16QI, QI
+
+/// lsx_vmaxi_h
+name = lsx_vmaxi_h
+asm-fmts = vd, vj, si5
+data-types = V8HI, V8HI, QI
+
+/// lsx_vmaxi_w
+name = lsx_vmaxi_w
+asm-fmts = vd, vj, si5
+data-types = V4SI, V4SI, QI
+
+/// lsx_vmaxi_d
+name = lsx_vmaxi_d
+asm-fmts = vd, vj, si5
+data-types = V2DI, V2DI, QI
+
+/// lsx_vmax_bu
+name = lsx_vmax_bu
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV16QI, UV16QI
+
+/// lsx_vmax_hu
+name = lsx_vmax_hu
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV8HI, UV8HI
+
+/// lsx_vmax_wu
+name = lsx_vmax_wu
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV4SI, UV4SI
+
+/// lsx_vmax_du
+name = lsx_vmax_du
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV2DI, UV2DI
+
+/// lsx_vmaxi_bu
+name = lsx_vmaxi_bu
+asm-fmts = vd, vj, ui5
+data-types = UV16QI, UV16QI, UQI
+
+/// lsx_vmaxi_hu
+name = lsx_vmaxi_hu
+asm-fmts = vd, vj, ui5
+data-types = UV8HI, UV8HI, UQI
+
+/// lsx_vmaxi_wu
+name = lsx_vmaxi_wu
+asm-fmts = vd, vj, ui5
+data-types = UV4SI, UV4SI, UQI
+
+/// lsx_vmaxi_du
+name = lsx_vmaxi_du
+asm-fmts = vd, vj, ui5
+data-types = UV2DI, UV2DI, UQI
+
+/// lsx_vmin_b
+name = lsx_vmin_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vmin_h
+name = lsx_vmin_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vmin_w
+name = lsx_vmin_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vmin_d
+name = lsx_vmin_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vmini_b
+name = lsx_vmini_b
+asm-fmts = vd, vj, si5
+data-types = V16QI, V16QI, QI
+
+/// lsx_vmini_h
+name = lsx_vmini_h
+asm-fmts = vd, vj, si5
+data-types = V8HI, V8HI, QI
+
+/// lsx_vmini_w
+name = lsx_vmini_w
+asm-fmts = vd, vj, si5
+data-types = V4SI, V4SI, QI
+
+/// lsx_vmini_d
+name = lsx_vmini_d
+asm-fmts = vd, vj, si5
+data-types = V2DI, V2DI, QI
+
+/// lsx_vmin_bu
+name = lsx_vmin_bu
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV16QI, UV16QI
+
+/// lsx_vmin_hu
+name = lsx_vmin_hu
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV8HI, UV8HI
+
+/// lsx_vmin_wu
+name = lsx_vmin_wu
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV4SI, UV4SI
+
+/// lsx_vmin_du
+name = lsx_vmin_du
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV2DI, UV2DI
+
+/// lsx_vmini_bu
+name = lsx_vmini_bu
+asm-fmts = vd, vj, ui5
+data-types = UV16QI, UV16QI, UQI
+
+/// lsx_vmini_hu
+name = lsx_vmini_hu
+asm-fmts = vd, vj, ui5
+data-types = UV8HI, UV8HI, UQI
+
+/// lsx_vmini_wu
+name = lsx_vmini_wu
+asm-fmts = vd, vj, ui5
+data-types = UV4SI, UV4SI, UQI
+
+/// lsx_vmini_du
+name = lsx_vmini_du
+asm-fmts = vd, vj, ui5
+data-types = UV2DI, UV2DI, UQI
+
+/// lsx_vseq_b
+name = lsx_vseq_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vseq_h
+name = lsx_vseq_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vseq_w
+name = lsx_vseq_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vseq_d
+name = lsx_vseq_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vseqi_b
+name = lsx_vseqi_b
+asm-fmts = vd, vj, si5
+data-types = V16QI, V16QI, QI
+
+/// lsx_vseqi_h
+name = lsx_vseqi_h
+asm-fmts = vd, vj, si5
+data-types = V8HI, V8HI, QI
+
+/// lsx_vseqi_w
+name = lsx_vseqi_w
+asm-fmts = vd, vj, si5
+data-types = V4SI, V4SI, QI
+
+/// lsx_vseqi_d
+name = lsx_vseqi_d
+asm-fmts = vd, vj, si5
+data-types = V2DI, V2DI, QI
+
+/// lsx_vslti_b
+name = lsx_vslti_b
+asm-fmts = vd, vj, si5
+data-types = V16QI, V16QI, QI
+
+/// lsx_vslt_b
+name = lsx_vslt_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vslt_h
+name = lsx_vslt_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vslt_w
+name = lsx_vslt_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vslt_d
+name = lsx_vslt_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vslti_h
+name = lsx_vslti_h
+asm-fmts = vd, vj, si5
+data-types = V8HI, V8HI, QI
+
+/// lsx_vslti_w
+name = lsx_vslti_w
+asm-fmts = vd, vj, si5
+data-types = V4SI, V4SI, QI
+
+/// lsx_vslti_d
+name = lsx_vslti_d
+asm-fmts = vd, vj, si5
+data-types = V2DI, V2DI, QI
+
+/// lsx_vslt_bu
+name = lsx_vslt_bu
+asm-fmts = vd, vj, vk
+data-types = V16QI, UV16QI, UV16QI
+
+/// lsx_vslt_hu
+name = lsx_vslt_hu
+asm-fmts = vd, vj, vk
+data-types = V8HI, UV8HI, UV8HI
+
+/// lsx_vslt_wu
+name = lsx_vslt_wu
+asm-fmts = vd, vj, vk
+data-types = V4SI, UV4SI, UV4SI
+
+/// lsx_vslt_du
+name = lsx_vslt_du
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV2DI, UV2DI
+
+/// lsx_vslti_bu
+name = lsx_vslti_bu
+asm-fmts = vd, vj, ui5
+data-types = V16QI, UV16QI, UQI
+
+/// lsx_vslti_hu
+name = lsx_vslti_hu
+asm-fmts = vd, vj, ui5
+data-types = V8HI, UV8HI, UQI
+
+/// lsx_vslti_wu
+name = lsx_vslti_wu
+asm-fmts = vd, vj, ui5
+data-types = V4SI, UV4SI, UQI
+
+/// lsx_vslti_du
+name = lsx_vslti_du
+asm-fmts = vd, vj, ui5
+data-types = V2DI, UV2DI, UQI
+
+/// lsx_vsle_b
+name = lsx_vsle_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vsle_h
+name = lsx_vsle_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vsle_w
+name = lsx_vsle_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vsle_d
+name = lsx_vsle_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vslei_b
+name = lsx_vslei_b
+asm-fmts = vd, vj, si5
+data-types = V16QI, V16QI, QI
+
+/// lsx_vslei_h
+name = lsx_vslei_h
+asm-fmts = vd, vj, si5
+data-types = V8HI, V8HI, QI
+
+/// lsx_vslei_w
+name = lsx_vslei_w
+asm-fmts = vd, vj, si5
+data-types = V4SI, V4SI, QI
+
+/// lsx_vslei_d
+name = lsx_vslei_d
+asm-fmts = vd, vj, si5
+data-types = V2DI, V2DI, QI
+
+/// lsx_vsle_bu
+name = lsx_vsle_bu
+asm-fmts = vd, vj, vk
+data-types = V16QI, UV16QI, UV16QI
+
+/// lsx_vsle_hu
+name = lsx_vsle_hu
+asm-fmts = vd, vj, vk
+data-types = V8HI, UV8HI, UV8HI
+
+/// lsx_vsle_wu
+name = lsx_vsle_wu
+asm-fmts = vd, vj, vk
+data-types = V4SI, UV4SI, UV4SI
+
+/// lsx_vsle_du
+name = lsx_vsle_du
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV2DI, UV2DI
+
+/// lsx_vslei_bu
+name = lsx_vslei_bu
+asm-fmts = vd, vj, ui5
+data-types = V16QI, UV16QI, UQI
+
+/// lsx_vslei_hu
+name = lsx_vslei_hu
+asm-fmts = vd, vj, ui5
+data-types = V8HI, UV8HI, UQI
+
+/// lsx_vslei_wu
+name = lsx_vslei_wu
+asm-fmts = vd, vj, ui5
+data-types = V4SI, UV4SI, UQI
+
+/// lsx_vslei_du
+name = lsx_vslei_du
+asm-fmts = vd, vj, ui5
+data-types = V2DI, UV2DI, UQI
+
+/// lsx_vsat_b
+name = lsx_vsat_b
+asm-fmts = vd, vj, ui3
+data-types = V16QI, V16QI, UQI
+
+/// lsx_vsat_h
+name = lsx_vsat_h
+asm-fmts = vd, vj, ui4
+data-types = V8HI, V8HI, UQI
+
+/// lsx_vsat_w
+name = lsx_vsat_w
+asm-fmts = vd, vj, ui5
+data-types = V4SI, V4SI, UQI
+
+/// lsx_vsat_d
+name = lsx_vsat_d
+asm-fmts = vd, vj, ui6
+data-types = V2DI, V2DI, UQI
+
+/// lsx_vsat_bu
+name = lsx_vsat_bu
+asm-fmts = vd, vj, ui3
+data-types = UV16QI, UV16QI, UQI
+
+/// lsx_vsat_hu
+name = lsx_vsat_hu
+asm-fmts = vd, vj, ui4
+data-types = UV8HI, UV8HI, UQI
+
+/// lsx_vsat_wu
+name = lsx_vsat_wu
+asm-fmts = vd, vj, ui5
+data-types = UV4SI, UV4SI, UQI
+
+/// lsx_vsat_du
+name = lsx_vsat_du
+asm-fmts = vd, vj, ui6
+data-types = UV2DI, UV2DI, UQI
+
+/// lsx_vadda_b
+name = lsx_vadda_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vadda_h
+name = lsx_vadda_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vadda_w
+name = lsx_vadda_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vadda_d
+name = lsx_vadda_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vsadd_b
+name = lsx_vsadd_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vsadd_h
+name = lsx_vsadd_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vsadd_w
+name = lsx_vsadd_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vsadd_d
+name = lsx_vsadd_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vsadd_bu
+name = lsx_vsadd_bu
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV16QI, UV16QI
+
+/// lsx_vsadd_hu
+name = lsx_vsadd_hu
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV8HI, UV8HI
+
+/// lsx_vsadd_wu
+name = lsx_vsadd_wu
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV4SI, UV4SI
+
+/// lsx_vsadd_du
+name = lsx_vsadd_du
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV2DI, UV2DI
+
+/// lsx_vavg_b
+name = lsx_vavg_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vavg_h
+name = lsx_vavg_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vavg_w
+name = lsx_vavg_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vavg_d
+name = lsx_vavg_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vavg_bu
+name = lsx_vavg_bu
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV16QI, UV16QI
+
+/// lsx_vavg_hu
+name = lsx_vavg_hu
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV8HI, UV8HI
+
+/// lsx_vavg_wu
+name = lsx_vavg_wu
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV4SI, UV4SI
+
+/// lsx_vavg_du
+name = lsx_vavg_du
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV2DI, UV2DI
+
+/// lsx_vavgr_b
+name = lsx_vavgr_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vavgr_h
+name = lsx_vavgr_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vavgr_w
+name = lsx_vavgr_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vavgr_d
+name = lsx_vavgr_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vavgr_bu
+name = lsx_vavgr_bu
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV16QI, UV16QI
+
+/// lsx_vavgr_hu
+name = lsx_vavgr_hu
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV8HI, UV8HI
+
+/// lsx_vavgr_wu
+name = lsx_vavgr_wu
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV4SI, UV4SI
+
+/// lsx_vavgr_du
+name = lsx_vavgr_du
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV2DI, UV2DI
+
+/// lsx_vssub_b
+name = lsx_vssub_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vssub_h
+name = lsx_vssub_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vssub_w
+name = lsx_vssub_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vssub_d
+name = lsx_vssub_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vssub_bu
+name = lsx_vssub_bu
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV16QI, UV16QI
+
+/// lsx_vssub_hu
+name = lsx_vssub_hu
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV8HI, UV8HI
+
+/// lsx_vssub_wu
+name = lsx_vssub_wu
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV4SI, UV4SI
+
+/// lsx_vssub_du
+name = lsx_vssub_du
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV2DI, UV2DI
+
+/// lsx_vabsd_b
+name = lsx_vabsd_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vabsd_h
+name = lsx_vabsd_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vabsd_w
+name = lsx_vabsd_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vabsd_d
+name = lsx_vabsd_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vabsd_bu
+name = lsx_vabsd_bu
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV16QI, UV16QI
+
+/// lsx_vabsd_hu
+name = lsx_vabsd_hu
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV8HI, UV8HI
+
+/// lsx_vabsd_wu
+name = lsx_vabsd_wu
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV4SI, UV4SI
+
+/// lsx_vabsd_du
+name = lsx_vabsd_du
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV2DI, UV2DI
+
+/// lsx_vmul_b
+name = lsx_vmul_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vmul_h
+name = lsx_vmul_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vmul_w
+name = lsx_vmul_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vmul_d
+name = lsx_vmul_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vmadd_b
+name = lsx_vmadd_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI, V16QI
+
+/// lsx_vmadd_h
+name = lsx_vmadd_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI, V8HI
+
+/// lsx_vmadd_w
+name = lsx_vmadd_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI, V4SI
+
+/// lsx_vmadd_d
+name = lsx_vmadd_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI, V2DI
+
+/// lsx_vmsub_b
+name = lsx_vmsub_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI, V16QI
+
+/// lsx_vmsub_h
+name = lsx_vmsub_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI, V8HI
+
+/// lsx_vmsub_w
+name = lsx_vmsub_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI, V4SI
+
+/// lsx_vmsub_d
+name = lsx_vmsub_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI, V2DI
+
+/// lsx_vdiv_b
+name = lsx_vdiv_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vdiv_h
+name = lsx_vdiv_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vdiv_w
+name = lsx_vdiv_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vdiv_d
+name = lsx_vdiv_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vdiv_bu
+name = lsx_vdiv_bu
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV16QI, UV16QI
+
+/// lsx_vdiv_hu
+name = lsx_vdiv_hu
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV8HI, UV8HI
+
+/// lsx_vdiv_wu
+name = lsx_vdiv_wu
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV4SI, UV4SI
+
+/// lsx_vdiv_du
+name = lsx_vdiv_du
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV2DI, UV2DI
+
+/// lsx_vhaddw_h_b
+name = lsx_vhaddw_h_b
+asm-fmts = vd, vj, vk
+data-types = V8HI, V16QI, V16QI
+
+/// lsx_vhaddw_w_h
+name = lsx_vhaddw_w_h
+asm-fmts = vd, vj, vk
+data-types = V4SI, V8HI, V8HI
+
+/// lsx_vhaddw_d_w
+name = lsx_vhaddw_d_w
+asm-fmts = vd, vj, vk
+data-types = V2DI, V4SI, V4SI
+
+/// lsx_vhaddw_hu_bu
+name = lsx_vhaddw_hu_bu
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV16QI, UV16QI
+
+/// lsx_vhaddw_wu_hu
+name = lsx_vhaddw_wu_hu
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV8HI, UV8HI
+
+/// lsx_vhaddw_du_wu
+name = lsx_vhaddw_du_wu
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV4SI, UV4SI
+
+/// lsx_vhsubw_h_b
+name = lsx_vhsubw_h_b
+asm-fmts = vd, vj, vk
+data-types = V8HI, V16QI, V16QI
+
+/// lsx_vhsubw_w_h
+name = lsx_vhsubw_w_h
+asm-fmts = vd, vj, vk
+data-types = V4SI, V8HI, V8HI
+
+/// lsx_vhsubw_d_w
+name = lsx_vhsubw_d_w
+asm-fmts = vd, vj, vk
+data-types = V2DI, V4SI, V4SI
+
+/// lsx_vhsubw_hu_bu
+name = lsx_vhsubw_hu_bu
+asm-fmts = vd, vj, vk
+data-types = V8HI, UV16QI, UV16QI
+
+/// lsx_vhsubw_wu_hu
+name = lsx_vhsubw_wu_hu
+asm-fmts = vd, vj, vk
+data-types = V4SI, UV8HI, UV8HI
+
+/// lsx_vhsubw_du_wu
+name = lsx_vhsubw_du_wu
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV4SI, UV4SI
+
+/// lsx_vmod_b
+name = lsx_vmod_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vmod_h
+name = lsx_vmod_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vmod_w
+name = lsx_vmod_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vmod_d
+name = lsx_vmod_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vmod_bu
+name = lsx_vmod_bu
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV16QI, UV16QI
+
+/// lsx_vmod_hu
+name = lsx_vmod_hu
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV8HI, UV8HI
+
+/// lsx_vmod_wu
+name = lsx_vmod_wu
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV4SI, UV4SI
+
+/// lsx_vmod_du
+name = lsx_vmod_du
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV2DI, UV2DI
+
+/// lsx_vreplve_b
+name = lsx_vreplve_b
+asm-fmts = vd, vj, rk
+data-types = V16QI, V16QI, SI
+
+/// lsx_vreplve_h
+name = lsx_vreplve_h
+asm-fmts = vd, vj, rk
+data-types = V8HI, V8HI, SI
+
+/// lsx_vreplve_w
+name = lsx_vreplve_w
+asm-fmts = vd, vj, rk
+data-types = V4SI, V4SI, SI
+
+/// lsx_vreplve_d
+name = lsx_vreplve_d
+asm-fmts = vd, vj, rk
+data-types = V2DI, V2DI, SI
+
+/// lsx_vreplvei_b
+name = lsx_vreplvei_b
+asm-fmts = vd, vj, ui4
+data-types = V16QI, V16QI, UQI
+
+/// lsx_vreplvei_h
+name = lsx_vreplvei_h
+asm-fmts = vd, vj, ui3
+data-types = V8HI, V8HI, UQI
+
+/// lsx_vreplvei_w
+name = lsx_vreplvei_w
+asm-fmts = vd, vj, ui2
+data-types = V4SI, V4SI, UQI
+
+/// lsx_vreplvei_d
+name = lsx_vreplvei_d
+asm-fmts = vd, vj, ui1
+data-types = V2DI, V2DI, UQI
+
+/// lsx_vpickev_b
+name = lsx_vpickev_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vpickev_h
+name = lsx_vpickev_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vpickev_w
+name = lsx_vpickev_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vpickev_d
+name = lsx_vpickev_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vpickod_b
+name = lsx_vpickod_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vpickod_h
+name = lsx_vpickod_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vpickod_w
+name = lsx_vpickod_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vpickod_d
+name = lsx_vpickod_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vilvh_b
+name = lsx_vilvh_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vilvh_h
+name = lsx_vilvh_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vilvh_w
+name = lsx_vilvh_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vilvh_d
+name = lsx_vilvh_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vilvl_b
+name = lsx_vilvl_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vilvl_h
+name = lsx_vilvl_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vilvl_w
+name = lsx_vilvl_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vilvl_d
+name = lsx_vilvl_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vpackev_b
+name = lsx_vpackev_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vpackev_h
+name = lsx_vpackev_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vpackev_w
+name = lsx_vpackev_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vpackev_d
+name = lsx_vpackev_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vpackod_b
+name = lsx_vpackod_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vpackod_h
+name = lsx_vpackod_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vpackod_w
+name = lsx_vpackod_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vpackod_d
+name = lsx_vpackod_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vshuf_h
+name = lsx_vshuf_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI, V8HI
+
+/// lsx_vshuf_w
+name = lsx_vshuf_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI, V4SI
+
+/// lsx_vshuf_d
+name = lsx_vshuf_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI, V2DI
+
+/// lsx_vand_v
+name = lsx_vand_v
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV16QI, UV16QI
+
+/// lsx_vandi_b
+name = lsx_vandi_b
+asm-fmts = vd, vj, ui8
+data-types = UV16QI, UV16QI, UQI
+
+/// lsx_vor_v
+name = lsx_vor_v
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV16QI, UV16QI
+
+/// lsx_vori_b
+name = lsx_vori_b
+asm-fmts = vd, vj, ui8
+data-types = UV16QI, UV16QI, UQI
+
+/// lsx_vnor_v
+name = lsx_vnor_v
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV16QI, UV16QI
+
+/// lsx_vnori_b
+name = lsx_vnori_b
+asm-fmts = vd, vj, ui8
+data-types = UV16QI, UV16QI, UQI
+
+/// lsx_vxor_v
+name = lsx_vxor_v
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV16QI, UV16QI
+
+/// lsx_vxori_b
+name = lsx_vxori_b
+asm-fmts = vd, vj, ui8
+data-types = UV16QI, UV16QI, UQI
+
+/// lsx_vbitsel_v
+name = lsx_vbitsel_v
+asm-fmts = vd, vj, vk, va
+data-types = UV16QI, UV16QI, UV16QI, UV16QI
+
+/// lsx_vbitseli_b
+name = lsx_vbitseli_b
+asm-fmts = vd, vj, ui8
+data-types = UV16QI, UV16QI, UV16QI, USI
+
+/// lsx_vshuf4i_b
+name = lsx_vshuf4i_b
+asm-fmts = vd, vj, ui8
+data-types = V16QI, V16QI, USI
+
+/// lsx_vshuf4i_h
+name = lsx_vshuf4i_h
+asm-fmts = vd, vj, ui8
+data-types = V8HI, V8HI, USI
+
+/// lsx_vshuf4i_w
+name = lsx_vshuf4i_w
+asm-fmts = vd, vj, ui8
+data-types = V4SI, V4SI, USI
+
+/// lsx_vreplgr2vr_b
+name = lsx_vreplgr2vr_b
+asm-fmts = vd, rj
+data-types = V16QI, SI
+
+/// lsx_vreplgr2vr_h
+name = lsx_vreplgr2vr_h
+asm-fmts = vd, rj
+data-types = V8HI, SI
+
+/// lsx_vreplgr2vr_w
+name = lsx_vreplgr2vr_w
+asm-fmts = vd, rj
+data-types = V4SI, SI
+
+/// lsx_vreplgr2vr_d
+name = lsx_vreplgr2vr_d
+asm-fmts = vd, rj
+data-types = V2DI, DI
+
+/// lsx_vpcnt_b
+name = lsx_vpcnt_b
+asm-fmts = vd, vj
+data-types = V16QI, V16QI
+
+/// lsx_vpcnt_h
+name = lsx_vpcnt_h
+asm-fmts = vd, vj
+data-types = V8HI, V8HI
+
+/// lsx_vpcnt_w
+name = lsx_vpcnt_w
+asm-fmts = vd, vj
+data-types = V4SI, V4SI
+
+/// lsx_vpcnt_d
+name = lsx_vpcnt_d
+asm-fmts = vd, vj
+data-types = V2DI, V2DI
+
+/// lsx_vclo_b
+name = lsx_vclo_b
+asm-fmts = vd, vj
+data-types = V16QI, V16QI
+
+/// lsx_vclo_h
+name = lsx_vclo_h
+asm-fmts = vd, vj
+data-types = V8HI, V8HI
+
+/// lsx_vclo_w
+name = lsx_vclo_w
+asm-fmts = vd, vj
+data-types = V4SI, V4SI
+
+/// lsx_vclo_d
+name = lsx_vclo_d
+asm-fmts = vd, vj
+data-types = V2DI, V2DI
+
+/// lsx_vclz_b
+name = lsx_vclz_b
+asm-fmts = vd, vj
+data-types = V16QI, V16QI
+
+/// lsx_vclz_h
+name = lsx_vclz_h
+asm-fmts = vd, vj
+data-types = V8HI, V8HI
+
+/// lsx_vclz_w
+name = lsx_vclz_w
+asm-fmts = vd, vj
+data-types = V4SI, V4SI
+
+/// lsx_vclz_d
+name = lsx_vclz_d
+asm-fmts = vd, vj
+data-types = V2DI, V2DI
+
+/// lsx_vpickve2gr_b
+name = lsx_vpickve2gr_b
+asm-fmts = rd, vj, ui4
+data-types = SI, V16QI, UQI
+
+/// lsx_vpickve2gr_h
+name = lsx_vpickve2gr_h
+asm-fmts = rd, vj, ui3
+data-types = SI, V8HI, UQI
+
+/// lsx_vpickve2gr_w
+name = lsx_vpickve2gr_w
+asm-fmts = rd, vj, ui2
+data-types = SI, V4SI, UQI
+
+/// lsx_vpickve2gr_d
+name = lsx_vpickve2gr_d
+asm-fmts = rd, vj, ui1
+data-types = DI, V2DI, UQI
+
+/// lsx_vpickve2gr_bu
+name = lsx_vpickve2gr_bu
+asm-fmts = rd, vj, ui4
+data-types = USI, V16QI, UQI
+
+/// lsx_vpickve2gr_hu
+name = lsx_vpickve2gr_hu
+asm-fmts = rd, vj, ui3
+data-types = USI, V8HI, UQI
+
+/// lsx_vpickve2gr_wu
+name = lsx_vpickve2gr_wu
+asm-fmts = rd, vj, ui2
+data-types = USI, V4SI, UQI
+
+/// lsx_vpickve2gr_du
+name = lsx_vpickve2gr_du
+asm-fmts = rd, vj, ui1
+data-types = UDI, V2DI, UQI
+
+/// lsx_vinsgr2vr_b
+name = lsx_vinsgr2vr_b
+asm-fmts = vd, rj, ui4
+data-types = V16QI, V16QI, SI, UQI
+
+/// lsx_vinsgr2vr_h
+name = lsx_vinsgr2vr_h
+asm-fmts = vd, rj, ui3
+data-types = V8HI, V8HI, SI, UQI
+
+/// lsx_vinsgr2vr_w
+name = lsx_vinsgr2vr_w
+asm-fmts = vd, rj, ui2
+data-types = V4SI, V4SI, SI, UQI
+
+/// lsx_vinsgr2vr_d
+name = lsx_vinsgr2vr_d
+asm-fmts = vd, rj, ui1
+data-types = V2DI, V2DI, DI, UQI
+
+/// lsx_vfadd_s
+name = lsx_vfadd_s
+asm-fmts = vd, vj, vk
+data-types = V4SF, V4SF, V4SF
+
+/// lsx_vfadd_d
+name = lsx_vfadd_d
+asm-fmts = vd, vj, vk
+data-types = V2DF, V2DF, V2DF
+
+/// lsx_vfsub_s
+name = lsx_vfsub_s
+asm-fmts = vd, vj, vk
+data-types = V4SF, V4SF, V4SF
+
+/// lsx_vfsub_d
+name = lsx_vfsub_d
+asm-fmts = vd, vj, vk
+data-types = V2DF, V2DF, V2DF
+
+/// lsx_vfmul_s
+name = lsx_vfmul_s
+asm-fmts = vd, vj, vk
+data-types = V4SF, V4SF, V4SF
+
+/// lsx_vfmul_d
+name = lsx_vfmul_d
+asm-fmts = vd, vj, vk
+data-types = V2DF, V2DF, V2DF
+
+/// lsx_vfdiv_s
+name = lsx_vfdiv_s
+asm-fmts = vd, vj, vk
+data-types = V4SF, V4SF, V4SF
+
+/// lsx_vfdiv_d
+name = lsx_vfdiv_d
+asm-fmts = vd, vj, vk
+data-types = V2DF, V2DF, V2DF
+
+/// lsx_vfcvt_h_s
+name = lsx_vfcvt_h_s
+asm-fmts = vd, vj, vk
+data-types = V8HI, V4SF, V4SF
+
+/// lsx_vfcvt_s_d
+name = lsx_vfcvt_s_d
+asm-fmts = vd, vj, vk
+data-types = V4SF, V2DF, V2DF
+
+/// lsx_vfmin_s
+name = lsx_vfmin_s
+asm-fmts = vd, vj, vk
+data-types = V4SF, V4SF, V4SF
+
+/// lsx_vfmin_d
+name = lsx_vfmin_d
+asm-fmts = vd, vj, vk
+data-types = V2DF, V2DF, V2DF
+
+/// lsx_vfmina_s
+name = lsx_vfmina_s
+asm-fmts = vd, vj, vk
+data-types = V4SF, V4SF, V4SF
+
+/// lsx_vfmina_d
+name = lsx_vfmina_d
+asm-fmts = vd, vj, vk
+data-types = V2DF, V2DF, V2DF
+
+/// lsx_vfmax_s
+name = lsx_vfmax_s
+asm-fmts = vd, vj, vk
+data-types = V4SF, V4SF, V4SF
+
+/// lsx_vfmax_d
+name = lsx_vfmax_d
+asm-fmts = vd, vj, vk
+data-types = V2DF, V2DF, V2DF
+
+/// lsx_vfmaxa_s
+name = lsx_vfmaxa_s
+asm-fmts = vd, vj, vk
+data-types = V4SF, V4SF, V4SF
+
+/// lsx_vfmaxa_d
+name = lsx_vfmaxa_d
+asm-fmts = vd, vj, vk
+data-types = V2DF, V2DF, V2DF
+
+/// lsx_vfclass_s
+name = lsx_vfclass_s
+asm-fmts = vd, vj
+data-types = V4SI, V4SF
+
+/// lsx_vfclass_d
+name = lsx_vfclass_d
+asm-fmts = vd, vj
+data-types = V2DI, V2DF
+
+/// lsx_vfsqrt_s
+name = lsx_vfsqrt_s
+asm-fmts = vd, vj
+data-types = V4SF, V4SF
+
+/// lsx_vfsqrt_d
+name = lsx_vfsqrt_d
+asm-fmts = vd, vj
+data-types = V2DF, V2DF
+
+/// lsx_vfrecip_s
+name = lsx_vfrecip_s
+asm-fmts = vd, vj
+data-types = V4SF, V4SF
+
+/// lsx_vfrecip_d
+name = lsx_vfrecip_d
+asm-fmts = vd, vj
+data-types = V2DF, V2DF
+
+/// lsx_vfrint_s
+name = lsx_vfrint_s
+asm-fmts = vd, vj
+data-types = V4SF, V4SF
+
+/// lsx_vfrint_d
+name = lsx_vfrint_d
+asm-fmts = vd, vj
+data-types = V2DF, V2DF
+
+/// lsx_vfrsqrt_s
+name = lsx_vfrsqrt_s
+asm-fmts = vd, vj
+data-types = V4SF, V4SF
+
+/// lsx_vfrsqrt_d
+name = lsx_vfrsqrt_d
+asm-fmts = vd, vj
+data-types = V2DF, V2DF
+
+/// lsx_vflogb_s
+name = lsx_vflogb_s
+asm-fmts = vd, vj
+data-types = V4SF, V4SF
+
+/// lsx_vflogb_d
+name = lsx_vflogb_d
+asm-fmts = vd, vj
+data-types = V2DF, V2DF
+
+/// lsx_vfcvth_s_h
+name = lsx_vfcvth_s_h
+asm-fmts = vd, vj
+data-types = V4SF, V8HI
+
+/// lsx_vfcvth_d_s
+name = lsx_vfcvth_d_s
+asm-fmts = vd, vj
+data-types = V2DF, V4SF
+
+/// lsx_vfcvtl_s_h
+name = lsx_vfcvtl_s_h
+asm-fmts = vd, vj
+data-types = V4SF, V8HI
+
+/// lsx_vfcvtl_d_s
+name = lsx_vfcvtl_d_s
+asm-fmts = vd, vj
+data-types = V2DF, V4SF
+
+/// lsx_vftint_w_s
+name = lsx_vftint_w_s
+asm-fmts = vd, vj
+data-types = V4SI, V4SF
+
+/// lsx_vftint_l_d
+name = lsx_vftint_l_d
+asm-fmts = vd, vj
+data-types = V2DI, V2DF
+
+/// lsx_vftint_wu_s
+name = lsx_vftint_wu_s
+asm-fmts = vd, vj
+data-types = UV4SI, V4SF
+
+/// lsx_vftint_lu_d
+name = lsx_vftint_lu_d
+asm-fmts = vd, vj
+data-types = UV2DI, V2DF
+
+/// lsx_vftintrz_w_s
+name = lsx_vftintrz_w_s
+asm-fmts = vd, vj
+data-types = V4SI, V4SF
+
+/// lsx_vftintrz_l_d
+name = lsx_vftintrz_l_d
+asm-fmts = vd, vj
+data-types = V2DI, V2DF
+
+/// lsx_vftintrz_wu_s
+name = lsx_vftintrz_wu_s
+asm-fmts = vd, vj
+data-types = UV4SI, V4SF
+
+/// lsx_vftintrz_lu_d
+name = lsx_vftintrz_lu_d
+asm-fmts = vd, vj
+data-types = UV2DI, V2DF
+
+/// lsx_vffint_s_w
+name = lsx_vffint_s_w
+asm-fmts = vd, vj
+data-types = V4SF, V4SI
+
+/// lsx_vffint_d_l
+name = lsx_vffint_d_l
+asm-fmts = vd, vj
+data-types = V2DF, V2DI
+
+/// lsx_vffint_s_wu
+name = lsx_vffint_s_wu
+asm-fmts = vd, vj
+data-types = V4SF, UV4SI
+
+/// lsx_vffint_d_lu
+name = lsx_vffint_d_lu
+asm-fmts = vd, vj
+data-types = V2DF, UV2DI
+
+/// lsx_vandn_v
+name = lsx_vandn_v
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV16QI, UV16QI
+
+/// lsx_vneg_b
+name = lsx_vneg_b
+asm-fmts = vd, vj
+data-types = V16QI, V16QI
+
+/// lsx_vneg_h
+name = lsx_vneg_h
+asm-fmts = vd, vj
+data-types = V8HI, V8HI
+
+/// lsx_vneg_w
+name = lsx_vneg_w
+asm-fmts = vd, vj
+data-types = V4SI, V4SI
+
+/// lsx_vneg_d
+name = lsx_vneg_d
+asm-fmts = vd, vj
+data-types = V2DI, V2DI
+
+/// lsx_vmuh_b
+name = lsx_vmuh_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vmuh_h
+name = lsx_vmuh_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vmuh_w
+name = lsx_vmuh_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vmuh_d
+name = lsx_vmuh_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vmuh_bu
+name = lsx_vmuh_bu
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV16QI, UV16QI
+
+/// lsx_vmuh_hu
+name = lsx_vmuh_hu
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV8HI, UV8HI
+
+/// lsx_vmuh_wu
+name = lsx_vmuh_wu
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV4SI, UV4SI
+
+/// lsx_vmuh_du
+name = lsx_vmuh_du
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV2DI, UV2DI
+
+/// lsx_vsllwil_h_b
+name = lsx_vsllwil_h_b
+asm-fmts = vd, vj, ui3
+data-types = V8HI, V16QI, UQI
+
+/// lsx_vsllwil_w_h
+name = lsx_vsllwil_w_h
+asm-fmts = vd, vj, ui4
+data-types = V4SI, V8HI, UQI
+
+/// lsx_vsllwil_d_w
+name = lsx_vsllwil_d_w
+asm-fmts = vd, vj, ui5
+data-types = V2DI, V4SI, UQI
+
+/// lsx_vsllwil_hu_bu
+name = lsx_vsllwil_hu_bu
+asm-fmts = vd, vj, ui3
+data-types = UV8HI, UV16QI, UQI
+
+/// lsx_vsllwil_wu_hu
+name = lsx_vsllwil_wu_hu
+asm-fmts = vd, vj, ui4
+data-types = UV4SI, UV8HI, UQI
+
+/// lsx_vsllwil_du_wu
+name = lsx_vsllwil_du_wu
+asm-fmts = vd, vj, ui5
+data-types = UV2DI, UV4SI, UQI
+
+/// lsx_vsran_b_h
+name = lsx_vsran_b_h
+asm-fmts = vd, vj, vk
+data-types = V16QI, V8HI, V8HI
+
+/// lsx_vsran_h_w
+name = lsx_vsran_h_w
+asm-fmts = vd, vj, vk
+data-types = V8HI, V4SI, V4SI
+
+/// lsx_vsran_w_d
+name = lsx_vsran_w_d
+asm-fmts = vd, vj, vk
+data-types = V4SI, V2DI, V2DI
+
+/// lsx_vssran_b_h
+name = lsx_vssran_b_h
+asm-fmts = vd, vj, vk
+data-types = V16QI, V8HI, V8HI
+
+/// lsx_vssran_h_w
+name = lsx_vssran_h_w
+asm-fmts = vd, vj, vk
+data-types = V8HI, V4SI, V4SI
+
+/// lsx_vssran_w_d
+name = lsx_vssran_w_d
+asm-fmts = vd, vj, vk
+data-types = V4SI, V2DI, V2DI
+
+/// lsx_vssran_bu_h
+name = lsx_vssran_bu_h
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV8HI, UV8HI
+
+/// lsx_vssran_hu_w
+name = lsx_vssran_hu_w
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV4SI, UV4SI
+
+/// lsx_vssran_wu_d
+name = lsx_vssran_wu_d
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV2DI, UV2DI
+
+/// lsx_vsrarn_b_h
+name = lsx_vsrarn_b_h
+asm-fmts = vd, vj, vk
+data-types = V16QI, V8HI, V8HI
+
+/// lsx_vsrarn_h_w
+name = lsx_vsrarn_h_w
+asm-fmts = vd, vj, vk
+data-types = V8HI, V4SI, V4SI
+
+/// lsx_vsrarn_w_d
+name = lsx_vsrarn_w_d
+asm-fmts = vd, vj, vk
+data-types = V4SI, V2DI, V2DI
+
+/// lsx_vssrarn_b_h
+name = lsx_vssrarn_b_h
+asm-fmts = vd, vj, vk
+data-types = V16QI, V8HI, V8HI
+
+/// lsx_vssrarn_h_w
+name = lsx_vssrarn_h_w
+asm-fmts = vd, vj, vk
+data-types = V8HI, V4SI, V4SI
+
+/// lsx_vssrarn_w_d
+name = lsx_vssrarn_w_d
+asm-fmts = vd, vj, vk
+data-types = V4SI, V2DI, V2DI
+
+/// lsx_vssrarn_bu_h
+name = lsx_vssrarn_bu_h
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV8HI, UV8HI
+
+/// lsx_vssrarn_hu_w
+name = lsx_vssrarn_hu_w
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV4SI, UV4SI
+
+/// lsx_vssrarn_wu_d
+name = lsx_vssrarn_wu_d
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV2DI, UV2DI
+
+/// lsx_vsrln_b_h
+name = lsx_vsrln_b_h
+asm-fmts = vd, vj, vk
+data-types = V16QI, V8HI, V8HI
+
+/// lsx_vsrln_h_w
+name = lsx_vsrln_h_w
+asm-fmts = vd, vj, vk
+data-types = V8HI, V4SI, V4SI
+
+/// lsx_vsrln_w_d
+name = lsx_vsrln_w_d
+asm-fmts = vd, vj, vk
+data-types = V4SI, V2DI, V2DI
+
+/// lsx_vssrln_bu_h
+name = lsx_vssrln_bu_h
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV8HI, UV8HI
+
+/// lsx_vssrln_hu_w
+name = lsx_vssrln_hu_w
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV4SI, UV4SI
+
+/// lsx_vssrln_wu_d
+name = lsx_vssrln_wu_d
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV2DI, UV2DI
+
+/// lsx_vsrlrn_b_h
+name = lsx_vsrlrn_b_h
+asm-fmts = vd, vj, vk
+data-types = V16QI, V8HI, V8HI
+
+/// lsx_vsrlrn_h_w
+name = lsx_vsrlrn_h_w
+asm-fmts = vd, vj, vk
+data-types = V8HI, V4SI, V4SI
+
+/// lsx_vsrlrn_w_d
+name = lsx_vsrlrn_w_d
+asm-fmts = vd, vj, vk
+data-types = V4SI, V2DI, V2DI
+
+/// lsx_vssrlrn_bu_h
+name = lsx_vssrlrn_bu_h
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV8HI, UV8HI
+
+/// lsx_vssrlrn_hu_w
+name = lsx_vssrlrn_hu_w
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV4SI, UV4SI
+
+/// lsx_vssrlrn_wu_d
+name = lsx_vssrlrn_wu_d
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV2DI, UV2DI
+
+/// lsx_vfrstpi_b
+name = lsx_vfrstpi_b
+asm-fmts = vd, vj, ui5
+data-types = V16QI, V16QI, V16QI, UQI
+
+/// lsx_vfrstpi_h
+name = lsx_vfrstpi_h
+asm-fmts = vd, vj, ui5
+data-types = V8HI, V8HI, V8HI, UQI
+
+/// lsx_vfrstp_b
+name = lsx_vfrstp_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI, V16QI
+
+/// lsx_vfrstp_h
+name = lsx_vfrstp_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI, V8HI
+
+/// lsx_vshuf4i_d
+name = lsx_vshuf4i_d
+asm-fmts = vd, vj, ui8
+data-types = V2DI, V2DI, V2DI, USI
+
+/// lsx_vbsrl_v
+name = lsx_vbsrl_v
+asm-fmts = vd, vj, ui5
+data-types = V16QI, V16QI, UQI
+
+/// lsx_vbsll_v
+name = lsx_vbsll_v
+asm-fmts = vd, vj, ui5
+data-types = V16QI, V16QI, UQI
+
+/// lsx_vextrins_b
+name = lsx_vextrins_b
+asm-fmts = vd, vj, ui8
+data-types = V16QI, V16QI, V16QI, USI
+
+/// lsx_vextrins_h
+name = lsx_vextrins_h
+asm-fmts = vd, vj, ui8
+data-types = V8HI, V8HI, V8HI, USI
+
+/// lsx_vextrins_w
+name = lsx_vextrins_w
+asm-fmts = vd, vj, ui8
+data-types = V4SI, V4SI, V4SI, USI
+
+/// lsx_vextrins_d
+name = lsx_vextrins_d
+asm-fmts = vd, vj, ui8
+data-types = V2DI, V2DI, V2DI, USI
+
+/// lsx_vmskltz_b
+name = lsx_vmskltz_b
+asm-fmts = vd, vj
+data-types = V16QI, V16QI
+
+/// lsx_vmskltz_h
+name = lsx_vmskltz_h
+asm-fmts = vd, vj
+data-types = V8HI, V8HI
+
+/// lsx_vmskltz_w
+name = lsx_vmskltz_w
+asm-fmts = vd, vj
+data-types = V4SI, V4SI
+
+/// lsx_vmskltz_d
+name = lsx_vmskltz_d
+asm-fmts = vd, vj
+data-types = V2DI, V2DI
+
+/// lsx_vsigncov_b
+name = lsx_vsigncov_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vsigncov_h
+name = lsx_vsigncov_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vsigncov_w
+name = lsx_vsigncov_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vsigncov_d
+name = lsx_vsigncov_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vfmadd_s
+name = lsx_vfmadd_s
+asm-fmts = vd, vj, vk, va
+data-types = V4SF, V4SF, V4SF, V4SF
+
+/// lsx_vfmadd_d
+name = lsx_vfmadd_d
+asm-fmts = vd, vj, vk, va
+data-types = V2DF, V2DF, V2DF, V2DF
+
+/// lsx_vfmsub_s
+name = lsx_vfmsub_s
+asm-fmts = vd, vj, vk, va
+data-types = V4SF, V4SF, V4SF, V4SF
+
+/// lsx_vfmsub_d
+name = lsx_vfmsub_d
+asm-fmts = vd, vj, vk, va
+data-types = V2DF, V2DF, V2DF, V2DF
+
+/// lsx_vfnmadd_s
+name = lsx_vfnmadd_s
+asm-fmts = vd, vj, vk, va
+data-types = V4SF, V4SF, V4SF, V4SF
+
+/// lsx_vfnmadd_d
+name = lsx_vfnmadd_d
+asm-fmts = vd, vj, vk, va
+data-types = V2DF, V2DF, V2DF, V2DF
+
+/// lsx_vfnmsub_s
+name = lsx_vfnmsub_s
+asm-fmts = vd, vj, vk, va
+data-types = V4SF, V4SF, V4SF, V4SF
+
+/// lsx_vfnmsub_d
+name = lsx_vfnmsub_d
+asm-fmts = vd, vj, vk, va
+data-types = V2DF, V2DF, V2DF, V2DF
+
+/// lsx_vftintrne_w_s
+name = lsx_vftintrne_w_s
+asm-fmts = vd, vj
+data-types = V4SI, V4SF
+
+/// lsx_vftintrne_l_d
+name = lsx_vftintrne_l_d
+asm-fmts = vd, vj
+data-types = V2DI, V2DF
+
+/// lsx_vftintrp_w_s
+name = lsx_vftintrp_w_s
+asm-fmts = vd, vj
+data-types = V4SI, V4SF
+
+/// lsx_vftintrp_l_d
+name = lsx_vftintrp_l_d
+asm-fmts = vd, vj
+data-types = V2DI, V2DF
+
+/// lsx_vftintrm_w_s
+name = lsx_vftintrm_w_s
+asm-fmts = vd, vj
+data-types = V4SI, V4SF
+
+/// lsx_vftintrm_l_d
+name = lsx_vftintrm_l_d
+asm-fmts = vd, vj
+data-types = V2DI, V2DF
+
+/// lsx_vftint_w_d
+name = lsx_vftint_w_d
+asm-fmts = vd, vj, vk
+data-types = V4SI, V2DF, V2DF
+
+/// lsx_vffint_s_l
+name = lsx_vffint_s_l
+asm-fmts = vd, vj, vk
+data-types = V4SF, V2DI, V2DI
+
+/// lsx_vftintrz_w_d
+name = lsx_vftintrz_w_d
+asm-fmts = vd, vj, vk
+data-types = V4SI, V2DF, V2DF
+
+/// lsx_vftintrp_w_d
+name = lsx_vftintrp_w_d
+asm-fmts = vd, vj, vk
+data-types = V4SI, V2DF, V2DF
+
+/// lsx_vftintrm_w_d
+name = lsx_vftintrm_w_d
+asm-fmts = vd, vj, vk
+data-types = V4SI, V2DF, V2DF
+
+/// lsx_vftintrne_w_d
+name = lsx_vftintrne_w_d
+asm-fmts = vd, vj, vk
+data-types = V4SI, V2DF, V2DF
+
+/// lsx_vftintl_l_s
+name = lsx_vftintl_l_s
+asm-fmts = vd, vj
+data-types = V2DI, V4SF
+
+/// lsx_vftinth_l_s
+name = lsx_vftinth_l_s
+asm-fmts = vd, vj
+data-types = V2DI, V4SF
+
+/// lsx_vffinth_d_w
+name = lsx_vffinth_d_w
+asm-fmts = vd, vj
+data-types = V2DF, V4SI
+
+/// lsx_vffintl_d_w
+name = lsx_vffintl_d_w
+asm-fmts = vd, vj
+data-types = V2DF, V4SI
+
+/// lsx_vftintrzl_l_s
+name = lsx_vftintrzl_l_s
+asm-fmts = vd, vj
+data-types = V2DI, V4SF
+
+/// lsx_vftintrzh_l_s
+name = lsx_vftintrzh_l_s
+asm-fmts = vd, vj
+data-types = V2DI, V4SF
+
+/// lsx_vftintrpl_l_s
+name = lsx_vftintrpl_l_s
+asm-fmts = vd, vj
+data-types = V2DI, V4SF
+
+/// lsx_vftintrph_l_s
+name = lsx_vftintrph_l_s
+asm-fmts = vd, vj
+data-types = V2DI, V4SF
+
+/// lsx_vftintrml_l_s
+name = lsx_vftintrml_l_s
+asm-fmts = vd, vj
+data-types = V2DI, V4SF
+
+/// lsx_vftintrmh_l_s
+name = lsx_vftintrmh_l_s
+asm-fmts = vd, vj
+data-types = V2DI, V4SF
+
+/// lsx_vftintrnel_l_s
+name = lsx_vftintrnel_l_s
+asm-fmts = vd, vj
+data-types = V2DI, V4SF
+
+/// lsx_vftintrneh_l_s
+name = lsx_vftintrneh_l_s
+asm-fmts = vd, vj
+data-types = V2DI, V4SF
+
+/// lsx_vfrintrne_s
+name = lsx_vfrintrne_s
+asm-fmts = vd, vj
+data-types = V4SF, V4SF
+
+/// lsx_vfrintrne_d
+name = lsx_vfrintrne_d
+asm-fmts = vd, vj
+data-types = V2DF, V2DF
+
+/// lsx_vfrintrz_s
+name = lsx_vfrintrz_s
+asm-fmts = vd, vj
+data-types = V4SF, V4SF
+
+/// lsx_vfrintrz_d
+name = lsx_vfrintrz_d
+asm-fmts = vd, vj
+data-types = V2DF, V2DF
+
+/// lsx_vfrintrp_s
+name = lsx_vfrintrp_s
+asm-fmts = vd, vj
+data-types = V4SF, V4SF
+
+/// lsx_vfrintrp_d
+name = lsx_vfrintrp_d
+asm-fmts = vd, vj
+data-types = V2DF, V2DF
+
+/// lsx_vfrintrm_s
+name = lsx_vfrintrm_s
+asm-fmts = vd, vj
+data-types = V4SF, V4SF
+
+/// lsx_vfrintrm_d
+name = lsx_vfrintrm_d
+asm-fmts = vd, vj
+data-types = V2DF, V2DF
+
+/// lsx_vstelm_b
+name = lsx_vstelm_b
+asm-fmts = vd, rj, si8, idx
+data-types = VOID, V16QI, CVPOINTER, SI, UQI
+
+/// lsx_vstelm_h
+name = lsx_vstelm_h
+asm-fmts = vd, rj, si8, idx
+data-types = VOID, V8HI, CVPOINTER, SI, UQI
+
+/// lsx_vstelm_w
+name = lsx_vstelm_w
+asm-fmts = vd, rj, si8, idx
+data-types = VOID, V4SI, CVPOINTER, SI, UQI
+
+/// lsx_vstelm_d
+name = lsx_vstelm_d
+asm-fmts = vd, rj, si8, idx
+data-types = VOID, V2DI, CVPOINTER, SI, UQI
+
+/// lsx_vaddwev_d_w
+name = lsx_vaddwev_d_w
+asm-fmts = vd, vj, vk
+data-types = V2DI, V4SI, V4SI
+
+/// lsx_vaddwev_w_h
+name = lsx_vaddwev_w_h
+asm-fmts = vd, vj, vk
+data-types = V4SI, V8HI, V8HI
+
+/// lsx_vaddwev_h_b
+name = lsx_vaddwev_h_b
+asm-fmts = vd, vj, vk
+data-types = V8HI, V16QI, V16QI
+
+/// lsx_vaddwod_d_w
+name = lsx_vaddwod_d_w
+asm-fmts = vd, vj, vk
+data-types = V2DI, V4SI, V4SI
+
+/// lsx_vaddwod_w_h
+name = lsx_vaddwod_w_h
+asm-fmts = vd, vj, vk
+data-types = V4SI, V8HI, V8HI
+
+/// lsx_vaddwod_h_b
+name = lsx_vaddwod_h_b
+asm-fmts = vd, vj, vk
+data-types = V8HI, V16QI, V16QI
+
+/// lsx_vaddwev_d_wu
+name = lsx_vaddwev_d_wu
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV4SI, UV4SI
+
+/// lsx_vaddwev_w_hu
+name = lsx_vaddwev_w_hu
+asm-fmts = vd, vj, vk
+data-types = V4SI, UV8HI, UV8HI
+
+/// lsx_vaddwev_h_bu
+name = lsx_vaddwev_h_bu
+asm-fmts = vd, vj, vk
+data-types = V8HI, UV16QI, UV16QI
+
+/// lsx_vaddwod_d_wu
+name = lsx_vaddwod_d_wu
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV4SI, UV4SI
+
+/// lsx_vaddwod_w_hu
+name = lsx_vaddwod_w_hu
+asm-fmts = vd, vj, vk
+data-types = V4SI, UV8HI, UV8HI
+
+/// lsx_vaddwod_h_bu
+name = lsx_vaddwod_h_bu
+asm-fmts = vd, vj, vk
+data-types = V8HI, UV16QI, UV16QI
+
+/// lsx_vaddwev_d_wu_w
+name = lsx_vaddwev_d_wu_w
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV4SI, V4SI
+
+/// lsx_vaddwev_w_hu_h
+name = lsx_vaddwev_w_hu_h
+asm-fmts = vd, vj, vk
+data-types = V4SI, UV8HI, V8HI
+
+/// lsx_vaddwev_h_bu_b
+name = lsx_vaddwev_h_bu_b
+asm-fmts = vd, vj, vk
+data-types = V8HI, UV16QI, V16QI
+
+/// lsx_vaddwod_d_wu_w
+name = lsx_vaddwod_d_wu_w
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV4SI, V4SI
+
+/// lsx_vaddwod_w_hu_h
+name = lsx_vaddwod_w_hu_h
+asm-fmts = vd, vj, vk
+data-types = V4SI, UV8HI, V8HI
+
+/// lsx_vaddwod_h_bu_b
+name = lsx_vaddwod_h_bu_b
+asm-fmts = vd, vj, vk
+data-types = V8HI, UV16QI, V16QI
+
+/// lsx_vsubwev_d_w
+name = lsx_vsubwev_d_w
+asm-fmts = vd, vj, vk
+data-types = V2DI, V4SI, V4SI
+
+/// lsx_vsubwev_w_h
+name = lsx_vsubwev_w_h
+asm-fmts = vd, vj, vk
+data-types = V4SI, V8HI, V8HI
+
+/// lsx_vsubwev_h_b
+name = lsx_vsubwev_h_b
+asm-fmts = vd, vj, vk
+data-types = V8HI, V16QI, V16QI
+
+/// lsx_vsubwod_d_w
+name = lsx_vsubwod_d_w
+asm-fmts = vd, vj, vk
+data-types = V2DI, V4SI, V4SI
+
+/// lsx_vsubwod_w_h
+name = lsx_vsubwod_w_h
+asm-fmts = vd, vj, vk
+data-types = V4SI, V8HI, V8HI
+
+/// lsx_vsubwod_h_b
+name = lsx_vsubwod_h_b
+asm-fmts = vd, vj, vk
+data-types = V8HI, V16QI, V16QI
+
+/// lsx_vsubwev_d_wu
+name = lsx_vsubwev_d_wu
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV4SI, UV4SI
+
+/// lsx_vsubwev_w_hu
+name = lsx_vsubwev_w_hu
+asm-fmts = vd, vj, vk
+data-types = V4SI, UV8HI, UV8HI
+
+/// lsx_vsubwev_h_bu
+name = lsx_vsubwev_h_bu
+asm-fmts = vd, vj, vk
+data-types = V8HI, UV16QI, UV16QI
+
+/// lsx_vsubwod_d_wu
+name = lsx_vsubwod_d_wu
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV4SI, UV4SI
+
+/// lsx_vsubwod_w_hu
+name = lsx_vsubwod_w_hu
+asm-fmts = vd, vj, vk
+data-types = V4SI, UV8HI, UV8HI
+
+/// lsx_vsubwod_h_bu
+name = lsx_vsubwod_h_bu
+asm-fmts = vd, vj, vk
+data-types = V8HI, UV16QI, UV16QI
+
+/// lsx_vaddwev_q_d
+name = lsx_vaddwev_q_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vaddwod_q_d
+name = lsx_vaddwod_q_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vaddwev_q_du
+name = lsx_vaddwev_q_du
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV2DI, UV2DI
+
+/// lsx_vaddwod_q_du
+name = lsx_vaddwod_q_du
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV2DI, UV2DI
+
+/// lsx_vsubwev_q_d
+name = lsx_vsubwev_q_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vsubwod_q_d
+name = lsx_vsubwod_q_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vsubwev_q_du
+name = lsx_vsubwev_q_du
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV2DI, UV2DI
+
+/// lsx_vsubwod_q_du
+name = lsx_vsubwod_q_du
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV2DI, UV2DI
+
+/// lsx_vaddwev_q_du_d
+name = lsx_vaddwev_q_du_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV2DI, V2DI
+
+/// lsx_vaddwod_q_du_d
+name = lsx_vaddwod_q_du_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV2DI, V2DI
+
+/// lsx_vmulwev_d_w
+name = lsx_vmulwev_d_w
+asm-fmts = vd, vj, vk
+data-types = V2DI, V4SI, V4SI
+
+/// lsx_vmulwev_w_h
+name = lsx_vmulwev_w_h
+asm-fmts = vd, vj, vk
+data-types = V4SI, V8HI, V8HI
+
+/// lsx_vmulwev_h_b
+name = lsx_vmulwev_h_b
+asm-fmts = vd, vj, vk
+data-types = V8HI, V16QI, V16QI
+
+/// lsx_vmulwod_d_w
+name = lsx_vmulwod_d_w
+asm-fmts = vd, vj, vk
+data-types = V2DI, V4SI, V4SI
+
+/// lsx_vmulwod_w_h
+name = lsx_vmulwod_w_h
+asm-fmts = vd, vj, vk
+data-types = V4SI, V8HI, V8HI
+
+/// lsx_vmulwod_h_b
+name = lsx_vmulwod_h_b
+asm-fmts = vd, vj, vk
+data-types = V8HI, V16QI, V16QI
+
+/// lsx_vmulwev_d_wu
+name = lsx_vmulwev_d_wu
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV4SI, UV4SI
+
+/// lsx_vmulwev_w_hu
+name = lsx_vmulwev_w_hu
+asm-fmts = vd, vj, vk
+data-types = V4SI, UV8HI, UV8HI
+
+/// lsx_vmulwev_h_bu
+name = lsx_vmulwev_h_bu
+asm-fmts = vd, vj, vk
+data-types = V8HI, UV16QI, UV16QI
+
+/// lsx_vmulwod_d_wu
+name = lsx_vmulwod_d_wu
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV4SI, UV4SI
+
+/// lsx_vmulwod_w_hu
+name = lsx_vmulwod_w_hu
+asm-fmts = vd, vj, vk
+data-types = V4SI, UV8HI, UV8HI
+
+/// lsx_vmulwod_h_bu
+name = lsx_vmulwod_h_bu
+asm-fmts = vd, vj, vk
+data-types = V8HI, UV16QI, UV16QI
+
+/// lsx_vmulwev_d_wu_w
+name = lsx_vmulwev_d_wu_w
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV4SI, V4SI
+
+/// lsx_vmulwev_w_hu_h
+name = lsx_vmulwev_w_hu_h
+asm-fmts = vd, vj, vk
+data-types = V4SI, UV8HI, V8HI
+
+/// lsx_vmulwev_h_bu_b
+name = lsx_vmulwev_h_bu_b
+asm-fmts = vd, vj, vk
+data-types = V8HI, UV16QI, V16QI
+
+/// lsx_vmulwod_d_wu_w
+name = lsx_vmulwod_d_wu_w
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV4SI, V4SI
+
+/// lsx_vmulwod_w_hu_h
+name = lsx_vmulwod_w_hu_h
+asm-fmts = vd, vj, vk
+data-types = V4SI, UV8HI, V8HI
+
+/// lsx_vmulwod_h_bu_b
+name = lsx_vmulwod_h_bu_b
+asm-fmts = vd, vj, vk
+data-types = V8HI, UV16QI, V16QI
+
+/// lsx_vmulwev_q_d
+name = lsx_vmulwev_q_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vmulwod_q_d
+name = lsx_vmulwod_q_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vmulwev_q_du
+name = lsx_vmulwev_q_du
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV2DI, UV2DI
+
+/// lsx_vmulwod_q_du
+name = lsx_vmulwod_q_du
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV2DI, UV2DI
+
+/// lsx_vmulwev_q_du_d
+name = lsx_vmulwev_q_du_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV2DI, V2DI
+
+/// lsx_vmulwod_q_du_d
+name = lsx_vmulwod_q_du_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, UV2DI, V2DI
+
+/// lsx_vhaddw_q_d
+name = lsx_vhaddw_q_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vhaddw_qu_du
+name = lsx_vhaddw_qu_du
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV2DI, UV2DI
+
+/// lsx_vhsubw_q_d
+name = lsx_vhsubw_q_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vhsubw_qu_du
+name = lsx_vhsubw_qu_du
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV2DI, UV2DI
+
+/// lsx_vmaddwev_d_w
+name = lsx_vmaddwev_d_w
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V4SI, V4SI
+
+/// lsx_vmaddwev_w_h
+name = lsx_vmaddwev_w_h
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V8HI, V8HI
+
+/// lsx_vmaddwev_h_b
+name = lsx_vmaddwev_h_b
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V16QI, V16QI
+
+/// lsx_vmaddwev_d_wu
+name = lsx_vmaddwev_d_wu
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV2DI, UV4SI, UV4SI
+
+/// lsx_vmaddwev_w_hu
+name = lsx_vmaddwev_w_hu
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV4SI, UV8HI, UV8HI
+
+/// lsx_vmaddwev_h_bu
+name = lsx_vmaddwev_h_bu
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV8HI, UV16QI, UV16QI
+
+/// lsx_vmaddwod_d_w
+name = lsx_vmaddwod_d_w
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V4SI, V4SI
+
+/// lsx_vmaddwod_w_h
+name = lsx_vmaddwod_w_h
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V8HI, V8HI
+
+/// lsx_vmaddwod_h_b
+name = lsx_vmaddwod_h_b
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V16QI, V16QI
+
+/// lsx_vmaddwod_d_wu
+name = lsx_vmaddwod_d_wu
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV2DI, UV4SI, UV4SI
+
+/// lsx_vmaddwod_w_hu
+name = lsx_vmaddwod_w_hu
+asm-fmts = vd, vj, vk
+data-types = UV4SI, UV4SI, UV8HI, UV8HI
+
+/// lsx_vmaddwod_h_bu
+name = lsx_vmaddwod_h_bu
+asm-fmts = vd, vj, vk
+data-types = UV8HI, UV8HI, UV16QI, UV16QI
+
+/// lsx_vmaddwev_d_wu_w
+name = lsx_vmaddwev_d_wu_w
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, UV4SI, V4SI
+
+/// lsx_vmaddwev_w_hu_h
+name = lsx_vmaddwev_w_hu_h
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, UV8HI, V8HI
+
+/// lsx_vmaddwev_h_bu_b
+name = lsx_vmaddwev_h_bu_b
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, UV16QI, V16QI
+
+/// lsx_vmaddwod_d_wu_w
+name = lsx_vmaddwod_d_wu_w
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, UV4SI, V4SI
+
+/// lsx_vmaddwod_w_hu_h
+name = lsx_vmaddwod_w_hu_h
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, UV8HI, V8HI
+
+/// lsx_vmaddwod_h_bu_b
+name = lsx_vmaddwod_h_bu_b
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, UV16QI, V16QI
+
+/// lsx_vmaddwev_q_d
+name = lsx_vmaddwev_q_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI, V2DI
+
+/// lsx_vmaddwod_q_d
+name = lsx_vmaddwod_q_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI, V2DI
+
+/// lsx_vmaddwev_q_du
+name = lsx_vmaddwev_q_du
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV2DI, UV2DI, UV2DI
+
+/// lsx_vmaddwod_q_du
+name = lsx_vmaddwod_q_du
+asm-fmts = vd, vj, vk
+data-types = UV2DI, UV2DI, UV2DI, UV2DI
+
+/// lsx_vmaddwev_q_du_d
+name = lsx_vmaddwev_q_du_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, UV2DI, V2DI
+
+/// lsx_vmaddwod_q_du_d
+name = lsx_vmaddwod_q_du_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, UV2DI, V2DI
+
+/// lsx_vrotr_b
+name = lsx_vrotr_b
+asm-fmts = vd, vj, vk
+data-types = V16QI, V16QI, V16QI
+
+/// lsx_vrotr_h
+name = lsx_vrotr_h
+asm-fmts = vd, vj, vk
+data-types = V8HI, V8HI, V8HI
+
+/// lsx_vrotr_w
+name = lsx_vrotr_w
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SI, V4SI
+
+/// lsx_vrotr_d
+name = lsx_vrotr_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vadd_q
+name = lsx_vadd_q
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vsub_q
+name = lsx_vsub_q
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DI, V2DI
+
+/// lsx_vldrepl_b
+name = lsx_vldrepl_b
+asm-fmts = vd, rj, si12
+data-types = V16QI, CVPOINTER, SI
+
+/// lsx_vldrepl_h
+name = lsx_vldrepl_h
+asm-fmts = vd, rj, si11
+data-types = V8HI, CVPOINTER, SI
+
+/// lsx_vldrepl_w
+name = lsx_vldrepl_w
+asm-fmts = vd, rj, si10
+data-types = V4SI, CVPOINTER, SI
+
+/// lsx_vldrepl_d
+name = lsx_vldrepl_d
+asm-fmts = vd, rj, si9
+data-types = V2DI, CVPOINTER, SI
+
+/// lsx_vmskgez_b
+name = lsx_vmskgez_b
+asm-fmts = vd, vj
+data-types = V16QI, V16QI
+
+/// lsx_vmsknz_b
+name = lsx_vmsknz_b
+asm-fmts = vd, vj
+data-types = V16QI, V16QI
+
+/// lsx_vexth_h_b
+name = lsx_vexth_h_b
+asm-fmts = vd, vj
+data-types = V8HI, V16QI
+
+/// lsx_vexth_w_h
+name = lsx_vexth_w_h
+asm-fmts = vd, vj
+data-types = V4SI, V8HI
+
+/// lsx_vexth_d_w
+name = lsx_vexth_d_w
+asm-fmts = vd, vj
+data-types = V2DI, V4SI
+
+/// lsx_vexth_q_d
+name = lsx_vexth_q_d
+asm-fmts = vd, vj
+data-types = V2DI, V2DI
+
+/// lsx_vexth_hu_bu
+name = lsx_vexth_hu_bu
+asm-fmts = vd, vj
+data-types = UV8HI, UV16QI
+
+/// lsx_vexth_wu_hu
+name = lsx_vexth_wu_hu
+asm-fmts = vd, vj
+data-types = UV4SI, UV8HI
+
+/// lsx_vexth_du_wu
+name = lsx_vexth_du_wu
+asm-fmts = vd, vj
+data-types = UV2DI, UV4SI
+
+/// lsx_vexth_qu_du
+name = lsx_vexth_qu_du
+asm-fmts = vd, vj
+data-types = UV2DI, UV2DI
+
+/// lsx_vrotri_b
+name = lsx_vrotri_b
+asm-fmts = vd, vj, ui3
+data-types = V16QI, V16QI, UQI
+
+/// lsx_vrotri_h
+name = lsx_vrotri_h
+asm-fmts = vd, vj, ui4
+data-types = V8HI, V8HI, UQI
+
+/// lsx_vrotri_w
+name = lsx_vrotri_w
+asm-fmts = vd, vj, ui5
+data-types = V4SI, V4SI, UQI
+
+/// lsx_vrotri_d
+name = lsx_vrotri_d
+asm-fmts = vd, vj, ui6
+data-types = V2DI, V2DI, UQI
+
+/// lsx_vextl_q_d
+name = lsx_vextl_q_d
+asm-fmts = vd, vj
+data-types = V2DI, V2DI
+
+/// lsx_vsrlni_b_h
+name = lsx_vsrlni_b_h
+asm-fmts = vd, vj, ui4
+data-types = V16QI, V16QI, V16QI, USI
+
+/// lsx_vsrlni_h_w
+name = lsx_vsrlni_h_w
+asm-fmts = vd, vj, ui5
+data-types = V8HI, V8HI, V8HI, USI
+
+/// lsx_vsrlni_w_d
+name = lsx_vsrlni_w_d
+asm-fmts = vd, vj, ui6
+data-types = V4SI, V4SI, V4SI, USI
+
+/// lsx_vsrlni_d_q
+name = lsx_vsrlni_d_q
+asm-fmts = vd, vj, ui7
+data-types = V2DI, V2DI, V2DI, USI
+
+/// lsx_vsrlrni_b_h
+name = lsx_vsrlrni_b_h
+asm-fmts = vd, vj, ui4
+data-types = V16QI, V16QI, V16QI, USI
+
+/// lsx_vsrlrni_h_w
+name = lsx_vsrlrni_h_w
+asm-fmts = vd, vj, ui5
+data-types = V8HI, V8HI, V8HI, USI
+
+/// lsx_vsrlrni_w_d
+name = lsx_vsrlrni_w_d
+asm-fmts = vd, vj, ui6
+data-types = V4SI, V4SI, V4SI, USI
+
+/// lsx_vsrlrni_d_q
+name = lsx_vsrlrni_d_q
+asm-fmts = vd, vj, ui7
+data-types = V2DI, V2DI, V2DI, USI
+
+/// lsx_vssrlni_b_h
+name = lsx_vssrlni_b_h
+asm-fmts = vd, vj, ui4
+data-types = V16QI, V16QI, V16QI, USI
+
+/// lsx_vssrlni_h_w
+name = lsx_vssrlni_h_w
+asm-fmts = vd, vj, ui5
+data-types = V8HI, V8HI, V8HI, USI
+
+/// lsx_vssrlni_w_d
+name = lsx_vssrlni_w_d
+asm-fmts = vd, vj, ui6
+data-types = V4SI, V4SI, V4SI, USI
+
+/// lsx_vssrlni_d_q
+name = lsx_vssrlni_d_q
+asm-fmts = vd, vj, ui7
+data-types = V2DI, V2DI, V2DI, USI
+
+/// lsx_vssrlni_bu_h
+name = lsx_vssrlni_bu_h
+asm-fmts = vd, vj, ui4
+data-types = UV16QI, UV16QI, V16QI, USI
+
+/// lsx_vssrlni_hu_w
+name = lsx_vssrlni_hu_w
+asm-fmts = vd, vj, ui5
+data-types = UV8HI, UV8HI, V8HI, USI
+
+/// lsx_vssrlni_wu_d
+name = lsx_vssrlni_wu_d
+asm-fmts = vd, vj, ui6
+data-types = UV4SI, UV4SI, V4SI, USI
+
+/// lsx_vssrlni_du_q
+name = lsx_vssrlni_du_q
+asm-fmts = vd, vj, ui7
+data-types = UV2DI, UV2DI, V2DI, USI
+
+/// lsx_vssrlrni_b_h
+name = lsx_vssrlrni_b_h
+asm-fmts = vd, vj, ui4
+data-types = V16QI, V16QI, V16QI, USI
+
+/// lsx_vssrlrni_h_w
+name = lsx_vssrlrni_h_w
+asm-fmts = vd, vj, ui5
+data-types = V8HI, V8HI, V8HI, USI
+
+/// lsx_vssrlrni_w_d
+name = lsx_vssrlrni_w_d
+asm-fmts = vd, vj, ui6
+data-types = V4SI, V4SI, V4SI, USI
+
+/// lsx_vssrlrni_d_q
+name = lsx_vssrlrni_d_q
+asm-fmts = vd, vj, ui7
+data-types = V2DI, V2DI, V2DI, USI
+
+/// lsx_vssrlrni_bu_h
+name = lsx_vssrlrni_bu_h
+asm-fmts = vd, vj, ui4
+data-types = UV16QI, UV16QI, V16QI, USI
+
+/// lsx_vssrlrni_hu_w
+name = lsx_vssrlrni_hu_w
+asm-fmts = vd, vj, ui5
+data-types = UV8HI, UV8HI, V8HI, USI
+
+/// lsx_vssrlrni_wu_d
+name = lsx_vssrlrni_wu_d
+asm-fmts = vd, vj, ui6
+data-types = UV4SI, UV4SI, V4SI, USI
+
+/// lsx_vssrlrni_du_q
+name = lsx_vssrlrni_du_q
+asm-fmts = vd, vj, ui7
+data-types = UV2DI, UV2DI, V2DI, USI
+
+/// lsx_vsrani_b_h
+name = lsx_vsrani_b_h
+asm-fmts = vd, vj, ui4
+data-types = V16QI, V16QI, V16QI, USI
+
+/// lsx_vsrani_h_w
+name = lsx_vsrani_h_w
+asm-fmts = vd, vj, ui5
+data-types = V8HI, V8HI, V8HI, USI
+
+/// lsx_vsrani_w_d
+name = lsx_vsrani_w_d
+asm-fmts = vd, vj, ui6
+data-types = V4SI, V4SI, V4SI, USI
+
+/// lsx_vsrani_d_q
+name = lsx_vsrani_d_q
+asm-fmts = vd, vj, ui7
+data-types = V2DI, V2DI, V2DI, USI
+
+/// lsx_vsrarni_b_h
+name = lsx_vsrarni_b_h
+asm-fmts = vd, vj, ui4
+data-types = V16QI, V16QI, V16QI, USI
+
+/// lsx_vsrarni_h_w
+name = lsx_vsrarni_h_w
+asm-fmts = vd, vj, ui5
+data-types = V8HI, V8HI, V8HI, USI
+
+/// lsx_vsrarni_w_d
+name = lsx_vsrarni_w_d
+asm-fmts = vd, vj, ui6
+data-types = V4SI, V4SI, V4SI, USI
+
+/// lsx_vsrarni_d_q
+name = lsx_vsrarni_d_q
+asm-fmts = vd, vj, ui7
+data-types = V2DI, V2DI, V2DI, USI
+
+/// lsx_vssrani_b_h
+name = lsx_vssrani_b_h
+asm-fmts = vd, vj, ui4
+data-types = V16QI, V16QI, V16QI, USI
+
+/// lsx_vssrani_h_w
+name = lsx_vssrani_h_w
+asm-fmts = vd, vj, ui5
+data-types = V8HI, V8HI, V8HI, USI
+
+/// lsx_vssrani_w_d
+name = lsx_vssrani_w_d
+asm-fmts = vd, vj, ui6
+data-types = V4SI, V4SI, V4SI, USI
+
+/// lsx_vssrani_d_q
+name = lsx_vssrani_d_q
+asm-fmts = vd, vj, ui7
+data-types = V2DI, V2DI, V2DI, USI
+
+/// lsx_vssrani_bu_h
+name = lsx_vssrani_bu_h
+asm-fmts = vd, vj, ui4
+data-types = UV16QI, UV16QI, V16QI, USI
+
+/// lsx_vssrani_hu_w
+name = lsx_vssrani_hu_w
+asm-fmts = vd, vj, ui5
+data-types = UV8HI, UV8HI, V8HI, USI
+
+/// lsx_vssrani_wu_d
+name = lsx_vssrani_wu_d
+asm-fmts = vd, vj, ui6
+data-types = UV4SI, UV4SI, V4SI, USI
+
+/// lsx_vssrani_du_q
+name = lsx_vssrani_du_q
+asm-fmts = vd, vj, ui7
+data-types = UV2DI, UV2DI, V2DI, USI
+
+/// lsx_vssrarni_b_h
+name = lsx_vssrarni_b_h
+asm-fmts = vd, vj, ui4
+data-types = V16QI, V16QI, V16QI, USI
+
+/// lsx_vssrarni_h_w
+name = lsx_vssrarni_h_w
+asm-fmts = vd, vj, ui5
+data-types = V8HI, V8HI, V8HI, USI
+
+/// lsx_vssrarni_w_d
+name = lsx_vssrarni_w_d
+asm-fmts = vd, vj, ui6
+data-types = V4SI, V4SI, V4SI, USI
+
+/// lsx_vssrarni_d_q
+name = lsx_vssrarni_d_q
+asm-fmts = vd, vj, ui7
+data-types = V2DI, V2DI, V2DI, USI
+
+/// lsx_vssrarni_bu_h
+name = lsx_vssrarni_bu_h
+asm-fmts = vd, vj, ui4
+data-types = UV16QI, UV16QI, V16QI, USI
+
+/// lsx_vssrarni_hu_w
+name = lsx_vssrarni_hu_w
+asm-fmts = vd, vj, ui5
+data-types = UV8HI, UV8HI, V8HI, USI
+
+/// lsx_vssrarni_wu_d
+name = lsx_vssrarni_wu_d
+asm-fmts = vd, vj, ui6
+data-types = UV4SI, UV4SI, V4SI, USI
+
+/// lsx_vssrarni_du_q
+name = lsx_vssrarni_du_q
+asm-fmts = vd, vj, ui7
+data-types = UV2DI, UV2DI, V2DI, USI
+
+/// lsx_vpermi_w
+name = lsx_vpermi_w
+asm-fmts = vd, vj, ui8
+data-types = V4SI, V4SI, V4SI, USI
+
+/// lsx_vld
+name = lsx_vld
+asm-fmts = vd, rj, si12
+data-types = V16QI, CVPOINTER, SI
+
+/// lsx_vst
+name = lsx_vst
+asm-fmts = vd, rj, si12
+data-types = VOID, V16QI, CVPOINTER, SI
+
+/// lsx_vssrlrn_b_h
+name = lsx_vssrlrn_b_h
+asm-fmts = vd, vj, vk
+data-types = V16QI, V8HI, V8HI
+
+/// lsx_vssrlrn_h_w
+name = lsx_vssrlrn_h_w
+asm-fmts = vd, vj, vk
+data-types = V8HI, V4SI, V4SI
+
+/// lsx_vssrlrn_w_d
+name = lsx_vssrlrn_w_d
+asm-fmts = vd, vj, vk
+data-types = V4SI, V2DI, V2DI
+
+/// lsx_vssrln_b_h
+name = lsx_vssrln_b_h
+asm-fmts = vd, vj, vk
+data-types = V16QI, V8HI, V8HI
+
+/// lsx_vssrln_h_w
+name = lsx_vssrln_h_w
+asm-fmts = vd, vj, vk
+data-types = V8HI, V4SI, V4SI
+
+/// lsx_vssrln_w_d
+name = lsx_vssrln_w_d
+asm-fmts = vd, vj, vk
+data-types = V4SI, V2DI, V2DI
+
+/// lsx_vorn_v
+name = lsx_vorn_v
+asm-fmts = vd, vj, vk
+data-types = UV16QI, UV16QI, UV16QI
+
+/// lsx_vldi
+name = lsx_vldi
+asm-fmts = vd, i13
+data-types = V2DI, HI
+
+/// lsx_vshuf_b
+name = lsx_vshuf_b
+asm-fmts = vd, vj, vk, va
+data-types = V16QI, V16QI, V16QI, V16QI
+
+/// lsx_vldx
+name = lsx_vldx
+asm-fmts = vd, rj, rk
+data-types = V16QI, CVPOINTER, DI
+
+/// lsx_vstx
+name = lsx_vstx
+asm-fmts = vd, rj, rk
+data-types = VOID, V16QI, CVPOINTER, DI
+
+/// lsx_vextl_qu_du
+name = lsx_vextl_qu_du
+asm-fmts = vd, vj
+data-types = UV2DI, UV2DI
+
+/// lsx_bnz_b
+name = lsx_bnz_b
+asm-fmts = cd, vj
+data-types = SI, UV16QI
+
+/// lsx_bnz_d
+name = lsx_bnz_d
+asm-fmts = cd, vj
+data-types = SI, UV2DI
+
+/// lsx_bnz_h
+name = lsx_bnz_h
+asm-fmts = cd, vj
+data-types = SI, UV8HI
+
+/// lsx_bnz_v
+name = lsx_bnz_v
+asm-fmts = cd, vj
+data-types = SI, UV16QI
+
+/// lsx_bnz_w
+name = lsx_bnz_w
+asm-fmts = cd, vj
+data-types = SI, UV4SI
+
+/// lsx_bz_b
+name = lsx_bz_b
+asm-fmts = cd, vj
+data-types = SI, UV16QI
+
+/// lsx_bz_d
+name = lsx_bz_d
+asm-fmts = cd, vj
+data-types = SI, UV2DI
+
+/// lsx_bz_h
+name = lsx_bz_h
+asm-fmts = cd, vj
+data-types = SI, UV8HI
+
+/// lsx_bz_v
+name = lsx_bz_v
+asm-fmts = cd, vj
+data-types = SI, UV16QI
+
+/// lsx_bz_w
+name = lsx_bz_w
+asm-fmts = cd, vj
+data-types = SI, UV4SI
+
+/// lsx_vfcmp_caf_d
+name = lsx_vfcmp_caf_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_caf_s
+name = lsx_vfcmp_caf_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_ceq_d
+name = lsx_vfcmp_ceq_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_ceq_s
+name = lsx_vfcmp_ceq_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_cle_d
+name = lsx_vfcmp_cle_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_cle_s
+name = lsx_vfcmp_cle_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_clt_d
+name = lsx_vfcmp_clt_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_clt_s
+name = lsx_vfcmp_clt_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_cne_d
+name = lsx_vfcmp_cne_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_cne_s
+name = lsx_vfcmp_cne_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_cor_d
+name = lsx_vfcmp_cor_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_cor_s
+name = lsx_vfcmp_cor_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_cueq_d
+name = lsx_vfcmp_cueq_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_cueq_s
+name = lsx_vfcmp_cueq_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_cule_d
+name = lsx_vfcmp_cule_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_cule_s
+name = lsx_vfcmp_cule_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_cult_d
+name = lsx_vfcmp_cult_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_cult_s
+name = lsx_vfcmp_cult_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_cun_d
+name = lsx_vfcmp_cun_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_cune_d
+name = lsx_vfcmp_cune_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_cune_s
+name = lsx_vfcmp_cune_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_cun_s
+name = lsx_vfcmp_cun_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_saf_d
+name = lsx_vfcmp_saf_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_saf_s
+name = lsx_vfcmp_saf_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_seq_d
+name = lsx_vfcmp_seq_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_seq_s
+name = lsx_vfcmp_seq_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_sle_d
+name = lsx_vfcmp_sle_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_sle_s
+name = lsx_vfcmp_sle_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_slt_d
+name = lsx_vfcmp_slt_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_slt_s
+name = lsx_vfcmp_slt_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_sne_d
+name = lsx_vfcmp_sne_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_sne_s
+name = lsx_vfcmp_sne_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_sor_d
+name = lsx_vfcmp_sor_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_sor_s
+name = lsx_vfcmp_sor_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_sueq_d
+name = lsx_vfcmp_sueq_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_sueq_s
+name = lsx_vfcmp_sueq_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_sule_d
+name = lsx_vfcmp_sule_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_sule_s
+name = lsx_vfcmp_sule_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_sult_d
+name = lsx_vfcmp_sult_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_sult_s
+name = lsx_vfcmp_sult_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_sun_d
+name = lsx_vfcmp_sun_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_sune_d
+name = lsx_vfcmp_sune_d
+asm-fmts = vd, vj, vk
+data-types = V2DI, V2DF, V2DF
+
+/// lsx_vfcmp_sune_s
+name = lsx_vfcmp_sune_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vfcmp_sun_s
+name = lsx_vfcmp_sun_s
+asm-fmts = vd, vj, vk
+data-types = V4SI, V4SF, V4SF
+
+/// lsx_vrepli_b
+name = lsx_vrepli_b
+asm-fmts = vd, si10
+data-types = V16QI, HI
+
+/// lsx_vrepli_d
+name = lsx_vrepli_d
+asm-fmts = vd, si10
+data-types = V2DI, HI
+
+/// lsx_vrepli_h
+name = lsx_vrepli_h
+asm-fmts = vd, si10
+data-types = V8HI, HI
+
+/// lsx_vrepli_w
+name = lsx_vrepli_w
+asm-fmts = vd, si10
+data-types = V4SI, HI
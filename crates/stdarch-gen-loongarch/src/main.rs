@@ -0,0 +1,359 @@
+use std::env;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{self, BufReader};
+use std::path::PathBuf;
+
+/// The LoongArch SIMD extension an intrinsic belongs to.
+#[derive(Clone, Copy, PartialEq)]
+enum TargetFeature {
+    Lsx,
+    Lasx,
+}
+
+impl TargetFeature {
+    fn new(ext_name: &str) -> TargetFeature {
+        match ext_name {
+            "lsx" => TargetFeature::Lsx,
+            "lasx" => TargetFeature::Lasx,
+            _ => panic!("unknown extension: {ext_name}"),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            TargetFeature::Lsx => "lsx",
+            TargetFeature::Lasx => "lasx",
+        }
+    }
+
+    /// The width of the vector registers, in bytes.
+    fn bytes(self) -> u32 {
+        match self {
+            TargetFeature::Lsx => 16,
+            TargetFeature::Lasx => 32,
+        }
+    }
+}
+
+/// Returns the Rust type of a GCC data type code from the spec.
+///
+/// Pointers are `*mut` for stores, whose intrinsics return `VOID`.
+fn type_to_rust(t: &str, is_store: bool) -> &'static str {
+    match t {
+        "V16QI" => "v16i8",
+        "V8HI" => "v8i16",
+        "V4SI" => "v4i32",
+        "V2DI" => "v2i64",
+        "UV16QI" => "v16u8",
+        "UV8HI" => "v8u16",
+        "UV4SI" => "v4u32",
+        "UV2DI" => "v2u64",
+        "V4SF" => "v4f32",
+        "V2DF" => "v2f64",
+        "V32QI" => "v32i8",
+        "V16HI" => "v16i16",
+        "V8SI" => "v8i32",
+        "V4DI" => "v4i64",
+        "UV32QI" => "v32u8",
+        "UV16HI" => "v16u16",
+        "UV8SI" => "v8u32",
+        "UV4DI" => "v4u64",
+        "V8SF" => "v8f32",
+        "V4DF" => "v4f64",
+        "QI" | "HI" | "SI" => "i32",
+        "UQI" | "USI" => "u32",
+        "DI" => "i64",
+        "UDI" => "u64",
+        "CVPOINTER" if is_store => "*mut i8",
+        "CVPOINTER" => "*const i8",
+        _ => panic!("unknown type: {t}"),
+    }
+}
+
+/// An immediate operand of an instruction, passed as a const generic.
+struct Immediate {
+    name: String,
+    ty: &'static str,
+    bits: u32,
+    signed: bool,
+}
+
+/// Decodes the assembly format of the immediate operand `fmt`, whose
+/// intrinsic argument has the data type `data_type`.
+///
+/// `idx` is the element index of `vstelm`, whose width depends on the
+/// element type of the intrinsic.
+fn immediate(fmt: &str, data_type: &str, name: &str, target: TargetFeature) -> Option<Immediate> {
+    let (signed, bits) = if fmt == "idx" {
+        let element_bytes = match name.chars().last().unwrap() {
+            'b' => 1,
+            'h' => 2,
+            'w' => 4,
+            'd' => 8,
+            _ => panic!("unknown element type of {name}"),
+        };
+        (false, (target.bytes() / element_bytes).trailing_zeros())
+    } else if let Some(bits) = fmt.strip_prefix("ui") {
+        (false, bits.parse().ok()?)
+    } else if let Some(bits) = fmt.strip_prefix("si") {
+        (true, bits.parse().ok()?)
+    } else if let Some(bits) = fmt.strip_prefix('i') {
+        (true, bits.parse().ok()?)
+    } else {
+        return None;
+    };
+    Some(Immediate {
+        name: if signed {
+            format!("IMM_S{bits}")
+        } else {
+            format!("IMM{bits}")
+        },
+        ty: type_to_rust(data_type, false),
+        bits,
+        signed,
+    })
+}
+
+/// Returns the mnemonic of the instruction generated by the intrinsic `name`.
+///
+/// The branch intrinsics `bz` and `bnz` set a condition flag with a `vset`
+/// instruction, which is then branched on.
+fn instruction(name: &str) -> String {
+    let insn = name.split_once('_').unwrap().1;
+    let (vector, branch) = match insn.strip_prefix('x') {
+        Some(branch) if branch.starts_with('b') => ("xv", branch),
+        _ => ("v", insn),
+    };
+    let set = match branch.split_once('_') {
+        Some(("bz", "v")) => "seteqz",
+        Some(("bnz", "v")) => "setnez",
+        Some(("bz", _)) => "setanyeqz",
+        Some(("bnz", _)) => "setallnez",
+        _ => return insn.replace('_', "."),
+    };
+    format!("{vector}{set}.{}", &branch[branch.len() - 1..])
+}
+
+/// Extracts the spec of every intrinsic declared in a GCC header.
+fn gen_spec(in_file: &str, ext_name: &str) -> io::Result<()> {
+    let f = File::open(in_file).unwrap_or_else(|_| panic!("Failed to open {in_file}"));
+    let f = BufReader::new(f);
+
+    let mut out = format!(
+        r#"// This code is automatically generated. DO NOT MODIFY.
+// ```
+// OUT_DIR=`pwd`/crates/stdarch-gen-loongarch cargo run -p stdarch-gen-loongarch -- {in_file}
+// ```
+"#
+    );
+
+    let fn_pat = format!("__{ext_name}_");
+    let mut asm_fmts = String::new();
+    let mut data_types = String::new();
+    // The intrinsics that depend on further extensions, like `frecipe`, are
+    // not supported yet.
+    let ext_macro = match ext_name {
+        "lsx" => "__loongarch_sx",
+        _ => "__loongarch_asx",
+    };
+    let mut skip = false;
+    for line in f.lines() {
+        let line = line?;
+        if line.starts_with("#if defined") {
+            skip = !line.contains(&format!("({ext_macro})"));
+        } else if line.starts_with("#endif") {
+            skip = false;
+        } else if skip {
+            continue;
+        } else if let Some(s) = line.find("/* Assembly instruction format:") {
+            let e = line.find('.').unwrap();
+            asm_fmts = line[s + 31..e].trim().to_string();
+        } else if let Some(s) = line.find("/* Data types in instruction templates:") {
+            let e = line.find('.').unwrap();
+            data_types = line[s + 39..e].trim().to_string();
+        } else if let Some(s) = line.find(&fn_pat) {
+            let e = line.find('(').unwrap();
+            let name = line[s + 2..e].trim();
+            out.push_str(&format!("\n/// {name}\n"));
+            out.push_str(&format!("name = {name}\n"));
+            out.push_str(&format!("asm-fmts = {asm_fmts}\n"));
+            out.push_str(&format!("data-types = {data_types}\n"));
+        }
+    }
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+    std::fs::create_dir_all(&out_path)?;
+    let mut file = File::create(out_path.join(format!("{ext_name}.spec")))?;
+    file.write_all(out.as_bytes())
+}
+
+/// Generates the bindings of every intrinsic of a spec.
+fn gen_bind(in_file: &str, ext_name: &str) -> io::Result<()> {
+    let f = File::open(in_file).unwrap_or_else(|_| panic!("Failed to open {in_file}"));
+    let f = BufReader::new(f);
+    let target = TargetFeature::new(ext_name);
+
+    let mut links = String::new();
+    let mut functions = String::new();
+    let mut current_name: Option<String> = None;
+    let mut asm_fmts: Vec<String> = Vec::new();
+    for line in f.lines() {
+        let line = line?;
+        if let Some(name) = line.strip_prefix("name = ") {
+            current_name = Some(name.to_string());
+        } else if let Some(fmts) = line.strip_prefix("asm-fmts = ") {
+            asm_fmts = fmts.split(',').map(|s| s.trim().to_string()).collect();
+        } else if let Some(types) = line.strip_prefix("data-types = ") {
+            let name = current_name.take().expect("data-types without a name");
+            let data_types: Vec<&str> = types.split(',').map(str::trim).collect();
+            let (link, function) = gen_bind_body(&name, &asm_fmts, &data_types, target);
+            links.push_str(&link);
+            functions.push_str(&function);
+        }
+    }
+
+    let out = format!(
+        r#"// This code is automatically generated. DO NOT MODIFY.
+//
+// Instead, modify `{in_file}` and run the following command to re-generate this file:
+//
+// ```
+// OUT_DIR=`pwd`/crates/core_arch cargo run -p stdarch-gen-loongarch -- {in_file}
+// ```
+
+use super::types::*;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "unadjusted" {{
+{links}}}
+{functions}"#
+    );
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap_or("crates/core_arch".to_string()))
+        .join("src")
+        .join("loongarch64")
+        .join(ext_name);
+    std::fs::create_dir_all(&out_path)?;
+    let mut file = File::create(out_path.join("generated.rs"))?;
+    file.write_all(out.as_bytes())
+}
+
+/// Generates the LLVM declaration and the public function of one intrinsic.
+fn gen_bind_body(
+    name: &str,
+    asm_fmts: &[String],
+    data_types: &[&str],
+    target: TargetFeature,
+) -> (String, String) {
+    let (out_t, in_t) = data_types.split_first().unwrap();
+    let is_store = *out_t == "VOID";
+    let ret = if is_store {
+        String::new()
+    } else {
+        format!(" -> {}", type_to_rust(out_t, is_store))
+    };
+
+    // The immediates are the trailing operands of the instruction, and the
+    // trailing arguments of the intrinsic.
+    let immediates: Vec<Immediate> = asm_fmts
+        .iter()
+        .rev()
+        .zip(in_t.iter().rev())
+        .map_while(|(fmt, t)| immediate(fmt, t, name, target))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect();
+    let n_args = in_t.len() - immediates.len();
+
+    let mut link_params = Vec::new();
+    let mut params = Vec::new();
+    let mut call_args = Vec::new();
+    let mut arg_names = ["a", "b", "c", "d"].iter();
+    for t in &in_t[..n_args] {
+        let ty = type_to_rust(t, is_store);
+        let arg = if *t == "CVPOINTER" {
+            "mem_addr"
+        } else {
+            arg_names.next().unwrap()
+        };
+        link_params.push(format!("{arg}: {ty}"));
+        params.push(format!("{arg}: {ty}"));
+        call_args.push(arg.to_string());
+    }
+    for (imm, arg) in immediates.iter().zip(arg_names) {
+        link_params.push(format!("{arg}: {}", imm.ty));
+        call_args.push(imm.name.clone());
+    }
+
+    let link = format!(
+        "    #[link_name = \"llvm.loongarch.{}\"]\n    fn __{name}({}){ret};\n",
+        name.replace('_', "."),
+        link_params.join(", ")
+    );
+
+    let insn = instruction(name);
+    let mut attrs = format!(
+        "/// Generates the instruction `{insn} {}`.\n#[inline]\n#[target_feature(enable = \"{}\")]\n",
+        asm_fmts.join(", "),
+        target.name()
+    );
+    // 1 is in the range of every immediate.
+    let assert_args: String = immediates
+        .iter()
+        .map(|imm| format!(", {} = 1", imm.name))
+        .collect();
+    attrs.push_str(&format!(
+        "#[cfg_attr(test, assert_instr({insn}{assert_args}))]\n"
+    ));
+    let mut generics = String::new();
+    let mut body = String::new();
+    if !immediates.is_empty() {
+        let positions: Vec<String> = (n_args..in_t.len()).map(|i| i.to_string()).collect();
+        attrs.push_str(&format!(
+            "#[rustc_legacy_const_generics({})]\n",
+            positions.join(", ")
+        ));
+        let consts: Vec<String> = immediates
+            .iter()
+            .map(|imm| format!("const {}: {}", imm.name, imm.ty))
+            .collect();
+        generics = format!("<{}>", consts.join(", "));
+        for imm in &immediates {
+            let assert = if imm.signed {
+                "static_assert_simm_bits"
+            } else {
+                "static_assert_uimm_bits"
+            };
+            body.push_str(&format!("    {assert}!({}, {});\n", imm.name, imm.bits));
+        }
+    }
+    body.push_str(&format!("    __{name}({})\n", call_args.join(", ")));
+
+    let function = format!(
+        r#"
+{attrs}pub unsafe fn {name}{generics}({}){ret} {{
+{body}}}
+"#,
+        params.join(", ")
+    );
+    (link, function)
+}
+
+fn main() -> io::Result<()> {
+    let args: Vec<String> = env::args().collect();
+    let in_file = args.get(1).expect("missing the input file");
+
+    let file_name = in_file.rsplit('/').next().unwrap();
+    if let Some(ext_name) = file_name.strip_suffix("intrin.h") {
+        gen_spec(in_file, ext_name)
+    } else if let Some(ext_name) = file_name.strip_suffix(".spec") {
+        gen_bind(in_file, ext_name)
+    } else {
+        panic!("expected a `*intrin.h` header or a `*.spec` file: {in_file}");
+    }
+}
@@ -0,0 +1,42 @@
+//! Checks the `#[assert_instr]` shims of the PTX modules passed as arguments.
+//!
+//! This stands in for the test harness of `nvptx64` targets, see
+//! `stdarch_test::ptx` for the details.
+
+use std::{env, fs, panic, process};
+
+fn main() {
+    let paths = env::args().skip(1).collect::<Vec<_>>();
+    if paths.is_empty() {
+        eprintln!("usage: assert-instr-ptx <module.ptx>...");
+        process::exit(2);
+    }
+
+    let mut passed = 0;
+    let mut failed = Vec::new();
+    for path in &paths {
+        let ptx = fs::read_to_string(path)
+            .unwrap_or_else(|e| panic!("failed to read PTX module `{path}`: {e}"));
+        for shim in stdarch_test::ptx::shims(&ptx) {
+            println!("test {} ...", shim.name());
+            match panic::catch_unwind(|| shim.assert()) {
+                Ok(()) => passed += 1,
+                Err(_) => failed.push(shim.name().to_string()),
+            }
+        }
+    }
+
+    println!();
+    if !failed.is_empty() {
+        println!("failures:");
+        for name in &failed {
+            println!("    {name}");
+        }
+        println!();
+    }
+    println!("test result: {} passed; {} failed", passed, failed.len());
+    // Finding no shims at all means they weren't built, which is an error too.
+    if !failed.is_empty() || passed == 0 {
+        process::exit(1);
+    }
+}
@@ -72,6 +72,9 @@ pub(crate) fn disassemble_myself() -> HashSet<Function> {
         let add_args = if cfg!(target_os = "macos") && cfg!(target_arch = "aarch64") {
             // Target features need to be enabled for LLVM objdump on Macos ARM64
             vec!["--mattr=+v8.6a,+crypto,+tme"]
+        } else if cfg!(target_arch = "powerpc64") {
+            // The MMA instructions are only decoded for POWER10
+            vec!["-Mpower10"]
         } else {
             vec![]
         };
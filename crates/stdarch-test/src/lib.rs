@@ -3,18 +3,26 @@
 //! This basically just disassembles the current executable and then parses the
 //! output once globally and then provides the `assert` function which makes
 //! assertions about the disassembly of a function.
+//!
+//! There's no `std` for nvptx, so there the crate only provides the macros.
+//! The shims they emit are checked on the host instead, see `ptx`.
 #![deny(rust_2018_idioms)]
 #![allow(clippy::missing_docs_in_private_items, clippy::print_stdout)]
+#![cfg_attr(target_arch = "nvptx64", no_std)]
 
+#[cfg(not(target_arch = "nvptx64"))]
 #[macro_use]
 extern crate lazy_static;
+#[cfg(not(target_arch = "nvptx64"))]
 #[macro_use]
 extern crate cfg_if;
 
 pub use assert_instr_macro::*;
 pub use simd_test_macro::*;
+#[cfg(not(target_arch = "nvptx64"))]
 use std::{cmp, collections::HashSet, env, hash, hint::black_box, str};
 
+#[cfg(not(target_arch = "nvptx64"))]
 cfg_if! {
     if #[cfg(target_arch = "wasm32")] {
         pub mod wasm;
@@ -25,15 +33,22 @@ cfg_if! {
     }
 }
 
+// nvptx binaries can't run on the host, so its PTX is checked from here.
+#[cfg(not(target_arch = "nvptx64"))]
+pub mod ptx;
+
+#[cfg(not(target_arch = "nvptx64"))]
 lazy_static! {
     static ref DISASSEMBLY: HashSet<Function> = disassemble_myself();
 }
 
+#[cfg(not(target_arch = "nvptx64"))]
 #[derive(Debug)]
 struct Function {
     name: String,
     instrs: Vec<String>,
 }
+#[cfg(not(target_arch = "nvptx64"))]
 impl Function {
     fn new(n: &str) -> Self {
         Self {
@@ -43,13 +58,16 @@ impl Function {
     }
 }
 
+#[cfg(not(target_arch = "nvptx64"))]
 impl cmp::PartialEq for Function {
     fn eq(&self, other: &Self) -> bool {
         self.name == other.name
     }
 }
+#[cfg(not(target_arch = "nvptx64"))]
 impl cmp::Eq for Function {}
 
+#[cfg(not(target_arch = "nvptx64"))]
 impl hash::Hash for Function {
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
         self.name.hash(state)
@@ -60,6 +78,7 @@ impl hash::Hash for Function {
 ///
 /// This asserts that the function at `fnptr` contains the instruction
 /// `expected` provided.
+#[cfg(not(target_arch = "nvptx64"))]
 pub fn assert(shim_addr: usize, fnname: &str, expected: &str) {
     // Make sure that the shim is not removed
     black_box(shim_addr);
@@ -70,26 +89,20 @@ pub fn assert(shim_addr: usize, fnname: &str, expected: &str) {
         .unwrap_or_else(|| panic!("function \"{fnname}\" not found in the disassembly"));
     //eprintln!("  function: {:?}", function);
 
-    let mut instrs = &function.instrs[..];
-    while instrs.last().map_or(false, |s| s == "nop") {
-        instrs = &instrs[..instrs.len() - 1];
-    }
-
-    // Look for `expected` as the first part of any instruction in this
-    // function, e.g., tzcntl in tzcntl %rax,%rax.
-    //
-    // There are two cases when the expected instruction is nop:
-    // 1. The expected intrinsic is compiled away so we can't
-    // check for it - aka the intrinsic is not generating any code.
-    // 2. It is a mark, indicating that the instruction will be
-    // compiled into other instructions - mainly because of llvm
-    // optimization.
-    let found = expected == "nop" || instrs.iter().any(|s| s.starts_with(expected));
+    check(
+        function,
+        expected,
+        |instr, expected| instr.starts_with(expected),
+        inlining_failed,
+    );
+}
 
-    // Look for subroutine call instructions in the disassembly to detect whether
-    // inlining failed: all intrinsics are `#[inline(always)]`, so calling one
-    // intrinsic from another should not generate subroutine call instructions.
-    let inlining_failed = if cfg!(target_arch = "x86_64") || cfg!(target_arch = "wasm32") {
+// Look for subroutine call instructions in the disassembly to detect whether
+// inlining failed: all intrinsics are `#[inline(always)]`, so calling one
+// intrinsic from another should not generate subroutine call instructions.
+#[cfg(not(target_arch = "nvptx64"))]
+fn inlining_failed(instrs: &[String]) -> bool {
+    if cfg!(target_arch = "x86_64") || cfg!(target_arch = "wasm32") {
         instrs.iter().any(|s| s.starts_with("call "))
     } else if cfg!(target_arch = "x86") {
         instrs.windows(2).any(|s| {
@@ -101,10 +114,44 @@ pub fn assert(shim_addr: usize, fnname: &str, expected: &str) {
         })
     } else if cfg!(target_arch = "aarch64") {
         instrs.iter().any(|s| s.starts_with("bl "))
+    } else if cfg!(target_arch = "s390x") {
+        instrs.iter().any(|s| s.starts_with("brasl "))
+    } else if cfg!(target_arch = "loongarch64") {
+        instrs.iter().any(|s| s.starts_with("bl "))
     } else {
         // FIXME: Add detection for other archs
         false
-    };
+    }
+}
+
+/// Asserts that `function` contains the instruction `expected`, using
+/// `contains` to look for it in each instruction and `inlining_failed` to
+/// look for leftover subroutine calls.
+#[cfg(not(target_arch = "nvptx64"))]
+fn check(
+    function: &Function,
+    expected: &str,
+    contains: fn(&str, &str) -> bool,
+    inlining_failed: fn(&[String]) -> bool,
+) {
+    let fnname = &function.name;
+    let mut instrs = &function.instrs[..];
+    while instrs.last().map_or(false, |s| s == "nop") {
+        instrs = &instrs[..instrs.len() - 1];
+    }
+
+    // Look for `expected` as the first part of any instruction in this
+    // function, e.g., tzcntl in tzcntl %rax,%rax.
+    //
+    // There are two cases when the expected instruction is nop:
+    // 1. The expected intrinsic is compiled away so we can't
+    // check for it - aka the intrinsic is not generating any code.
+    // 2. It is a mark, indicating that the instruction will be
+    // compiled into other instructions - mainly because of llvm
+    // optimization.
+    let found = expected == "nop" || instrs.iter().any(|s| contains(s, expected));
+
+    let inlining_failed = inlining_failed(instrs);
 
     let instruction_limit = std::env::var("STDARCH_ASSERT_INSTR_LIMIT")
         .ok()
@@ -185,6 +232,7 @@ pub fn assert(shim_addr: usize, fnname: &str, expected: &str) {
     }
 }
 
+#[cfg(not(target_arch = "nvptx64"))]
 pub fn assert_skip_test_ok(name: &str) {
     if env::var("STDARCH_TEST_EVERYTHING").is_err() {
         return;
@@ -193,4 +241,5 @@ pub fn assert_skip_test_ok(name: &str) {
 }
 
 // See comment in `assert-instr-macro` crate for why this exists
+#[cfg(not(target_arch = "nvptx64"))]
 pub static mut _DONT_DEDUP: *const u8 = std::ptr::null();
@@ -0,0 +1,319 @@
+//! Checking of `#[assert_instr]` shims in the PTX text of `nvptx64` targets.
+//!
+//! There's no `std` for nvptx, and so no test harness either, which means the
+//! tests can't check themselves the way they do elsewhere. Instead the shims
+//! are compiled into a PTX module, together with the instruction each of them
+//! expects, and that module is parsed and checked here on the host. No GPU is
+//! needed for this.
+
+use crate::Function;
+use std::collections::HashMap;
+
+/// An `#[assert_instr]` shim found in a PTX module.
+#[derive(Debug)]
+pub struct Shim {
+    function: Function,
+    expected: String,
+}
+
+impl Shim {
+    /// Name of the shim function.
+    pub fn name(&self) -> &str {
+        &self.function.name
+    }
+
+    /// Asserts that the shim contains its expected instruction, panicking
+    /// otherwise, just like `stdarch_test::assert`.
+    pub fn assert(&self) {
+        crate::check(&self.function, &self.expected, contains, inlining_failed);
+    }
+}
+
+// Special registers, such as `%laneid`, are only read by moving them into a
+// virtual register, so they're looked for as an operand of any instruction.
+fn contains(instr: &str, expected: &str) -> bool {
+    if expected.starts_with('%') {
+        instr
+            .split([' ', ',', ';'])
+            .any(|operand| operand == expected)
+    } else {
+        instr.starts_with(expected)
+    }
+}
+
+// PTX has no inlining of its own: any remaining call is one LLVM didn't inline.
+fn inlining_failed(instrs: &[String]) -> bool {
+    instrs.iter().any(|s| s.starts_with("call"))
+}
+
+/// Parses the `.entry` and `.func` bodies of the PTX module `ptx` and returns
+/// its `#[assert_instr]` shims, sorted by name.
+pub fn shims(ptx: &str) -> Vec<Shim> {
+    let mut functions = Vec::new();
+    let mut expected = HashMap::new();
+
+    let mut lines = ptx.lines().map(|s| s.trim());
+    while let Some(line) = lines.next() {
+        let directives = || line.split_whitespace().take_while(|s| s.starts_with('.'));
+
+        // The expected instruction of each shim is emitted as a byte array
+        // next to it, for example:
+        //
+        //     .visible .global .align 1 .b8 stdarch_test_shim_..._expected[7] = {...};
+        if directives().any(|s| s == ".global") {
+            let mut decl = line.to_string();
+            while !decl.ends_with(';') {
+                match lines.next() {
+                    Some(line) => decl.push_str(line),
+                    None => break,
+                }
+            }
+            if let Some((name, bytes)) = parse_expected(&decl) {
+                expected.insert(name.to_string(), bytes);
+            }
+            continue;
+        }
+
+        // If this isn't a function, we don't care about it.
+        if !directives().any(|s| s == ".func" || s == ".entry") {
+            continue;
+        }
+
+        // The header spans several lines when the function has parameters.
+        // Declarations of external functions end with `;` and have no body.
+        let mut header = line.to_string();
+        while !header.ends_with(';') {
+            match lines.next() {
+                Some("{") | None => break,
+                Some(line) => {
+                    header.push(' ');
+                    header.push_str(line);
+                }
+            }
+        }
+        if header.ends_with(';') {
+            continue;
+        }
+
+        let mut function = Function::new(function_name(&header));
+
+        // Calls are wrapped in nested braces, so count them to find the end of
+        // the body.
+        let mut depth = 1;
+        for line in lines.by_ref() {
+            let line = line.split("//").next().unwrap().trim();
+            match line {
+                "{" => depth += 1,
+                "}" => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                break;
+            }
+            // Skip braces, directives such as register declarations, and
+            // labels, none of which are instructions.
+            if line.is_empty()
+                || line == "{"
+                || line == "}"
+                || line.starts_with('.')
+                || line.ends_with(':')
+            {
+                continue;
+            }
+            let instr = line.split_whitespace().collect::<Vec<_>>().join(" ");
+            function.instrs.push(instr);
+        }
+
+        if function.name.contains("stdarch_test_shim") {
+            functions.push(function);
+        }
+    }
+
+    let mut shims = functions
+        .into_iter()
+        .map(|function| {
+            let expected = expected.remove(&function.name).unwrap_or_else(|| {
+                panic!(
+                    "expected instruction of \"{}\" not found in the PTX",
+                    function.name
+                )
+            });
+            Shim { function, expected }
+        })
+        .collect::<Vec<_>>();
+    shims.sort_by(|a, b| a.function.name.cmp(&b.function.name));
+    shims
+}
+
+// Extracts the name from the header of a function, skipping the parameter
+// declaring its return value if there is one, e.g. `foo` in:
+//
+//     .visible .func (.param .b32 func_retval0) foo( .param .b32 foo_param_0 )
+fn function_name(header: &str) -> &str {
+    let mut rest = header
+        .split_whitespace()
+        .skip_while(|s| *s != ".func" && *s != ".entry")
+        .skip(1);
+    let mut name = rest.next().unwrap_or_default();
+    if name.starts_with('(') {
+        rest.find(|s| s.ends_with(')'));
+        name = rest.next().unwrap_or_default();
+    }
+    name.split('(').next().unwrap()
+}
+
+// Parses a `#[assert_instr]` expected instruction, returning the name of the
+// shim it belongs to along with the instruction.
+fn parse_expected(decl: &str) -> Option<(&str, String)> {
+    let (decl, init) = decl.split_once('=')?;
+    let name = decl.split_whitespace().last()?.split('[').next()?;
+    let name = name.strip_suffix("_expected")?;
+    let bytes = init
+        .trim()
+        .trim_end_matches(';')
+        .trim()
+        .strip_prefix('{')?
+        .strip_suffix('}')?
+        .split(',')
+        .map(|s| s.trim().parse::<u8>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    Some((name, String::from_utf8(bytes).ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PTX: &str = "
+.version 7.0
+.target sm_80
+.address_size 64
+
+	// .globl	stdarch_test_shim__lane_id_laneid
+.visible .global .align 1 .b8 stdarch_test_shim__lane_id_laneid_expected[7] = {37, 108, 97, 110, 101, 105,
+ 100};
+.visible .global .align 1 .b8 stdarch_test_shim__syncthreads_bar_sync_expected[8] = {98, 97, 114, 46, 115, 121, 110, 99};
+.extern .func vprintf
+(
+	.param .b64 vprintf_param_0
+)
+;
+.visible .func  (.param .b32 func_retval0) stdarch_test_shim__lane_id_laneid()
+{
+	.reg .b32 	%r<2>;
+
+	mov.u32 	%r1, %laneid;
+	st.param.b32 	[func_retval0], %r1;
+	ret;
+}
+.visible .entry stdarch_test_shim__syncthreads_bar_sync(
+	.param .u64 stdarch_test_shim__syncthreads_bar_sync_param_0
+)
+{
+$L__BB1_1:
+	bar.sync 	0; // a comment
+	{
+	call.uni vprintf, (param0);
+	}
+	ret;
+}
+.visible .func helper()
+{
+	ret;
+}
+";
+
+    #[test]
+    fn shims_are_parsed() {
+        let shims = shims(PTX);
+        assert_eq!(shims.len(), 2);
+
+        assert_eq!(shims[0].name(), "stdarch_test_shim__lane_id_laneid");
+        assert_eq!(shims[0].expected, "%laneid");
+        assert_eq!(
+            shims[0].function.instrs,
+            [
+                "mov.u32 %r1, %laneid;",
+                "st.param.b32 [func_retval0], %r1;",
+                "ret;"
+            ]
+        );
+        shims[0].assert();
+
+        assert_eq!(shims[1].name(), "stdarch_test_shim__syncthreads_bar_sync");
+        assert_eq!(shims[1].expected, "bar.sync");
+        assert_eq!(
+            shims[1].function.instrs,
+            ["bar.sync 0;", "call.uni vprintf, (param0);", "ret;"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "expected instruction of \"stdarch_test_shim_foo\" not found")]
+    fn shims_without_expected() {
+        shims(".visible .func stdarch_test_shim_foo()\n{\n\tret;\n}\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "inlining failed")]
+    fn shims_with_calls() {
+        shims(PTX)[1].assert();
+    }
+
+    #[test]
+    fn special_registers() {
+        assert!(contains("mov.u32 %r1, %laneid;", "%laneid"));
+        assert!(contains("mov.u64 %rd1, %clock64;", "%clock64"));
+        assert!(!contains("mov.u32 %r1, %laneid;", "%lane"));
+        assert!(!contains("mov.u32 %r1, %laneid;", "%r"));
+        assert!(contains("bar.sync 0;", "bar.sync"));
+        assert!(!contains("mov.u32 %r1, %laneid;", "laneid"));
+    }
+
+    #[test]
+    fn function_names() {
+        assert_eq!(function_name(".visible .func foo()"), "foo");
+        assert_eq!(
+            function_name(
+                ".visible .func (.param .b32 func_retval0) foo( .param .b32 foo_param_0 )"
+            ),
+            "foo"
+        );
+        assert_eq!(
+            function_name(".visible .func  (.param .align 16 .b8 func_retval0[16]) foo()"),
+            "foo"
+        );
+        assert_eq!(function_name(".visible .entry foo("), "foo");
+        assert_eq!(function_name(".func foo"), "foo");
+        assert_eq!(function_name(".visible .global foo"), "");
+    }
+
+    #[test]
+    fn expected_instructions() {
+        assert_eq!(
+            parse_expected(".global .align 1 .b8 foo_expected[3] = {114, 101, 116};"),
+            Some(("foo", "ret".to_string()))
+        );
+        assert_eq!(
+            parse_expected(".global .align 1 .b8 foo_expected[3] = { 114,101 , 116 }"),
+            Some(("foo", "ret".to_string()))
+        );
+
+        // Not an expected instruction.
+        assert_eq!(
+            parse_expected(".global .align 1 .b8 foo[3] = {114, 101, 116};"),
+            None
+        );
+        assert_eq!(
+            parse_expected(".global .align 1 .b8 foo_expected[3];"),
+            None
+        );
+        // Not a byte array.
+        assert_eq!(parse_expected(".global .b8 foo_expected[1] = {256};"), None);
+        assert_eq!(parse_expected(".global .b8 foo_expected[1] = {x};"), None);
+        assert_eq!(parse_expected(".global .b8 foo_expected[1] = 114;"), None);
+        assert_eq!(parse_expected(".global .b8 foo_expected[1] = {114;"), None);
+        // Not UTF-8.
+        assert_eq!(parse_expected(".global .b8 foo_expected[1] = {255};"), None);
+    }
+}
@@ -32,6 +32,11 @@ pub(crate) fn disassemble_myself() -> HashSet<Function> {
         // Lines that have an imbalanced `)` mark the end of a function.
         if !line.ends_with("))") {
             while let Some(line) = lines.next() {
+                // Locals declared by the function, for example those used by
+                // `asm!` operands, aren't instructions.
+                if line.starts_with("(local ") {
+                    continue;
+                }
                 function.instrs.push(line.to_string());
                 if !line.starts_with("(") && line.ends_with(")") {
                     break;
@@ -0,0 +1,76 @@
+/* Prototypes of the overloaded <altivec.h> intrinsics added by the Power ISA
+   2.07 (POWER8) and 3.0 (POWER9), from
+   https://gcc.gnu.org/onlinedocs/gcc/PowerPC-AltiVec-Built-in-Functions-Available-on-ISA-2_002e07.html
+   https://gcc.gnu.org/onlinedocs/gcc/PowerPC-AltiVec-Built-in-Functions-Available-on-ISA-3_002e0.html
+   grouped under the feature macros that guard them in <altivec.h>.  */
+
+#ifdef __POWER8_VECTOR__
+vector signed long long vec_mul (vector signed long long, vector signed long long);
+vector unsigned long long vec_mul (vector unsigned long long, vector unsigned long long);
+
+vector unsigned char vec_popcnt (vector signed char);
+vector unsigned char vec_popcnt (vector unsigned char);
+vector unsigned short vec_popcnt (vector signed short);
+vector unsigned short vec_popcnt (vector unsigned short);
+vector unsigned int vec_popcnt (vector signed int);
+vector unsigned int vec_popcnt (vector unsigned int);
+vector unsigned long long vec_popcnt (vector signed long long);
+vector unsigned long long vec_popcnt (vector unsigned long long);
+#endif
+
+#ifdef __CRYPTO__
+vector unsigned char vec_cipher_be (vector unsigned char, vector unsigned char);
+vector unsigned char vec_cipherlast_be (vector unsigned char, vector unsigned char);
+vector unsigned char vec_ncipher_be (vector unsigned char, vector unsigned char);
+vector unsigned char vec_ncipherlast_be (vector unsigned char, vector unsigned char);
+vector unsigned char vec_sbox_be (vector unsigned char);
+
+vector unsigned int vec_shasigma_be (vector unsigned int, const int, const int);
+vector unsigned long long vec_shasigma_be (vector unsigned long long, const int, const int);
+#endif
+
+#ifdef __POWER9_VECTOR__
+vector bool char vec_cmpne (vector bool char, vector bool char);
+vector bool char vec_cmpne (vector signed char, vector signed char);
+vector bool char vec_cmpne (vector unsigned char, vector unsigned char);
+vector bool short vec_cmpne (vector bool short, vector bool short);
+vector bool short vec_cmpne (vector signed short, vector signed short);
+vector bool short vec_cmpne (vector unsigned short, vector unsigned short);
+vector bool int vec_cmpne (vector bool int, vector bool int);
+vector bool int vec_cmpne (vector signed int, vector signed int);
+vector bool int vec_cmpne (vector unsigned int, vector unsigned int);
+vector bool long long vec_cmpne (vector bool long long, vector bool long long);
+vector bool long long vec_cmpne (vector signed long long, vector signed long long);
+vector bool long long vec_cmpne (vector unsigned long long, vector unsigned long long);
+
+signed int vec_cntlz_lsbb (vector signed char);
+signed int vec_cntlz_lsbb (vector unsigned char);
+
+vector signed char vec_xl_len (const signed char *, size_t);
+vector unsigned char vec_xl_len (const unsigned char *, size_t);
+vector signed short vec_xl_len (const signed short *, size_t);
+vector unsigned short vec_xl_len (const unsigned short *, size_t);
+vector signed int vec_xl_len (const signed int *, size_t);
+vector unsigned int vec_xl_len (const unsigned int *, size_t);
+vector float vec_xl_len (const float *, size_t);
+vector signed long long vec_xl_len (const signed long long *, size_t);
+vector unsigned long long vec_xl_len (const unsigned long long *, size_t);
+vector double vec_xl_len (const double *, size_t);
+
+vector float vec_extract_fp32_from_shorth (vector unsigned short);
+
+vector bool char vec_revb (vector bool char);
+vector signed char vec_revb (vector signed char);
+vector unsigned char vec_revb (vector unsigned char);
+vector bool short vec_revb (vector bool short);
+vector signed short vec_revb (vector signed short);
+vector unsigned short vec_revb (vector unsigned short);
+vector bool int vec_revb (vector bool int);
+vector signed int vec_revb (vector signed int);
+vector unsigned int vec_revb (vector unsigned int);
+vector float vec_revb (vector float);
+vector bool long long vec_revb (vector bool long long);
+vector signed long long vec_revb (vector signed long long);
+vector unsigned long long vec_revb (vector unsigned long long);
+vector double vec_revb (vector double);
+#endif
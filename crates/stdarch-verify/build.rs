@@ -8,6 +8,10 @@ fn main() {
     walk(&root.join("core_arch/src/x86_64"));
     walk(&root.join("core_arch/src/arm"));
     walk(&root.join("core_arch/src/aarch64"));
+    walk(&root.join("core_arch/src/mips"));
+    walk(&root.join("core_arch/src/loongarch64"));
+    walk(&root.join("core_arch/src/powerpc"));
+    walk(&root.join("core_arch/src/powerpc64"));
 }
 
 fn walk(root: &Path) {
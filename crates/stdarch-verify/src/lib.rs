@@ -30,6 +30,22 @@ pub fn mips_functions(input: TokenStream) -> TokenStream {
     functions(input, &["core_arch/src/mips"])
 }
 
+#[proc_macro]
+pub fn loongarch_functions(input: TokenStream) -> TokenStream {
+    functions(
+        input,
+        &[
+            "core_arch/src/loongarch64/lsx",
+            "core_arch/src/loongarch64/lasx",
+        ],
+    )
+}
+
+#[proc_macro]
+pub fn powerpc_functions(input: TokenStream) -> TokenStream {
+    functions(input, &["core_arch/src/powerpc", "core_arch/src/powerpc64"])
+}
+
 fn functions(input: TokenStream, dirs: &[&str]) -> TokenStream {
     let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
     let root = dir.parent().expect("root-dir not found");
@@ -88,6 +104,25 @@ fn functions(input: TokenStream, dirs: &[&str]) -> TokenStream {
             // println!("{name}");
             let mut arguments = Vec::new();
             let mut const_arguments = Vec::new();
+            // Type parameters are only allowed for the overloaded PowerPC
+            // intrinsics, which are generic over sealed traits.
+            let mut type_params = Vec::new();
+            for generic in f.sig.generics.params.iter() {
+                match *generic {
+                    syn::GenericParam::Const(ref c) => const_arguments.push(to_type(&c.ty)),
+                    syn::GenericParam::Type(ref t) if path.contains("powerpc") => {
+                        type_params.push(&t.ident)
+                    }
+                    _ => panic!("invalid generic argument on {name}"),
+                }
+            }
+            let to_type = |ty: &syn::Type| {
+                if is_generic(ty, &type_params) {
+                    quote! { &GENERIC }
+                } else {
+                    to_type(ty)
+                }
+            };
             for input in f.sig.inputs.iter() {
                 let ty = match *input {
                     syn::FnArg::Typed(ref c) => &c.ty,
@@ -95,13 +130,6 @@ fn functions(input: TokenStream, dirs: &[&str]) -> TokenStream {
                 };
                 arguments.push(to_type(ty));
             }
-            for generic in f.sig.generics.params.iter() {
-                let ty = match *generic {
-                    syn::GenericParam::Const(ref c) => &c.ty,
-                    _ => panic!("invalid generic argument on {name}"),
-                };
-                const_arguments.push(to_type(ty));
-            }
             let ret = match f.sig.output {
                 syn::ReturnType::Default => quote! { None },
                 syn::ReturnType::Type(_, ref t) => {
@@ -335,6 +363,38 @@ fn to_type(t: &syn::Type) -> proc_macro2::TokenStream {
             "v8f16" => quote! { &v8f16 },
             "v4f32" => quote! { &v4f32 },
             "v2f64" => quote! { &v2f64 },
+            "v4i8" | "v4q7" => quote! { &v4i8 },
+            "v2q15" | "v2i16" => quote! { &v2q15 },
+            "v32i8" => quote! { &v32i8 },
+            "v16i16" => quote! { &v16i16 },
+            "v8i32" => quote! { &v8i32 },
+            "v4i64" => quote! { &v4i64 },
+            "v32u8" => quote! { &v32u8 },
+            "v16u16" => quote! { &v16u16 },
+            "v8u32" => quote! { &v8u32 },
+            "v4u64" => quote! { &v4u64 },
+            "v8f32" => quote! { &v8f32 },
+            "v4f64" => quote! { &v4f64 },
+
+            // powerpc ...
+            "isize" => quote! { &ISIZE },
+            "usize" => quote! { &USIZE },
+            "vector_signed_char" => quote! { &VECTOR_SIGNED_CHAR },
+            "vector_unsigned_char" => quote! { &VECTOR_UNSIGNED_CHAR },
+            "vector_bool_char" => quote! { &VECTOR_BOOL_CHAR },
+            "vector_signed_short" => quote! { &VECTOR_SIGNED_SHORT },
+            "vector_unsigned_short" => quote! { &VECTOR_UNSIGNED_SHORT },
+            "vector_bool_short" => quote! { &VECTOR_BOOL_SHORT },
+            "vector_signed_int" => quote! { &VECTOR_SIGNED_INT },
+            "vector_unsigned_int" => quote! { &VECTOR_UNSIGNED_INT },
+            "vector_bool_int" => quote! { &VECTOR_BOOL_INT },
+            "vector_float" => quote! { &VECTOR_FLOAT },
+            "vector_signed_long" => quote! { &VECTOR_SIGNED_LONG },
+            "vector_unsigned_long" => quote! { &VECTOR_UNSIGNED_LONG },
+            "vector_bool_long" => quote! { &VECTOR_BOOL_LONG },
+            "vector_double" => quote! { &VECTOR_DOUBLE },
+            "__vector_pair" => quote! { &VECTOR_PAIR },
+            "__vector_quad" => quote! { &VECTOR_QUAD },
 
             s => panic!("unsupported type: \"{s}\""),
         },
@@ -367,6 +427,17 @@ fn to_type(t: &syn::Type) -> proc_macro2::TokenStream {
     }
 }
 
+/// Whether `t` is one of the type parameters `params`, or a projection like
+/// `<T as sealed::VectorAdd<U>>::Result`.
+fn is_generic(t: &syn::Type, params: &[&syn::Ident]) -> bool {
+    match *t {
+        syn::Type::Path(ref p) => {
+            p.qself.is_some() || params.iter().any(|param| p.path.is_ident(*param))
+        }
+        _ => false,
+    }
+}
+
 fn extract_path_ident(path: &syn::Path) -> syn::Ident {
     if path.leading_colon.is_some() {
         panic!("unsupported leading colon in path")
@@ -0,0 +1,348 @@
+//! Verification of LoongArch LSX and LASX intrinsics
+#![allow(bad_style, unused)]
+
+use std::collections::HashMap;
+
+// These files are obtained from GCC, see the source URL at the top of each.
+static LSX_HEADER: &str = include_str!("../../stdarch-gen-loongarch/lsxintrin.h");
+static LASX_HEADER: &str = include_str!("../../stdarch-gen-loongarch/lasxintrin.h");
+
+stdarch_verify::loongarch_functions!(static FUNCTIONS);
+
+struct Function {
+    name: &'static str,
+    arguments: &'static [&'static Type],
+    ret: Option<&'static Type>,
+    target_feature: Option<&'static str>,
+    instrs: &'static [&'static str],
+    file: &'static str,
+    required_const: &'static [usize],
+    has_test: bool,
+}
+
+static F16: Type = Type::PrimFloat(16);
+static F32: Type = Type::PrimFloat(32);
+static F64: Type = Type::PrimFloat(64);
+static I8: Type = Type::PrimSigned(8);
+static I16: Type = Type::PrimSigned(16);
+static I32: Type = Type::PrimSigned(32);
+static I64: Type = Type::PrimSigned(64);
+static U8: Type = Type::PrimUnsigned(8);
+static U16: Type = Type::PrimUnsigned(16);
+static U32: Type = Type::PrimUnsigned(32);
+static U64: Type = Type::PrimUnsigned(64);
+static NEVER: Type = Type::Never;
+static TUPLE: Type = Type::Tuple;
+static v16i8: Type = Type::I(8, 16, 1);
+static v8i16: Type = Type::I(16, 8, 1);
+static v4i32: Type = Type::I(32, 4, 1);
+static v2i64: Type = Type::I(64, 2, 1);
+static v16u8: Type = Type::U(8, 16, 1);
+static v8u16: Type = Type::U(16, 8, 1);
+static v4u32: Type = Type::U(32, 4, 1);
+static v2u64: Type = Type::U(64, 2, 1);
+static v8f16: Type = Type::F(16, 8, 1);
+static v4f32: Type = Type::F(32, 4, 1);
+static v2f64: Type = Type::F(64, 2, 1);
+static v32i8: Type = Type::I(8, 32, 1);
+static v16i16: Type = Type::I(16, 16, 1);
+static v8i32: Type = Type::I(32, 8, 1);
+static v4i64: Type = Type::I(64, 4, 1);
+static v32u8: Type = Type::U(8, 32, 1);
+static v16u16: Type = Type::U(16, 16, 1);
+static v8u32: Type = Type::U(32, 8, 1);
+static v4u64: Type = Type::U(64, 4, 1);
+static v8f32: Type = Type::F(32, 8, 1);
+static v4f64: Type = Type::F(64, 4, 1);
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Type {
+    PrimFloat(u8),
+    PrimSigned(u8),
+    PrimUnsigned(u8),
+    PrimPoly(u8),
+    MutPtr(&'static Type),
+    ConstPtr(&'static Type),
+    Tuple,
+    I(u8, u8, u8),
+    U(u8, u8, u8),
+    P(u8, u8, u8),
+    F(u8, u8, u8),
+    Never,
+}
+
+/// Returns the type of the C scalar type `t`.
+fn scalar(t: &str) -> Type {
+    match t {
+        "signed char" => Type::PrimSigned(8),
+        "unsigned char" => Type::PrimUnsigned(8),
+        "short" => Type::PrimSigned(16),
+        "unsigned short" => Type::PrimUnsigned(16),
+        "int" => Type::PrimSigned(32),
+        "unsigned int" => Type::PrimUnsigned(32),
+        "long int" | "long long" => Type::PrimSigned(64),
+        "unsigned long int" | "unsigned long long" => Type::PrimUnsigned(64),
+        "float" => Type::PrimFloat(32),
+        "double" => Type::PrimFloat(64),
+        t => panic!("unknown type: \"{t}\""),
+    }
+}
+
+/// Parses the vector types declared in a header, like
+/// `typedef signed char v16i8 __attribute__ ((vector_size(16), aligned(16)));`.
+fn typedefs(header: &str) -> HashMap<&str, Type> {
+    let mut types = HashMap::new();
+    for line in header.lines() {
+        let Some((decl, attrs)) = line
+            .strip_prefix("typedef ")
+            .and_then(|l| l.split_once(" __attribute__"))
+        else {
+            continue;
+        };
+        let (element, name) = decl.rsplit_once(' ').unwrap();
+        let size = attrs.split_once("vector_size").unwrap().1;
+        let bytes: u32 = size
+            .trim_start_matches(['_', ' ', '('])
+            .split(')')
+            .next()
+            .unwrap()
+            .parse()
+            .unwrap();
+        let lanes = |bits| (bytes * 8 / u32::from(bits)) as u8;
+        let ty = match scalar(element) {
+            Type::PrimSigned(bits) => Type::I(bits, lanes(bits), 1),
+            Type::PrimUnsigned(bits) => Type::U(bits, lanes(bits), 1),
+            Type::PrimFloat(bits) => Type::F(bits, lanes(bits), 1),
+            _ => unreachable!(),
+        };
+        types.insert(name, ty);
+    }
+    types
+}
+
+/// Returns the integer vector type of the GCC machine mode `mode`, like
+/// `V16QI` or `UV4DI`.
+fn integer_mode(mode: &str) -> Type {
+    let (unsigned, mode) = match mode.strip_prefix('U') {
+        Some(mode) => (true, mode),
+        None => (false, mode),
+    };
+    let mode = mode.strip_prefix('V').unwrap();
+    let (lanes, element) = mode.split_at(mode.len() - 2);
+    let bits = match element {
+        "QI" => 8,
+        "HI" => 16,
+        "SI" => 32,
+        "DI" => 64,
+        _ => panic!("unknown mode: \"{mode}\""),
+    };
+    let lanes = lanes.parse().unwrap();
+    if unsigned {
+        Type::U(bits, lanes, 1)
+    } else {
+        Type::I(bits, lanes, 1)
+    }
+}
+
+#[derive(Debug)]
+struct LoongArchIntrinsic {
+    id: String,
+    target_feature: &'static str,
+    ret: Option<Type>,
+    arguments: Vec<Type>,
+    required_const: Vec<usize>,
+}
+
+/// Parses the intrinsics of a GCC header.
+///
+/// Every intrinsic is a function or a macro that casts its arguments to the
+/// operand types of a builtin, and casts the result of the builtin to its
+/// return type, like
+/// `return (__m128i)__builtin_lsx_vsll_b ((v16i8)_1, (v16i8)_2);`. The
+/// immediate operands are not cast, and their format, like `ui3`, is in a
+/// comment in the parameters of the macro. Intrinsics depending on further
+/// extensions, like `frecipe`, are guarded by `#if defined` blocks and
+/// skipped.
+fn parse(header: &'static str, target_feature: &'static str) -> Vec<LoongArchIntrinsic> {
+    let guard = match target_feature {
+        "lsx" => "(__loongarch_sx)",
+        _ => "(__loongarch_asx)",
+    };
+    let types = typedefs(header);
+    let pointer = |is_store| {
+        if is_store {
+            Type::MutPtr(&I8)
+        } else {
+            Type::ConstPtr(&I8)
+        }
+    };
+
+    let mut intrinsics = Vec::new();
+    let mut mode = "";
+    let mut immediates = HashMap::new();
+    let mut skip = false;
+    for line in header.lines() {
+        if line.starts_with("#if defined") {
+            skip = !line.contains(guard);
+        } else if line.starts_with("#endif") {
+            skip = false;
+        } else if skip {
+            continue;
+        } else if let Some(types) = line.strip_prefix("/* Data types in instruction templates:") {
+            // The first data type is the one of the result.
+            mode = types.trim().split(',').next().unwrap();
+        } else if let Some(macro_) = line.strip_prefix("#define __") {
+            // `#define __lsx_vslli_b(/*__m128i*/ _1, /*ui3*/ _2) \`
+            let params = macro_.split_once('(').unwrap().1;
+            immediates = params
+                .split(',')
+                .filter_map(|param| {
+                    let (format, param) = param.trim().strip_prefix("/*")?.split_once("*/")?;
+                    let param = param.trim().trim_end_matches([')', ' ', '\\']);
+                    Some((param, format))
+                })
+                .collect();
+        } else if let Some((cast, call)) = line.split_once(&format!("__builtin_{target_feature}_"))
+        {
+            let id = &call[..call.find([' ', '(']).unwrap()];
+            let ret = cast.trim_end().strip_suffix(')').unwrap();
+            let ret = ret.rsplit_once('(').unwrap().1;
+            let is_store = ret == "void";
+            let ret = match ret {
+                "void" => None,
+                // The integer vectors are opaque, the type of their elements
+                // is the mode of the result.
+                "__m128i" | "__m256i" => Some(integer_mode(mode)),
+                t => Some(types.get(t).copied().unwrap_or_else(|| scalar(t))),
+            };
+
+            // The arguments of the builtin, like `(v16i8)(_1)` or `(_2)`.
+            let args = call.split_once('(').unwrap().1;
+            let mut depth = 0;
+            let end = args
+                .find(|c| {
+                    match c {
+                        '(' => depth += 1,
+                        ')' if depth == 0 => return true,
+                        ')' => depth -= 1,
+                        _ => {}
+                    }
+                    false
+                })
+                .unwrap();
+            let mut arguments = Vec::new();
+            let mut required_const = Vec::new();
+            for (i, arg) in args[..end].split(',').enumerate() {
+                let (t, _) = arg
+                    .trim()
+                    .strip_prefix('(')
+                    .unwrap()
+                    .split_once(')')
+                    .unwrap();
+                if t.starts_with('_') {
+                    let format = immediates[t];
+                    let unsigned = format.starts_with("ui") || format == "idx";
+                    arguments.push(if unsigned { U32 } else { I32 });
+                    required_const.push(i);
+                } else if t == "void *" {
+                    arguments.push(pointer(is_store));
+                } else {
+                    arguments.push(types.get(t).copied().unwrap_or_else(|| scalar(t)));
+                }
+            }
+
+            intrinsics.push(LoongArchIntrinsic {
+                // `__builtin_lsx_vadd_b` is `lsx_vadd_b` in Rust.
+                id: format!("{target_feature}_{id}"),
+                target_feature,
+                ret,
+                arguments,
+                required_const,
+            });
+        }
+    }
+    intrinsics
+}
+
+#[test]
+fn verify_all_signatures() {
+    let mut intrinsics = HashMap::<String, LoongArchIntrinsic>::new();
+    for intrinsic in parse(LSX_HEADER, "lsx")
+        .into_iter()
+        .chain(parse(LASX_HEADER, "lasx"))
+    {
+        assert!(!intrinsics.contains_key(&intrinsic.id));
+        intrinsics.insert(intrinsic.id.clone(), intrinsic);
+    }
+    assert!(!intrinsics.is_empty());
+
+    let mut all_valid = true;
+    for rust in FUNCTIONS {
+        let loongarch = match intrinsics.remove(rust.name) {
+            Some(i) => i,
+            None => {
+                println!(
+                    "missing loongarch definition for {:?} in {}",
+                    rust.name, rust.file
+                );
+                all_valid = false;
+                continue;
+            }
+        };
+
+        if let Err(e) = matches(rust, &loongarch) {
+            println!("failed to verify `{}`", rust.name);
+            println!("  * {e}");
+            all_valid = false;
+        }
+    }
+
+    let mut missing: Vec<_> = intrinsics.keys().collect();
+    missing.sort();
+    for id in missing {
+        println!("missing Rust definition of {id}");
+        all_valid = false;
+    }
+    assert!(all_valid);
+}
+
+fn matches(rust: &Function, loongarch: &LoongArchIntrinsic) -> Result<(), String> {
+    macro_rules! bail {
+        ($($t:tt)*) => (return Err(format!($($t)*)))
+    }
+
+    if rust.ret.copied() != loongarch.ret {
+        bail!(
+            "mismatched return value: {:?} != {:?}",
+            rust.ret,
+            loongarch.ret
+        );
+    }
+
+    if rust.arguments.len() != loongarch.arguments.len() {
+        bail!("mismatched argument lengths");
+    }
+    for (i, (rust_arg, arg)) in rust.arguments.iter().zip(&loongarch.arguments).enumerate() {
+        if *rust_arg != arg {
+            bail!("mismatched argument {i}: {:?} != {:?}", rust_arg, arg);
+        }
+    }
+
+    if rust.required_const != loongarch.required_const.as_slice() {
+        bail!(
+            "argument const mismatch: {:?} != {:?}",
+            rust.required_const,
+            loongarch.required_const
+        );
+    }
+
+    if rust.instrs.is_empty() {
+        bail!("instruction not listed for `{}`", rust.name);
+    }
+
+    if rust.target_feature != Some(loongarch.target_feature) {
+        bail!("wrong target_feature");
+    }
+
+    Ok(())
+}
@@ -1,10 +1,14 @@
-//! Verification of MIPS MSA intrinsics
+//! Verification of MIPS MSA and DSP ASE intrinsics
 #![allow(bad_style, unused)]
 
 // This file is obtained from
 // https://gcc.gnu.org/onlinedocs//gcc/MIPS-SIMD-Architecture-Built-in-Functions.html
 static HEADER: &str = include_str!("../mips-msa.h");
 
+// This file is obtained from
+// https://gcc.gnu.org/onlinedocs/gcc/MIPS-DSP-Built-in-Functions.html
+static DSP_HEADER: &str = include_str!("../mips-dsp.h");
+
 stdarch_verify::mips_functions!(static FUNCTIONS);
 
 struct Function {
@@ -29,6 +33,7 @@ static U8: Type = Type::PrimUnsigned(8);
 static U16: Type = Type::PrimUnsigned(16);
 static U32: Type = Type::PrimUnsigned(32);
 static U64: Type = Type::PrimUnsigned(64);
+static USIZE: Type = Type::PrimUnsigned(64);
 static NEVER: Type = Type::Never;
 static TUPLE: Type = Type::Tuple;
 static v16i8: Type = Type::I(8, 16, 1);
@@ -42,6 +47,8 @@ static v2u64: Type = Type::U(64, 2, 1);
 static v8f16: Type = Type::F(16, 8, 1);
 static v4f32: Type = Type::F(32, 4, 1);
 static v2f64: Type = Type::F(64, 2, 1);
+static v4i8: Type = Type::I(8, 4, 1);
+static v2q15: Type = Type::I(16, 2, 1);
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 enum Type {
@@ -81,6 +88,7 @@ enum MsaTy {
     imm0_63,
     imm0_255,
     imm_n16_15,
+    imm_n32_31,
     imm_n512_511,
     imm_n1024_1022,
     imm_n2048_2044,
@@ -91,6 +99,14 @@ enum MsaTy {
     u64,
     Void,
     MutVoidPtr,
+    // DSP ASE
+    v4i8,
+    v4q7,
+    v2q15,
+    v2i16,
+    q31,
+    ui32,
+    a64,
 }
 
 impl<'a> From<&'a str> for MsaTy {
@@ -115,6 +131,7 @@ impl<'a> From<&'a str> for MsaTy {
             "imm0_63" => MsaTy::imm0_63,
             "imm0_255" => MsaTy::imm0_255,
             "imm_n16_15" => MsaTy::imm_n16_15,
+            "imm_n32_31" => MsaTy::imm_n32_31,
             "imm_n512_511" => MsaTy::imm_n512_511,
             "imm_n1024_1022" => MsaTy::imm_n1024_1022,
             "imm_n2048_2044" => MsaTy::imm_n2048_2044,
@@ -125,6 +142,13 @@ impl<'a> From<&'a str> for MsaTy {
             "u64" => MsaTy::u64,
             "void" => MsaTy::Void,
             "void *" => MsaTy::MutVoidPtr,
+            "v4i8" => MsaTy::v4i8,
+            "v4q7" => MsaTy::v4q7,
+            "v2q15" => MsaTy::v2q15,
+            "v2i16" => MsaTy::v2i16,
+            "q31" => MsaTy::q31,
+            "ui32" => MsaTy::ui32,
+            "a64" => MsaTy::a64,
             v => panic!("unknown ty: \"{v}\""),
         }
     }
@@ -167,9 +191,15 @@ impl std::convert::TryFrom<&'static str> for MsaIntrinsic {
                 let arg = arg.trim();
                 arg_tys.push(MsaTy::from(arg));
             }
+            if arg_tys == [MsaTy::Void] {
+                arg_tys.clear();
+            }
 
-            // The instruction is the intrinsic name without the __msa_ prefix.
-            let instruction = &id[6..];
+            // The instruction is the intrinsic name without the __msa_ or
+            // __mips_ prefix.
+            let instruction = id
+                .strip_prefix("__msa_")
+                .or_else(|| id.strip_prefix("__mips_"))?;
             let mut instruction = instruction.to_string();
             // With all underscores but the first one replaced with a `.`
             if let Some(first_underscore) = instruction.find('_') {
@@ -206,6 +236,11 @@ fn verify_all_signatures() {
 
     let mut all_valid = true;
     for rust in FUNCTIONS {
+        // Only the MSA intrinsics are declared in mips-msa.h.
+        if !rust.name.starts_with("__msa_") {
+            continue;
+        }
+
         if !rust.has_test {
             let skip = [
                 "__msa_ceqi_d",
@@ -216,7 +251,6 @@ fn verify_all_signatures() {
                 "__msa_ldi_d",
                 "__msa_maxi_s_d",
                 "__msa_mini_s_d",
-                "break_",
             ];
             if !skip.contains(&rust.name) {
                 println!(
@@ -234,11 +268,6 @@ fn verify_all_signatures() {
             }
         }
 
-        // Skip some intrinsics that aren't part of MSA
-        match rust.name {
-            "break_" => continue,
-            _ => {}
-        }
         let mips = match intrinsics.get(rust.name) {
             Some(i) => i,
             None => {
@@ -251,7 +280,7 @@ fn verify_all_signatures() {
             }
         };
 
-        if let Err(e) = matches(rust, mips) {
+        if let Err(e) = matches(rust, mips, Some("msa")) {
             println!("failed to verify `{}`", rust.name);
             println!("  * {e}");
             all_valid = false;
@@ -260,7 +289,64 @@ fn verify_all_signatures() {
     assert!(all_valid);
 }
 
-fn matches(rust: &Function, mips: &MsaIntrinsic) -> Result<(), String> {
+#[test]
+fn verify_dsp_signatures() {
+    // GCC declares overloads of some intrinsics, usually one taking an
+    // immediate and one taking a register, so collect all of them:
+    let mut intrinsics = std::collections::HashMap::<String, Vec<MsaIntrinsic>>::new();
+    for line in DSP_HEADER.lines() {
+        if line.is_empty() {
+            continue;
+        }
+
+        use std::convert::TryFrom;
+        let intrinsic: MsaIntrinsic =
+            TryFrom::try_from(line).unwrap_or_else(|_| panic!("failed to parse line: \"{line}\""));
+        intrinsics
+            .entry(intrinsic.id.clone())
+            .or_default()
+            .push(intrinsic);
+    }
+
+    let mut all_valid = true;
+    for rust in FUNCTIONS {
+        if !rust.name.starts_with("__mips_") {
+            continue;
+        }
+
+        let overloads = match intrinsics.get(rust.name) {
+            Some(i) => i,
+            None => {
+                eprintln!(
+                    "missing mips definition for {:?} in {}",
+                    rust.name, rust.file
+                );
+                all_valid = false;
+                continue;
+            }
+        };
+
+        // rustc has no target features for the DSP ASE.
+        let errors = overloads
+            .iter()
+            .filter_map(|mips| matches(rust, mips, None).err())
+            .collect::<Vec<_>>();
+        if errors.len() == overloads.len() {
+            println!("failed to verify `{}`", rust.name);
+            for e in errors {
+                println!("  * {e}");
+            }
+            all_valid = false;
+        }
+    }
+    assert!(all_valid);
+}
+
+fn matches(
+    rust: &Function,
+    mips: &MsaIntrinsic,
+    target_feature: Option<&str>,
+) -> Result<(), String> {
     macro_rules! bail {
         ($($t:tt)*) => (return Err(format!($($t)*)))
     }
@@ -286,6 +372,8 @@ fn matches(rust: &Function, mips: &MsaIntrinsic) -> Result<(), String> {
             MsaTy::v2u64 if **rust_arg == v2u64 => (),
             MsaTy::v4f32 if **rust_arg == v4f32 => (),
             MsaTy::v2f64 if **rust_arg == v2f64 => (),
+            MsaTy::v4i8 | MsaTy::v4q7 if **rust_arg == v4i8 => (),
+            MsaTy::v2q15 | MsaTy::v2i16 if **rust_arg == v2q15 => (),
             MsaTy::imm0_1
             | MsaTy::imm0_3
             | MsaTy::imm0_7
@@ -294,14 +382,15 @@ fn matches(rust: &Function, mips: &MsaIntrinsic) -> Result<(), String> {
             | MsaTy::imm0_63
             | MsaTy::imm0_255
             | MsaTy::imm_n16_15
+            | MsaTy::imm_n32_31
             | MsaTy::imm_n512_511
             | MsaTy::imm_n1024_1022
             | MsaTy::imm_n2048_2044
             | MsaTy::imm_n4096_4088
                 if **rust_arg == I32 => {}
-            MsaTy::i32 if **rust_arg == I32 => (),
-            MsaTy::i64 if **rust_arg == I64 => (),
-            MsaTy::u32 if **rust_arg == U32 => (),
+            MsaTy::i32 | MsaTy::q31 if **rust_arg == I32 => (),
+            MsaTy::i64 | MsaTy::a64 if **rust_arg == I64 => (),
+            MsaTy::u32 | MsaTy::ui32 if **rust_arg == U32 => (),
             MsaTy::u64 if **rust_arg == U64 => (),
             MsaTy::MutVoidPtr if **rust_arg == Type::MutPtr(&U8) => (),
             m => bail!(
@@ -322,6 +411,7 @@ fn matches(rust: &Function, mips: &MsaIntrinsic) -> Result<(), String> {
                 | MsaTy::imm0_63
                 | MsaTy::imm0_255
                 | MsaTy::imm_n16_15
+                | MsaTy::imm_n32_31
                 | MsaTy::imm_n512_511
                 | MsaTy::imm_n1024_1022
                 | MsaTy::imm_n2048_2044
@@ -339,7 +429,7 @@ fn matches(rust: &Function, mips: &MsaIntrinsic) -> Result<(), String> {
         bail!("wrong number of const arguments");
     }
 
-    if rust.target_feature != Some("msa") {
+    if rust.target_feature != target_feature {
         bail!("wrong target_feature");
     }
 
@@ -0,0 +1,260 @@
+//! Verification of the PowerPC POWER8 and POWER9 vector intrinsics
+#![allow(bad_style, unused)]
+
+// This file is an excerpt of the GCC documentation, see the source URLs at
+// its top.
+static HEADER: &str = include_str!("../altivec.h");
+
+stdarch_verify::powerpc_functions!(static FUNCTIONS);
+
+struct Function {
+    name: &'static str,
+    arguments: &'static [&'static Type],
+    ret: Option<&'static Type>,
+    target_feature: Option<&'static str>,
+    instrs: &'static [&'static str],
+    file: &'static str,
+    required_const: &'static [usize],
+    has_test: bool,
+}
+
+static BOOL: Type = Type::PrimBool;
+static F32: Type = Type::PrimFloat(32);
+static F64: Type = Type::PrimFloat(64);
+static I8: Type = Type::PrimSigned(8);
+static I16: Type = Type::PrimSigned(16);
+static I32: Type = Type::PrimSigned(32);
+static I64: Type = Type::PrimSigned(64);
+static ISIZE: Type = Type::PrimSigned(64);
+static U8: Type = Type::PrimUnsigned(8);
+static U16: Type = Type::PrimUnsigned(16);
+static U32: Type = Type::PrimUnsigned(32);
+static U64: Type = Type::PrimUnsigned(64);
+static USIZE: Type = Type::PrimUnsigned(64);
+static NEVER: Type = Type::Never;
+static TUPLE: Type = Type::Tuple;
+static GENERIC: Type = Type::Generic;
+static CONST_I8_PTR: Type = Type::ConstPtr(&I8);
+static CONST_U8_PTR: Type = Type::ConstPtr(&U8);
+static CONST_I16_PTR: Type = Type::ConstPtr(&I16);
+static CONST_U16_PTR: Type = Type::ConstPtr(&U16);
+static CONST_I32_PTR: Type = Type::ConstPtr(&I32);
+static CONST_U32_PTR: Type = Type::ConstPtr(&U32);
+static CONST_F32_PTR: Type = Type::ConstPtr(&F32);
+static CONST_I64_PTR: Type = Type::ConstPtr(&I64);
+static CONST_U64_PTR: Type = Type::ConstPtr(&U64);
+static CONST_F64_PTR: Type = Type::ConstPtr(&F64);
+static VECTOR_SIGNED_CHAR: Type = Type::I(8, 16, 1);
+static VECTOR_UNSIGNED_CHAR: Type = Type::U(8, 16, 1);
+static VECTOR_BOOL_CHAR: Type = Type::B(8, 16, 1);
+static VECTOR_SIGNED_SHORT: Type = Type::I(16, 8, 1);
+static VECTOR_UNSIGNED_SHORT: Type = Type::U(16, 8, 1);
+static VECTOR_BOOL_SHORT: Type = Type::B(16, 8, 1);
+static VECTOR_SIGNED_INT: Type = Type::I(32, 4, 1);
+static VECTOR_UNSIGNED_INT: Type = Type::U(32, 4, 1);
+static VECTOR_BOOL_INT: Type = Type::B(32, 4, 1);
+static VECTOR_FLOAT: Type = Type::F(32, 4, 1);
+static VECTOR_SIGNED_LONG: Type = Type::I(64, 2, 1);
+static VECTOR_UNSIGNED_LONG: Type = Type::U(64, 2, 1);
+static VECTOR_BOOL_LONG: Type = Type::B(64, 2, 1);
+static VECTOR_DOUBLE: Type = Type::F(64, 2, 1);
+static VECTOR_PAIR: Type = Type::VectorPair;
+static VECTOR_QUAD: Type = Type::VectorQuad;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Type {
+    PrimBool,
+    PrimFloat(u8),
+    PrimSigned(u8),
+    PrimUnsigned(u8),
+    MutPtr(&'static Type),
+    ConstPtr(&'static Type),
+    Tuple,
+    I(u8, u8, u8),
+    U(u8, u8, u8),
+    B(u8, u8, u8),
+    F(u8, u8, u8),
+    VectorPair,
+    VectorQuad,
+    Never,
+    /// A type parameter of an overloaded intrinsic, or a projection of one.
+    Generic,
+}
+
+fn from_c(t: &str) -> &'static Type {
+    match t {
+        "vector signed char" => &VECTOR_SIGNED_CHAR,
+        "vector unsigned char" => &VECTOR_UNSIGNED_CHAR,
+        "vector bool char" => &VECTOR_BOOL_CHAR,
+        "vector signed short" => &VECTOR_SIGNED_SHORT,
+        "vector unsigned short" => &VECTOR_UNSIGNED_SHORT,
+        "vector bool short" => &VECTOR_BOOL_SHORT,
+        "vector signed int" => &VECTOR_SIGNED_INT,
+        "vector unsigned int" => &VECTOR_UNSIGNED_INT,
+        "vector bool int" => &VECTOR_BOOL_INT,
+        "vector float" => &VECTOR_FLOAT,
+        "vector signed long long" => &VECTOR_SIGNED_LONG,
+        "vector unsigned long long" => &VECTOR_UNSIGNED_LONG,
+        "vector bool long long" => &VECTOR_BOOL_LONG,
+        "vector double" => &VECTOR_DOUBLE,
+        "signed int" | "int" => &I32,
+        "size_t" => &USIZE,
+        "const signed char *" => &CONST_I8_PTR,
+        "const unsigned char *" => &CONST_U8_PTR,
+        "const signed short *" => &CONST_I16_PTR,
+        "const unsigned short *" => &CONST_U16_PTR,
+        "const signed int *" => &CONST_I32_PTR,
+        "const unsigned int *" => &CONST_U32_PTR,
+        "const float *" => &CONST_F32_PTR,
+        "const signed long long *" => &CONST_I64_PTR,
+        "const unsigned long long *" => &CONST_U64_PTR,
+        "const double *" => &CONST_F64_PTR,
+        t => panic!("unknown type: \"{t}\""),
+    }
+}
+
+/// One overload of an intrinsic, like
+/// `vector unsigned char vec_popcnt (vector signed char);`.
+#[derive(Debug)]
+struct Overload {
+    ret: &'static Type,
+    /// The arguments, except the `const int` immediates, which are const
+    /// generics in Rust.
+    arguments: Vec<&'static Type>,
+}
+
+#[derive(Debug)]
+struct PowerPcIntrinsic {
+    id: String,
+    target_feature: &'static str,
+    overloads: Vec<Overload>,
+}
+
+/// Parses the prototypes of the header, keyed by the intrinsic name.
+///
+/// The target feature of each intrinsic comes from the `#ifdef` block it is
+/// declared in.
+fn parse(header: &'static str) -> std::collections::HashMap<String, PowerPcIntrinsic> {
+    let mut intrinsics = std::collections::HashMap::<String, PowerPcIntrinsic>::new();
+    let mut target_feature = None;
+    for line in header.lines() {
+        if let Some(guard) = line.strip_prefix("#ifdef ") {
+            target_feature = Some(match guard {
+                "__POWER8_VECTOR__" => "power8-vector",
+                "__POWER9_VECTOR__" => "power9-vector",
+                "__CRYPTO__" => "power8-crypto",
+                g => panic!("unknown guard: \"{g}\""),
+            });
+            continue;
+        } else if line.starts_with("#endif") {
+            target_feature = None;
+            continue;
+        }
+        let Some(line) = line.strip_suffix(");") else {
+            continue;
+        };
+
+        let (decl, args) = line.split_once('(').unwrap();
+        let (ret, id) = decl.trim().rsplit_once(' ').unwrap();
+        let overload = Overload {
+            ret: from_c(ret),
+            arguments: args
+                .split(',')
+                .map(str::trim)
+                .filter(|arg| *arg != "const int")
+                .map(from_c)
+                .collect(),
+        };
+
+        let target_feature = target_feature.expect("intrinsic outside of an #ifdef block");
+        let intrinsic = intrinsics
+            .entry(id.to_string())
+            .or_insert_with(|| PowerPcIntrinsic {
+                id: id.to_string(),
+                target_feature,
+                overloads: Vec::new(),
+            });
+        assert_eq!(intrinsic.target_feature, target_feature);
+        intrinsic.overloads.push(overload);
+    }
+    intrinsics
+}
+
+#[test]
+fn verify_all_signatures() {
+    let mut intrinsics = parse(HEADER);
+    assert!(!intrinsics.is_empty());
+    let target_features: Vec<_> = intrinsics.values().map(|i| i.target_feature).collect();
+
+    let mut all_valid = true;
+    for rust in FUNCTIONS {
+        // Only the intrinsics of the ISA versions in the header are verified.
+        if !rust
+            .target_feature
+            .is_some_and(|feature| target_features.contains(&feature))
+        {
+            continue;
+        }
+
+        let powerpc = match intrinsics.remove(rust.name) {
+            Some(i) => i,
+            None => {
+                println!(
+                    "missing powerpc definition for {:?} in {}",
+                    rust.name, rust.file
+                );
+                all_valid = false;
+                continue;
+            }
+        };
+
+        if let Err(e) = matches(rust, &powerpc) {
+            println!("failed to verify `{}`", rust.name);
+            println!("  * {e}");
+            all_valid = false;
+        }
+    }
+
+    let mut missing: Vec<_> = intrinsics.keys().collect();
+    missing.sort();
+    for id in missing {
+        println!("missing Rust definition of {id}");
+        all_valid = false;
+    }
+    assert!(all_valid);
+}
+
+fn matches(rust: &Function, powerpc: &PowerPcIntrinsic) -> Result<(), String> {
+    macro_rules! bail {
+        ($($t:tt)*) => (return Err(format!($($t)*)))
+    }
+
+    // The generic arguments and return value are checked by the sealed
+    // traits, so only the concrete ones are compared with every overload.
+    for overload in &powerpc.overloads {
+        match rust.ret {
+            Some(&Type::Generic) => {}
+            Some(ret) if ret == overload.ret => {}
+            ret => bail!("mismatched return value: {:?} != {:?}", ret, overload.ret),
+        }
+
+        if rust.arguments.len() != overload.arguments.len() {
+            bail!("mismatched argument lengths");
+        }
+        for (i, (rust_arg, arg)) in rust.arguments.iter().zip(&overload.arguments).enumerate() {
+            if **rust_arg != Type::Generic && rust_arg != arg {
+                bail!("mismatched argument {i}: {:?} != {:?}", rust_arg, arg);
+            }
+        }
+    }
+
+    if !rust.required_const.is_empty() {
+        bail!("immediates should be const generics");
+    }
+
+    if rust.target_feature != Some(powerpc.target_feature) {
+        bail!("wrong target_feature");
+    }
+
+    Ok(())
+}